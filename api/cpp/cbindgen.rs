@@ -32,6 +32,10 @@ macro_rules! enum_file {
             writeln!(enums_priv, "using slint::testing::AccessibleRole;")?;
             &mut enums_pub
         }};
+        (ColorSpace) => {{
+            writeln!(enums_priv, "using slint::ColorSpace;")?;
+            &mut enums_pub
+        }};
         ($_:ident) => {
             &mut enums_priv
         };
@@ -230,6 +234,7 @@ fn default_config() -> cbindgen::Config {
             ("FloatArg".into(), "float".into()),
             ("IntArg".into(), "int".into()),
             ("MenuEntryArg".into(), "MenuEntry".into()),
+            ("ColorArg".into(), "Color".into()),
             // Note: these types are not the same, but they are only used in callback return types that are only used in C++ (set and called)
             // therefore it is ok to reinterpret_cast
             ("MenuEntryModel".into(), "std::shared_ptr<slint::Model<MenuEntry>>".into()),
@@ -296,11 +301,13 @@ fn gen_corelib(
         "BorderRectangle",
         "DragArea",
         "DropArea",
+        "ScreenColorPicker",
         "ImageItem",
         "ClippedImage",
         "TouchArea",
         "FocusScope",
         "SwipeGestureHandler",
+        "PinchGestureHandler",
         "Flickable",
         "SimpleText",
         "ComplexText",
@@ -313,6 +320,11 @@ fn gen_corelib(
         "Transform",
         "Opacity",
         "Layer",
+        "ShaderEffect",
+        "BackdropBlur",
+        "Mask",
+        "Blend",
+        "ZOrder",
         "ContextMenu",
         "MenuItem",
     ];
@@ -396,6 +408,7 @@ fn gen_corelib(
         "Point",
         "MenuEntryModel",
         "MenuEntryArg",
+        "ColorArg",
         "Coord",
         "LogicalRect",
         "LogicalPoint",
@@ -461,6 +474,10 @@ fn gen_corelib(
             "slint_timer_stop",
             "slint_timer_restart",
             "slint_timer_running",
+            "slint_timer_pause",
+            "slint_timer_resume",
+            "slint_timer_paused",
+            "slint_timer_remaining",
         ]
         .iter()
         .map(|s| s.to_string())
@@ -494,6 +511,8 @@ fn gen_corelib(
                 "slint_image_to_rgba8_premultiplied",
                 "SharedPixelBuffer",
                 "SharedImageBuffer",
+                "Rgb565Pixel",
+                "Gray8Pixel",
                 "StaticTextures",
                 "BorrowedOpenGLTextureOrigin"
             ],
@@ -506,7 +525,12 @@ fn gen_corelib(
             "slint_color_mix",
             "slint_color_with_alpha",
             "slint_color_to_hsva",
-            "slint_color_from_hsva",],
+            "slint_color_from_hsva",
+            "slint_color_mix_in",
+            "slint_color_to_hsl",
+            "slint_color_from_hsl",
+            "slint_color_to_oklch",
+            "slint_color_from_oklch",],
             "slint_color_internal.h",
             "",
         ),
@@ -521,7 +545,7 @@ fn gen_corelib(
             "",
         ),
         (
-            vec!["MouseEvent"],
+            vec!["MouseEvent", "GestureEvent"],
             "slint_events_internal.h",
             "#include \"slint_point.h\"
             namespace slint::cbindgen_private {
@@ -548,6 +572,8 @@ fn gen_corelib(
             "slint_windowrc_set_scale_factor",
             "slint_windowrc_get_text_input_focused",
             "slint_windowrc_set_text_input_focused",
+            "slint_windowrc_set_clipboard_text",
+            "slint_windowrc_open_url",
             "slint_windowrc_set_focus_item",
             "slint_windowrc_set_component",
             "slint_windowrc_show_popup",
@@ -869,6 +869,79 @@ public:
         cbindgen_private::slint_software_renderer_set_rendering_rotation(
                 inner, static_cast<int>(rotation));
     }
+
+    /// This enum describes the arrangement of the sub-pixels of the display.
+    /// To be used in set_text_subpixel_layout()
+    enum class SubpixelLayout {
+        /// Text is rendered with grayscale anti-aliasing
+        None = 0,
+        /// The sub-pixels are arranged horizontally, in the order red, green, blue
+        Rgb = 1,
+        /// The sub-pixels are arranged horizontally, in the order blue, green, red
+        Bgr = 2,
+    };
+
+    /// Enable sub-pixel anti-aliasing of text, for a display with the given arrangement of
+    /// sub-pixels. This makes text more legible on displays with a low pixel density.
+    ///
+    /// It only applies to text rendered with vector fonts, and only if the display isn't rotated
+    /// by 90 or 270 degrees.
+    void set_text_subpixel_layout(SubpixelLayout layout)
+    {
+        cbindgen_private::slint_software_renderer_set_text_subpixel_layout(
+                inner, static_cast<int>(layout));
+    }
+
+    /// This enum describes how the gradients are dithered.
+    /// To be used in set_dithering()
+    enum class Dithering {
+        /// The colors are truncated to the precision of the buffer
+        None = 0,
+        /// Ordered dithering with a fixed pattern
+        Ordered = 1,
+        /// Ordered dithering with a pattern that changes on every frame
+        Temporal = 2,
+    };
+
+    /// Set how the gradients are dithered when rendering into a buffer with less than 8 bits per
+    /// color component, such as Rgb565Pixel. Dithering hides the banding of gradients.
+    void set_dithering(Dithering dithering)
+    {
+        cbindgen_private::slint_software_renderer_set_dithering(inner,
+                                                                 static_cast<int>(dithering));
+    }
+
+    /// This enum describes how the scene is scaled when rendered into the buffer.
+    /// To be used in set_render_scale()
+    enum class RenderScale {
+        /// The scene is rendered at the resolution of the buffer
+        Native = 0,
+        /// The scene is rendered at a multiple of the buffer's resolution and then downsampled
+        Supersample = 1,
+        /// The scene is rendered at a fraction of the buffer's resolution and each pixel is then
+        /// enlarged to a square block
+        PixelArt = 2,
+    };
+
+    /// Set how the scene is scaled when rendered into the buffer, in addition to the window's
+    /// scale factor. The `factor` is the multiple (for RenderScale::Supersample) or the size of
+    /// the pixel blocks (for RenderScale::PixelArt). The whole window is redrawn on every frame
+    /// when not using RenderScale::Native.
+    void set_render_scale(RenderScale mode, uint8_t factor)
+    {
+        cbindgen_private::slint_software_renderer_set_render_scale(inner, static_cast<int>(mode),
+                                                                    factor);
+    }
+
+    /// Make the output of the renderer independent of the platform, for golden-image tests.
+    /// Text is rendered with grayscale anti-aliasing, the temporal dithering uses a fixed pattern
+    /// and the scale factor is rounded to a multiple of 1/64. Set the
+    /// `SLINT_DETERMINISTIC_RENDERING` environment variable to also use the bundled font instead
+    /// of the fonts of the system.
+    void set_deterministic_rendering(bool enabled)
+    {
+        cbindgen_private::slint_software_renderer_set_deterministic_rendering(inner, enabled);
+    }
 };
 #endif
 
@@ -0,0 +1,106 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+#pragma once
+
+#include "slint.h"
+
+#if !defined(SLINT_FEATURE_BACKEND_QT) && !defined(DOXYGEN)
+#    error "slint-qt.h requires Slint to be built with the Qt backend (SLINT_FEATURE_BACKEND_QT)"
+#endif
+
+#include <memory>
+#include <QtWidgets/QWidget>
+#include <QtWidgets/QVBoxLayout>
+
+/// The types in this namespace help migrating an application from Qt Widgets to Slint
+/// incrementally, by showing Slint components inside of an existing QWidget hierarchy and by
+/// showing existing QWidgets inside of Slint windows.
+///
+/// They require the Qt backend to be used at run-time.
+namespace slint::qt {
+
+/// Returns the QWidget that shows the \a window, or nullptr if the Qt backend isn't used.
+inline QWidget *qwidget(const slint::Window &window)
+{
+    return reinterpret_cast<QWidget *>(cbindgen_private::slint_qt_get_widget(
+            reinterpret_cast<const cbindgen_private::WindowAdapterRc *>(
+                    &window.window_handle().handle())));
+}
+
+/// Shows the \a widget as a child of the \a window, over the area of the window at \a position
+/// with the given \a size, in logical coordinates. Call it again to move the widget, for example
+/// from the changed callback of the geometry of a placeholder element in the .slint file.
+///
+/// The window takes the ownership of the widget, like a QWidget parent does.
+inline void embed_qwidget(const slint::Window &window, QWidget *widget, LogicalPosition position,
+                          LogicalSize size)
+{
+    cbindgen_private::LogicalRect geometry { .x = position.x,
+                                             .y = position.y,
+                                             .width = size.width,
+                                             .height = size.height };
+    cbindgen_private::slint_qt_embed_widget(
+            reinterpret_cast<const cbindgen_private::WindowAdapterRc *>(
+                    &window.window_handle().handle()),
+            widget, &geometry);
+}
+
+/// A QWidget that shows a Slint component, to add it to the layout of an existing QWidget.
+///
+/// The size of the QSlintWidget follows the layout constraints of the component, and the
+/// component is kept alive as long as the QSlintWidget exists.
+///
+/// ```cpp
+/// auto ui = AppWindow::create();
+/// auto slint_widget = new slint::qt::QSlintWidget(ui);
+/// main_window->setCentralWidget(slint_widget);
+/// ```
+class QSlintWidget : public QWidget
+{
+public:
+    /// Creates a QSlintWidget that shows the \a component, as a child of \a parent.
+    template<typename T>
+    explicit QSlintWidget(const ComponentHandle<T> &component, QWidget *parent = nullptr)
+        : QWidget(parent), m_window(&component->window())
+    {
+        // Keep the component alive, as the window is owned by it
+        m_component = std::make_shared<ComponentHandle<T>>(component);
+
+        auto layout = new QVBoxLayout(this);
+        layout->setContentsMargins(0, 0, 0, 0);
+        if (QWidget *slint_widget = qwidget(*m_window)) {
+            set_parent_widget(this);
+            layout->addWidget(slint_widget);
+        }
+        m_window->window_handle().show();
+    }
+
+    /// Hides the component. The component is destroyed if no other handle refers to it.
+    ~QSlintWidget() override
+    {
+        m_window->window_handle().hide();
+        // The widget of the window is owned by Slint, don't let QWidget delete it
+        set_parent_widget(nullptr);
+    }
+
+    QSlintWidget(const QSlintWidget &) = delete;
+    QSlintWidget &operator=(const QSlintWidget &) = delete;
+
+    /// Returns the window of the component shown by this widget.
+    const slint::Window &window() const { return *m_window; }
+
+private:
+    void set_parent_widget(QWidget *parent)
+    {
+        cbindgen_private::slint_qt_set_parent_widget(
+                reinterpret_cast<const cbindgen_private::WindowAdapterRc *>(
+                        &m_window->window_handle().handle()),
+                parent);
+    }
+
+    std::shared_ptr<void> m_component;
+    const slint::Window *m_window;
+};
+
+}
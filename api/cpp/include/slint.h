@@ -103,8 +103,23 @@ inline bool operator==(const EasingCurve &a, const EasingCurve &b)
 }
 }
 
+/// The easing curve of an animation, used by the generated `set_<property>_animated` functions.
+/// A default constructed `EasingCurve` is the linear curve.
+using cbindgen_private::EasingCurve;
+
 namespace private_api {
 
+/// Returns the animation used by the generated `set_<property>_animated` functions
+inline cbindgen_private::PropertyAnimation
+make_property_animation(std::chrono::milliseconds duration, const EasingCurve &easing)
+{
+    cbindgen_private::PropertyAnimation animation {};
+    animation.duration = int32_t(duration.count());
+    animation.iteration_count = 1.;
+    animation.easing = easing;
+    return animation;
+}
+
 inline static void register_item_tree(const vtable::VRc<ItemTreeVTable> *c,
                                       const std::optional<slint::Window> &maybe_window)
 {
@@ -218,6 +233,83 @@ inline void setup_popup_menu_from_menu_item_tree(
             [shared](const auto &entry) { shared.vtable()->activate(shared.borrow(), &entry); });
 }
 
+inline std::shared_ptr<Model<int>> fuzzy_filter(const std::shared_ptr<Model<SharedString>> &model,
+                                                const SharedString &pattern)
+{
+    std::vector<SharedString> texts;
+    if (model) {
+        model->track_row_count_changes();
+        texts.reserve(model->row_count());
+        for (size_t row = 0; row < model->row_count(); ++row) {
+            texts.push_back(model->row_data_tracked(row).value_or(SharedString()));
+        }
+    }
+    SharedVector<int> indices;
+    cbindgen_private::slint_fuzzy_filter(make_slice(texts.data(), texts.size()), &pattern, &indices);
+    return std::make_shared<VectorModel<int>>(std::vector<int>(indices.begin(), indices.end()));
+}
+
+inline std::shared_ptr<Model<SharedString>> process_arguments()
+{
+    SharedVector<SharedString> arguments;
+    cbindgen_private::slint_process_arguments(&arguments);
+    return std::make_shared<VectorModel<SharedString>>(
+            std::vector<SharedString>(arguments.begin(), arguments.end()));
+}
+
+inline SharedString
+table_cells_to_tsv(const std::shared_ptr<Model<std::shared_ptr<Model<StandardListViewItem>>>> &rows,
+                   int start_row, int start_column, int end_row, int end_column)
+{
+    SharedString out;
+    if (!rows) {
+        return out;
+    }
+    // The number of columns of the selected rows, which are clamped by slint_table_cell_range
+    size_t row_count = rows->row_count();
+    size_t column_count = 0;
+    for (int row = std::max(0, std::min(start_row, end_row));
+         row <= std::max(start_row, end_row) && size_t(row) < row_count; ++row) {
+        if (auto row_data = rows->row_data(size_t(row)); row_data && *row_data) {
+            column_count = std::max(column_count, (*row_data)->row_count());
+        }
+    }
+    uintptr_t rows_start = 0, rows_end = 0, columns_start = 0, columns_end = 0;
+    cbindgen_private::slint_table_cell_range(row_count, column_count, start_row, start_column,
+                                             end_row, end_column, &rows_start, &rows_end,
+                                             &columns_start, &columns_end);
+    std::vector<SharedString> cells;
+    for (size_t row = rows_start; row < rows_end; ++row) {
+        auto row_data = rows->row_data(row);
+        for (size_t column = columns_start; column < columns_end; ++column) {
+            std::optional<StandardListViewItem> cell;
+            if (row_data && *row_data) {
+                cell = (*row_data)->row_data(column);
+            }
+            cells.push_back(cell ? cell->text : SharedString());
+        }
+    }
+    cbindgen_private::slint_cells_to_tsv(make_slice(cells.data(), cells.size()),
+                                         columns_end - columns_start, &out);
+    return out;
+}
+
+inline SharedString color_to_hex(const Color &color)
+{
+    SharedString out;
+    cbindgen_private::slint_color_to_hex_string(color.as_argb_encoded(), &out);
+    return out;
+}
+
+inline Color parse_color_hex(const SharedString &str, const Color &fallback)
+{
+    uint32_t argb = 0;
+    if (cbindgen_private::slint_color_from_hex_string(&str, &argb)) {
+        return Color::from_argb_encoded(argb);
+    }
+    return fallback;
+}
+
 inline SharedString translate(const SharedString &original, const SharedString &context,
                               const SharedString &domain,
                               cbindgen_private::Slice<SharedString> arguments, int n,
@@ -48,6 +48,34 @@ struct HsvaColor
     float alpha;
 };
 
+/// HslaColor stores the hue, saturation, lightness, and alpha components of a color in the HSL
+/// color space.
+struct HslaColor
+{
+    /// The hue component in degrees between 0 and 360.
+    float hue;
+    /// The saturation component, between 0 and 1.
+    float saturation;
+    /// The lightness component, between 0 and 1.
+    float lightness;
+    /// The alpha component, between 0 and 1.
+    float alpha;
+};
+
+/// OklchColor stores the lightness, chroma, hue, and alpha components of a color in the perceptual
+/// OKLCH color space.
+struct OklchColor
+{
+    /// The perceived lightness, between 0 and 1.
+    float lightness;
+    /// The chroma component, from 0 (gray) to about 0.4.
+    float chroma;
+    /// The hue component in degrees between 0 and 360.
+    float hue;
+    /// The alpha component, between 0 and 1.
+    float alpha;
+};
+
 /// Color represents a color in the Slint run-time, represented using 8-bit channels for
 /// red, green, blue and the alpha (opacity).
 class Color
@@ -153,6 +181,46 @@ public:
         return hsv;
     }
 
+    /// Construct a color from the HSL color space components.
+    /// The hue is expected to be in the range between 0 and 360, and the other parameters between 0
+    /// and 1.
+    [[nodiscard]] static Color from_hsl(float h, float s, float l, float a)
+    {
+        Color ret;
+        ret.inner = cbindgen_private::types::slint_color_from_hsl(h, s, l, a);
+        return ret;
+    }
+
+    /// Convert this color to the HSL color space.
+    /// @returns a new HslaColor.
+    [[nodiscard]] HslaColor to_hsl() const
+    {
+        HslaColor hsl {};
+        cbindgen_private::types::slint_color_to_hsl(&inner, &hsl.hue, &hsl.saturation,
+                                                    &hsl.lightness, &hsl.alpha);
+        return hsl;
+    }
+
+    /// Construct a color from the OKLCH color space components.
+    /// The lightness and alpha are expected to be between 0 and 1, the chroma is usually between 0
+    /// and 0.4, and the hue is between 0 and 360. Colors outside of the sRGB gamut are clipped.
+    [[nodiscard]] static Color from_oklch(float l, float c, float h, float a)
+    {
+        Color ret;
+        ret.inner = cbindgen_private::types::slint_color_from_oklch(l, c, h, a);
+        return ret;
+    }
+
+    /// Convert this color to the OKLCH color space.
+    /// @returns a new OklchColor.
+    [[nodiscard]] OklchColor to_oklch() const
+    {
+        OklchColor oklch {};
+        cbindgen_private::types::slint_color_to_oklch(&inner, &oklch.lightness, &oklch.chroma,
+                                                      &oklch.hue, &oklch.alpha);
+        return oklch;
+    }
+
     /// Returns the red channel of the color as u8 in the range 0..255.
     [[nodiscard]] uint8_t red() const { return inner.red; }
 
@@ -189,6 +257,11 @@ public:
     /// factor` is applied to \a other.
     [[nodiscard]] inline Color mix(const Color &other, float factor) const;
 
+    /// Like mix(), but interpolates in the color space \a color_space. The hue of the HSL and
+    /// OKLCH color spaces takes the shorter arc.
+    [[nodiscard]] inline Color mix_in(const Color &other, float factor,
+                                      ColorSpace color_space) const;
+
     /// Returns a new version of this color with the opacity set to \a alpha.
     [[nodiscard]] inline Color with_alpha(float alpha) const;
 
@@ -245,6 +318,14 @@ inline Color Color::mix(const Color &other, float factor) const
     return result;
 }
 
+inline Color Color::mix_in(const Color &other, float factor, ColorSpace color_space) const
+{
+    Color result;
+    cbindgen_private::types::slint_color_mix_in(&inner, &other.inner, factor, color_space,
+                                                &result.inner);
+    return result;
+}
+
 inline Color Color::with_alpha(float alpha) const
 {
     Color result;
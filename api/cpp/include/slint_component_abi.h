@@ -0,0 +1,152 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+// This header describes the entry points of a dynamic library built from Slint code compiled
+// with the C ABI mode (`slint-compiler --c-abi`, or `CompilerConfiguration::with_c_abi(true)`
+// in slint-build). It is plain C and doesn't depend on the rest of the Slint headers, so that a
+// host can load libraries built with a different version of Slint.
+//
+// Load the library with dlopen()/LoadLibrary(), resolve the symbols below, and check that
+// slint_component_abi_version() returns SLINT_COMPONENT_ABI_VERSION before calling anything else.
+//
+// slint_component_metadata() returns a JSON document describing the components:
+//
+//     {"abi_version":2,"components":[{"name":"MainWindow",
+//         "properties":[{"name":"counter","type":"number","read_only":false}],
+//         "callbacks":[{"name":"clicked","args":["string"],"return":"void"}],
+//         "functions":[]}]}
+//
+// Types are "void", "number", "bool", "string", or "color". Properties, callbacks, and functions
+// using other types, as well as components with required properties, aren't exposed.
+// Names are the names from the .slint file.
+//
+// All functions must be called from the thread that runs the event loop.
+
+#pragma once
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define SLINT_COMPONENT_ABI_VERSION 2
+
+typedef enum {
+    SLINT_ABI_VALUE_VOID = 0,
+    SLINT_ABI_VALUE_NUMBER = 1,
+    /// `number` is non-zero for true
+    SLINT_ABI_VALUE_BOOL = 2,
+    SLINT_ABI_VALUE_STRING = 3,
+    SLINT_ABI_VALUE_COLOR = 4,
+} SlintAbiValueKind;
+
+/// A value passed to or returned by the library.
+///
+/// Strings returned by the library are UTF-8, zero terminated, and must be released with
+/// slint_component_value_free(). Strings passed by the host must be UTF-8 and stay owned by the
+/// host; a string returned from a callback handler must stay valid until the handler is called
+/// again or the callback is reset.
+typedef struct {
+    uint32_t kind;
+    /// The color as 0xAARRGGBB
+    uint32_t color;
+    double number;
+    const char *string;
+    /// Length of `string` in bytes, without the terminating zero
+    size_t string_len;
+} SlintAbiValue;
+
+typedef enum {
+    SLINT_ABI_POINTER_MOVED = 0,
+    SLINT_ABI_POINTER_PRESSED = 1,
+    SLINT_ABI_POINTER_RELEASED = 2,
+    SLINT_ABI_POINTER_EXITED = 3,
+    SLINT_ABI_POINTER_SCROLLED = 4,
+} SlintAbiPointerEventKind;
+
+typedef enum {
+    SLINT_ABI_POINTER_BUTTON_OTHER = 0,
+    SLINT_ABI_POINTER_BUTTON_LEFT = 1,
+    SLINT_ABI_POINTER_BUTTON_RIGHT = 2,
+    SLINT_ABI_POINTER_BUTTON_MIDDLE = 3,
+} SlintAbiPointerButton;
+
+/// Opaque handle to a component instance
+typedef void *SlintComponentHandle;
+
+/// Handler for a callback. `result` is initialized to a void value; write the return value into it.
+typedef void (*SlintAbiCallbackHandler)(void *user_data, const SlintAbiValue *args,
+                                        size_t arg_count, SlintAbiValue *result);
+
+uint32_t slint_component_abi_version(void);
+
+/// Returns the JSON metadata. The string is static.
+const char *slint_component_metadata(void);
+
+/// Creates an instance of the component with the given name, or returns null on error.
+SlintComponentHandle slint_component_create(const char *name);
+void slint_component_destroy(SlintComponentHandle handle);
+
+/// Shows or hides the window of the component.
+bool slint_component_show(SlintComponentHandle handle, bool visible);
+
+/// Runs the event loop until the last window is closed.
+bool slint_component_run_event_loop(void);
+
+/// Embeds the components into the host: their windows aren't shown on the screen, but rendered
+/// with slint_component_render(), and the host forwards the input events. Must be called before
+/// any component is created. Returns false if the library can't be embedded, for example because
+/// it was built without the software renderer.
+bool slint_component_set_embedded(void);
+
+/// Runs the timers and animations of the library. Embedding hosts must call this regularly,
+/// for example before rendering each frame.
+void slint_component_update_timers_and_animations(void);
+
+/// Resizes the window of an embedded component to `width` x `height` physical pixels and renders
+/// it into `buffer`, which holds `width * height` RGBA pixels with 8 bits per channel and without
+/// padding between rows. The alpha channel is not premultiplied.
+bool slint_component_render(SlintComponentHandle handle, uint32_t width, uint32_t height,
+                            float scale_factor, uint8_t *buffer);
+
+/// Dispatches a pointer event to an embedded component. The position is in logical pixels,
+/// `button` is only used for presses and releases, and the deltas only for scrolling.
+bool slint_component_dispatch_pointer_event(SlintComponentHandle handle,
+                                            SlintAbiPointerEventKind kind, float x, float y,
+                                            SlintAbiPointerButton button, float delta_x,
+                                            float delta_y);
+
+/// Dispatches a key press or release to an embedded component. `text` is the UTF-8 text of the
+/// key, as in slint::platform::WindowEvent::KeyPressed.
+bool slint_component_dispatch_key_event(SlintComponentHandle handle, bool pressed,
+                                        const char *text);
+
+/// Returns false if there is no such property. On success, release `out` with slint_component_value_free().
+bool slint_component_get_property(SlintComponentHandle handle, const char *name,
+                                  SlintAbiValue *out);
+
+/// Returns false if there is no such property, if it's read-only, or if the value has the wrong kind.
+bool slint_component_set_property(SlintComponentHandle handle, const char *name,
+                                  const SlintAbiValue *value);
+
+/// Invokes a callback or a function. Returns false if there is no such callback or function, or
+/// if the arguments have the wrong kind. `out` may be null; otherwise release it with
+/// slint_component_value_free().
+bool slint_component_invoke(SlintComponentHandle handle, const char *name,
+                            const SlintAbiValue *args, size_t arg_count, SlintAbiValue *out);
+
+/// Sets the handler of a callback. `drop_user_data` (which may be null) is called with
+/// `user_data` when the handler is replaced or the component is destroyed, or right away if
+/// this function returns false.
+bool slint_component_set_callback(SlintComponentHandle handle, const char *name,
+                                  SlintAbiCallbackHandler handler, void *user_data,
+                                  void (*drop_user_data)(void *));
+
+void slint_component_value_free(SlintAbiValue *value);
+
+#ifdef __cplusplus
+}
+#endif
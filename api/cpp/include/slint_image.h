@@ -171,6 +171,67 @@ public:
         );
     }
 
+    /// Constructs a new Image from a single plane Linux DMA-BUF, such as a frame decoded by a
+    /// video player or captured by a camera. The renderer imports the buffer without copying the
+    /// pixels. This is supported by the Skia renderer when rendering with OpenGL via EGL.
+    ///
+    /// `fourcc` is the DRM format code of the pixels, and `modifier` the DRM format modifier
+    /// describing their layout in memory. `offset` is the offset of the first pixel in bytes, and
+    /// `stride` the number of bytes per row.
+    ///
+    /// Safety:
+    ///
+    /// The file descriptor must refer to a DMA-BUF holding an image of the given size and format,
+    /// and must remain open for as long as the image is in use. Slint does not take ownership of
+    /// the file descriptor.
+    [[nodiscard]] static Image create_from_borrowed_dma_buf(int32_t fd, uint32_t fourcc,
+                                                            uint64_t modifier, uint32_t offset,
+                                                            uint32_t stride, Size<uint32_t> size)
+    {
+        return Image(Data::ImageInner_BorrowedExternalTexture(
+                cbindgen_private::types::BorrowedExternalTexture {
+                        cbindgen_private::types::ExternalTextureHandle::DmaBuf(fd, fourcc, modifier,
+                                                                               offset, stride),
+                        size,
+                }));
+    }
+
+    /// Constructs a new Image from an `IOSurfaceRef` with the `BGRA` pixel format, for example the
+    /// one backing a `CVPixelBuffer`. The renderer imports the surface without copying the pixels.
+    /// This is supported by the Skia renderer when rendering with Metal.
+    ///
+    /// Safety:
+    ///
+    /// The application must keep a reference to the surface for as long as the image is in use.
+    [[nodiscard]] static Image create_from_borrowed_io_surface(void *io_surface,
+                                                               Size<uint32_t> size)
+    {
+        return Image(Data::ImageInner_BorrowedExternalTexture(
+                cbindgen_private::types::BorrowedExternalTexture {
+                        cbindgen_private::types::ExternalTextureHandle::IOSurface(io_surface),
+                        size,
+                }));
+    }
+
+    /// Constructs a new Image from a Direct3D 11 or 12 texture shared through an NT handle, with
+    /// the `DXGI_FORMAT_R8G8B8A8_UNORM` or `DXGI_FORMAT_B8G8R8A8_UNORM` format. The renderer
+    /// imports the texture without copying the pixels. This is supported by the Skia renderer when
+    /// rendering with Direct3D.
+    ///
+    /// Safety:
+    ///
+    /// The handle must remain open for as long as the image is in use, and the texture must be
+    /// created on the same adapter that Slint renders with.
+    [[nodiscard]] static Image create_from_borrowed_d3d_shared_handle(void *handle,
+                                                                      Size<uint32_t> size)
+    {
+        return Image(Data::ImageInner_BorrowedExternalTexture(
+                cbindgen_private::types::BorrowedExternalTexture {
+                        cbindgen_private::types::ExternalTextureHandle::D3DSharedHandle(handle),
+                        size,
+                }));
+    }
+
     /// Construct an image from a SharedPixelBuffer of RGB pixels.
     Image(SharedPixelBuffer<Rgb8Pixel> buffer)
         : data(Data::ImageInner_EmbeddedImage(
@@ -6,6 +6,7 @@
 #pragma once
 
 #include <chrono>
+#include <optional>
 #include <slint_timer_internal.h>
 
 #ifndef SLINT_FEATURE_FREESTANDING
@@ -106,6 +107,38 @@ struct Timer
         private_api::assert_main_thread();
         return cbindgen_private::slint_timer_running(id);
     }
+    /// Pauses the timer. The time that remains until the timer fires is kept, and the timer
+    /// continues from there when resume() is called.
+    /// Does nothing if the timer is not running.
+    void pause() const
+    {
+        private_api::assert_main_thread();
+        cbindgen_private::slint_timer_pause(id);
+    }
+    /// Resumes a timer that was paused with pause().
+    /// Does nothing if the timer is not paused.
+    void resume() const
+    {
+        private_api::assert_main_thread();
+        cbindgen_private::slint_timer_resume(id);
+    }
+    /// Returns true if the timer was paused and not resumed yet; false otherwise.
+    bool paused() const
+    {
+        private_api::assert_main_thread();
+        return cbindgen_private::slint_timer_paused(id);
+    }
+    /// Returns the time that remains until the timer fires.
+    /// Returns an empty optional if the timer is neither running nor paused.
+    std::optional<std::chrono::milliseconds> remaining() const
+    {
+        private_api::assert_main_thread();
+        auto remaining = cbindgen_private::slint_timer_remaining(id);
+        if (remaining < 0) {
+            return std::nullopt;
+        }
+        return std::chrono::milliseconds(remaining);
+    }
     /// Returns the interval of the timer.
     /// Returns 0 if the timer was never started.
     std::chrono::milliseconds interval() const
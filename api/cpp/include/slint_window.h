@@ -62,6 +62,13 @@ public:
         slint_windowrc_set_text_input_focused(&inner, value);
     }
 
+    void set_clipboard_text(const SharedString &text) const
+    {
+        slint_windowrc_set_clipboard_text(&inner, &text);
+    }
+
+    void open_url(const SharedString &url) const { slint_windowrc_open_url(&inner, &url); }
+
     template<typename Component, typename ItemArray>
     void unregister_item_tree(Component *c, ItemArray items) const
     {
@@ -710,6 +710,58 @@ extern "C" fn cpp_render_line_callback<
         });
     }
 
+    #[unsafe(no_mangle)]
+    pub unsafe extern "C" fn slint_software_renderer_set_text_subpixel_layout(
+        r: SoftwareRendererOpaque,
+        layout: i32,
+    ) {
+        use i_slint_core::software_renderer::SubpixelLayout;
+        let renderer = &*(r as *const SoftwareRenderer);
+        renderer.set_text_subpixel_layout(match layout {
+            1 => SubpixelLayout::Rgb,
+            2 => SubpixelLayout::Bgr,
+            _ => SubpixelLayout::None,
+        });
+    }
+
+    #[unsafe(no_mangle)]
+    pub unsafe extern "C" fn slint_software_renderer_set_dithering(
+        r: SoftwareRendererOpaque,
+        dithering: i32,
+    ) {
+        use i_slint_core::software_renderer::Dithering;
+        let renderer = &*(r as *const SoftwareRenderer);
+        renderer.set_dithering(match dithering {
+            1 => Dithering::Ordered,
+            2 => Dithering::Temporal,
+            _ => Dithering::None,
+        });
+    }
+
+    #[unsafe(no_mangle)]
+    pub unsafe extern "C" fn slint_software_renderer_set_render_scale(
+        r: SoftwareRendererOpaque,
+        mode: i32,
+        factor: u8,
+    ) {
+        use i_slint_core::software_renderer::RenderScale;
+        let renderer = &*(r as *const SoftwareRenderer);
+        renderer.set_render_scale(match mode {
+            1 => RenderScale::Supersample(factor),
+            2 => RenderScale::PixelArt(factor),
+            _ => RenderScale::Native,
+        });
+    }
+
+    #[unsafe(no_mangle)]
+    pub unsafe extern "C" fn slint_software_renderer_set_deterministic_rendering(
+        r: SoftwareRendererOpaque,
+        enabled: bool,
+    ) {
+        let renderer = &*(r as *const SoftwareRenderer);
+        renderer.set_deterministic_rendering(enabled);
+    }
+
     #[unsafe(no_mangle)]
     pub unsafe extern "C" fn slint_software_renderer_handle(
         r: SoftwareRendererOpaque,
@@ -137,6 +137,42 @@ TEST_CASE("C++ Restart Repeated Timer")
     REQUIRE(timer_was_running);
 }
 
+TEST_CASE("C++ Pause and Resume Timer")
+{
+    int timer_triggered = 0;
+    slint::Timer timer;
+    REQUIRE(!timer.remaining().has_value());
+
+    timer.start(slint::TimerMode::Repeated, std::chrono::milliseconds(200),
+                [&]() { timer_triggered++; });
+    REQUIRE(timer.remaining().has_value());
+    REQUIRE(*timer.remaining() <= std::chrono::milliseconds(200));
+
+    timer.pause();
+    REQUIRE(timer.paused());
+    REQUIRE(!timer.running());
+    auto remaining = timer.remaining();
+    REQUIRE(remaining.has_value());
+
+    slint::Timer::single_shot(std::chrono::milliseconds(500), [&]() { slint::quit_event_loop(); });
+    slint::run_event_loop();
+
+    REQUIRE(timer_triggered == 0);
+    REQUIRE(timer.remaining() == remaining);
+
+    timer.resume();
+    REQUIRE(!timer.paused());
+    REQUIRE(timer.running());
+
+    slint::Timer::single_shot(std::chrono::milliseconds(500), [&]() { slint::quit_event_loop(); });
+    slint::run_event_loop();
+
+    REQUIRE(timer_triggered > 0);
+
+    timer.stop();
+    REQUIRE(!timer.remaining().has_value());
+}
+
 TEST_CASE("Quit from event")
 {
     int called = 0;
@@ -288,6 +288,7 @@ test("get/set brush properties", (t) => {
     in-out property <brush> ref: transparent;
     in-out property <brush> linear-gradient: @linear-gradient(90deg, #3f87a6 0%, #ebf8e1 50%, #f69d3c 100%);
     in-out property <brush> radial-gradient: @radial-gradient(circle, #f00 0%, #0f0 50%, #00f 100%);
+    in-out property <brush> conic-gradient: @conic-gradient(#f00 0deg, #0f0 180deg, #00f 360deg);
     in-out property <color> ref-color;
   }
   `,
@@ -395,6 +396,15 @@ test("get/set brush properties", (t) => {
         );
     }
 
+    const conicGradient = instance!.getProperty("conic-gradient");
+
+    if (t.true(conicGradient instanceof private_api.SlintBrush)) {
+        t.is(
+            (conicGradient as private_api.SlintBrush).toString(),
+            "conic-gradient(rgba(255, 0, 0, 255) 0deg, rgba(0, 255, 0, 255) 180deg, rgba(0, 0, 255, 255) 360deg)",
+        );
+    }
+
     const linearGradient = instance!.getProperty("linear-gradient");
 
     if (t.true(linearGradient instanceof private_api.SlintBrush)) {
@@ -302,27 +302,41 @@ pub fn to_string(&self) -> String {
                 format!(
                     "linear-gradient({}deg, {})",
                     gradient.angle(),
-                    gradient_stops_to_string(gradient.stops())
+                    gradient_stops_to_string(gradient.stops(), 100., "%")
                 )
             }
             Brush::RadialGradient(gradient) => {
-                format!("radial-gradient(circle, {})", gradient_stops_to_string(gradient.stops()))
+                format!(
+                    "radial-gradient(circle, {})",
+                    gradient_stops_to_string(gradient.stops(), 100., "%")
+                )
+            }
+            Brush::ConicGradient(gradient) => {
+                format!(
+                    "conic-gradient({})",
+                    gradient_stops_to_string(gradient.stops(), 360., "deg")
+                )
             }
             _ => String::default(),
         }
     }
 }
 
-fn gradient_stops_to_string<'a>(stops: impl Iterator<Item = &'a GradientStop>) -> String {
+/// Formats the stops, with their position scaled by `position_scale` and followed by `position_unit`
+fn gradient_stops_to_string<'a>(
+    stops: impl Iterator<Item = &'a GradientStop>,
+    position_scale: f32,
+    position_unit: &str,
+) -> String {
     let stops: Vec<String> = stops
         .map(|s| {
             format!(
-                "rgba({}, {}, {}, {}) {}%",
+                "rgba({}, {}, {}, {}) {}{position_unit}",
                 s.color.red(),
                 s.color.green(),
                 s.color.blue(),
                 s.color.alpha(),
-                s.position * 100.
+                s.position * position_scale
             )
         })
         .collect();
@@ -240,6 +240,11 @@ fn properties(&self) -> IndexMap<String, PyValueType> {
             .collect()
     }
 
+    #[getter]
+    fn required_properties(&self) -> Vec<String> {
+        self.definition.required_properties().collect()
+    }
+
     #[getter]
     fn callbacks(&self) -> Vec<String> {
         self.definition.callbacks().collect()
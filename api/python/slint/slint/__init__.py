@@ -142,6 +142,15 @@ def _build_class(
     compdef: native.ComponentDefinition,
 ) -> typing.Callable[..., Component]:
     def cls_init(self: Component, **kwargs: Any) -> Any:
+        missing = [
+            _normalize_prop(name)
+            for name in compdef.required_properties
+            if _normalize_prop(name) not in kwargs
+        ]
+        if missing:
+            raise TypeError(
+                f"{compdef.name}() missing required properties: {', '.join(missing)}"
+            )
         self.__instance__ = compdef.create()
         for name, value in self.__class__.__dict__.items():
             if hasattr(value, "slint.callback"):
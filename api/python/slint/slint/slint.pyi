@@ -210,6 +210,7 @@ class ComponentDefinition:
     functions: list[str]
     callbacks: list[str]
     properties: dict[str, ValueType]
+    required_properties: list[str]
     def global_functions(self, global_name: str) -> list[str]: ...
     def global_callbacks(self, global_name: str) -> list[str]: ...
     def global_properties(self, global_name: str) -> typing.Dict[str, ValueType]: ...
@@ -46,6 +46,16 @@ def test_load_file(caplog: pytest.LogCaptureFixture) -> None:
     assert module.MyDiag is module.Diag
 
 
+def test_required_properties() -> None:
+    module = load_file(base_dir() / "test-required-properties.slint")
+
+    with pytest.raises(TypeError, match="missing required properties: user_name"):
+        module.Greeter()
+
+    instance = module.Greeter(user_name="Alice")
+    assert instance.greeting == "Hello Alice"
+
+
 def test_load_file_fail() -> None:
     with pytest.raises(CompileError, match="Could not compile non-existent.slint"):
         load_file("non-existent.slint")
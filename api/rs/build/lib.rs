@@ -58,6 +58,7 @@ fn main() {
 use std::path::Path;
 
 use i_slint_compiler::diagnostics::BuildDiagnostics;
+use i_slint_compiler::project_config::ProjectConfiguration;
 
 /// The structure for configuring aspects of the compilation of `.slint` markup files to Rust.
 #[derive(Clone)]
@@ -138,6 +139,25 @@ pub fn with_library_paths(self, library_paths: HashMap<String, std::path::PathBu
         Self { config }
     }
 
+    /// Create a new configuration that compiles additional palettes for the widgets, that are
+    /// selected at run-time with the `brand` property of the `Palette` global.
+    ///
+    /// Each brand palette is a pair of the name of the brand, which becomes a value of the `Brand`
+    /// enum, and the path to a `.slint` file that exports a `Palette` global with constant values
+    /// for the properties that differ from the style.
+    ///
+    /// ```rust,no_run
+    /// let config = slint_build::CompilerConfiguration::new()
+    ///     .with_brand_palettes(vec![("acme".into(), "ui/palettes/acme.slint".into())]);
+    /// slint_build::compile_with_config("ui/main.slint", config).unwrap();
+    /// ```
+    #[must_use]
+    pub fn with_brand_palettes(self, brand_palettes: Vec<(String, std::path::PathBuf)>) -> Self {
+        let mut config = self.config;
+        config.brand_palettes = brand_palettes;
+        Self { config }
+    }
+
     /// Create a new configuration that selects the style to be used for widgets.
     #[must_use]
     pub fn with_style(self, style: String) -> Self {
@@ -231,6 +251,21 @@ pub fn rust_module(self, rust_module: &str) -> Self {
         config.rust_module = Some(rust_module.to_string());
         Self { config }
     }
+
+    /// Configures the compiler to generate `extern "C"` entry points for the exported components.
+    ///
+    /// Build the crate as a `cdylib` to get a dynamic library with a stable C ABI that a host
+    /// application can load at runtime to create the components, access their properties,
+    /// and invoke their callbacks and functions. The entry points and the metadata format are
+    /// described in `slint_component_abi.h`.
+    ///
+    /// **Note**: This feature is experimental and may change or be removed in the future.
+    #[must_use]
+    pub fn with_c_abi(self, enable: bool) -> Self {
+        let mut config = self.config;
+        config.generate_c_abi = enable;
+        Self { config }
+    }
     /// Configures the compiler to use Signed Distance Field (SDF) encoding for fonts.
     ///
     /// This flag only takes effect when `embed_resources` is set to [`EmbedResourcesKind::EmbedForSoftwareRenderer`],
@@ -249,7 +284,22 @@ pub fn with_sdf_fonts(self, enable: bool) -> Self {
         Self { config }
     }
 
-    /// Converts any relative include_paths or library_paths to absolute paths relative to the manifest_dir.
+    /// Configures the compiler to compress the embedded images with a run-length encoding.
+    ///
+    /// This flag only takes effect when `embed_resources` is set to [`EmbedResourcesKind::EmbedForSoftwareRenderer`].
+    ///
+    /// The software renderer decodes the compressed images line by line while rendering, so they stay in
+    /// flash memory and no additional RAM is needed for the decoded image. Images with large areas of the
+    /// same color, such as backgrounds, compress well. An image is only compressed if that makes it smaller.
+    /// Rendering compressed images is slower, especially when the rendering is rotated.
+    #[must_use]
+    pub fn with_compressed_textures(self, enable: bool) -> Self {
+        let mut config = self.config;
+        config.compress_embedded_textures = enable;
+        Self { config }
+    }
+
+    /// Converts any relative include_paths, library_paths, or brand_palettes to absolute paths relative to the manifest_dir.
     #[must_use]
     fn with_absolute_paths(self, manifest_dir: &std::path::Path) -> Self {
         let mut config = self.config;
@@ -268,6 +318,10 @@ fn with_absolute_paths(self, manifest_dir: &std::path::Path) -> Self {
             to_absolute_path(path);
         }
 
+        for (_, path) in config.brand_palettes.iter_mut() {
+            to_absolute_path(path);
+        }
+
         Self { config }
     }
 }
@@ -535,15 +589,31 @@ pub fn compile_with_output_path(
     let mut diag = BuildDiagnostics::default();
     let syntax_node = i_slint_compiler::parser::parse_file(&input_slint_file_path, &mut diag);
 
+    let mut compiler_config = config.config;
+    compiler_config.translation_domain = std::env::var("CARGO_PKG_NAME").ok();
+
+    let project_config_path =
+        match ProjectConfiguration::find_and_load(input_slint_file_path.as_ref()) {
+            Ok(project_config) => project_config.map(|project_config| {
+                // The SLINT_STYLE environment variable has precedence over the style of the project
+                if compiler_config.style.is_none() {
+                    compiler_config.style = std::env::var("SLINT_STYLE").ok();
+                }
+                project_config.apply(&mut compiler_config);
+                project_config.path
+            }),
+            Err(err) => {
+                diag.push_compiler_error(err);
+                None
+            }
+        };
+
     if diag.has_errors() {
         let vec = diag.to_string_vec();
         diag.print();
         return Err(CompileError::CompileError(vec));
     }
 
-    let mut compiler_config = config.config;
-    compiler_config.translation_domain = std::env::var("CARGO_PKG_NAME").ok();
-
     let syntax_node = syntax_node.expect("diags contained no compilation errors");
 
     // 'spin_on' is ok here because the compiler in single threaded and does not block if there is no blocking future
@@ -581,6 +651,7 @@ pub fn compile_with_output_path(
 
     write!(code_formatter, "{generated}").map_err(CompileError::SaveError)?;
     dependencies.push(input_slint_file_path.as_ref().to_path_buf());
+    dependencies.extend(project_config_path);
 
     for resource in doc.embedded_file_resources.borrow().keys() {
         if !resource.starts_with("builtin:") {
@@ -77,6 +77,16 @@
 //!
 //!
 //! Note Slint does not require a specific build tool and can work with others, such as [xbuild](https://github.com/rust-mobile/xbuild).
+//!
+//! ## Splash Screen
+//!
+//! Since Android 12, the system shows a splash screen with the icon of the application when it starts, until the
+//! application renders its first frame. Customize it with the `windowSplashScreen*` attributes of the theme of the
+//! activity in `AndroidManifest.xml`. To hand off from the splash screen to the user interface sooner, create the
+//! component and call [`Window::prepare_first_frame()`](crate::Window::prepare_first_frame) before running the event
+//! loop: the layout is then already evaluated when Android provides the surface of the window. Use
+//! [`Window::first_frame_timings()`](crate::Window::first_frame_timings) or set the `SLINT_DEBUG_PERFORMANCE`
+//! environment variable to `startup` to measure the time to the first frame.
 
 /// Re-export of the android-activity crate.
 #[cfg(all(
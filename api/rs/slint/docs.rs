@@ -56,6 +56,32 @@ pub fn get_counter(&self) -> i32 {
         /// In this case, this is the setter that sets the value of the `counter` property
         /// declared in the `.slint` design markup.
         pub fn set_counter(&self, value: i32) {}
+        /// For the properties that can be animated, such as numbers, lengths, and colors, a setter
+        /// is generated that animates the property from its current value to the new value, over
+        /// the given duration and with the given easing curve.
+        pub fn set_counter_animated(
+            &self,
+            value: i32,
+            duration: core::time::Duration,
+            easing: crate::EasingCurve,
+        ) {
+        }
+        /// For each property declared at the root of the component, a function is generated that
+        /// registers a callback called with the new value when the value of the property changes.
+        /// The callback is called later in the event loop, not immediately when the property is set,
+        /// and it is called until the returned subscription is dropped.
+        /// ```ignore
+        ///     let sample = SampleComponent::new().unwrap();
+        ///     let _subscription = sample.on_counter_changed(|counter| {
+        ///         println!("The counter is now {counter}");
+        ///     });
+        /// ```
+        pub fn on_counter_changed(
+            &self,
+            f: impl FnMut(i32) + 'static,
+        ) -> crate::PropertySubscription {
+            unimplemented!()
+        }
         /// Returns the value of the `user_name` property declared in the `.slint` design markup.
         pub fn get_user_name(&self) -> crate::SharedString {
             unimplemented!()
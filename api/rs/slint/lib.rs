@@ -144,6 +144,10 @@ struct MyComponent { /*...*/ }
 For each top-level property
   - A setter [`fn set_<property_name>(&self, value: <PropertyType>)`](docs::generated_code::SampleComponent::set_counter)
   - A getter [`fn get_<property_name>(&self) -> <PropertyType>`](docs::generated_code::SampleComponent::get_counter)
+  - For properties that can be animated (numbers, lengths, angles, colors and brushes), a setter that animates
+    the property to the new value: [`fn set_<property_name>_animated(&self, value: <PropertyType>, duration: Duration, easing: EasingCurve)`](docs::generated_code::SampleComponent::set_counter_animated)
+  - A function to observe the changes of the property: [`fn on_<property_name>_changed(&self, callback: impl FnMut(<PropertyType>) + 'static) -> PropertySubscription`](docs::generated_code::SampleComponent::on_counter_changed).
+    The callback is called until the returned [`PropertySubscription`] is dropped.
 
 For each top-level callback
   - [`fn invoke_<callback_name>(&self)`](docs::generated_code::SampleComponent::invoke_hello): to invoke the callback
@@ -169,6 +173,8 @@ struct MyComponent { /*...*/ }
 thread to avoid blocking animations. Use the [`invoke_from_event_loop`] function to communicate from your worker thread to the UI thread.
 
 To run a function with a delay or with an interval use a [`Timer`].
+To coalesce rapid invocations of a callback, for example when searching as the user types, use a
+[`Debouncer`] or a [`CallbackThrottle`].
 
 To run an async function or a future, use [`spawn_local()`].
 
@@ -182,6 +188,8 @@ struct MyComponent { /*...*/ }
 For each property
   - A setter: `fn set_<property_name>(&self, value: <PropertyType>)`
   - A getter: `fn get_<property_name>(&self) -> <PropertyType>`
+  - For properties that can be animated, an animated setter: `fn set_<property_name>_animated(&self, value: <PropertyType>, duration: Duration, easing: EasingCurve)`
+  - A function to observe the changes of the property: `fn on_<property_name>_changed(&self, callback: impl FnMut(<PropertyType>) + 'static) -> PropertySubscription`
 
 For each callback
   - `fn invoke_<callback_name>(&self, <CallbackArgs>) -> <ReturnValue>` to invoke the callback
@@ -211,20 +219,32 @@ struct MyComponent { /*...*/ }
 
 pub use slint_macros::slint;
 
+pub use i_slint_core::animations::EasingCurve;
 pub use i_slint_core::api::*;
 #[doc(hidden)]
 #[deprecated(note = "Experimental type was made public by mistake")]
 pub use i_slint_core::component_factory::ComponentFactory;
 #[cfg(not(target_arch = "wasm32"))]
-pub use i_slint_core::graphics::{BorrowedOpenGLTextureBuilder, BorrowedOpenGLTextureOrigin};
+pub use i_slint_core::graphics::{
+    BorrowedExternalTextureBuilder, BorrowedOpenGLTextureBuilder, BorrowedOpenGLTextureOrigin,
+};
 // keep in sync with internal/interpreter/api.rs
 pub use i_slint_core::graphics::{
-    Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
+    Brush, Color, Gray8Pixel, HslaColor, Image, LoadImageError, OklchColor, Rgb565Pixel, Rgb8Pixel,
+    Rgba8Pixel, RgbaColor, SharedPixelBuffer,
 };
+#[cfg(feature = "std")]
+pub use i_slint_core::graphics::{PathBuilder, PathData};
+pub use i_slint_core::items::ColorSpace;
 pub use i_slint_core::model::{
-    FilterModel, MapModel, Model, ModelExt, ModelNotify, ModelPeer, ModelRc, ModelTracker,
-    ReverseModel, SortModel, StandardListViewItem, TableColumn, VecModel,
+    BiMapModel, FilterModel, MapModel, Model, ModelExt, ModelNotify, ModelObserver, ModelPeer,
+    ModelRc, ModelSubscription, ModelTracker, PageModel, PageProvider, PagedModel, PagedSource,
+    ReverseModel, RingBufferModel, SortModel, StandardListViewItem, TableColumn, VecModel,
+    VecModelBatch,
 };
+#[cfg(feature = "std")]
+pub use i_slint_core::model::{SharedModel, SharedModelSender};
+pub use i_slint_core::properties::PropertySubscription;
 pub use i_slint_core::sharedvector::SharedVector;
 pub use i_slint_core::timers::{Timer, TimerMode};
 pub use i_slint_core::translations::{select_bundled_translation, SelectBundledTranslationError};
@@ -233,6 +253,8 @@ struct MyComponent { /*...*/ }
     string::{SharedString, ToSharedString},
 };
 
+pub mod models;
+
 pub mod private_unstable_api;
 
 /// Enters the main event loop. This is necessary in order to receive
@@ -261,6 +283,49 @@ pub fn run_event_loop_until_quit() -> Result<(), PlatformError> {
     })
 }
 
+/// The status returned by [`pump_events()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PumpStatus {
+    /// The event loop is still running: call [`pump_events()`] again to continue processing events.
+    Continue,
+    /// The event loop was exited, because the last window was closed or [`quit_event_loop()`] was called.
+    /// A subsequent call of [`pump_events()`] starts the event loop afresh.
+    Exit,
+}
+
+/// Processes the pending events of the event loop and returns, instead of running the event loop
+/// until it's quit like [`run_event_loop()`] does.
+///
+/// Use this function to integrate Slint into an application that has its own main loop, such as a game:
+/// call it in each iteration of the loop. The function waits for events for at most `timeout`, and
+/// returns immediately after processing the pending events if `timeout` is zero. Timers, animations, and
+/// rendering of the windows are processed as part of the events.
+///
+/// Only the winit backend supports this function, and not on all platforms: on iOS and WebAssembly, the
+/// event loop can't be driven manually and [`PlatformError::NoEventLoopProvider`] is returned.
+///
+/// ```rust,no_run
+/// # slint::slint!{ export component MyUi inherits Window {} }
+/// let ui = MyUi::new().unwrap();
+/// ui.show().unwrap();
+/// loop {
+///     // ... update the game state ...
+///     match slint::pump_events(std::time::Duration::ZERO).unwrap() {
+///         slint::PumpStatus::Continue => {}
+///         _ => break,
+///     }
+/// }
+/// ```
+pub fn pump_events(timeout: core::time::Duration) -> Result<PumpStatus, PlatformError> {
+    i_slint_backend_selector::with_platform(|b| {
+        Ok(match b.process_events(timeout, i_slint_core::InternalToken)? {
+            core::ops::ControlFlow::Continue(()) => PumpStatus::Continue,
+            core::ops::ControlFlow::Break(()) => PumpStatus::Exit,
+        })
+    })
+}
+
 /// Spawns a [`Future`](core::future::Future) to execute in the Slint event loop.
 ///
 /// This function is intended to be invoked only from the main Slint thread that runs the event loop.
@@ -270,6 +335,9 @@ pub fn run_event_loop_until_quit() -> Result<(), PlatformError> {
 ///
 /// This function is typically called from a UI callback.
 ///
+/// The returned [`JoinHandle`] can be awaited to get the output of the future, or used to abort the
+/// future, for example when the user navigates away from the part of the UI that started it.
+///
 /// # Example
 ///
 /// ```rust,no_run
@@ -421,6 +489,9 @@ pub mod femtovg_renderer {
     }
 }
 
+#[cfg(feature = "std")]
+pub use i_slint_core::dialogs;
+
 #[cfg(any(
     doc,
     all(
@@ -432,6 +503,9 @@ pub mod femtovg_renderer {
 
 pub use i_slint_backend_selector::api::*;
 
+#[cfg(feature = "plugin")]
+pub mod plugin;
+
 /// Helper type that helps checking that the generated code is generated for the right version
 #[doc(hidden)]
 #[allow(non_camel_case_types)]
@@ -559,6 +633,8 @@ pub mod wgpu_27 {
     //!
     //! To integrate rendering content into a scene shared with a Slint UI, use either [`slint::Window::set_rendering_notifier()`](i_slint_core::api::Window::set_rendering_notifier()) to render an underlay
     //! or overlay, or integrate externally produced [`wgpu::Texture`]s using [`slint::Image::try_from<wgpu::Texture>()`](i_slint_core::graphics::Image::try_from).
+    //! For an underlay or overlay, render into the `surface_texture` of [`slint::GraphicsAPI::WGPU27`](i_slint_core::api::GraphicsAPI::WGPU27),
+    //! which the FemtoVG WGPU and Skia WGPU renderers provide while a frame is rendered. Select one of them with `slint::BackendSelector::new().renderer_name("wgpu".into())`.
     //!
     //! The following example allocates a [`wgpu::Texture`] and, for the sake of simplicity in this documentation, fills with green as color, and then proceeds to set it as a `slint::Image` in the scene.
     //!
@@ -702,7 +778,154 @@ pub mod winit_030 {
         WinitWindowAccessor,
     };
 
+    #[cfg(any(
+        feature = "renderer-skia",
+        feature = "renderer-skia-opengl",
+        feature = "renderer-skia-vulkan"
+    ))]
+    pub use i_slint_backend_winit::skia_safe;
+
     #[deprecated(note = "Renamed to `EventResult`")]
     /// Deprecated alias to [`EventResult`]
     pub type WinitWindowEventResult = EventResult;
 }
+
+#[cfg(all(
+    any(feature = "backend-linuxkms", feature = "backend-linuxkms-noseat"),
+    target_os = "linux"
+))]
+pub mod linuxkms {
+    //! Types to configure the displays and input devices of the windows shown by the LinuxKMS backend.
+    //!
+    //! *Note*: This module is behind the `backend-linuxkms` and `backend-linuxkms-noseat` feature flags.
+    //!
+    //! By default, the LinuxKMS backend shows one window in full screen on one display, and sends it the
+    //! events of all input devices. To show several windows on different displays in one process, for
+    //! example the instrument cluster and the infotainment screen of a car, assign a display and input
+    //! devices to each window with [`BackendSelector::with_linuxkms_seat_assignments()`](crate::BackendSelector::with_linuxkms_seat_assignments).
+    //! The windows are assigned in the order in which they're created.
+    //!
+    //! ```rust,no_run
+    //! use slint::linuxkms::SeatAssignment;
+    //!
+    //! slint::slint!{
+    //!     export component Cluster inherits Window {
+    //!         Text { text: "Instrument cluster"; }
+    //!     }
+    //!     export component Infotainment inherits Window {
+    //!         Text { text: "Infotainment"; }
+    //!     }
+    //! }
+    //! fn main() -> Result<(), Box<dyn std::error::Error>> {
+    //!     slint::BackendSelector::new()
+    //!         .backend_name("linuxkms".into())
+    //!         .with_linuxkms_seat_assignments(vec![
+    //!             SeatAssignment { output: "DSI-1".into(), input_devices: vec![] },
+    //!             SeatAssignment {
+    //!                 output: "HDMI-A-1".into(),
+    //!                 input_devices: vec!["ILITEK Multi-Touch".into(), "event5".into()],
+    //!             },
+    //!         ])
+    //!         .select()?;
+    //!
+    //!     // Shown on DSI-1, with the input devices that aren't assigned to another window
+    //!     let cluster = Cluster::new()?;
+    //!     // Shown on HDMI-A-1, with the touch screen and the device of /dev/input/event5
+    //!     let infotainment = Infotainment::new()?;
+    //!     cluster.show()?;
+    //!     infotainment.show()?;
+    //!     slint::run_event_loop()?;
+    //!     Ok(())
+    //! }
+    //! ```
+
+    pub use i_slint_backend_linuxkms::SeatAssignment;
+}
+
+#[cfg(feature = "backend-layershell")]
+pub mod layershell {
+    //! Types to configure the layer surfaces of the windows shown by the layershell backend.
+    //!
+    //! *Note*: This module is behind the `backend-layershell` feature flag.
+    //!
+    //! The layershell backend shows each window in a layer surface of a Wayland compositor that
+    //! supports the [wlr layer shell protocol](https://wayland.app/protocols/wlr-layer-shell-unstable-v1),
+    //! to implement bars, launchers, docks and on-screen displays. Select it with `SLINT_BACKEND=layershell`
+    //! or with the [`BackendSelector`](crate::BackendSelector), and configure the layer surface with the
+    //! [`LayerShellWindowAccessor`] before showing the window.
+    //!
+    //! For example, a bar that is anchored to the top edge of the screen:
+    //!
+    //! ```rust,no_run
+    //! use slint::layershell::{Anchor, LayerShellWindowAccessor, LayerSurfaceSettings};
+    //!
+    //! slint::slint!{
+    //!     export component Bar inherits Window {
+    //!         height: 32px;
+    //!         Text { text: "Hello from the bar"; }
+    //!     }
+    //! }
+    //! fn main() -> Result<(), Box<dyn std::error::Error>> {
+    //!     slint::BackendSelector::new().backend_name("layershell".into()).select()?;
+    //!
+    //!     let bar = Bar::new()?;
+    //!     bar.window().set_layer_surface_settings(LayerSurfaceSettings {
+    //!         anchor: Anchor { top: true, left: true, right: true, bottom: false },
+    //!         // Keep the maximized windows below the bar
+    //!         exclusive_zone: 32,
+    //!         namespace: "bar".into(),
+    //!         ..Default::default()
+    //!     })?;
+    //!     bar.run()?;
+    //!     Ok(())
+    //! }
+    //! ```
+
+    pub use i_slint_backend_layershell::{
+        Anchor, KeyboardInteractivity, Layer, LayerShellWindowAccessor, LayerSurfaceSettings,
+        Margins,
+    };
+}
+
+#[cfg(feature = "backend-headless")]
+pub mod headless {
+    //! Functions to control the virtual clock of the headless backend.
+    //!
+    //! *Note*: This module is behind the `backend-headless` feature flag.
+    //!
+    //! The headless backend doesn't show any windows, but renders them with the software renderer
+    //! when taking a snapshot with [`Window::take_snapshot()`](crate::Window::take_snapshot). Select it
+    //! with `SLINT_BACKEND=headless` or with the [`BackendSelector`](crate::BackendSelector).
+    //!
+    //! Time doesn't pass on its own in the headless backend: animations and timers only advance when
+    //! [`advance_time()`] is called, or when the event loop has nothing else to do and skips ahead to
+    //! the next timer. This way, the rendered images don't depend on the speed of the machine.
+    //!
+    //! For example, a snapshot of a window half way through an animation:
+    //!
+    //! ```rust,no_run
+    //! slint::slint!{
+    //!     export component Demo inherits Window {
+    //!         width: 100px;
+    //!         height: 100px;
+    //!         in property <bool> active;
+    //!         background: active ? blue : red;
+    //!         animate background { duration: 500ms; }
+    //!     }
+    //! }
+    //! fn main() -> Result<(), Box<dyn std::error::Error>> {
+    //!     slint::BackendSelector::new().backend_name("headless".into()).select()?;
+    //!
+    //!     let demo = Demo::new()?;
+    //!     demo.show()?;
+    //!     demo.set_active(true);
+    //!     slint::headless::advance_time(std::time::Duration::from_millis(250));
+    //!
+    //!     let snapshot = demo.window().take_snapshot()?;
+    //!     assert_eq!(snapshot.width(), 100);
+    //!     Ok(())
+    //! }
+    //! ```
+
+    pub use i_slint_backend_testing::headless::{advance_time, elapsed_time};
+}
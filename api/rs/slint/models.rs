@@ -0,0 +1,549 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+Functions that load the rows of a model from CSV or JSON data.
+
+The rows are structs declared in a `.slint` file. Slint implements the [`FromRecord`] trait for
+all the structs whose fields are strings, numbers, booleans or colors. Each field of the struct is
+read from the column (for CSV) or the key (for JSON) with the same name, where `-` and `_` are
+considered equal. The values are converted to the type of the field, and fields without a value
+get their default value.
+
+```rust,no_run
+slint::slint! {
+    export struct Planet { name: string, diameter: float, has-rings: bool }
+    export component Example inherits Window {
+        in property <[Planet]> planets;
+    }
+}
+
+let csv = "name,diameter,has_rings\nEarth,12742,false\nSaturn,116460,true\n";
+let planets = slint::models::from_csv::<Planet>(csv.as_bytes()).unwrap();
+let example = Example::new().unwrap();
+example.set_planets(planets);
+```
+*/
+
+use crate::{Color, ModelRc, SharedString, VecModel};
+#[cfg(feature = "std")]
+use crate::{StandardListViewItem, TableColumn};
+use alloc::format;
+use alloc::string::String;
+#[cfg(any(feature = "std", feature = "serde"))]
+use alloc::vec::Vec;
+
+/// A value of a record, as found in the CSV or JSON data.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum FieldValue<'a> {
+    /// There is no value, such as an empty cell of a CSV file, or `null` in JSON
+    Null,
+    /// A boolean value
+    Bool(bool),
+    /// A number
+    Number(f64),
+    /// A text, which is converted to the type of the field
+    Text(&'a str),
+}
+
+/// A row of CSV or JSON data, which gives the value of each field by name.
+pub trait Record {
+    /// Returns the value of the field with the given name, or None if the record doesn't have this field.
+    ///
+    /// The names are compared with `-` and `_` considered equal.
+    fn field(&self, name: &str) -> Option<FieldValue<'_>>;
+}
+
+/// The error returned when a field of a [`Record`] can't be converted to the type of the field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldError {
+    /// The name of the field
+    pub field: SharedString,
+    /// A description of the problem
+    pub message: String,
+}
+
+impl core::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid value for field `{}`: {}", self.field, self.message)
+    }
+}
+
+/// Types that can be read from a [`Record`].
+///
+/// This trait is implemented by the structs declared in `.slint` files whose fields are all strings,
+/// numbers, booleans or colors.
+pub trait FromRecord: Sized {
+    /// Creates a value from the fields of the record.
+    fn from_record(record: &dyn Record) -> Result<Self, FieldError>;
+}
+
+/// Types of the fields of a [`FromRecord`] struct, which can be converted from a [`FieldValue`].
+pub trait FromFieldValue: Sized {
+    /// Converts the value, or returns a description of why this isn't possible.
+    fn from_field_value(value: FieldValue<'_>) -> Result<Self, String>;
+}
+
+impl FromFieldValue for SharedString {
+    fn from_field_value(value: FieldValue<'_>) -> Result<Self, String> {
+        Ok(match value {
+            FieldValue::Null => SharedString::default(),
+            FieldValue::Bool(b) => if b { "true" } else { "false" }.into(),
+            FieldValue::Number(n) => crate::format!("{n}"),
+            FieldValue::Text(text) => text.into(),
+        })
+    }
+}
+
+/// Returns the number of the value, or None if the value is empty
+fn number(value: FieldValue<'_>) -> Result<Option<f64>, String> {
+    match value {
+        FieldValue::Null => Ok(None),
+        FieldValue::Bool(b) => Ok(Some(if b { 1. } else { 0. })),
+        FieldValue::Number(n) => Ok(Some(n)),
+        FieldValue::Text(text) if text.trim().is_empty() => Ok(None),
+        FieldValue::Text(text) => {
+            text.trim().parse::<f64>().map(Some).map_err(|_| format!("`{text}` is not a number"))
+        }
+    }
+}
+
+macro_rules! impl_from_field_value_for_integer {
+    ($($ty:ty)*) => {$(
+        impl FromFieldValue for $ty {
+            fn from_field_value(value: FieldValue<'_>) -> Result<Self, String> {
+                let Some(n) = number(value)? else { return Ok(0) };
+                if n.fract() != 0. || n < <$ty>::MIN as f64 || n > <$ty>::MAX as f64 {
+                    return Err(format!("`{n}` is not an integer in the range of {}", stringify!($ty)));
+                }
+                Ok(n as $ty)
+            }
+        }
+    )*};
+}
+
+impl_from_field_value_for_integer!(i32 i64);
+
+impl FromFieldValue for f32 {
+    fn from_field_value(value: FieldValue<'_>) -> Result<Self, String> {
+        Ok(number(value)?.unwrap_or_default() as f32)
+    }
+}
+
+impl FromFieldValue for f64 {
+    fn from_field_value(value: FieldValue<'_>) -> Result<Self, String> {
+        Ok(number(value)?.unwrap_or_default())
+    }
+}
+
+impl FromFieldValue for bool {
+    fn from_field_value(value: FieldValue<'_>) -> Result<Self, String> {
+        match value {
+            FieldValue::Bool(b) => Ok(b),
+            FieldValue::Text(text) => match text.trim().to_ascii_lowercase().as_str() {
+                "" | "false" | "no" | "0" => Ok(false),
+                "true" | "yes" | "1" => Ok(true),
+                _ => Err(format!("`{text}` is not a boolean")),
+            },
+            value => Ok(number(value)?.is_some_and(|n| n != 0.)),
+        }
+    }
+}
+
+impl FromFieldValue for Color {
+    fn from_field_value(value: FieldValue<'_>) -> Result<Self, String> {
+        match value {
+            FieldValue::Null => Ok(Color::default()),
+            FieldValue::Text(text) if text.trim().is_empty() => Ok(Color::default()),
+            FieldValue::Text(text) => Color::from_hex_str(text.trim())
+                .ok_or_else(|| format!("`{text}` is not a color in the `#rrggbb` format")),
+            // A number is taken as 0xAARRGGBB
+            FieldValue::Number(n) if n.fract() == 0. && (0. ..=u32::MAX as f64).contains(&n) => {
+                Ok(Color::from_argb_encoded(n as u32))
+            }
+            value => Err(format!("{value:?} is not a color")),
+        }
+    }
+}
+
+/// Reads the field with the given name from the record, converted to `T`.
+///
+/// Fields that are not in the record get their default value.
+pub fn read_field<T: FromFieldValue + Default>(
+    record: &dyn Record,
+    name: &str,
+) -> Result<T, FieldError> {
+    match record.field(name) {
+        None => Ok(T::default()),
+        Some(value) => {
+            T::from_field_value(value).map_err(|message| FieldError { field: name.into(), message })
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "serde"))]
+fn same_name(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .all(|(a, b)| a == b || (a == b'-' && b == b'_') || (a == b'_' && b == b'-'))
+}
+
+/// The error returned by the functions of this module
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum LoadModelError {
+    /// Reading the data failed
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+    /// The CSV data is malformed at the given line, starting at 1
+    Syntax {
+        /// The line of the error
+        line: usize,
+        /// A description of the error
+        message: String,
+    },
+    /// The JSON value is not an array of objects
+    UnexpectedJson(String),
+    /// A value can't be converted to the type of its field
+    InvalidValue {
+        /// The index of the row in the model
+        row: usize,
+        /// The error of the field
+        error: FieldError,
+    },
+}
+
+impl core::fmt::Display for LoadModelError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            LoadModelError::Io(err) => err.fmt(f),
+            LoadModelError::Syntax { line, message } => write!(f, "line {line}: {message}"),
+            LoadModelError::UnexpectedJson(message) => f.write_str(message),
+            LoadModelError::InvalidValue { row, error } => write!(f, "row {row}: {error}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LoadModelError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadModelError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for LoadModelError {
+    fn from(err: std::io::Error) -> Self {
+        LoadModelError::Io(err)
+    }
+}
+
+/// Splits CSV text into records of fields, following RFC 4180: fields are separated by commas,
+/// and fields that contain commas, quotes or newlines are quoted, with quotes doubled.
+#[cfg(feature = "std")]
+fn parse_csv(text: &str) -> Result<Vec<Vec<String>>, LoadModelError> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut line = 1;
+    let mut chars = text.strip_prefix('\u{feff}').unwrap_or(text).chars().peekable();
+    // true when the current field started with a quote
+    let mut quoted = false;
+    // true when the current record has content, so that empty lines are skipped
+    let mut has_content = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    quoted = false;
+                    if !matches!(chars.peek(), None | Some(',' | '\r' | '\n')) {
+                        return Err(LoadModelError::Syntax {
+                            line,
+                            message: "unexpected character after a closing quote".into(),
+                        });
+                    }
+                }
+            }
+            '"' if field.is_empty() => {
+                quoted = true;
+                has_content = true;
+            }
+            '\n' if quoted => {
+                line += 1;
+                field.push(c);
+            }
+            _ if quoted => field.push(c),
+            ',' => {
+                record.push(core::mem::take(&mut field));
+                has_content = true;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\r' | '\n' => {
+                if has_content || !field.is_empty() {
+                    record.push(core::mem::take(&mut field));
+                    records.push(core::mem::take(&mut record));
+                }
+                has_content = false;
+                line += 1;
+            }
+            _ => {
+                field.push(c);
+                has_content = true;
+            }
+        }
+    }
+    if quoted {
+        return Err(LoadModelError::Syntax { line, message: "unterminated quoted field".into() });
+    }
+    if has_content || !field.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    Ok(records)
+}
+
+#[cfg(feature = "std")]
+struct CsvRecord<'a> {
+    header: &'a [String],
+    fields: &'a [String],
+}
+
+#[cfg(feature = "std")]
+impl Record for CsvRecord<'_> {
+    fn field(&self, name: &str) -> Option<FieldValue<'_>> {
+        let index = self.header.iter().position(|h| same_name(h.trim(), name))?;
+        Some(match self.fields.get(index) {
+            Some(text) if !text.is_empty() => FieldValue::Text(text),
+            _ => FieldValue::Null,
+        })
+    }
+}
+
+/// Loads a model from CSV data.
+///
+/// The first line of the data is the header, which contains the names of the columns. Each following
+/// line is a row of the model, converted to `T`: see the [module documentation](self) for details.
+///
+/// ```rust
+/// slint::slint! {
+///     export struct Contact { name: string, age: int }
+/// }
+/// use slint::Model;
+/// let csv = "name,age\n\"Doe, John\",42\nJane,\n";
+/// let contacts = slint::models::from_csv::<Contact>(csv.as_bytes()).unwrap();
+/// assert_eq!(contacts.row_count(), 2);
+/// assert_eq!(contacts.row_data(0).unwrap().name, "Doe, John");
+/// assert_eq!(contacts.row_data(1).unwrap().age, 0);
+///
+/// let error = slint::models::from_csv::<Contact>("name,age\nJohn,old".as_bytes()).unwrap_err();
+/// assert_eq!(error.to_string(), "row 0: invalid value for field `age`: `old` is not a number");
+/// ```
+#[cfg(feature = "std")]
+pub fn from_csv<T: FromRecord + Clone + 'static>(
+    mut reader: impl std::io::Read,
+) -> Result<ModelRc<T>, LoadModelError> {
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+    let mut records = parse_csv(&text)?.into_iter();
+    let header = records.next().unwrap_or_default();
+    let rows = records
+        .enumerate()
+        .map(|(row, fields)| {
+            T::from_record(&CsvRecord { header: &header, fields: &fields })
+                .map_err(|error| LoadModelError::InvalidValue { row, error })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(ModelRc::new(VecModel::from(rows)))
+}
+
+/// Loads the columns and the rows of a `StandardTableView` from CSV data.
+///
+/// The first line of the data is the header, which gives the titles of the columns.
+///
+/// ```rust,no_run
+/// slint::slint! {
+///     import { StandardTableView } from "std-widgets.slint";
+///     export component Example inherits Window {
+///         in property <[TableColumn]> columns;
+///         in property <[[StandardListViewItem]]> rows;
+///         StandardTableView { columns: root.columns; rows: root.rows; }
+///     }
+/// }
+/// let (columns, rows) = slint::models::table_from_csv("a,b\n1,2\n".as_bytes()).unwrap();
+/// let example = Example::new().unwrap();
+/// example.set_columns(columns);
+/// example.set_rows(rows);
+/// ```
+#[cfg(feature = "std")]
+pub fn table_from_csv(
+    mut reader: impl std::io::Read,
+) -> Result<(ModelRc<TableColumn>, ModelRc<ModelRc<StandardListViewItem>>), LoadModelError> {
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+    let mut records = parse_csv(&text)?.into_iter();
+    let columns = records
+        .next()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|title| {
+            let mut column = TableColumn::default();
+            column.title = title.into();
+            column
+        })
+        .collect::<Vec<_>>();
+    let rows = records
+        .map(|fields| {
+            ModelRc::new(VecModel::from(
+                fields.iter().map(|f| StandardListViewItem::from(f.as_str())).collect::<Vec<_>>(),
+            ))
+        })
+        .collect::<Vec<_>>();
+    Ok((ModelRc::new(VecModel::from(columns)), ModelRc::new(VecModel::from(rows))))
+}
+
+#[cfg(feature = "serde")]
+impl Record for serde_json::Map<String, serde_json::Value> {
+    fn field(&self, name: &str) -> Option<FieldValue<'_>> {
+        let value = match self.get(name) {
+            Some(value) => value,
+            None => self.iter().find(|(key, _)| same_name(key, name))?.1,
+        };
+        Some(match value {
+            serde_json::Value::Null => FieldValue::Null,
+            serde_json::Value::Bool(b) => FieldValue::Bool(*b),
+            serde_json::Value::Number(n) => FieldValue::Number(n.as_f64().unwrap_or_default()),
+            serde_json::Value::String(s) => FieldValue::Text(s),
+            // Arrays and objects can't be converted, so the field keeps its default value
+            serde_json::Value::Array(_) | serde_json::Value::Object(_) => return None,
+        })
+    }
+}
+
+/// Loads a model from a JSON array of objects.
+///
+/// Each object of the array is a row of the model, converted to `T`: see the
+/// [module documentation](self) for details.
+///
+/// ```rust
+/// slint::slint! {
+///     export struct Contact { name: string, age: int }
+/// }
+/// use slint::Model;
+/// let json = serde_json::json!([{ "name": "John", "age": 42 }, { "name": "Jane", "age": "37" }]);
+/// let contacts = slint::models::from_json::<Contact>(&json).unwrap();
+/// assert_eq!(contacts.row_data(1).unwrap().age, 37);
+/// ```
+#[cfg(feature = "serde")]
+pub fn from_json<T: FromRecord + Clone + 'static>(
+    value: &serde_json::Value,
+) -> Result<ModelRc<T>, LoadModelError> {
+    let array = value
+        .as_array()
+        .ok_or_else(|| LoadModelError::UnexpectedJson("expected an array of objects".into()))?;
+    let rows = array
+        .iter()
+        .enumerate()
+        .map(|(row, value)| {
+            let object = value.as_object().ok_or_else(|| {
+                LoadModelError::UnexpectedJson(format!(
+                    "element {row} of the array is not an object"
+                ))
+            })?;
+            T::from_record(object).map_err(|error| LoadModelError::InvalidValue { row, error })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(ModelRc::new(VecModel::from(rows)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Model;
+    use alloc::string::ToString;
+
+    #[derive(Clone, Default, Debug, PartialEq)]
+    struct Item {
+        name: SharedString,
+        count: i32,
+        enabled: bool,
+    }
+
+    impl FromRecord for Item {
+        fn from_record(record: &dyn Record) -> Result<Self, FieldError> {
+            Ok(Self {
+                name: read_field(record, "name")?,
+                count: read_field(record, "item-count")?,
+                enabled: read_field(record, "enabled")?,
+            })
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse_csv() {
+        let records = parse_csv("a,b\r\n\"x, \"\"y\"\"\",\"multi\nline\"\n\n,\n").unwrap();
+        assert_eq!(
+            records,
+            alloc::vec![
+                alloc::vec!["a".to_string(), "b".into()],
+                alloc::vec!["x, \"y\"".to_string(), "multi\nline".into()],
+                alloc::vec![String::new(), String::new()],
+            ]
+        );
+        assert!(matches!(parse_csv("a\n\"b"), Err(LoadModelError::Syntax { line: 2, .. })));
+        assert!(matches!(parse_csv("\"a\"b"), Err(LoadModelError::Syntax { line: 1, .. })));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_csv() {
+        let model =
+            from_csv::<Item>("name,item_count,enabled,other\nfoo,3,yes,x\nbar\n".as_bytes())
+                .unwrap();
+        assert_eq!(
+            model.iter().collect::<Vec<_>>(),
+            alloc::vec![
+                Item { name: "foo".into(), count: 3, enabled: true },
+                Item { name: "bar".into(), count: 0, enabled: false },
+            ]
+        );
+        let err = from_csv::<Item>("name,item-count\nfoo,1.5\n".as_bytes()).unwrap_err();
+        assert!(
+            matches!(err, LoadModelError::InvalidValue { row: 0, ref error } if error.field == "item-count")
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_from_json() {
+        let json = serde_json::json!([
+            { "name": "foo", "item_count": 3, "enabled": true },
+            { "name": 42, "item-count": "4", "enabled": null },
+        ]);
+        let model = from_json::<Item>(&json).unwrap();
+        assert_eq!(
+            model.iter().collect::<Vec<_>>(),
+            alloc::vec![
+                Item { name: "foo".into(), count: 3, enabled: true },
+                Item { name: "42".into(), count: 4, enabled: false },
+            ]
+        );
+        assert!(matches!(
+            from_json::<Item>(&serde_json::json!({})),
+            Err(LoadModelError::UnexpectedJson(_))
+        ));
+        assert!(matches!(
+            from_json::<Item>(&serde_json::json!([{ "enabled": "maybe" }])),
+            Err(LoadModelError::InvalidValue { row: 0, .. })
+        ));
+    }
+}
@@ -0,0 +1,856 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+Load components from dynamic libraries at runtime.
+
+A plugin is a dynamic library (`cdylib`) built from Slint code compiled with the C ABI generation
+mode, enabled with `slint_build::CompilerConfiguration::with_c_abi(true)` or `slint-compiler --c-abi`.
+The library exposes its exported components through a small, stable C ABI, so a host application can
+load plugins that were built separately, even against a different version of Slint.
+
+```no_run
+// Safety: the library is trusted and was built with the C ABI generation mode
+let plugin = unsafe { slint::plugin::load("libmy_plugin.so") }.unwrap();
+for component in plugin.components() {
+    println!("{} has {} properties", component.name(), component.properties().len());
+}
+let instance = plugin.create("MainWindow").unwrap();
+instance.set_property("title", "Hello".into()).unwrap();
+instance.on_callback("clicked", |args| {
+    println!("clicked with {args:?}");
+    slint::plugin::Value::Void
+});
+instance.show().unwrap();
+plugin.run_event_loop().unwrap();
+```
+
+Only properties, callbacks, and functions whose types are numbers, booleans, strings, or colors
+are available. Components with required properties can't be created from a plugin.
+
+The plugin contains its own copy of the Slint run-time. By default, the windows of its components
+are separate windows on the screen, handled by the event loop of the plugin, started with
+[`Plugin::run_event_loop()`].
+
+## Embedding
+
+After calling [`Plugin::enable_embedding()`], the components of the plugin are embedded into the
+scene of the host instead: [`PluginComponent::render()`] renders a component into an image that
+the host shows in one of its own elements or windows, and [`PluginComponent::dispatch_event()`]
+forwards the input events of the host to the component. The host's event loop drives the plugin,
+so call [`Plugin::update_timers_and_animations()`] before rendering each frame.
+
+```no_run
+slint::slint! {
+    export component Host inherits Window {
+        in property <image> plugin-view;
+        callback pointer-pressed(length, length);
+        callback pointer-released(length, length);
+        Image {
+            source: root.plugin-view;
+            TouchArea {
+                pointer-event(event) => {
+                    if event.kind == PointerEventKind.down {
+                        root.pointer-pressed(self.mouse-x, self.mouse-y);
+                    } else if event.kind == PointerEventKind.up {
+                        root.pointer-released(self.mouse-x, self.mouse-y);
+                    }
+                }
+            }
+        }
+    }
+}
+
+use slint::platform::{PointerEventButton, WindowEvent};
+use std::rc::Rc;
+
+// Safety: the library is trusted and was built with the C ABI generation mode
+let plugin = Rc::new(unsafe { slint::plugin::load("libmy_plugin.so") }.unwrap());
+plugin.enable_embedding().unwrap();
+let component = Rc::new(plugin.create("Panel").unwrap());
+
+let host = Host::new().unwrap();
+let timer = slint::Timer::default();
+timer.start(slint::TimerMode::Repeated, std::time::Duration::from_millis(16), {
+    let host = host.as_weak();
+    let component = component.clone();
+    move || {
+        let Some(host) = host.upgrade() else { return };
+        plugin.update_timers_and_animations();
+        let size = slint::PhysicalSize::new(400, 300);
+        let frame = component.render(size, host.window().scale_factor()).unwrap();
+        host.set_plugin_view(slint::Image::from_rgba8(frame));
+    }
+});
+host.on_pointer_pressed({
+    let component = component.clone();
+    move |x, y| {
+        let position = slint::LogicalPosition::new(x, y);
+        component.dispatch_event(&WindowEvent::PointerPressed { position, button: PointerEventButton::Left });
+    }
+});
+host.on_pointer_released(move |x, y| {
+    let position = slint::LogicalPosition::new(x, y);
+    component.dispatch_event(&WindowEvent::PointerReleased { position, button: PointerEventButton::Left });
+});
+host.run().unwrap();
+```
+
+**Note**: This module is experimental and may change or be removed in the future.
+*/
+
+#![allow(unsafe_code)]
+
+use crate::private_unstable_api::c_abi;
+use crate::{Color, SharedString};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ffi::{c_char, c_void};
+use std::ffi::CString;
+use std::path::Path;
+
+/// Error returned by [`load()`] and [`Plugin::create()`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PluginError {
+    /// The library could not be loaded, or is missing one of the entry points
+    Library(libloading::Error),
+    /// The library was built with a version of the C ABI that isn't supported
+    IncompatibleAbiVersion(u32),
+    /// The metadata embedded in the library is invalid
+    InvalidMetadata(String),
+    /// The plugin doesn't have a component with this name
+    NoSuchComponent(String),
+    /// The plugin failed to create the component
+    CreationFailed,
+    /// The plugin can't be embedded, because it was built without the software renderer, or
+    /// because a component was already created
+    EmbeddingUnsupported,
+}
+
+impl core::fmt::Display for PluginError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PluginError::Library(err) => write!(f, "Error loading the plugin: {err}"),
+            PluginError::IncompatibleAbiVersion(version) => write!(
+                f,
+                "The plugin uses version {version} of the C ABI, but version {} is expected",
+                c_abi::ABI_VERSION
+            ),
+            PluginError::InvalidMetadata(err) => write!(f, "Invalid plugin metadata: {err}"),
+            PluginError::NoSuchComponent(name) => write!(f, "No component named '{name}'"),
+            PluginError::CreationFailed => f.write_str("The plugin failed to create the component"),
+            PluginError::EmbeddingUnsupported => f.write_str("The plugin can't be embedded"),
+        }
+    }
+}
+
+impl std::error::Error for PluginError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PluginError::Library(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<libloading::Error> for PluginError {
+    fn from(err: libloading::Error) -> Self {
+        Self::Library(err)
+    }
+}
+
+/// Error returned by [`PluginComponent::set_property()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SetPropertyError {
+    /// There is no property with the given name, or it is read-only
+    NoSuchProperty,
+    /// The value doesn't have the type of the property
+    WrongType,
+}
+
+impl core::fmt::Display for SetPropertyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SetPropertyError::NoSuchProperty => f.write_str("no such property"),
+            SetPropertyError::WrongType => f.write_str("the value has the wrong type"),
+        }
+    }
+}
+
+impl std::error::Error for SetPropertyError {}
+
+/// The type of a property, or of an argument or return value of a callback or function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValueType {
+    /// No value, only used as the return type of callbacks and functions
+    Void,
+    /// A number. Lengths, durations, angles, and percentages are also numbers.
+    Number,
+    /// A boolean
+    Bool,
+    /// A string
+    String,
+    /// A color. Brushes are also passed as colors.
+    Color,
+}
+
+/// A value passed to or returned from a [`PluginComponent`]
+#[derive(Debug, Clone, PartialEq, Default)]
+#[non_exhaustive]
+pub enum Value {
+    /// No value
+    #[default]
+    Void,
+    /// A number. Lengths are in logical pixels, durations in milliseconds, and angles in degrees.
+    Number(f64),
+    /// A boolean
+    Bool(bool),
+    /// A string
+    String(SharedString),
+    /// A color
+    Color(Color),
+}
+
+impl Value {
+    /// Returns the type of this value
+    pub fn value_type(&self) -> ValueType {
+        match self {
+            Value::Void => ValueType::Void,
+            Value::Number(_) => ValueType::Number,
+            Value::Bool(_) => ValueType::Bool,
+            Value::String(_) => ValueType::String,
+            Value::Color(_) => ValueType::Color,
+        }
+    }
+
+    /// Convert a value returned by the plugin. Strings are copied and need to be freed by the caller.
+    fn from_abi(value: &c_abi::Value) -> Self {
+        match value.kind {
+            c_abi::VALUE_KIND_NUMBER => Value::Number(value.number),
+            c_abi::VALUE_KIND_BOOL => Value::Bool(value.number != 0.),
+            c_abi::VALUE_KIND_COLOR => Value::Color(Color::from_argb_encoded(value.color)),
+            c_abi::VALUE_KIND_STRING => Value::String(
+                <SharedString as c_abi::AbiValue>::from_abi(value).unwrap_or_default(),
+            ),
+            _ => Value::Void,
+        }
+    }
+
+    /// Convert the value to pass it to the plugin. Strings point into `self`.
+    fn to_abi(&self) -> c_abi::Value {
+        match self {
+            Value::Void => c_abi::Value::void(),
+            Value::Number(n) => {
+                c_abi::Value { kind: c_abi::VALUE_KIND_NUMBER, number: *n, ..c_abi::Value::void() }
+            }
+            Value::Bool(b) => c_abi::Value {
+                kind: c_abi::VALUE_KIND_BOOL,
+                number: *b as i32 as f64,
+                ..c_abi::Value::void()
+            },
+            Value::String(s) => c_abi::Value {
+                kind: c_abi::VALUE_KIND_STRING,
+                string: s.as_ptr() as *const c_char,
+                string_len: s.len(),
+                ..c_abi::Value::void()
+            },
+            Value::Color(c) => c_abi::Value {
+                kind: c_abi::VALUE_KIND_COLOR,
+                color: c.as_argb_encoded(),
+                ..c_abi::Value::void()
+            },
+        }
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Number(value)
+    }
+}
+impl From<f32> for Value {
+    fn from(value: f32) -> Self {
+        Value::Number(value as f64)
+    }
+}
+impl From<i32> for Value {
+    fn from(value: i32) -> Self {
+        Value::Number(value as f64)
+    }
+}
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+impl From<SharedString> for Value {
+    fn from(value: SharedString) -> Self {
+        Value::String(value)
+    }
+}
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(value.into())
+    }
+}
+impl From<Color> for Value {
+    fn from(value: Color) -> Self {
+        Value::Color(value)
+    }
+}
+
+/// Description of a property of a component of a plugin
+#[derive(Debug, Clone)]
+pub struct PropertyInfo {
+    name: String,
+    ty: ValueType,
+    read_only: bool,
+}
+
+impl PropertyInfo {
+    /// The name of the property, as in the `.slint` file
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// The type of the property
+    pub fn value_type(&self) -> ValueType {
+        self.ty
+    }
+    /// Returns true if the property is an `out` property and can't be set
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+}
+
+/// Description of a callback or a function of a component of a plugin
+#[derive(Debug, Clone)]
+pub struct FunctionInfo {
+    name: String,
+    args: Vec<ValueType>,
+    return_type: ValueType,
+}
+
+impl FunctionInfo {
+    /// The name of the callback or function, as in the `.slint` file
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// The types of the arguments
+    pub fn args(&self) -> &[ValueType] {
+        &self.args
+    }
+    /// The return type
+    pub fn return_type(&self) -> ValueType {
+        self.return_type
+    }
+}
+
+/// Description of a component that a plugin can create
+#[derive(Debug, Clone)]
+pub struct ComponentInfo {
+    name: String,
+    properties: Vec<PropertyInfo>,
+    callbacks: Vec<FunctionInfo>,
+    functions: Vec<FunctionInfo>,
+}
+
+impl ComponentInfo {
+    /// The name of the component
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// The public properties of the component
+    pub fn properties(&self) -> &[PropertyInfo] {
+        &self.properties
+    }
+    /// The public callbacks of the component
+    pub fn callbacks(&self) -> &[FunctionInfo] {
+        &self.callbacks
+    }
+    /// The public functions of the component
+    pub fn functions(&self) -> &[FunctionInfo] {
+        &self.functions
+    }
+}
+
+type HandlerFn = c_abi::HandlerFn;
+type DropFn = unsafe extern "C" fn(*mut c_void);
+
+/// The entry points of the library, see `slint_component_abi.h`
+struct EntryPoints {
+    create: unsafe extern "C" fn(*const c_char) -> *mut c_void,
+    destroy: unsafe extern "C" fn(*mut c_void),
+    show: unsafe extern "C" fn(*mut c_void, bool) -> bool,
+    run_event_loop: unsafe extern "C" fn() -> bool,
+    get_property: unsafe extern "C" fn(*mut c_void, *const c_char, *mut c_abi::Value) -> bool,
+    set_property: unsafe extern "C" fn(*mut c_void, *const c_char, *const c_abi::Value) -> bool,
+    invoke: unsafe extern "C" fn(
+        *mut c_void,
+        *const c_char,
+        *const c_abi::Value,
+        usize,
+        *mut c_abi::Value,
+    ) -> bool,
+    set_callback: unsafe extern "C" fn(
+        *mut c_void,
+        *const c_char,
+        HandlerFn,
+        *mut c_void,
+        Option<DropFn>,
+    ) -> bool,
+    value_free: unsafe extern "C" fn(*mut c_abi::Value),
+    set_embedded: unsafe extern "C" fn() -> bool,
+    update_timers_and_animations: unsafe extern "C" fn(),
+    render: unsafe extern "C" fn(*mut c_void, u32, u32, f32, *mut u8) -> bool,
+    dispatch_pointer_event: unsafe extern "C" fn(*mut c_void, u32, f32, f32, u32, f32, f32) -> bool,
+    dispatch_key_event: unsafe extern "C" fn(*mut c_void, bool, *const c_char) -> bool,
+    // Must be dropped last, after the function pointers can no longer be called
+    _library: libloading::Library,
+}
+
+impl EntryPoints {
+    /// Copy a value returned by the plugin and release it
+    fn take_value(&self, mut value: c_abi::Value) -> Value {
+        let result = Value::from_abi(&value);
+        unsafe { (self.value_free)(&mut value) };
+        result
+    }
+}
+
+/// A dynamic library loaded with [`load()`]
+pub struct Plugin {
+    entry_points: Rc<EntryPoints>,
+    components: Vec<ComponentInfo>,
+}
+
+/// Load the plugin at the given path.
+///
+/// # Safety
+///
+/// Loading a library runs its initialization code, and the entry points of the library are then
+/// called with the signatures of `slint_component_abi.h`. The library must be trusted, and must
+/// have been built with the C ABI generation mode of the Slint compiler.
+pub unsafe fn load(path: impl AsRef<Path>) -> Result<Plugin, PluginError> {
+    let library = unsafe { libloading::Library::new(path.as_ref()) }?;
+
+    let abi_version =
+        unsafe { library.get::<unsafe extern "C" fn() -> u32>(b"slint_component_abi_version\0")? };
+    let abi_version = unsafe { abi_version() };
+    if abi_version != c_abi::ABI_VERSION {
+        return Err(PluginError::IncompatibleAbiVersion(abi_version));
+    }
+
+    let metadata = unsafe {
+        let metadata = library
+            .get::<unsafe extern "C" fn() -> *const c_char>(b"slint_component_metadata\0")?;
+        core::ffi::CStr::from_ptr(metadata())
+    };
+    let components = parse_metadata(
+        metadata.to_str().map_err(|e| PluginError::InvalidMetadata(e.to_string()))?,
+    )?;
+
+    macro_rules! entry_point {
+        ($name:literal) => {
+            symbol(&library, concat!($name, "\0").as_bytes())?
+        };
+    }
+    let entry_points = EntryPoints {
+        create: entry_point!("slint_component_create"),
+        destroy: entry_point!("slint_component_destroy"),
+        show: entry_point!("slint_component_show"),
+        run_event_loop: entry_point!("slint_component_run_event_loop"),
+        get_property: entry_point!("slint_component_get_property"),
+        set_property: entry_point!("slint_component_set_property"),
+        invoke: entry_point!("slint_component_invoke"),
+        set_callback: entry_point!("slint_component_set_callback"),
+        value_free: entry_point!("slint_component_value_free"),
+        set_embedded: entry_point!("slint_component_set_embedded"),
+        update_timers_and_animations: entry_point!("slint_component_update_timers_and_animations"),
+        render: entry_point!("slint_component_render"),
+        dispatch_pointer_event: entry_point!("slint_component_dispatch_pointer_event"),
+        dispatch_key_event: entry_point!("slint_component_dispatch_key_event"),
+        _library: library,
+    };
+
+    Ok(Plugin { entry_points: Rc::new(entry_points), components })
+}
+
+/// Resolve a function pointer. `T` must be the `extern "C"` function type of the symbol.
+fn symbol<T: Copy>(library: &libloading::Library, name: &[u8]) -> Result<T, libloading::Error> {
+    Ok(*unsafe { library.get::<T>(name)? })
+}
+
+fn parse_metadata(metadata: &str) -> Result<Vec<ComponentInfo>, PluginError> {
+    use serde_json::Value as Json;
+    let invalid = |what: &str| PluginError::InvalidMetadata(what.to_string());
+
+    let json: Json =
+        serde_json::from_str(metadata).map_err(|e| PluginError::InvalidMetadata(e.to_string()))?;
+
+    let value_type = |json: &Json| match json.as_str() {
+        Some("void") => Ok(ValueType::Void),
+        Some("number") => Ok(ValueType::Number),
+        Some("bool") => Ok(ValueType::Bool),
+        Some("string") => Ok(ValueType::String),
+        Some("color") => Ok(ValueType::Color),
+        _ => Err(invalid("unknown type")),
+    };
+    let name = |json: &Json| {
+        json.get("name")
+            .and_then(Json::as_str)
+            .map(String::from)
+            .ok_or_else(|| invalid("missing name"))
+    };
+    let array = |json: &Json, field: &str| {
+        json.get(field).and_then(Json::as_array).cloned().unwrap_or_default()
+    };
+    let function = |json: &Json| -> Result<FunctionInfo, PluginError> {
+        Ok(FunctionInfo {
+            name: name(json)?,
+            args: array(json, "args").iter().map(value_type).collect::<Result<_, _>>()?,
+            return_type: json.get("return").map_or(Ok(ValueType::Void), value_type)?,
+        })
+    };
+
+    array(&json, "components")
+        .iter()
+        .map(|component| {
+            Ok(ComponentInfo {
+                name: name(component)?,
+                properties: array(component, "properties")
+                    .iter()
+                    .map(|p| {
+                        Ok(PropertyInfo {
+                            name: name(p)?,
+                            ty: value_type(p.get("type").unwrap_or(&Json::Null))?,
+                            read_only: p.get("read_only").and_then(Json::as_bool).unwrap_or(false),
+                        })
+                    })
+                    .collect::<Result<_, PluginError>>()?,
+                callbacks: array(component, "callbacks")
+                    .iter()
+                    .map(function)
+                    .collect::<Result<_, _>>()?,
+                functions: array(component, "functions")
+                    .iter()
+                    .map(function)
+                    .collect::<Result<_, _>>()?,
+            })
+        })
+        .collect()
+}
+
+impl Plugin {
+    /// Returns the description of the components that the plugin can create
+    pub fn components(&self) -> &[ComponentInfo] {
+        &self.components
+    }
+
+    /// Returns the description of the component with the given name
+    pub fn component(&self, name: &str) -> Option<&ComponentInfo> {
+        self.components.iter().find(|c| c.name == name)
+    }
+
+    /// Creates a new instance of the component with the given name
+    pub fn create(&self, name: &str) -> Result<PluginComponent, PluginError> {
+        if self.component(name).is_none() {
+            return Err(PluginError::NoSuchComponent(name.into()));
+        }
+        let c_name = CString::new(name).map_err(|_| PluginError::NoSuchComponent(name.into()))?;
+        let handle = unsafe { (self.entry_points.create)(c_name.as_ptr()) };
+        if handle.is_null() {
+            return Err(PluginError::CreationFailed);
+        }
+        Ok(PluginComponent { handle, entry_points: self.entry_points.clone() })
+    }
+
+    /// Embeds the components of the plugin into the host's scene, see the [module documentation](self#embedding).
+    ///
+    /// This must be called before creating any component. Afterwards, the components aren't shown
+    /// in windows of their own, but rendered with [`PluginComponent::render()`].
+    pub fn enable_embedding(&self) -> Result<(), PluginError> {
+        if unsafe { (self.entry_points.set_embedded)() } {
+            Ok(())
+        } else {
+            Err(PluginError::EmbeddingUnsupported)
+        }
+    }
+
+    /// Runs the timers and animations of the plugin's run-time.
+    ///
+    /// When the plugin is embedded, its event loop doesn't run, so the host must call this regularly,
+    /// for example before rendering the components for a new frame.
+    pub fn update_timers_and_animations(&self) {
+        unsafe { (self.entry_points.update_timers_and_animations)() }
+    }
+
+    /// Runs the event loop of the plugin until its last window is closed.
+    ///
+    /// The windows of the components of the plugin are handled by this event loop.
+    pub fn run_event_loop(&self) -> Result<(), crate::PlatformError> {
+        if unsafe { (self.entry_points.run_event_loop)() } {
+            Ok(())
+        } else {
+            Err(crate::PlatformError::Other("The event loop of the plugin failed".into()))
+        }
+    }
+}
+
+/// A component instance created from a [`Plugin`]. The instance is destroyed when this is dropped.
+pub struct PluginComponent {
+    handle: *mut c_void,
+    entry_points: Rc<EntryPoints>,
+}
+
+impl Drop for PluginComponent {
+    fn drop(&mut self) {
+        unsafe { (self.entry_points.destroy)(self.handle) }
+    }
+}
+
+fn c_name(name: &str) -> Option<CString> {
+    CString::new(name).ok()
+}
+
+/// The state of a callback handler set with [`PluginComponent::on_callback()`]
+struct CallbackState {
+    handler: Box<dyn FnMut(&[Value]) -> Value>,
+    /// The last value returned, which must stay alive until the next call
+    last_result: Value,
+}
+
+unsafe extern "C" fn callback_trampoline(
+    user_data: *mut c_void,
+    args: *const c_abi::Value,
+    arg_count: usize,
+    result: *mut c_abi::Value,
+) {
+    let state = unsafe { &mut *(user_data as *mut CallbackState) };
+    let args = if arg_count == 0 {
+        Vec::new()
+    } else {
+        unsafe { core::slice::from_raw_parts(args, arg_count) }
+            .iter()
+            .map(Value::from_abi)
+            .collect()
+    };
+    state.last_result = (state.handler)(&args);
+    unsafe { result.write(state.last_result.to_abi()) };
+}
+
+unsafe extern "C" fn drop_callback_state(user_data: *mut c_void) {
+    drop(unsafe { Box::from_raw(user_data as *mut CallbackState) });
+}
+
+impl PluginComponent {
+    /// Shows the window of the component
+    pub fn show(&self) -> Result<(), crate::PlatformError> {
+        self.set_visible(true)
+    }
+
+    /// Hides the window of the component
+    pub fn hide(&self) -> Result<(), crate::PlatformError> {
+        self.set_visible(false)
+    }
+
+    fn set_visible(&self, visible: bool) -> Result<(), crate::PlatformError> {
+        if unsafe { (self.entry_points.show)(self.handle, visible) } {
+            Ok(())
+        } else {
+            Err(crate::PlatformError::Other("The plugin failed to change the visibility".into()))
+        }
+    }
+
+    /// Renders the component of an embedded plugin into an image of `size` physical pixels.
+    ///
+    /// The window of the component is resized to `size`, with the given scale factor. The result can
+    /// be shown in the host's scene with [`Image::from_rgba8()`](crate::Image::from_rgba8).
+    /// This requires [`Plugin::enable_embedding()`].
+    pub fn render(
+        &self,
+        size: crate::PhysicalSize,
+        scale_factor: f32,
+    ) -> Result<crate::SharedPixelBuffer<crate::Rgba8Pixel>, crate::PlatformError> {
+        let mut buffer =
+            crate::SharedPixelBuffer::<crate::Rgba8Pixel>::new(size.width, size.height);
+        let rendered = unsafe {
+            (self.entry_points.render)(
+                self.handle,
+                size.width,
+                size.height,
+                scale_factor,
+                buffer.make_mut_bytes().as_mut_ptr(),
+            )
+        };
+        if rendered {
+            Ok(buffer)
+        } else {
+            Err(crate::PlatformError::Other("The plugin failed to render the component".into()))
+        }
+    }
+
+    /// Forwards an input event of the host to the component of an embedded plugin.
+    ///
+    /// Positions are in the logical coordinates of the component. Pointer and key events are
+    /// supported; returns false for other events.
+    pub fn dispatch_event(&self, event: &crate::platform::WindowEvent) -> bool {
+        use crate::platform::{PointerEventButton, WindowEvent};
+        let pointer_button = |button: &PointerEventButton| match button {
+            PointerEventButton::Left => c_abi::POINTER_BUTTON_LEFT,
+            PointerEventButton::Right => c_abi::POINTER_BUTTON_RIGHT,
+            PointerEventButton::Middle => c_abi::POINTER_BUTTON_MIDDLE,
+            _ => c_abi::POINTER_BUTTON_OTHER,
+        };
+        let (kind, position, button, delta_x, delta_y) = match event {
+            WindowEvent::PointerMoved { position } => {
+                (c_abi::POINTER_EVENT_MOVED, *position, c_abi::POINTER_BUTTON_OTHER, 0., 0.)
+            }
+            WindowEvent::PointerPressed { position, button } => {
+                (c_abi::POINTER_EVENT_PRESSED, *position, pointer_button(button), 0., 0.)
+            }
+            WindowEvent::PointerReleased { position, button } => {
+                (c_abi::POINTER_EVENT_RELEASED, *position, pointer_button(button), 0., 0.)
+            }
+            WindowEvent::PointerExited => (
+                c_abi::POINTER_EVENT_EXITED,
+                Default::default(),
+                c_abi::POINTER_BUTTON_OTHER,
+                0.,
+                0.,
+            ),
+            WindowEvent::PointerScrolled { position, delta_x, delta_y } => (
+                c_abi::POINTER_EVENT_SCROLLED,
+                *position,
+                c_abi::POINTER_BUTTON_OTHER,
+                *delta_x,
+                *delta_y,
+            ),
+            WindowEvent::KeyPressed { text } | WindowEvent::KeyPressRepeated { text } => {
+                return self.dispatch_key_event(true, text);
+            }
+            WindowEvent::KeyReleased { text } => return self.dispatch_key_event(false, text),
+            _ => return false,
+        };
+        unsafe {
+            (self.entry_points.dispatch_pointer_event)(
+                self.handle,
+                kind,
+                position.x,
+                position.y,
+                button,
+                delta_x,
+                delta_y,
+            )
+        }
+    }
+
+    fn dispatch_key_event(&self, pressed: bool, text: &str) -> bool {
+        let Some(text) = c_name(text) else { return false };
+        unsafe { (self.entry_points.dispatch_key_event)(self.handle, pressed, text.as_ptr()) }
+    }
+
+    /// Returns the value of the property, or None if there is no such property
+    pub fn get_property(&self, name: &str) -> Option<Value> {
+        let name = c_name(name)?;
+        let mut out = c_abi::Value::void();
+        unsafe { (self.entry_points.get_property)(self.handle, name.as_ptr(), &mut out) }
+            .then(|| self.entry_points.take_value(out))
+    }
+
+    /// Sets the value of the property
+    pub fn set_property(&self, name: &str, value: Value) -> Result<(), SetPropertyError> {
+        let name = c_name(name).ok_or(SetPropertyError::NoSuchProperty)?;
+        let abi_value = value.to_abi();
+        if unsafe { (self.entry_points.set_property)(self.handle, name.as_ptr(), &abi_value) } {
+            Ok(())
+        } else if self.get_property(name.to_str().unwrap_or_default()).is_some() {
+            Err(SetPropertyError::WrongType)
+        } else {
+            Err(SetPropertyError::NoSuchProperty)
+        }
+    }
+
+    /// Invokes the callback or function with the given arguments and returns its result,
+    /// or None if there is no such callback or function, or if the arguments don't match.
+    pub fn invoke(&self, name: &str, args: &[Value]) -> Option<Value> {
+        let name = c_name(name)?;
+        let abi_args = args.iter().map(Value::to_abi).collect::<Vec<_>>();
+        let mut out = c_abi::Value::void();
+        unsafe {
+            (self.entry_points.invoke)(
+                self.handle,
+                name.as_ptr(),
+                abi_args.as_ptr(),
+                abi_args.len(),
+                &mut out,
+            )
+        }
+        .then(|| self.entry_points.take_value(out))
+    }
+
+    /// Sets the handler of the callback. Returns false if there is no such callback.
+    pub fn on_callback(
+        &self,
+        name: &str,
+        handler: impl FnMut(&[Value]) -> Value + 'static,
+    ) -> bool {
+        let Some(name) = c_name(name) else { return false };
+        let state = Box::into_raw(Box::new(CallbackState {
+            handler: Box::new(handler),
+            last_result: Value::Void,
+        }));
+        // On failure, the plugin calls drop_callback_state right away
+        unsafe {
+            (self.entry_points.set_callback)(
+                self.handle,
+                name.as_ptr(),
+                callback_trampoline,
+                state as *mut c_void,
+                Some(drop_callback_state),
+            )
+        }
+    }
+}
+
+#[test]
+fn test_parse_metadata() {
+    let components = parse_metadata(
+        r#"{"abi_version":1,"components":[{"name":"Main","properties":[{"name":"counter","type":"number","read_only":false},{"name":"label","type":"string","read_only":true}],"callbacks":[{"name":"clicked","args":["string","color"],"return":"bool"}],"functions":[]}]}"#,
+    )
+    .unwrap();
+    assert_eq!(components.len(), 1);
+    let main = &components[0];
+    assert_eq!(main.name(), "Main");
+    assert_eq!(main.properties().len(), 2);
+    assert_eq!(main.properties()[0].value_type(), ValueType::Number);
+    assert!(main.properties()[1].is_read_only());
+    assert_eq!(main.callbacks()[0].args(), &[ValueType::String, ValueType::Color]);
+    assert_eq!(main.callbacks()[0].return_type(), ValueType::Bool);
+    assert!(main.functions().is_empty());
+
+    assert!(matches!(parse_metadata("{"), Err(PluginError::InvalidMetadata(_))));
+    assert!(matches!(
+        parse_metadata(
+            r#"{"components":[{"name":"A","properties":[{"name":"x","type":"image"}]}]}"#
+        ),
+        Err(PluginError::InvalidMetadata(_))
+    ));
+}
+
+#[test]
+fn test_value_round_trip() {
+    for value in [
+        Value::Void,
+        Value::Number(42.5),
+        Value::Bool(true),
+        Value::String("héllo".into()),
+        Value::Color(Color::from_argb_u8(0x80, 1, 2, 3)),
+    ] {
+        assert_eq!(Value::from_abi(&value.to_abi()), value);
+    }
+}
@@ -127,6 +127,25 @@ pub fn set_callback_handler<
     })
 }
 
+pub fn subscribe_to_property<
+    T: Clone + PartialEq + 'static,
+    StrongRef: StrongItemTreeRef + 'static,
+>(
+    component_strong: &StrongRef,
+    getter: fn(StrongRef) -> T,
+    mut callback: impl FnMut(T) + 'static,
+) -> PropertySubscription {
+    PropertySubscription::new(
+        component_strong.to_weak(),
+        move |weak| <StrongRef as StrongItemTreeRef>::from_weak(weak).map(getter),
+        move |value: &Option<T>| {
+            if let Some(value) = value {
+                callback(value.clone())
+            }
+        },
+    )
+}
+
 pub fn debug(s: SharedString) {
     #[cfg(feature = "log")]
     log::debug!("{s}");
@@ -168,8 +187,12 @@ pub fn use_24_hour_format() -> bool {
     i_slint_core::date_time::use_24_hour_format()
 }
 
+#[cfg(feature = "std")]
+pub mod c_abi;
+
 /// internal re_exports used by the macro generated
 pub mod re_exports {
+    pub use crate::models::{read_field, FieldError, FromRecord, Record};
     pub use alloc::boxed::Box;
     pub use alloc::rc::{Rc, Weak};
     pub use alloc::string::String;
@@ -191,6 +214,7 @@ pub mod re_exports {
     pub use i_slint_core::callbacks::Callback;
     pub use i_slint_core::date_time::*;
     pub use i_slint_core::detect_operating_system;
+    pub use i_slint_core::fuzzy_match::fuzzy_filter_model;
     pub use i_slint_core::graphics::*;
     pub use i_slint_core::input::{
         key_codes::Key, FocusEvent, FocusReason, InputEventResult, KeyEvent, KeyEventResult,
@@ -211,17 +235,25 @@ pub mod re_exports {
     };
     pub use i_slint_core::menus::{Menu, MenuFromItemTree, MenuVTable};
     pub use i_slint_core::model::*;
+    pub use i_slint_core::process::{process_arguments, process_env, process_exit};
     pub use i_slint_core::properties::{
-        set_state_binding, ChangeTracker, Property, PropertyTracker, StateInfo,
+        set_state_binding, ChangeTracker, Property, PropertySubscription, PropertyTracker,
+        StateInfo,
     };
     pub use i_slint_core::slice::Slice;
     pub use i_slint_core::string::shared_string_from_number;
     pub use i_slint_core::string::shared_string_from_number_fixed;
     pub use i_slint_core::string::shared_string_from_number_precision;
+    pub use i_slint_core::system::{
+        set_system_tick_interval, system_battery_level, system_locale, system_network_online,
+        system_on_battery, system_tick_interval, system_time_of_day, system_time_zone,
+        system_unix_time, system_utc_offset,
+    };
     pub use i_slint_core::timers::{Timer, TimerMode};
     pub use i_slint_core::translations::{
         set_bundled_languages, translate_from_bundle, translate_from_bundle_with_plural,
     };
+    pub use i_slint_core::tsv::table_cells_to_tsv;
     pub use i_slint_core::window::{
         InputMethodRequest, WindowAdapter, WindowAdapterRc, WindowInner,
     };
@@ -0,0 +1,462 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Runtime support for the `extern "C"` entry points that the compiler generates when
+//! `CompilerConfiguration::generate_c_abi` is set.
+//!
+//! The types here must stay in sync with `api/cpp/include/slint_component_abi.h`, and any
+//! incompatible change must bump [`ABI_VERSION`].
+
+#![allow(unsafe_code)]
+
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::ffi::{c_char, c_void, CStr};
+use i_slint_core::api::PlatformError;
+use i_slint_core::graphics::Brush;
+use i_slint_core::{Color, SharedString};
+
+/// Version of the C ABI, returned by `slint_component_abi_version()`
+pub const ABI_VERSION: u32 = 2;
+
+pub const VALUE_KIND_VOID: u32 = 0;
+pub const VALUE_KIND_NUMBER: u32 = 1;
+pub const VALUE_KIND_BOOL: u32 = 2;
+pub const VALUE_KIND_STRING: u32 = 3;
+pub const VALUE_KIND_COLOR: u32 = 4;
+
+pub const POINTER_EVENT_MOVED: u32 = 0;
+pub const POINTER_EVENT_PRESSED: u32 = 1;
+pub const POINTER_EVENT_RELEASED: u32 = 2;
+pub const POINTER_EVENT_EXITED: u32 = 3;
+pub const POINTER_EVENT_SCROLLED: u32 = 4;
+
+pub const POINTER_BUTTON_OTHER: u32 = 0;
+pub const POINTER_BUTTON_LEFT: u32 = 1;
+pub const POINTER_BUTTON_RIGHT: u32 = 2;
+pub const POINTER_BUTTON_MIDDLE: u32 = 3;
+
+/// A value crossing the C ABI. This is `SlintAbiValue` in the C header.
+///
+/// Strings returned by the library are allocated by it, zero terminated, and must be released
+/// with `slint_component_value_free()`. Strings passed by the host stay owned by the host.
+#[repr(C)]
+pub struct Value {
+    pub kind: u32,
+    /// The color as `0xAARRGGBB`
+    pub color: u32,
+    pub number: f64,
+    pub string: *const c_char,
+    /// The length of `string` in bytes, without the terminating zero
+    pub string_len: usize,
+}
+
+impl Value {
+    pub fn void() -> Self {
+        Self {
+            kind: VALUE_KIND_VOID,
+            color: 0,
+            number: 0.,
+            string: core::ptr::null(),
+            string_len: 0,
+        }
+    }
+
+    fn string(&self) -> Option<&str> {
+        if self.kind != VALUE_KIND_STRING {
+            return None;
+        }
+        if self.string.is_null() {
+            return Some("");
+        }
+        // Safety: the caller of the entry points guarantees that the string is valid for string_len bytes
+        let bytes =
+            unsafe { core::slice::from_raw_parts(self.string as *const u8, self.string_len) };
+        core::str::from_utf8(bytes).ok()
+    }
+
+    /// Release the string if it was allocated by [`AbiValue::to_abi`]
+    ///
+    /// # Safety
+    /// The value must have been created by this library and not been released before.
+    pub unsafe fn free(&mut self) {
+        if self.kind == VALUE_KIND_STRING && !self.string.is_null() {
+            drop(Box::from_raw(core::ptr::slice_from_raw_parts_mut(
+                self.string as *mut u8,
+                self.string_len + 1,
+            )));
+        }
+        *self = Self::void();
+    }
+}
+
+/// Conversion between the Rust types of the generated code and [`Value`]
+pub trait AbiValue: Sized {
+    fn to_abi(self) -> Value;
+    fn from_abi(value: &Value) -> Option<Self>;
+}
+
+impl AbiValue for () {
+    fn to_abi(self) -> Value {
+        Value::void()
+    }
+    fn from_abi(_: &Value) -> Option<Self> {
+        Some(())
+    }
+}
+
+macro_rules! number_abi_value {
+    ($($ty:ty)*) => {$(
+        impl AbiValue for $ty {
+            fn to_abi(self) -> Value {
+                Value { kind: VALUE_KIND_NUMBER, number: self as f64, ..Value::void() }
+            }
+            fn from_abi(value: &Value) -> Option<Self> {
+                (value.kind == VALUE_KIND_NUMBER).then(|| value.number as $ty)
+            }
+        }
+    )*};
+}
+number_abi_value!(i32 i64 f32 f64);
+
+impl AbiValue for bool {
+    fn to_abi(self) -> Value {
+        Value { kind: VALUE_KIND_BOOL, number: self as i32 as f64, ..Value::void() }
+    }
+    fn from_abi(value: &Value) -> Option<Self> {
+        (value.kind == VALUE_KIND_BOOL).then_some(value.number != 0.)
+    }
+}
+
+impl AbiValue for SharedString {
+    fn to_abi(self) -> Value {
+        let mut bytes = Vec::with_capacity(self.len() + 1);
+        bytes.extend_from_slice(self.as_bytes());
+        bytes.push(0);
+        let string_len = self.len();
+        let string = Box::into_raw(bytes.into_boxed_slice()) as *const c_char;
+        Value { kind: VALUE_KIND_STRING, string, string_len, ..Value::void() }
+    }
+    fn from_abi(value: &Value) -> Option<Self> {
+        value.string().map(SharedString::from)
+    }
+}
+
+impl AbiValue for Color {
+    fn to_abi(self) -> Value {
+        Value { kind: VALUE_KIND_COLOR, color: self.as_argb_encoded(), ..Value::void() }
+    }
+    fn from_abi(value: &Value) -> Option<Self> {
+        (value.kind == VALUE_KIND_COLOR).then(|| Color::from_argb_encoded(value.color))
+    }
+}
+
+/// Brushes cross the ABI as their color. Gradients are reported with their first stop color.
+impl AbiValue for Brush {
+    fn to_abi(self) -> Value {
+        self.color().to_abi()
+    }
+    fn from_abi(value: &Value) -> Option<Self> {
+        Color::from_abi(value).map(Brush::SolidColor)
+    }
+}
+
+/// Signature of the handler that the host sets with `slint_component_set_callback()`
+pub type HandlerFn = unsafe extern "C" fn(
+    user_data: *mut c_void,
+    args: *const Value,
+    arg_count: usize,
+    result: *mut Value,
+);
+
+/// A callback handler set by the host, together with its user data.
+pub struct CallbackHandler {
+    handler: HandlerFn,
+    user_data: *mut c_void,
+    drop_user_data: Option<unsafe extern "C" fn(*mut c_void)>,
+}
+
+impl CallbackHandler {
+    /// Call the host's handler. The arguments are released after the call, and the result is
+    /// converted to `R`, or its default value if the host returned a value of the wrong kind.
+    pub fn call<R: AbiValue + Default>(&self, mut args: Vec<Value>) -> R {
+        let mut result = Value::void();
+        unsafe { (self.handler)(self.user_data, args.as_ptr(), args.len(), &mut result) };
+        for arg in args.iter_mut() {
+            unsafe { arg.free() };
+        }
+        R::from_abi(&result).unwrap_or_default()
+    }
+}
+
+impl Drop for CallbackHandler {
+    fn drop(&mut self) {
+        if let Some(drop_user_data) = self.drop_user_data {
+            unsafe { drop_user_data(self.user_data) };
+        }
+    }
+}
+
+/// Implemented by the generated code for each component exposed through the C ABI.
+///
+/// `name` is the name of the property, callback, or function as written in the `.slint` file.
+pub trait Component {
+    fn window(&self) -> &crate::Window;
+    fn show(&self) -> Result<(), PlatformError>;
+    fn hide(&self) -> Result<(), PlatformError>;
+    fn get_property(&self, name: &str) -> Option<Value>;
+    fn set_property(&self, name: &str, value: &Value) -> bool;
+    fn invoke(&self, name: &str, args: &[Value]) -> Option<Value>;
+    fn set_callback(&self, name: &str, handler: Rc<CallbackHandler>) -> bool;
+}
+
+/// An entry of the table of components that can be created by name
+pub struct ComponentEntry {
+    pub name: &'static str,
+    pub create: fn() -> Result<Box<dyn Component>, PlatformError>,
+}
+
+/// Read the `index`-th argument, or `None` if it doesn't have the expected kind
+pub fn arg<T: AbiValue>(args: &[Value], index: usize) -> Option<T> {
+    args.get(index).and_then(T::from_abi)
+}
+
+/// The handle passed to the host
+type Handle = *mut Box<dyn Component>;
+
+unsafe fn name<'a>(name: *const c_char) -> Option<&'a str> {
+    if name.is_null() {
+        return None;
+    }
+    CStr::from_ptr(name).to_str().ok()
+}
+
+unsafe fn component<'a>(handle: *mut c_void) -> Option<&'a dyn Component> {
+    (handle as Handle).as_ref().map(|c| &**c)
+}
+
+/// # Safety
+/// `component_name` must be null or a valid zero terminated string
+pub unsafe fn create(components: &[ComponentEntry], component_name: *const c_char) -> *mut c_void {
+    let Some(component_name) = name(component_name) else { return core::ptr::null_mut() };
+    let Some(entry) = components.iter().find(|c| c.name == component_name) else {
+        return core::ptr::null_mut();
+    };
+    match (entry.create)() {
+        Ok(component) => Box::into_raw(Box::new(component)) as *mut c_void,
+        Err(_) => core::ptr::null_mut(),
+    }
+}
+
+/// # Safety
+/// `handle` must be null or a handle returned by [`create`] that wasn't destroyed yet
+pub unsafe fn destroy(handle: *mut c_void) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle as Handle));
+    }
+}
+
+/// # Safety
+/// `handle` must be null or a valid handle returned by [`create`]
+pub unsafe fn show(handle: *mut c_void, visible: bool) -> bool {
+    component(handle).is_some_and(|c| if visible { c.show() } else { c.hide() }.is_ok())
+}
+
+pub fn run_event_loop() -> bool {
+    crate::run_event_loop().is_ok()
+}
+
+/// The platform used when the host embeds the components: windows aren't shown on the screen,
+/// but rendered into the buffers passed by the host with the software renderer.
+#[cfg(feature = "renderer-software")]
+struct EmbeddedPlatform;
+
+#[cfg(feature = "renderer-software")]
+impl crate::platform::Platform for EmbeddedPlatform {
+    fn create_window_adapter(
+        &self,
+    ) -> Result<Rc<dyn crate::platform::WindowAdapter>, PlatformError> {
+        use crate::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+        Ok(MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer))
+    }
+
+    fn run_event_loop(&self) -> Result<(), PlatformError> {
+        Err(PlatformError::Other(
+            "The components of an embedded library are driven by the event loop of the host".into(),
+        ))
+    }
+}
+
+/// Select the platform that renders the components into the host's buffers. Returns false
+/// if a platform was already set, or if the library was built without the software renderer.
+pub fn set_embedded() -> bool {
+    #[cfg(feature = "renderer-software")]
+    return crate::platform::set_platform(Box::new(EmbeddedPlatform)).is_ok();
+    #[cfg(not(feature = "renderer-software"))]
+    return false;
+}
+
+pub fn update_timers_and_animations() {
+    crate::platform::update_timers_and_animations()
+}
+
+/// # Safety
+/// `handle` must be null or a valid handle, and `buffer` null or valid for writes of
+/// `width * height * 4` bytes
+pub unsafe fn render(
+    handle: *mut c_void,
+    width: u32,
+    height: u32,
+    scale_factor: f32,
+    buffer: *mut u8,
+) -> bool {
+    let Some(c) = component(handle) else { return false };
+    if buffer.is_null() || width == 0 || height == 0 || scale_factor <= 0. {
+        return false;
+    }
+    let window = c.window();
+    if window.scale_factor() != scale_factor {
+        window.dispatch_event(crate::platform::WindowEvent::ScaleFactorChanged { scale_factor });
+    }
+    window.set_size(crate::PhysicalSize::new(width, height));
+    let Ok(snapshot) = window.take_snapshot() else { return false };
+
+    let buffer = core::slice::from_raw_parts_mut(buffer, width as usize * height as usize * 4);
+    buffer.fill(0);
+    let copied_width = snapshot.width().min(width) as usize;
+    for (target, source) in buffer
+        .chunks_exact_mut(width as usize * 4)
+        .zip(snapshot.as_bytes().chunks_exact(snapshot.width() as usize * 4))
+    {
+        target[..copied_width * 4].copy_from_slice(&source[..copied_width * 4]);
+    }
+    true
+}
+
+/// # Safety
+/// `handle` must be null or a valid handle
+pub unsafe fn dispatch_pointer_event(
+    handle: *mut c_void,
+    kind: u32,
+    x: f32,
+    y: f32,
+    button: u32,
+    delta_x: f32,
+    delta_y: f32,
+) -> bool {
+    use crate::platform::{PointerEventButton, WindowEvent};
+    let Some(c) = component(handle) else { return false };
+    let position = crate::LogicalPosition::new(x, y);
+    let button = match button {
+        POINTER_BUTTON_LEFT => PointerEventButton::Left,
+        POINTER_BUTTON_RIGHT => PointerEventButton::Right,
+        POINTER_BUTTON_MIDDLE => PointerEventButton::Middle,
+        _ => PointerEventButton::Other,
+    };
+    let event = match kind {
+        POINTER_EVENT_MOVED => WindowEvent::PointerMoved { position },
+        POINTER_EVENT_PRESSED => WindowEvent::PointerPressed { position, button },
+        POINTER_EVENT_RELEASED => WindowEvent::PointerReleased { position, button },
+        POINTER_EVENT_EXITED => WindowEvent::PointerExited,
+        POINTER_EVENT_SCROLLED => WindowEvent::PointerScrolled { position, delta_x, delta_y },
+        _ => return false,
+    };
+    c.window().dispatch_event(event);
+    true
+}
+
+/// # Safety
+/// `handle` must be null or a valid handle, and `text` null or a valid zero terminated string
+pub unsafe fn dispatch_key_event(handle: *mut c_void, pressed: bool, text: *const c_char) -> bool {
+    use crate::platform::WindowEvent;
+    let (Some(c), Some(text)) = (component(handle), name(text)) else { return false };
+    let text = SharedString::from(text);
+    c.window().dispatch_event(if pressed {
+        WindowEvent::KeyPressed { text }
+    } else {
+        WindowEvent::KeyReleased { text }
+    });
+    true
+}
+
+/// # Safety
+/// `handle` must be null or a valid handle, `property` null or a valid zero terminated string,
+/// and `out` null or valid for writes
+pub unsafe fn get_property(handle: *mut c_void, property: *const c_char, out: *mut Value) -> bool {
+    let (Some(c), Some(property)) = (component(handle), name(property)) else { return false };
+    if out.is_null() {
+        return false;
+    }
+    match c.get_property(property) {
+        Some(value) => {
+            out.write(value);
+            true
+        }
+        None => false,
+    }
+}
+
+/// # Safety
+/// `handle` must be null or a valid handle, `property` null or a valid zero terminated string,
+/// and `value` null or a valid value
+pub unsafe fn set_property(
+    handle: *mut c_void,
+    property: *const c_char,
+    value: *const Value,
+) -> bool {
+    let (Some(c), Some(property), Some(value)) =
+        (component(handle), name(property), value.as_ref())
+    else {
+        return false;
+    };
+    c.set_property(property, value)
+}
+
+/// # Safety
+/// `handle` must be null or a valid handle, `function` null or a valid zero terminated string,
+/// `args` valid for `arg_count` values, and `out` null or valid for writes
+pub unsafe fn invoke(
+    handle: *mut c_void,
+    function: *const c_char,
+    args: *const Value,
+    arg_count: usize,
+    out: *mut Value,
+) -> bool {
+    let (Some(c), Some(function)) = (component(handle), name(function)) else { return false };
+    let args = if arg_count == 0 { &[][..] } else { core::slice::from_raw_parts(args, arg_count) };
+    match c.invoke(function, args) {
+        Some(mut value) => {
+            if out.is_null() {
+                value.free();
+            } else {
+                out.write(value);
+            }
+            true
+        }
+        None => false,
+    }
+}
+
+/// # Safety
+/// `handle` must be null or a valid handle, and `callback` null or a valid zero terminated string.
+/// `handler` and `drop_user_data` must be safe to call with `user_data`.
+pub unsafe fn set_callback(
+    handle: *mut c_void,
+    callback: *const c_char,
+    handler: HandlerFn,
+    user_data: *mut c_void,
+    drop_user_data: Option<unsafe extern "C" fn(*mut c_void)>,
+) -> bool {
+    let handler = Rc::new(CallbackHandler { handler, user_data, drop_user_data });
+    let (Some(c), Some(callback)) = (component(handle), name(callback)) else { return false };
+    c.set_callback(callback, handler)
+}
+
+/// # Safety
+/// `value` must be null or a value returned by the library
+pub unsafe fn value_free(value: *mut Value) {
+    if let Some(value) = value.as_mut() {
+        value.free();
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::dialogs::{FileDialogKind, FileDialogOptions, FileFilter};
+use slint::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+use slint::platform::{PlatformError, WindowAdapter};
+use std::cell::RefCell;
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// The futures of the test platform are ready right away
+fn now_or_never<T>(future: impl Future<Output = T>) -> T {
+    let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+    match std::pin::pin!(future).poll(&mut cx) {
+        std::task::Poll::Ready(result) => result,
+        std::task::Poll::Pending => panic!("The future is not ready"),
+    }
+}
+
+struct TestPlatform {
+    window: Rc<MinimalSoftwareWindow>,
+    dialogs: Rc<RefCell<Vec<(FileDialogKind, FileDialogOptions)>>>,
+}
+
+impl slint::platform::Platform for TestPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(self.window.clone())
+    }
+
+    fn show_file_dialog(
+        &self,
+        kind: FileDialogKind,
+        options: FileDialogOptions,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<PathBuf>, PlatformError>>>> {
+        // The user cancels the save dialogs
+        let path = (kind != FileDialogKind::SaveFile)
+            .then(|| options.directory.clone().unwrap_or_default().join("selected"));
+        self.dialogs.borrow_mut().push((kind, options));
+        Box::pin(std::future::ready(Ok(path)))
+    }
+}
+
+struct PlatformWithoutDialogs(Rc<MinimalSoftwareWindow>);
+
+impl slint::platform::Platform for PlatformWithoutDialogs {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(self.0.clone())
+    }
+}
+
+#[test]
+fn file_dialogs() {
+    assert!(matches!(
+        now_or_never(slint::dialogs::open_file(Default::default())),
+        Err(PlatformError::NoPlatform)
+    ));
+
+    let window = MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer);
+    let dialogs = Rc::new(RefCell::new(Vec::new()));
+    slint::platform::set_platform(Box::new(TestPlatform {
+        window: window.clone(),
+        dialogs: dialogs.clone(),
+    }))
+    .unwrap();
+
+    let options = FileDialogOptions::default()
+        .with_title("Open Image")
+        .with_directory("/images")
+        .with_file_name("picture.png")
+        .with_filter("Images", &["png", "jpg"])
+        .with_parent(window.window());
+    assert_eq!(options.title, "Open Image");
+    assert_eq!(options.directory, Some(PathBuf::from("/images")));
+    assert_eq!(options.file_name.as_deref(), Some("picture.png"));
+    assert_eq!(
+        options.filters,
+        [FileFilter { name: "Images".into(), extensions: vec!["png".into(), "jpg".into()] }]
+    );
+    let parent = options.parent_window_adapter().unwrap();
+    assert!(std::ptr::eq(parent.window(), window.window()));
+    assert_eq!(options, options.clone());
+    assert_ne!(options, FileDialogOptions::default());
+    assert!(FileDialogOptions::default().parent_window_adapter().is_none());
+
+    assert_eq!(
+        now_or_never(slint::dialogs::open_file(options.clone())).unwrap(),
+        Some(PathBuf::from("/images/selected"))
+    );
+    assert_eq!(now_or_never(slint::dialogs::save_file(options.clone())).unwrap(), None);
+    assert_eq!(
+        now_or_never(slint::dialogs::pick_folder(FileDialogOptions::default())).unwrap(),
+        Some(PathBuf::from("selected"))
+    );
+    let dialogs = dialogs.borrow();
+    let kinds: Vec<_> = dialogs.iter().map(|(kind, _)| *kind).collect();
+    assert_eq!(
+        kinds,
+        [FileDialogKind::OpenFile, FileDialogKind::SaveFile, FileDialogKind::PickFolder]
+    );
+    assert_eq!(dialogs[0].1, options);
+
+    // The parent doesn't keep the window alive
+    drop(parent);
+    drop(window);
+    assert!(options.parent_window_adapter().is_none());
+
+    // Platforms that don't implement the dialogs report an error
+    std::thread::spawn(|| {
+        let window = MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer);
+        slint::platform::set_platform(Box::new(PlatformWithoutDialogs(window))).unwrap();
+        assert!(matches!(
+            now_or_never(slint::dialogs::pick_folder(Default::default())),
+            Err(PlatformError::Other(_))
+        ));
+    })
+    .join()
+    .unwrap();
+}
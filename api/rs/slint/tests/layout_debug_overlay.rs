@@ -0,0 +1,89 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+use slint::platform::{PlatformError, WindowAdapter};
+use slint::{ComponentHandle, PhysicalPosition, PhysicalSize, Rgb8Pixel};
+use std::rc::Rc;
+
+struct TestPlatform(Rc<MinimalSoftwareWindow>);
+
+impl slint::platform::Platform for TestPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(self.0.clone())
+    }
+}
+
+fn pixel(buffer: &[Rgb8Pixel], x: usize, y: usize) -> Rgb8Pixel {
+    buffer[y * 100 + x]
+}
+
+/// Returns the bounding box of the region that was redrawn, if any
+fn render(
+    window: &MinimalSoftwareWindow,
+    buffer: &mut [Rgb8Pixel],
+) -> Option<(PhysicalPosition, PhysicalSize)> {
+    let mut region = None;
+    window.draw_if_needed(|renderer| region = Some(renderer.render(buffer, 100)));
+    region.map(|region| (region.bounding_box_origin(), region.bounding_box_size()))
+}
+
+#[test]
+fn layout_debug_overlay() {
+    slint::slint! {
+        export component Ui inherits Window {
+            background: black;
+            HorizontalLayout {
+                x: 0;
+                y: 0;
+                width: 100phx;
+                height: 50phx;
+                padding: 10phx;
+                spacing: 20phx;
+                Rectangle { background: blue; }
+                Rectangle { background: blue; }
+            }
+            HorizontalLayout {
+                x: 0;
+                y: 60phx;
+                width: 100phx;
+                height: 40phx;
+                Rectangle { min-width: 50phx; max-width: 10phx; background: blue; }
+            }
+        }
+    }
+
+    let window = MinimalSoftwareWindow::new(RepaintBufferType::ReusedBuffer);
+    slint::platform::set_platform(Box::new(TestPlatform(window.clone()))).unwrap();
+    let ui = Ui::new().unwrap();
+    window.set_size(PhysicalSize::new(100, 100));
+    ui.show().unwrap();
+
+    let mut buffer = vec![Rgb8Pixel::default(); 100 * 100];
+    let full_window = Some((PhysicalPosition::new(0, 0), PhysicalSize::new(100, 100)));
+
+    assert_eq!(render(&window, &mut buffer), full_window);
+    assert_eq!(render(&window, &mut buffer), None);
+
+    // Toggling the overlay redraws the whole window, not only the items that changed
+    ui.window().set_layout_debug_overlay(true);
+    assert!(ui.window().layout_debug_overlay());
+    assert_eq!(render(&window, &mut buffer), full_window);
+
+    // The padding is shaded in green, the spacing in orange, and the children are left alone
+    let padding = pixel(&buffer, 5, 25);
+    assert!(padding.g > padding.r && padding.g > padding.b, "{padding:?}");
+    let spacing = pixel(&buffer, 50, 25);
+    assert!(spacing.r > spacing.g && spacing.g > spacing.b, "{spacing:?}");
+    assert_eq!(pixel(&buffer, 25, 25), Rgb8Pixel::new(0, 0, 0xff));
+    assert_eq!(pixel(&buffer, 75, 25), Rgb8Pixel::new(0, 0, 0xff));
+
+    // The second layout can't satisfy its constraints, but the first one can
+    assert_eq!(pixel(&buffer, 1, 80), Rgb8Pixel::new(0xff, 0, 0));
+    assert_ne!(pixel(&buffer, 1, 25), Rgb8Pixel::new(0xff, 0, 0));
+
+    ui.window().set_layout_debug_overlay(false);
+    assert_eq!(render(&window, &mut buffer), full_window);
+    assert_eq!(pixel(&buffer, 5, 25), Rgb8Pixel::new(0, 0, 0));
+    assert_eq!(pixel(&buffer, 50, 25), Rgb8Pixel::new(0, 0, 0));
+}
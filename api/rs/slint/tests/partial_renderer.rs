@@ -824,3 +824,45 @@ fn create_item_tree_during_rendering() {
         do_test_render_region(renderer, 12, 3, 22, 24);
     }));
 }
+
+#[test]
+fn render_scale() {
+    use slint::platform::software_renderer::RenderScale;
+
+    slint::slint! {
+        export component Ui inherits Window {
+            background: black;
+            Rectangle {
+                x: 2px;
+                y: 2px;
+                width: 2px;
+                height: 2px;
+                background: red;
+            }
+        }
+    }
+
+    slint::platform::set_platform(Box::new(TestPlatform)).ok();
+    let ui = Ui::new().unwrap();
+    let window = WINDOW.with(|x| x.clone());
+    window.set_size(slint::PhysicalSize::new(8, 8));
+    ui.show().unwrap();
+
+    for render_scale in [RenderScale::Supersample(2), RenderScale::PixelArt(2)] {
+        window.request_redraw();
+        assert!(window.draw_if_needed(|renderer| {
+            renderer.set_render_scale(render_scale);
+            let mut buffer = vec![PremultipliedRgbaColor::default(); 8 * 8];
+            let r = renderer.render(buffer.as_mut_slice(), 8);
+            assert_eq!(r.bounding_box_size(), PhysicalSize { width: 8, height: 8 });
+            for y in 0..8 {
+                for x in 0..8 {
+                    let p = buffer[y * 8 + x];
+                    let is_red = p.red == 255 && p.green == 0 && p.blue == 0;
+                    let inside = (2..4).contains(&x) && (2..4).contains(&y);
+                    assert_eq!(is_red, inside, "{render_scale:?} at {x},{y}: {p:?}");
+                }
+            }
+        }));
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+#![cfg(feature = "alloc-stats")]
+
+use slint::platform::alloc_stats::{self, CountingAllocator};
+use slint::platform::software_renderer::{
+    LineBufferProvider, MinimalSoftwareWindow, RepaintBufferType, Rgb565Pixel,
+};
+use slint::platform::{PlatformError, WindowAdapter};
+use slint::PhysicalSize;
+use std::rc::Rc;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator<std::alloc::System> =
+    CountingAllocator::new(std::alloc::System);
+
+struct TestPlatform(Rc<MinimalSoftwareWindow>);
+
+impl slint::platform::Platform for TestPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(self.0.clone())
+    }
+}
+
+struct LineBuffer<'a>(&'a mut [Rgb565Pixel]);
+
+impl LineBufferProvider for LineBuffer<'_> {
+    type TargetPixel = Rgb565Pixel;
+    fn process_line(
+        &mut self,
+        line: usize,
+        range: core::ops::Range<usize>,
+        render_fn: impl FnOnce(&mut [Self::TargetPixel]),
+    ) {
+        render_fn(&mut self.0[line * 100..][range]);
+    }
+}
+
+#[test]
+fn render_without_allocating() {
+    slint::slint! {
+        export component Ui inherits Window {
+            in property <bool> toggled;
+            background: black;
+            Rectangle {
+                x: 10px;
+                y: 10px;
+                width: 80px;
+                height: 80px;
+                background: toggled ? red : blue;
+                Rectangle {
+                    x: toggled ? 20px : 10px;
+                    y: 10px;
+                    width: 40px;
+                    height: 40px;
+                    border-radius: 8px;
+                    border-width: 2px;
+                    border-color: white;
+                    background: @linear-gradient(90deg, green 0%, yellow 100%);
+                }
+            }
+        }
+    }
+
+    let window = MinimalSoftwareWindow::new(RepaintBufferType::ReusedBuffer);
+    slint::platform::set_platform(Box::new(TestPlatform(window.clone()))).unwrap();
+    let ui = Ui::new().unwrap();
+    window.set_size(PhysicalSize::new(100, 100));
+    ui.show().unwrap();
+
+    let mut buffer = vec![Rgb565Pixel::default(); 100 * 100];
+
+    // The first frames fill the caches of the renderer and the nodes of the property dependencies
+    assert!(window.draw_if_needed(|renderer| {
+        renderer.reserve_scene_capacity(64, 8);
+        renderer.render_by_line(LineBuffer(&mut buffer));
+    }));
+    ui.set_toggled(true);
+    assert!(window.draw_if_needed(|renderer| {
+        renderer.render_by_line(LineBuffer(&mut buffer));
+    }));
+    alloc_stats::finish_initialization();
+
+    // The next frames reuse them
+    alloc_stats::assert_no_allocations(|| ui.set_toggled(false));
+    assert!(window.draw_if_needed(|renderer| {
+        renderer.render_by_line(LineBuffer(&mut buffer));
+    }));
+    assert_eq!(alloc_stats::last_frame().allocations, 0);
+
+    // Rendering into a buffer doesn't allocate either
+    alloc_stats::assert_no_allocations(|| ui.set_toggled(true));
+    assert!(window.draw_if_needed(|renderer| {
+        renderer.render(&mut buffer, 100);
+    }));
+    assert_eq!(alloc_stats::last_frame().allocations, 0);
+}
@@ -0,0 +1,173 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use i_slint_renderer_skia::skia_safe;
+use i_slint_renderer_skia::SkiaRenderer;
+use i_slint_renderer_skia::SkiaSharedContext;
+use slint::platform::{PlatformError, WindowAdapter};
+use slint::{PhysicalSize, RenderingState, Rgba8Pixel, SharedPixelBuffer};
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+
+struct TestPlatform(Rc<SkiaTestWindow>);
+
+impl slint::platform::Platform for TestPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(self.0.clone())
+    }
+}
+
+struct SkiaTestWindow {
+    window: slint::Window,
+    renderer: SkiaRenderer,
+    render_buffer: Rc<SkiaTestSoftwareBuffer>,
+    size: Cell<PhysicalSize>,
+}
+
+impl SkiaTestWindow {
+    fn new() -> Rc<Self> {
+        let render_buffer = Rc::new(SkiaTestSoftwareBuffer::default());
+        let renderer = SkiaRenderer::new_with_surface(
+            &SkiaSharedContext::default(),
+            Box::new(i_slint_renderer_skia::software_surface::SoftwareSurface::from(
+                render_buffer.clone(),
+            )),
+        );
+        Rc::new_cyclic(|w: &Weak<Self>| Self {
+            window: slint::Window::new(w.clone()),
+            renderer,
+            render_buffer,
+            size: Default::default(),
+        })
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> Rgba8Pixel {
+        let pixels = self.render_buffer.pixels.borrow();
+        let pixels = pixels.as_ref().unwrap();
+        pixels.as_slice()[y * pixels.width() as usize + x]
+    }
+}
+
+impl WindowAdapter for SkiaTestWindow {
+    fn window(&self) -> &slint::Window {
+        &self.window
+    }
+
+    fn size(&self) -> PhysicalSize {
+        self.size.get()
+    }
+
+    fn renderer(&self) -> &dyn slint::platform::Renderer {
+        &self.renderer
+    }
+
+    fn set_size(&self, size: slint::WindowSize) {
+        self.size.set(size.to_physical(1.));
+        self.window
+            .dispatch_event(slint::platform::WindowEvent::Resized { size: size.to_logical(1.) })
+    }
+}
+
+#[derive(Default)]
+struct SkiaTestSoftwareBuffer {
+    pixels: RefCell<Option<SharedPixelBuffer<Rgba8Pixel>>>,
+}
+
+impl i_slint_renderer_skia::software_surface::RenderBuffer for SkiaTestSoftwareBuffer {
+    fn with_buffer(
+        &self,
+        _window: &slint::Window,
+        size: PhysicalSize,
+        render_callback: &mut dyn FnMut(
+            std::num::NonZeroU32,
+            std::num::NonZeroU32,
+            skia_safe::ColorType,
+            u8,
+            &mut [u8],
+        ) -> Result<
+            Option<i_slint_core::partial_renderer::DirtyRegion>,
+            i_slint_core::platform::PlatformError,
+        >,
+    ) -> Result<(), i_slint_core::platform::PlatformError> {
+        let Some((width, height)): Option<(std::num::NonZeroU32, std::num::NonZeroU32)> =
+            size.width.try_into().ok().zip(size.height.try_into().ok())
+        else {
+            // Nothing to render
+            return Ok(());
+        };
+        let mut pixels = self.pixels.borrow_mut();
+        let pixels =
+            pixels.get_or_insert_with(|| SharedPixelBuffer::new(width.get(), height.get()));
+        let bytes = bytemuck::cast_slice_mut(pixels.make_mut_slice());
+        render_callback(width, height, skia_safe::ColorType::RGBA8888, 0, bytes)?;
+        Ok(())
+    }
+}
+
+fn fill(canvas: &skia_safe::Canvas, color: skia_safe::Color) {
+    let mut paint = skia_safe::Paint::default();
+    paint.set_color(color);
+    canvas.draw_rect(skia_safe::Rect::from_xywh(0., 0., 50., 50.), &paint);
+}
+
+#[test]
+fn canvas_rendering_notifier() {
+    slint::slint! {
+        export component Ui inherits Window {
+            background: black;
+        }
+    }
+
+    let window = SkiaTestWindow::new();
+    slint::platform::set_platform(Box::new(TestPlatform(window.clone()))).unwrap();
+    let _ui = Ui::new().unwrap();
+    window.set_size(PhysicalSize::new(100, 100).into());
+
+    let states = Rc::new(RefCell::new(Vec::new()));
+    let states_clone = states.clone();
+    let window_weak = Rc::downgrade(&window);
+    window.renderer.set_canvas_rendering_notifier(Some(Box::new(
+        move |state, canvas, scale_factor| {
+            assert_eq!(scale_factor, 1.);
+            states_clone.borrow_mut().push(state.clone());
+            if !matches!(state, RenderingState::AfterRendering) {
+                return;
+            }
+            fill(canvas, skia_safe::Color::RED);
+            // Replacing the callback from within the callback must not panic
+            let states = states_clone.clone();
+            let window = window_weak.upgrade().unwrap();
+            window.renderer.set_canvas_rendering_notifier(Some(Box::new(
+                move |state, canvas, _| {
+                    states.borrow_mut().push(state.clone());
+                    if matches!(state, RenderingState::AfterRendering) {
+                        fill(canvas, skia_safe::Color::GREEN);
+                    }
+                },
+            )));
+        },
+    )));
+
+    window.renderer.render().unwrap();
+    assert!(matches!(
+        states.borrow().as_slice(),
+        [RenderingState::BeforeRendering, RenderingState::AfterRendering]
+    ));
+    assert_eq!(window.pixel(10, 10), Rgba8Pixel::new(0xff, 0, 0, 0xff));
+    assert_eq!(window.pixel(60, 60), Rgba8Pixel::new(0, 0, 0, 0xff));
+
+    // The replaced callback is invoked for the next frame
+    states.borrow_mut().clear();
+    window.renderer.render().unwrap();
+    assert!(matches!(
+        states.borrow().as_slice(),
+        [RenderingState::BeforeRendering, RenderingState::AfterRendering]
+    ));
+    assert_eq!(window.pixel(10, 10), Rgba8Pixel::new(0, 0xff, 0, 0xff));
+
+    states.borrow_mut().clear();
+    window.renderer.set_canvas_rendering_notifier(None);
+    window.renderer.render().unwrap();
+    assert!(states.borrow().is_empty());
+    assert_eq!(window.pixel(10, 10), Rgba8Pixel::new(0, 0, 0, 0xff));
+}
@@ -82,6 +82,48 @@ fn main() {
     slint::run_event_loop().unwrap();
 }
 
+#[test]
+fn abort_and_join() {
+    use i_slint_core::SlintContext;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    struct SetOnDrop(Rc<Cell<bool>>);
+    impl Drop for SetOnDrop {
+        fn drop(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    let ctx = SlintContext::new(Box::new(i_slint_backend_testing::TestingBackend::new(
+        i_slint_backend_testing::TestingBackendOptions { mock_time: true, threading: true },
+    )));
+    let dropped = Rc::new(Cell::new(false));
+    let guard = SetOnDrop(dropped.clone());
+    let pending = ctx
+        .spawn_local(async move {
+            let _guard = guard;
+            std::future::pending::<i32>().await
+        })
+        .unwrap();
+    let abort_handle = pending.abort_handle();
+    let finished = ctx.spawn_local(async { 42 }).unwrap();
+    let proxy = ctx.event_loop_proxy().unwrap();
+    ctx.spawn_local(async move {
+        assert!(!pending.is_aborted());
+        assert_eq!(finished.join().await, Ok(42));
+        // Abort from another thread while this task awaits the handle
+        std::thread::spawn(move || abort_handle.abort()).join().unwrap();
+        assert!(pending.is_aborted());
+        // The aborted future is dropped, and the tasks awaiting it are woken up
+        assert!(pending.join().await.is_err());
+        assert!(dropped.get());
+        proxy.quit_event_loop().unwrap();
+    })
+    .unwrap();
+    ctx.run_event_loop().unwrap()
+}
+
 #[test]
 fn with_context() {
     use i_slint_core::SlintContext;
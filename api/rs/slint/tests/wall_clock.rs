@@ -0,0 +1,52 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType};
+use slint::platform::{PlatformError, WallClock, WindowAdapter};
+use std::rc::Rc;
+
+struct TestPlatform(Rc<MinimalSoftwareWindow>);
+
+impl slint::platform::Platform for TestPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(self.0.clone())
+    }
+}
+
+/// 2024-01-01 10:30:00 UTC, in a time zone one hour ahead
+struct FixedClock;
+
+impl WallClock for FixedClock {
+    fn unix_time_millis(&self) -> i64 {
+        1_704_105_000_000
+    }
+
+    fn utc_offset_seconds(&self, _unix_time_millis: i64) -> i32 {
+        3600
+    }
+
+    fn time_zone_name(&self) -> slint::SharedString {
+        "Europe/Berlin".into()
+    }
+}
+
+#[test]
+fn wall_clock_set_before_the_platform() {
+    slint::slint! {
+        export component Ui inherits Window {
+            out property <duration> unix-time: System.unix-time;
+            out property <duration> time-of-day: System.time-of-day;
+            out property <string> time-zone: System.time-zone;
+        }
+    }
+
+    // The clock is kept until Slint is initialized
+    slint::platform::set_wall_clock(Box::new(FixedClock));
+
+    let window = MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer);
+    slint::platform::set_platform(Box::new(TestPlatform(window))).unwrap();
+    let ui = Ui::new().unwrap();
+    assert_eq!(ui.get_unix_time(), 1_704_105_000_000);
+    assert_eq!(ui.get_time_of_day(), (11 * 3600 + 30 * 60) * 1000);
+    assert_eq!(ui.get_time_zone(), "Europe/Berlin");
+}
@@ -0,0 +1,309 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+# Slint models backed by SQLite
+
+This crate provides [`SqliteModel`], a [`Model`] that exposes the result of an SQLite query to
+Slint views such as `ListView` or `StandardTableView`.
+
+The rows are loaded lazily, by pages, when the view requests them, with [`slint::PagedModel`].
+This makes it possible to show tables of millions of rows. Edits done in the view can be written back
+to the database with a hook set with [`SqliteModel::set_write_back()`].
+
+```
+use slint::{Model, SharedString};
+use slint_sqlite::SqliteModel;
+
+#[derive(Clone)]
+struct Contact {
+    id: i64,
+    name: SharedString,
+}
+
+let connection = rusqlite::Connection::open_in_memory().unwrap();
+connection.execute_batch(
+    "CREATE TABLE contacts (id INTEGER PRIMARY KEY, name TEXT);
+     INSERT INTO contacts (name) VALUES ('Alice'), ('Bob');",
+).unwrap();
+
+let model = SqliteModel::new(connection, "SELECT id, name FROM contacts ORDER BY name", |row| {
+    Ok(Contact { id: row.get(0)?, name: row.get::<_, String>(1)?.into() })
+})
+.unwrap();
+model.set_write_back(|connection, contact| {
+    connection
+        .execute("UPDATE contacts SET name = ?1 WHERE id = ?2", (contact.name.as_str(), contact.id))
+        .map(drop)
+});
+
+assert_eq!(model.row_count(), 2);
+assert_eq!(model.row_data(1).unwrap().name, "Bob");
+
+// Insert a row, and reload the model
+model.execute("INSERT INTO contacts (name) VALUES ('Carol')", ()).unwrap();
+assert_eq!(model.row_count(), 3);
+```
+*/
+
+#![warn(missing_docs)]
+#![deny(unsafe_code)]
+
+use rusqlite::{Connection, Params, Row};
+use slint::{Model, ModelTracker, PagedModel, PagedSource};
+use std::cell::RefCell;
+use std::ops::Range;
+
+type RowMapper<T> = Box<dyn Fn(&Row<'_>) -> rusqlite::Result<T>>;
+type WriteBack<T> = Box<dyn Fn(&Connection, &T) -> rusqlite::Result<()>>;
+
+/// The [`PagedSource`] of a [`SqliteModel`], that runs the query by pages.
+pub struct SqliteQuery<T> {
+    connection: Connection,
+    query: String,
+    map_row: RowMapper<T>,
+    write_back: RefCell<Option<WriteBack<T>>>,
+    last_error: RefCell<Option<rusqlite::Error>>,
+}
+
+impl<T> SqliteQuery<T> {
+    /// Returns the connection to the database
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
+    /// Returns the query whose result is exposed by the model
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    fn record_error<R>(&self, result: rusqlite::Result<R>) -> Option<R> {
+        result.map_err(|err| *self.last_error.borrow_mut() = Some(err)).ok()
+    }
+}
+
+impl<T: Clone> PagedSource for SqliteQuery<T> {
+    type Data = T;
+
+    fn row_count(&self) -> usize {
+        let count = self
+            .connection
+            .prepare_cached(&format!("SELECT COUNT(*) FROM ({})", self.query))
+            .and_then(|mut statement| statement.query_row((), |row| row.get::<_, i64>(0)));
+        self.record_error(count).unwrap_or_default() as usize
+    }
+
+    fn fetch_rows(&self, offset: usize, count: usize) -> Vec<T> {
+        let rows = self
+            .connection
+            .prepare_cached(&format!("SELECT * FROM ({}) LIMIT ?1 OFFSET ?2", self.query))
+            .and_then(|mut statement| {
+                statement
+                    .query_map((count as i64, offset as i64), |row| (self.map_row)(row))?
+                    .collect::<rusqlite::Result<Vec<_>>>()
+            });
+        self.record_error(rows).unwrap_or_default()
+    }
+
+    fn write_row(&self, _row: usize, data: &T) -> bool {
+        let write_back = self.write_back.borrow();
+        let Some(write_back) = write_back.as_ref() else { return false };
+        self.record_error(write_back(&self.connection, data)).is_some()
+    }
+}
+
+/// A [`Model`] of the rows returned by an SQLite query.
+///
+/// The query is run by pages with `LIMIT` and `OFFSET` clauses when the rows are requested by the view,
+/// so it should have an `ORDER BY` clause for the order of the rows to be stable.
+/// `map_row` converts a row of the result into the data of the model.
+///
+/// The model isn't notified when the database changes. Call [`Self::refresh()`] after modifying the
+/// database, or [`Self::rows_changed()`], [`Self::rows_inserted()`] and [`Self::rows_removed()`] to only
+/// reload what changed. [`Self::execute()`] runs a statement and refreshes the model.
+///
+/// Errors that occur while loading the rows are not reported by the model, which then appears empty
+/// or with missing rows. Use [`Self::take_error()`] to retrieve the last error.
+pub struct SqliteModel<T: Clone>(PagedModel<SqliteQuery<T>>);
+
+impl<T: Clone + 'static> SqliteModel<T> {
+    /// Creates a model of the rows returned by `query` on `connection`.
+    ///
+    /// Returns an error if the query is invalid.
+    pub fn new(
+        connection: Connection,
+        query: impl Into<String>,
+        map_row: impl Fn(&Row<'_>) -> rusqlite::Result<T> + 'static,
+    ) -> rusqlite::Result<Self> {
+        let query = query.into();
+        connection.prepare_cached(&format!("SELECT COUNT(*) FROM ({query})"))?;
+        Ok(Self(PagedModel::new(SqliteQuery {
+            connection,
+            query,
+            map_row: Box::new(map_row),
+            write_back: Default::default(),
+            last_error: Default::default(),
+        })))
+    }
+
+    /// Sets the hook called when the view modifies a row with [`Model::set_row_data()`], to write
+    /// it back to the database.
+    ///
+    /// The row is only modified in the model if the hook succeeds.
+    /// Without this hook, the model is read-only.
+    pub fn set_write_back(
+        &self,
+        write_back: impl Fn(&Connection, &T) -> rusqlite::Result<()> + 'static,
+    ) {
+        *self.source().write_back.borrow_mut() = Some(Box::new(write_back));
+    }
+
+    /// Returns the connection to the database
+    pub fn connection(&self) -> &Connection {
+        self.source().connection()
+    }
+
+    /// Returns the [`SqliteQuery`] that loads the rows
+    pub fn source(&self) -> &SqliteQuery<T> {
+        self.0.source()
+    }
+
+    /// Returns the underlying [`PagedModel`], for example to change the page size
+    pub fn paged_model(&self) -> &PagedModel<SqliteQuery<T>> {
+        &self.0
+    }
+
+    /// Executes an SQL statement on the connection and refreshes the model.
+    ///
+    /// Returns the number of rows that were changed.
+    pub fn execute(&self, sql: &str, params: impl Params) -> rusqlite::Result<usize> {
+        let changed = self.connection().execute(sql, params)?;
+        self.refresh();
+        Ok(changed)
+    }
+
+    /// Runs the query again and notifies the views.
+    pub fn refresh(&self) {
+        self.0.refresh();
+    }
+
+    /// Reloads the rows in `range` and notifies the views that they changed.
+    pub fn rows_changed(&self, range: Range<usize>) {
+        self.0.rows_changed(range);
+    }
+
+    /// Notifies the views that `count` rows were inserted in the result of the query at `index`.
+    pub fn rows_inserted(&self, index: usize, count: usize) {
+        self.0.rows_inserted(index, count);
+    }
+
+    /// Notifies the views that `count` rows were removed from the result of the query at `index`.
+    pub fn rows_removed(&self, index: usize, count: usize) {
+        self.0.rows_removed(index, count);
+    }
+
+    /// Returns the last error that occurred while loading or writing back rows, and clears it.
+    pub fn take_error(&self) -> Option<rusqlite::Error> {
+        self.source().last_error.borrow_mut().take()
+    }
+}
+
+impl<T: Clone + 'static> Model for SqliteModel<T> {
+    type Data = T;
+
+    fn row_count(&self) -> usize {
+        self.0.row_count()
+    }
+
+    fn row_data(&self, row: usize) -> Option<T> {
+        self.0.row_data(row)
+    }
+
+    fn set_row_data(&self, row: usize, data: T) {
+        self.0.set_row_data(row, data)
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        self.0.model_tracker()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers_model(count: i64) -> SqliteModel<(i64, String)> {
+        let connection = Connection::open_in_memory().unwrap();
+        connection.execute("CREATE TABLE numbers (value INTEGER, label TEXT)", ()).unwrap();
+        for value in 0..count {
+            connection
+                .execute("INSERT INTO numbers VALUES (?1, ?2)", (value, format!("#{value}")))
+                .unwrap();
+        }
+        SqliteModel::new(connection, "SELECT value, label FROM numbers ORDER BY value", |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn paged_query() {
+        let model = numbers_model(250);
+        model.paged_model().set_page_size(20);
+        assert_eq!(model.row_count(), 250);
+        assert_eq!(model.row_data(0), Some((0, "#0".into())));
+        assert_eq!(model.row_data(137), Some((137, "#137".into())));
+        assert_eq!(model.row_data(249), Some((249, "#249".into())));
+        assert_eq!(model.row_data(250), None);
+        assert!(model.take_error().is_none());
+    }
+
+    #[test]
+    fn write_back_and_refresh() {
+        let model = numbers_model(10);
+        // Read-only without hook
+        model.set_row_data(3, (3, "three".into()));
+        assert_eq!(model.row_data(3), Some((3, "#3".into())));
+
+        model.set_write_back(|connection, (value, label)| {
+            connection
+                .execute("UPDATE numbers SET label = ?1 WHERE value = ?2", (label, value))
+                .map(drop)
+        });
+        model.set_row_data(3, (3, "three".into()));
+        assert_eq!(model.row_data(3), Some((3, "three".into())));
+        let label: String = model
+            .connection()
+            .query_row("SELECT label FROM numbers WHERE value = 3", (), |row| row.get(0))
+            .unwrap();
+        assert_eq!(label, "three");
+
+        model
+            .connection()
+            .execute("UPDATE numbers SET label = 'four' WHERE value = 4", ())
+            .unwrap();
+        assert_eq!(model.row_data(4), Some((4, "#4".into())));
+        model.rows_changed(4..5);
+        assert_eq!(model.row_data(4), Some((4, "four".into())));
+
+        assert_eq!(model.execute("DELETE FROM numbers WHERE value < 5", ()).unwrap(), 5);
+        assert_eq!(model.row_count(), 5);
+        assert_eq!(model.row_data(0), Some((5, "#5".into())));
+
+        model.connection().execute("DROP TABLE numbers", ()).unwrap();
+        model.refresh();
+        assert_eq!(model.row_count(), 0);
+        assert!(model.take_error().is_some());
+    }
+
+    #[test]
+    fn invalid_query() {
+        let connection = Connection::open_in_memory().unwrap();
+        assert!(SqliteModel::new(connection, "SELECT * FROM nothing", |row| row.get::<_, i64>(0))
+            .is_err());
+    }
+}
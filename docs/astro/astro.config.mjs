@@ -174,6 +174,8 @@ export default defineConfig({
                                         label: "Overview",
                                         slug: "guide/backends-and-renderers/backends_and_renderers",
                                     },
+                                    "guide/backends-and-renderers/backend_headless",
+                                    "guide/backends-and-renderers/backend_layershell",
                                     "guide/backends-and-renderers/backend_linuxkms",
                                     "guide/backends-and-renderers/backend_qt",
                                     "guide/backends-and-renderers/backend_winit",
@@ -304,6 +306,14 @@ export default defineConfig({
                                         label: "Platform Namespace",
                                         slug: "reference/global-namespaces/platform",
                                     },
+                                    {
+                                        label: "System Namespace",
+                                        slug: "reference/global-namespaces/system",
+                                    },
+                                    {
+                                        label: "Process Namespace",
+                                        slug: "reference/global-namespaces/process",
+                                    },
                                 ],
                             },
                             {
@@ -315,6 +315,7 @@ function getHelpUrlForElement(
         Rectangle: "elements/rectangle",
         // gestures
         Flickable: "gestures/flickable",
+        PinchGestureHandler: "gestures/pinchgesturehandler",
         SwipeGestureHandler: "gestures/swipegesturehandler",
         TouchArea: "gestures/toucharea",
         // keyboard-input
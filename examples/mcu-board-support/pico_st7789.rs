@@ -198,6 +198,7 @@ pub fn init() {
         buffer_provider: buffer_provider.into(),
         touch: touch.into(),
         backlight: Some(backlight).into(),
+        frame_throttle: slint::platform::FrameThrottle::new(core::time::Duration::from_millis(16)),
     }))
     .expect("backend already initialized");
 }
@@ -207,6 +208,7 @@ struct PicoBackend<DrawBuffer, Touch, Backlight> {
     buffer_provider: RefCell<DrawBuffer>,
     touch: RefCell<Touch>,
     backlight: RefCell<Option<Backlight>>,
+    frame_throttle: slint::platform::FrameThrottle,
 }
 
 impl<
@@ -242,6 +244,10 @@ fn duration_since_start(&self) -> core::time::Duration {
         core::time::Duration::from_micros(counter)
     }
 
+    fn request_redraw_at(&self, deadline: core::time::Duration) {
+        self.frame_throttle.request_redraw_at(deadline);
+    }
+
     fn run_event_loop(&self) -> Result<(), slint::PlatformError> {
         let mut last_touch = None;
 
@@ -251,14 +257,16 @@ fn run_event_loop(&self) -> Result<(), slint::PlatformError> {
             slint::platform::update_timers_and_animations();
 
             if let Some(window) = self.window.borrow().clone() {
-                window.draw_if_needed(|renderer| {
-                    let mut buffer_provider = self.buffer_provider.borrow_mut();
-                    renderer.render_by_line(&mut *buffer_provider);
-                    buffer_provider.flush_frame();
-                    if let Some(mut backlight) = self.backlight.take() {
-                        backlight.set_high().unwrap();
-                    }
-                });
+                if self.frame_throttle.begin_frame() {
+                    window.draw_if_needed(|renderer| {
+                        let mut buffer_provider = self.buffer_provider.borrow_mut();
+                        renderer.render_by_line(&mut *buffer_provider);
+                        buffer_provider.flush_frame();
+                        if let Some(mut backlight) = self.backlight.take() {
+                            backlight.set_high().unwrap();
+                        }
+                    });
+                }
 
                 // handle touch event
                 let button = PointerEventButton::Left;
@@ -297,13 +305,9 @@ fn run_event_loop(&self) -> Result<(), slint::PlatformError> {
                     // Don't go to sleep after a touch event that forces a redraw
                     continue;
                 }
-
-                if window.has_active_animations() {
-                    continue;
-                }
             }
 
-            let sleep_duration = match slint::platform::duration_until_next_timer_update() {
+            let sleep_duration = match self.frame_throttle.time_until_next_frame() {
                 None => None,
                 Some(d) => {
                     let micros = d.as_micros() as u32;
@@ -0,0 +1,672 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the connection to the Wayland compositor, the event loop and the input handling
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
+
+use i_slint_core::api::LogicalPosition;
+use i_slint_core::platform::{PlatformError, PointerEventButton, WindowEvent};
+use i_slint_core::window::WindowAdapter;
+use i_slint_core::SharedString;
+use smithay_client_toolkit::compositor::{CompositorHandler, CompositorState};
+use smithay_client_toolkit::output::{OutputHandler, OutputState};
+use smithay_client_toolkit::reexports::calloop::{self, EventLoop, LoopHandle};
+use smithay_client_toolkit::reexports::calloop_wayland_source::WaylandSource;
+use smithay_client_toolkit::reexports::client::globals::registry_queue_init;
+use smithay_client_toolkit::reexports::client::protocol::{
+    wl_keyboard, wl_output, wl_pointer, wl_seat, wl_surface,
+};
+use smithay_client_toolkit::reexports::client::{Connection, QueueHandle};
+use smithay_client_toolkit::registry::{ProvidesRegistryState, RegistryState};
+use smithay_client_toolkit::seat::keyboard::{KeyEvent, KeyboardHandler, Keysym, Modifiers};
+use smithay_client_toolkit::seat::pointer::{
+    CursorIcon, PointerEvent, PointerEventKind, PointerHandler, ThemeSpec, ThemedPointer,
+};
+use smithay_client_toolkit::seat::{Capability, SeatHandler, SeatState};
+use smithay_client_toolkit::shell::wlr_layer::{
+    LayerShell, LayerShellHandler, LayerSurface, LayerSurfaceConfigure,
+};
+use smithay_client_toolkit::shell::WaylandSurface;
+use smithay_client_toolkit::shm::{Shm, ShmHandler};
+use smithay_client_toolkit::{
+    delegate_compositor, delegate_keyboard, delegate_layer, delegate_output, delegate_pointer,
+    delegate_registry, delegate_seat, delegate_shm, registry_handlers,
+};
+
+use crate::layersurfacewindowadapter::LayerSurfaceWindowAdapter;
+use crate::BackendBuilder;
+
+#[derive(Clone)]
+struct Proxy {
+    loop_signal: calloop::LoopSignal,
+    quit_loop: Arc<AtomicBool>,
+    user_event_channel: Arc<Mutex<calloop::channel::Sender<Box<dyn FnOnce() + Send>>>>,
+}
+
+impl i_slint_core::platform::EventLoopProxy for Proxy {
+    fn quit_event_loop(&self) -> Result<(), i_slint_core::api::EventLoopError> {
+        self.quit_loop.store(true, std::sync::atomic::Ordering::Release);
+        self.loop_signal.wakeup();
+        Ok(())
+    }
+
+    fn invoke_from_event_loop(
+        &self,
+        event: Box<dyn FnOnce() + Send>,
+    ) -> Result<(), i_slint_core::api::EventLoopError> {
+        let user_event_channel = self.user_event_channel.lock().unwrap();
+        user_event_channel
+            .send(event)
+            .map_err(|_| i_slint_core::api::EventLoopError::EventLoopTerminated)
+    }
+}
+
+/// The Wayland objects that the window adapters need to create their layer surfaces.
+pub struct SharedState {
+    pub compositor: CompositorState,
+    pub layer_shell: LayerShell,
+    pub queue_handle: QueueHandle<WaylandState>,
+    windows: RefCell<Vec<Weak<LayerSurfaceWindowAdapter>>>,
+}
+
+impl SharedState {
+    fn windows(&self) -> Vec<Rc<LayerSurfaceWindowAdapter>> {
+        let mut windows = self.windows.borrow_mut();
+        windows.retain(|window| window.strong_count() > 0);
+        windows.iter().filter_map(Weak::upgrade).collect()
+    }
+
+    fn window_for_surface(
+        &self,
+        surface: &wl_surface::WlSurface,
+    ) -> Option<Rc<LayerSurfaceWindowAdapter>> {
+        self.windows().into_iter().find(|window| window.has_wl_surface(surface))
+    }
+}
+
+/// The state that the Wayland event handlers operate on.
+pub struct WaylandState {
+    registry_state: RegistryState,
+    seat_state: SeatState,
+    output_state: OutputState,
+    shm: Shm,
+    shared_state: Rc<SharedState>,
+    loop_handle: LoopHandle<'static, WaylandState>,
+    keyboard: Option<wl_keyboard::WlKeyboard>,
+    pointer: Option<ThemedPointer>,
+    keyboard_focus: Option<Rc<LayerSurfaceWindowAdapter>>,
+    callbacks_to_invoke_per_iteration: Vec<Box<dyn FnOnce() + Send>>,
+    /// The first error returned while dispatching an event to a window, which ends the event loop.
+    error: Option<PlatformError>,
+}
+
+impl WaylandState {
+    fn dispatch_event(&mut self, window: &LayerSurfaceWindowAdapter, event: WindowEvent) {
+        if let Err(err) = window.window().try_dispatch_event(event) {
+            self.error.get_or_insert(err);
+        }
+    }
+
+    fn dispatch_key_event(&mut self, event: KeyEvent, action: KeyAction) {
+        let Some(window) = self.keyboard_focus.clone() else { return };
+        let Some(event) = key_window_event(event.keysym, action) else { return };
+        self.dispatch_event(&window, event);
+    }
+}
+
+pub struct Backend {
+    connection: Connection,
+    shared_state: Rc<SharedState>,
+    event_loop: RefCell<Option<(EventLoop<'static, WaylandState>, WaylandState)>>,
+    proxy: Proxy,
+    sel_clipboard: RefCell<Option<String>>,
+    clipboard: RefCell<Option<String>>,
+}
+
+impl Backend {
+    pub fn build(builder: BackendBuilder) -> Result<Self, PlatformError> {
+        match builder.renderer_name.as_deref() {
+            None | Some("software") => {}
+            Some(renderer_name) => {
+                eprintln!(
+                    "slint layershell backend: unrecognized renderer {}, falling back to the software renderer",
+                    renderer_name
+                );
+            }
+        }
+
+        let connection = Connection::connect_to_env()
+            .map_err(|e| format!("Error connecting to the Wayland compositor: {e}"))?;
+        let (globals, event_queue) = registry_queue_init::<WaylandState>(&connection)
+            .map_err(|e| format!("Error retrieving the Wayland globals: {e}"))?;
+        let queue_handle = event_queue.handle();
+
+        let compositor = CompositorState::bind(&globals, &queue_handle)
+            .map_err(|e| format!("The compositor doesn't support wl_compositor: {e}"))?;
+        let layer_shell = LayerShell::bind(&globals, &queue_handle).map_err(|e| {
+            format!("The compositor doesn't support the wlr layer shell protocol: {e}")
+        })?;
+        let shm = Shm::bind(&globals, &queue_handle)
+            .map_err(|e| format!("The compositor doesn't support wl_shm: {e}"))?;
+
+        let event_loop: EventLoop<'static, WaylandState> =
+            EventLoop::try_new().map_err(|e| format!("Error creating event loop: {}", e))?;
+
+        WaylandSource::new(connection.clone(), event_queue)
+            .insert(event_loop.handle())
+            .map_err(|e| format!("Error registering the Wayland event source: {e}"))?;
+
+        let (user_event_sender, user_event_receiver) = calloop::channel::channel();
+        event_loop
+            .handle()
+            .insert_source(user_event_receiver, |event, _, state: &mut WaylandState| {
+                let calloop::channel::Event::Msg(callback) = event else { return };
+                // Remember the callbacks and invoke them after updating the animation tick
+                state.callbacks_to_invoke_per_iteration.push(callback);
+            })
+            .map_err(
+                |e: calloop::InsertError<calloop::channel::Channel<Box<dyn FnOnce() + Send>>>| {
+                    format!("Error registering user event channel source: {e}")
+                },
+            )?;
+
+        let shared_state = Rc::new(SharedState {
+            compositor,
+            layer_shell,
+            queue_handle: queue_handle.clone(),
+            windows: Default::default(),
+        });
+
+        let state = WaylandState {
+            registry_state: RegistryState::new(&globals),
+            seat_state: SeatState::new(&globals, &queue_handle),
+            output_state: OutputState::new(&globals, &queue_handle),
+            shm,
+            shared_state: shared_state.clone(),
+            loop_handle: event_loop.handle(),
+            keyboard: None,
+            pointer: None,
+            keyboard_focus: None,
+            callbacks_to_invoke_per_iteration: Vec::new(),
+            error: None,
+        };
+
+        let proxy = Proxy {
+            loop_signal: event_loop.get_signal(),
+            quit_loop: Arc::new(AtomicBool::new(false)),
+            user_event_channel: Arc::new(Mutex::new(user_event_sender)),
+        };
+
+        Ok(Backend {
+            connection,
+            shared_state,
+            event_loop: RefCell::new(Some((event_loop, state))),
+            proxy,
+            sel_clipboard: Default::default(),
+            clipboard: Default::default(),
+        })
+    }
+}
+
+impl i_slint_core::platform::Platform for Backend {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        let adapter = LayerSurfaceWindowAdapter::new(self.shared_state.clone());
+        self.shared_state.windows.borrow_mut().push(Rc::downgrade(&adapter));
+        Ok(adapter)
+    }
+
+    fn run_event_loop(&self) -> Result<(), PlatformError> {
+        let Some((mut event_loop, mut state)) = self.event_loop.borrow_mut().take() else {
+            return Err("Re-entering the layershell event loop is currently not supported".into());
+        };
+
+        let quit_loop = self.proxy.quit_loop.clone();
+        quit_loop.store(false, std::sync::atomic::Ordering::Release);
+
+        let result = (|| {
+            while !quit_loop.load(std::sync::atomic::Ordering::Acquire) {
+                i_slint_core::platform::update_timers_and_animations();
+
+                // Only after updating the animation tick, invoke callbacks from invoke_from_event_loop(). They
+                // might set animated properties, which requires an up-to-date start time.
+                for callback in std::mem::take(&mut state.callbacks_to_invoke_per_iteration) {
+                    callback();
+                }
+
+                for window in self.shared_state.windows() {
+                    window.render_if_needed(&state.shm)?;
+                }
+
+                let next_timeout = i_slint_core::platform::duration_until_next_timer_update();
+                event_loop
+                    .dispatch(next_timeout, &mut state)
+                    .map_err(|e| format!("Error dispatch events: {e}"))?;
+
+                if let Some(err) = state.error.take() {
+                    return Err(err);
+                }
+            }
+            Ok(())
+        })();
+
+        // Flush the requests of the windows that were hidden when quitting
+        let _ = self.connection.flush();
+        *self.event_loop.borrow_mut() = Some((event_loop, state));
+        result
+    }
+
+    fn new_event_loop_proxy(&self) -> Option<Box<dyn i_slint_core::platform::EventLoopProxy>> {
+        Some(Box::new(self.proxy.clone()))
+    }
+
+    fn clipboard_text(&self, clipboard: i_slint_core::platform::Clipboard) -> Option<String> {
+        match clipboard {
+            i_slint_core::platform::Clipboard::DefaultClipboard => self.clipboard.borrow().clone(),
+            i_slint_core::platform::Clipboard::SelectionClipboard => {
+                self.sel_clipboard.borrow().clone()
+            }
+            _ => None,
+        }
+    }
+    fn set_clipboard_text(&self, text: &str, clipboard: i_slint_core::platform::Clipboard) {
+        match clipboard {
+            i_slint_core::platform::Clipboard::DefaultClipboard => {
+                *self.clipboard.borrow_mut() = Some(text.into())
+            }
+            i_slint_core::platform::Clipboard::SelectionClipboard => {
+                *self.sel_clipboard.borrow_mut() = Some(text.into())
+            }
+            _ => (),
+        }
+    }
+}
+
+pub fn with_window_adapter<R>(
+    window: &i_slint_core::api::Window,
+    callback: impl FnOnce(&LayerSurfaceWindowAdapter) -> R,
+) -> Option<R> {
+    i_slint_core::window::WindowInner::from_pub(window)
+        .window_adapter()
+        .internal(i_slint_core::InternalToken)
+        .and_then(|wa| wa.as_any().downcast_ref::<LayerSurfaceWindowAdapter>())
+        .map(callback)
+}
+
+impl CompositorHandler for WaylandState {
+    fn scale_factor_changed(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        surface: &wl_surface::WlSurface,
+        new_factor: i32,
+    ) {
+        let Some(window) = self.shared_state.window_for_surface(surface) else { return };
+        if let Err(err) = window.set_buffer_scale(new_factor) {
+            self.error.get_or_insert(err);
+        }
+    }
+
+    fn transform_changed(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _surface: &wl_surface::WlSurface,
+        _new_transform: wl_output::Transform,
+    ) {
+    }
+
+    fn frame(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        surface: &wl_surface::WlSurface,
+        _time: u32,
+    ) {
+        if let Some(window) = self.shared_state.window_for_surface(surface) {
+            window.frame_done();
+        }
+    }
+
+    fn surface_enter(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _surface: &wl_surface::WlSurface,
+        _output: &wl_output::WlOutput,
+    ) {
+    }
+
+    fn surface_leave(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _surface: &wl_surface::WlSurface,
+        _output: &wl_output::WlOutput,
+    ) {
+    }
+}
+
+impl OutputHandler for WaylandState {
+    fn output_state(&mut self) -> &mut OutputState {
+        &mut self.output_state
+    }
+
+    fn new_output(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _output: wl_output::WlOutput,
+    ) {
+    }
+
+    fn update_output(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _output: wl_output::WlOutput,
+    ) {
+    }
+
+    fn output_destroyed(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _output: wl_output::WlOutput,
+    ) {
+    }
+}
+
+impl LayerShellHandler for WaylandState {
+    fn closed(&mut self, _conn: &Connection, _qh: &QueueHandle<Self>, layer: &LayerSurface) {
+        let Some(window) = self.shared_state.window_for_surface(layer.wl_surface()) else {
+            return;
+        };
+        if let Err(err) = window.closed() {
+            self.error.get_or_insert(err);
+        }
+    }
+
+    fn configure(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        layer: &LayerSurface,
+        configure: LayerSurfaceConfigure,
+        _serial: u32,
+    ) {
+        let Some(window) = self.shared_state.window_for_surface(layer.wl_surface()) else {
+            return;
+        };
+        if let Err(err) = window.configure(configure.new_size) {
+            self.error.get_or_insert(err);
+        }
+    }
+}
+
+impl SeatHandler for WaylandState {
+    fn seat_state(&mut self) -> &mut SeatState {
+        &mut self.seat_state
+    }
+
+    fn new_seat(&mut self, _conn: &Connection, _qh: &QueueHandle<Self>, _seat: wl_seat::WlSeat) {}
+
+    fn new_capability(
+        &mut self,
+        _conn: &Connection,
+        qh: &QueueHandle<Self>,
+        seat: wl_seat::WlSeat,
+        capability: Capability,
+    ) {
+        if capability == Capability::Keyboard && self.keyboard.is_none() {
+            let keyboard = self.seat_state.get_keyboard_with_repeat(
+                qh,
+                &seat,
+                None,
+                self.loop_handle.clone(),
+                Box::new(|state, _keyboard, event| {
+                    state.dispatch_key_event(event, KeyAction::Repeat)
+                }),
+            );
+            match keyboard {
+                Ok(keyboard) => self.keyboard = Some(keyboard),
+                Err(err) => eprintln!("slint layershell backend: Error creating keyboard: {err}"),
+            }
+        }
+        if capability == Capability::Pointer && self.pointer.is_none() {
+            let pointer = self.seat_state.get_pointer_with_theme(
+                qh,
+                &seat,
+                self.shm.wl_shm(),
+                self.shared_state.compositor.create_surface(qh),
+                ThemeSpec::default(),
+            );
+            match pointer {
+                Ok(pointer) => self.pointer = Some(pointer),
+                Err(err) => eprintln!("slint layershell backend: Error creating pointer: {err}"),
+            }
+        }
+    }
+
+    fn remove_capability(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _seat: wl_seat::WlSeat,
+        capability: Capability,
+    ) {
+        if capability == Capability::Keyboard {
+            if let Some(keyboard) = self.keyboard.take() {
+                keyboard.release();
+            }
+        }
+        if capability == Capability::Pointer {
+            if let Some(pointer) = self.pointer.take() {
+                pointer.pointer().release();
+            }
+        }
+    }
+
+    fn remove_seat(&mut self, _conn: &Connection, _qh: &QueueHandle<Self>, _seat: wl_seat::WlSeat) {
+    }
+}
+
+impl KeyboardHandler for WaylandState {
+    fn enter(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _keyboard: &wl_keyboard::WlKeyboard,
+        surface: &wl_surface::WlSurface,
+        _serial: u32,
+        _raw: &[u32],
+        _keysyms: &[Keysym],
+    ) {
+        self.keyboard_focus = self.shared_state.window_for_surface(surface);
+        if let Some(window) = self.keyboard_focus.clone() {
+            self.dispatch_event(&window, WindowEvent::WindowActiveChanged(true));
+        }
+    }
+
+    fn leave(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _keyboard: &wl_keyboard::WlKeyboard,
+        _surface: &wl_surface::WlSurface,
+        _serial: u32,
+    ) {
+        if let Some(window) = self.keyboard_focus.take() {
+            self.dispatch_event(&window, WindowEvent::WindowActiveChanged(false));
+        }
+    }
+
+    fn press_key(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _keyboard: &wl_keyboard::WlKeyboard,
+        _serial: u32,
+        event: KeyEvent,
+    ) {
+        self.dispatch_key_event(event, KeyAction::Press);
+    }
+
+    fn release_key(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _keyboard: &wl_keyboard::WlKeyboard,
+        _serial: u32,
+        event: KeyEvent,
+    ) {
+        self.dispatch_key_event(event, KeyAction::Release);
+    }
+
+    fn update_modifiers(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _keyboard: &wl_keyboard::WlKeyboard,
+        _serial: u32,
+        _modifiers: Modifiers,
+        _layout: u32,
+    ) {
+        // Slint tracks the modifiers from the key events of the modifier keys
+    }
+}
+
+impl PointerHandler for WaylandState {
+    fn pointer_frame(
+        &mut self,
+        conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _pointer: &wl_pointer::WlPointer,
+        events: &[PointerEvent],
+    ) {
+        for event in events {
+            let Some(window) = self.shared_state.window_for_surface(&event.surface) else {
+                continue;
+            };
+            let position = LogicalPosition::new(event.position.0 as f32, event.position.1 as f32);
+            let window_event = match event.kind {
+                PointerEventKind::Enter { .. } => {
+                    if let Some(pointer) = self.pointer.as_ref() {
+                        let _ = pointer.set_cursor(conn, CursorIcon::Default);
+                    }
+                    WindowEvent::PointerMoved { position }
+                }
+                PointerEventKind::Motion { .. } => WindowEvent::PointerMoved { position },
+                PointerEventKind::Leave { .. } => WindowEvent::PointerExited,
+                PointerEventKind::Press { button, .. } => {
+                    WindowEvent::PointerPressed { position, button: map_pointer_button(button) }
+                }
+                PointerEventKind::Release { button, .. } => {
+                    WindowEvent::PointerReleased { position, button: map_pointer_button(button) }
+                }
+                PointerEventKind::Axis { horizontal, vertical, .. } => {
+                    WindowEvent::PointerScrolled {
+                        position,
+                        delta_x: -horizontal.absolute as f32,
+                        delta_y: -vertical.absolute as f32,
+                    }
+                }
+            };
+            self.dispatch_event(&window, window_event);
+        }
+    }
+}
+
+impl ShmHandler for WaylandState {
+    fn shm_state(&mut self) -> &mut Shm {
+        &mut self.shm
+    }
+}
+
+impl ProvidesRegistryState for WaylandState {
+    fn registry(&mut self) -> &mut RegistryState {
+        &mut self.registry_state
+    }
+
+    registry_handlers![OutputState, SeatState];
+}
+
+delegate_compositor!(WaylandState);
+delegate_output!(WaylandState);
+delegate_shm!(WaylandState);
+delegate_seat!(WaylandState);
+delegate_keyboard!(WaylandState);
+delegate_pointer!(WaylandState);
+delegate_layer!(WaylandState);
+delegate_registry!(WaylandState);
+
+/// Maps the Linux input event codes of the pointer buttons.
+fn map_pointer_button(button: u32) -> PointerEventButton {
+    match button {
+        0x110 => PointerEventButton::Left,
+        0x111 => PointerEventButton::Right,
+        0x112 => PointerEventButton::Middle,
+        0x113 => PointerEventButton::Back,
+        0x114 => PointerEventButton::Forward,
+        _ => PointerEventButton::Other,
+    }
+}
+
+/// How a key event was produced
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum KeyAction {
+    Press,
+    /// The key is held down, sent at the repeat rate of the compositor
+    Repeat,
+    Release,
+}
+
+fn key_window_event(sym: Keysym, action: KeyAction) -> Option<WindowEvent> {
+    let text = map_key_sym(sym)?;
+    Some(match action {
+        KeyAction::Press => WindowEvent::KeyPressed { text },
+        KeyAction::Repeat => WindowEvent::KeyPressRepeated { text },
+        KeyAction::Release => WindowEvent::KeyReleased { text },
+    })
+}
+
+fn map_key_sym(sym: Keysym) -> Option<SharedString> {
+    macro_rules! keysym_to_string {
+        ($($char:literal # $name:ident # $($_qt:ident)|* # $($_winit:ident $(($_pos:ident))?)|* # $($xkb:ident)|*;)*) => {
+            match(sym) {
+                $($(Keysym::$xkb => $char,)*)*
+                _ => sym.key_char()?,
+            }
+        };
+    }
+    let char = i_slint_common::for_each_special_keys!(keysym_to_string);
+    Some(char.into())
+}
+
+#[test]
+fn test_key_window_event() {
+    use i_slint_core::platform::Key;
+    assert_eq!(
+        key_window_event(Keysym::a, KeyAction::Press),
+        Some(WindowEvent::KeyPressed { text: "a".into() })
+    );
+    assert_eq!(
+        key_window_event(Keysym::BackSpace, KeyAction::Repeat),
+        Some(WindowEvent::KeyPressRepeated { text: Key::Backspace.into() })
+    );
+    assert_eq!(
+        key_window_event(Keysym::Shift_L, KeyAction::Release),
+        Some(WindowEvent::KeyReleased { text: Key::Shift.into() })
+    );
+}
+
+#[test]
+fn test_map_pointer_button() {
+    assert_eq!(map_pointer_button(0x110), PointerEventButton::Left);
+    assert_eq!(map_pointer_button(0x111), PointerEventButton::Right);
+    assert_eq!(map_pointer_button(0x112), PointerEventButton::Middle);
+    assert_eq!(map_pointer_button(0x200), PointerEventButton::Other);
+}
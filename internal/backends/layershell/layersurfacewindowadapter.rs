@@ -0,0 +1,364 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the window adapter that shows a Slint window in a layer surface, rendered with the software renderer
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use i_slint_core::api::{LogicalSize, PhysicalSize as PhysicalWindowSize};
+use i_slint_core::platform::{PlatformError, WindowEvent};
+use i_slint_core::software_renderer::{
+    PremultipliedRgbaColor, RepaintBufferType, SoftwareRenderer, TargetPixel,
+};
+use i_slint_core::window::{WindowAdapter, WindowAdapterInternal, WindowProperties};
+use smithay_client_toolkit::reexports::client::protocol::{wl_shm, wl_surface::WlSurface};
+use smithay_client_toolkit::shell::wlr_layer::{self, LayerSurface};
+use smithay_client_toolkit::shell::WaylandSurface;
+use smithay_client_toolkit::shm::slot::{Buffer, SlotPool};
+use smithay_client_toolkit::shm::Shm;
+
+use crate::calloop_backend::SharedState;
+use crate::{Anchor, KeyboardInteractivity, Layer, LayerSurfaceSettings};
+
+/// A pixel of a `wl_shm` buffer in the `Argb8888` format, which is premultiplied.
+#[repr(transparent)]
+#[derive(Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+struct ShmPixelArgb8888(pub u32);
+
+impl From<ShmPixelArgb8888> for PremultipliedRgbaColor {
+    #[inline]
+    fn from(pixel: ShmPixelArgb8888) -> Self {
+        let v = pixel.0;
+        PremultipliedRgbaColor {
+            red: (v >> 16) as u8,
+            green: (v >> 8) as u8,
+            blue: (v >> 0) as u8,
+            alpha: (v >> 24) as u8,
+        }
+    }
+}
+
+impl From<PremultipliedRgbaColor> for ShmPixelArgb8888 {
+    #[inline]
+    fn from(pixel: PremultipliedRgbaColor) -> Self {
+        Self(
+            (pixel.alpha as u32) << 24
+                | ((pixel.red as u32) << 16)
+                | ((pixel.green as u32) << 8)
+                | (pixel.blue as u32),
+        )
+    }
+}
+
+impl TargetPixel for ShmPixelArgb8888 {
+    fn blend(&mut self, color: PremultipliedRgbaColor) {
+        let mut x = PremultipliedRgbaColor::from(*self);
+        x.blend(color);
+        *self = x.into();
+    }
+
+    fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self(0xff000000 | ((r as u32) << 16) | ((g as u32) << 8) | (b as u32))
+    }
+
+    fn background() -> Self {
+        Self(0)
+    }
+}
+
+impl From<Layer> for wlr_layer::Layer {
+    fn from(layer: Layer) -> Self {
+        match layer {
+            Layer::Background => wlr_layer::Layer::Background,
+            Layer::Bottom => wlr_layer::Layer::Bottom,
+            Layer::Top => wlr_layer::Layer::Top,
+            Layer::Overlay => wlr_layer::Layer::Overlay,
+        }
+    }
+}
+
+impl From<Anchor> for wlr_layer::Anchor {
+    fn from(anchor: Anchor) -> Self {
+        let mut result = wlr_layer::Anchor::empty();
+        result.set(wlr_layer::Anchor::TOP, anchor.top);
+        result.set(wlr_layer::Anchor::BOTTOM, anchor.bottom);
+        result.set(wlr_layer::Anchor::LEFT, anchor.left);
+        result.set(wlr_layer::Anchor::RIGHT, anchor.right);
+        result
+    }
+}
+
+impl From<KeyboardInteractivity> for wlr_layer::KeyboardInteractivity {
+    fn from(keyboard_interactivity: KeyboardInteractivity) -> Self {
+        match keyboard_interactivity {
+            KeyboardInteractivity::None => wlr_layer::KeyboardInteractivity::None,
+            KeyboardInteractivity::Exclusive => wlr_layer::KeyboardInteractivity::Exclusive,
+            KeyboardInteractivity::OnDemand => wlr_layer::KeyboardInteractivity::OnDemand,
+        }
+    }
+}
+
+pub struct LayerSurfaceWindowAdapter {
+    window: i_slint_core::api::Window,
+    renderer: SoftwareRenderer,
+    shared_state: Rc<SharedState>,
+    settings: RefCell<LayerSurfaceSettings>,
+    /// The layer surface while the window is visible.
+    layer_surface: RefCell<Option<LayerSurface>>,
+    pool: RefCell<Option<SlotPool>>,
+    /// The buffer of the last frame, which is re-used for the next frame once the compositor released it.
+    buffer: RefCell<Option<Buffer>>,
+    /// The preferred size of the window, in logical pixels.
+    preferred_size: Cell<(u32, u32)>,
+    /// The size of the surface in logical pixels, as configured by the compositor.
+    configured_size: Cell<Option<(u32, u32)>>,
+    buffer_scale: Cell<i32>,
+    redraw_requested: Cell<bool>,
+    frame_callback_pending: Cell<bool>,
+}
+
+impl LayerSurfaceWindowAdapter {
+    pub fn new(shared_state: Rc<SharedState>) -> Rc<Self> {
+        Rc::new_cyclic(|self_weak| Self {
+            window: i_slint_core::api::Window::new(self_weak.clone()),
+            renderer: SoftwareRenderer::new(),
+            shared_state,
+            settings: Default::default(),
+            layer_surface: Default::default(),
+            pool: Default::default(),
+            buffer: Default::default(),
+            preferred_size: Cell::new((1, 1)),
+            configured_size: Default::default(),
+            buffer_scale: Cell::new(1),
+            redraw_requested: Cell::new(true),
+            frame_callback_pending: Cell::new(false),
+        })
+    }
+
+    pub fn settings(&self) -> LayerSurfaceSettings {
+        self.settings.borrow().clone()
+    }
+
+    pub fn set_settings(&self, settings: LayerSurfaceSettings) -> Result<(), PlatformError> {
+        let old_settings = self.settings.replace(settings);
+        let layer_surface = self.layer_surface.borrow();
+        let Some(layer_surface) = layer_surface.as_ref() else { return Ok(()) };
+        let settings = self.settings.borrow();
+        if settings.layer == old_settings.layer && settings.namespace == old_settings.namespace {
+            self.configure_layer_surface(layer_surface, &settings);
+            layer_surface.commit();
+            return Ok(());
+        }
+        // The namespace can't be changed, and the layer only with version 2 of the protocol, so
+        // replace the surface
+        drop(settings);
+        drop(layer_surface);
+        self.destroy_layer_surface();
+        self.create_layer_surface();
+        Ok(())
+    }
+
+    pub fn has_wl_surface(&self, surface: &WlSurface) -> bool {
+        self.layer_surface.borrow().as_ref().is_some_and(|s| s.wl_surface() == surface)
+    }
+
+    fn create_layer_surface(&self) {
+        let settings = self.settings.borrow();
+        let surface = self.shared_state.compositor.create_surface(&self.shared_state.queue_handle);
+        surface.set_buffer_scale(self.buffer_scale.get());
+        let layer_surface = self.shared_state.layer_shell.create_layer_surface(
+            &self.shared_state.queue_handle,
+            surface,
+            settings.layer.into(),
+            Some(settings.namespace.clone()),
+            None,
+        );
+        self.configure_layer_surface(&layer_surface, &settings);
+        // The initial commit without a buffer makes the compositor send the first configure event
+        layer_surface.commit();
+        *self.layer_surface.borrow_mut() = Some(layer_surface);
+    }
+
+    fn destroy_layer_surface(&self) {
+        self.layer_surface.borrow_mut().take();
+        self.buffer.borrow_mut().take();
+        self.configured_size.set(None);
+        self.frame_callback_pending.set(false);
+    }
+
+    fn configure_layer_surface(
+        &self,
+        layer_surface: &LayerSurface,
+        settings: &LayerSurfaceSettings,
+    ) {
+        let (preferred_width, preferred_height) = self.preferred_size.get();
+        let anchor = settings.anchor;
+        // A size of 0 lets the compositor stretch the surface between the anchored edges
+        let width = if anchor.left && anchor.right { 0 } else { preferred_width };
+        let height = if anchor.top && anchor.bottom { 0 } else { preferred_height };
+        layer_surface.set_size(width, height);
+        layer_surface.set_anchor(anchor.into());
+        layer_surface.set_exclusive_zone(settings.exclusive_zone);
+        let margins = settings.margins;
+        layer_surface.set_margin(margins.top, margins.right, margins.bottom, margins.left);
+        layer_surface.set_keyboard_interactivity(settings.keyboard_interactivity.into());
+    }
+
+    /// Called when the compositor configured the size of the surface, in logical pixels.
+    pub fn configure(&self, (width, height): (u32, u32)) -> Result<(), PlatformError> {
+        let (preferred_width, preferred_height) = self.preferred_size.get();
+        let width = if width == 0 { preferred_width } else { width };
+        let height = if height == 0 { preferred_height } else { height };
+        if self.configured_size.replace(Some((width, height))) != Some((width, height)) {
+            self.window.try_dispatch_event(WindowEvent::Resized {
+                size: LogicalSize::new(width as f32, height as f32),
+            })?;
+        }
+        self.request_redraw();
+        Ok(())
+    }
+
+    /// Called when the compositor closed the surface, for example because its output was removed.
+    pub fn closed(&self) -> Result<(), PlatformError> {
+        self.destroy_layer_surface();
+        self.window.try_dispatch_event(WindowEvent::CloseRequested)
+    }
+
+    pub fn set_buffer_scale(&self, scale: i32) -> Result<(), PlatformError> {
+        if self.buffer_scale.replace(scale) == scale {
+            return Ok(());
+        }
+        if let Some(layer_surface) = self.layer_surface.borrow().as_ref() {
+            layer_surface.wl_surface().set_buffer_scale(scale);
+        }
+        self.window
+            .try_dispatch_event(WindowEvent::ScaleFactorChanged { scale_factor: scale as f32 })?;
+        self.request_redraw();
+        Ok(())
+    }
+
+    pub fn frame_done(&self) {
+        self.frame_callback_pending.set(false);
+    }
+
+    pub fn render_if_needed(&self, shm: &Shm) -> Result<(), PlatformError> {
+        if self.frame_callback_pending.get() || !self.redraw_requested.get() {
+            return Ok(());
+        }
+        let layer_surface = self.layer_surface.borrow();
+        let Some(layer_surface) = layer_surface.as_ref() else { return Ok(()) };
+        if self.configured_size.get().is_none() {
+            // Nothing can be attached to the surface before the first configure event
+            return Ok(());
+        }
+        self.redraw_requested.set(false);
+
+        let size = self.size();
+        let (width, height) = (size.width as i32, size.height as i32);
+        let stride = width * 4;
+
+        let mut pool = self.pool.borrow_mut();
+        let pool = match pool.as_mut() {
+            Some(pool) => pool,
+            None => pool.insert(
+                SlotPool::new((stride * height) as usize, shm)
+                    .map_err(|e| format!("Error creating the shared memory pool: {e}"))?,
+            ),
+        };
+
+        let mut buffer = self.buffer.borrow_mut();
+        let reuse_buffer = buffer.as_ref().is_some_and(|buffer| {
+            buffer.height() == height && buffer.stride() == stride && buffer.canvas(pool).is_some()
+        });
+        if !reuse_buffer {
+            let (new_buffer, _) = pool
+                .create_buffer(width, height, stride, wl_shm::Format::Argb8888)
+                .map_err(|e| format!("Error creating a buffer for the layer surface: {e}"))?;
+            *buffer = Some(new_buffer);
+        }
+        let buffer = buffer.as_ref().unwrap();
+        let canvas = buffer.canvas(pool).ok_or("The buffer of the layer surface is in use")?;
+
+        self.renderer.set_repaint_buffer_type(if reuse_buffer {
+            RepaintBufferType::ReusedBuffer
+        } else {
+            RepaintBufferType::NewBuffer
+        });
+        let region = self.renderer.render(bytemuck::cast_slice_mut(canvas), width as usize);
+
+        let surface = layer_surface.wl_surface();
+        if reuse_buffer {
+            for (position, size) in region.iter() {
+                surface.damage_buffer(position.x, position.y, size.width as _, size.height as _);
+            }
+        } else {
+            surface.damage_buffer(0, 0, width, height);
+        }
+        surface.frame(&self.shared_state.queue_handle, surface.clone());
+        buffer
+            .attach_to(surface)
+            .map_err(|e| format!("Error attaching the buffer to the layer surface: {e}"))?;
+        layer_surface.commit();
+        self.frame_callback_pending.set(true);
+
+        // Render the next frame of the animations once the compositor is ready for it
+        if self.window.has_active_animations() {
+            self.redraw_requested.set(true);
+        }
+        Ok(())
+    }
+}
+
+impl WindowAdapter for LayerSurfaceWindowAdapter {
+    fn window(&self) -> &i_slint_core::api::Window {
+        &self.window
+    }
+
+    fn size(&self) -> PhysicalWindowSize {
+        let (width, height) = self.configured_size.get().unwrap_or(self.preferred_size.get());
+        let scale = self.buffer_scale.get() as u32;
+        PhysicalWindowSize::new(width * scale, height * scale)
+    }
+
+    fn renderer(&self) -> &dyn i_slint_core::renderer::Renderer {
+        &self.renderer
+    }
+
+    fn request_redraw(&self) {
+        self.redraw_requested.set(true)
+    }
+
+    fn set_visible(&self, visible: bool) -> Result<(), PlatformError> {
+        let is_visible = self.layer_surface.borrow().is_some();
+        if visible && !is_visible {
+            self.create_layer_surface();
+        } else if !visible && is_visible {
+            self.destroy_layer_surface();
+        }
+        Ok(())
+    }
+
+    fn update_window_properties(&self, properties: WindowProperties<'_>) {
+        let preferred = properties.layout_constraints().preferred;
+        let preferred_size =
+            (preferred.width.ceil().max(1.) as u32, preferred.height.ceil().max(1.) as u32);
+        if self.preferred_size.replace(preferred_size) == preferred_size {
+            return;
+        }
+        if let Some(layer_surface) = self.layer_surface.borrow().as_ref() {
+            self.configure_layer_surface(layer_surface, &self.settings.borrow());
+            layer_surface.commit();
+        }
+    }
+
+    fn internal(&self, _: i_slint_core::InternalToken) -> Option<&dyn WindowAdapterInternal> {
+        Some(self)
+    }
+}
+
+impl WindowAdapterInternal for LayerSurfaceWindowAdapter {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+#![doc = include_str!("README.md")]
+#![doc(html_logo_url = "https://slint.dev/logo/slint-logo-square-light.svg")]
+
+#[cfg(target_os = "linux")]
+mod layersurfacewindowadapter;
+
+#[cfg(target_os = "linux")]
+mod calloop_backend;
+
+#[cfg(target_os = "linux")]
+use calloop_backend::*;
+
+#[cfg(not(target_os = "linux"))]
+mod noop_backend;
+use i_slint_core::api::PlatformError;
+#[cfg(not(target_os = "linux"))]
+use noop_backend::*;
+
+/// The layer of a layer surface. The compositor stacks the surfaces of the upper layers above
+/// the surfaces of the lower layers, and the normal windows between the bottom and top layers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Layer {
+    /// Below the desktop background, for example for wallpapers.
+    Background,
+    /// Below the normal windows, for example for desktop widgets.
+    Bottom,
+    /// Above the normal windows, for example for panels and bars.
+    #[default]
+    Top,
+    /// Above the full screen windows, for example for on-screen displays and lock screens.
+    Overlay,
+}
+
+/// The edges of the output that a layer surface is anchored to.
+///
+/// A surface that is anchored to two opposite edges is stretched between them. A surface that is
+/// anchored to neither of two opposite edges is centered between them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Anchor {
+    pub top: bool,
+    pub bottom: bool,
+    pub left: bool,
+    pub right: bool,
+}
+
+/// How a layer surface receives the keyboard focus.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum KeyboardInteractivity {
+    /// The surface never receives the keyboard focus, for example for bars without text input.
+    None,
+    /// The surface receives the keyboard focus as long as it's shown in the top or overlay layer,
+    /// for example for launchers and lock screens.
+    Exclusive,
+    /// The surface receives the keyboard focus like a normal window, when it's clicked.
+    #[default]
+    OnDemand,
+}
+
+/// The distances between a layer surface and the edges of the output it's anchored to, in logical pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Margins {
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+    pub left: i32,
+}
+
+/// The placement and behavior of the [layer surface](https://wayland.app/protocols/wlr-layer-shell-unstable-v1)
+/// that shows a window.
+///
+/// The size of the surface is the preferred size of the window, except along the axes on which
+/// the surface is stretched because it's anchored to both edges.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LayerSurfaceSettings {
+    pub layer: Layer,
+    pub anchor: Anchor,
+    /// The size of the area along the anchored edge that other surfaces shouldn't cover, in logical
+    /// pixels. For example, a bar anchored to the top, left and right edges sets it to its height,
+    /// so that the maximized windows are placed below it.
+    ///
+    /// With 0, the surface is moved to not cover the exclusive zones of other surfaces.
+    /// With -1, the surface is placed regardless of the exclusive zones of other surfaces.
+    pub exclusive_zone: i32,
+    pub margins: Margins,
+    pub keyboard_interactivity: KeyboardInteractivity,
+    /// The namespace of the surface, which tells the compositor what the surface is used for,
+    /// for example `"panel"` or `"notifications"`.
+    pub namespace: String,
+}
+
+impl Default for LayerSurfaceSettings {
+    fn default() -> Self {
+        Self {
+            layer: Default::default(),
+            anchor: Default::default(),
+            exclusive_zone: 0,
+            margins: Default::default(),
+            keyboard_interactivity: Default::default(),
+            namespace: "slint".into(),
+        }
+    }
+}
+
+/// This helper trait can be used to configure the layer surface of a [`slint::Window`](`i_slint_core::api::Window`)
+/// that is shown by the layershell backend.
+pub trait LayerShellWindowAccessor: private::LayerShellWindowAccessorSealed {
+    /// Returns the settings of the layer surface of the window, or None if the window is
+    /// not shown by the layershell backend.
+    fn layer_surface_settings(&self) -> Option<LayerSurfaceSettings>;
+
+    /// Changes the settings of the layer surface of the window. Call this function before showing
+    /// the window to show it with these settings right away.
+    ///
+    /// Returns an error if the window is not shown by the layershell backend.
+    fn set_layer_surface_settings(
+        &self,
+        settings: LayerSurfaceSettings,
+    ) -> Result<(), PlatformError>;
+}
+
+impl LayerShellWindowAccessor for i_slint_core::api::Window {
+    fn layer_surface_settings(&self) -> Option<LayerSurfaceSettings> {
+        with_window_adapter(self, |adapter| adapter.settings())
+    }
+
+    fn set_layer_surface_settings(
+        &self,
+        settings: LayerSurfaceSettings,
+    ) -> Result<(), PlatformError> {
+        with_window_adapter(self, |adapter| adapter.set_settings(settings))
+            .unwrap_or_else(|| Err("The window is not shown by the layershell backend".into()))
+    }
+}
+
+impl private::LayerShellWindowAccessorSealed for i_slint_core::api::Window {}
+
+mod private {
+    pub trait LayerShellWindowAccessorSealed {}
+}
+
+#[derive(Default)]
+pub struct BackendBuilder {
+    pub(crate) renderer_name: Option<String>,
+}
+
+impl BackendBuilder {
+    pub fn with_renderer_name(mut self, name: String) -> Self {
+        self.renderer_name = Some(name);
+        self
+    }
+
+    pub fn build(self) -> Result<Backend, PlatformError> {
+        Backend::build(self)
+    }
+}
+
+#[doc(hidden)]
+pub type NativeWidgets = ();
+#[doc(hidden)]
+pub type NativeGlobals = ();
+#[doc(hidden)]
+pub const HAS_NATIVE_STYLE: bool = false;
+#[doc(hidden)]
+pub mod native_widgets {}
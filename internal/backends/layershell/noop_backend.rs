@@ -0,0 +1,57 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use i_slint_core::platform::PlatformError;
+
+use crate::LayerSurfaceSettings;
+
+pub struct Backend {}
+
+impl Backend {
+    pub fn build(_builder: super::BackendBuilder) -> Result<Self, PlatformError> {
+        Ok(Backend {})
+    }
+}
+
+impl i_slint_core::platform::Platform for Backend {
+    fn create_window_adapter(
+        &self,
+    ) -> Result<
+        std::rc::Rc<dyn i_slint_core::window::WindowAdapter>,
+        i_slint_core::platform::PlatformError,
+    > {
+        Err("The layershell backend is only supported on Linux".into())
+    }
+
+    fn run_event_loop(&self) -> Result<(), PlatformError> {
+        Err(PlatformError::Other("The layershell backend is only supported on Linux".into()))
+    }
+}
+
+/// There are no layer surfaces on this platform.
+pub enum LayerSurfaceWindowAdapter {}
+
+impl LayerSurfaceWindowAdapter {
+    pub fn settings(&self) -> LayerSurfaceSettings {
+        match *self {}
+    }
+
+    pub fn set_settings(&self, _settings: LayerSurfaceSettings) -> Result<(), PlatformError> {
+        match *self {}
+    }
+}
+
+pub fn with_window_adapter<R>(
+    _window: &i_slint_core::api::Window,
+    _callback: impl FnOnce(&LayerSurfaceWindowAdapter) -> R,
+) -> Option<R> {
+    None
+}
+
+#[test]
+fn test_unsupported_platform() {
+    use i_slint_core::platform::Platform;
+    let backend = Backend::build(Default::default()).unwrap();
+    assert!(backend.create_window_adapter().is_err());
+    assert!(matches!(backend.run_event_loop(), Err(PlatformError::Other(_))));
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use std::cell::RefCell;
+use std::collections::HashMap;
 #[cfg(not(feature = "libseat"))]
 use std::fs::OpenOptions;
 use std::os::fd::OwnedFd;
@@ -9,7 +10,8 @@
 use std::os::fd::{AsFd, AsRawFd, FromRawFd};
 #[cfg(not(feature = "libseat"))]
 use std::os::unix::fs::OpenOptionsExt;
-use std::rc::Rc;
+use std::path::PathBuf;
+use std::rc::{Rc, Weak};
 use std::sync::atomic::AtomicBool;
 use std::sync::{Arc, Mutex};
 
@@ -17,7 +19,7 @@
 use i_slint_core::platform::PlatformError;
 
 use crate::fullscreenwindowadapter::FullscreenWindowAdapter;
-use crate::BackendBuilder;
+use crate::{BackendBuilder, SeatAssignment};
 
 #[cfg(not(any(target_family = "windows", target_vendor = "apple", target_arch = "wasm32")))]
 mod input;
@@ -66,11 +68,16 @@ fn invoke_from_event_loop(
 pub struct Backend {
     #[cfg(feature = "libseat")]
     seat: Rc<RefCell<libseat::Seat>>,
-    window: RefCell<Option<Rc<FullscreenWindowAdapter>>>,
+    windows: RefCell<Vec<Rc<FullscreenWindowAdapter>>>,
+    seat_assignments: Vec<SeatAssignment>,
+    /// The devices that are in use, to share the file descriptor of a DRM device between the
+    /// outputs on it. Only one file descriptor of a device can be the DRM master.
+    opened_devices: RefCell<HashMap<PathBuf, Weak<OwnedFd>>>,
     user_event_receiver: RefCell<Option<calloop::channel::Channel<Box<dyn FnOnce() + Send>>>>,
     proxy: Proxy,
     renderer_factory: for<'a> fn(
         &'a crate::DeviceOpener,
+        Option<&'a str>,
     ) -> Result<
         Box<dyn crate::fullscreenwindowadapter::FullscreenRenderer>,
         PlatformError,
@@ -78,6 +85,8 @@ pub struct Backend {
     sel_clipboard: RefCell<Option<String>>,
     clipboard: RefCell<Option<String>>,
     libinput_event_hook: Option<Box<dyn Fn(&::input::Event) -> bool>>,
+    #[cfg(feature = "drm")]
+    overlay_plane_hook: RefCell<Option<Box<dyn FnOnce(crate::OverlayPlane)>>>,
 }
 
 impl Backend {
@@ -105,6 +114,14 @@ pub fn build(builder: BackendBuilder) -> Result<Self, PlatformError> {
             }
         };
 
+        let seat_assignments = if builder.seat_assignments.is_empty() {
+            std::env::var("SLINT_KMS_SEATS")
+                .map(|seats| SeatAssignment::parse_list(&seats))
+                .unwrap_or_default()
+        } else {
+            builder.seat_assignments
+        };
+
         #[cfg(feature = "libseat")]
         let seat_active = Rc::new(RefCell::new(false));
 
@@ -136,13 +153,17 @@ pub fn build(builder: BackendBuilder) -> Result<Self, PlatformError> {
         Ok(Backend {
             #[cfg(feature = "libseat")]
             seat: Rc::new(RefCell::new(seat)),
-            window: Default::default(),
+            windows: Default::default(),
+            seat_assignments,
+            opened_devices: Default::default(),
             user_event_receiver: RefCell::new(Some(user_event_receiver)),
             proxy: Proxy::new(user_event_sender),
             renderer_factory,
             sel_clipboard: Default::default(),
             clipboard: Default::default(),
             libinput_event_hook: builder.libinput_event_hook,
+            #[cfg(feature = "drm")]
+            overlay_plane_hook: RefCell::new(builder.overlay_plane_hook),
         })
     }
 }
@@ -152,7 +173,7 @@ fn create_window_adapter(
         &self,
     ) -> Result<std::rc::Rc<dyn i_slint_core::window::WindowAdapter>, PlatformError> {
         #[cfg(feature = "libseat")]
-        let device_accessor = |device: &std::path::Path| -> Result<Rc<OwnedFd>, PlatformError> {
+        let open_device = |device: &std::path::Path| -> Result<Rc<OwnedFd>, PlatformError> {
             let device = self
                 .seat
                 .borrow_mut()
@@ -173,7 +194,7 @@ fn create_window_adapter(
         };
 
         #[cfg(not(feature = "libseat"))]
-        let device_accessor = |device: &std::path::Path| -> Result<Rc<OwnedFd>, PlatformError> {
+        let open_device = |device: &std::path::Path| -> Result<Rc<OwnedFd>, PlatformError> {
             let device = OpenOptions::new()
                 .custom_flags((nix::fcntl::OFlag::O_NOCTTY | nix::fcntl::OFlag::O_CLOEXEC).bits())
                 .read(true)
@@ -185,7 +206,30 @@ fn create_window_adapter(
             Ok(Rc::new(device))
         };
 
-        // This could be per-screen, once we support multiple outputs
+        let device_accessor = |device: &std::path::Path| -> Result<Rc<OwnedFd>, PlatformError> {
+            if let Some(fd) = self.opened_devices.borrow().get(device).and_then(Weak::upgrade) {
+                return Ok(fd);
+            }
+            let fd = open_device(device)?;
+            self.opened_devices.borrow_mut().insert(device.into(), Rc::downgrade(&fd));
+            Ok(fd)
+        };
+
+        // The n-th window is shown on the output of the n-th seat assignment
+        let output = if self.seat_assignments.is_empty() {
+            None
+        } else {
+            let index = self.windows.borrow().len();
+            let seat_assignment = self.seat_assignments.get(index).ok_or_else(|| {
+                format!(
+                    "Cannot create more windows than there are seat assignments ({})",
+                    self.seat_assignments.len()
+                )
+            })?;
+            Some(seat_assignment.output.as_str())
+        };
+
+        // The rotation applies to the outputs of all windows
         let rotation =
             std::env::var("SLINT_KMS_ROTATION").map_or(Ok(Default::default()), |rot_str| {
                 rot_str
@@ -194,10 +238,25 @@ fn create_window_adapter(
                     .map_err(|e| format!("Failed to parse SLINT_KMS_ROTATION: {e}"))
             })?;
 
-        let renderer = (self.renderer_factory)(&device_accessor)?;
+        let renderer = (self.renderer_factory)(&device_accessor, output)?;
+
+        #[cfg(feature = "drm")]
+        if let Some(overlay_plane_hook) = self.overlay_plane_hook.borrow_mut().take() {
+            if let Some(overlay_plane) =
+                renderer.drm_output().and_then(|drm_output| drm_output.overlay_plane())
+            {
+                overlay_plane_hook(overlay_plane);
+            }
+        }
+
         let adapter = FullscreenWindowAdapter::new(renderer, rotation)?;
 
-        *self.window.borrow_mut() = Some(adapter.clone());
+        if self.seat_assignments.is_empty() {
+            // Without seat assignments, the last created window is shown and receives all input
+            *self.windows.borrow_mut() = vec![adapter.clone()];
+        } else {
+            self.windows.borrow_mut().push(adapter.clone());
+        }
 
         Ok(adapter)
     }
@@ -211,8 +270,9 @@ fn run_event_loop(&self) -> Result<(), PlatformError> {
         *self.proxy.loop_signal.lock().unwrap() = Some(loop_signal.clone());
         let quit_loop = self.proxy.quit_loop.clone();
 
-        let mouse_position_property = input::LibInputHandler::init(
-            &self.window,
+        input::LibInputHandler::init(
+            &self.windows,
+            &self.seat_assignments,
             &event_loop.handle(),
             #[cfg(feature = "libseat")]
             &self.seat,
@@ -256,9 +316,10 @@ fn run_event_loop(&self) -> Result<(), PlatformError> {
                 callback();
             }
 
-            if let Some(adapter) = self.window.borrow().as_ref() {
-                adapter.clone().render_if_needed(mouse_position_property.as_ref())?;
-            };
+            let windows = self.windows.borrow().clone();
+            for adapter in windows {
+                adapter.render_if_needed()?;
+            }
 
             let next_timeout = i_slint_core::platform::duration_until_next_timer_update();
             event_loop
@@ -14,20 +14,24 @@
 #[cfg(not(feature = "libseat"))]
 use std::os::unix::fs::OpenOptionsExt;
 use std::path::Path;
-use std::pin::Pin;
 use std::rc::Rc;
 
 use i_slint_core::api::LogicalPosition;
-use i_slint_core::platform::{PlatformError, PointerEventButton, WindowEvent};
+use i_slint_core::platform::{GestureEvent, PlatformError, PointerEventButton, WindowEvent};
 use i_slint_core::window::WindowAdapter;
-use i_slint_core::{Property, SharedString};
+use i_slint_core::SharedString;
+use input::event::EventTrait;
 use input::LibinputInterface;
 
+use input::event::gesture::{
+    GestureEndEvent, GestureEventCoordinates, GestureEventTrait, GesturePinchEventTrait,
+};
 use input::event::keyboard::{KeyState, KeyboardEventTrait};
 use input::event::touch::TouchEventPosition;
 use xkbcommon::*;
 
 use crate::fullscreenwindowadapter::FullscreenWindowAdapter;
+use crate::SeatAssignment;
 
 #[cfg(feature = "libseat")]
 struct SeatWrap {
@@ -112,37 +116,43 @@ fn close_restricted(&mut self, fd: OwnedFd) {
     }
 }
 
+/// The state of the input devices that send their events to one window.
+#[derive(Default)]
+struct WindowInputState {
+    last_touch_pos: LogicalPosition,
+    // libinput reports the change of the angle since the last update of a pinch gesture
+    pinch_rotation: f32,
+    keystate: Option<xkb::State>,
+}
+
 pub struct LibInputHandler<'a> {
     libinput: input::Libinput,
     token: Option<calloop::Token>,
-    mouse_pos: Pin<Rc<Property<Option<LogicalPosition>>>>,
-    last_touch_pos: LogicalPosition,
-    window: &'a RefCell<Option<Rc<FullscreenWindowAdapter>>>,
-    keystate: Option<xkb::State>,
+    windows: &'a RefCell<Vec<Rc<FullscreenWindowAdapter>>>,
+    seat_assignments: &'a [SeatAssignment],
+    window_states: Vec<WindowInputState>,
     libinput_event_hook: &'a Option<Box<dyn Fn(&::input::Event) -> bool>>,
 }
 
 impl<'a> LibInputHandler<'a> {
     pub fn init<T>(
-        window: &'a RefCell<Option<Rc<FullscreenWindowAdapter>>>,
+        windows: &'a RefCell<Vec<Rc<FullscreenWindowAdapter>>>,
+        seat_assignments: &'a [SeatAssignment],
         event_loop_handle: &calloop::LoopHandle<'a, T>,
         #[cfg(feature = "libseat")] seat: &'a Rc<RefCell<libseat::Seat>>,
         libinput_event_hook: &'a Option<Box<dyn Fn(&::input::Event) -> bool>>,
-    ) -> Result<Pin<Rc<Property<Option<LogicalPosition>>>>, PlatformError> {
+    ) -> Result<(), PlatformError> {
         #[cfg(feature = "libseat")]
         let libinput = SeatWrap::new(seat);
         #[cfg(not(feature = "libseat"))]
         let libinput = DirectDeviceAccess::new();
 
-        let mouse_pos_property = Rc::pin(Property::new(None));
-
         let handler = Self {
             libinput,
             token: Default::default(),
-            mouse_pos: mouse_pos_property.clone(),
-            last_touch_pos: Default::default(),
-            window,
-            keystate: Default::default(),
+            windows,
+            seat_assignments,
+            window_states: Default::default(),
             libinput_event_hook,
         };
 
@@ -150,10 +160,24 @@ pub fn init<T>(
             .insert_source(handler, move |_, _, _| {})
             .map_err(|e| format!("Error registering libinput event source: {e}"))?;
 
-        Ok(mouse_pos_property)
+        Ok(())
     }
 }
 
+/// Returns the index of the window that receives the events of the device: the window of the
+/// seat assignment that lists the device, or the first window.
+fn window_index(seat_assignments: &[SeatAssignment], device: &input::Device) -> usize {
+    seat_assignments
+        .iter()
+        .position(|seat_assignment| {
+            seat_assignment
+                .input_devices
+                .iter()
+                .any(|name| name == device.sysname() || name == device.name())
+        })
+        .unwrap_or(0)
+}
+
 impl<'a> calloop::EventSource for LibInputHandler<'a> {
     type Event = i_slint_core::platform::WindowEvent;
     type Metadata = ();
@@ -175,30 +199,36 @@ fn process_events<F>(
 
         self.libinput.dispatch()?;
 
-        let Some(adapter) = self.window.borrow().clone() else {
+        let windows = self.windows.borrow().clone();
+        if windows.is_empty() {
             return Ok(calloop::PostAction::Continue);
-        };
-        let window = adapter.window();
-        let screen_size = window.size().to_logical(window.scale_factor());
+        }
+        self.window_states.resize_with(windows.len(), Default::default);
 
         for event in &mut self.libinput {
             if self.libinput_event_hook.as_ref().map_or(false, |hook| hook(&event)) {
                 continue;
             };
+            let index = window_index(self.seat_assignments, &event.device());
+            // The events of devices that are assigned to a window that isn't created yet are dropped
+            let Some(adapter) = windows.get(index) else { continue };
+            let window = adapter.window();
+            let screen_size = window.size().to_logical(window.scale_factor());
+            let mouse_position = adapter.mouse_position();
+            let state = &mut self.window_states[index];
             match event {
                 input::Event::Pointer(pointer_event) => {
                     match pointer_event {
                         input::event::PointerEvent::Motion(motion_event) => {
-                            let mut mouse_pos =
-                                self.mouse_pos.as_ref().get().unwrap_or(LogicalPosition {
-                                    x: screen_size.width / 2.,
-                                    y: screen_size.height / 2.,
-                                });
+                            let mut mouse_pos = mouse_position.get().unwrap_or(LogicalPosition {
+                                x: screen_size.width / 2.,
+                                y: screen_size.height / 2.,
+                            });
                             mouse_pos.x = (mouse_pos.x + motion_event.dx() as f32)
                                 .clamp(0., screen_size.width);
                             mouse_pos.y = (mouse_pos.y + motion_event.dy() as f32)
                                 .clamp(0., screen_size.height);
-                            self.mouse_pos.set(Some(mouse_pos));
+                            mouse_position.set(Some(mouse_pos));
                             let event = WindowEvent::PointerMoved { position: mouse_pos };
                             window.try_dispatch_event(event).map_err(Self::Error::other)?;
                         }
@@ -210,7 +240,7 @@ fn process_events<F>(
                                     .absolute_y_transformed(screen_size.height as u32)
                                     as _,
                             };
-                            self.mouse_pos.set(Some(mouse_pos));
+                            mouse_position.set(Some(mouse_pos));
                             let event = WindowEvent::PointerMoved { position: mouse_pos };
                             window.try_dispatch_event(event).map_err(Self::Error::other)?;
                         }
@@ -224,7 +254,7 @@ fn process_events<F>(
                                 0x115 => PointerEventButton::Forward,
                                 _ => PointerEventButton::Other,
                             };
-                            let mouse_pos = self.mouse_pos.as_ref().get().unwrap_or_default();
+                            let mouse_pos = mouse_position.get().unwrap_or_default();
                             let event = match button_event.button_state() {
                                 input::event::tablet_pad::ButtonState::Pressed => {
                                     WindowEvent::PointerPressed { position: mouse_pos, button }
@@ -241,37 +271,80 @@ fn process_events<F>(
                 input::Event::Touch(touch_event) => {
                     if let Some(event) = match touch_event {
                         input::event::TouchEvent::Down(touch_down_event) => {
-                            self.last_touch_pos = LogicalPosition::new(
+                            state.last_touch_pos = LogicalPosition::new(
                                 touch_down_event.x_transformed(screen_size.width as u32) as _,
                                 touch_down_event.y_transformed(screen_size.height as u32) as _,
                             );
                             Some(WindowEvent::PointerPressed {
-                                position: self.last_touch_pos,
+                                position: state.last_touch_pos,
                                 button: PointerEventButton::Left,
                             })
                         }
                         input::event::TouchEvent::Up(..) => Some(WindowEvent::PointerReleased {
-                            position: self.last_touch_pos,
+                            position: state.last_touch_pos,
                             button: PointerEventButton::Left,
                         }),
                         input::event::TouchEvent::Motion(touch_motion_event) => {
-                            self.last_touch_pos = LogicalPosition::new(
+                            state.last_touch_pos = LogicalPosition::new(
                                 touch_motion_event.x_transformed(screen_size.width as u32) as _,
                                 touch_motion_event.y_transformed(screen_size.height as u32) as _,
                             );
-                            Some(WindowEvent::PointerMoved { position: self.last_touch_pos })
+                            Some(WindowEvent::PointerMoved { position: state.last_touch_pos })
                         }
                         _ => None,
                     } {
                         window.try_dispatch_event(event).map_err(Self::Error::other)?;
                     }
                 }
+                input::Event::Gesture(gesture_event) => {
+                    if let Some(gesture) = match gesture_event {
+                        input::event::GestureEvent::Pinch(pinch_event) => match pinch_event {
+                            input::event::gesture::GesturePinchEvent::Begin(_) => {
+                                state.pinch_rotation = 0.;
+                                Some(GestureEvent::PinchBegin)
+                            }
+                            input::event::gesture::GesturePinchEvent::Update(update_event) => {
+                                state.pinch_rotation += update_event.angle_delta() as f32;
+                                Some(GestureEvent::PinchUpdate {
+                                    scale: update_event.scale() as f32,
+                                    rotation: state.pinch_rotation,
+                                })
+                            }
+                            input::event::gesture::GesturePinchEvent::End(end_event) => {
+                                Some(GestureEvent::PinchEnd { cancelled: end_event.cancelled() })
+                            }
+                            _ => None,
+                        },
+                        input::event::GestureEvent::Swipe(swipe_event) => match swipe_event {
+                            input::event::gesture::GestureSwipeEvent::Begin(begin_event) => {
+                                Some(GestureEvent::SwipeBegin {
+                                    fingers: begin_event.finger_count() as u32,
+                                })
+                            }
+                            input::event::gesture::GestureSwipeEvent::Update(update_event) => {
+                                Some(GestureEvent::SwipeUpdate {
+                                    delta_x: update_event.dx() as f32,
+                                    delta_y: update_event.dy() as f32,
+                                })
+                            }
+                            input::event::gesture::GestureSwipeEvent::End(end_event) => {
+                                Some(GestureEvent::SwipeEnd { cancelled: end_event.cancelled() })
+                            }
+                            _ => None,
+                        },
+                        _ => None,
+                    } {
+                        let position = mouse_position.get().unwrap_or_default();
+                        let event = WindowEvent::PointerGesture { position, gesture };
+                        window.try_dispatch_event(event).map_err(Self::Error::other)?;
+                    }
+                }
                 input::Event::Keyboard(input::event::KeyboardEvent::Key(key_event)) => {
                     // On Linux key codes have a fixed offset of 8: https://docs.rs/xkbcommon/0.6.0/xkbcommon/xkb/struct.Keycode.html
                     let key_code = xkb::Keycode::new(key_event.key() + 8);
                     let state = key_event.key_state();
 
-                    let xkb_key_state = self.keystate.get_or_insert_with(|| {
+                    let xkb_key_state = state.keystate.get_or_insert_with(|| {
                         let xkb_context = xkb::Context::new(xkb::CONTEXT_NO_FLAGS);
                         let keymap =
                             xkb::Keymap::new_from_names(&xkb_context, "", "", "", "", None, 0)
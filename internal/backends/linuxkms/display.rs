@@ -7,6 +7,11 @@
 pub trait Presenter {
     // Present updated front-buffer to the screen
     fn present(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+    /// The DRM output the frames are presented to, if any, for the cursor and overlay planes.
+    #[cfg(feature = "drm")]
+    fn drm_output(&self) -> Option<&crate::drmoutput::DrmOutput> {
+        None
+    }
 }
 
 #[cfg(any(feature = "renderer-skia-opengl", feature = "renderer-femtovg"))]
@@ -72,6 +72,10 @@ pub fn filter_gl_config(&self, config: &glutin::config::Config) -> bool {
 }
 
 impl super::Presenter for GbmDisplay {
+    fn drm_output(&self) -> Option<&DrmOutput> {
+        Some(&self.drm_output)
+    }
+
     fn present(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         let mut front_buffer = unsafe {
             self.gbm_surface
@@ -35,11 +35,16 @@ pub fn negotiate_format(
 
 pub fn new(
     device_opener: &crate::DeviceOpener,
+    output: Option<&str>,
     renderer_formats: &[drm::buffer::DrmFourcc],
 ) -> Result<Arc<dyn SoftwareBufferDisplay>, PlatformError> {
+    // The legacy framebuffers don't map to the names of the DRM connectors
+    if output.is_some() {
+        return dumbbuffer::DumbBufferDisplay::new(device_opener, output, renderer_formats);
+    }
     if std::env::var_os("SLINT_BACKEND_LINUXFB").is_some() {
         return linuxfb::LinuxFBDisplay::new(device_opener, renderer_formats);
     }
-    dumbbuffer::DumbBufferDisplay::new(device_opener, renderer_formats)
+    dumbbuffer::DumbBufferDisplay::new(device_opener, None, renderer_formats)
         .or_else(|_| linuxfb::LinuxFBDisplay::new(device_opener, renderer_formats))
 }
@@ -21,9 +21,10 @@ pub struct DumbBufferDisplay {
 impl DumbBufferDisplay {
     pub fn new(
         device_opener: &crate::DeviceOpener,
+        output: Option<&str>,
         renderer_formats: &[drm::buffer::DrmFourcc],
     ) -> Result<Arc<dyn super::SoftwareBufferDisplay>, PlatformError> {
-        let drm_output = DrmOutput::new(device_opener)?;
+        let drm_output = DrmOutput::new(device_opener, output)?;
 
         let available_formats = drm_output.get_supported_formats()?;
 
@@ -90,6 +91,10 @@ fn as_presenter(self: Arc<Self>) -> Arc<dyn crate::display::Presenter> {
 }
 
 impl crate::display::Presenter for DumbBufferDisplay {
+    fn drm_output(&self) -> Option<&DrmOutput> {
+        Some(&self.drm_output)
+    }
+
     fn present(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         self.drm_output.wait_for_page_flip();
 
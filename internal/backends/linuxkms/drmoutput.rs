@@ -2,15 +2,28 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use std::cell::{Cell, RefCell};
-use std::os::fd::{AsFd, BorrowedFd, OwnedFd};
+use std::os::fd::{AsFd, AsRawFd, BorrowedFd, OwnedFd, RawFd};
 use std::rc::Rc;
 
 use crate::DeviceOpener;
 use drm::buffer::Buffer;
 use drm::control::Device;
 use drm::Device as DrmDevice;
+use i_slint_core::api::{PhysicalPosition, PhysicalSize};
+use i_slint_core::graphics::{Rgba8Pixel, SharedPixelBuffer};
 use i_slint_core::platform::PlatformError;
 
+// The values of the "type" property of planes, from drm_mode.h
+const DRM_PLANE_TYPE_OVERLAY: u64 = 0;
+const DRM_PLANE_TYPE_CURSOR: u64 = 2;
+
+thread_local! {
+    /// The page flips that were read from the file descriptor of a DRM device, but not yet
+    /// waited for by the output of the CRTC.
+    static RECEIVED_PAGE_FLIPS: RefCell<Vec<(RawFd, drm::control::crtc::Handle)>> =
+        RefCell::default();
+}
+
 // Wrapped needed because gbm::Device<T> wants T to be sized.
 #[derive(Clone)]
 pub struct SharedFd(Rc<OwnedFd>);
@@ -35,6 +48,24 @@ enum PageFlipState {
     ReadyForNextBuffer,
 }
 
+/// The cursor plane of the CRTC, which shows the mouse cursor without rendering a new frame
+/// when the mouse moves.
+struct HardwareCursor {
+    plane: drm::control::plane::Handle,
+    framebuffer: drm::control::framebuffer::Handle,
+    _buffer: drm::control::dumbbuffer::DumbBuffer,
+    size: (u32, u32),
+    position: Option<(i32, i32)>,
+}
+
+#[derive(Default)]
+enum HardwareCursorState {
+    #[default]
+    Uninitialized,
+    Unsupported,
+    Ready(HardwareCursor),
+}
+
 pub struct DrmOutput {
     pub drm_device: SharedFd,
     connector: drm::control::connector::Info,
@@ -42,15 +73,21 @@ pub struct DrmOutput {
     crtc: drm::control::crtc::Handle,
     last_buffer: Cell<Option<Box<dyn Buffer>>>,
     page_flip_state: Rc<RefCell<PageFlipState>>,
+    hardware_cursor: RefCell<HardwareCursorState>,
 }
 
 impl DrmOutput {
-    pub fn new(device_opener: &DeviceOpener) -> Result<Self, PlatformError> {
+    /// Opens the display connected to the connector with the name `requested_output`, or to the
+    /// one selected with `SLINT_DRM_OUTPUT`, or to the first connected connector.
+    pub fn new(
+        device_opener: &DeviceOpener,
+        requested_output: Option<&str>,
+    ) -> Result<Self, PlatformError> {
         let mut last_err = None;
         if let Ok(drm_devices) = std::fs::read_dir("/dev/dri/") {
             for device in drm_devices {
                 if let Ok(device) = device.map_err(|e| format!("Error opening DRM device: {e}")) {
-                    match Self::new_with_path(device_opener, &device.path()) {
+                    match Self::new_with_path(device_opener, &device.path(), requested_output) {
                         Ok(dsp) => return Ok(dsp),
                         Err(e) => last_err = Some(e),
                     }
@@ -63,6 +100,7 @@ pub fn new(device_opener: &DeviceOpener) -> Result<Self, PlatformError> {
     fn new_with_path(
         device_opener: &DeviceOpener,
         device: &std::path::Path,
+        requested_output: Option<&str>,
     ) -> Result<Self, PlatformError> {
         let drm_device = SharedFd(device_opener(device)?);
 
@@ -70,7 +108,11 @@ fn new_with_path(
             .resource_handles()
             .map_err(|e| format!("Error reading DRM resource handles: {e}"))?;
 
-        let connector = if let Ok(requested_connector_name) = std::env::var("SLINT_DRM_OUTPUT") {
+        let requested_connector_name = requested_output
+            .map(ToString::to_string)
+            .or_else(|| std::env::var("SLINT_DRM_OUTPUT").ok());
+
+        let connector = if let Some(requested_connector_name) = requested_connector_name {
             let mut connectors = resources.connectors().iter().filter_map(|handle| {
                 let connector = drm_device.get_connector(*handle, false).ok()?;
                 let name =
@@ -197,6 +239,7 @@ fn new_with_path(
             crtc,
             last_buffer: Cell::default(),
             page_flip_state: Default::default(),
+            hardware_cursor: Default::default(),
         })
     }
 
@@ -238,18 +281,29 @@ pub fn wait_for_page_flip(&self) {
             return;
         }
 
+        // The outputs on the same DRM device share its file descriptor, so the events read here
+        // may be for the CRTCs of other outputs. Keep those for when the other outputs wait.
+        let fd = self.drm_device.as_fd().as_raw_fd();
         loop {
-            let Ok(mut event_it) = self.drm_device.receive_events() else {
+            let flipped = RECEIVED_PAGE_FLIPS.with_borrow_mut(|flips| {
+                let index = flips.iter().position(|flip| *flip == (fd, self.crtc));
+                index.map(|index| flips.swap_remove(index)).is_some()
+            });
+            if flipped {
+                *self.page_flip_state.borrow_mut() = PageFlipState::ReadyForNextBuffer;
+                return;
+            }
+
+            let Ok(event_it) = self.drm_device.receive_events() else {
                 return;
             };
 
-            if event_it.any(|event| matches!(event, drm::control::Event::PageFlip(..))) {
-                if let PageFlipState::WaitingForPageFlip { .. } =
-                    self.page_flip_state.replace(PageFlipState::ReadyForNextBuffer)
-                {
-                    return;
-                }
-            }
+            RECEIVED_PAGE_FLIPS.with_borrow_mut(|flips| {
+                flips.extend(event_it.filter_map(|event| match event {
+                    drm::control::Event::PageFlip(event) => Some((fd, event.crtc)),
+                    _ => None,
+                }))
+            });
         }
     }
 
@@ -288,4 +342,219 @@ pub fn size(&self) -> (u32, u32) {
         let (width, height) = self.mode.size();
         (width as u32, height as u32)
     }
+
+    /// Shows the mouse cursor at `position` on the screen with the cursor plane of the CRTC, or
+    /// hides it with `None`. The cursor image is only requested the first time. Returns false if
+    /// there's no usable cursor plane, in which case the cursor must be drawn into the frames.
+    pub fn set_cursor_position(
+        &self,
+        position: Option<(i32, i32)>,
+        cursor_image: impl FnOnce() -> Option<SharedPixelBuffer<Rgba8Pixel>>,
+    ) -> bool {
+        // Planes can only be attached to the CRTC once it's set up with the first frame
+        if matches!(*self.page_flip_state.borrow(), PageFlipState::NoFrameBufferPosted) {
+            return false;
+        }
+
+        let mut state = self.hardware_cursor.borrow_mut();
+        if matches!(*state, HardwareCursorState::Uninitialized) {
+            *state = match self.create_hardware_cursor(cursor_image) {
+                Ok(cursor) => HardwareCursorState::Ready(cursor),
+                Err(err) => {
+                    eprintln!("Not using a hardware cursor: {err}");
+                    HardwareCursorState::Unsupported
+                }
+            };
+        }
+        let HardwareCursorState::Ready(cursor) = &mut *state else { return false };
+        if cursor.position == position {
+            return true;
+        }
+
+        let (width, height) = cursor.size;
+        let result = match position {
+            Some((x, y)) => self.drm_device.set_plane(
+                cursor.plane,
+                self.crtc,
+                Some(cursor.framebuffer),
+                0,
+                (x, y, width, height),
+                // The source rectangle is in 16.16 fixed point
+                (0, 0, width << 16, height << 16),
+            ),
+            None => self.drm_device.set_plane(
+                cursor.plane,
+                self.crtc,
+                None,
+                0,
+                (0, 0, 0, 0),
+                (0, 0, 0, 0),
+            ),
+        };
+        match result {
+            Ok(()) => {
+                cursor.position = position;
+                true
+            }
+            Err(err) => {
+                eprintln!(
+                    "Error moving the hardware cursor, falling back to a software cursor: {err}"
+                );
+                *state = HardwareCursorState::Unsupported;
+                false
+            }
+        }
+    }
+
+    fn create_hardware_cursor(
+        &self,
+        cursor_image: impl FnOnce() -> Option<SharedPixelBuffer<Rgba8Pixel>>,
+    ) -> Result<HardwareCursor, String> {
+        if std::env::var("SLINT_KMS_HARDWARE_CURSOR").is_ok_and(|value| value == "0") {
+            return Err("disabled with SLINT_KMS_HARDWARE_CURSOR".into());
+        }
+        let plane = self
+            .find_plane(DRM_PLANE_TYPE_CURSOR)
+            .ok_or_else(|| format!("No cursor plane found for CRTC {:?}", self.crtc))?;
+
+        let width = self
+            .drm_device
+            .get_driver_capability(drm::DriverCapability::CursorWidth)
+            .map_or(64, |width| width as u32);
+        let height = self
+            .drm_device
+            .get_driver_capability(drm::DriverCapability::CursorHeight)
+            .map_or(64, |height| height as u32);
+
+        let pixels =
+            cursor_image().ok_or_else(|| "Could not render the cursor image".to_string())?;
+        if pixels.width() > width || pixels.height() > height {
+            return Err(format!(
+                "The cursor image ({}x{}) is larger than the cursor plane ({width}x{height})",
+                pixels.width(),
+                pixels.height()
+            ));
+        }
+
+        let mut buffer = self
+            .drm_device
+            .create_dumb_buffer((width, height), drm::buffer::DrmFourcc::Argb8888, 32)
+            .map_err(|e| format!("Error creating the cursor buffer: {e}"))?;
+        {
+            let pitch = buffer.pitch() as usize;
+            let mut mapping = self
+                .drm_device
+                .map_dumb_buffer(&mut buffer)
+                .map_err(|e| format!("Error mapping the cursor buffer: {e}"))?;
+            let bytes = mapping.as_mut();
+            bytes.fill(0);
+            for (y, row) in pixels.as_slice().chunks(pixels.width() as usize).enumerate() {
+                for (x, pixel) in row.iter().enumerate() {
+                    let offset = y * pitch + x * 4;
+                    // ARGB8888 is stored in little endian, with premultiplied alpha
+                    bytes[offset..offset + 4]
+                        .copy_from_slice(&[pixel.b, pixel.g, pixel.r, pixel.a]);
+                }
+            }
+        }
+        let framebuffer = self
+            .drm_device
+            .add_framebuffer(&buffer, 32, 32)
+            .map_err(|e| format!("Error creating the cursor framebuffer: {e}"))?;
+
+        Ok(HardwareCursor {
+            plane,
+            framebuffer,
+            _buffer: buffer,
+            size: (width, height),
+            position: None,
+        })
+    }
+
+    /// Returns an overlay plane that can be used with the CRTC, if the display has one.
+    pub fn overlay_plane(&self) -> Option<OverlayPlane> {
+        let plane = self.find_plane(DRM_PLANE_TYPE_OVERLAY)?;
+        Some(OverlayPlane {
+            drm_device: self.drm_device.clone(),
+            plane,
+            crtc: self.crtc,
+            screen_size: self.size(),
+        })
+    }
+
+    fn find_plane(&self, plane_type: u64) -> Option<drm::control::plane::Handle> {
+        let _ = self.drm_device.set_client_capability(drm::ClientCapability::UniversalPlanes, true);
+        let resources = self.drm_device.resource_handles().ok()?;
+        let planes = self.drm_device.plane_handles().ok()?;
+        planes.into_iter().find(|handle| {
+            self.drm_device.get_plane(*handle).is_ok_and(|plane| {
+                resources.filter_crtcs(plane.possible_crtcs()).contains(&self.crtc)
+            }) && self.plane_type(*handle) == Some(plane_type)
+        })
+    }
+
+    fn plane_type(&self, plane: drm::control::plane::Handle) -> Option<u64> {
+        let properties = self.drm_device.get_properties(plane).ok()?;
+        let (ids, values) = properties.as_props_and_values();
+        ids.iter().zip(values).find_map(|(id, value)| {
+            let info = self.drm_device.get_property(*id).ok()?;
+            (info.name().to_bytes() == b"type").then_some(*value)
+        })
+    }
+}
+
+/// An overlay plane of the display, which shows content such as decoded video frames directly
+/// with the display controller, without composing it into the frames rendered by Slint. It's
+/// usually shown above the Slint scene.
+///
+/// Create the framebuffers to show with the DRM device of [`Self::drm_fd()`], for example by
+/// importing the DMA-BUFs of a video decoder.
+pub struct OverlayPlane {
+    drm_device: SharedFd,
+    plane: drm::control::plane::Handle,
+    crtc: drm::control::crtc::Handle,
+    screen_size: (u32, u32),
+}
+
+impl OverlayPlane {
+    /// Returns the file descriptor of the DRM device of the display.
+    pub fn drm_fd(&self) -> BorrowedFd<'_> {
+        self.drm_device.as_fd()
+    }
+
+    /// Returns the size of the screen, in physical pixels.
+    pub fn screen_size(&self) -> PhysicalSize {
+        PhysicalSize::new(self.screen_size.0, self.screen_size.1)
+    }
+
+    /// Shows the framebuffer with the id `framebuffer_id` and the size `framebuffer_size` on the
+    /// plane, scaled to the area of the screen at `position` with the given `size`.
+    pub fn show(
+        &self,
+        framebuffer_id: u32,
+        framebuffer_size: PhysicalSize,
+        position: PhysicalPosition,
+        size: PhysicalSize,
+    ) -> Result<(), PlatformError> {
+        let framebuffer = drm::control::from_u32(framebuffer_id)
+            .ok_or_else(|| format!("Invalid framebuffer id {framebuffer_id}"))?;
+        self.drm_device
+            .set_plane(
+                self.plane,
+                self.crtc,
+                Some(framebuffer),
+                0,
+                (position.x, position.y, size.width, size.height),
+                // The source rectangle is in 16.16 fixed point
+                (0, 0, framebuffer_size.width << 16, framebuffer_size.height << 16),
+            )
+            .map_err(|e| format!("Error showing the framebuffer on the overlay plane: {e}").into())
+    }
+
+    /// Hides the plane.
+    pub fn hide(&self) -> Result<(), PlatformError> {
+        self.drm_device
+            .set_plane(self.plane, self.crtc, None, 0, (0, 0, 0, 0), (0, 0, 0, 0))
+            .map_err(|e| format!("Error hiding the overlay plane: {e}").into())
+    }
 }
@@ -26,6 +26,11 @@ fn render_and_present(
         draw_mouse_cursor_callback: &dyn Fn(&mut dyn ItemRenderer),
     ) -> Result<(), PlatformError>;
     fn size(&self) -> PhysicalWindowSize;
+    /// The DRM output the renderer presents to, if any.
+    #[cfg(feature = "drm")]
+    fn drm_output(&self) -> Option<&crate::drmoutput::DrmOutput> {
+        None
+    }
 }
 
 pub struct FullscreenWindowAdapter {
@@ -33,6 +38,11 @@ pub struct FullscreenWindowAdapter {
     renderer: Box<dyn FullscreenRenderer>,
     redraw_requested: Cell<bool>,
     rotation: RenderingRotation,
+    /// True if the mouse cursor is shown with the cursor plane of the display instead of being
+    /// drawn into each frame.
+    hardware_cursor: Cell<bool>,
+    /// The position of the mouse cursor, or None if no mouse moved on this window yet.
+    mouse_position: Pin<Box<Property<Option<LogicalPosition>>>>,
 }
 
 impl WindowAdapter for FullscreenWindowAdapter {
@@ -86,15 +96,29 @@ pub fn new(
             renderer,
             redraw_requested: Cell::new(true),
             rotation,
+            hardware_cursor: Cell::new(false),
+            mouse_position: Box::pin(Property::new(None)),
         }))
     }
 
-    pub fn render_if_needed(
-        self: Rc<Self>,
-        mouse_position: Pin<&Property<Option<LogicalPosition>>>,
-    ) -> Result<(), PlatformError> {
+    pub fn mouse_position(&self) -> Pin<&Property<Option<LogicalPosition>>> {
+        self.mouse_position.as_ref()
+    }
+
+    pub fn render_if_needed(self: Rc<Self>) -> Result<(), PlatformError> {
+        let mouse_position = self.mouse_position();
+        // Moving the hardware cursor doesn't need a new frame, so don't track the mouse position
+        let hardware_cursor = self.update_hardware_cursor(mouse_position.get_untracked());
+        if self.hardware_cursor.replace(hardware_cursor) != hardware_cursor {
+            // Add or remove the cursor drawn into the frames
+            self.request_redraw();
+        }
+
         if self.redraw_requested.replace(false) {
             self.renderer.render_and_present(self.rotation, &|item_renderer| {
+                if hardware_cursor {
+                    return;
+                }
                 if let Some(mouse_position) = mouse_position.get() {
                     let cursor_image = mouse_cursor_image();
                     item_renderer.save_state();
@@ -129,6 +153,28 @@ pub fn render_if_needed(
         }
         Ok(())
     }
+
+    /// Moves the cursor of the cursor plane to the mouse position, and returns false if the
+    /// cursor needs to be drawn into the frames instead.
+    #[cfg(feature = "drm")]
+    fn update_hardware_cursor(&self, mouse_position: Option<LogicalPosition>) -> bool {
+        // The cursor plane can't be rotated with the frames
+        if self.rotation != RenderingRotation::NoRotation {
+            return false;
+        }
+        let Some(drm_output) = self.renderer.drm_output() else { return false };
+        let scale_factor = self.window.scale_factor();
+        let position = mouse_position.map(|position| {
+            let position = position.to_physical(scale_factor);
+            (position.x, position.y)
+        });
+        drm_output.set_cursor_position(position, || mouse_cursor_image().to_rgba8_premultiplied())
+    }
+
+    #[cfg(not(feature = "drm"))]
+    fn update_hardware_cursor(&self, _mouse_position: Option<LogicalPosition>) -> bool {
+        false
+    }
 }
 
 fn mouse_cursor_image() -> Image {
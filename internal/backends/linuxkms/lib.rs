@@ -16,6 +16,8 @@
 
 #[cfg(all(target_os = "linux", feature = "drm"))]
 mod drmoutput;
+#[cfg(all(target_os = "linux", feature = "drm"))]
+pub use drmoutput::OverlayPlane;
 
 #[cfg(target_os = "linux")]
 mod display;
@@ -37,10 +39,13 @@ mod renderer {
 
     pub fn try_skia_then_femtovg_then_software(
         _device_opener: &crate::DeviceOpener,
+        _output: Option<&str>,
     ) -> Result<Box<dyn FullscreenRenderer>, PlatformError> {
         #[allow(unused)]
-        type FactoryFn =
-            fn(&crate::DeviceOpener) -> Result<Box<(dyn FullscreenRenderer)>, PlatformError>;
+        type FactoryFn = fn(
+            &crate::DeviceOpener,
+            Option<&str>,
+        ) -> Result<Box<(dyn FullscreenRenderer)>, PlatformError>;
 
         let renderers = [
             #[cfg(any(feature = "renderer-skia-opengl", feature = "renderer-skia-vulkan"))]
@@ -52,12 +57,12 @@ pub fn try_skia_then_femtovg_then_software(
             ("FemtoVG", femtovg::FemtoVGRendererAdapter::new as FactoryFn),
             #[cfg(feature = "renderer-software")]
             ("Software", sw::SoftwareRendererAdapter::new as FactoryFn),
-            ("", |_| Err(PlatformError::NoPlatform)),
+            ("", |_, _| Err(PlatformError::NoPlatform)),
         ];
 
         let mut renderer_errors: Vec<String> = Vec::new();
         for (name, factory) in renderers {
-            match factory(_device_opener) {
+            match factory(_device_opener, _output) {
                 Ok(renderer) => return Ok(renderer),
                 Err(err) => {
                     renderer_errors.push(if !name.is_empty() {
@@ -88,11 +93,54 @@ pub fn try_skia_then_femtovg_then_software(
 #[cfg(not(target_os = "linux"))]
 use noop_backend::*;
 
+/// The display and the input devices of a window, when showing several windows on different
+/// displays in one process, for example the instrument cluster and the infotainment screen
+/// of a car.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SeatAssignment {
+    /// The name of the DRM connector of the display that shows the window, such as `HDMI-A-1`.
+    /// Set `SLINT_DRM_OUTPUT=list` to print the names of the connectors.
+    pub output: String,
+    /// The input devices whose events are sent to the window, either by the name of the device
+    /// node, such as `event3`, or by the name of the device, such as `ILITEK Multi-Touch`.
+    pub input_devices: Vec<String>,
+}
+
+impl SeatAssignment {
+    /// Parses the seat assignments in the format of the `SLINT_KMS_SEATS` environment variable:
+    /// the assignments are separated by semicolons, and each starts with the output name,
+    /// optionally followed by a colon and a comma separated list of input devices.
+    /// For example: `HDMI-A-1:event2,event3;DSI-1:event4`.
+    #[cfg(target_os = "linux")]
+    pub(crate) fn parse_list(list: &str) -> Vec<Self> {
+        list.split(';')
+            .map(str::trim)
+            .filter(|assignment| !assignment.is_empty())
+            .map(|assignment| {
+                let (output, input_devices) =
+                    assignment.split_once(':').unwrap_or((assignment, ""));
+                Self {
+                    output: output.trim().into(),
+                    input_devices: input_devices
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|device| !device.is_empty())
+                        .map(Into::into)
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+}
+
 #[derive(Default)]
 pub struct BackendBuilder {
     pub(crate) renderer_name: Option<String>,
+    pub(crate) seat_assignments: Vec<SeatAssignment>,
     #[cfg(target_os = "linux")]
     pub(crate) libinput_event_hook: Option<Box<dyn Fn(&input::Event) -> bool>>,
+    #[cfg(all(target_os = "linux", feature = "drm"))]
+    pub(crate) overlay_plane_hook: Option<Box<dyn FnOnce(OverlayPlane)>>,
 }
 
 impl BackendBuilder {
@@ -110,6 +158,31 @@ pub fn with_libinput_event_hook(
         self
     }
 
+    /// Sets a function that receives the overlay plane of the display when the window is created,
+    /// to show content such as video frames without rendering it with Slint. The function isn't
+    /// called if the display has no overlay plane.
+    #[cfg(all(target_os = "linux", feature = "drm"))]
+    pub fn with_overlay_plane_hook(
+        mut self,
+        overlay_plane_hook: Box<dyn FnOnce(OverlayPlane)>,
+    ) -> Self {
+        self.overlay_plane_hook = Some(overlay_plane_hook);
+        self
+    }
+
+    /// Shows each window on its own display, with its own input devices. The first window that's
+    /// created is shown on the display of the first assignment, the second window on the second
+    /// one, and so on. The events of the input devices that aren't assigned to any window are sent
+    /// to the first window.
+    ///
+    /// Without assignments, they're read from the `SLINT_KMS_SEATS` environment variable. If that
+    /// isn't set either, the backend shows one window on the display selected with
+    /// `SLINT_DRM_OUTPUT`, and sends it the events of all input devices.
+    pub fn with_seat_assignments(mut self, seat_assignments: Vec<SeatAssignment>) -> Self {
+        self.seat_assignments = seat_assignments;
+        self
+    }
+
     pub fn build(self) -> Result<Backend, PlatformError> {
         Backend::build(self)
     }
@@ -156,8 +156,9 @@ fn get_proc_address(&self, name: &std::ffi::CStr) -> *const std::ffi::c_void {
 impl FemtoVGRendererAdapter {
     pub fn new(
         device_opener: &crate::DeviceOpener,
+        output: Option<&str>,
     ) -> Result<Box<dyn crate::fullscreenwindowadapter::FullscreenRenderer>, PlatformError> {
-        let drm_output = DrmOutput::new(device_opener)?;
+        let drm_output = DrmOutput::new(device_opener, output)?;
         let egl_display = Rc::new(crate::display::gbmdisplay::GbmDisplay::new(drm_output)?);
 
         let renderer = Box::new(Self {
@@ -199,4 +200,8 @@ fn size(&self) -> i_slint_core::api::PhysicalSize {
         let (width, height) = self.gbm_display.drm_output.size();
         i_slint_core::api::PhysicalSize::new(width, height)
     }
+
+    fn drm_output(&self) -> Option<&DrmOutput> {
+        Some(&self.gbm_display.drm_output)
+    }
 }
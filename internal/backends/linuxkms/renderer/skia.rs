@@ -61,7 +61,12 @@ impl SkiaRendererAdapter {
     #[cfg(feature = "renderer-skia-vulkan")]
     pub fn new_vulkan(
         _device_opener: &crate::DeviceOpener,
+        output: Option<&str>,
     ) -> Result<Box<dyn crate::fullscreenwindowadapter::FullscreenRenderer>, PlatformError> {
+        // The Vulkan displays don't map to the names of the DRM connectors
+        if output.is_some() {
+            return Err("The Skia Vulkan renderer can't select the display by output name".into());
+        }
         // TODO: figure out how to associate vulkan with an existing drm fd.
         let display = crate::display::vulkandisplay::create_vulkan_display()?;
 
@@ -90,8 +95,9 @@ pub fn new_vulkan(
     #[cfg(feature = "renderer-skia-opengl")]
     pub fn new_opengl(
         device_opener: &crate::DeviceOpener,
+        output: Option<&str>,
     ) -> Result<Box<dyn crate::fullscreenwindowadapter::FullscreenRenderer>, PlatformError> {
-        let drm_output = DrmOutput::new(device_opener)?;
+        let drm_output = DrmOutput::new(device_opener, output)?;
         let display = Arc::new(crate::display::gbmdisplay::GbmDisplay::new(drm_output)?);
 
         let (width, height) = display.drm_output.size();
@@ -131,9 +137,13 @@ pub fn new_opengl(
 
     pub fn new_software(
         device_opener: &crate::DeviceOpener,
+        output: Option<&str>,
     ) -> Result<Box<dyn crate::fullscreenwindowadapter::FullscreenRenderer>, PlatformError> {
-        let display =
-            crate::display::swdisplay::new(device_opener, SKIA_SUPPORTED_DRM_FOURCC_FORMATS)?;
+        let display = crate::display::swdisplay::new(
+            device_opener,
+            output,
+            SKIA_SUPPORTED_DRM_FOURCC_FORMATS,
+        )?;
 
         let skia_software_surface: i_slint_renderer_skia::software_surface::SoftwareSurface =
             DrmDumbBufferAccess { display: display.clone() }.into();
@@ -157,22 +167,23 @@ pub fn new_software(
 
     pub fn new_try_vulkan_then_opengl_then_software(
         device_opener: &crate::DeviceOpener,
+        output: Option<&str>,
     ) -> Result<Box<dyn crate::fullscreenwindowadapter::FullscreenRenderer>, PlatformError> {
         #[allow(unused_assignments)]
         let mut result = Err(format!("No skia renderer available").into());
 
         #[cfg(feature = "renderer-skia-vulkan")]
         {
-            result = Self::new_vulkan(device_opener);
+            result = Self::new_vulkan(device_opener, output);
         }
 
         #[cfg(feature = "renderer-skia-opengl")]
         if result.is_err() {
-            result = Self::new_opengl(device_opener);
+            result = Self::new_opengl(device_opener, output);
         }
 
         if result.is_err() {
-            result = Self::new_software(device_opener);
+            result = Self::new_software(device_opener, output);
         }
 
         result
@@ -203,6 +214,10 @@ fn render_and_present(
     fn size(&self) -> i_slint_core::api::PhysicalSize {
         self.size
     }
+
+    fn drm_output(&self) -> Option<&crate::drmoutput::DrmOutput> {
+        self.presenter.drm_output()
+    }
 }
 struct DrmDumbBufferAccess {
     display: Arc<dyn crate::display::swdisplay::SoftwareBufferDisplay>,
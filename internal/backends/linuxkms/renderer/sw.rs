@@ -105,9 +105,11 @@ fn background() -> Self {
 impl SoftwareRendererAdapter {
     pub fn new(
         device_opener: &crate::DeviceOpener,
+        output: Option<&str>,
     ) -> Result<Box<dyn crate::fullscreenwindowadapter::FullscreenRenderer>, PlatformError> {
         let display = crate::display::swdisplay::new(
             device_opener,
+            output,
             SOFTWARE_RENDER_SUPPORTED_DRM_FOURCC_FORMATS,
         )?;
 
@@ -187,4 +189,8 @@ fn render_and_present(
     fn size(&self) -> i_slint_core::api::PhysicalSize {
         self.size
     }
+
+    fn drm_output(&self) -> Option<&crate::drmoutput::DrmOutput> {
+        self.presenter.drm_output()
+    }
 }
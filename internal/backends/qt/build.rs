@@ -45,6 +45,8 @@ fn main() {
 
     println!("cargo:rerun-if-changed=lib.rs");
     println!("cargo:rerun-if-changed=qt_accessible.rs");
+    println!("cargo:rerun-if-changed=qt_file_dialog.rs");
+    println!("cargo:rerun-if-changed=qt_tray_icon.rs");
     println!("cargo:rerun-if-changed=qt_widgets.rs");
     println!("cargo:rerun-if-changed=qt_widgets/button.rs");
     println!("cargo:rerun-if-changed=qt_widgets/checkbox.rs");
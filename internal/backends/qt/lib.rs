@@ -15,6 +15,10 @@
 #[cfg(not(no_qt))]
 mod qt_accessible;
 #[cfg(not(no_qt))]
+mod qt_file_dialog;
+#[cfg(not(no_qt))]
+mod qt_tray_icon;
+#[cfg(not(no_qt))]
 mod qt_widgets;
 #[cfg(not(no_qt))]
 mod qt_window;
@@ -30,6 +34,21 @@ pub extern "C" fn slint_qt_get_widget(
     ) -> *mut std::ffi::c_void {
         std::ptr::null_mut()
     }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_qt_set_parent_widget(
+        _: &i_slint_core::window::WindowAdapterRc,
+        _: *mut std::ffi::c_void,
+    ) {
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_qt_embed_widget(
+        _: &i_slint_core::window::WindowAdapterRc,
+        _: std::ptr::NonNull<std::ffi::c_void>,
+        _: &i_slint_core::lengths::LogicalRect,
+    ) {
+    }
 }
 
 /// NativeWidgets and NativeGlobals are "type list" containing all the native widgets and global types.
@@ -303,6 +322,150 @@ fn clipboard_text(&self, _clipboard: i_slint_core::platform::Clipboard) -> Optio
         None
     }
 
+    #[cfg(not(no_qt))]
+    fn set_clipboard_data(
+        &self,
+        data: i_slint_core::platform::ClipboardData,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) {
+        use cpp::cpp;
+        let is_selection: bool = match clipboard {
+            i_slint_core::platform::Clipboard::DefaultClipboard => false,
+            i_slint_core::platform::Clipboard::SelectionClipboard => true,
+            _ => return,
+        };
+        let has_text = data.text.is_some();
+        let text: qttypes::QString = data.text.unwrap_or_default().into();
+        let has_html = data.html.is_some();
+        let html: qttypes::QString = data.html.unwrap_or_default().into();
+        let (width, height) =
+            data.image.as_ref().map_or((0, 0), |image| (image.width(), image.height()));
+        let image_bytes = data.image.as_ref().map_or(&[][..], |image| image.as_bytes());
+        let image_ptr = image_bytes.as_ptr();
+        cpp! {unsafe [is_selection as "bool", has_text as "bool", text as "QString", has_html as "bool", html as "QString",
+                      width as "uint32_t", height as "uint32_t", image_ptr as "const uchar *"] {
+            ensure_initialized();
+            if (is_selection && !QGuiApplication::clipboard()->supportsSelection())
+                return;
+            auto mime_data = new QMimeData();
+            if (has_text)
+                mime_data->setText(text);
+            if (has_html)
+                mime_data->setHtml(html);
+            if (width > 0 && height > 0)
+                mime_data->setImageData(QImage(image_ptr, width, height, width * 4, QImage::Format_RGBA8888).copy());
+            QGuiApplication::clipboard()->setMimeData(mime_data, is_selection ? QClipboard::Selection : QClipboard::Clipboard);
+        } }
+    }
+
+    #[cfg(not(no_qt))]
+    fn clipboard_data(
+        &self,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) -> Option<i_slint_core::platform::ClipboardData> {
+        use cpp::cpp;
+        let mut data = i_slint_core::platform::ClipboardData::default();
+        data.text = self.clipboard_text(clipboard.clone());
+        let is_selection: bool = match clipboard {
+            i_slint_core::platform::Clipboard::DefaultClipboard => false,
+            i_slint_core::platform::Clipboard::SelectionClipboard => true,
+            _ => return None,
+        };
+        let html = cpp! {unsafe [is_selection as "bool"] -> qttypes::QString as "QString" {
+            if (is_selection && !QGuiApplication::clipboard()->supportsSelection())
+                return QString();
+            auto mime_data = QGuiApplication::clipboard()->mimeData(is_selection ? QClipboard::Selection : QClipboard::Clipboard);
+            return mime_data && mime_data->hasHtml() ? mime_data->html() : QString();
+        } };
+        if !html.is_empty() {
+            data.html = Some(html.into());
+        }
+        let mut size = qttypes::QSize { width: 0, height: 0 };
+        let size_ref: &mut qttypes::QSize = &mut size;
+        let image_bytes = cpp! {unsafe [is_selection as "bool", size_ref as "QSize*"] -> qttypes::QByteArray as "QByteArray" {
+            if (is_selection && !QGuiApplication::clipboard()->supportsSelection())
+                return QByteArray();
+            auto mime_data = QGuiApplication::clipboard()->mimeData(is_selection ? QClipboard::Selection : QClipboard::Clipboard);
+            if (!mime_data || !mime_data->hasImage())
+                return QByteArray();
+            QImage image = qvariant_cast<QImage>(mime_data->imageData()).convertToFormat(QImage::Format_RGBA8888);
+            *size_ref = image.size();
+            QByteArray bytes;
+            for (int y = 0; y < image.height(); ++y)
+                bytes.append(reinterpret_cast<const char *>(image.constScanLine(y)), image.width() * 4);
+            return bytes;
+        } };
+        if size.width > 0 && size.height > 0 {
+            data.image = Some(i_slint_core::graphics::SharedPixelBuffer::clone_from_slice(
+                image_bytes.to_slice(),
+                size.width,
+                size.height,
+            ));
+        }
+        (data.text.is_some() || data.html.is_some() || data.image.is_some()).then_some(data)
+    }
+
+    #[cfg(not(no_qt))]
+    fn open_url(&self, url: &str) -> Result<(), PlatformError> {
+        use cpp::cpp;
+        let url: qttypes::QString = url.into();
+        let opened = cpp! {unsafe [url as "QString"] -> bool as "bool" {
+            ensure_initialized();
+            return QDesktopServices::openUrl(QUrl(url));
+        } };
+        if opened {
+            Ok(())
+        } else {
+            Err(PlatformError::Other("QDesktopServices could not open the url".into()))
+        }
+    }
+
+    #[cfg(not(no_qt))]
+    fn screens(&self) -> Result<Vec<i_slint_core::api::Screen>, PlatformError> {
+        use cpp::cpp;
+        let count = cpp! {unsafe [] -> usize as "size_t" {
+            ensure_initialized();
+            return QGuiApplication::screens().size();
+        }};
+        Ok((0..count)
+            .filter_map(|index| {
+                let screen_ptr = cpp! {unsafe [index as "size_t"] -> *mut std::ffi::c_void as "QScreen*" {
+                    return QGuiApplication::screens().value(index);
+                }};
+                qt_window::screen_from_qscreen(screen_ptr)
+            })
+            .collect())
+    }
+
+    #[cfg(not(no_qt))]
+    fn show_file_dialog(
+        &self,
+        kind: i_slint_core::dialogs::FileDialogKind,
+        options: i_slint_core::dialogs::FileDialogOptions,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<Option<std::path::PathBuf>, PlatformError>>>,
+    > {
+        let parent = options
+            .parent_window_adapter()
+            .and_then(|adapter| {
+                adapter
+                    .internal(i_slint_core::InternalToken)?
+                    .as_any()
+                    .downcast_ref::<qt_window::QtWindow>()
+                    .map(|window| window.widget_ptr().as_ptr() as *mut std::ffi::c_void)
+            })
+            .unwrap_or(std::ptr::null_mut());
+        qt_file_dialog::show(kind, options, parent)
+    }
+
+    #[cfg(not(no_qt))]
+    fn create_tray_icon(
+        &self,
+        events: i_slint_core::platform::TrayIconEvents,
+    ) -> Result<Box<dyn i_slint_core::platform::TrayIconAdapter>, PlatformError> {
+        Ok(Box::new(qt_tray_icon::QtTrayIcon::new(events)?))
+    }
+
     #[cfg(not(no_qt))]
     fn click_interval(&self) -> core::time::Duration {
         let duration_ms = unsafe {
@@ -326,18 +489,61 @@ fn cursor_flash_cycle(&self) -> core::time::Duration {
 
 /// This helper trait can be used to obtain access to a pointer to a QtWidget for a given
 /// [`slint::Window`](slint:rust:slint/struct.window).")]
+///
+/// It also allows migrating an application from Qt incrementally, by showing a Slint window
+/// inside of an existing QWidget hierarchy, and by showing QWidgets inside of a Slint window.
 #[cfg(not(no_qt))]
 pub trait QtWidgetAccessor {
     fn qt_widget_ptr(&self) -> Option<std::ptr::NonNull<()>>;
+
+    /// Makes the window a child widget of the given `QWidget*`, for example to add it to the
+    /// QLayout of an existing QWidget. The parent then decides the size of the window.
+    /// With `None`, the window becomes a top-level window again.
+    fn set_qt_parent_widget(&self, parent: Option<std::ptr::NonNull<()>>);
+
+    /// Shows the given `QWidget*` as a child of the window, over the given area of the window in
+    /// logical coordinates. Call it again to move the widget, for example when the geometry of a
+    /// placeholder element changes.
+    fn embed_qt_widget(
+        &self,
+        widget: std::ptr::NonNull<()>,
+        position: i_slint_core::api::LogicalPosition,
+        size: i_slint_core::api::LogicalSize,
+    );
 }
 
 #[cfg(not(no_qt))]
 impl QtWidgetAccessor for i_slint_core::api::Window {
     fn qt_widget_ptr(&self) -> Option<std::ptr::NonNull<()>> {
-        i_slint_core::window::WindowInner::from_pub(self)
-            .window_adapter()
-            .internal(i_slint_core::InternalToken)
-            .and_then(|wa| wa.as_any().downcast_ref::<qt_window::QtWindow>())
-            .map(qt_window::QtWindow::widget_ptr)
+        with_qt_window(self, qt_window::QtWindow::widget_ptr)
+    }
+
+    fn set_qt_parent_widget(&self, parent: Option<std::ptr::NonNull<()>>) {
+        with_qt_window(self, |window| window.set_parent_widget(parent));
     }
+
+    fn embed_qt_widget(
+        &self,
+        widget: std::ptr::NonNull<()>,
+        position: i_slint_core::api::LogicalPosition,
+        size: i_slint_core::api::LogicalSize,
+    ) {
+        let geometry = i_slint_core::lengths::LogicalRect::new(
+            i_slint_core::lengths::LogicalPoint::new(position.x, position.y),
+            i_slint_core::lengths::LogicalSize::new(size.width, size.height),
+        );
+        with_qt_window(self, |window| window.embed_widget(widget, geometry));
+    }
+}
+
+#[cfg(not(no_qt))]
+fn with_qt_window<R>(
+    window: &i_slint_core::api::Window,
+    f: impl FnOnce(&qt_window::QtWindow) -> R,
+) -> Option<R> {
+    i_slint_core::window::WindowInner::from_pub(window)
+        .window_adapter()
+        .internal(i_slint_core::InternalToken)
+        .and_then(|wa| wa.as_any().downcast_ref::<qt_window::QtWindow>())
+        .map(f)
 }
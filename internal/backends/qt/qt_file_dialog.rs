@@ -0,0 +1,120 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! The file dialogs, implemented with QFileDialog
+
+use cpp::*;
+use i_slint_core::dialogs::{FileDialogKind, FileDialogOptions};
+use i_slint_core::platform::PlatformError;
+use std::cell::RefCell;
+use std::ffi::c_void;
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Poll, Waker};
+
+cpp! {{
+    #include <QtWidgets/QFileDialog>
+}}
+
+/// The result of a dialog, set when it's closed
+#[derive(Default)]
+struct DialogState {
+    result: RefCell<Option<Option<PathBuf>>>,
+    waker: RefCell<Option<Waker>>,
+}
+
+impl DialogState {
+    /// Sets the result, unless the dialog already finished, and wakes the future
+    fn finish(&self, path: Option<PathBuf>) {
+        self.result.borrow_mut().get_or_insert(path);
+        let waker = self.waker.borrow_mut().take();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// Opens the dialog window-modal for the widget `parent`, which may be null, without blocking
+/// the event loop. The returned future resolves when the dialog is closed.
+pub fn show(
+    kind: FileDialogKind,
+    options: FileDialogOptions,
+    parent: *mut c_void,
+) -> Pin<Box<dyn Future<Output = Result<Option<PathBuf>, PlatformError>>>> {
+    let kind = match kind {
+        FileDialogKind::OpenFile => 0,
+        FileDialogKind::SaveFile => 1,
+        FileDialogKind::PickFolder => 2,
+        _ => {
+            return Box::pin(std::future::ready(Err(PlatformError::Other(format!(
+                "Unsupported file dialog: {kind:?}"
+            )))))
+        }
+    };
+    let title: qttypes::QString = options.title.as_str().into();
+    let directory: qttypes::QString =
+        options.directory.unwrap_or_default().to_string_lossy().as_ref().into();
+    let file_name: qttypes::QString = options.file_name.unwrap_or_default().as_str().into();
+    let filter = options
+        .filters
+        .iter()
+        .map(|filter| {
+            let patterns: Vec<_> = filter.extensions.iter().map(|ext| format!("*.{ext}")).collect();
+            format!("{} ({})", filter.name, patterns.join(" "))
+        })
+        .collect::<Vec<_>>()
+        .join(";;");
+    let filter: qttypes::QString = filter.as_str().into();
+
+    let state = Rc::new(DialogState::default());
+    // Released when the dialog is destroyed
+    let state_ptr = Rc::into_raw(state.clone());
+    cpp! {unsafe [kind as "int", title as "QString", directory as "QString", file_name as "QString",
+                  filter as "QString", parent as "QWidget*", state_ptr as "const void*"] {
+        ensure_initialized();
+        auto dialog = new QFileDialog(parent, title, directory, filter);
+        dialog->setAttribute(Qt::WA_DeleteOnClose);
+        switch (kind) {
+            case 0:
+                dialog->setFileMode(QFileDialog::ExistingFile);
+                break;
+            case 1:
+                dialog->setAcceptMode(QFileDialog::AcceptSave);
+                dialog->setFileMode(QFileDialog::AnyFile);
+                if (!file_name.isEmpty())
+                    dialog->selectFile(file_name);
+                break;
+            default:
+                dialog->setFileMode(QFileDialog::Directory);
+                dialog->setOption(QFileDialog::ShowDirsOnly);
+                break;
+        }
+        QObject::connect(dialog, &QDialog::finished, [dialog, state_ptr](int result) {
+            auto files = dialog->selectedFiles();
+            QString path = result == QDialog::Accepted && !files.isEmpty() ? files.first() : QString();
+            rust!(Slint_file_dialog_finished [state_ptr: &DialogState as "const void*", path: qttypes::QString as "QString"] {
+                let path = String::from(&path);
+                state_ptr.finish((!path.is_empty()).then(|| path.into()));
+            });
+        });
+        // Also when the parent is destroyed while the dialog is open, which doesn't emit `finished`
+        QObject::connect(dialog, &QObject::destroyed, [state_ptr] {
+            rust!(Slint_file_dialog_destroyed [state_ptr: *const DialogState as "const void*"] {
+                let state = unsafe { Rc::from_raw(state_ptr) };
+                state.finish(None);
+            });
+        });
+        // Unlike exec(), open() returns right away
+        dialog->open();
+    }};
+
+    Box::pin(std::future::poll_fn(move |cx| match state.result.borrow_mut().take() {
+        Some(result) => Poll::Ready(Ok(result)),
+        None => {
+            *state.waker.borrow_mut() = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }))
+}
@@ -0,0 +1,143 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! The tray icon, implemented with QSystemTrayIcon
+
+use cpp::*;
+use i_slint_core::graphics::{Image, ImageInner};
+use i_slint_core::platform::{PlatformError, TrayIconAdapter, TrayIconEvents};
+use i_slint_core::tray_icon::{Menu, MenuItem};
+use std::ffi::c_void;
+
+cpp! {{
+    #include <QtWidgets/QSystemTrayIcon>
+    #include <QtWidgets/QMenu>
+
+    using QSystemTrayIconPtr = std::unique_ptr<QSystemTrayIcon>;
+    using QMenuPtr = std::unique_ptr<QMenu>;
+}}
+
+cpp_class!(unsafe struct QSystemTrayIconPtr as "QSystemTrayIconPtr");
+cpp_class!(unsafe struct QMenuPtr as "QMenuPtr");
+
+pub struct QtTrayIcon {
+    tray_icon: QSystemTrayIconPtr,
+    /// QSystemTrayIcon doesn't own its menu
+    menu: std::cell::RefCell<QMenuPtr>,
+    /// Boxed so that the signal handlers can keep a pointer to it. Dropped after the tray icon
+    /// and the menu, which disconnects them.
+    events: Box<TrayIconEvents>,
+}
+
+impl QtTrayIcon {
+    pub fn new(events: TrayIconEvents) -> Result<Self, PlatformError> {
+        let events = Box::new(events);
+        let events_ptr: *const TrayIconEvents = &*events;
+        let tray_icon = cpp! {unsafe [events_ptr as "void*"] -> QSystemTrayIconPtr as "QSystemTrayIconPtr" {
+            ensure_initialized();
+            if (!QSystemTrayIcon::isSystemTrayAvailable())
+                return {};
+            auto tray_icon = std::make_unique<QSystemTrayIcon>();
+            QObject::connect(tray_icon.get(), &QSystemTrayIcon::activated, [events_ptr](QSystemTrayIcon::ActivationReason reason) {
+                if (reason != QSystemTrayIcon::Trigger && reason != QSystemTrayIcon::DoubleClick)
+                    return;
+                rust!(Slint_tray_icon_activated [events_ptr: &TrayIconEvents as "void*"] {
+                    events_ptr.activated();
+                });
+            });
+            tray_icon->show();
+            return tray_icon;
+        }};
+        let is_null = cpp! {unsafe [tray_icon as "QSystemTrayIconPtr*"] -> bool as "bool" {
+            return !*tray_icon;
+        }};
+        if is_null {
+            return Err(PlatformError::Other("The system tray is not available".into()));
+        }
+        Ok(Self { tray_icon, menu: Default::default(), events })
+    }
+}
+
+impl TrayIconAdapter for QtTrayIcon {
+    fn set_visible(&self, visible: bool) -> Result<(), PlatformError> {
+        let tray_icon = &self.tray_icon;
+        cpp! {unsafe [tray_icon as "QSystemTrayIconPtr*", visible as "bool"] {
+            (*tray_icon)->setVisible(visible);
+        }};
+        Ok(())
+    }
+
+    fn set_icon(&self, icon: &Image) -> Result<(), PlatformError> {
+        let inner: &ImageInner = icon.into();
+        let Some(pixmap) = crate::qt_window::image_to_pixmap(inner, None) else {
+            return Err(PlatformError::Other("Could not convert the tray icon image".into()));
+        };
+        let tray_icon = &self.tray_icon;
+        cpp! {unsafe [tray_icon as "QSystemTrayIconPtr*", pixmap as "QPixmap"] {
+            (*tray_icon)->setIcon(QIcon(pixmap));
+        }};
+        Ok(())
+    }
+
+    fn set_tooltip(&self, tooltip: &str) -> Result<(), PlatformError> {
+        let tooltip: qttypes::QString = tooltip.into();
+        let tray_icon = &self.tray_icon;
+        cpp! {unsafe [tray_icon as "QSystemTrayIconPtr*", tooltip as "QString"] {
+            (*tray_icon)->setToolTip(tooltip);
+        }};
+        Ok(())
+    }
+
+    fn set_menu(&self, menu: &Menu) -> Result<(), PlatformError> {
+        let new_menu = cpp! {unsafe [] -> QMenuPtr as "QMenuPtr" {
+            return std::make_unique<QMenu>();
+        }};
+        let menu_ptr = cpp! {unsafe [new_menu as "QMenuPtr"] -> *mut c_void as "QMenu*" {
+            return new_menu.get();
+        }};
+        let events_ptr: *const TrayIconEvents = &*self.events;
+        add_menu_items(menu_ptr, &menu.items, events_ptr);
+        let tray_icon = &self.tray_icon;
+        cpp! {unsafe [tray_icon as "QSystemTrayIconPtr*", menu_ptr as "QMenu*"] {
+            (*tray_icon)->setContextMenu(menu_ptr);
+        }};
+        // The previous menu is deleted after being replaced
+        *self.menu.borrow_mut() = new_menu;
+        Ok(())
+    }
+}
+
+fn add_menu_items(menu: *mut c_void, items: &[MenuItem], events_ptr: *const TrayIconEvents) {
+    for item in items {
+        let title: qttypes::QString = item.title.as_str().into();
+        let enabled = item.enabled;
+        if item.is_separator {
+            cpp! {unsafe [menu as "QMenu*"] {
+                menu->addSeparator();
+            }};
+        } else if let Some(sub_menu) = &item.sub_menu {
+            let sub = cpp! {unsafe [menu as "QMenu*", title as "QString", enabled as "bool"] -> *mut c_void as "QMenu*" {
+                auto sub = menu->addMenu(title);
+                sub->setEnabled(enabled);
+                return sub;
+            }};
+            add_menu_items(sub, &sub_menu.items, events_ptr);
+        } else {
+            let id: qttypes::QString = item.id.as_str().into();
+            let checkable = item.checked.is_some();
+            let checked = item.checked.unwrap_or_default();
+            cpp! {unsafe [menu as "QMenu*", title as "QString", enabled as "bool", checkable as "bool",
+                          checked as "bool", id as "QString", events_ptr as "void*"] {
+                auto action = menu->addAction(title);
+                action->setEnabled(enabled);
+                action->setCheckable(checkable);
+                action->setChecked(checked);
+                QObject::connect(action, &QAction::triggered, [events_ptr, id] {
+                    rust!(Slint_tray_icon_menu_item_activated [events_ptr: &TrayIconEvents as "void*", id: &qttypes::QString as "const QString&"] {
+                        events_ptr.menu_item_activated(&String::from(id));
+                    });
+                });
+            }};
+        }
+    }
+}
@@ -151,6 +151,7 @@ pub fn draw(&self, callback: &mut dyn FnMut(u32, u32, &[u8])) {
     #include <QtWidgets/QStyleFactory>
     #include <QtGui/QPainter>
     #include <QtGui/QClipboard>
+    #include <QtGui/QDesktopServices>
     #include <QtGui/QPaintEngine>
     #include <QtCore/QMimeData>
     #include <QtCore/QDebug>
@@ -289,7 +289,7 @@ fn input_event(
                 }
             }
             MouseEvent::Wheel { .. } => return InputEventResult::EventIgnored,
-            MouseEvent::DragMove(..) | MouseEvent::Drop(..) => {
+            MouseEvent::DragMove(..) | MouseEvent::Drop(..) | MouseEvent::Gesture { .. } => {
                 return InputEventResult::EventIgnored
             }
         });
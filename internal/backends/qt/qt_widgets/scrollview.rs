@@ -240,7 +240,9 @@ fn input_event(
                     }
                     InputEventResult::EventAccepted
                 }
-                MouseEvent::DragMove(..) | MouseEvent::Drop(..) => InputEventResult::EventIgnored,
+                MouseEvent::DragMove(..) | MouseEvent::Drop(..) | MouseEvent::Gesture { .. } => {
+                    InputEventResult::EventIgnored
+                }
             };
             self.data.set(data);
             result
@@ -251,7 +251,9 @@ fn input_event(
                 debug_assert_ne!(*button, PointerEventButton::Left);
                 InputEventResult::EventIgnored
             }
-            MouseEvent::DragMove(..) | MouseEvent::Drop(..) => InputEventResult::EventIgnored,
+            MouseEvent::DragMove(..) | MouseEvent::Drop(..) | MouseEvent::Gesture { .. } => {
+                InputEventResult::EventIgnored
+            }
         };
         data.active_controls = new_control;
 
@@ -224,7 +224,9 @@ fn input_event(
 
                     true
                 }
-                MouseEvent::DragMove(..) | MouseEvent::Drop(..) => false,
+                MouseEvent::DragMove(..) | MouseEvent::Drop(..) | MouseEvent::Gesture { .. } => {
+                    false
+                }
             };
         data.active_controls = new_control;
         if changed {
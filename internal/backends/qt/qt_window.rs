@@ -20,8 +20,8 @@
 use i_slint_core::item_tree::ParentItemTraversalMode;
 use i_slint_core::item_tree::{ItemTreeRc, ItemTreeRef, ItemTreeWeak};
 use i_slint_core::items::{
-    self, ColorScheme, FillRule, ImageRendering, ItemRc, ItemRef, Layer, LineCap, MouseCursor,
-    Opacity, PointerEventButton, RenderingResult, TextWrap,
+    self, ColorScheme, FillRule, ImageRendering, ItemRc, ItemRef, Layer, LineCap, LineJoin,
+    MouseCursor, Opacity, PointerEventButton, RenderingResult, TextWrap,
 };
 use i_slint_core::layout::Orientation;
 use i_slint_core::lengths::{
@@ -161,6 +161,9 @@ struct SlintWidget : QWidget {
             rust!(Slint_mousePressEvent [rust_window: &QtWindow as "void*", pos: qttypes::QPoint as "QPoint", button: u32 as "int" ] {
                 let position = LogicalPoint::new(pos.x as _, pos.y as _);
                 let button = from_qt_button(button);
+                if button == PointerEventButton::Left && rust_window.start_system_move_or_resize_at(position) {
+                    return;
+                }
                 rust_window.mouse_event(MouseEvent::Pressed{ position, button, click_count: 0 })
             });
         }
@@ -716,6 +719,18 @@ fn draw_path(&mut self, path: Pin<&items::Path>, item_rc: &ItemRc, size: Logical
             LineCap::Round => 0x20,
             LineCap::Square => 0x10,
         };
+        let stroke_pen_join_style: i32 = match path.stroke_line_join() {
+            LineJoin::Miter => 0x00,
+            LineJoin::Bevel => 0x40,
+            LineJoin::Round => 0x80,
+        };
+        // QPen expects the dash pattern in units of the pen width
+        let (stroke_dash, stroke_gap): (f32, f32) = match path.stroke_dash_pattern() {
+            Some((dash, gap)) if stroke_width > 0. => {
+                (dash.get() / stroke_width, gap.get() / stroke_width)
+            }
+            _ => (0., 0.),
+        };
         let pos = qttypes::QPoint { x: offset.x as _, y: offset.y as _ };
         let mut painter_path = QPainterPath::default();
 
@@ -762,11 +777,21 @@ fn to_qpointf(p: Point) -> qttypes::QPointF {
                 stroke_brush as "QBrush",
                 stroke_width as "float",
                 stroke_pen_cap_style as "int",
+                stroke_pen_join_style as "int",
+                stroke_dash as "float",
+                stroke_gap as "float",
                 anti_alias as "bool"] {
             (*painter)->save();
             auto cleanup = qScopeGuard([&] { (*painter)->restore(); });
             (*painter)->translate(pos);
-            (*painter)->setPen(stroke_width > 0 ? QPen(stroke_brush, stroke_width, Qt::SolidLine, Qt::PenCapStyle(stroke_pen_cap_style)) : Qt::NoPen);
+            if (stroke_width > 0) {
+                QPen pen(stroke_brush, stroke_width, Qt::SolidLine, Qt::PenCapStyle(stroke_pen_cap_style), Qt::PenJoinStyle(stroke_pen_join_style));
+                if (stroke_dash > 0)
+                    pen.setDashPattern({ stroke_dash, stroke_gap });
+                (*painter)->setPen(pen);
+            } else {
+                (*painter)->setPen(Qt::NoPen);
+            }
             (*painter)->setBrush(fill_brush);
             (*painter)->setRenderHint(QPainter::Antialiasing, anti_alias);
             (*painter)->drawPath(painter_path);
@@ -1204,6 +1229,12 @@ fn shared_image_buffer_to_pixmap(buffer: &SharedImageBuffer) -> Option<qttypes::
         SharedImageBuffer::RGB8(img) => {
             (qttypes::ImageFormat::RGB888, img.width() * 3, img.as_bytes().as_ptr())
         }
+        SharedImageBuffer::RGB565(img) => {
+            (qttypes::ImageFormat::RGB16, img.width() * 2, img.as_slice().as_ptr() as *const u8)
+        }
+        SharedImageBuffer::Gray8(img) => {
+            (qttypes::ImageFormat::Grayscale8, img.width(), img.as_bytes().as_ptr())
+        }
     };
     let width: i32 = buffer.width() as _;
     let height: i32 = buffer.height() as _;
@@ -1596,6 +1627,40 @@ pub fn widget_ptr(&self) -> NonNull<()> {
         unsafe { std::mem::transmute_copy::<QWidgetPtr, NonNull<_>>(&self.widget_ptr) }
     }
 
+    /// Makes the QWidget of this window a child of `parent`, to show it in an existing QWidget
+    /// hierarchy. With `None`, the QWidget becomes a top-level window again.
+    pub fn set_parent_widget(&self, parent: Option<NonNull<()>>) {
+        let widget_ptr = self.widget_ptr();
+        let parent_ptr = parent.map_or(std::ptr::null_mut(), |p| p.as_ptr());
+        cpp! {unsafe [widget_ptr as "QWidget*", parent_ptr as "QWidget*"] {
+            bool visible = widget_ptr->isVisible();
+            widget_ptr->setParent(parent_ptr, parent_ptr ? Qt::Widget : Qt::Window);
+            // setParent() hides the widget
+            if (visible)
+                widget_ptr->show();
+        }};
+    }
+
+    /// Shows `widget` as a child of the QWidget of this window, over the given area of the window.
+    /// Call it again to move the widget when the area changes.
+    pub fn embed_widget(&self, widget: NonNull<()>, geometry: LogicalRect) {
+        let widget_ptr = self.widget_ptr();
+        let child_ptr = widget.as_ptr();
+        // Qt uses logical units!
+        let rect = qttypes::QRectF {
+            x: geometry.origin.x as _,
+            y: geometry.origin.y as _,
+            width: geometry.size.width as _,
+            height: geometry.size.height as _,
+        };
+        cpp! {unsafe [widget_ptr as "QWidget*", child_ptr as "QWidget*", rect as "QRectF"] {
+            if (child_ptr->parentWidget() != widget_ptr)
+                child_ptr->setParent(widget_ptr);
+            child_ptr->setGeometry(rect.toAlignedRect());
+            child_ptr->show();
+        }};
+    }
+
     fn paint_event(&self, painter: QPainterPtr) {
         let runtime_window = WindowInner::from_pub(&self.window);
         let window_adapter = runtime_window.window_adapter();
@@ -1652,6 +1717,21 @@ fn mouse_event(&self, event: MouseEvent) {
         timer_event();
     }
 
+    /// Moves or resizes the window if the position is in the title bar or a border drawn by the
+    /// application, and returns true in that case.
+    fn start_system_move_or_resize_at(&self, position: LogicalPoint) -> bool {
+        use i_slint_core::api::WindowHitTestArea;
+        let window = WindowInner::from_pub(&self.window);
+        let result = match window.hit_test(i_slint_core::lengths::logical_position_to_api(position))
+        {
+            Some(WindowHitTestArea::TitleBar) => window.start_system_move(),
+            Some(WindowHitTestArea::ResizeBorder(edge)) => window.start_system_resize(edge),
+            _ => return false,
+        };
+        // Let the elements handle the press if the window system doesn't support it
+        result.is_ok()
+    }
+
     fn key_event(&self, key: i32, text: qttypes::QString, released: bool, repeat: bool) {
         i_slint_core::animations::update_animations();
         let text: String = text.into();
@@ -1857,18 +1937,24 @@ fn update_window_properties(&self, properties: i_slint_core::window::WindowPrope
         let fullscreen: bool = properties.is_fullscreen();
         let minimized: bool = properties.is_minimized();
         let maximized: bool = properties.is_maximized();
+        let skip_taskbar: bool = properties.is_skip_taskbar();
+        let opacity = properties.opacity() as f64;
 
         cpp! {unsafe [widget_ptr as "QWidget*",  title as "QString", size as "QSize", background as "QBrush", no_frame as "bool", always_on_top as "bool",
-                      fullscreen as "bool", minimized as "bool", maximized as "bool"] {
+                      fullscreen as "bool", minimized as "bool", maximized as "bool", skip_taskbar as "bool", opacity as "qreal"] {
 
-            if (size != widget_ptr->size()) {
-                widget_ptr->resize(size.expandedTo({1, 1}));
-            }
+            // When embedded in another QWidget, the parent decides the geometry and the state
+            if (widget_ptr->isWindow()) {
+                if (size != widget_ptr->size()) {
+                    widget_ptr->resize(size.expandedTo({1, 1}));
+                }
 
-            widget_ptr->setWindowFlag(Qt::FramelessWindowHint, no_frame);
-            widget_ptr->setWindowFlag(Qt::WindowStaysOnTopHint, always_on_top);
+                widget_ptr->setWindowFlag(Qt::FramelessWindowHint, no_frame);
+                widget_ptr->setWindowFlag(Qt::WindowStaysOnTopHint, always_on_top);
+                // Tool windows are not shown in the taskbar
+                widget_ptr->setWindowFlag(Qt::Tool, skip_taskbar);
+                widget_ptr->setWindowOpacity(opacity);
 
-                        {
                 // Depending on the request, we either set or clear the bits.
                 // See also: https://doc.qt.io/qt-6/qt.html#WindowState-enum
                 auto state = widget_ptr->windowState();
@@ -1936,6 +2022,40 @@ fn into_qsize(logical_size: i_slint_core::api::LogicalSize) -> qttypes::QSize {
     }
 }
 
+/// Converts a QScreen, whose geometry is in device independent pixels, to a Screen
+pub(crate) fn screen_from_qscreen(
+    screen_ptr: *mut std::ffi::c_void,
+) -> Option<i_slint_core::api::Screen> {
+    if screen_ptr.is_null() {
+        return None;
+    }
+    let name = cpp! {unsafe [screen_ptr as "QScreen*"] -> qttypes::QString as "QString" {
+        return screen_ptr->name();
+    }};
+    let scale_factor = cpp! {unsafe [screen_ptr as "QScreen*"] -> f64 as "qreal" {
+        return screen_ptr->devicePixelRatio();
+    }};
+    let geometry = cpp! {unsafe [screen_ptr as "QScreen*"] -> qttypes::QRectF as "QRectF" {
+        return QRectF(screen_ptr->geometry());
+    }};
+    let is_primary = cpp! {unsafe [screen_ptr as "QScreen*"] -> bool as "bool" {
+        return screen_ptr == QGuiApplication::primaryScreen();
+    }};
+    Some(i_slint_core::api::Screen::new(
+        String::from(&name),
+        i_slint_core::api::PhysicalPosition::new(
+            (geometry.x * scale_factor).round() as i32,
+            (geometry.y * scale_factor).round() as i32,
+        ),
+        i_slint_core::api::PhysicalSize::new(
+            (geometry.width * scale_factor).round() as u32,
+            (geometry.height * scale_factor).round() as u32,
+        ),
+        scale_factor as f32,
+        is_primary,
+    ))
+}
+
 impl WindowAdapterInternal for QtWindow {
     fn register_item_tree(&self) {
         self.tree_structure_changed.replace(true);
@@ -2086,6 +2206,90 @@ fn bring_to_front(&self) -> Result<(), i_slint_core::platform::PlatformError> {
         }};
         Ok(())
     }
+
+    fn start_system_move(&self) -> Result<(), i_slint_core::platform::PlatformError> {
+        let widget_ptr = self.widget_ptr();
+        let started = cpp! {unsafe [widget_ptr as "QWidget*"] -> bool as "bool" {
+            #if QT_VERSION >= QT_VERSION_CHECK(5, 15, 0)
+            if (auto window = widget_ptr->windowHandle())
+                return window->startSystemMove();
+            #endif
+            return false;
+        }};
+        if started {
+            Ok(())
+        } else {
+            Err("The window system doesn't support moving the window".into())
+        }
+    }
+
+    fn start_system_resize(
+        &self,
+        edge: i_slint_core::api::WindowResizeEdge,
+    ) -> Result<(), i_slint_core::platform::PlatformError> {
+        use i_slint_core::api::WindowResizeEdge;
+        // The values of Qt::Edge
+        let (top, left, right, bottom) = (1, 2, 4, 8);
+        let edges: u32 = match edge {
+            WindowResizeEdge::Top => top,
+            WindowResizeEdge::Bottom => bottom,
+            WindowResizeEdge::Left => left,
+            WindowResizeEdge::TopLeft => top | left,
+            WindowResizeEdge::TopRight => top | right,
+            WindowResizeEdge::BottomLeft => bottom | left,
+            WindowResizeEdge::BottomRight => bottom | right,
+            _ => right,
+        };
+        let widget_ptr = self.widget_ptr();
+        let started = cpp! {unsafe [widget_ptr as "QWidget*", edges as "uint"] -> bool as "bool" {
+            #if QT_VERSION >= QT_VERSION_CHECK(5, 15, 0)
+            if (auto window = widget_ptr->windowHandle())
+                return window->startSystemResize(Qt::Edges(edges));
+            #endif
+            return false;
+        }};
+        if started {
+            Ok(())
+        } else {
+            Err("The window system doesn't support resizing the window".into())
+        }
+    }
+
+    fn screen(&self) -> Option<i_slint_core::api::Screen> {
+        let widget_ptr = self.widget_ptr();
+        let screen_ptr = cpp! {unsafe [widget_ptr as "QWidget*"] -> *mut std::ffi::c_void as "QScreen*" {
+            auto window = widget_ptr->windowHandle();
+            return window ? window->screen() : nullptr;
+        }};
+        screen_from_qscreen(screen_ptr)
+    }
+
+    fn set_screen(
+        &self,
+        screen: &i_slint_core::api::Screen,
+    ) -> Result<(), i_slint_core::platform::PlatformError> {
+        let name: qttypes::QString = screen.name().as_str().into();
+        let widget_ptr = self.widget_ptr();
+        let found = cpp! {unsafe [widget_ptr as "QWidget*", name as "QString"] -> bool as "bool" {
+            for (auto screen : QGuiApplication::screens()) {
+                if (screen->name() != name)
+                    continue;
+                if (auto window = widget_ptr->windowHandle())
+                    window->setScreen(screen);
+                widget_ptr->move(screen->geometry().topLeft());
+                // The window only leaves its screen when shown fullscreen again
+                if (widget_ptr->isFullScreen())
+                    widget_ptr->showFullScreen();
+                return true;
+            }
+            return false;
+        }};
+        if found {
+            Ok(())
+        } else {
+            Err("The screen is not connected".into())
+        }
+    }
 }
 
 impl i_slint_core::renderer::RendererSealed for QtWindow {
@@ -2374,6 +2578,33 @@ pub extern "C" fn slint_qt_get_widget(
                 win.widget_ptr().cast::<c_void>().as_ptr()
             })
     }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_qt_set_parent_widget(
+        window_adapter: &i_slint_core::window::WindowAdapterRc,
+        parent: *mut c_void,
+    ) {
+        if let Some(win) = window_adapter
+            .internal(i_slint_core::InternalToken)
+            .and_then(|wa| <dyn std::any::Any>::downcast_ref::<QtWindow>(wa.as_any()))
+        {
+            win.set_parent_widget(std::ptr::NonNull::new(parent.cast()));
+        }
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_qt_embed_widget(
+        window_adapter: &i_slint_core::window::WindowAdapterRc,
+        widget: std::ptr::NonNull<c_void>,
+        geometry: &i_slint_core::lengths::LogicalRect,
+    ) {
+        if let Some(win) = window_adapter
+            .internal(i_slint_core::InternalToken)
+            .and_then(|wa| <dyn std::any::Any>::downcast_ref::<QtWindow>(wa.as_any()))
+        {
+            win.embed_widget(widget.cast(), *geometry);
+        }
+    }
 }
 
 fn qt_password_character() -> char {
@@ -49,6 +49,13 @@ pub struct BackendSelector {
         Option<Box<dyn i_slint_backend_winit::CustomApplicationHandler>>,
     #[cfg(all(target_os = "linux", feature = "unstable-libinput-09"))]
     libinput_event_hook: Option<Box<dyn Fn(&input::Event) -> bool>>,
+    #[cfg(all(feature = "i-slint-backend-linuxkms", target_os = "linux"))]
+    linuxkms_seat_assignments: Vec<i_slint_backend_linuxkms::SeatAssignment>,
+    #[cfg(all(
+        feature = "i-slint-backend-winit",
+        any(feature = "renderer-femtovg", feature = "renderer-femtovg-wgpu")
+    ))]
+    femtovg_glyph_cache_budget: Option<usize>,
 }
 
 impl BackendSelector {
@@ -233,10 +240,44 @@ pub fn with_libinput_event_hook(
         self
     }
 
+    /// Configures the LinuxKMS backend to show each window on its own display, with its own input
+    /// devices, when the LinuxKMS backend is selected. The first window that's created is shown on the
+    /// display of the first assignment, the second window on the second one, and so on. The events of
+    /// the input devices that aren't assigned to any window are sent to the first window.
+    ///
+    /// *Note*: This function is behind the `backend-linuxkms` and `backend-linuxkms-noseat` feature flags.
+    #[must_use]
+    #[cfg(all(feature = "i-slint-backend-linuxkms", target_os = "linux"))]
+    pub fn with_linuxkms_seat_assignments(
+        mut self,
+        seat_assignments: Vec<i_slint_backend_linuxkms::SeatAssignment>,
+    ) -> Self {
+        self.linuxkms_seat_assignments = seat_assignments;
+        self
+    }
+
+    /// Limits the GPU memory, in bytes, that the FemtoVG renderer uses for its atlas of rasterized glyphs.
+    /// When the atlas exceeds this budget, the glyphs that weren't drawn in the last frame are evicted.
+    /// By default, the atlas grows without limit.
+    ///
+    /// *Note*: The budget is only applied when the winit backend is selected.
+    #[must_use]
+    #[cfg(all(
+        feature = "i-slint-backend-winit",
+        any(feature = "renderer-femtovg", feature = "renderer-femtovg-wgpu")
+    ))]
+    pub fn with_femtovg_glyph_cache_budget(mut self, bytes: usize) -> Self {
+        self.femtovg_glyph_cache_budget = Some(bytes);
+        self
+    }
+
     /// Adds the requirement that the selected renderer must match the given name. This is
     /// equivalent to setting the `SLINT_BACKEND=name` environment variable and requires
     /// that the corresponding renderer feature is enabled. For example, to select the Skia renderer,
     /// enable the `renderer-skia` feature and call this function with `skia` as argument.
+    ///
+    /// `wgpu` selects the renderer of the winit backend that renders with WGPU: Skia if it's enabled
+    /// together with an `unstable-wgpu-*` feature, otherwise FemtoVG with the `renderer-femtovg-wgpu` feature.
     #[must_use]
     pub fn renderer_name(mut self, name: String) -> Self {
         self.renderer = Some(name);
@@ -267,7 +308,9 @@ fn select_internal(&mut self) -> Result<(), PlatformError> {
         #[cfg(any(
             feature = "i-slint-backend-qt",
             feature = "i-slint-backend-winit",
-            feature = "i-slint-backend-linuxkms"
+            feature = "i-slint-backend-linuxkms",
+            feature = "i-slint-backend-layershell",
+            feature = "backend-headless"
         ))]
         if self.backend.is_none() || self.renderer.is_none() {
             let backend_config = std::env::var("SLINT_BACKEND").unwrap_or_default();
@@ -282,10 +325,10 @@ fn select_internal(&mut self) -> Result<(), PlatformError> {
         }
 
         let backend_name = self.backend.as_deref().unwrap_or_else(|| {
-            // Only the winit backend supports graphics API requests right now, so prefer that over
-            // aborting.
+            // Only the winit backend supports graphics API requests and the wgpu renderer right now,
+            // so prefer that over aborting.
             #[cfg(feature = "i-slint-backend-winit")]
-            if self.requested_graphics_api.is_some() {
+            if self.requested_graphics_api.is_some() || self.renderer.as_deref() == Some("wgpu") {
                 return "winit";
             }
             super::DEFAULT_BACKEND_NAME
@@ -309,8 +352,40 @@ fn select_internal(&mut self) -> Result<(), PlatformError> {
                     builder = builder.with_libinput_event_hook(event_hook);
                 }
 
+                if !self.linuxkms_seat_assignments.is_empty() {
+                    builder = builder.with_seat_assignments(core::mem::take(
+                        &mut self.linuxkms_seat_assignments,
+                    ));
+                }
+
+                Box::new(builder.build()?)
+            }
+            #[cfg(all(feature = "i-slint-backend-layershell", target_os = "linux"))]
+            "layershell" => {
+                if self.requested_graphics_api.is_some() {
+                    return Err("The layershell backend does not implement renderer selection by graphics API".into());
+                }
+
+                let mut builder = i_slint_backend_layershell::BackendBuilder::default();
+
+                if let Some(renderer_name) = self.renderer.as_ref() {
+                    builder = builder.with_renderer_name(renderer_name.into());
+                }
+
                 Box::new(builder.build()?)
             }
+            #[cfg(feature = "backend-headless")]
+            "headless" => {
+                if self.requested_graphics_api.is_some() {
+                    return Err("The headless backend does not implement renderer selection by graphics API".into());
+                }
+
+                if self.renderer.as_deref().is_some_and(|name| name != "software" && name != "sw") {
+                    return Err("The headless backend only supports the software renderer".into());
+                }
+
+                Box::new(i_slint_backend_testing::headless::HeadlessBackend::new())
+            }
             #[cfg(feature = "i-slint-backend-winit")]
             "winit" => {
                 let builder = i_slint_backend_winit::Backend::builder();
@@ -345,6 +420,12 @@ fn select_internal(&mut self) -> Result<(), PlatformError> {
                     None => builder,
                 };
 
+                #[cfg(any(feature = "renderer-femtovg", feature = "renderer-femtovg-wgpu"))]
+                let builder = match self.femtovg_glyph_cache_budget {
+                    Some(bytes) => builder.with_femtovg_glyph_cache_budget(bytes),
+                    None => builder,
+                };
+
                 Box::new(builder.build()?)
             }
             #[cfg(feature = "i-slint-backend-qt")]
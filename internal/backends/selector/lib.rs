@@ -7,7 +7,9 @@
     not(any(
         feature = "i-slint-backend-qt",
         feature = "i-slint-backend-winit",
-        feature = "i-slint-backend-linuxkms"
+        feature = "i-slint-backend-linuxkms",
+        feature = "i-slint-backend-layershell",
+        feature = "backend-headless"
     )),
     no_std
 )]
@@ -35,6 +37,16 @@ fn create_linuxkms_backend() -> Result<Box<dyn Platform + 'static>, PlatformErro
     Ok(Box::new(i_slint_backend_linuxkms::BackendBuilder::default().build()?))
 }
 
+#[cfg(all(feature = "i-slint-backend-layershell", target_os = "linux"))]
+fn create_layershell_backend() -> Result<Box<dyn Platform + 'static>, PlatformError> {
+    Ok(Box::new(i_slint_backend_layershell::BackendBuilder::default().build()?))
+}
+
+#[cfg(feature = "backend-headless")]
+fn create_headless_backend() -> Result<Box<dyn Platform + 'static>, PlatformError> {
+    Ok(Box::new(i_slint_backend_testing::headless::HeadlessBackend::new()))
+}
+
 cfg_if::cfg_if! {
     if #[cfg(target_os = "android")] {
         const DEFAULT_BACKEND_NAME: &str = "";
@@ -47,6 +59,12 @@ fn create_linuxkms_backend() -> Result<Box<dyn Platform + 'static>, PlatformErro
     } else if #[cfg(all(feature = "i-slint-backend-linuxkms", target_os = "linux"))] {
         use i_slint_backend_linuxkms as default_backend;
         const DEFAULT_BACKEND_NAME: &str = "linuxkms";
+    } else if #[cfg(all(feature = "i-slint-backend-layershell", target_os = "linux"))] {
+        use i_slint_backend_layershell as default_backend;
+        const DEFAULT_BACKEND_NAME: &str = "layershell";
+    } else if #[cfg(feature = "backend-headless")] {
+        use i_slint_backend_testing::headless as default_backend;
+        const DEFAULT_BACKEND_NAME: &str = "headless";
     } else {
         const DEFAULT_BACKEND_NAME: &str = "";
     }
@@ -56,7 +74,9 @@ fn create_linuxkms_backend() -> Result<Box<dyn Platform + 'static>, PlatformErro
     if #[cfg(all(not(target_os = "android"), any(
             all(feature = "i-slint-backend-qt", not(no_qt)),
             feature = "i-slint-backend-winit",
-            all(feature = "i-slint-backend-linuxkms", target_os = "linux")
+            all(feature = "i-slint-backend-linuxkms", target_os = "linux"),
+            all(feature = "i-slint-backend-layershell", target_os = "linux"),
+            feature = "backend-headless"
         )))] {
         fn create_default_backend() -> Result<Box<dyn Platform + 'static>, PlatformError> {
             use alloc::borrow::Cow;
@@ -68,6 +88,10 @@ fn create_default_backend() -> Result<Box<dyn Platform + 'static>, PlatformError
                 ("Winit", create_winit_backend as fn() -> Result<Box<(dyn Platform + 'static)>, PlatformError>),
                 #[cfg(all(feature = "i-slint-backend-linuxkms", target_os = "linux"))]
                 ("LinuxKMS", create_linuxkms_backend as fn() -> Result<Box<(dyn Platform + 'static)>, PlatformError>),
+                #[cfg(all(feature = "i-slint-backend-layershell", target_os = "linux"))]
+                ("LayerShell", create_layershell_backend as fn() -> Result<Box<(dyn Platform + 'static)>, PlatformError>),
+                #[cfg(feature = "backend-headless")]
+                ("Headless", create_headless_backend as fn() -> Result<Box<(dyn Platform + 'static)>, PlatformError>),
                 ("", || Err(PlatformError::NoPlatform)),
             ];
 
@@ -108,6 +132,16 @@ pub fn create_backend() -> Result<Box<dyn Platform + 'static>, PlatformError>  {
                     }
                     return builder.build().map(|b| Box::new(b) as Box<dyn Platform + 'static>)
                 },
+                #[cfg(all(feature = "i-slint-backend-layershell", target_os = "linux"))]
+                "layershell" => {
+                    let mut builder = i_slint_backend_layershell::BackendBuilder::default();
+                    if !_renderer.is_empty() {
+                        builder = builder.with_renderer_name(_renderer.into());
+                    }
+                    return builder.build().map(|b| Box::new(b) as Box<dyn Platform + 'static>)
+                },
+                #[cfg(feature = "backend-headless")]
+                "headless" => return create_headless_backend(),
                 _ => {},
             }
 
@@ -138,6 +172,8 @@ pub fn parse_backend_env_var(backend_config: &str) -> (&str, &str) {
         "skia" => ("winit", "skia"),
         "sw" | "software" => ("winit", "software"),
         "linuxkms" => ("linuxkms", ""),
+        "layershell" => ("layershell", ""),
+        "headless" => ("headless", ""),
         x => (x, ""),
     })
 }
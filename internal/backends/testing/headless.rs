@@ -0,0 +1,195 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! The headless backend doesn't show any windows, but renders them with the software renderer
+//! on demand, for example with [`Window::take_snapshot()`](i_slint_core::api::Window::take_snapshot).
+//!
+//! Time doesn't pass on its own in this backend: animations and timers follow a virtual clock
+//! that only advances with [`advance_time()`], or when the event loop has nothing else to do
+//! and jumps to the next timer. This makes rendering tests in CI and server-side image
+//! generation deterministic.
+
+use i_slint_core::api::{PhysicalSize, Window};
+use i_slint_core::platform::{PlatformError, WindowEvent};
+use i_slint_core::renderer::Renderer;
+use i_slint_core::software_renderer::SoftwareRenderer;
+use i_slint_core::window::{WindowAdapter, WindowAdapterInternal};
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::testing_backend::{Event, Queue};
+
+/// The size of a window that doesn't have a preferred size.
+const DEFAULT_SIZE: PhysicalSize = PhysicalSize::new(800, 600);
+
+/// Advances the virtual clock of the animations and timers by the specified duration, running
+/// the callbacks of the timers that expire on the way in the order of their timeouts.
+///
+/// This function must be called from the thread that runs the event loop.
+pub fn advance_time(duration: Duration) {
+    let mut remaining = duration.as_millis() as u64;
+    while remaining > 0 {
+        let now = i_slint_core::tests::slint_get_mocked_time();
+        // Stop at each timer, so that a timer that is started by the callback of another one
+        // expires at the right time too.
+        let step = i_slint_core::timers::TimerList::next_timeout()
+            .map_or(remaining, |timeout| timeout.0.saturating_sub(now).clamp(1, remaining));
+        i_slint_core::tests::slint_mock_elapsed_time(step);
+        remaining -= step;
+    }
+}
+
+/// Returns the time on the virtual clock of the animations and timers, since the start of the
+/// application.
+pub fn elapsed_time() -> Duration {
+    Duration::from_millis(i_slint_core::tests::slint_get_mocked_time())
+}
+
+/// The platform of the headless backend. Select it with `SLINT_BACKEND=headless`, or set it with
+/// [`slint::platform::set_platform()`](i_slint_core::platform::set_platform).
+pub struct HeadlessBackend {
+    clipboard: Mutex<Option<String>>,
+    queue: Queue,
+}
+
+impl HeadlessBackend {
+    /// Creates the headless backend. Its event loop must run on the current thread.
+    pub fn new() -> Self {
+        Self {
+            clipboard: Mutex::default(),
+            queue: Queue(Default::default(), std::thread::current()),
+        }
+    }
+}
+
+impl Default for HeadlessBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl i_slint_core::platform::Platform for HeadlessBackend {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(Rc::new_cyclic(|self_weak| HeadlessWindow {
+            window: Window::new(self_weak.clone() as _),
+            size: Default::default(),
+            renderer: SoftwareRenderer::new(),
+        }))
+    }
+
+    fn duration_since_start(&self) -> Duration {
+        elapsed_time()
+    }
+
+    fn set_clipboard_text(&self, text: &str, clipboard: i_slint_core::platform::Clipboard) {
+        if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
+            *self.clipboard.lock().unwrap() = Some(text.into());
+        }
+    }
+
+    fn clipboard_text(&self, clipboard: i_slint_core::platform::Clipboard) -> Option<String> {
+        if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
+            self.clipboard.lock().unwrap().clone()
+        } else {
+            None
+        }
+    }
+
+    fn run_event_loop(&self) -> Result<(), PlatformError> {
+        loop {
+            let e = self.queue.0.lock().unwrap().pop_front();
+            i_slint_core::platform::update_timers_and_animations();
+            match e {
+                Some(Event::Quit) => break Ok(()),
+                Some(Event::Event(e)) => e(),
+                // Nothing happens until the next timer expires, so skip the time in between.
+                None => match i_slint_core::platform::duration_until_next_timer_update() {
+                    Some(duration) => advance_time(duration.max(Duration::from_millis(1))),
+                    None => std::thread::park(),
+                },
+            }
+        }
+    }
+
+    fn new_event_loop_proxy(&self) -> Option<Box<dyn i_slint_core::platform::EventLoopProxy>> {
+        Some(Box::new(self.queue.clone()))
+    }
+}
+
+/// The window adapter of the headless backend. Its window is never shown, but can be rendered
+/// with [`Window::take_snapshot()`](i_slint_core::api::Window::take_snapshot).
+pub struct HeadlessWindow {
+    window: Window,
+    size: Cell<PhysicalSize>,
+    renderer: SoftwareRenderer,
+}
+
+impl WindowAdapterInternal for HeadlessWindow {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl WindowAdapter for HeadlessWindow {
+    fn window(&self) -> &Window {
+        &self.window
+    }
+
+    fn size(&self) -> PhysicalSize {
+        if self.size.get().width == 0 {
+            DEFAULT_SIZE
+        } else {
+            self.size.get()
+        }
+    }
+
+    fn set_size(&self, size: i_slint_core::api::WindowSize) {
+        let scale_factor = self.window.scale_factor();
+        self.size.set(size.to_physical(scale_factor));
+        self.window.dispatch_event(WindowEvent::Resized { size: size.to_logical(scale_factor) });
+    }
+
+    fn renderer(&self) -> &dyn Renderer {
+        &self.renderer
+    }
+
+    fn set_visible(&self, visible: bool) -> Result<(), PlatformError> {
+        if visible {
+            if let Some(scale_factor) =
+                std::env::var("SLINT_SCALE_FACTOR").ok().and_then(|sf| sf.parse().ok())
+            {
+                self.window.try_dispatch_event(WindowEvent::ScaleFactorChanged { scale_factor })?;
+            }
+            let size = self.size();
+            self.window.try_dispatch_event(WindowEvent::Resized {
+                size: size.to_logical(self.window.scale_factor()),
+            })?;
+        }
+        Ok(())
+    }
+
+    fn update_window_properties(&self, properties: i_slint_core::window::WindowProperties<'_>) {
+        if self.size.get().width == 0 {
+            let preferred = properties.layout_constraints().preferred;
+            if preferred.width > 0. && preferred.height > 0. {
+                self.size.set(preferred.to_physical(self.window.scale_factor()));
+            }
+        }
+    }
+
+    fn internal(&self, _: i_slint_core::InternalToken) -> Option<&dyn WindowAdapterInternal> {
+        Some(self)
+    }
+}
+
+#[doc(hidden)]
+pub type NativeWidgets = ();
+#[doc(hidden)]
+pub type NativeGlobals = ();
+#[doc(hidden)]
+pub const HAS_NATIVE_STYLE: bool = false;
+#[doc(hidden)]
+pub mod native_widgets {}
@@ -17,6 +17,8 @@
 mod ffi;
 #[cfg(feature = "system-testing")]
 pub mod systest;
+#[cfg(feature = "headless")]
+pub mod headless;
 
 /// Initialize the testing backend without support for event loop.
 /// This means that each test thread can use its own backend, but global functions that needs
@@ -248,14 +248,14 @@ fn supports_transformations(&self) -> bool {
     }
 }
 
-enum Event {
+pub(crate) enum Event {
     Quit,
     Event(Box<dyn FnOnce() + Send>),
 }
 #[derive(Clone)]
-struct Queue(
-    std::sync::Arc<std::sync::Mutex<std::collections::VecDeque<Event>>>,
-    std::thread::Thread,
+pub(crate) struct Queue(
+    pub(crate) std::sync::Arc<std::sync::Mutex<std::collections::VecDeque<Event>>>,
+    pub(crate) std::thread::Thread,
 );
 
 impl i_slint_core::platform::EventLoopProxy for Queue {
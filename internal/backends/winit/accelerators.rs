@@ -0,0 +1,150 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Application-global keyboard accelerators, such as `Ctrl+S`, that are handled by the event loop
+//! before the key event reaches the focused item.
+
+use i_slint_core::platform::PlatformError;
+use i_slint_core::SharedString;
+use std::cell::RefCell;
+use std::rc::Rc;
+use winit::keyboard::ModifiersState;
+
+/// A key combination, parsed from a string such as `Ctrl+Shift+Z` or `F5`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Accelerator {
+    modifiers: ModifiersState,
+    /// The key as the text Slint uses in key events, lowercase for letters.
+    key: SharedString,
+}
+
+impl Accelerator {
+    pub(crate) fn parse(accelerator: &str) -> Result<Self, PlatformError> {
+        let invalid = || PlatformError::from(format!("Invalid accelerator '{accelerator}'"));
+        let (modifiers, key) = accelerator.rsplit_once('+').unwrap_or(("", accelerator));
+        // "Ctrl++" ends with the plus key
+        let (modifiers, key) = match (modifiers.strip_suffix('+'), key) {
+            (Some(modifiers), "") => (modifiers, "+"),
+            _ => (modifiers, key),
+        };
+
+        let mut modifier_state = ModifiersState::empty();
+        for modifier in modifiers.split('+').filter(|m| !m.is_empty()) {
+            modifier_state |= match modifier.trim().to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => ModifiersState::CONTROL,
+                "shift" => ModifiersState::SHIFT,
+                "alt" | "option" => ModifiersState::ALT,
+                "meta" | "super" | "cmd" | "command" => ModifiersState::SUPER,
+                _ => return Err(invalid()),
+            };
+        }
+
+        let key = key.trim();
+        let key: SharedString = if key.chars().count() == 1 {
+            key.to_lowercase().into()
+        } else {
+            macro_rules! special_key_by_name {
+                ($($char:literal # $name:ident # $($_qt:ident)|* # $($_winit:ident $(($_pos:ident))?)|* # $($_xkb:ident)|*;)*) => {
+                    match key {
+                        $(stringify!($name) => $char.into(),)*
+                        _ => return Err(invalid()),
+                    }
+                }
+            }
+            i_slint_common::for_each_special_keys!(special_key_by_name)
+        };
+        if key.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(Self { modifiers: modifier_state, key })
+    }
+
+    /// `text` is the key without the modifiers applied, for example `1` for Shift+1 on a US layout.
+    fn matches(&self, modifiers: ModifiersState, text: &str) -> bool {
+        self.modifiers == modifiers && self.key.as_str() == text.to_lowercase()
+    }
+}
+
+/// The accelerators registered for the application, shared by all windows.
+#[derive(Default)]
+pub(crate) struct AcceleratorRegistry {
+    accelerators: RefCell<Vec<(Accelerator, Rc<dyn Fn()>)>>,
+}
+
+impl AcceleratorRegistry {
+    /// Registers `callback` for `accelerator`. Fails if the string can't be parsed, or if the same
+    /// key combination was already registered.
+    pub(crate) fn register(
+        &self,
+        accelerator: &str,
+        callback: Rc<dyn Fn()>,
+    ) -> Result<(), PlatformError> {
+        let parsed = Accelerator::parse(accelerator)?;
+        let mut accelerators = self.accelerators.borrow_mut();
+        if accelerators.iter().any(|(existing, _)| *existing == parsed) {
+            return Err(format!(
+                "The accelerator '{accelerator}' conflicts with an accelerator that is already registered"
+            )
+            .into());
+        }
+        accelerators.push((parsed, callback));
+        Ok(())
+    }
+
+    /// Removes the callback registered for `accelerator`. Returns false if there was none.
+    pub(crate) fn unregister(&self, accelerator: &str) -> bool {
+        let Ok(parsed) = Accelerator::parse(accelerator) else { return false };
+        let mut accelerators = self.accelerators.borrow_mut();
+        let len = accelerators.len();
+        accelerators.retain(|(existing, _)| *existing != parsed);
+        accelerators.len() != len
+    }
+
+    /// Invokes the callback registered for the key `text` pressed with `modifiers`, and returns
+    /// true if there was one, in which case the key event must not be delivered to the window.
+    pub(crate) fn activate(&self, modifiers: ModifiersState, text: &str) -> bool {
+        let callback = self
+            .accelerators
+            .borrow()
+            .iter()
+            .find(|(accelerator, _)| accelerator.matches(modifiers, text))
+            .map(|(_, callback)| callback.clone());
+        // Don't keep the registry borrowed: the callback may register or unregister accelerators
+        match callback {
+            Some(callback) => {
+                callback();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[test]
+fn test_accelerators() {
+    let registry = AcceleratorRegistry::default();
+    let count = Rc::new(std::cell::Cell::new(0));
+    let c = count.clone();
+    registry.register("Ctrl+S", Rc::new(move || c.set(c.get() + 1))).unwrap();
+    assert!(registry.register("control+s", Rc::new(|| {})).is_err());
+    assert!(registry.register("Hyper+S", Rc::new(|| {})).is_err());
+    registry.register("Ctrl+Shift+S", Rc::new(|| {})).unwrap();
+    registry.register("Ctrl++", Rc::new(|| {})).unwrap();
+    registry.register("F5", Rc::new(|| {})).unwrap();
+
+    assert!(registry.activate(ModifiersState::CONTROL, "S"));
+    assert!(!registry.activate(ModifiersState::empty(), "s"));
+    assert!(!registry.activate(ModifiersState::ALT, "s"));
+    assert_eq!(count.get(), 1);
+    assert!(registry.activate(ModifiersState::CONTROL, "+"));
+    assert!(registry.activate(ModifiersState::empty(), "\u{F708}"));
+
+    // Shifted digits and punctuation match by their unmodified key
+    registry.register("Ctrl+Shift+1", Rc::new(|| {})).unwrap();
+    assert!(registry.activate(ModifiersState::CONTROL | ModifiersState::SHIFT, "1"));
+
+    assert!(registry.unregister("Ctrl+S"));
+    assert!(!registry.unregister("Ctrl+S"));
+    assert!(!registry.activate(ModifiersState::CONTROL, "s"));
+}
@@ -0,0 +1,57 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Detects whether the computer runs on battery, to reduce the frame rate of the animations with
+//! `AnimationThrottling::WhenHiddenOrOnBattery`.
+
+use i_slint_core::animations::Instant;
+use std::cell::Cell;
+use std::time::Duration;
+
+/// Reading the power source isn't free, so it's only read again after this duration
+const CACHE_DURATION: Duration = Duration::from_secs(10);
+
+thread_local! {
+    static ON_BATTERY: Cell<Option<(Instant, bool)>> = const { Cell::new(None) };
+}
+
+pub fn on_battery() -> bool {
+    let now = Instant::now();
+    ON_BATTERY.with(|cached| match cached.get() {
+        Some((time, on_battery)) if now - time < CACHE_DURATION => on_battery,
+        _ => {
+            let on_battery = query_on_battery();
+            cached.set(Some((now, on_battery)));
+            on_battery
+        }
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn query_on_battery() -> bool {
+    let Ok(entries) = std::fs::read_dir("/sys/class/power_supply") else { return false };
+    let mut discharging = false;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let read = |name: &str| std::fs::read_to_string(path.join(name)).unwrap_or_default();
+        match read("type").trim() {
+            "Mains" | "USB" if read("online").trim() == "1" => return false,
+            "Battery" => discharging |= read("status").trim() == "Discharging",
+            _ => {}
+        }
+    }
+    discharging
+}
+
+#[cfg(target_os = "windows")]
+fn query_on_battery() -> bool {
+    use windows::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+    let mut status = SYSTEM_POWER_STATUS::default();
+    // ACLineStatus is 0 when offline, 1 when online, and 255 when unknown
+    unsafe { GetSystemPowerStatus(&mut status) }.is_ok() && status.ACLineStatus == 0
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+fn query_on_battery() -> bool {
+    false
+}
@@ -13,6 +13,9 @@ fn main() {
        supports_opengl: { all(any(enable_skia_renderer, feature = "renderer-femtovg"), not(ios_and_friends)) },
        use_winit_theme: { any(target_family = "windows", target_vendor = "apple", target_arch = "wasm32", target_os = "android") },
        muda: { all(feature = "muda", any(target_os = "windows", target_os = "macos")) },
+       tray_icon: { all(feature = "tray-icon", muda) },
+       // winit's KeyEventExtModifierSupplement
+       key_without_modifiers: { any(target_os = "windows", target_os = "macos", all(unix, not(target_vendor = "apple"), not(target_os = "android"), any(feature = "x11", feature = "wayland"))) },
     }
     // This uses `web_sys_unstable_api`, which is typically set via `RUST_FLAGS`
     println!("cargo:rustc-check-cfg=cfg(web_sys_unstable_apis)");
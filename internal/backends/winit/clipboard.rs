@@ -17,6 +17,40 @@ pub fn select_clipboard(
     }
 }
 
+/// Reads the HTML and image representations of the default clipboard into `data`.
+#[cfg(not(any(target_os = "android", all(target_vendor = "apple", not(target_os = "macos")))))]
+pub fn read_rich_contents(data: &mut i_slint_core::platform::ClipboardData) {
+    let Ok(mut clipboard) = arboard::Clipboard::new() else { return };
+    data.html = clipboard.get().html().ok();
+    data.image = clipboard.get_image().ok().map(|image| {
+        i_slint_core::graphics::SharedPixelBuffer::clone_from_slice(
+            &image.bytes[..],
+            image.width as u32,
+            image.height as u32,
+        )
+    });
+}
+
+/// Replaces the content of the default clipboard with `data`, which contains HTML or an image.
+#[cfg(not(any(target_os = "android", all(target_vendor = "apple", not(target_os = "macos")))))]
+pub fn write_rich_contents(data: i_slint_core::platform::ClipboardData) {
+    let Ok(mut clipboard) = arboard::Clipboard::new() else { return };
+    let result = if let Some(image) = data.image {
+        clipboard.set_image(arboard::ImageData {
+            width: image.width() as usize,
+            height: image.height() as usize,
+            bytes: image.as_bytes().into(),
+        })
+    } else if let Some(html) = data.html {
+        clipboard.set_html(html, data.text)
+    } else {
+        return;
+    };
+    if let Err(err) = result {
+        i_slint_core::debug_log!("Error setting clipboard content: {err}");
+    }
+}
+
 pub fn create_clipboard(
     _display_handle: &winit::raw_window_handle::DisplayHandle<'_>,
 ) -> ClipboardPair {
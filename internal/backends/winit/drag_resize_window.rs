@@ -1,6 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
+use i_slint_core::api::WindowResizeEdge;
 use winit::window::{CursorIcon, ResizeDirection};
 
 pub fn handle_cursor_move_for_resize(
@@ -28,6 +29,21 @@ pub fn handle_resize(window: &winit::window::Window, direction: Option<ResizeDir
     }
 }
 
+/// Get the winit resize direction for the edge of the window.
+pub fn resize_direction(edge: WindowResizeEdge) -> ResizeDirection {
+    match edge {
+        WindowResizeEdge::Top => ResizeDirection::North,
+        WindowResizeEdge::Bottom => ResizeDirection::South,
+        WindowResizeEdge::Left => ResizeDirection::West,
+        WindowResizeEdge::TopLeft => ResizeDirection::NorthWest,
+        WindowResizeEdge::TopRight => ResizeDirection::NorthEast,
+        WindowResizeEdge::BottomLeft => ResizeDirection::SouthWest,
+        WindowResizeEdge::BottomRight => ResizeDirection::SouthEast,
+        // Right and any future edge
+        _ => ResizeDirection::East,
+    }
+}
+
 /// Get the cursor icon that corresponds to the resize direction.
 fn resize_direction_cursor_icon(resize_direction: Option<ResizeDirection>) -> CursorIcon {
     match resize_direction {
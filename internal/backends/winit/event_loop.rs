@@ -11,6 +11,7 @@
 use crate::winitwindowadapter::WindowVisibility;
 use crate::EventResult;
 use crate::{SharedBackendData, SlintEvent};
+use corelib::api::WindowHitTestArea;
 use corelib::graphics::euclid;
 use corelib::input::{KeyEvent, KeyEventType, MouseEvent};
 use corelib::items::{ColorScheme, PointerEventButton};
@@ -41,6 +42,8 @@ pub enum CustomEvent {
     Accesskit(accesskit_winit::Event),
     #[cfg(muda)]
     Muda(muda::MenuEvent),
+    #[cfg(tray_icon)]
+    TrayIcon(tray_icon::TrayIconEvent),
 }
 
 impl std::fmt::Debug for CustomEvent {
@@ -54,6 +57,8 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             Self::Accesskit(a) => write!(f, "AccessKit({a:?})"),
             #[cfg(muda)]
             Self::Muda(e) => write!(f, "Muda({e:?})"),
+            #[cfg(tray_icon)]
+            Self::TrayIcon(e) => write!(f, "TrayIcon({e:?})"),
         }
     }
 }
@@ -72,9 +77,34 @@ pub struct EventLoopState {
     pumping_events_instantly: bool,
 
     custom_application_handler: Option<Box<dyn crate::CustomApplicationHandler>>,
+
+    /// Files currently dragged over a window, reported one by one by winit.
+    hovered_files: Vec<std::path::PathBuf>,
+    /// Files of the current drop received so far; the drop is delivered once all hovered files arrived.
+    dropped_files: Vec<std::path::PathBuf>,
+    /// The modifiers currently pressed, to match accelerators
+    modifiers: winit::keyboard::ModifiersState,
+    /// The keys whose press activated an accelerator, so that their release isn't delivered either
+    accelerator_keys: Vec<winit::keyboard::PhysicalKey>,
+    /// When the animations were last drawn, to limit their frame rate on battery
+    last_animation_frame: corelib::animations::Instant,
 }
 
+/// The interval between the frames of the animations on battery, with
+/// `AnimationThrottling::WhenHiddenOrOnBattery`
+const BATTERY_FRAME_INTERVAL: std::time::Duration = std::time::Duration::from_millis(33);
+
 impl EventLoopState {
+    /// Creates the event for a drag of `files` at the current cursor position. The files are
+    /// passed as a `text/uri-list`.
+    fn file_drop_event(&self, files: &[std::path::PathBuf]) -> corelib::items::DropEvent {
+        corelib::items::DropEvent {
+            mime_type: "text/uri-list".into(),
+            data: files.iter().map(|path| file_uri(path) + "\r\n").collect::<String>().into(),
+            position: corelib::lengths::logical_position_to_api(self.cursor_pos),
+        }
+    }
+
     pub fn new(
         shared_backend_data: Rc<SharedBackendData>,
         custom_application_handler: Option<Box<dyn crate::CustomApplicationHandler>>,
@@ -88,6 +118,11 @@ pub fn new(
             current_resize_direction: Default::default(),
             pumping_events_instantly: Default::default(),
             custom_application_handler,
+            hovered_files: Default::default(),
+            dropped_files: Default::default(),
+            modifiers: Default::default(),
+            accelerator_keys: Vec::new(),
+            last_animation_frame: Default::default(),
         }
     }
 
@@ -118,6 +153,7 @@ fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         ) {
             return;
         }
+        self.shared_backend_data.update_monitors(event_loop);
         if let Err(err) = self.shared_backend_data.create_inactive_windows(event_loop) {
             self.loop_error = Some(err);
             event_loop.exit();
@@ -206,7 +242,18 @@ fn window_event(
                 self.loop_error = window.activation_changed(have_focus).err();
             }
 
+            WindowEvent::ModifiersChanged(modifiers) => {
+                self.modifiers = modifiers.state();
+            }
             WindowEvent::KeyboardInput { event, is_synthetic, .. } => {
+                // Accelerators match the key without the modifiers, so that Shift+1 is 1 and not !
+                #[cfg(key_without_modifiers)]
+                let unmodified_key = {
+                    use winit::platform::modifier_supplement::KeyEventExtModifierSupplement;
+                    event.key_without_modifiers()
+                };
+                #[cfg(not(key_without_modifiers))]
+                let unmodified_key = event.logical_key.clone();
                 let key_code = event.logical_key;
                 // For now: Match Qt's behavior of mapping command to control and control to meta (LWin/RWin).
                 let swap_cmd_ctrl = i_slint_core::is_apple_platform();
@@ -240,7 +287,37 @@ macro_rules! winit_key_to_char {
                     }
                 }
             }
-                let text = i_slint_common::for_each_special_keys!(winit_key_to_char);
+                let text: i_slint_core::SharedString =
+                    i_slint_common::for_each_special_keys!(winit_key_to_char);
+
+                // Application-global accelerators take precedence over the focused item
+                if event.state == winit::event::ElementState::Pressed && !is_synthetic {
+                    use winit::keyboard::ModifiersState;
+                    let mut modifiers = self.modifiers;
+                    if swap_cmd_ctrl {
+                        modifiers.set(ModifiersState::CONTROL, self.modifiers.super_key());
+                        modifiers.set(ModifiersState::SUPER, self.modifiers.control_key());
+                    }
+                    let accelerator_text = match &unmodified_key {
+                        winit::keyboard::Key::Character(str) => str.as_str().into(),
+                        _ => text.clone(),
+                    };
+                    if self.shared_backend_data.accelerators.activate(modifiers, &accelerator_text)
+                    {
+                        if !self.accelerator_keys.contains(&event.physical_key) {
+                            self.accelerator_keys.push(event.physical_key);
+                        }
+                        return;
+                    }
+                } else if event.state == winit::event::ElementState::Released {
+                    // The window didn't see the press of the accelerator, so don't send it the release
+                    if let Some(index) =
+                        self.accelerator_keys.iter().position(|key| *key == event.physical_key)
+                    {
+                        self.accelerator_keys.swap_remove(index);
+                        return;
+                    }
+                }
 
                 self.loop_error = window
                     .window()
@@ -296,7 +373,32 @@ macro_rules! winit_key_to_char {
                 );
                 let position = position.to_logical(runtime_window.scale_factor() as f64);
                 self.cursor_pos = euclid::point2(position.x, position.y);
-                runtime_window.process_mouse_input(MouseEvent::Moved { position: self.cursor_pos });
+                if self.hovered_files.is_empty() {
+                    runtime_window
+                        .process_mouse_input(MouseEvent::Moved { position: self.cursor_pos });
+                } else {
+                    runtime_window
+                        .process_external_drag(self.file_drop_event(&self.hovered_files), false);
+                }
+            }
+            WindowEvent::HoveredFile(path) => {
+                self.hovered_files.push(path);
+                runtime_window
+                    .process_external_drag(self.file_drop_event(&self.hovered_files), false);
+            }
+            WindowEvent::HoveredFileCancelled => {
+                self.hovered_files.clear();
+                self.dropped_files.clear();
+                runtime_window.process_external_drag_exit();
+            }
+            WindowEvent::DroppedFile(path) => {
+                // winit sends one event per file, after having sent one HoveredFile per file.
+                self.dropped_files.push(path);
+                if self.dropped_files.len() >= self.hovered_files.len() {
+                    let files = core::mem::take(&mut self.dropped_files);
+                    self.hovered_files.clear();
+                    runtime_window.process_external_drag(self.file_drop_event(&files), true);
+                }
             }
             WindowEvent::CursorLeft { .. } => {
                 // On the html canvas, we don't get the mouse move or release event when outside the canvas. So we have no choice but canceling the event
@@ -340,6 +442,27 @@ macro_rules! winit_key_to_char {
                             return;
                         }
 
+                        // The regions of the title bar and borders drawn by the application. On
+                        // Windows, the system handles them before they're reported as input.
+                        if button == PointerEventButton::Left {
+                            let area = runtime_window.hit_test(
+                                corelib::lengths::logical_position_to_api(self.cursor_pos),
+                            );
+                            let result = match area {
+                                Some(WindowHitTestArea::TitleBar) => {
+                                    Some(window.window().start_system_move())
+                                }
+                                Some(WindowHitTestArea::ResizeBorder(edge)) => {
+                                    Some(window.window().start_system_resize(edge))
+                                }
+                                _ => None,
+                            };
+                            // Let the elements handle the press if the window system doesn't support it
+                            if let Some(Ok(())) = result {
+                                return;
+                            }
+                        }
+
                         self.pressed = true;
                         MouseEvent::Pressed { position: self.cursor_pos, button, click_count: 0 }
                     }
@@ -398,6 +521,11 @@ macro_rules! winit_key_to_char {
             }),
             WindowEvent::Occluded(x) => {
                 window.renderer.occluded(x);
+                WindowInner::from_pub(window.window()).set_occluded(x);
+                if !x {
+                    // Resume the animations that were paused while the window was covered
+                    window.request_redraw();
+                }
 
                 // In addition to the hack done for WindowEvent::Resize, also do it for Occluded so we handle Minimized change
                 window.window_state_event();
@@ -435,6 +563,13 @@ fn user_event(&mut self, event_loop: &ActiveEventLoop, event: SlintEvent) {
             CustomEvent::WakeEventLoopWorkaround => {
                 event_loop.set_control_flow(ControlFlow::Poll);
             }
+            #[cfg(tray_icon)]
+            CustomEvent::Muda(event)
+                if crate::tray_icon::handle_menu_event(&self.shared_backend_data, &event) => {}
+            #[cfg(tray_icon)]
+            CustomEvent::TrayIcon(event) => {
+                crate::tray_icon::handle_tray_icon_event(&self.shared_backend_data, event);
+            }
             #[cfg(muda)]
             CustomEvent::Muda(event) => {
                 if let Some((window, eid, muda_type)) =
@@ -484,7 +619,16 @@ fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
             self.loop_error = Some(err);
         }
 
+        let mut next_animation_frame = None;
         if !event_loop.exiting() {
+            // On battery, limit the frame rate of the animations instead of drawing them right away
+            let now = corelib::animations::Instant::now();
+            let throttled_on_battery = corelib::animations::animation_throttling()
+                == corelib::api::AnimationThrottling::WhenHiddenOrOnBattery
+                && crate::battery::on_battery();
+            let since_last_frame = now - self.last_animation_frame;
+            let frame_due = !throttled_on_battery || since_last_frame >= BATTERY_FRAME_INTERVAL;
+
             for w in self
                 .shared_backend_data
                 .active_windows
@@ -493,14 +637,24 @@ fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
                 .filter_map(|(_, w)| w.upgrade())
             {
                 if w.window().has_active_animations() {
-                    w.request_redraw();
+                    if frame_due {
+                        w.request_redraw();
+                        self.last_animation_frame = now;
+                    } else {
+                        next_animation_frame = Some(BATTERY_FRAME_INTERVAL - since_last_frame);
+                    }
                 }
             }
         }
 
         if event_loop.control_flow() == ControlFlow::Wait {
-            if let Some(next_timer) = corelib::platform::duration_until_next_timer_update() {
-                event_loop.set_control_flow(ControlFlow::wait_duration(next_timer));
+            let next_timer = corelib::platform::duration_until_next_timer_update();
+            let next_wakeup = match (next_timer, next_animation_frame) {
+                (Some(timer), Some(frame)) => Some(timer.min(frame)),
+                (timer, frame) => timer.or(frame),
+            };
+            if let Some(next_wakeup) = next_wakeup {
+                event_loop.set_control_flow(ControlFlow::wait_duration(next_wakeup));
             }
         }
 
@@ -623,3 +777,23 @@ pub fn spawn(self) -> Result<(), corelib::platform::PlatformError> {
         Ok(())
     }
 }
+
+/// Converts an absolute path into a `file://` URI, percent-encoding the bytes that aren't allowed in a URI path.
+fn file_uri(path: &std::path::Path) -> String {
+    let path = path.to_string_lossy();
+    let mut uri = String::from("file://");
+    if !path.starts_with('/') {
+        // Windows paths, such as C:\dir\file
+        uri.push('/');
+    }
+    for byte in path.bytes() {
+        match byte {
+            b'\\' => uri.push('/'),
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'/' | b'-' | b'_' | b'.' | b'~' | b':' => {
+                uri.push(byte as char)
+            }
+            _ => uri.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    uri
+}
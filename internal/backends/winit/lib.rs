@@ -20,6 +20,8 @@
 use std::sync::Arc;
 use winit::event_loop::ActiveEventLoop;
 
+mod accelerators;
+mod battery;
 #[cfg(not(target_arch = "wasm32"))]
 mod clipboard;
 mod drag_resize_window;
@@ -31,6 +33,9 @@
 /// Re-export of the winit crate.
 pub use winit;
 
+#[cfg(enable_skia_renderer)]
+pub use i_slint_renderer_skia::skia_safe;
+
 /// Internal type used by the winit backend for thread communication and window system updates.
 ///
 /// See also [`EventLoopBuilder`]
@@ -75,6 +80,11 @@ fn resume(
             active_event_loop: &ActiveEventLoop,
             window_attributes: winit::window::WindowAttributes,
         ) -> Result<Arc<winit::window::Window>, PlatformError>;
+
+        #[cfg(enable_skia_renderer)]
+        fn as_skia_renderer(&self) -> Option<&i_slint_renderer_skia::SkiaRenderer> {
+            None
+        }
     }
 
     #[cfg(any(
@@ -94,7 +104,17 @@ fn resume(
 #[cfg(muda)]
 mod muda;
 #[cfg(not(use_winit_theme))]
+mod system_status;
+#[cfg(tray_icon)]
+mod tray_icon;
+#[cfg(target_os = "windows")]
+mod win32_hit_test;
+#[cfg(not(use_winit_theme))]
 mod xdg_color_scheme;
+#[cfg(not(use_winit_theme))]
+mod xdg_file_dialog;
+#[cfg(not(use_winit_theme))]
+mod xdg_screen_color;
 
 #[cfg(target_arch = "wasm32")]
 pub(crate) mod wasm_input_helper;
@@ -257,6 +277,8 @@ pub struct BackendBuilder {
     #[cfg(target_family = "wasm")]
     spawn_event_loop: bool,
     custom_application_handler: Option<Box<dyn CustomApplicationHandler>>,
+    #[cfg(enable_femtovg_renderer)]
+    femtovg_glyph_cache_budget: Option<usize>,
 }
 
 impl BackendBuilder {
@@ -338,6 +360,16 @@ pub fn with_custom_application_handler(
         self
     }
 
+    /// Configures this builder to limit the GPU memory, in bytes, that the FemtoVG renderer uses
+    /// for its atlas of rasterized glyphs. When the budget is exceeded, the glyphs that weren't
+    /// drawn in the last frame are evicted. By default, the atlas grows without limit.
+    #[must_use]
+    #[cfg(enable_femtovg_renderer)]
+    pub fn with_femtovg_glyph_cache_budget(mut self, bytes: usize) -> Self {
+        self.femtovg_glyph_cache_budget = Some(bytes);
+        self
+    }
+
     /// Builds the backend with the parameters configured previously. Set the resulting backend
     /// with `slint::platform::set_platform()`:
     ///
@@ -363,17 +395,43 @@ pub fn build(self) -> Result<Backend, PlatformError> {
             false,
         );
 
+        let mut renderer_name = self.renderer_name.as_deref();
+        #[allow(unused_mut)]
+        let mut requested_graphics_api = self.requested_graphics_api;
+        // `wgpu` selects the renderer that renders with the requested WGPU version, or with WGPU 27
+        // if no graphics API was requested.
+        if renderer_name == Some("wgpu") {
+            #[cfg(feature = "unstable-wgpu-27")]
+            if requested_graphics_api.is_none() {
+                requested_graphics_api = Some(RequestedGraphicsAPI::WGPU27(Default::default()));
+            }
+            if requested_graphics_api.as_ref().is_some_and(|_api| {
+                #[cfg(feature = "unstable-wgpu-26")]
+                if matches!(_api, RequestedGraphicsAPI::WGPU26(..)) {
+                    return true;
+                }
+                #[cfg(feature = "unstable-wgpu-27")]
+                if matches!(_api, RequestedGraphicsAPI::WGPU27(..)) {
+                    return true;
+                }
+                false
+            }) {
+                renderer_name = None;
+            }
+        }
+
         // Initialize the winit event loop and propagate errors if for example `DISPLAY` or `WAYLAND_DISPLAY` isn't set.
 
-        let shared_data = Rc::new(SharedBackendData::new(
-            event_loop_builder,
-            self.requested_graphics_api.clone(),
-        )?);
+        #[allow(unused_mut)]
+        let mut shared_data =
+            SharedBackendData::new(event_loop_builder, requested_graphics_api.clone())?;
+        #[cfg(enable_femtovg_renderer)]
+        {
+            shared_data.femtovg_glyph_cache_budget = self.femtovg_glyph_cache_budget;
+        }
+        let shared_data = Rc::new(shared_data);
 
-        let renderer_factory_fn = match (
-            self.renderer_name.as_deref(),
-            self.requested_graphics_api.as_ref(),
-        ) {
+        let renderer_factory_fn = match (renderer_name, requested_graphics_api.as_ref()) {
             #[cfg(all(feature = "renderer-femtovg", supports_opengl))]
             (Some("gl"), maybe_graphics_api) | (Some("femtovg"), maybe_graphics_api) => {
                 // If a graphics API was requested, double check that it's GL. FemtoVG doesn't support Metal, etc.
@@ -517,6 +575,8 @@ pub fn build(self) -> Result<Backend, PlatformError> {
             #[cfg(target_family = "wasm")]
             spawn_event_loop: self.spawn_event_loop,
             custom_application_handler: self.custom_application_handler.into(),
+            #[cfg(not(use_winit_theme))]
+            system_status_watched: Default::default(),
         })
     }
 }
@@ -534,6 +594,15 @@ pub(crate) struct SharedBackendData {
     not_running_event_loop: RefCell<Option<winit::event_loop::EventLoop<SlintEvent>>>,
     event_loop_proxy: winit::event_loop::EventLoopProxy<SlintEvent>,
     is_wayland: bool,
+    #[cfg(enable_femtovg_renderer)]
+    femtovg_glyph_cache_budget: Option<usize>,
+    accelerators: accelerators::AcceleratorRegistry,
+    /// The events of the tray icons, by tray icon id
+    #[cfg(tray_icon)]
+    tray_icons: RefCell<HashMap<String, i_slint_core::platform::TrayIconEvents>>,
+    /// The monitors and the primary monitor, as of the last time the event loop was resumed.
+    /// Used to enumerate the screens when there's no window to query them from.
+    monitors: RefCell<(Vec<winit::monitor::MonitorHandle>, Option<winit::monitor::MonitorHandle>)>,
 }
 
 impl SharedBackendData {
@@ -603,6 +672,12 @@ fn new(
             not_running_event_loop: RefCell::new(Some(event_loop)),
             event_loop_proxy,
             is_wayland,
+            #[cfg(enable_femtovg_renderer)]
+            femtovg_glyph_cache_budget: None,
+            accelerators: Default::default(),
+            #[cfg(tray_icon)]
+            tray_icons: Default::default(),
+            monitors: Default::default(),
         })
     }
 
@@ -649,6 +724,58 @@ pub fn create_inactive_windows(
     pub fn window_by_id(&self, id: winit::window::WindowId) -> Option<Rc<WinitWindowAdapter>> {
         self.active_windows.borrow().get(&id).and_then(|weakref| weakref.upgrade())
     }
+
+    pub fn update_monitors(&self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        *self.monitors.borrow_mut() =
+            (event_loop.available_monitors().collect(), event_loop.primary_monitor());
+    }
+
+    /// Returns the connected monitors and the primary monitor. They are queried from a window
+    /// if there's one, as monitors may have been connected since the event loop was resumed.
+    pub fn available_monitors(
+        &self,
+    ) -> (Vec<winit::monitor::MonitorHandle>, Option<winit::monitor::MonitorHandle>) {
+        let window = self
+            .active_windows
+            .borrow()
+            .values()
+            .find_map(|w| w.upgrade().and_then(|w| w.winit_window()));
+        match window {
+            Some(window) => (window.available_monitors().collect(), window.primary_monitor()),
+            None => self.monitors.borrow().clone(),
+        }
+    }
+
+    pub fn find_monitor(
+        &self,
+        screen: &i_slint_core::api::Screen,
+    ) -> Option<winit::monitor::MonitorHandle> {
+        self.available_monitors().0.into_iter().find(|monitor| {
+            monitor.name().unwrap_or_default() == screen.name().as_str()
+                && monitor_position(monitor) == screen.position()
+        })
+    }
+}
+
+fn monitor_position(
+    monitor: &winit::monitor::MonitorHandle,
+) -> i_slint_core::api::PhysicalPosition {
+    let position = monitor.position();
+    i_slint_core::api::PhysicalPosition::new(position.x, position.y)
+}
+
+pub(crate) fn monitor_to_screen(
+    monitor: &winit::monitor::MonitorHandle,
+    primary_monitor: Option<&winit::monitor::MonitorHandle>,
+) -> i_slint_core::api::Screen {
+    let size = monitor.size();
+    i_slint_core::api::Screen::new(
+        monitor.name().unwrap_or_default(),
+        monitor_position(monitor),
+        i_slint_core::api::PhysicalSize::new(size.width, size.height),
+        monitor.scale_factor() as f32,
+        primary_monitor == Some(monitor),
+    )
 }
 
 type RendererFactoryFn =
@@ -690,6 +817,10 @@ pub struct Backend {
 
     #[cfg(target_family = "wasm")]
     spawn_event_loop: bool,
+
+    /// Whether the network and the battery are monitored for the `System` global
+    #[cfg(not(use_winit_theme))]
+    system_status_watched: std::cell::Cell<bool>,
 }
 
 impl Backend {
@@ -729,6 +860,8 @@ pub fn builder() -> BackendBuilder {
             #[cfg(target_family = "wasm")]
             spawn_event_loop: false,
             custom_application_handler: None,
+            #[cfg(enable_femtovg_renderer)]
+            femtovg_glyph_cache_budget: None,
         }
     }
 }
@@ -764,6 +897,22 @@ fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError>
                 ))
             },
         )?;
+
+        #[cfg(not(use_winit_theme))]
+        if !self.system_status_watched.replace(true) {
+            let context = i_slint_core::window::WindowInner::from_pub(adapter.window()).context();
+            let _ = context.spawn_local(async {
+                if let Err(err) = crate::system_status::watch_network().await {
+                    i_slint_core::debug_log!("Error watching the network state: {}", err);
+                }
+            });
+            let _ = context.spawn_local(async {
+                if let Err(err) = crate::system_status::watch_battery().await {
+                    i_slint_core::debug_log!("Error watching the battery state: {}", err);
+                }
+            });
+        }
+
         Ok(adapter)
     }
 
@@ -855,6 +1004,147 @@ fn set_clipboard_text(&self, text: &str, clipboard: i_slint_core::platform::Clip
         }
     }
 
+    fn screens(&self) -> Result<Vec<i_slint_core::api::Screen>, PlatformError> {
+        // Before the event loop was started and without window, winit can't tell the monitors
+        let (monitors, primary_monitor) = self.shared_data.available_monitors();
+        Ok(monitors
+            .iter()
+            .map(|monitor| monitor_to_screen(monitor, primary_monitor.as_ref()))
+            .collect())
+    }
+
+    #[cfg(tray_icon)]
+    fn create_tray_icon(
+        &self,
+        events: i_slint_core::platform::TrayIconEvents,
+    ) -> Result<Box<dyn i_slint_core::platform::TrayIconAdapter>, PlatformError> {
+        Ok(Box::new(tray_icon::WinitTrayIcon::new(&self.shared_data, events)?))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn open_url(&self, url: &str) -> Result<(), PlatformError> {
+        web_sys::window()
+            .ok_or_else(|| PlatformError::from("No browser window to open the url in"))?
+            .open_with_url_and_target(url, "_blank")
+            .map_err(|err| format!("Error opening url: {err:?}"))?;
+        Ok(())
+    }
+
+    #[cfg(not(any(
+        target_arch = "wasm32",
+        target_os = "android",
+        all(target_vendor = "apple", not(target_os = "macos"))
+    )))]
+    fn open_url(&self, url: &str) -> Result<(), PlatformError> {
+        // Pass the url as a separate argument, so that it's never interpreted by a shell
+        let mut command = if cfg!(target_os = "macos") {
+            std::process::Command::new("open")
+        } else if cfg!(target_family = "windows") {
+            let mut command = std::process::Command::new("rundll32");
+            command.arg("url.dll,FileProtocolHandler");
+            command
+        } else {
+            std::process::Command::new("xdg-open")
+        };
+        command
+            .arg(url)
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+            .map_err(|err| format!("Error opening url: {err}"))?;
+        Ok(())
+    }
+
+    #[cfg(not(use_winit_theme))]
+    fn pick_screen_color(
+        &self,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<Option<i_slint_core::Color>, PlatformError>>>,
+    > {
+        Box::pin(async {
+            crate::xdg_screen_color::pick_color().await.map_err(|err| {
+                format!("Error picking a color with the desktop portal: {err}").into()
+            })
+        })
+    }
+
+    #[cfg(any(not(use_winit_theme), target_os = "windows", target_os = "macos"))]
+    fn show_file_dialog(
+        &self,
+        kind: i_slint_core::dialogs::FileDialogKind,
+        options: i_slint_core::dialogs::FileDialogOptions,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<Option<std::path::PathBuf>, PlatformError>>>,
+    > {
+        use i_slint_core::dialogs::FileDialogKind;
+        if !matches!(
+            kind,
+            FileDialogKind::OpenFile | FileDialogKind::SaveFile | FileDialogKind::PickFolder
+        ) {
+            return Box::pin(std::future::ready(Err(PlatformError::Other(format!(
+                "Unsupported file dialog: {kind:?}"
+            )))));
+        }
+        let parent = options.parent_window_adapter().and_then(|adapter| {
+            adapter
+                .internal(i_slint_core::InternalToken)?
+                .as_any()
+                .downcast_ref::<WinitWindowAdapter>()?
+                .winit_window()
+        });
+
+        #[cfg(not(use_winit_theme))]
+        {
+            use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+            // winit doesn't export its Wayland surfaces with xdg-foreign, so the portal can only
+            // make the dialog modal for X11 windows
+            let parent_window = match parent
+                .as_ref()
+                .and_then(|window| window.window_handle().ok())
+                .map(|handle| handle.as_raw())
+            {
+                Some(RawWindowHandle::Xlib(handle)) => format!("x11:{:x}", handle.window),
+                Some(RawWindowHandle::Xcb(handle)) => format!("x11:{:x}", handle.window),
+                _ => String::new(),
+            };
+            Box::pin(async move {
+                crate::xdg_file_dialog::show(kind, options, parent_window).await.map_err(|err| {
+                    format!("Error showing a file dialog with the desktop portal: {err}").into()
+                })
+            })
+        }
+
+        // rfd shows a sheet on macOS, and on Windows a dialog owned by the parent in a separate thread
+        #[cfg(any(target_os = "windows", target_os = "macos"))]
+        {
+            let mut dialog = rfd::AsyncFileDialog::new();
+            if !options.title.is_empty() {
+                dialog = dialog.set_title(options.title);
+            }
+            if let Some(directory) = &options.directory {
+                dialog = dialog.set_directory(directory);
+            }
+            if let Some(file_name) = options.file_name {
+                dialog = dialog.set_file_name(file_name);
+            }
+            for filter in &options.filters {
+                dialog = dialog.add_filter(&filter.name, &filter.extensions);
+            }
+            if let Some(parent) = &parent {
+                dialog = dialog.set_parent(&**parent);
+            }
+            Box::pin(async move {
+                let handle = match kind {
+                    FileDialogKind::OpenFile => dialog.pick_file().await,
+                    FileDialogKind::SaveFile => dialog.save_file().await,
+                    _ => dialog.pick_folder().await,
+                };
+                Ok(handle.map(|handle| handle.path().to_path_buf()))
+            })
+        }
+    }
+
     #[cfg(target_arch = "wasm32")]
     fn clipboard_text(&self, clipboard: i_slint_core::platform::Clipboard) -> Option<String> {
         crate::wasm_input_helper::get_clipboard_text(clipboard)
@@ -865,6 +1155,43 @@ fn clipboard_text(&self, clipboard: i_slint_core::platform::Clipboard) -> Option
         let mut pair = self.shared_data.clipboard.borrow_mut();
         clipboard::select_clipboard(&mut pair, clipboard).and_then(|c| c.get_contents().ok())
     }
+
+    #[cfg(not(any(
+        target_arch = "wasm32",
+        target_os = "android",
+        all(target_vendor = "apple", not(target_os = "macos"))
+    )))]
+    fn set_clipboard_data(
+        &self,
+        data: i_slint_core::platform::ClipboardData,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) {
+        // HTML and images are only supported in the default clipboard
+        if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard
+            && (data.html.is_some() || data.image.is_some())
+        {
+            clipboard::write_rich_contents(data);
+        } else if let Some(text) = data.text {
+            self.set_clipboard_text(&text, clipboard);
+        }
+    }
+
+    #[cfg(not(any(
+        target_arch = "wasm32",
+        target_os = "android",
+        all(target_vendor = "apple", not(target_os = "macos"))
+    )))]
+    fn clipboard_data(
+        &self,
+        clipboard: i_slint_core::platform::Clipboard,
+    ) -> Option<i_slint_core::platform::ClipboardData> {
+        let mut data = i_slint_core::platform::ClipboardData::default();
+        if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
+            clipboard::read_rich_contents(&mut data);
+        }
+        data.text = self.clipboard_text(clipboard);
+        (data.text.is_some() || data.html.is_some() || data.image.is_some()).then_some(data)
+    }
 }
 
 mod private {
@@ -950,6 +1277,72 @@ fn on_winit_window_event(
     fn winit_window(
         &self,
     ) -> impl std::future::Future<Output = Result<Arc<winit::window::Window>, PlatformError>>;
+
+    /// Sets the window that this window is transient for, such as the main window that a tool palette
+    /// or a dialog belongs to. Windowing systems typically keep a transient window above its parent and
+    /// don't list it separately in the task bar. Pass `None` to remove the relationship.
+    ///
+    /// Windowing systems only accept the parent when the winit window is created, so this takes effect
+    /// the next time this window is shown. The parent must be shown at that point, otherwise the relationship
+    /// is ignored. If either window is not backed by winit, this function is a no-op.
+    ///
+    /// On Windows, the parent becomes the owner of the window. On macOS, the window becomes a child window
+    /// of the parent. On X11, the window is transient for the parent. Wayland is not supported yet.
+    fn set_transient_parent(&self, parent: Option<&i_slint_core::api::Window>);
+
+    /// Invokes the callback for each window of the winit backend that is currently shown, including this one,
+    /// in no particular order.
+    ///
+    /// If this window is not backed by winit, the callback is not invoked.
+    fn for_each_open_window(&self, callback: impl FnMut(&i_slint_core::api::Window));
+
+    /// Starts dragging `data` of the given `mime_type`, such as `text/plain` or `text/uri-list`, from
+    /// the current pointer position. Call this while the left pointer button is pressed, for example
+    /// from a `TouchArea`'s `moved` callback. The data is delivered to the `DropArea` element under
+    /// the pointer when the button is released.
+    ///
+    /// Files dragged from other applications onto the window are delivered to `DropArea` elements as
+    /// `text/uri-list` data. Dragging data out of the window to other applications is not supported by
+    /// winit yet, so the drag ends when the pointer leaves the window.
+    fn start_drag(&self, mime_type: &str, data: &str);
+
+    /// Registers an application-global accelerator, such as `"Ctrl+S"` or `"Ctrl+Shift+F5"`, that invokes
+    /// `callback` when pressed in any window of the application, regardless of which item has the focus.
+    /// The key event is then not delivered to the window.
+    ///
+    /// The accelerator is a `+`-separated list of modifiers (`Ctrl`, `Shift`, `Alt`, `Meta`) followed by a
+    /// character or the name of a key of [`slint::platform::Key`](i_slint_core::platform::Key). As for key
+    /// events, `Ctrl` is the Command key on macOS. Characters are matched as they're typed without the modifiers,
+    /// so that `"Ctrl+Shift+1"` is activated by the 1 key on a US keyboard layout, even though Shift+1 types `!`.
+    ///
+    /// Returns an error if the accelerator can't be parsed, if the same key combination is already registered,
+    /// or if this window is not backed by winit.
+    fn register_accelerator(
+        &self,
+        accelerator: &str,
+        callback: impl Fn() + 'static,
+    ) -> Result<(), PlatformError>;
+
+    /// Removes an accelerator registered with [`Self::register_accelerator()`]. Returns true if there was one.
+    fn unregister_accelerator(&self, accelerator: &str) -> bool;
+
+    /// Sets a callback that's invoked with the [`skia_safe::Canvas`] this window is rendered into, to draw
+    /// custom content below or above the Slint scene. Pass `None` to remove the callback.
+    ///
+    /// The callback is invoked with [`RenderingState::BeforeRendering`](i_slint_core::api::RenderingState::BeforeRendering)
+    /// before the items are drawn and with [`RenderingState::AfterRendering`](i_slint_core::api::RenderingState::AfterRendering)
+    /// after. The canvas is in physical pixels and the last parameter is the window's scale factor.
+    /// Call [`Window::request_redraw()`](i_slint_core::api::Window::request_redraw) when the custom content changes.
+    ///
+    /// Returns [`SetRenderingNotifierError::Unsupported`](i_slint_core::api::SetRenderingNotifierError::Unsupported)
+    /// if this window is not backed by winit and rendered with Skia.
+    #[cfg(enable_skia_renderer)]
+    fn set_skia_canvas_rendering_notifier(
+        &self,
+        callback: Option<
+            Box<dyn FnMut(i_slint_core::api::RenderingState, &skia_safe::Canvas, f32)>,
+        >,
+    ) -> Result<(), i_slint_core::api::SetRenderingNotifierError>;
 }
 
 impl WinitWindowAccessor for i_slint_core::api::Window {
@@ -1005,6 +1398,88 @@ fn on_winit_window_event(
                 .set(Some(Box::new(move |window, event| callback(window, event))));
         }
     }
+
+    fn set_transient_parent(&self, parent: Option<&i_slint_core::api::Window>) {
+        let parent = parent.and_then(|parent| {
+            i_slint_core::window::WindowInner::from_pub(parent)
+                .window_adapter()
+                .internal(i_slint_core::InternalToken)
+                .and_then(|wa| wa.as_any().downcast_ref::<WinitWindowAdapter>())
+                .map(|wa| wa.self_weak.clone())
+        });
+        if let Some(adapter) = i_slint_core::window::WindowInner::from_pub(self)
+            .window_adapter()
+            .internal(i_slint_core::InternalToken)
+            .and_then(|wa| wa.as_any().downcast_ref::<WinitWindowAdapter>())
+        {
+            *adapter.transient_parent.borrow_mut() = parent;
+        }
+    }
+
+    fn for_each_open_window(&self, mut callback: impl FnMut(&i_slint_core::api::Window)) {
+        let Some(shared_backend_data) = i_slint_core::window::WindowInner::from_pub(self)
+            .window_adapter()
+            .internal(i_slint_core::InternalToken)
+            .and_then(|wa| wa.as_any().downcast_ref::<WinitWindowAdapter>())
+            .map(|wa| wa.shared_backend_data.clone())
+        else {
+            return;
+        };
+        // Collect first, as the callback may show or hide windows
+        let windows = shared_backend_data
+            .active_windows
+            .borrow()
+            .values()
+            .filter_map(|w| w.upgrade())
+            .collect::<Vec<_>>();
+        for window in windows {
+            callback(window.window());
+        }
+    }
+
+    fn start_drag(&self, mime_type: &str, data: &str) {
+        i_slint_core::window::WindowInner::from_pub(self).start_drag(mime_type.into(), data.into());
+    }
+
+    fn register_accelerator(
+        &self,
+        accelerator: &str,
+        callback: impl Fn() + 'static,
+    ) -> Result<(), PlatformError> {
+        i_slint_core::window::WindowInner::from_pub(self)
+            .window_adapter()
+            .internal(i_slint_core::InternalToken)
+            .and_then(|wa| wa.as_any().downcast_ref::<WinitWindowAdapter>())
+            .ok_or_else(|| PlatformError::from("The window is not backed by the winit backend"))?
+            .shared_backend_data
+            .accelerators
+            .register(accelerator, Rc::new(callback))
+    }
+
+    fn unregister_accelerator(&self, accelerator: &str) -> bool {
+        i_slint_core::window::WindowInner::from_pub(self)
+            .window_adapter()
+            .internal(i_slint_core::InternalToken)
+            .and_then(|wa| wa.as_any().downcast_ref::<WinitWindowAdapter>())
+            .is_some_and(|wa| wa.shared_backend_data.accelerators.unregister(accelerator))
+    }
+
+    #[cfg(enable_skia_renderer)]
+    fn set_skia_canvas_rendering_notifier(
+        &self,
+        callback: Option<
+            Box<dyn FnMut(i_slint_core::api::RenderingState, &skia_safe::Canvas, f32)>,
+        >,
+    ) -> Result<(), i_slint_core::api::SetRenderingNotifierError> {
+        i_slint_core::window::WindowInner::from_pub(self)
+            .window_adapter()
+            .internal(i_slint_core::InternalToken)
+            .and_then(|wa| wa.as_any().downcast_ref::<WinitWindowAdapter>())
+            .and_then(|wa| wa.renderer.as_skia_renderer())
+            .ok_or(i_slint_core::api::SetRenderingNotifierError::Unsupported)?
+            .set_canvas_rendering_notifier(callback);
+        Ok(())
+    }
 }
 
 impl private::WinitWindowAccessorSealed for i_slint_core::api::Window {}
@@ -1039,6 +1514,12 @@ fn test_window_accessor_and_rwh() {
         let result = slint_window.winit_window().await;
         assert!(result.is_ok(), "Failed to get winit window: {:?}", result.err());
         assert!(slint_window.has_winit_window());
+        let mut open_windows = 0;
+        slint_window.for_each_open_window(|window| {
+            assert!(window.has_winit_window());
+            open_windows += 1;
+        });
+        assert_eq!(open_windows, 1);
         let handle = slint_window.window_handle();
         use raw_window_handle::{HasDisplayHandle, HasWindowHandle};
         assert!(handle.window_handle().is_ok());
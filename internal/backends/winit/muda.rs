@@ -249,7 +249,7 @@ pub fn window_activation_changed(&self, is_active: bool) {
     }
 }
 
-fn install_event_handler_if_necessary(proxy: EventLoopProxy<SlintEvent>) {
+pub(crate) fn install_event_handler_if_necessary(proxy: EventLoopProxy<SlintEvent>) {
     // `MenuEvent::set_event_handler()` in `muda` seems to use `OnceCell`, which is an
     // can only be set a single time.  Therefore, we need to take care to only call this
     // a single time
@@ -30,8 +30,10 @@ impl GlutinFemtoVGRenderer {
     pub fn new_suspended(
         shared_backend_data: &Rc<crate::SharedBackendData>,
     ) -> Result<Box<dyn WinitCompatibleRenderer>, PlatformError> {
+        let renderer = FemtoVGRenderer::new_suspended();
+        renderer.set_glyph_cache_budget(shared_backend_data.femtovg_glyph_cache_budget);
         Ok(Box::new(Self {
-            renderer: FemtoVGRenderer::new_suspended(),
+            renderer,
             _requested_graphics_api: shared_backend_data._requested_graphics_api.clone(),
         }))
     }
@@ -102,9 +104,11 @@ pub fn new_suspended(
         ) {
             return Err(PlatformError::from("WGPU: No GPU adapters found"));
         }
+        let renderer =
+            FemtoVGRenderer::<i_slint_renderer_femtovg::wgpu::WGPUBackend>::new_suspended();
+        renderer.set_glyph_cache_budget(shared_backend_data.femtovg_glyph_cache_budget);
         Ok(Box::new(Self {
-            renderer: FemtoVGRenderer::<i_slint_renderer_femtovg::wgpu::WGPUBackend>::new_suspended(
-            ),
+            renderer,
             requested_graphics_api: shared_backend_data._requested_graphics_api.clone(),
         }))
     }
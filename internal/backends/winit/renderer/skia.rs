@@ -162,6 +162,10 @@ fn as_core_renderer(&self) -> &dyn i_slint_core::renderer::Renderer {
         &self.renderer
     }
 
+    fn as_skia_renderer(&self) -> Option<&SkiaRenderer> {
+        Some(&self.renderer)
+    }
+
     fn suspend(&self) -> Result<(), PlatformError> {
         self.renderer.set_pre_present_callback(None);
         self.renderer.suspend()
@@ -117,7 +117,11 @@ fn render(&self, window: &i_slint_core::api::Window) -> Result<(), PlatformError
         let region = if std::env::var_os("SLINT_LINE_BY_LINE").is_none() {
             let buffer: &mut [SoftBufferPixel] =
                 bytemuck::cast_slice_mut(target_buffer.deref_mut());
-            self.renderer.render(buffer, width.get() as usize)
+            #[cfg(feature = "renderer-software-parallel")]
+            let render = SoftwareRenderer::render_parallel;
+            #[cfg(not(feature = "renderer-software-parallel"))]
+            let render = SoftwareRenderer::render;
+            render(&self.renderer, buffer, width.get() as usize)
         } else {
             // SLINT_LINE_BY_LINE is set and this is a debug mode where we also render in a Rgb565Pixel
             struct FrameBuffer<'a> {
@@ -0,0 +1,80 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Updates the `System.network-online`, `System.on-battery` and `System.battery-level` properties
+//! with the state reported by NetworkManager and UPower on the D-Bus system bus.
+
+use futures::stream::StreamExt;
+use i_slint_core::platform::{set_system_battery_status, set_system_network_online};
+
+/// `NM_STATE_CONNECTED_GLOBAL`: the network is connected and the internet reachable
+const NM_STATE_CONNECTED_GLOBAL: u32 = 70;
+/// `NM_STATE_UNKNOWN`: NetworkManager doesn't know, so we keep assuming that the network is reachable
+const NM_STATE_UNKNOWN: u32 = 0;
+
+async fn proxy<'a>(
+    connection: &zbus::Connection,
+    destination: &'a str,
+    path: &'a str,
+    interface: &'a str,
+) -> zbus::Result<zbus::Proxy<'a>> {
+    zbus::proxy::Builder::new(connection)
+        .destination(destination)?
+        .path(path)?
+        .interface(interface)?
+        .build()
+        .await
+}
+
+pub async fn watch_network() -> zbus::Result<()> {
+    let connection = zbus::Connection::system().await?;
+    let network_manager = proxy(
+        &connection,
+        "org.freedesktop.NetworkManager",
+        "/org/freedesktop/NetworkManager",
+        "org.freedesktop.NetworkManager",
+    )
+    .await?;
+
+    // The stream yields the current state first
+    let mut states = network_manager.receive_property_changed::<u32>("State").await;
+    while let Some(state) = states.next().await {
+        let state = state.get().await?;
+        set_system_network_online(state == NM_STATE_UNKNOWN || state == NM_STATE_CONNECTED_GLOBAL);
+    }
+    Ok(())
+}
+
+pub async fn watch_battery() -> zbus::Result<()> {
+    let connection = zbus::Connection::system().await?;
+    let upower = proxy(
+        &connection,
+        "org.freedesktop.UPower",
+        "/org/freedesktop/UPower",
+        "org.freedesktop.UPower",
+    )
+    .await?;
+    // The display device combines all the batteries of the computer
+    let display_device = proxy(
+        &connection,
+        "org.freedesktop.UPower",
+        "/org/freedesktop/UPower/devices/DisplayDevice",
+        "org.freedesktop.UPower.Device",
+    )
+    .await?;
+
+    let mut changes = futures::stream::select(
+        upower.receive_property_changed::<bool>("OnBattery").await.map(|_| ()),
+        display_device.receive_property_changed::<f64>("Percentage").await.map(|_| ()),
+    );
+    while changes.next().await.is_some() {
+        let on_battery = upower.get_property::<bool>("OnBattery").await?;
+        let level = if display_device.get_property::<bool>("IsPresent").await? {
+            display_device.get_property::<f64>("Percentage").await? / 100.
+        } else {
+            1.
+        };
+        set_system_battery_status(on_battery, level as f32);
+    }
+    Ok(())
+}
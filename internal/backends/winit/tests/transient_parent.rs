@@ -0,0 +1,69 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::winit_030::{winit, WinitWindowAccessor};
+
+fn main() {
+    slint::slint! {
+        export component MainWindow inherits Window {
+            width: 200px;
+            height: 200px;
+        }
+        export component Palette inherits Window {
+            width: 50px;
+            height: 50px;
+        }
+    }
+    slint::BackendSelector::new().backend_name("winit".into()).select().unwrap();
+
+    let main_window = MainWindow::new().unwrap();
+    let palette = Palette::new().unwrap();
+    palette.window().set_transient_parent(Some(main_window.window()));
+
+    slint::spawn_local(async move {
+        main_window.show().unwrap();
+        let parent = main_window.window().winit_window().await.unwrap();
+        palette.show().unwrap();
+        let window = palette.window().winit_window().await.unwrap();
+
+        // The palette is a top-level window of its own, not embedded into the main window
+        assert_ne!(window.id(), parent.id());
+        let mut open_windows = 0;
+        palette.window().for_each_open_window(|_| open_windows += 1);
+        assert_eq!(open_windows, 2);
+
+        #[cfg(all(unix, not(target_vendor = "apple"), feature = "x11"))]
+        check_x11_transient_for(&window, &parent);
+
+        slint::quit_event_loop().unwrap();
+    })
+    .unwrap();
+
+    slint::run_event_loop().unwrap();
+}
+
+#[cfg(all(unix, not(target_vendor = "apple"), feature = "x11"))]
+fn check_x11_transient_for(window: &winit::window::Window, parent: &winit::window::Window) {
+    use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+    use x11rb::protocol::xproto::{AtomEnum, ConnectionExt};
+
+    let x11_window = |window: &winit::window::Window| match window.window_handle().unwrap().as_raw()
+    {
+        RawWindowHandle::Xlib(handle) => Some(handle.window as u32),
+        RawWindowHandle::Xcb(handle) => Some(handle.window.get()),
+        _ => None,
+    };
+    // Not running on X11
+    let (Some(window), Some(parent)) = (x11_window(window), x11_window(parent)) else { return };
+
+    let (connection, _) = x11rb::connect(None).unwrap();
+    let transient_for = connection
+        .get_property(false, window, AtomEnum::WM_TRANSIENT_FOR, AtomEnum::WINDOW, 0, 1)
+        .unwrap()
+        .reply()
+        .unwrap();
+    assert_eq!(transient_for.value32().unwrap().collect::<Vec<_>>(), [parent]);
+
+    let tree = connection.query_tree(window).unwrap().reply().unwrap();
+    assert_ne!(tree.parent, parent);
+}
@@ -0,0 +1,70 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use i_slint_core::api::{GraphicsAPI, RenderingState};
+use i_slint_core::graphics::wgpu_27::wgpu;
+use std::cell::Cell;
+use std::rc::Rc;
+
+fn main() {
+    slint::slint! {
+        export component App inherits Window {
+            width: 100px;
+            height: 100px;
+            background: transparent;
+        }
+    }
+
+    let backend =
+        i_slint_backend_winit::Backend::builder().with_renderer_name("wgpu").build().unwrap();
+    slint::platform::set_platform(Box::new(backend)).unwrap();
+
+    let app = App::new().unwrap();
+    let underlay_rendered = Rc::new(Cell::new(false));
+
+    app.window()
+        .set_rendering_notifier({
+            let underlay_rendered = underlay_rendered.clone();
+            move |state, graphics_api| {
+                let GraphicsAPI::WGPU27 { device, queue, surface_texture, .. } = graphics_api
+                else {
+                    panic!("The wgpu renderer must provide the WGPU27 graphics API");
+                };
+                match state {
+                    RenderingState::BeforeRendering => {
+                        let texture = surface_texture
+                            .as_ref()
+                            .expect("The surface texture must be available while rendering");
+                        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+                        let mut encoder = device
+                            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+                        encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                                view: &view,
+                                depth_slice: None,
+                                resolve_target: None,
+                                ops: wgpu::Operations {
+                                    load: wgpu::LoadOp::Clear(wgpu::Color::GREEN),
+                                    store: wgpu::StoreOp::Store,
+                                },
+                            })],
+                            ..Default::default()
+                        });
+                        queue.submit(Some(encoder.finish()));
+                        underlay_rendered.set(true);
+                    }
+                    RenderingState::AfterRendering => {
+                        assert!(surface_texture.is_some());
+                        slint::quit_event_loop().unwrap();
+                    }
+                    _ => {}
+                }
+            }
+        })
+        .unwrap();
+
+    app.show().unwrap();
+    slint::run_event_loop().unwrap();
+
+    assert!(underlay_rendered.get());
+}
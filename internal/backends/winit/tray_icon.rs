@@ -0,0 +1,155 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Implementation of the tray icon with the tray-icon crate. Its menu is a muda menu, whose
+//! events are received with the ones of the menu bars and are told apart with the `tray|` prefix
+//! of their ids.
+
+use super::CustomEvent;
+use crate::{SharedBackendData, SlintEvent};
+use i_slint_core::api::PlatformError;
+use i_slint_core::graphics::Image;
+use i_slint_core::platform::{TrayIconAdapter, TrayIconEvents};
+use i_slint_core::tray_icon::Menu;
+use std::rc::{Rc, Weak};
+use std::sync::atomic::{AtomicBool, Ordering};
+use winit::event_loop::EventLoopProxy;
+
+static TRAY_ICON_SET_EVENT_HANDLER_INSTALLED: AtomicBool = AtomicBool::new(false);
+
+pub struct WinitTrayIcon {
+    tray_icon: tray_icon::TrayIcon,
+    shared_backend_data: Weak<SharedBackendData>,
+}
+
+impl WinitTrayIcon {
+    pub fn new(
+        shared_backend_data: &Rc<SharedBackendData>,
+        events: TrayIconEvents,
+    ) -> Result<Self, PlatformError> {
+        install_event_handler_if_necessary(shared_backend_data.event_loop_proxy.clone());
+        crate::muda::install_event_handler_if_necessary(
+            shared_backend_data.event_loop_proxy.clone(),
+        );
+        let tray_icon = tray_icon::TrayIconBuilder::new()
+            .build()
+            .map_err(|err| format!("Error creating the tray icon: {err}"))?;
+        shared_backend_data.tray_icons.borrow_mut().insert(tray_icon.id().0.clone(), events);
+        Ok(Self { tray_icon, shared_backend_data: Rc::downgrade(shared_backend_data) })
+    }
+}
+
+impl TrayIconAdapter for WinitTrayIcon {
+    fn set_visible(&self, visible: bool) -> Result<(), PlatformError> {
+        self.tray_icon
+            .set_visible(visible)
+            .map_err(|err| format!("Error showing the tray icon: {err}").into())
+    }
+
+    fn set_icon(&self, icon: &Image) -> Result<(), PlatformError> {
+        let icon = icon
+            .to_rgba8()
+            .map(|rgba| {
+                tray_icon::Icon::from_rgba(rgba.as_bytes().to_vec(), rgba.width(), rgba.height())
+            })
+            .transpose()
+            .map_err(|err| format!("Error converting the tray icon image: {err}"))?;
+        self.tray_icon
+            .set_icon(icon)
+            .map_err(|err| format!("Error setting the tray icon image: {err}").into())
+    }
+
+    fn set_tooltip(&self, tooltip: &str) -> Result<(), PlatformError> {
+        self.tray_icon
+            .set_tooltip((!tooltip.is_empty()).then_some(tooltip))
+            .map_err(|err| format!("Error setting the tray icon tooltip: {err}").into())
+    }
+
+    fn set_menu(&self, menu: &Menu) -> Result<(), PlatformError> {
+        let tray_menu = muda::Menu::new();
+        let tray_id = &self.tray_icon.id().0;
+        for item in &menu.items {
+            tray_menu
+                .append(&*menu_item(tray_id, item, 0))
+                .map_err(|err| format!("Error creating the tray icon menu: {err}"))?;
+        }
+        self.tray_icon.set_menu(Some(Box::new(tray_menu)));
+        Ok(())
+    }
+}
+
+impl Drop for WinitTrayIcon {
+    fn drop(&mut self) {
+        if let Some(shared_backend_data) = self.shared_backend_data.upgrade() {
+            shared_backend_data.tray_icons.borrow_mut().remove(&self.tray_icon.id().0);
+        }
+    }
+}
+
+fn menu_item(
+    tray_id: &str,
+    item: &i_slint_core::tray_icon::MenuItem,
+    depth: usize,
+) -> Box<dyn muda::IsMenuItem> {
+    let id = muda::MenuId(format!("tray|{tray_id}|{}", item.id));
+    if item.is_separator {
+        Box::new(muda::PredefinedMenuItem::separator())
+    } else if let Some(sub_menu) = &item.sub_menu {
+        let sub = muda::Submenu::with_id(id, &item.title, item.enabled);
+        // Limit the depth, as for the menu bars
+        if depth < 15 {
+            for sub_item in &sub_menu.items {
+                sub.append(&*menu_item(tray_id, sub_item, depth + 1)).ok();
+            }
+        }
+        Box::new(sub)
+    } else if let Some(checked) = item.checked {
+        Box::new(muda::CheckMenuItem::with_id(id, &item.title, item.enabled, checked, None))
+    } else {
+        Box::new(muda::MenuItem::with_id(id, &item.title, item.enabled, None))
+    }
+}
+
+/// Handles a menu event if it comes from the menu of a tray icon, and returns false otherwise.
+pub fn handle_menu_event(shared_backend_data: &SharedBackendData, event: &muda::MenuEvent) -> bool {
+    let Some((tray_id, item_id)) =
+        event.id().0.strip_prefix("tray|").and_then(|id| id.split_once('|'))
+    else {
+        return false;
+    };
+    // Don't keep the map borrowed, the callback may create or drop tray icons
+    let events = shared_backend_data.tray_icons.borrow().get(tray_id).cloned();
+    if let Some(events) = events {
+        events.menu_item_activated(item_id);
+    }
+    true
+}
+
+pub fn handle_tray_icon_event(
+    shared_backend_data: &SharedBackendData,
+    event: tray_icon::TrayIconEvent,
+) {
+    if let tray_icon::TrayIconEvent::Click {
+        id,
+        button: tray_icon::MouseButton::Left,
+        button_state: tray_icon::MouseButtonState::Up,
+        ..
+    } = event
+    {
+        let events = shared_backend_data.tray_icons.borrow().get(&id.0).cloned();
+        if let Some(events) = events {
+            events.activated();
+        }
+    }
+}
+
+fn install_event_handler_if_necessary(proxy: EventLoopProxy<SlintEvent>) {
+    // Like for muda, the handler can only be set once
+    if !TRAY_ICON_SET_EVENT_HANDLER_INSTALLED.load(Ordering::Relaxed) {
+        tray_icon::TrayIconEvent::set_event_handler(Some(move |e| {
+            let _ = proxy.send_event(SlintEvent(CustomEvent::TrayIcon(e)));
+        }));
+
+        TRAY_ICON_SET_EVENT_HANDLER_INSTALLED.store(true, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Reports the hit test regions of frameless windows to Windows, by answering `WM_NCHITTEST` in a
+//! subclass of the window procedure of winit. This enables the behaviors of the system title bar,
+//! such as the snap layouts shown when hovering the maximize button.
+
+use crate::winitwindowadapter::WinitWindowAdapter;
+use i_slint_core::api::{LogicalPosition, WindowHitTestArea, WindowResizeEdge};
+use i_slint_core::window::{WindowAdapter, WindowInner};
+use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use std::rc::Weak;
+use windows::Win32::Foundation::{HWND, LPARAM, LRESULT, POINT, WPARAM};
+use windows::Win32::Graphics::Gdi::ScreenToClient;
+use windows::Win32::UI::Shell::{DefSubclassProc, RemoveWindowSubclass, SetWindowSubclass};
+use windows::Win32::UI::WindowsAndMessaging::*;
+
+const SUBCLASS_ID: usize = 0x51494e54;
+
+pub fn install(winit_window: &winit::window::Window, adapter: Weak<WinitWindowAdapter>) {
+    let Ok(RawWindowHandle::Win32(handle)) = winit_window.window_handle().map(|h| h.as_raw())
+    else {
+        return;
+    };
+    let hwnd = HWND(handle.hwnd.get() as *mut core::ffi::c_void);
+    // Freed when the window is destroyed, in WM_NCDESTROY
+    let ref_data = Box::into_raw(Box::new(adapter));
+    unsafe {
+        if !SetWindowSubclass(hwnd, Some(subclass_proc), SUBCLASS_ID, ref_data as usize).as_bool() {
+            drop(Box::from_raw(ref_data));
+        }
+    }
+}
+
+fn hit_test(adapter: &WinitWindowAdapter, hwnd: HWND, lparam: LPARAM) -> Option<WindowHitTestArea> {
+    // The coordinates are signed, as they can be negative with several screens
+    let mut point = POINT {
+        x: (lparam.0 & 0xffff) as i16 as i32,
+        y: ((lparam.0 >> 16) & 0xffff) as i16 as i32,
+    };
+    unsafe { ScreenToClient(hwnd, &mut point) }.as_bool().then_some(())?;
+    let window = WindowInner::from_pub(adapter.window());
+    let scale_factor = window.scale_factor();
+    window.hit_test(LogicalPosition::new(
+        point.x as f32 / scale_factor,
+        point.y as f32 / scale_factor,
+    ))
+}
+
+unsafe extern "system" fn subclass_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+    _id: usize,
+    ref_data: usize,
+) -> LRESULT {
+    let adapter = &*(ref_data as *const Weak<WinitWindowAdapter>);
+    match msg {
+        WM_NCHITTEST => {
+            let area = adapter.upgrade().and_then(|adapter| hit_test(&adapter, hwnd, lparam));
+            if let Some(area) = area {
+                return LRESULT(match area {
+                    WindowHitTestArea::TitleBar => HTCAPTION,
+                    WindowHitTestArea::MaximizeButton => HTMAXBUTTON,
+                    WindowHitTestArea::ResizeBorder(edge) => match edge {
+                        WindowResizeEdge::Top => HTTOP,
+                        WindowResizeEdge::Bottom => HTBOTTOM,
+                        WindowResizeEdge::Left => HTLEFT,
+                        WindowResizeEdge::TopLeft => HTTOPLEFT,
+                        WindowResizeEdge::TopRight => HTTOPRIGHT,
+                        WindowResizeEdge::BottomLeft => HTBOTTOMLEFT,
+                        WindowResizeEdge::BottomRight => HTBOTTOMRIGHT,
+                        _ => HTRIGHT,
+                    },
+                    _ => HTCLIENT,
+                } as isize);
+            }
+        }
+        // The system doesn't maximize the window when the button is drawn by the application
+        WM_NCLBUTTONDOWN if wparam.0 == HTMAXBUTTON as usize => return LRESULT(0),
+        WM_NCLBUTTONUP if wparam.0 == HTMAXBUTTON as usize => {
+            if let Some(adapter) = adapter.upgrade() {
+                let window = adapter.window();
+                window.set_maximized(!window.is_maximized());
+            }
+            return LRESULT(0);
+        }
+        WM_NCDESTROY => {
+            let _ = RemoveWindowSubclass(hwnd, Some(subclass_proc), SUBCLASS_ID);
+            drop(Box::from_raw(ref_data as *mut Weak<WinitWindowAdapter>));
+        }
+        _ => {}
+    }
+    DefSubclassProc(hwnd, msg, wparam, lparam)
+}
@@ -142,6 +142,17 @@ fn icon_to_winit(
                     .chain(std::iter::once(alpha as u8))
             })
             .collect(),
+        SharedImageBuffer::RGB565(pixels) => pixels
+            .as_slice()
+            .iter()
+            .flat_map(|pixel| {
+                let rgb: i_slint_core::graphics::Rgb8Pixel = (*pixel).into();
+                [rgb.r, rgb.g, rgb.b, 255]
+            })
+            .collect(),
+        SharedImageBuffer::Gray8(pixels) => {
+            pixels.as_bytes().iter().flat_map(|gray| [*gray, *gray, *gray, 255]).collect()
+        }
     };
 
     winit::window::Icon::from_rgba(rgba_pixels, pixel_buffer.width(), pixel_buffer.height()).ok()
@@ -222,6 +233,18 @@ fn set_fullscreen(&self, fullscreen: Option<winit::window::Fullscreen>) {
         }
     }
 
+    #[cfg(target_family = "windows")]
+    fn set_skip_taskbar(&self, skip_taskbar: bool) {
+        match self {
+            Self::HasWindow { window, .. } => window.set_skip_taskbar(skip_taskbar),
+            Self::None(attributes) => {
+                use winit::platform::windows::WindowAttributesExtWindows;
+                let new_attributes = attributes.take().with_skip_taskbar(skip_taskbar);
+                *attributes.borrow_mut() = new_attributes;
+            }
+        }
+    }
+
     fn set_window_level(&self, level: winit::window::WindowLevel) {
         match self {
             Self::HasWindow { window, .. } => window.set_window_level(level),
@@ -321,6 +344,11 @@ pub struct WinitWindowAdapter {
     maximized: Cell<bool>,
     minimized: Cell<bool>,
     fullscreen: Cell<bool>,
+    #[cfg(target_family = "windows")]
+    skip_taskbar: Cell<bool>,
+    /// The opacity last applied to the window, which is applied again when the window is created
+    #[cfg(target_family = "windows")]
+    opacity: Cell<f32>,
 
     pub(crate) renderer: Box<dyn WinitCompatibleRenderer>,
     /// We cache the size because winit_window.inner_size() can return different value between calls (eg, on X11)
@@ -347,6 +375,8 @@ pub struct WinitWindowAdapter {
     >,
 
     winit_window_or_none: RefCell<WinitWindowOrNone>,
+    /// The window this window is transient for, applied when the winit window is created
+    pub(crate) transient_parent: RefCell<Option<Weak<Self>>>,
     window_existence_wakers: RefCell<Vec<core::task::Waker>>,
 
     #[cfg(not(use_winit_theme))]
@@ -389,7 +419,12 @@ pub(crate) fn new(
             maximized: Cell::default(),
             minimized: Cell::default(),
             fullscreen: Cell::default(),
+            #[cfg(target_family = "windows")]
+            skip_taskbar: Cell::default(),
+            #[cfg(target_family = "windows")]
+            opacity: Cell::new(1.),
             winit_window_or_none: RefCell::new(WinitWindowOrNone::None(window_attributes.into())),
+            transient_parent: Default::default(),
             window_existence_wakers: RefCell::new(Vec::default()),
             size: Cell::default(),
             pending_requested_size: Cell::new(None),
@@ -451,6 +486,33 @@ pub fn ensure_window(
             }
         }
 
+        let transient_parent = self
+            .transient_parent
+            .borrow()
+            .as_ref()
+            .and_then(|parent| parent.upgrade())
+            .and_then(|parent| parent.winit_window());
+        if let Some(parent_window) = &transient_parent {
+            use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+            match parent_window.window_handle().map(|handle| handle.as_raw()) {
+                #[cfg(target_family = "windows")]
+                Ok(RawWindowHandle::Win32(handle)) => {
+                    use winit::platform::windows::WindowAttributesExtWindows;
+                    window_attributes = window_attributes.with_owner_window(handle.hwnd.get());
+                }
+                // AppKit child windows are top-level windows that are ordered above their parent
+                #[cfg(target_os = "macos")]
+                Ok(handle @ RawWindowHandle::AppKit(_)) => {
+                    // Safety: the handle is of a window that's alive, as we hold a reference to it
+                    window_attributes =
+                        unsafe { window_attributes.with_parent_window(Some(handle)) };
+                }
+                // X11 windows are marked as transient once they're created. winit doesn't give access
+                // to the xdg_toplevel of Wayland windows, so they can't have a parent there.
+                _ => {}
+            }
+        }
+
         // Never show the window right away, as we
         //  a) need to compute the correct size based on the scale factor before it's shown on the screen (handled by set_visible)
         //  b) need to create the accesskit adapter before it's shown on the screen, as required by accesskit.
@@ -474,6 +536,13 @@ pub fn ensure_window(
 
         let winit_window = self.renderer.resume(active_event_loop, window_attributes)?;
 
+        #[cfg(all(unix, not(target_vendor = "apple"), feature = "x11"))]
+        if let Some(parent_window) = &transient_parent {
+            if let Err(err) = set_x11_transient_for(&winit_window, parent_window) {
+                i_slint_core::debug_log!("Error setting the transient parent of the window: {err}");
+            }
+        }
+
         let scale_factor =
             overriding_scale_factor.unwrap_or_else(|| winit_window.scale_factor() as f32);
         self.window().try_dispatch_event(WindowEvent::ScaleFactorChanged { scale_factor })?;
@@ -494,6 +563,14 @@ pub fn ensure_window(
             context_menu_muda_adapter: None.into(),
         };
 
+        #[cfg(target_os = "windows")]
+        {
+            crate::win32_hit_test::install(&winit_window, self.self_weak.clone());
+            if self.opacity.get() < 1. {
+                set_win32_window_opacity(&winit_window, self.opacity.get());
+            }
+        }
+
         #[cfg(muda)]
         {
             let new_muda_adapter = self.menubar.borrow().as_ref().map(|menubar| {
@@ -1133,6 +1210,20 @@ fn update_window_properties(&self, properties: corelib::window::WindowProperties
             winit_window_or_none.set_window_level(new_window_level);
         }
 
+        #[cfg(target_family = "windows")]
+        {
+            let skip_taskbar = properties.is_skip_taskbar();
+            if self.skip_taskbar.replace(skip_taskbar) != skip_taskbar {
+                winit_window_or_none.set_skip_taskbar(skip_taskbar);
+            }
+            let opacity = properties.opacity();
+            if self.opacity.replace(opacity) != opacity {
+                if let Some(window) = winit_window_or_none.as_window() {
+                    set_win32_window_opacity(&window, opacity);
+                }
+            }
+        }
+
         let mut width = window_item.width().get() as f32;
         let mut height = window_item.height().get() as f32;
         let mut must_resize = false;
@@ -1484,6 +1575,47 @@ fn bring_to_front(&self) -> Result<(), PlatformError> {
         Ok(())
     }
 
+    fn start_system_move(&self) -> Result<(), PlatformError> {
+        let winit_window = self.winit_window_or_none.borrow().as_window();
+        let winit_window =
+            winit_window.ok_or_else(|| PlatformError::from("The window is not shown"))?;
+        winit_window.drag_window().map_err(|err| format!("Error moving the window: {err}").into())
+    }
+
+    fn start_system_resize(
+        &self,
+        edge: corelib::api::WindowResizeEdge,
+    ) -> Result<(), PlatformError> {
+        let winit_window = self.winit_window_or_none.borrow().as_window();
+        let winit_window =
+            winit_window.ok_or_else(|| PlatformError::from("The window is not shown"))?;
+        winit_window
+            .drag_resize_window(crate::drag_resize_window::resize_direction(edge))
+            .map_err(|err| format!("Error resizing the window: {err}").into())
+    }
+
+    fn screen(&self) -> Option<corelib::api::Screen> {
+        let window = self.winit_window()?;
+        let monitor = window.current_monitor()?;
+        Some(crate::monitor_to_screen(&monitor, window.primary_monitor().as_ref()))
+    }
+
+    fn set_screen(&self, screen: &corelib::api::Screen) -> Result<(), PlatformError> {
+        let monitor = self
+            .shared_backend_data
+            .find_monitor(screen)
+            .ok_or_else(|| PlatformError::from("The screen is not connected"))?;
+        let is_fullscreen = self.winit_window_or_none.borrow().fullscreen().is_some();
+        if is_fullscreen {
+            self.winit_window_or_none
+                .borrow()
+                .set_fullscreen(Some(winit::window::Fullscreen::Borderless(Some(monitor))));
+        } else {
+            self.set_position(corelib::api::WindowPosition::Physical(screen.position()));
+        }
+        Ok(())
+    }
+
     #[cfg(target_os = "ios")]
     fn safe_area_inset(&self) -> i_slint_core::lengths::PhysicalInset {
         self.winit_window_or_none
@@ -1522,6 +1654,68 @@ fn drop(&mut self) {
     }
 }
 
+/// Winit doesn't support the opacity of the whole window, so make it a layered window
+#[cfg(target_os = "windows")]
+fn set_win32_window_opacity(window: &winit::window::Window, opacity: f32) {
+    use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+    use windows::Win32::Foundation::{COLORREF, HWND};
+    use windows::Win32::UI::WindowsAndMessaging::{
+        GetWindowLongW, SetLayeredWindowAttributes, SetWindowLongW, GWL_EXSTYLE, LWA_ALPHA,
+        WS_EX_LAYERED,
+    };
+
+    let Ok(RawWindowHandle::Win32(handle)) = window.window_handle().map(|h| h.as_raw()) else {
+        return;
+    };
+    let hwnd = HWND(handle.hwnd.get() as *mut core::ffi::c_void);
+    unsafe {
+        let ex_style = GetWindowLongW(hwnd, GWL_EXSTYLE);
+        if ex_style & WS_EX_LAYERED.0 as i32 == 0 {
+            SetWindowLongW(hwnd, GWL_EXSTYLE, ex_style | WS_EX_LAYERED.0 as i32);
+        }
+        if let Err(err) =
+            SetLayeredWindowAttributes(hwnd, COLORREF(0), (opacity * 255.).round() as u8, LWA_ALPHA)
+        {
+            i_slint_core::debug_log!("Error setting the opacity of the window: {err}");
+        }
+    }
+}
+
+/// Winit doesn't support transient windows on X11, so set the WM_TRANSIENT_FOR property of the window
+#[cfg(all(unix, not(target_vendor = "apple"), feature = "x11"))]
+fn set_x11_transient_for(
+    window: &winit::window::Window,
+    parent: &winit::window::Window,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use raw_window_handle::{HandleError, HasWindowHandle, RawWindowHandle};
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{AtomEnum, PropMode};
+    use x11rb::wrapper::ConnectionExt;
+
+    let x11_window = |window: &winit::window::Window| -> Result<Option<u32>, HandleError> {
+        Ok(match window.window_handle()?.as_raw() {
+            RawWindowHandle::Xlib(handle) => Some(handle.window as u32),
+            RawWindowHandle::Xcb(handle) => Some(handle.window.get()),
+            _ => None,
+        })
+    };
+    let (Some(window), Some(parent)) = (x11_window(window)?, x11_window(parent)?) else {
+        return Ok(());
+    };
+
+    // The property is set before the window is mapped, so a separate connection is fine
+    let (connection, _) = x11rb::connect(None)?;
+    connection.change_property32(
+        PropMode::REPLACE,
+        window,
+        AtomEnum::WM_TRANSIENT_FOR,
+        AtomEnum::WINDOW,
+        &[parent],
+    )?;
+    connection.flush()?;
+    Ok(())
+}
+
 // Winit doesn't automatically resize the window to satisfy constraints. Qt does it though, and so do we here.
 #[cfg(not(ios_and_friends))]
 fn adjust_window_size_to_satisfy_constraints(
@@ -0,0 +1,125 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Shows file dialogs with the `FileChooser` interface of the XDG desktop portal.
+
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use i_slint_core::dialogs::{FileDialogKind, FileDialogOptions};
+use zbus::zvariant::Value;
+
+/// Shows the dialog, modal for the window identified by `parent_window` as described in the
+/// documentation of the portal, such as `x11:1a00004`, or an empty string.
+pub async fn show(
+    kind: FileDialogKind,
+    options: FileDialogOptions,
+    parent_window: String,
+) -> zbus::Result<Option<PathBuf>> {
+    static REQUEST_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    let connection = zbus::Connection::session().await?;
+
+    // Listen to the response before making the request, at the path derived from our token,
+    // so that it can't be missed.
+    let token = format!("slint_file_dialog_{}", REQUEST_COUNTER.fetch_add(1, Ordering::Relaxed));
+    let sender = connection
+        .unique_name()
+        .map(|name| name.trim_start_matches(':').replace('.', "_"))
+        .unwrap_or_default();
+    let request_proxy: zbus::Proxy = zbus::proxy::Builder::new(&connection)
+        .interface("org.freedesktop.portal.Request")?
+        .path(format!("/org/freedesktop/portal/desktop/request/{sender}/{token}"))?
+        .destination("org.freedesktop.portal.Desktop")?
+        .build()
+        .await?;
+
+    use futures::stream::StreamExt;
+    let mut responses = request_proxy.receive_signal("Response").await?;
+
+    let file_chooser_proxy: zbus::Proxy = zbus::proxy::Builder::new(&connection)
+        .interface("org.freedesktop.portal.FileChooser")?
+        .path("/org/freedesktop/portal/desktop")?
+        .destination("org.freedesktop.portal.Desktop")?
+        .build()
+        .await?;
+
+    let mut portal_options = HashMap::from([
+        ("handle_token", Value::from(token.as_str())),
+        ("modal", Value::from(true)),
+    ]);
+    if kind == FileDialogKind::PickFolder {
+        portal_options.insert("directory", Value::from(true));
+    }
+    if !options.filters.is_empty() {
+        // Each filter is a name and a list of glob patterns, with the type 0
+        let filters: Vec<(String, Vec<(u32, String)>)> = options
+            .filters
+            .iter()
+            .map(|filter| {
+                let patterns =
+                    filter.extensions.iter().map(|ext| (0, format!("*.{ext}"))).collect();
+                (filter.name.clone(), patterns)
+            })
+            .collect();
+        portal_options.insert("filters", Value::from(filters));
+    }
+    if let Some(directory) = &options.directory {
+        // The folder is a null-terminated byte string
+        let mut directory = directory.as_os_str().as_bytes().to_vec();
+        directory.push(0);
+        portal_options.insert("current_folder", Value::from(directory));
+    }
+    if let Some(file_name) =
+        options.file_name.as_deref().filter(|_| kind == FileDialogKind::SaveFile)
+    {
+        portal_options.insert("current_name", Value::from(file_name));
+    }
+
+    let method = if kind == FileDialogKind::SaveFile { "SaveFile" } else { "OpenFile" };
+    let _: zbus::zvariant::OwnedObjectPath = file_chooser_proxy
+        .call(method, &(parent_window.as_str(), options.title.as_str(), portal_options))
+        .await?;
+
+    let Some(response) = responses.next().await else { return Ok(None) };
+    let (status, mut results): (u32, HashMap<String, zbus::zvariant::OwnedValue>) =
+        response.body().deserialize()?;
+    // A non-zero status means that the user canceled
+    let Some(uris) = results.remove("uris").filter(|_| status == 0) else { return Ok(None) };
+    let uris = <Vec<String>>::try_from(uris)?;
+    Ok(uris.first().and_then(|uri| file_uri_to_path(uri)))
+}
+
+/// Converts a `file://` URI, as returned by the portal, to a path.
+fn file_uri_to_path(uri: &str) -> Option<PathBuf> {
+    let path = uri.strip_prefix("file://")?.as_bytes();
+    let mut bytes = Vec::with_capacity(path.len());
+    let mut i = 0;
+    while i < path.len() {
+        let escaped = (path[i] == b'%')
+            .then(|| path.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match escaped {
+            Some(byte) => {
+                bytes.push(byte);
+                i += 3;
+            }
+            None => {
+                bytes.push(path[i]);
+                i += 1;
+            }
+        }
+    }
+    Some(OsString::from_vec(bytes).into())
+}
+
+#[test]
+fn test_file_uri_to_path() {
+    assert_eq!(file_uri_to_path("file:///home/user/a%20b.txt"), Some("/home/user/a b.txt".into()));
+    assert_eq!(file_uri_to_path("file:///tmp/100%"), Some("/tmp/100%".into()));
+    assert_eq!(file_uri_to_path("https://slint.dev"), None);
+}
@@ -0,0 +1,50 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Picks a color on the screen with the `PickColor` method of the XDG desktop portal.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use i_slint_core::Color;
+
+pub async fn pick_color() -> zbus::Result<Option<Color>> {
+    static REQUEST_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    let connection = zbus::Connection::session().await?;
+
+    // Listen to the response before making the request, at the path derived from our token,
+    // so that it can't be missed.
+    let token = format!("slint_pick_color_{}", REQUEST_COUNTER.fetch_add(1, Ordering::Relaxed));
+    let sender = connection
+        .unique_name()
+        .map(|name| name.trim_start_matches(':').replace('.', "_"))
+        .unwrap_or_default();
+    let request_proxy: zbus::Proxy = zbus::proxy::Builder::new(&connection)
+        .interface("org.freedesktop.portal.Request")?
+        .path(format!("/org/freedesktop/portal/desktop/request/{sender}/{token}"))?
+        .destination("org.freedesktop.portal.Desktop")?
+        .build()
+        .await?;
+
+    use futures::stream::StreamExt;
+    let mut responses = request_proxy.receive_signal("Response").await?;
+
+    let screenshot_proxy: zbus::Proxy = zbus::proxy::Builder::new(&connection)
+        .interface("org.freedesktop.portal.Screenshot")?
+        .path("/org/freedesktop/portal/desktop")?
+        .destination("org.freedesktop.portal.Desktop")?
+        .build()
+        .await?;
+    let options = HashMap::from([("handle_token", zbus::zvariant::Value::from(token.as_str()))]);
+    let _: zbus::zvariant::OwnedObjectPath =
+        screenshot_proxy.call("PickColor", &("", options)).await?;
+
+    let Some(response) = responses.next().await else { return Ok(None) };
+    let (status, mut results): (u32, HashMap<String, zbus::zvariant::OwnedValue>) =
+        response.body().deserialize()?;
+    // A non-zero status means that the user canceled
+    let Some(color) = results.remove("color").filter(|_| status == 0) else { return Ok(None) };
+    let (red, green, blue) = <(f64, f64, f64)>::try_from(color)?;
+    Ok(Some(Color::from_rgb_f32(red as f32, green as f32, blue as f32)))
+}
@@ -337,6 +337,9 @@ enum LayoutAlignment {
                 /// Use the preferred size for all elements, distribute remaining space evenly before the
                 /// first element, after the last element and between elements.
                 SpaceEvenly,
+                /// Only valid for `HorizontalLayout`. Distribute the space like `stretch` and vertically
+                /// align the elements so that the baselines of their text are on the same line.
+                Baseline,
             }
 
             /// PathEvent is a low-level data structure describing the composition of a path. Typically it is
@@ -422,6 +425,16 @@ enum Orientation {
                 Vertical,
             }
 
+            /// This enum describes the color space in which two colors are interpolated, for example with `Color.mix-in()`.
+            enum ColorSpace {
+                /// Interpolate the red, green, and blue channels.
+                Srgb,
+                /// Interpolate the hue, saturation, and lightness in the HSL color space. The hue takes the shorter arc.
+                Hsl,
+                /// Interpolate the lightness, chroma, and hue in the perceptual OKLCH color space. The hue takes the shorter arc.
+                Oklch,
+            }
+
             /// This enum indicates the color scheme used by the widget style. Use this to explicitly switch
             /// between dark and light schemes, or choose Unknown to fall back to the system default.
             enum ColorScheme {
@@ -478,6 +491,73 @@ enum LineCap {
                 Square,
             }
 
+            /// This enum describes the appearance of the corners of stroked paths.
+            enum LineJoin {
+                /// The outer edges of the lines are extended until they meet in a sharp corner.
+                Miter,
+                /// The corner is rounded.
+                Round,
+                /// The corner is cut off with a straight edge.
+                Bevel,
+            }
+
+            /// This enum describes the style of the border of a `Rectangle`.
+            enum BorderStyle {
+                /// The border is a continuous line.
+                Solid,
+                /// The border is a series of dashes, three times as long as the border is wide.
+                Dashed,
+                /// The border is a series of square dots, as long as the border is wide.
+                Dotted,
+            }
+
+            /// This enum specifies whether the edges and the border of a `Rectangle` are rounded to device pixels.
+            /// With fractional scale factors such as 1.25 or 1.5, this keeps thin borders and lines sharp.
+            enum PixelSnapping {
+                /// Follows the policy of the window, which rounds to device pixels by default.
+                Auto,
+                /// The edges and the border are rounded to device pixels.
+                Always,
+                /// The edges and the border are drawn at their exact position, which may look blurry.
+                Never,
+            }
+
+            /// This enum describes how an element is blended with the content drawn below it, with the `mix-blend-mode` property.
+            enum MixBlendMode {
+                /// The element is drawn over the content below it.
+                Normal,
+                /// The colors are multiplied, which darkens the content below, like a tinted overlay.
+                Multiply,
+                /// The inverted colors are multiplied, which lightens the content below.
+                Screen,
+                /// Multiplies dark areas and screens light areas of the content below.
+                Overlay,
+                /// Keeps the darker of the two colors.
+                Darken,
+                /// Keeps the lighter of the two colors.
+                Lighten,
+                /// Brightens the content below to reflect the color of the element.
+                ColorDodge,
+                /// Darkens the content below to reflect the color of the element.
+                ColorBurn,
+                /// Multiplies or screens the colors, depending on the color of the element.
+                HardLight,
+                /// Darkens or lightens the colors, depending on the color of the element.
+                SoftLight,
+                /// Subtracts the darker of the two colors from the lighter one.
+                Difference,
+                /// Like `difference`, with a lower contrast.
+                Exclusion,
+                /// Uses the hue of the element with the saturation and luminosity of the content below.
+                Hue,
+                /// Uses the saturation of the element with the hue and luminosity of the content below.
+                Saturation,
+                /// Uses the hue and saturation of the element with the luminosity of the content below.
+                Color,
+                /// Uses the luminosity of the element with the hue and saturation of the content below.
+                Luminosity,
+            }
+
             /// This enum describes the detected operating system types.
             #[non_exhaustive]
             enum OperatingSystemType {
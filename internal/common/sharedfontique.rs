@@ -8,35 +8,20 @@
 use std::sync::Arc;
 
 pub static COLLECTION: std::sync::LazyLock<Collection> = std::sync::LazyLock::new(|| {
+    // Golden-image tests must not depend on the fonts installed on the system
+    let deterministic = std::env::var_os("SLINT_DETERMINISTIC_RENDERING").is_some();
+
     let mut collection = fontique::Collection::new(fontique::CollectionOptions {
         shared: true,
-        ..Default::default()
+        system_fonts: !deterministic,
     });
 
     let mut source_cache = fontique::SourceCache::new_shared();
 
     let mut default_fonts: HashMap<std::path::PathBuf, fontique::QueryFont> = Default::default();
 
-    #[cfg(any(target_family = "wasm", target_os = "nto"))]
-    {
-        let data = include_bytes!("sharedfontique/DejaVuSans.ttf");
-        let fonts = collection.register_fonts(fontique::Blob::new(Arc::new(data)), None);
-        for script in fontique::Script::all_samples().iter().map(|(script, _)| *script) {
-            collection.append_fallbacks(
-                fontique::FallbackKey::new(script, None),
-                fonts.iter().map(|(family_id, _)| *family_id),
-            );
-        }
-        for generic_family in [
-            fontique::GenericFamily::SansSerif,
-            fontique::GenericFamily::SystemUi,
-            fontique::GenericFamily::UiSansSerif,
-        ] {
-            collection.append_generic_families(
-                generic_family,
-                fonts.iter().map(|(family_id, _)| *family_id),
-            );
-        }
+    if deterministic || cfg!(any(target_family = "wasm", target_os = "nto")) {
+        register_bundled_font(&mut collection);
     }
 
     let mut add_font_from_path = |path: std::path::PathBuf| {
@@ -71,6 +56,27 @@
     Collection { inner: collection, source_cache, default_fonts: Arc::new(default_fonts) }
 });
 
+/// Registers DejaVu Sans as the fallback for all scripts and generic families, for the platforms
+/// without system fonts.
+fn register_bundled_font(collection: &mut fontique::Collection) {
+    let data = include_bytes!("sharedfontique/DejaVuSans.ttf");
+    let fonts = collection.register_fonts(fontique::Blob::new(Arc::new(data)), None);
+    for script in fontique::Script::all_samples().iter().map(|(script, _)| *script) {
+        collection.append_fallbacks(
+            fontique::FallbackKey::new(script, None),
+            fonts.iter().map(|(family_id, _)| *family_id),
+        );
+    }
+    for generic_family in [
+        fontique::GenericFamily::SansSerif,
+        fontique::GenericFamily::SystemUi,
+        fontique::GenericFamily::UiSansSerif,
+    ] {
+        collection
+            .append_generic_families(generic_family, fonts.iter().map(|(family_id, _)| *family_id));
+    }
+}
+
 pub fn get_collection() -> Collection {
     COLLECTION.clone()
 }
@@ -86,7 +86,15 @@ pub fn lower_macro(
             expr
         }
         BuiltinMacroFunction::Rgb => rgb_macro(n, sub_expr.collect(), diag),
-        BuiltinMacroFunction::Hsv => hsv_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::Hsv => {
+            color_space_macro(n, BuiltinFunction::Hsv, sub_expr.collect(), diag)
+        }
+        BuiltinMacroFunction::Hsl => {
+            color_space_macro(n, BuiltinFunction::Hsl, sub_expr.collect(), diag)
+        }
+        BuiltinMacroFunction::Oklch => {
+            color_space_macro(n, BuiltinFunction::Oklch, sub_expr.collect(), diag)
+        }
     }
 }
 
@@ -265,8 +273,10 @@ fn rgb_macro(
     }
 }
 
-fn hsv_macro(
+/// `hsv(h, s, v, a)`, `hsl(h, s, l, a)`, or `oklch(l, c, h, a)`, where the alpha is optional
+fn color_space_macro(
     node: &dyn Spanned,
+    function: BuiltinFunction,
     args: Vec<(Expression, Option<NodeOrToken>)>,
     diag: &mut BuildDiagnostics,
 ) -> Expression {
@@ -283,7 +293,7 @@ fn hsv_macro(
         arguments.push(Expression::NumberLiteral(1., Unit::None))
     }
     Expression::FunctionCall {
-        function: BuiltinFunction::Hsv.into(),
+        function: function.into(),
         arguments,
         source_location: Some(node.to_source_location()),
     }
@@ -240,9 +240,9 @@ fn from(l: DiagnosticLevel) -> Self {
 /// position in the code
 #[derive(Debug, Clone)]
 pub struct Diagnostic {
-    message: String,
-    span: SourceLocation,
-    level: DiagnosticLevel,
+    pub(crate) message: String,
+    pub(crate) span: SourceLocation,
+    pub(crate) level: DiagnosticLevel,
 }
 
 //NOTE! Diagnostic is re-exported in the public API of the interpreter
@@ -355,6 +355,15 @@ pub fn push_property_deprecation_warning(
         )
     }
 
+    /// Turns all the warnings into errors.
+    pub fn deny_warnings(&mut self) {
+        for diag in &mut self.inner {
+            if diag.level == DiagnosticLevel::Warning {
+                diag.level = DiagnosticLevel::Error;
+            }
+        }
+    }
+
     /// Return true if there is at least one compilation error for this file
     pub fn has_errors(&self) -> bool {
         self.inner.iter().any(|diag| diag.level == DiagnosticLevel::Error)
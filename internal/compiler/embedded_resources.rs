@@ -20,6 +20,10 @@ pub enum PixelFormat {
     RgbaPremultiplied,
     // 8bit alpha map with a given color
     AlphaMap([u8; 3]),
+    // 24 bit RGB, with each line compressed with a run-length encoding
+    RunLengthEncodedRgb,
+    // 32 bit RGBA pre-multiplied, with each line compressed with a run-length encoding
+    RunLengthEncodedRgbaPremultiplied,
 }
 
 #[cfg(feature = "software-renderer")]
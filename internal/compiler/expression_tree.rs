@@ -73,15 +73,20 @@ pub enum BuiltinFunction {
     StringToUppercase,
     ColorRgbaStruct,
     ColorHsvaStruct,
+    ColorHslaStruct,
+    ColorOklchStruct,
     ColorBrighter,
     ColorDarker,
     ColorTransparentize,
     ColorMix,
+    ColorMixIn,
     ColorWithAlpha,
     ImageSize,
     ArrayLength,
     Rgb,
     Hsv,
+    Hsl,
+    Oklch,
     ColorScheme,
     SupportsNativeMenuBar,
     /// Setup the menu bar
@@ -98,10 +103,38 @@ pub enum BuiltinFunction {
     DateNow,
     ValidDate,
     ParseDate,
+    /// Arguments are `(texts, pattern)`, returns the indices of the matching texts
+    FuzzyFilter,
+    ColorToHex,
+    /// Arguments are `(text, fallback)`, returns the fallback color if the text isn't a valid color
+    ParseColorHex,
+    /// Arguments are `(rows, start-row, start-column, end-row, end-column)`, returns the text of the cells
+    /// of the `[[StandardListViewItem]]` within the range as tab-separated values
+    TableCellsToTsv,
+    SetClipboardText,
+    /// `@open-url(url)`: asks the platform to open the url, subject to the application's policy
+    OpenUrl,
+    /// `Process.arguments`
+    ProcessArguments,
+    /// `Process.env(name)`
+    ProcessEnv,
+    /// `Process.exit(code)`
+    ProcessExit,
     TextInputFocused,
     SetTextInputFocused,
+    SystemTimeOfDay,
+    SystemUnixTime,
+    SystemUtcOffset,
+    SystemTimeZone,
+    SystemTickInterval,
+    SetSystemTickInterval,
+    SystemLocale,
+    SystemNetworkOnline,
+    SystemOnBattery,
+    SystemBatteryLevel,
     ImplicitLayoutInfo(Orientation),
     ItemAbsolutePosition,
+    ItemVisibleInViewport,
     RegisterCustomFontByPath,
     RegisterCustomFontByMemory,
     RegisterBitmapFont,
@@ -137,6 +170,8 @@ pub enum BuiltinMacroFunction {
     /// transform the argument so it is always rgb(r, g, b, a) with r, g, b between 0 and 255.
     Rgb,
     Hsv,
+    Hsl,
+    Oklch,
     /// transform `debug(a, b, c)` into debug `a + " " + b + " " + c`
     Debug,
 }
@@ -230,11 +265,38 @@ pub fn ty(&self, function: &BuiltinFunction) -> Rc<Function> {
         node: None,
         rust_attributes: None,
     })),
+    ColorHslaStruct: (Type::Color) -> Type::Struct(Rc::new(Struct {
+        fields: IntoIterator::into_iter([
+            (SmolStr::new_static("hue"), Type::Float32),
+            (SmolStr::new_static("saturation"), Type::Float32),
+            (SmolStr::new_static("lightness"), Type::Float32),
+            (SmolStr::new_static("alpha"), Type::Float32),
+        ])
+        .collect(),
+        name: Some("Color".into()),
+        node: None,
+        rust_attributes: None,
+    })),
+    ColorOklchStruct: (Type::Color) -> Type::Struct(Rc::new(Struct {
+        fields: IntoIterator::into_iter([
+            (SmolStr::new_static("lightness"), Type::Float32),
+            (SmolStr::new_static("chroma"), Type::Float32),
+            (SmolStr::new_static("hue"), Type::Float32),
+            (SmolStr::new_static("alpha"), Type::Float32),
+        ])
+        .collect(),
+        name: Some("Color".into()),
+        node: None,
+        rust_attributes: None,
+    })),
     ColorBrighter: (Type::Brush, Type::Float32) -> Type::Brush,
     ColorDarker: (Type::Brush, Type::Float32) -> Type::Brush,
     ColorTransparentize: (Type::Brush, Type::Float32) -> Type::Brush,
     ColorWithAlpha: (Type::Brush, Type::Float32) -> Type::Brush,
     ColorMix: (Type::Color, Type::Color, Type::Float32) -> Type::Color,
+    ColorMixIn: (Type::Color, Type::Color, Type::Float32, Type::Enumeration(
+        typeregister::BUILTIN.with(|e| e.enums.ColorSpace.clone()),
+    )) -> Type::Color,
     ImageSize: (Type::Image) -> Type::Struct(Rc::new(Struct {
         fields: IntoIterator::into_iter([
             (SmolStr::new_static("width"), Type::Int32),
@@ -248,6 +310,8 @@ pub fn ty(&self, function: &BuiltinFunction) -> Rc<Function> {
     ArrayLength: (Type::Model) -> Type::Int32,
     Rgb: (Type::Int32, Type::Int32, Type::Int32, Type::Float32) -> Type::Color,
     Hsv: (Type::Float32, Type::Float32, Type::Float32, Type::Float32) -> Type::Color,
+    Hsl: (Type::Float32, Type::Float32, Type::Float32, Type::Float32) -> Type::Color,
+    Oklch: (Type::Float32, Type::Float32, Type::Float32, Type::Float32) -> Type::Color,
     ColorScheme: () -> Type::Enumeration(
         typeregister::BUILTIN.with(|e| e.enums.ColorScheme.clone()),
     ),
@@ -261,8 +325,28 @@ pub fn ty(&self, function: &BuiltinFunction) -> Rc<Function> {
     DateNow: () -> Type::Array(Rc::new(Type::Int32)),
     ValidDate: (Type::String, Type::String) -> Type::Bool,
     ParseDate: (Type::String, Type::String) -> Type::Array(Rc::new(Type::Int32)),
+    FuzzyFilter: (Type::Array(Rc::new(Type::String)), Type::String) -> Type::Array(Rc::new(Type::Int32)),
+    ColorToHex: (Type::Color) -> Type::String,
+    ParseColorHex: (Type::String, Type::Color) -> Type::Color,
+    TableCellsToTsv: (Type::Model, Type::Int32, Type::Int32, Type::Int32, Type::Int32) -> Type::String,
+    SetClipboardText: (Type::String) -> Type::Void,
+    OpenUrl: (Type::String) -> Type::Void,
+    ProcessArguments: () -> Type::Array(Rc::new(Type::String)),
+    ProcessEnv: (Type::String) -> Type::String,
+    ProcessExit: (Type::Int32) -> Type::Void,
     SetTextInputFocused: (Type::Bool) -> Type::Void,
+    SystemTimeOfDay: () -> Type::Duration,
+    SystemUnixTime: () -> Type::Duration,
+    SystemUtcOffset: () -> Type::Duration,
+    SystemTimeZone: () -> Type::String,
+    SystemTickInterval: () -> Type::Duration,
+    SetSystemTickInterval: (Type::Duration) -> Type::Void,
+    SystemLocale: () -> Type::String,
+    SystemNetworkOnline: () -> Type::Bool,
+    SystemOnBattery: () -> Type::Bool,
+    SystemBatteryLevel: () -> Type::Float32,
     ItemAbsolutePosition: (Type::ElementReference) -> typeregister::logical_point_type().into(),
+    ItemVisibleInViewport: (Type::ElementReference) -> Type::Bool,
     RegisterCustomFontByPath: (Type::String) -> Type::Void,
     RegisterCustomFontByMemory: (Type::Int32) -> Type::Void,
     RegisterBitmapFont: (Type::Int32) -> Type::Void,
@@ -303,6 +387,15 @@ fn is_const(&self, global_analysis: Option<&crate::passes::GlobalAnalysis>) -> b
             BuiltinFunction::DateNow => false,
             BuiltinFunction::ValidDate => false,
             BuiltinFunction::ParseDate => false,
+            BuiltinFunction::FuzzyFilter => true,
+            BuiltinFunction::ColorToHex => true,
+            BuiltinFunction::ParseColorHex => true,
+            BuiltinFunction::TableCellsToTsv => true,
+            BuiltinFunction::SetClipboardText => false,
+            BuiltinFunction::OpenUrl => false,
+            BuiltinFunction::ProcessArguments
+            | BuiltinFunction::ProcessEnv
+            | BuiltinFunction::ProcessExit => false,
             // Even if it is not pure, we optimize it away anyway
             BuiltinFunction::Debug => true,
             BuiltinFunction::Mod
@@ -339,10 +432,13 @@ fn is_const(&self, global_analysis: Option<&crate::passes::GlobalAnalysis>) -> b
             | BuiltinFunction::StringToUppercase => true,
             BuiltinFunction::ColorRgbaStruct
             | BuiltinFunction::ColorHsvaStruct
+            | BuiltinFunction::ColorHslaStruct
+            | BuiltinFunction::ColorOklchStruct
             | BuiltinFunction::ColorBrighter
             | BuiltinFunction::ColorDarker
             | BuiltinFunction::ColorTransparentize
             | BuiltinFunction::ColorMix
+            | BuiltinFunction::ColorMixIn
             | BuiltinFunction::ColorWithAlpha => true,
             // ImageSize is pure, except when loading images via the network. Then the initial size will be 0/0 and
             // we need to make sure that calls to this function stay within a binding, so that the property
@@ -354,11 +450,22 @@ fn is_const(&self, global_analysis: Option<&crate::passes::GlobalAnalysis>) -> b
             BuiltinFunction::ImageSize => false,
             BuiltinFunction::ArrayLength => true,
             BuiltinFunction::Rgb => true,
-            BuiltinFunction::Hsv => true,
+            BuiltinFunction::Hsv | BuiltinFunction::Hsl | BuiltinFunction::Oklch => true,
             BuiltinFunction::SetTextInputFocused => false,
             BuiltinFunction::TextInputFocused => false,
+            BuiltinFunction::SystemTimeOfDay
+            | BuiltinFunction::SystemUnixTime
+            | BuiltinFunction::SystemUtcOffset
+            | BuiltinFunction::SystemTimeZone
+            | BuiltinFunction::SystemTickInterval
+            | BuiltinFunction::SetSystemTickInterval
+            | BuiltinFunction::SystemLocale
+            | BuiltinFunction::SystemNetworkOnline
+            | BuiltinFunction::SystemOnBattery
+            | BuiltinFunction::SystemBatteryLevel => false,
             BuiltinFunction::ImplicitLayoutInfo(_) => false,
             BuiltinFunction::ItemAbsolutePosition => true,
+            BuiltinFunction::ItemVisibleInViewport => false,
             BuiltinFunction::RegisterCustomFontByPath
             | BuiltinFunction::RegisterCustomFontByMemory
             | BuiltinFunction::RegisterBitmapFont => false,
@@ -387,6 +494,14 @@ pub fn is_pure(&self) -> bool {
             BuiltinFunction::DateNow => true,
             BuiltinFunction::ValidDate => true,
             BuiltinFunction::ParseDate => true,
+            BuiltinFunction::FuzzyFilter => true,
+            BuiltinFunction::ColorToHex => true,
+            BuiltinFunction::ParseColorHex => true,
+            BuiltinFunction::TableCellsToTsv => true,
+            BuiltinFunction::SetClipboardText => false,
+            BuiltinFunction::OpenUrl => false,
+            BuiltinFunction::ProcessArguments | BuiltinFunction::ProcessEnv => true,
+            BuiltinFunction::ProcessExit => false,
             // Even if it has technically side effect, we still consider it as pure for our purpose
             BuiltinFunction::Debug => true,
             BuiltinFunction::Mod
@@ -423,19 +538,33 @@ pub fn is_pure(&self) -> bool {
             | BuiltinFunction::StringToUppercase => true,
             BuiltinFunction::ColorRgbaStruct
             | BuiltinFunction::ColorHsvaStruct
+            | BuiltinFunction::ColorHslaStruct
+            | BuiltinFunction::ColorOklchStruct
             | BuiltinFunction::ColorBrighter
             | BuiltinFunction::ColorDarker
             | BuiltinFunction::ColorTransparentize
             | BuiltinFunction::ColorMix
+            | BuiltinFunction::ColorMixIn
             | BuiltinFunction::ColorWithAlpha => true,
             BuiltinFunction::ImageSize => true,
             BuiltinFunction::ArrayLength => true,
             BuiltinFunction::Rgb => true,
-            BuiltinFunction::Hsv => true,
+            BuiltinFunction::Hsv | BuiltinFunction::Hsl | BuiltinFunction::Oklch => true,
             BuiltinFunction::ImplicitLayoutInfo(_) => true,
             BuiltinFunction::ItemAbsolutePosition => true,
+            BuiltinFunction::ItemVisibleInViewport => true,
             BuiltinFunction::SetTextInputFocused => false,
             BuiltinFunction::TextInputFocused => true,
+            BuiltinFunction::SystemTimeOfDay
+            | BuiltinFunction::SystemUnixTime
+            | BuiltinFunction::SystemUtcOffset
+            | BuiltinFunction::SystemTimeZone
+            | BuiltinFunction::SystemTickInterval
+            | BuiltinFunction::SystemLocale
+            | BuiltinFunction::SystemNetworkOnline
+            | BuiltinFunction::SystemOnBattery
+            | BuiltinFunction::SystemBatteryLevel => true,
+            BuiltinFunction::SetSystemTickInterval => false,
             BuiltinFunction::RegisterCustomFontByPath
             | BuiltinFunction::RegisterCustomFontByMemory
             | BuiltinFunction::RegisterBitmapFont => false,
@@ -360,6 +360,8 @@ pub struct Function {
         pub template_parameters: Option<String>,
         /// Explicit initializers, such as FooClass::FooClass() : someMember(42) {}
         pub constructor_member_initializers: Vec<String>,
+        /// Adds a `[[deprecated]]` attribute, with the reason if it isn't empty
+        pub deprecated: Option<SmolStr>,
     }
 
     impl Display for Function {
@@ -368,6 +370,11 @@ fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
             if let Some(tpl) = &self.template_parameters {
                 write!(f, "template<{tpl}> ")?;
             }
+            match &self.deprecated {
+                Some(reason) if reason.is_empty() => write!(f, "[[deprecated]] ")?,
+                Some(reason) => write!(f, "[[deprecated(\"{}\")]] ", escape_string(reason))?,
+                None => {}
+            }
             if self.is_static {
                 write!(f, "static ")?;
             }
@@ -1281,6 +1288,8 @@ fn generate_public_component(
         &ctx,
     );
 
+    add_required_properties_to_create(&mut component_struct, &component.public_properties, "self");
+
     component_struct.members.push((
         Access::Public,
         Declaration::Function(Function {
@@ -1628,6 +1637,25 @@ fn generate_item_tree(
         }),
     ));
 
+    target_struct.members.push((
+        Access::Private,
+        Declaration::Function(Function {
+            name: "item_layout_info".into(),
+            signature:
+                "([[maybe_unused]] slint::private_api::ItemTreeRef component, uint32_t index, slint::cbindgen_private::Orientation o, slint::cbindgen_private::LayoutInfo *result) -> bool"
+                    .into(),
+            is_static: true,
+            statements: Some(vec![
+                format!(
+                    "if (auto info = reinterpret_cast<const {}*>(component.instance)->item_layout_info(index, o)) {{ *result = *info; return true; }}",
+                    item_tree_class_name
+                ),
+                "return false;".into(),
+            ]),
+            ..Default::default()
+        }),
+    ));
+
     target_struct.members.push((
         Access::Private,
         Declaration::Function(Function {
@@ -1759,7 +1787,7 @@ fn generate_item_tree(
         init: Some(format!(
             "{{ visit_children, get_item_ref, get_subtree_range, get_subtree, \
                 get_item_tree, parent_node, embed_component, subtree_index, layout_info, \
-                item_geometry, accessible_role, accessible_string_property, accessibility_action, \
+                item_layout_info, item_geometry, accessible_role, accessible_string_property, accessibility_action, \
                 supported_accessibility_actions, element_infos, window_adapter, \
                 slint::private_api::drop_in_place<{item_tree_class_name}>, slint::private_api::dealloc }}"
         )),
@@ -2355,6 +2383,23 @@ fn generate_sub_component(
         item_geometry_cases,
     );
 
+    let mut item_layout_info_cases = vec!["switch (index) {".to_string()];
+    item_layout_info_cases.extend(component.item_layout_infos.iter().map(|(index, (h, v))| {
+        format!(
+            "    case {index}: return o == slint::cbindgen_private::Orientation::Horizontal ? {} : {};",
+            compile_expression(&h.borrow(), &ctx),
+            compile_expression(&v.borrow(), &ctx)
+        )
+    }));
+    item_layout_info_cases.push("}".into());
+
+    dispatch_item_function(
+        "item_layout_info",
+        "(uint32_t index, slint::cbindgen_private::Orientation o) const -> std::optional<slint::cbindgen_private::LayoutInfo>",
+        ", o",
+        item_layout_info_cases,
+    );
+
     let mut accessible_role_cases = vec!["switch (index) {".into()];
     let mut accessible_string_cases = vec!["switch ((index << 8) | uintptr_t(what)) {".into()];
     let mut accessibility_action_cases =
@@ -2744,6 +2789,39 @@ fn generate_functions<'a>(
     })
 }
 
+/// Adds the `required` properties as parameters of the `create()` function of the public component,
+/// so that they must be provided. They are set on `instance` before the `init` callbacks run.
+pub(super) fn add_required_properties_to_create(
+    component_struct: &mut Struct,
+    public_properties: &llr::PublicProperties,
+    instance: &str,
+) {
+    let required = public_properties.iter().filter(|p| p.required).collect::<Vec<_>>();
+    if required.is_empty() {
+        return;
+    }
+    let Some(create) = component_struct.members.iter_mut().find_map(|(_, decl)| match decl {
+        Declaration::Function(f) if f.name == "create" && f.is_static => Some(f),
+        _ => None,
+    }) else {
+        return;
+    };
+    let params = required
+        .iter()
+        .map(|p| format!("{} {}", p.ty.cpp_type().unwrap(), concatenate_ident(&p.name)))
+        .join(", ");
+    create.signature = create.signature.replacen("()", &format!("({params})"), 1);
+    let set_code = required.iter().map(|p| {
+        let name = concatenate_ident(&p.name);
+        format!("{instance}->set_{name}(std::move({name}));")
+    });
+    let statements = create.statements.as_mut().unwrap();
+    // Before the `init` callbacks run in user_init(), or before the return for the live preview
+    let pos =
+        statements.iter().position(|s| s == "self->user_init();").unwrap_or(statements.len() - 1);
+    statements.splice(pos..pos, set_code);
+}
+
 fn generate_public_api_for_properties(
     declarations: &mut Vec<(Access, Declaration)>,
     public_properties: &llr::PublicProperties,
@@ -2842,6 +2920,7 @@ fn generate_public_api_for_properties(
                     name: format_smolstr!("get_{}", &prop_ident),
                     signature: format!("() const -> {}", &cpp_property_type),
                     statements: Some(prop_getter),
+                    deprecated: p.deprecated.clone(),
                     ..Default::default()
                 }),
             ));
@@ -2858,9 +2937,33 @@ fn generate_public_api_for_properties(
                         name: format_smolstr!("set_{}", &prop_ident),
                         signature: format!("(const {} &value) const -> void", &cpp_property_type),
                         statements: Some(prop_setter),
+                        deprecated: p.deprecated.clone(),
                         ..Default::default()
                     }),
                 ));
+                if p.ty.is_animatable() {
+                    let prop_setter: Vec<String> = vec![
+                        "slint::private_api::assert_main_thread();".into(),
+                        "[[maybe_unused]] auto self = this;".into(),
+                        format!(
+                            "{}.set_animated_value(value, slint::private_api::make_property_animation(duration, easing));",
+                            access_member(&p.prop, ctx)
+                        ),
+                    ];
+                    declarations.push((
+                        Access::Public,
+                        Declaration::Function(Function {
+                            name: format_smolstr!("set_{}_animated", &prop_ident),
+                            signature: format!(
+                                "(const {} &value, std::chrono::milliseconds duration, const slint::EasingCurve &easing = {{}}) const -> void",
+                                &cpp_property_type
+                            ),
+                            statements: Some(prop_setter),
+                            deprecated: p.deprecated.clone(),
+                            ..Default::default()
+                        }),
+                    ));
+                }
             } else {
                 declarations.push((
                     Access::Private,
@@ -3714,6 +3817,12 @@ fn compile_builtin_function_call(
         BuiltinFunction::ColorHsvaStruct => {
             format!("{}.to_hsva()", a.next().unwrap())
         }
+        BuiltinFunction::ColorHslaStruct => {
+            format!("{}.to_hsl()", a.next().unwrap())
+        }
+        BuiltinFunction::ColorOklchStruct => {
+            format!("{}.to_oklch()", a.next().unwrap())
+        }
         BuiltinFunction::ColorBrighter => {
             format!("{}.brighter({})", a.next().unwrap(), a.next().unwrap())
         }
@@ -3726,6 +3835,15 @@ fn compile_builtin_function_call(
         BuiltinFunction::ColorMix => {
             format!("{}.mix({}, {})", a.next().unwrap(), a.next().unwrap(), a.next().unwrap())
         }
+        BuiltinFunction::ColorMixIn => {
+            format!(
+                "{}.mix_in({}, {}, {})",
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
         BuiltinFunction::ColorWithAlpha => {
             format!("{}.with_alpha({})", a.next().unwrap(), a.next().unwrap())
         }
@@ -3751,6 +3869,22 @@ fn compile_builtin_function_call(
                 a = a.next().unwrap(),
             )
         }
+        BuiltinFunction::Hsl => {
+            format!("slint::Color::from_hsl(static_cast<float>({h}), std::clamp(static_cast<float>({s}), 0.f, 1.f), std::clamp(static_cast<float>({l}), 0.f, 1.f), std::clamp(static_cast<float>({a}), 0.f, 1.f))",
+                h = a.next().unwrap(),
+                s = a.next().unwrap(),
+                l = a.next().unwrap(),
+                a = a.next().unwrap(),
+            )
+        }
+        BuiltinFunction::Oklch => {
+            format!("slint::Color::from_oklch(std::clamp(static_cast<float>({l}), 0.f, 1.f), std::max(static_cast<float>({c}), 0.f), static_cast<float>({h}), std::clamp(static_cast<float>({a}), 0.f, 1.f))",
+                l = a.next().unwrap(),
+                c = a.next().unwrap(),
+                h = a.next().unwrap(),
+                a = a.next().unwrap(),
+            )
+        }
         BuiltinFunction::ColorScheme => {
             format!("{}.color_scheme()", access_window_field(ctx))
         }
@@ -3828,12 +3962,74 @@ fn compile_builtin_function_call(
                 a.next().unwrap(), a.next().unwrap()
             )
         }
+        BuiltinFunction::FuzzyFilter => {
+            format!("slint::private_api::fuzzy_filter({}, {})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::ColorToHex => {
+            format!("slint::private_api::color_to_hex({})", a.next().unwrap())
+        }
+        BuiltinFunction::ParseColorHex => {
+            format!("slint::private_api::parse_color_hex({}, {})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::TableCellsToTsv => {
+            format!(
+                "slint::private_api::table_cells_to_tsv({}, {}, {}, {}, {})",
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
+        BuiltinFunction::SetClipboardText => {
+            format!("{}.set_clipboard_text({})", access_window_field(ctx), a.next().unwrap())
+        }
+        BuiltinFunction::OpenUrl => {
+            format!("{}.open_url({})", access_window_field(ctx), a.next().unwrap())
+        }
+        BuiltinFunction::ProcessArguments => "slint::private_api::process_arguments()".into(),
+        BuiltinFunction::ProcessEnv => {
+            format!("[](const slint::SharedString &name) {{ slint::SharedString value; slint::cbindgen_private::slint_process_env(&name, &value); return value; }}({})", a.next().unwrap())
+        }
+        BuiltinFunction::ProcessExit => {
+            format!("slint::cbindgen_private::slint_process_exit({})", a.next().unwrap())
+        }
         BuiltinFunction::SetTextInputFocused => {
             format!("{}.set_text_input_focused({})", access_window_field(ctx), a.next().unwrap())
         }
         BuiltinFunction::TextInputFocused => {
             format!("{}.text_input_focused()", access_window_field(ctx))
         }
+        BuiltinFunction::SystemTimeOfDay => {
+            "slint::cbindgen_private::slint_system_time_of_day()".into()
+        }
+        BuiltinFunction::SystemUnixTime => {
+            "slint::cbindgen_private::slint_system_unix_time()".into()
+        }
+        BuiltinFunction::SystemUtcOffset => {
+            "slint::cbindgen_private::slint_system_utc_offset()".into()
+        }
+        BuiltinFunction::SystemTimeZone => {
+            "[] { slint::SharedString tz; slint::cbindgen_private::slint_system_time_zone(&tz); return tz; }()".into()
+        }
+        BuiltinFunction::SystemTickInterval => {
+            "slint::cbindgen_private::slint_system_tick_interval()".into()
+        }
+        BuiltinFunction::SetSystemTickInterval => {
+            format!("slint::cbindgen_private::slint_system_set_tick_interval({})", a.next().unwrap())
+        }
+        BuiltinFunction::SystemLocale => {
+            "[] { slint::SharedString locale; slint::cbindgen_private::slint_system_locale(&locale); return locale; }()".into()
+        }
+        BuiltinFunction::SystemNetworkOnline => {
+            "slint::cbindgen_private::slint_system_network_online()".into()
+        }
+        BuiltinFunction::SystemOnBattery => {
+            "slint::cbindgen_private::slint_system_on_battery()".into()
+        }
+        BuiltinFunction::SystemBatteryLevel => {
+            "slint::cbindgen_private::slint_system_battery_level()".into()
+        }
         BuiltinFunction::ShowPopupWindow => {
             if let [llr::Expression::NumberLiteral(popup_index), close_policy, llr::Expression::PropertyReference(parent_ref)] =
                 arguments
@@ -3991,6 +4187,14 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to ItemAbsolutePosition {arguments:?}")
             }
         }
+        BuiltinFunction::ItemVisibleInViewport => {
+            if let [llr::Expression::PropertyReference(pr)] = arguments {
+                let item_rc = access_item_rc(pr, ctx);
+                format!("slint::cbindgen_private::slint_item_visible_in_viewport(&{item_rc})")
+            } else {
+                panic!("internal error: invalid args to ItemVisibleInViewport {arguments:?}")
+            }
+        }
         BuiltinFunction::RegisterCustomFontByPath => {
             if let [llr::Expression::StringLiteral(path)] = arguments {
                 let window = access_window_field(ctx);
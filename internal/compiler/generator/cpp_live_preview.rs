@@ -1,7 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use super::cpp::{concatenate_ident, cpp_ast::*, ident, Config};
+use super::cpp::{add_required_properties_to_create, concatenate_ident, cpp_ast::*, ident, Config};
 use crate::langtype::{EnumerationValue, Type};
 use crate::llr;
 use crate::object_tree::Document;
@@ -154,6 +154,12 @@ fn generate_public_component(
         }),
     ));
 
+    add_required_properties_to_create(
+        &mut component_struct,
+        &component.public_properties,
+        "self_rc",
+    );
+
     component_struct.members.push((
         Access::Public,
         Declaration::Function(Function {
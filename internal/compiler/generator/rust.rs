@@ -72,6 +72,10 @@ fn to_tokens(&self, tokens: &mut TokenStream) {
                 quote!(sp::TexturePixelFormat::RgbaPremultiplied)
             }
             AlphaMap(_) => quote!(sp::TexturePixelFormat::AlphaMap),
+            RunLengthEncodedRgb => quote!(sp::TexturePixelFormat::RunLengthEncodedRgb),
+            RunLengthEncodedRgbaPremultiplied => {
+                quote!(sp::TexturePixelFormat::RunLengthEncodedRgbaPremultiplied)
+            }
         };
         tokens.extend(tks);
     }
@@ -237,6 +241,7 @@ pub fn generate(
 
     let resource_symbols = generate_resources(doc);
     let named_exports = generate_named_exports(&doc.exports);
+    let c_abi = compiler_config.generate_c_abi.then(|| generate_c_abi(&llr));
     // The inner module was meant to be internal private, but projects have been reaching into it
     // so we can't change the name of this module
     let generated_mod = doc
@@ -262,6 +267,7 @@ mod #generated_mod {
             #shared_globals
             #(#resource_symbols)*
             #translations
+            #c_abi
         }
         #[allow(unused_imports)]
         pub use #generated_mod::{#(#compo_ids,)* #(#structs_and_enums_ids,)* #(#globals_ids,)* #(#named_exports,)* #(#global_exports,)*};
@@ -338,6 +344,8 @@ fn generate_public_component(
         &llr.public_properties,
         &llr.private_properties,
         quote!(sp::VRc::as_pin_ref(&self.0)),
+        quote!(&self.0),
+        quote!(sp::VRc::as_pin_ref(&self_rc)),
         &ctx,
     );
 
@@ -349,16 +357,25 @@ fn generate_public_component(
     #[cfg(not(feature = "bundle-translations"))]
     let init_bundle_translations = quote!();
 
+    let (required_properties_struct, new_args, set_required_properties) = required_properties(
+        &public_component_id,
+        &llr.public_properties,
+        quote!(Self(inner.clone())),
+    );
+
     quote!(
         #component
         pub struct #public_component_id(sp::VRc<sp::ItemTreeVTable, #inner_component_id>);
 
+        #required_properties_struct
+
         impl #public_component_id {
-            pub fn new() -> ::core::result::Result<Self, slint::PlatformError> {
+            pub fn new(#new_args) -> ::core::result::Result<Self, slint::PlatformError> {
                 let inner = #inner_component_id::new()?;
                 #init_bundle_translations
                 // ensure that the window exist as this point so further call to window() don't panic
                 inner.globals.get().unwrap().window_adapter_ref()?;
+                #set_required_properties
                 #inner_component_id::user_init(sp::VRc::map(inner.clone(), |x| x));
                 ::core::result::Result::Ok(Self(inner))
             }
@@ -412,6 +429,285 @@ fn global<'a, T: slint::Global<'a, Self>>(&'a self) -> T {
     )
 }
 
+/// Returns the struct holding the `required` properties of the public component, the argument of
+/// `new()` taking that struct, and the code setting the properties on `instance` from it.
+/// All are empty if there are no required properties.
+pub(super) fn required_properties(
+    public_component_id: &Ident,
+    public_properties: &llr::PublicProperties,
+    instance: TokenStream,
+) -> (TokenStream, TokenStream, TokenStream) {
+    let required = public_properties.iter().filter(|p| p.required).collect::<Vec<_>>();
+    if required.is_empty() {
+        return Default::default();
+    }
+    let struct_id = format_ident!("{}RequiredProperties", public_component_id);
+    let fields = required.iter().map(|p| ident(&p.name)).collect::<Vec<_>>();
+    let types = required.iter().map(|p| rust_primitive_type(&p.ty).unwrap());
+    let setters = fields.iter().map(|f| format_ident!("set_{}", f));
+    (
+        quote!(
+            pub struct #struct_id {
+                #(pub #fields: #types,)*
+            }
+        ),
+        quote!(required: #struct_id),
+        quote!({
+            let instance = #instance;
+            #(#[allow(deprecated)] instance.#setters(required.#fields);)*
+        }),
+    )
+}
+
+/// Must match `slint::private_unstable_api::c_abi::ABI_VERSION`
+const C_ABI_VERSION: u32 = 2;
+
+/// The name of the type in the metadata of the C ABI, or None if values of that type
+/// can't cross the C ABI.
+fn c_abi_type_name(ty: &Type) -> Option<&'static str> {
+    match ty {
+        Type::Void => Some("void"),
+        Type::Int32
+        | Type::Float32
+        | Type::Duration
+        | Type::PhysicalLength
+        | Type::LogicalLength
+        | Type::Rem
+        | Type::Angle
+        | Type::Percent
+        | Type::UnitProduct(_) => Some("number"),
+        Type::Bool => Some("bool"),
+        Type::String => Some("string"),
+        Type::Color | Type::Brush => Some("color"),
+        _ => None,
+    }
+}
+
+/// Generate the `extern "C"` entry points and the JSON metadata described in `slint_component_abi.h`.
+///
+/// Components with required properties can't be created through the C ABI and are skipped,
+/// as well as the properties, callbacks, and functions whose types can't cross the C ABI.
+fn generate_c_abi(llr: &llr::CompilationUnit) -> TokenStream {
+    let mut component_entries = Vec::new();
+    let mut component_impls = Vec::new();
+    let mut metadata_components = Vec::new();
+
+    for component in
+        llr.public_components.iter().filter(|c| !c.public_properties.iter().any(|p| p.required))
+    {
+        let component_id = ident(&component.name);
+        let component_name = component.name.as_str();
+        let mut getters = Vec::new();
+        let mut setters = Vec::new();
+        let mut invokers = Vec::new();
+        let mut handlers = Vec::new();
+        let mut metadata_properties = Vec::new();
+        let mut metadata_callbacks = Vec::new();
+        let mut metadata_functions = Vec::new();
+
+        for p in &component.public_properties {
+            let name = p.name.as_str();
+            let prop_ident = ident(&p.name);
+            match &p.ty {
+                Type::Callback(function) | Type::Function(function) => {
+                    let Some(arg_types) =
+                        function.args.iter().map(c_abi_type_name).collect::<Option<Vec<_>>>()
+                    else {
+                        continue;
+                    };
+                    let Some(return_type) = c_abi_type_name(&function.return_type) else {
+                        continue;
+                    };
+                    if arg_types.contains(&"void") {
+                        continue;
+                    }
+                    let metadata = format!(
+                        r#"{{"name":"{name}","args":[{}],"return":"{return_type}"}}"#,
+                        arg_types.iter().map(|t| format!(r#""{t}""#)).collect::<Vec<_>>().join(",")
+                    );
+                    let caller_ident = format_ident!("invoke_{}", prop_ident);
+                    let args_index = (0..arg_types.len()).collect::<Vec<_>>();
+                    invokers.push(quote!(
+                        #name => Some(c_abi::AbiValue::to_abi(self.#caller_ident(#(c_abi::arg(args, #args_index)?,)*)))
+                    ));
+                    if matches!(p.ty, Type::Callback(_)) {
+                        let on_ident = format_ident!("on_{}", prop_ident);
+                        let args_name = (0..arg_types.len())
+                            .map(|i| format_ident!("arg_{}", i))
+                            .collect::<Vec<_>>();
+                        handlers.push(quote!(
+                            #name => {
+                                self.#on_ident(move |#(#args_name),*| handler.call(sp::vec![#(c_abi::AbiValue::to_abi(#args_name)),*]));
+                                true
+                            }
+                        ));
+                        metadata_callbacks.push(metadata);
+                    } else {
+                        metadata_functions.push(metadata);
+                    }
+                }
+                ty => {
+                    let Some(type_name) = c_abi_type_name(ty).filter(|t| *t != "void") else {
+                        continue;
+                    };
+                    let getter_ident = format_ident!("get_{}", prop_ident);
+                    getters
+                        .push(quote!(#name => Some(c_abi::AbiValue::to_abi(self.#getter_ident()))));
+                    if !p.read_only {
+                        let setter_ident = format_ident!("set_{}", prop_ident);
+                        setters.push(quote!(
+                            #name => match c_abi::AbiValue::from_abi(value) {
+                                Some(value) => { self.#setter_ident(value); true }
+                                None => false,
+                            }
+                        ));
+                    }
+                    metadata_properties.push(format!(
+                        r#"{{"name":"{name}","type":"{type_name}","read_only":{}}}"#,
+                        p.read_only
+                    ));
+                }
+            }
+        }
+
+        component_entries.push(quote!(
+            c_abi::ComponentEntry {
+                name: #component_name,
+                create: || Ok(sp::Box::new(#component_id::new()?)),
+            }
+        ));
+        component_impls.push(quote!(
+            #[allow(deprecated, unused_variables)]
+            impl c_abi::Component for #component_id {
+                fn window(&self) -> &slint::Window {
+                    slint::ComponentHandle::window(self)
+                }
+                fn show(&self) -> Result<(), slint::PlatformError> {
+                    slint::ComponentHandle::show(self)
+                }
+                fn hide(&self) -> Result<(), slint::PlatformError> {
+                    slint::ComponentHandle::hide(self)
+                }
+                fn get_property(&self, name: &str) -> Option<c_abi::Value> {
+                    match name {
+                        #(#getters,)*
+                        _ => None,
+                    }
+                }
+                fn set_property(&self, name: &str, value: &c_abi::Value) -> bool {
+                    match name {
+                        #(#setters,)*
+                        _ => false,
+                    }
+                }
+                fn invoke(&self, name: &str, args: &[c_abi::Value]) -> Option<c_abi::Value> {
+                    match name {
+                        #(#invokers,)*
+                        _ => None,
+                    }
+                }
+                fn set_callback(&self, name: &str, handler: sp::Rc<c_abi::CallbackHandler>) -> bool {
+                    match name {
+                        #(#handlers,)*
+                        _ => false,
+                    }
+                }
+            }
+        ));
+        metadata_components.push(format!(
+            r#"{{"name":"{component_name}","properties":[{}],"callbacks":[{}],"functions":[{}]}}"#,
+            metadata_properties.join(","),
+            metadata_callbacks.join(","),
+            metadata_functions.join(","),
+        ));
+    }
+
+    let metadata = format!(
+        "{{\"abi_version\":{},\"components\":[{}]}}\0",
+        C_ABI_VERSION,
+        metadata_components.join(",")
+    );
+    let abi_version = C_ABI_VERSION;
+
+    // In a module of its own, so that it builds in crates that deny unsafe code
+    quote!(
+        #[allow(unsafe_code)]
+        mod slint_c_abi {
+            use super::*;
+            use slint::private_unstable_api::c_abi;
+            #(#component_impls)*
+            static SLINT_C_ABI_COMPONENTS: &[c_abi::ComponentEntry] = &[#(#component_entries),*];
+            static SLINT_C_ABI_METADATA: &str = #metadata;
+            const _ : () = assert!(c_abi::ABI_VERSION == #abi_version, "The C ABI of the compiler and the runtime must match");
+
+            #[unsafe(no_mangle)]
+            pub extern "C" fn slint_component_abi_version() -> u32 {
+                c_abi::ABI_VERSION
+            }
+            #[unsafe(no_mangle)]
+            pub extern "C" fn slint_component_metadata() -> *const ::core::ffi::c_char {
+                SLINT_C_ABI_METADATA.as_ptr() as *const ::core::ffi::c_char
+            }
+            #[unsafe(no_mangle)]
+            pub unsafe extern "C" fn slint_component_create(name: *const ::core::ffi::c_char) -> *mut ::core::ffi::c_void {
+                unsafe { c_abi::create(SLINT_C_ABI_COMPONENTS, name) }
+            }
+            #[unsafe(no_mangle)]
+            pub unsafe extern "C" fn slint_component_destroy(handle: *mut ::core::ffi::c_void) {
+                unsafe { c_abi::destroy(handle) }
+            }
+            #[unsafe(no_mangle)]
+            pub unsafe extern "C" fn slint_component_show(handle: *mut ::core::ffi::c_void, visible: bool) -> bool {
+                unsafe { c_abi::show(handle, visible) }
+            }
+            #[unsafe(no_mangle)]
+            pub extern "C" fn slint_component_run_event_loop() -> bool {
+                c_abi::run_event_loop()
+            }
+            #[unsafe(no_mangle)]
+            pub extern "C" fn slint_component_set_embedded() -> bool {
+                c_abi::set_embedded()
+            }
+            #[unsafe(no_mangle)]
+            pub extern "C" fn slint_component_update_timers_and_animations() {
+                c_abi::update_timers_and_animations()
+            }
+            #[unsafe(no_mangle)]
+            pub unsafe extern "C" fn slint_component_render(handle: *mut ::core::ffi::c_void, width: u32, height: u32, scale_factor: f32, buffer: *mut u8) -> bool {
+                unsafe { c_abi::render(handle, width, height, scale_factor, buffer) }
+            }
+            #[unsafe(no_mangle)]
+            pub unsafe extern "C" fn slint_component_dispatch_pointer_event(handle: *mut ::core::ffi::c_void, kind: u32, x: f32, y: f32, button: u32, delta_x: f32, delta_y: f32) -> bool {
+                unsafe { c_abi::dispatch_pointer_event(handle, kind, x, y, button, delta_x, delta_y) }
+            }
+            #[unsafe(no_mangle)]
+            pub unsafe extern "C" fn slint_component_dispatch_key_event(handle: *mut ::core::ffi::c_void, pressed: bool, text: *const ::core::ffi::c_char) -> bool {
+                unsafe { c_abi::dispatch_key_event(handle, pressed, text) }
+            }
+            #[unsafe(no_mangle)]
+            pub unsafe extern "C" fn slint_component_get_property(handle: *mut ::core::ffi::c_void, name: *const ::core::ffi::c_char, out: *mut c_abi::Value) -> bool {
+                unsafe { c_abi::get_property(handle, name, out) }
+            }
+            #[unsafe(no_mangle)]
+            pub unsafe extern "C" fn slint_component_set_property(handle: *mut ::core::ffi::c_void, name: *const ::core::ffi::c_char, value: *const c_abi::Value) -> bool {
+                unsafe { c_abi::set_property(handle, name, value) }
+            }
+            #[unsafe(no_mangle)]
+            pub unsafe extern "C" fn slint_component_invoke(handle: *mut ::core::ffi::c_void, name: *const ::core::ffi::c_char, args: *const c_abi::Value, arg_count: usize, out: *mut c_abi::Value) -> bool {
+                unsafe { c_abi::invoke(handle, name, args, arg_count, out) }
+            }
+            #[unsafe(no_mangle)]
+            pub unsafe extern "C" fn slint_component_set_callback(handle: *mut ::core::ffi::c_void, name: *const ::core::ffi::c_char, handler: c_abi::HandlerFn, user_data: *mut ::core::ffi::c_void, drop_user_data: Option<unsafe extern "C" fn(*mut ::core::ffi::c_void)>) -> bool {
+                unsafe { c_abi::set_callback(handle, name, handler, user_data, drop_user_data) }
+            }
+            #[unsafe(no_mangle)]
+            pub unsafe extern "C" fn slint_component_value_free(value: *mut c_abi::Value) {
+                unsafe { c_abi::value_free(value) }
+            }
+        }
+    )
+}
+
 fn generate_shared_globals(
     doc: &Document,
     llr: &llr::CompilationUnit,
@@ -548,12 +844,45 @@ fn generate_struct(
         quote! {}
     };
 
+    // Structs with only primitive fields can be loaded from CSV or JSON with slint::models
+    let from_record_impl = fields
+        .values()
+        .all(|ty| {
+            matches!(
+                ty,
+                Type::Int32
+                    | Type::Float32
+                    | Type::String
+                    | Type::Color
+                    | Type::Duration
+                    | Type::Angle
+                    | Type::PhysicalLength
+                    | Type::LogicalLength
+                    | Type::Rem
+                    | Type::Percent
+                    | Type::Bool
+            )
+        })
+        .then(|| {
+            let field_names = fields.keys().map(|name| name.as_str());
+            quote! {
+                impl sp::FromRecord for #component_id {
+                    fn from_record(record: &dyn sp::Record) -> sp::Result<Self, sp::FieldError> {
+                        sp::Ok(Self {
+                            #(#declared_property_vars : sp::read_field(record, #field_names)?),*
+                        })
+                    }
+                }
+            }
+        });
+
     quote! {
         #attributes
         #[derive(Default, PartialEq, Debug, Clone)]
         pub struct #component_id {
             #(pub #declared_property_vars : #declared_property_types),*
         }
+        #from_record_impl
     }
 }
 
@@ -679,12 +1008,22 @@ fn public_api(
     public_properties: &llr::PublicProperties,
     private_properties: &llr::PrivateProperties,
     self_init: TokenStream,
+    self_rc: TokenStream,
+    self_from_rc: TokenStream,
     ctx: &EvaluationContext,
 ) -> TokenStream {
     let mut property_and_callback_accessors: Vec<TokenStream> = vec![];
     for p in public_properties {
         let prop_ident = ident(&p.name);
         let prop = access_member(&p.prop, ctx).unwrap();
+        let deprecated = p.deprecated.as_ref().map(|reason| {
+            if reason.is_empty() {
+                quote!(#[deprecated])
+            } else {
+                let reason = reason.as_str();
+                quote!(#[deprecated(note = #reason)])
+            }
+        });
 
         if let Type::Callback(callback) = &p.ty {
             let callback_args =
@@ -732,10 +1071,12 @@ pub fn #caller_ident(&self, #(#args_name : #callback_args,)*) -> #return_type {
 
             let getter_ident = format_ident!("get_{}", prop_ident);
 
-            let prop_expression = primitive_property_value(&p.ty, MemberAccess::Direct(prop));
+            let prop_expression =
+                primitive_property_value(&p.ty, MemberAccess::Direct(prop.clone()));
 
             property_and_callback_accessors.push(quote!(
                 #[allow(dead_code)]
+                #deprecated
                 pub fn #getter_ident(&self) -> #rust_property_type {
                     #[allow(unused_imports)]
                     let _self = #self_init;
@@ -743,17 +1084,56 @@ pub fn #getter_ident(&self) -> #rust_property_type {
                 }
             ));
 
+            // A callback named `<property>-changed` already has an `on_<property>_changed` function
+            let changed_name = format!("{}_changed", p.name.replace('-', "_"));
+            let has_changed_callback = public_properties.iter().any(|other| {
+                matches!(other.ty, Type::Callback(_))
+                    && other.name.replace('-', "_") == changed_name
+            });
+            if !has_changed_callback {
+                let on_changed_ident = format_ident!("on_{}_changed", prop_ident);
+                property_and_callback_accessors.push(quote!(
+                    #[allow(dead_code)]
+                    #deprecated
+                    pub fn #on_changed_ident(&self, f: impl FnMut(#rust_property_type) + 'static) -> sp::PropertySubscription {
+                        slint::private_unstable_api::subscribe_to_property(
+                            #self_rc,
+                            |self_rc| {
+                                #[allow(unused_imports)]
+                                let _self = #self_from_rc;
+                                #prop_expression
+                            },
+                            f,
+                        )
+                    }
+                ));
+            }
+
             let setter_ident = format_ident!("set_{}", prop_ident);
             if !p.read_only {
                 let set_value = property_set_value_tokens(&p.prop, quote!(value), ctx);
                 property_and_callback_accessors.push(quote!(
                     #[allow(dead_code)]
+                    #deprecated
                     pub fn #setter_ident(&self, value: #rust_property_type) {
                         #[allow(unused_imports)]
                         let _self = #self_init;
                         #set_value
                     }
                 ));
+                if p.ty.is_animatable() {
+                    let animated_setter_ident = format_ident!("set_{}_animated", prop_ident);
+                    let value_tokens = set_primitive_property_value(&p.ty, quote!(value));
+                    property_and_callback_accessors.push(quote!(
+                        #[allow(dead_code)]
+                        #deprecated
+                        pub fn #animated_setter_ident(&self, value: #rust_property_type, duration: ::core::time::Duration, easing: sp::EasingCurve) {
+                            #[allow(unused_imports)]
+                            let _self = #self_init;
+                            #prop.set_animated(#value_tokens as _, duration, easing)
+                        }
+                    ));
+                }
             } else {
                 property_and_callback_accessors.push(quote!(
                     #[allow(dead_code)] fn #setter_ident(&self, _read_only_property : ()) { }
@@ -1033,6 +1413,19 @@ fn generate_sub_component(
         })
         .collect::<Vec<_>>();
 
+    let mut item_layout_info_branch = component
+        .item_layout_infos
+        .iter()
+        .map(|(index, (h, v))| {
+            let h = compile_expression_no_parenthesis(&h.borrow(), &ctx);
+            let v = compile_expression_no_parenthesis(&v.borrow(), &ctx);
+            quote!(#index => return sp::Some(match orientation {
+                sp::Orientation::Horizontal => #h,
+                sp::Orientation::Vertical => #v,
+            }),)
+        })
+        .collect::<Vec<_>>();
+
     let mut item_element_infos_branch = component
         .element_infos
         .iter()
@@ -1109,6 +1502,9 @@ fn generate_sub_component(
         supported_accessibility_actions_branch.push(quote!(
             #local_tree_index => #sub_compo_field.apply_pin(_self).supported_accessibility_actions(0),
         ));
+        item_layout_info_branch.push(quote!(
+            #local_tree_index => return #sub_compo_field.apply_pin(_self).item_layout_info(0, orientation),
+        ));
         if sub_items_count > 1 {
             let range_begin = local_index_of_first_child;
             let range_end = range_begin + sub_items_count - 2 + sc.repeater_count(root);
@@ -1130,6 +1526,9 @@ fn generate_sub_component(
             item_element_infos_branch.push(quote!(
                 #range_begin..=#range_end => #sub_compo_field.apply_pin(_self).item_element_infos(index - #range_begin + 1),
             ));
+            item_layout_info_branch.push(quote!(
+                #range_begin..=#range_end => return #sub_compo_field.apply_pin(_self).item_layout_info(index - #range_begin + 1, orientation),
+            ));
         }
 
         sub_component_names.push(field_name);
@@ -1386,6 +1785,15 @@ fn supported_accessibility_actions(self: ::core::pin::Pin<&Self>, index: u32) ->
                 }
             }
 
+            fn item_layout_info(self: ::core::pin::Pin<&Self>, index: u32, orientation: sp::Orientation) -> sp::Option<sp::LayoutInfo> {
+                #![allow(unused)]
+                let _self = self;
+                match index {
+                    #(#item_layout_info_branch)*
+                    _ => sp::None,
+                }
+            }
+
             fn item_element_infos(self: ::core::pin::Pin<&Self>, index: u32) -> sp::Option<sp::SharedString> {
                 #![allow(unused)]
                 let _self = self;
@@ -1559,6 +1967,8 @@ fn generate_global(
             &global.public_properties,
             &global.private_properties,
             quote!(self.0.as_ref()),
+            quote!(self.0),
+            quote!(self_rc.as_ref()),
             &ctx,
         );
         let aliases = global.aliases.iter().map(|name| ident(name));
@@ -1844,6 +2254,20 @@ fn layout_info(self: ::core::pin::Pin<&Self>, orientation: sp::Orientation) -> s
                 self.layout_info(orientation)
             }
 
+            fn item_layout_info(
+                self: ::core::pin::Pin<&Self>,
+                index: u32,
+                orientation: sp::Orientation,
+                result: &mut sp::LayoutInfo,
+            ) -> bool {
+                if let Some(info) = self.item_layout_info(index, orientation) {
+                    *result = info;
+                    true
+                } else {
+                    false
+                }
+            }
+
             fn item_geometry(self: ::core::pin::Pin<&Self>, index: u32) -> sp::LogicalRect {
                 self.item_geometry(index)
             }
@@ -3238,6 +3662,8 @@ fn compile_builtin_function_call(
         BuiltinFunction::StringToUppercase => quote!(sp::SharedString::from(#(#a)*.to_uppercase())),
         BuiltinFunction::ColorRgbaStruct => quote!( #(#a)*.to_argb_u8()),
         BuiltinFunction::ColorHsvaStruct => quote!( #(#a)*.to_hsva()),
+        BuiltinFunction::ColorHslaStruct => quote!( #(#a)*.to_hsl()),
+        BuiltinFunction::ColorOklchStruct => quote!( #(#a)*.to_oklch()),
         BuiltinFunction::ColorBrighter => {
             let x = a.next().unwrap();
             let factor = a.next().unwrap();
@@ -3259,6 +3685,13 @@ fn compile_builtin_function_call(
             let factor = a.next().unwrap();
             quote!(#x.mix(&#y.into(), #factor as f32))
         }
+        BuiltinFunction::ColorMixIn => {
+            let x = a.next().unwrap();
+            let y = a.next().unwrap();
+            let factor = a.next().unwrap();
+            let color_space = a.next().unwrap();
+            quote!(#x.mix_in(&#y.into(), #factor as f32, #color_space))
+        }
         BuiltinFunction::ColorWithAlpha => {
             let x = a.next().unwrap();
             let alpha = a.next().unwrap();
@@ -3293,6 +3726,26 @@ fn compile_builtin_function_call(
                 sp::Color::from_hsva(#h as f32, s, v, a)
             })
         }
+        BuiltinFunction::Hsl => {
+            let (h, s, l, a) =
+                (a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!({
+                let s: f32 = (#s as f32).max(0.).min(1.) as f32;
+                let l: f32 = (#l as f32).max(0.).min(1.) as f32;
+                let a: f32 = (1. * (#a as f32)).max(0.).min(1.) as f32;
+                sp::Color::from_hsl(#h as f32, s, l, a)
+            })
+        }
+        BuiltinFunction::Oklch => {
+            let (l, c, h, a) =
+                (a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!({
+                let l: f32 = (#l as f32).max(0.).min(1.) as f32;
+                let c: f32 = (#c as f32).max(0.) as f32;
+                let a: f32 = (1. * (#a as f32)).max(0.).min(1.) as f32;
+                sp::Color::from_oklch(l, c, #h as f32, a)
+            })
+        }
         BuiltinFunction::ColorScheme => {
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).color_scheme())
@@ -3393,6 +3846,43 @@ fn compile_builtin_function_call(
         BuiltinFunction::DateNow => {
             quote!(sp::ModelRc::new(sp::VecModel::from_slice(&sp::date_now())))
         }
+        BuiltinFunction::FuzzyFilter => {
+            let (m, p) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::fuzzy_filter_model(&#m, #p.as_str()))
+        }
+        BuiltinFunction::ColorToHex => {
+            let c = a.next().unwrap();
+            quote!(#c.to_hex_string())
+        }
+        BuiltinFunction::ParseColorHex => {
+            let (s, fallback) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::Color::from_hex_str(#s.as_str()).unwrap_or(#fallback))
+        }
+        BuiltinFunction::TableCellsToTsv => {
+            let rows = a.next().unwrap();
+            let (r1, c1, r2, c2) =
+                (a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(sp::table_cells_to_tsv(&#rows, #r1 as i32, #c1 as i32, #r2 as i32, #c2 as i32))
+        }
+        BuiltinFunction::SetClipboardText => {
+            let window_adapter_tokens = access_window_adapter_field(ctx);
+            let text = a.next().unwrap();
+            quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).set_clipboard_text(#text.as_str()))
+        }
+        BuiltinFunction::OpenUrl => {
+            let window_adapter_tokens = access_window_adapter_field(ctx);
+            let url = a.next().unwrap();
+            quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).open_url(#url.as_str()))
+        }
+        BuiltinFunction::ProcessArguments => quote!(sp::process_arguments()),
+        BuiltinFunction::ProcessEnv => {
+            let name = a.next().unwrap();
+            quote!(sp::process_env(#name.as_str()))
+        }
+        BuiltinFunction::ProcessExit => {
+            let code = a.next().unwrap();
+            quote!(sp::process_exit(#code as i32))
+        }
         BuiltinFunction::TextInputFocused => {
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).text_input_focused())
@@ -3401,6 +3891,18 @@ fn compile_builtin_function_call(
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).set_text_input_focused(#(#a)*))
         }
+        BuiltinFunction::SystemTimeOfDay => quote!(sp::system_time_of_day()),
+        BuiltinFunction::SystemUnixTime => quote!(sp::system_unix_time()),
+        BuiltinFunction::SystemUtcOffset => quote!(sp::system_utc_offset()),
+        BuiltinFunction::SystemTimeZone => quote!(sp::system_time_zone()),
+        BuiltinFunction::SystemTickInterval => quote!(sp::system_tick_interval()),
+        BuiltinFunction::SetSystemTickInterval => {
+            quote!(sp::set_system_tick_interval(#(#a)* as i64))
+        }
+        BuiltinFunction::SystemLocale => quote!(sp::system_locale()),
+        BuiltinFunction::SystemNetworkOnline => quote!(sp::system_network_online()),
+        BuiltinFunction::SystemOnBattery => quote!(sp::system_on_battery()),
+        BuiltinFunction::SystemBatteryLevel => quote!(sp::system_battery_level()),
         BuiltinFunction::Translate => {
             quote!(slint::private_unstable_api::translate(#((#a) as _),*))
         }
@@ -3417,6 +3919,14 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to MapPointToWindow {arguments:?}")
             }
         }
+        BuiltinFunction::ItemVisibleInViewport => {
+            if let [Expression::PropertyReference(pr)] = arguments {
+                let item_rc = access_item_rc(pr, ctx);
+                quote!((*#item_rc).is_visible_in_viewport())
+            } else {
+                panic!("internal error: invalid args to ItemVisibleInViewport {arguments:?}")
+            }
+        }
         BuiltinFunction::UpdateTimers => {
             quote!(_self.update_timers())
         }
@@ -1,7 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use super::rust::{ident, rust_primitive_type};
+use super::rust::{ident, required_properties, rust_primitive_type};
 use crate::langtype::{Struct, Type};
 use crate::llr;
 use crate::object_tree::Document;
@@ -173,18 +173,25 @@ pub fn #setter_ident(&self, value: #rust_property_type) {
     });
     let style = compiler_config.style.iter();
 
+    let (required_properties_struct, new_args, set_required_properties) =
+        required_properties(&public_component_id, &llr.public_properties, quote!(&instance));
+
     quote!(
         pub struct #public_component_id(sp::Rc<::core::cell::RefCell<sp::live_preview::LiveReloadingComponent>>, sp::Rc<dyn sp::WindowAdapter>);
 
+        #required_properties_struct
+
         impl #public_component_id {
-            pub fn new() -> sp::Result<Self, slint::PlatformError> {
+            pub fn new(#new_args) -> sp::Result<Self, slint::PlatformError> {
                 let mut compiler = sp::live_preview::Compiler::default();
                 compiler.set_include_paths([#(#include_paths.into()),*].into_iter().collect());
                 compiler.set_library_paths([#(#library_paths.into()),*].into_iter().collect());
                 #(compiler.set_style(#style.to_string());)*
                 let instance = sp::live_preview::LiveReloadingComponent::new(compiler, #main_file.into(), #component_name.into())?;
                 let window_adapter = sp::WindowInner::from_pub(slint::ComponentHandle::window(instance.borrow().instance())).window_adapter();
-                sp::Ok(Self(instance, window_adapter))
+                let instance = Self(instance, window_adapter);
+                #set_required_properties
+                sp::Ok(instance)
             }
 
             #(#property_and_callback_accessors)*
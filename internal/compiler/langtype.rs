@@ -220,6 +220,21 @@ pub fn ok_for_public_api(&self) -> bool {
         !matches!(self, Self::Easing)
     }
 
+    /// Returns true if a property of this type can be animated.
+    /// This must match the types registered as supported by the `PropertyAnimation` in the `TypeRegister`
+    pub fn is_animatable(&self) -> bool {
+        matches!(
+            self,
+            Self::Float32
+                | Self::Int32
+                | Self::Color
+                | Self::PhysicalLength
+                | Self::LogicalLength
+                | Self::Brush
+                | Self::Angle
+        )
+    }
+
     /// Assume it is an enumeration, panic if it isn't
     pub fn as_enum(&self) -> &Rc<Enumeration> {
         match self {
@@ -518,6 +518,11 @@ pub fn implicit_layout_info_call(elem: &ElementRc, orientation: Orientation) ->
                         | "FocusScope"
                         | "Opacity"
                         | "Layer"
+                        | "ShaderEffect"
+                        | "BackdropBlur"
+                        | "Mask"
+                        | "Blend"
+                        | "ZOrder"
                         | "BoxShadow"
                         | "Clip"
                 ) =>
@@ -34,6 +34,7 @@
 pub mod object_tree;
 pub mod parser;
 pub mod pathutils;
+pub mod project_config;
 #[cfg(feature = "bundle-translations")]
 pub mod translations;
 pub mod typeloader;
@@ -91,6 +92,56 @@ pub enum ComponentSelection {
     >,
 >;
 
+/// Restrictions applied when compiling untrusted .slint code, for example a UI theme provided by the user.
+///
+/// See [`CompilerConfiguration::sandbox()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SandboxConfiguration {
+    /// The directories from which .slint files, fonts and images may be loaded. Builtin files, such as
+    /// `std-widgets.slint`, can always be imported.
+    pub allowed_paths: Vec<std::path::PathBuf>,
+    /// The maximum number of rows of a `for` model. Array literals can't be longer, and the number of
+    /// repetitions of a `for` over a number is clamped to it.
+    pub max_model_size: usize,
+    /// The maximum depth of the element tree, counting the elements of the instantiated components.
+    pub max_element_depth: usize,
+    /// The maximum number of elements a component may instantiate, counting the elements of the
+    /// instantiated components and each row of the `for` models.
+    pub max_element_instances: usize,
+}
+
+impl Default for SandboxConfiguration {
+    fn default() -> Self {
+        Self {
+            allowed_paths: Vec::new(),
+            max_model_size: 10_000,
+            max_element_depth: 256,
+            max_element_instances: 100_000,
+        }
+    }
+}
+
+impl SandboxConfiguration {
+    /// Returns true if resources may be loaded from `path`.
+    ///
+    /// Symbolic links are resolved, for `path` and for the allowed paths, so that a link in an
+    /// allowed directory can't point outside of it. Paths that can't be resolved are refused, unless
+    /// they aren't on the file system and are allowed explicitly, like the files provided by
+    /// [`CompilerConfiguration::open_import_fallback`].
+    pub fn allows_path(&self, path: &Path) -> bool {
+        if path.starts_with("builtin:/") {
+            return true;
+        }
+        let Ok(canonical_path) = std::fs::canonicalize(path) else {
+            return std::fs::symlink_metadata(path).is_err()
+                && self.allowed_paths.iter().any(|allowed| allowed == path);
+        };
+        self.allowed_paths.iter().any(|allowed| {
+            std::fs::canonicalize(allowed).is_ok_and(|allowed| canonical_path.starts_with(allowed))
+        })
+    }
+}
+
 pub type OpenImportFallback =
     Rc<dyn Fn(String) -> Pin<Box<dyn Future<Output = Option<std::io::Result<String>>>>>>;
 pub type ResourceUrlMapper = Rc<dyn Fn(&str) -> Pin<Box<dyn Future<Output = Option<String>>>>>;
@@ -104,6 +155,10 @@ pub struct CompilerConfiguration {
     /// Whether to use SDF when pre-rendering fonts.
     #[cfg(all(feature = "software-renderer", feature = "sdf-fonts"))]
     pub use_sdf_fonts: bool,
+    /// Whether to compress the embedded textures with a run-length encoding, when that makes them smaller.
+    /// The software renderer decodes them line by line while rendering.
+    #[cfg(feature = "software-renderer")]
+    pub compress_embedded_textures: bool,
     /// The compiler will look in these paths for components used in the file to compile.
     pub include_paths: Vec<std::path::PathBuf>,
     /// The compiler will look in these paths for library imports.
@@ -153,6 +208,9 @@ pub struct CompilerConfiguration {
     /// (otherwise this is a compatibility warning)
     pub error_on_binding_loop_with_window_layout: bool,
 
+    /// When true, report the warnings as errors
+    pub deny_warnings: bool,
+
     /// Generate debug information for elements (ids, type names)
     pub debug_info: bool,
 
@@ -169,6 +227,19 @@ pub struct CompilerConfiguration {
 
     /// Specify the Rust module to place the generated code in.
     pub rust_module: Option<String>,
+
+    /// Generate `extern "C"` entry points to create and drive the exported components,
+    /// so that the generated code can be built as a dynamic library loaded by a host at runtime.
+    pub generate_c_abi: bool,
+
+    /// When set, the code is untrusted and is compiled with these restrictions.
+    pub sandbox: Option<SandboxConfiguration>,
+
+    /// Additional palettes for the std-widgets, as pairs of the brand name and the path to a
+    /// `.slint` file that exports a `Palette` global with the colors of that brand.
+    ///
+    /// The brands are values of the `Brand` enum, selected at run-time with `Palette.brand`.
+    pub brand_palettes: Vec<(String, std::path::PathBuf)>,
 }
 
 impl CompilerConfiguration {
@@ -244,6 +315,7 @@ pub fn new(output_format: OutputFormat) -> Self {
             no_native_menu: false,
             cpp_namespace,
             error_on_binding_loop_with_window_layout: false,
+            deny_warnings: false,
             debug_info,
             debug_hooks: None,
             components_to_generate: ComponentSelection::ExportedWindows,
@@ -251,12 +323,43 @@ pub fn new(output_format: OutputFormat) -> Self {
             font_cache: Default::default(),
             #[cfg(all(feature = "software-renderer", feature = "sdf-fonts"))]
             use_sdf_fonts: false,
+            #[cfg(feature = "software-renderer")]
+            compress_embedded_textures: false,
             #[cfg(feature = "bundle-translations")]
             translation_path_bundle: std::env::var("SLINT_BUNDLE_TRANSLATIONS")
                 .ok()
                 .map(|x| x.into()),
             library_name: None,
             rust_module: None,
+            generate_c_abi: false,
+            sandbox: None,
+            brand_palettes: Vec::new(),
+        }
+    }
+
+    /// Returns a configuration for the interpreter to compile untrusted .slint code.
+    ///
+    /// Imports and resources are only loaded from the [allowed paths](SandboxConfiguration::allowed_paths),
+    /// the environment variables that affect the compiler are ignored, and the size of the models and of
+    /// the element tree are limited.
+    pub fn sandbox(sandbox: SandboxConfiguration) -> Self {
+        Self {
+            embed_resources: EmbedResourcesKind::Nothing,
+            include_paths: Vec::new(),
+            library_paths: HashMap::new(),
+            style: None,
+            open_import_fallback: None,
+            resource_url_mapper: None,
+            inline_all_elements: true,
+            const_scale_factor: 1.,
+            enable_experimental: false,
+            debug_info: false,
+            debug_hooks: None,
+            cpp_namespace: None,
+            #[cfg(feature = "bundle-translations")]
+            translation_path_bundle: None,
+            sandbox: Some(sandbox),
+            ..Self::new(OutputFormat::Interpreter)
         }
     }
 
@@ -301,6 +404,11 @@ fn prepare_for_compile(
     } else {
         crate::typeregister::TypeRegister::builtin()
     };
+    passes::brand_palettes::register_brand_enum(
+        &compiler_config.brand_palettes,
+        &mut global_type_registry.borrow_mut(),
+        diagnostics,
+    );
 
     typeloader::TypeLoader::new(global_type_registry, compiler_config, diagnostics)
 }
@@ -333,6 +441,9 @@ pub async fn compile_syntax_node(
         // Don't run all the passes in case of errors because because some invariants are not met.
         passes::run_import_passes(&doc, &loader, &mut diagnostics);
     }
+    if loader.compiler_config.deny_warnings {
+        diagnostics.deny_warnings();
+    }
     (doc, diagnostics, loader)
 }
 
@@ -352,6 +463,9 @@ pub async fn load_root_file(
 
     let (path, _) =
         loader.load_root_file(path, source_path, source_code, false, &mut diagnostics).await;
+    if loader.compiler_config.deny_warnings {
+        diagnostics.deny_warnings();
+    }
 
     (path, diagnostics, loader)
 }
@@ -378,6 +492,9 @@ pub async fn load_root_file_with_raw_type_loader(
 
     let (path, raw_type_loader) =
         loader.load_root_file(path, source_path, source_code, true, &mut diagnostics).await;
+    if loader.compiler_config.deny_warnings {
+        diagnostics.deny_warnings();
+    }
 
     (path, diagnostics, loader, raw_type_loader)
 }
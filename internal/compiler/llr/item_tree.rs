@@ -282,6 +282,9 @@ pub struct SubComponent {
     pub layout_info_h: MutExpression,
     pub layout_info_v: MutExpression,
 
+    /// Maps the item index of the layouts to the horizontal and vertical layout info they compute
+    pub item_layout_infos: BTreeMap<u32, (MutExpression, MutExpression)>,
+
     /// Maps (item_index, property) to an expression
     pub accessible_prop: BTreeMap<(u32, String), MutExpression>,
 
@@ -453,6 +456,10 @@ pub fn for_each_expression<'a>(
             }
             visitor(&sc.layout_info_h, ctx);
             visitor(&sc.layout_info_v, ctx);
+            for (h, v) in sc.item_layout_infos.values() {
+                visitor(h, ctx);
+                visitor(v, ctx);
+            }
             for e in sc.accessible_prop.values() {
                 visitor(e, ctx);
             }
@@ -481,6 +488,10 @@ pub struct PublicProperty {
     pub ty: Type,
     pub prop: PropertyReference,
     pub read_only: bool,
+    /// The property is declared as `required` and must be passed when creating the component
+    pub required: bool,
+    /// The property is annotated with `@deprecated`, with the given reason (which may be empty)
+    pub deprecated: Option<SmolStr>,
 }
 pub type PublicProperties = Vec<PublicProperty>;
 pub type PrivateProperties = Vec<(SmolStr, Type)>;
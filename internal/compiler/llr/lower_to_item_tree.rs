@@ -4,6 +4,7 @@
 use by_address::ByAddress;
 
 use super::lower_expression::{ExpressionLoweringCtx, ExpressionLoweringCtxInner};
+use crate::diagnostics::Spanned;
 use crate::expression_tree::Expression as tree_Expression;
 use crate::langtype::{ElementType, Struct, Type};
 use crate::llr::item_tree::*;
@@ -263,6 +264,7 @@ fn lower_sub_component(
         // just initialize to dummy expression right now and it will be set later
         layout_info_h: super::Expression::BoolLiteral(false).into(),
         layout_info_v: super::Expression::BoolLiteral(false).into(),
+        item_layout_infos: Default::default(),
         accessible_prop: Default::default(),
         element_infos: Default::default(),
         prop_analysis: Default::default(),
@@ -581,6 +583,17 @@ fn lower_sub_component(
         if elem.repeated.is_some() {
             return;
         };
+        // The layout info of the root of a sub-component is provided by the sub-component
+        let is_layout =
+            elem.sub_component().is_none() && elem.debug.iter().any(|d| d.layout.is_some());
+        if let Some((h, v)) = elem.layout_info_prop.as_ref().filter(|_| is_layout) {
+            let info = |nr: &NamedReference| {
+                super::Expression::PropertyReference(ctx.map_property_reference(nr))
+            };
+            sub_component
+                .item_layout_infos
+                .insert(*elem.item_index.get().unwrap(), (info(h).into(), info(v).into()));
+        }
         let Some(geom) = &elem.geometry_props else { return };
         let item_index = *elem.item_index.get().unwrap() as usize;
         if item_index >= sub_component.geometries.len() {
@@ -944,12 +957,17 @@ fn public_properties(
     mapping: &LoweredSubComponentMapping,
     state: &LoweringState,
 ) -> PublicProperties {
-    component
-        .root_element
-        .borrow()
+    let root_element = component.root_element.borrow();
+    let mut declarations = root_element
         .property_declarations
         .iter()
         .filter(|(_, c)| c.expose_in_public_api)
+        .collect::<Vec<_>>();
+    // The required properties come last, in the order of their declaration, as they are the
+    // parameters of the constructor in C++
+    declarations.sort_by_key(|(_, c)| c.required.then(|| c.node.span().offset));
+    declarations
+        .into_iter()
         .map(|(p, c)| {
             let property_reference = mapping.map_property_reference(
                 &NamedReference::new(&component.root_element, p.clone()),
@@ -960,6 +978,8 @@ fn public_properties(
                 ty: c.property_type.clone(),
                 prop: property_reference,
                 read_only: c.visibility == PropertyVisibility::Output,
+                required: c.required,
+                deprecated: c.deprecated.clone(),
             }
         })
         .collect()
@@ -78,6 +78,10 @@ pub fn count_property_use(root: &CompilationUnit) {
         // 5. the layout info
         sc.layout_info_h.borrow().visit_property_references(ctx, &mut visit_property);
         sc.layout_info_v.borrow().visit_property_references(ctx, &mut visit_property);
+        for (h, v) in sc.item_layout_infos.values() {
+            h.borrow().visit_property_references(ctx, &mut visit_property);
+            v.borrow().visit_property_references(ctx, &mut visit_property);
+        }
 
         // 6. accessibility props and geometries
         for b in sc.accessible_prop.values() {
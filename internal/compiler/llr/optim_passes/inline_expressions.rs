@@ -123,17 +123,23 @@ fn builtin_function_cost(function: &BuiltinFunction) -> isize {
         BuiltinFunction::StringToUppercase => ALLOC_COST,
         BuiltinFunction::ColorRgbaStruct => 50,
         BuiltinFunction::ColorHsvaStruct => 50,
+        BuiltinFunction::ColorHslaStruct => 50,
+        BuiltinFunction::ColorOklchStruct => 50,
         BuiltinFunction::ColorBrighter => 50,
         BuiltinFunction::ColorDarker => 50,
         BuiltinFunction::ColorTransparentize => 50,
         BuiltinFunction::ColorMix => 50,
+        BuiltinFunction::ColorMixIn => 50,
         BuiltinFunction::ColorWithAlpha => 50,
         BuiltinFunction::ImageSize => 50,
         BuiltinFunction::ArrayLength => 50,
         BuiltinFunction::Rgb => 50,
         BuiltinFunction::Hsv => 50,
+        BuiltinFunction::Hsl => 50,
+        BuiltinFunction::Oklch => 50,
         BuiltinFunction::ImplicitLayoutInfo(_) => isize::MAX,
         BuiltinFunction::ItemAbsolutePosition => isize::MAX,
+        BuiltinFunction::ItemVisibleInViewport => isize::MAX,
         BuiltinFunction::RegisterCustomFontByPath => isize::MAX,
         BuiltinFunction::RegisterCustomFontByMemory => isize::MAX,
         BuiltinFunction::RegisterBitmapFont => isize::MAX,
@@ -146,8 +152,27 @@ fn builtin_function_cost(function: &BuiltinFunction) -> isize {
         BuiltinFunction::DateNow => isize::MAX,
         BuiltinFunction::ValidDate => isize::MAX,
         BuiltinFunction::ParseDate => isize::MAX,
+        BuiltinFunction::FuzzyFilter => isize::MAX,
+        BuiltinFunction::ColorToHex => isize::MAX,
+        BuiltinFunction::ParseColorHex => isize::MAX,
+        BuiltinFunction::TableCellsToTsv => isize::MAX,
+        BuiltinFunction::SetClipboardText => isize::MAX,
+        BuiltinFunction::OpenUrl => isize::MAX,
+        BuiltinFunction::ProcessArguments => 2 * ALLOC_COST,
+        BuiltinFunction::ProcessEnv => ALLOC_COST,
+        BuiltinFunction::ProcessExit => isize::MAX,
         BuiltinFunction::SetTextInputFocused => PROPERTY_ACCESS_COST,
         BuiltinFunction::TextInputFocused => PROPERTY_ACCESS_COST,
+        BuiltinFunction::SystemTimeOfDay => PROPERTY_ACCESS_COST,
+        BuiltinFunction::SystemUnixTime => PROPERTY_ACCESS_COST,
+        BuiltinFunction::SystemUtcOffset => PROPERTY_ACCESS_COST,
+        BuiltinFunction::SystemTimeZone => ALLOC_COST + PROPERTY_ACCESS_COST,
+        BuiltinFunction::SystemTickInterval => PROPERTY_ACCESS_COST,
+        BuiltinFunction::SetSystemTickInterval => PROPERTY_ACCESS_COST,
+        BuiltinFunction::SystemLocale => ALLOC_COST + PROPERTY_ACCESS_COST,
+        BuiltinFunction::SystemNetworkOnline => PROPERTY_ACCESS_COST,
+        BuiltinFunction::SystemOnBattery => PROPERTY_ACCESS_COST,
+        BuiltinFunction::SystemBatteryLevel => PROPERTY_ACCESS_COST,
         BuiltinFunction::Translate => 2 * ALLOC_COST + PROPERTY_ACCESS_COST,
         BuiltinFunction::Use24HourFormat => 2 * ALLOC_COST + PROPERTY_ACCESS_COST,
         BuiltinFunction::UpdateTimers => 10,
@@ -14,7 +14,7 @@
 use crate::object_tree::{ElementRc, PropertyVisibility};
 use crate::parser::NodeOrToken;
 use crate::typeregister::TypeRegister;
-use smol_str::{SmolStr, ToSmolStr};
+use smol_str::{format_smolstr, SmolStr, ToSmolStr};
 use std::cell::RefCell;
 
 mod named_colors;
@@ -121,6 +121,7 @@ pub enum BuiltinNamespace {
     Easing,
     Math,
     Key,
+    Process,
     SlintInternal,
 }
 
@@ -202,6 +203,9 @@ fn for_each_entry<R>(
             }
             LookupResult::Namespace(BuiltinNamespace::Math) => MathFunctions.for_each_entry(ctx, f),
             LookupResult::Namespace(BuiltinNamespace::Key) => KeysLookup.for_each_entry(ctx, f),
+            LookupResult::Namespace(BuiltinNamespace::Process) => {
+                ProcessFunctions.for_each_entry(ctx, f)
+            }
             LookupResult::Namespace(BuiltinNamespace::SlintInternal) => {
                 SlintInternal.for_each_entry(ctx, f)
             }
@@ -219,6 +223,9 @@ fn lookup(&self, ctx: &LookupCtx, name: &SmolStr) -> Option<LookupResult> {
             LookupResult::Namespace(BuiltinNamespace::Easing) => EasingSpecific.lookup(ctx, name),
             LookupResult::Namespace(BuiltinNamespace::Math) => MathFunctions.lookup(ctx, name),
             LookupResult::Namespace(BuiltinNamespace::Key) => KeysLookup.lookup(ctx, name),
+            LookupResult::Namespace(BuiltinNamespace::Process) => {
+                ProcessFunctions.lookup(ctx, name)
+            }
             LookupResult::Namespace(BuiltinNamespace::SlintInternal) => {
                 SlintInternal.lookup(ctx, name)
             }
@@ -494,6 +501,44 @@ fn lookup(&self, ctx: &LookupCtx, name: &SmolStr) -> Option<LookupResult> {
     }
 }
 
+/// The edges that can be referred to in the `anchor-*` bindings, such as `other.right`.
+/// They are in the coordinate system of the parent of the anchored element: the edges of the
+/// parent itself start at zero.
+pub fn anchor_edge(elem: &ElementRc, ctx: &LookupCtx, name: &str) -> Option<Expression> {
+    if !ctx.property_name.is_some_and(|p| p.starts_with("anchor-")) {
+        return None;
+    }
+    let (pos, size, factor) = match name {
+        "left" => ("x", "width", 0.),
+        "right" => ("x", "width", 1.),
+        "horizontal-center" => ("x", "width", 0.5),
+        "top" => ("y", "height", 0.),
+        "bottom" => ("y", "height", 1.),
+        "vertical-center" => ("y", "height", 0.5),
+        _ => return None,
+    };
+    let is_parent = ctx.component_scope.iter().nth_back(1).is_some_and(|p| Rc::ptr_eq(p, elem));
+    let origin = if is_parent {
+        Expression::NumberLiteral(0., Unit::Px)
+    } else {
+        Expression::PropertyReference(NamedReference::new(elem, SmolStr::new_static(pos)))
+    };
+    if factor == 0. {
+        return Some(origin);
+    }
+    let size = Expression::PropertyReference(NamedReference::new(elem, SmolStr::new_static(size)));
+    let offset = if factor == 1. {
+        size
+    } else {
+        Expression::BinaryExpression {
+            lhs: Box::new(size),
+            rhs: Box::new(Expression::NumberLiteral(factor, Unit::None)),
+            op: '*',
+        }
+    };
+    Some(Expression::BinaryExpression { lhs: Box::new(origin), rhs: Box::new(offset), op: '+' })
+}
+
 pub fn check_extra_deprecated(
     elem: &ElementRc,
     ctx: &LookupCtx<'_>,
@@ -597,6 +642,7 @@ fn for_each_entry<R>(
             Type::Brush => ColorSpecific.for_each_entry(ctx, f),
             Type::Easing => EasingSpecific.for_each_entry(ctx, f),
             Type::Enumeration(enumeration) => enumeration.clone().for_each_entry(ctx, f),
+            Type::LogicalLength => SizeSpecific.for_each_entry(ctx, f),
             _ => None,
         }
     }
@@ -607,11 +653,35 @@ fn lookup(&self, ctx: &LookupCtx, name: &SmolStr) -> Option<LookupResult> {
             Type::Brush => ColorSpecific.lookup(ctx, name),
             Type::Easing => EasingSpecific.lookup(ctx, name),
             Type::Enumeration(enumeration) => enumeration.clone().lookup(ctx, name),
+            Type::LogicalLength => SizeSpecific.lookup(ctx, name),
             _ => None,
         }
     }
 }
 
+/// The `min-content` and `max-content` keywords for the `width` and `height` of an element,
+/// which refer to the minimum and preferred size computed from the element's content.
+struct SizeSpecific;
+impl LookupObject for SizeSpecific {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&SmolStr, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        let orientation = match ctx.property_name {
+            Some("width") => "width",
+            Some("height") => "height",
+            _ => return None,
+        };
+        let elem = ctx.component_scope.last()?;
+        let mut f = |n, prop| {
+            let nr = NamedReference::new(elem, format_smolstr!("{prop}-{orientation}"));
+            f(&SmolStr::new_static(n), Expression::PropertyReference(nr).into())
+        };
+        None.or_else(|| f("min-content", "min")).or_else(|| f("max-content", "preferred"))
+    }
+}
+
 struct ColorSpecific;
 impl LookupObject for ColorSpecific {
     fn for_each_entry<R>(
@@ -762,6 +832,31 @@ fn for_each_entry<R>(
     }
 }
 
+struct ProcessFunctions;
+impl LookupObject for ProcessFunctions {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&SmolStr, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        let mut f = |n, e: LookupResult| f(&SmolStr::new_static(n), e);
+        let b = |b| LookupResult::from(Callable::Builtin(b));
+        None.or_else(|| {
+            f(
+                "arguments",
+                Expression::FunctionCall {
+                    function: BuiltinFunction::ProcessArguments.into(),
+                    arguments: vec![],
+                    source_location: ctx.current_token.as_ref().map(|t| t.to_source_location()),
+                }
+                .into(),
+            )
+        })
+        .or_else(|| f("env", b(BuiltinFunction::ProcessEnv)))
+        .or_else(|| f("exit", b(BuiltinFunction::ProcessExit)))
+    }
+}
+
 struct SlintInternal;
 impl LookupObject for SlintInternal {
     fn for_each_entry<R>(
@@ -809,6 +904,11 @@ fn for_each_entry<R>(
         .or_else(|| f("date-now", b(BuiltinFunction::DateNow)))
         .or_else(|| f("valid-date", b(BuiltinFunction::ValidDate)))
         .or_else(|| f("parse-date", b(BuiltinFunction::ParseDate)))
+        .or_else(|| f("fuzzy-filter", b(BuiltinFunction::FuzzyFilter)))
+        .or_else(|| f("color-to-hex", b(BuiltinFunction::ColorToHex)))
+        .or_else(|| f("parse-color-hex", b(BuiltinFunction::ParseColorHex)))
+        .or_else(|| f("table-cells-to-tsv", b(BuiltinFunction::TableCellsToTsv)))
+        .or_else(|| f("set-clipboard-text", b(BuiltinFunction::SetClipboardText)))
     }
 }
 
@@ -823,6 +923,8 @@ fn for_each_entry<R>(
         None.or_else(|| f("rgb", BuiltinMacroFunction::Rgb))
             .or_else(|| f("rgba", BuiltinMacroFunction::Rgb))
             .or_else(|| f("hsv", BuiltinMacroFunction::Hsv))
+            .or_else(|| f("hsl", BuiltinMacroFunction::Hsl))
+            .or_else(|| f("oklch", BuiltinMacroFunction::Oklch))
     }
 }
 
@@ -854,6 +956,7 @@ fn for_each_entry<R>(
             .or_else(|| f("Easing", LookupResult::Namespace(BuiltinNamespace::Easing)))
             .or_else(|| f("Math", LookupResult::Namespace(BuiltinNamespace::Math)))
             .or_else(|| f("Key", LookupResult::Namespace(BuiltinNamespace::Key)))
+            .or_else(|| f("Process", LookupResult::Namespace(BuiltinNamespace::Process)))
             .or_else(|| {
                 if ctx.type_register.expose_internal_types {
                     f("SlintInternal", LookupResult::Namespace(BuiltinNamespace::SlintInternal))
@@ -993,7 +1096,13 @@ fn for_each_entry<R>(
         f: &mut impl FnMut(&SmolStr, LookupResult) -> Option<R>,
     ) -> Option<R> {
         let member_function = |f: BuiltinFunction| {
-            let base = if f == BuiltinFunction::ColorHsvaStruct && self.0.ty() == Type::Brush {
+            let base = if matches!(
+                f,
+                BuiltinFunction::ColorHsvaStruct
+                    | BuiltinFunction::ColorHslaStruct
+                    | BuiltinFunction::ColorOklchStruct
+            ) && self.0.ty() == Type::Brush
+            {
                 Expression::Cast { from: Box::new(self.0.clone()), to: Type::Color }
             } else {
                 self.0.clone()
@@ -1026,11 +1135,14 @@ fn for_each_entry<R>(
             .or_else(|| f("blue", field_access("blue")))
             .or_else(|| f("alpha", field_access("alpha")))
             .or_else(|| f("to-hsv", member_function(BuiltinFunction::ColorHsvaStruct)))
+            .or_else(|| f("to-hsl", member_function(BuiltinFunction::ColorHslaStruct)))
+            .or_else(|| f("to-oklch", member_function(BuiltinFunction::ColorOklchStruct)))
             .or_else(|| f("brighter", member_function(BuiltinFunction::ColorBrighter)))
             .or_else(|| f("darker", member_function(BuiltinFunction::ColorDarker)))
             .or_else(|| f("transparentize", member_function(BuiltinFunction::ColorTransparentize)))
             .or_else(|| f("with-alpha", member_function(BuiltinFunction::ColorWithAlpha)))
             .or_else(|| f("mix", member_function(BuiltinFunction::ColorMix)))
+            .or_else(|| f("mix-in", member_function(BuiltinFunction::ColorMixIn)))
     }
 }
 
@@ -48,6 +48,9 @@ pub struct Document {
     pub inner_components: Vec<Rc<Component>>,
     pub inner_types: Vec<Type>,
     pub local_registry: TypeRegister,
+    /// The type registers of the components of this document that select a style with
+    /// `style: "..."`, indexed by style. See [`Self::type_register_for`]
+    pub scoped_registries: HashMap<SmolStr, TypeRegister>,
     /// A list of paths to .ttf/.ttc files that are supposed to be registered on
     /// startup for custom font use.
     pub custom_fonts: Vec<(SmolStr, crate::parser::SyntaxToken)>,
@@ -258,11 +261,21 @@ pub fn from_node(
             }
         }
 
+        let scoped_registries = inner_components
+            .iter()
+            .filter_map(|c| c.style.clone())
+            .filter_map(|style| {
+                let registry = local_registry.with_scoped_style(&style)?;
+                Some((style, registry))
+            })
+            .collect();
+
         Document {
             node: Some(node),
             inner_components,
             inner_types,
             local_registry,
+            scoped_registries,
             custom_fonts,
             imports,
             exports,
@@ -275,6 +288,16 @@ pub fn from_node(
         }
     }
 
+    /// The type register to look up the types used in the given component of this document, which takes
+    /// the style selected in the component into account.
+    pub fn type_register_for(&self, component: &Component) -> &TypeRegister {
+        component
+            .style
+            .as_ref()
+            .and_then(|style| self.scoped_registries.get(style))
+            .unwrap_or(&self.local_registry)
+    }
+
     pub fn exported_roots(&self) -> impl DoubleEndedIterator<Item = Rc<Component>> + '_ {
         self.exports.iter().filter_map(|e| e.1.as_ref().left()).filter(|c| !c.is_global()).cloned()
     }
@@ -424,6 +447,12 @@ pub struct Component {
 
     /// True if this component is imported from an external library.
     pub from_library: Cell<bool>,
+
+    /// Set if the component is annotated with `@deprecated`. Contains the reason, which may be empty.
+    pub deprecated: Option<SmolStr>,
+
+    /// The style of the widgets of this component, if selected with `style: "..."` in the root element.
+    pub style: Option<SmolStr>,
 }
 
 impl Component {
@@ -434,23 +463,34 @@ pub fn from_node(
     ) -> Rc<Self> {
         let mut child_insertion_point = None;
         let is_legacy_syntax = node.child_token(SyntaxKind::ColonEqual).is_some();
+        // `@deprecated export component ...` puts the annotation in the ExportsList
+        let deprecated = node
+            .AtDeprecated()
+            .or_else(|| node.parent().and_then(syntax_nodes::ExportsList::new)?.AtDeprecated())
+            .map(|n| deprecation_reason(&n));
+        let root_element = Element::from_node(
+            node.Element(),
+            "root".into(),
+            if node.child_text(SyntaxKind::Identifier).is_some_and(|t| t == "global") {
+                ElementType::Global
+            } else {
+                ElementType::Error
+            },
+            &mut child_insertion_point,
+            is_legacy_syntax,
+            diag,
+            tr,
+        );
+        let style = style_declaration(&node.Element())
+            .filter(|_| !root_element.borrow().base_type.lookup_property("style").is_valid())
+            .map(|(style, _)| style);
         let c = Component {
             node: Some(node.clone()),
+            deprecated,
             id: parser::identifier_text(&node.DeclaredIdentifier()).unwrap_or_default(),
-            root_element: Element::from_node(
-                node.Element(),
-                "root".into(),
-                if node.child_text(SyntaxKind::Identifier).is_some_and(|t| t == "global") {
-                    ElementType::Global
-                } else {
-                    ElementType::Error
-                },
-                &mut child_insertion_point,
-                is_legacy_syntax,
-                diag,
-                tr,
-            ),
+            root_element,
             child_insertion_point: RefCell::new(child_insertion_point),
+            style,
             ..Default::default()
         };
         let c = Rc::new(c);
@@ -544,6 +584,10 @@ pub struct PropertyDeclaration {
     pub visibility: PropertyVisibility,
     /// For function or callback: whether it is declared as `pure` (None for private function for which this has to be deduced)
     pub pure: Option<bool>,
+    /// The property is declared as `required`: it must be set when the component is instantiated
+    pub required: bool,
+    /// Set if the property is annotated with `@deprecated`. Contains the reason, which may be empty.
+    pub deprecated: Option<SmolStr>,
 }
 
 impl PropertyDeclaration {
@@ -714,14 +758,24 @@ pub struct ElementDebugInfo {
 }
 
 impl ElementDebugInfo {
-    // Returns a comma separate string that encodes the element type name (`Rectangle`, `MyButton`, etc.)
-    // and the qualified id (`SurroundingComponent::my-id`).
+    // Returns a comma separate string that encodes the element type name (`Rectangle`, `MyButton`, etc.),
+    // the qualified id (`SurroundingComponent::my-id`), and the source location (`file.slint:line:column`).
+    // The file name is stripped of its directory because `/` and `;` are separators of the encoding.
     fn encoded_element_info(&self) -> String {
         let mut info = self.type_name.clone();
         info.push(',');
         if let Some(id) = self.qualified_id.as_ref() {
             info.push_str(id);
         }
+        if let Some(source_file) = self.node.source_file() {
+            let (line, column) = source_file.line_column(self.node.span().offset);
+            let file_name = source_file.path().file_name().unwrap_or_default().to_string_lossy();
+            info.push(',');
+            info.extend(
+                file_name.chars().map(|c| if matches!(c, ',' | ';' | '/') { '_' } else { c }),
+            );
+            info.push_str(&format!(":{line}:{column}"));
+        }
         info
     }
 }
@@ -1112,11 +1166,13 @@ pub fn from_node(
             }
 
             let mut visibility = None;
+            let mut required = None;
             for token in prop_decl.children_with_tokens() {
                 if token.kind() != SyntaxKind::Identifier {
                     continue;
                 }
                 match (token.as_token().unwrap().text(), visibility) {
+                    ("required", _) => required = Some(token.clone()),
                     ("in", None) => visibility = Some(PropertyVisibility::Input),
                     ("in", Some(_)) => diag.push_error("Extra 'in' keyword".into(), &token),
                     ("out", None) => visibility = Some(PropertyVisibility::Output),
@@ -1140,12 +1196,41 @@ pub fn from_node(
                 }
             });
 
+            let required = match required {
+                Some(token)
+                    if !matches!(
+                        visibility,
+                        PropertyVisibility::Input | PropertyVisibility::InOut
+                    ) =>
+                {
+                    diag.push_error(
+                        "Only 'in' or 'in-out' properties can be required".into(),
+                        &token,
+                    );
+                    false
+                }
+                Some(token)
+                    if prop_decl.BindingExpression().is_some()
+                        || prop_decl.TwoWayBinding().is_some() =>
+                {
+                    diag.push_error(
+                        "A required property cannot have a default value".into(),
+                        &token,
+                    );
+                    false
+                }
+                Some(_) => true,
+                None => false,
+            };
+
             r.property_declarations.insert(
                 prop_name.clone().into(),
                 PropertyDeclaration {
                     property_type: prop_type,
                     node: Some(prop_decl.clone().into()),
                     visibility,
+                    required,
+                    deprecated: prop_decl.AtDeprecated().map(|n| deprecation_reason(&n)),
                     ..Default::default()
                 },
             );
@@ -1176,10 +1261,35 @@ pub fn from_node(
             }
         }
 
+        // The widgets of a component with `style: "..."` are looked up in the std-widgets of that style
+        let style_declaration =
+            style_declaration(&node).filter(|_| !r.base_type.lookup_property("style").is_valid());
+        let scoped_registry;
+        let tr = match &style_declaration {
+            Some((style, binding)) => match tr.with_scoped_style(style) {
+                Some(registry) => {
+                    scoped_registry = registry;
+                    &scoped_registry
+                }
+                None => {
+                    diag.push_error(
+                        format!("Style {style} is not known"),
+                        &binding.BindingExpression(),
+                    );
+                    tr
+                }
+            },
+            None => tr,
+        };
+
         r.parse_bindings(
-            node.Binding().filter_map(|b| {
-                Some((b.child_token(SyntaxKind::Identifier)?, b.BindingExpression().into()))
-            }),
+            node.Binding()
+                .filter(|b| {
+                    style_declaration.as_ref().is_none_or(|(_, d)| d.text_range() != b.text_range())
+                })
+                .filter_map(|b| {
+                    Some((b.child_token(SyntaxKind::Identifier)?, b.BindingExpression().into()))
+                }),
             is_legacy_syntax,
             diag,
         );
@@ -2012,6 +2122,37 @@ pub fn type_from_node(
     }
 }
 
+/// Return the reason given in a `@deprecated("reason")` annotation, or an empty string
+fn deprecation_reason(node: &syntax_nodes::AtDeprecated) -> SmolStr {
+    node.child_text(SyntaxKind::StringLiteral)
+        .and_then(|s| crate::literals::unescape_string(&s))
+        .unwrap_or_default()
+}
+
+/// Return the style selected with `style: "material"` in the root element of a component, and the
+/// binding of the declaration.
+///
+/// The root element may not declare a `style` property itself. It is up to the caller to check that
+/// the base type doesn't have a `style` property either.
+pub fn style_declaration(node: &syntax_nodes::Element) -> Option<(SmolStr, syntax_nodes::Binding)> {
+    let component = node.parent().and_then(syntax_nodes::Component::new)?;
+    if component.child_text(SyntaxKind::Identifier).is_some_and(|t| t == "global")
+        || node
+            .PropertyDeclaration()
+            .any(|p| parser::identifier_text(&p.DeclaredIdentifier()).is_some_and(|n| n == "style"))
+    {
+        return None;
+    }
+    node.Binding().find_map(|b| {
+        if parser::identifier_text(&b)? != "style" {
+            return None;
+        }
+        let expr = b.BindingExpression().Expression()?;
+        let style = crate::literals::unescape_string(&expr.child_text(SyntaxKind::StringLiteral)?)?;
+        Some((style, b))
+    })
+}
+
 /// Create a [`Type::Struct`] from a [`syntax_nodes::ObjectType`]
 pub fn type_struct_from_node(
     object_node: syntax_nodes::ObjectType,
@@ -2644,6 +2785,15 @@ pub fn from_node(
                 }
             };
 
+        for exports in doc.ExportsList().filter(|exports| exports.ExportModule().is_none()) {
+            if let Some(star) = exports.child_token(SyntaxKind::Star) {
+                diag.push_error(
+                    "'*' can only be used when re-exporting from another file".into(),
+                    &star,
+                );
+            }
+        }
+
         let mut sorted_exports_with_duplicates: Vec<(ExportedName, _)> = Vec::new();
 
         let mut extend_exports =
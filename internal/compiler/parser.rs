@@ -332,7 +332,7 @@ fn source_file(&self) -> Option<&SourceFile> {
     {
         Document -> [ *Component, *ExportsList, *ImportSpecifier, *StructDeclaration, *EnumDeclaration ],
         /// `DeclaredIdentifier := Element { ... }`
-        Component -> [ DeclaredIdentifier, Element ],
+        Component -> [ DeclaredIdentifier, Element, ?AtDeprecated ],
         /// `id := Element { ... }`
         SubElement -> [ Element ],
         Element -> [ ?QualifiedName, *PropertyDeclaration, *Binding, *CallbackConnection,
@@ -351,7 +351,7 @@ fn source_file(&self) -> Option<&SourceFile> {
         ReturnType -> [Type],
         CallbackConnection -> [ *DeclaredIdentifier, ?CodeBlock, ?Expression ],
         /// Declaration of a property.
-        PropertyDeclaration-> [ ?Type , DeclaredIdentifier, ?BindingExpression, ?TwoWayBinding ],
+        PropertyDeclaration-> [ ?Type , DeclaredIdentifier, ?BindingExpression, ?TwoWayBinding, ?AtDeprecated ],
         /// QualifiedName are the properties name
         PropertyAnimation-> [ *QualifiedName, *Binding ],
         /// `changed xxx => {...}`  where `xxx` is the DeclaredIdentifier
@@ -374,7 +374,7 @@ fn source_file(&self) -> Option<&SourceFile> {
         Expression-> [ ?Expression, ?FunctionCallExpression, ?IndexExpression, ?SelfAssignment,
                        ?ConditionalExpression, ?QualifiedName, ?BinaryExpression, ?Array, ?ObjectLiteral,
                        ?UnaryOpExpression, ?CodeBlock, ?StringTemplate, ?AtImageUrl, ?AtGradient, ?AtTr,
-                       ?MemberAccess ],
+                       ?AtOpenUrl, ?MemberAccess ],
         /// Concatenate the Expressions to make a string (usually expanded from a template string)
         StringTemplate -> [*Expression],
         /// `@image-url("foo.png")`
@@ -387,6 +387,8 @@ fn source_file(&self) -> Option<&SourceFile> {
         TrContext -> [],
         /// `| "foo" % n`  in a `AtTr` node
         TrPlural -> [Expression],
+        /// `@open-url("https://slint.dev")`
+        AtOpenUrl -> [Expression],
         /// expression()
         FunctionCallExpression -> [*Expression],
         /// `expression[index]`
@@ -418,7 +420,7 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// There is an identifier "in", "out", "in-out", the DeclaredIdentifier is the state name
         Transition -> [?DeclaredIdentifier, *PropertyAnimation],
         /// Export a set of declared components by name
-        ExportsList -> [ *ExportSpecifier, ?Component, *StructDeclaration, ?ExportModule, *EnumDeclaration ],
+        ExportsList -> [ *ExportSpecifier, ?Component, *StructDeclaration, ?ExportModule, *EnumDeclaration, ?AtDeprecated ],
         /// Declare the first identifier to be exported, either under its name or instead
         /// under the name of the second identifier.
         ExportSpecifier -> [ ExportIdentifier, ?ExportName ],
@@ -449,6 +451,8 @@ fn source_file(&self) -> Option<&SourceFile> {
         EnumValue -> [],
         /// `@rust-attr(...)`
         AtRustAttr -> [],
+        /// `@deprecated("reason")`. The reason is an optional StringLiteral
+        AtDeprecated -> [],
     }
 }
 
@@ -15,6 +15,8 @@
 /// struct Foo { foo: foo }
 /// enum Foo { hello }
 /// @rust-attr(...) struct X {}
+/// @deprecated("use Y") component X {}
+/// @deprecated export global G {}
 /// /* empty */
 /// ```
 pub fn parse_document(p: &mut impl Parser) -> bool {
@@ -52,6 +54,24 @@ pub fn parse_document(p: &mut impl Parser) -> bool {
                     break;
                 }
             }
+            "@" if p.nth(1).as_str() == "deprecated" => {
+                let checkpoint = p.checkpoint();
+                parse_deprecated(&mut *p);
+                let is_export = p.nth(0).as_str() == "export";
+                let i = if is_export { 1 } else { 0 };
+                if !matches!(p.nth(i).as_str(), "component" | "global") {
+                    p.error("Expected component or global after @deprecated");
+                    continue;
+                }
+                let r = if is_export {
+                    parse_export(&mut *p, Some(checkpoint))
+                } else {
+                    parse_component(&mut *p, Some(checkpoint))
+                };
+                if !r {
+                    break;
+                }
+            }
             "@" if p.nth(1).as_str() == "rust-attr" => {
                 let checkpoint = p.checkpoint();
                 if !parse_rustattr(&mut *p) {
@@ -77,7 +97,7 @@ pub fn parse_document(p: &mut impl Parser) -> bool {
                 }
             }
             _ => {
-                if !parse_component(&mut *p) {
+                if !parse_component(&mut *p, None) {
                     break;
                 }
             }
@@ -100,7 +120,7 @@ pub fn parse_document(p: &mut impl Parser) -> bool {
 /// component C { property<int> xx; }
 /// component C inherits D { }
 /// ```
-pub fn parse_component(p: &mut impl Parser) -> bool {
+pub fn parse_component<P: Parser>(p: &mut P, checkpoint: Option<P::Checkpoint>) -> bool {
     let simple_component = p.nth(1).kind() == SyntaxKind::ColonEqual;
     let is_global = !simple_component && p.peek().as_str() == "global";
     let is_new_component = !simple_component && p.peek().as_str() == "component";
@@ -110,7 +130,7 @@ pub fn parse_component(p: &mut impl Parser) -> bool {
         );
         return false;
     }
-    let mut p = p.start_node(SyntaxKind::Component);
+    let mut p = p.start_node_at(checkpoint, SyntaxKind::Component);
     if is_global || is_new_component {
         p.consume();
     }
@@ -154,6 +174,24 @@ pub fn parse_component(p: &mut impl Parser) -> bool {
     parse_element(&mut *p)
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,AtDeprecated
+/// @deprecated
+/// @deprecated("use something else")
+/// ```
+pub fn parse_deprecated(p: &mut impl Parser) -> bool {
+    debug_assert_eq!(p.peek().as_str(), "@");
+    let mut p = p.start_node(SyntaxKind::AtDeprecated);
+    p.consume(); // "@"
+    debug_assert_eq!(p.peek().as_str(), "deprecated");
+    p.consume(); // "deprecated"
+    if p.test(SyntaxKind::LParent) {
+        p.expect(SyntaxKind::StringLiteral);
+        return p.expect(SyntaxKind::RParent);
+    }
+    true
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,QualifiedName
 /// Rectangle
@@ -188,6 +226,7 @@ pub fn parse_qualified_name(p: &mut impl Parser) -> bool {
 /// export * from "foo";
 /// export { Abc } from "foo";
 /// export { Abc, Efg } from "foo";
+/// export { *, Abc as Efg, Hij as _ } from "foo";
 /// ```
 fn parse_export<P: Parser>(p: &mut P, checkpoint: Option<P::Checkpoint>) -> bool {
     debug_assert_eq!(p.peek().as_str(), "export");
@@ -199,7 +238,10 @@ fn parse_export<P: Parser>(p: &mut P, checkpoint: Option<P::Checkpoint>) -> bool
             if p.test(SyntaxKind::RBrace) {
                 break;
             }
-            parse_export_specifier(&mut *p);
+            // `*` re-exports the types that are not listed, when re-exporting from another file
+            if !p.test(SyntaxKind::Star) {
+                parse_export_specifier(&mut *p);
+            }
             match p.nth(0).kind() {
                 SyntaxKind::RBrace => {
                     p.consume();
@@ -249,7 +291,8 @@ fn parse_export<P: Parser>(p: &mut P, checkpoint: Option<P::Checkpoint>) -> bool
         p.consume();
         p.expect(SyntaxKind::Semicolon)
     } else {
-        parse_component(&mut *p)
+        // A `@deprecated` annotation before `export` stays in the ExportsList
+        parse_component(&mut *p, None)
     }
 }
 
@@ -3,7 +3,7 @@
 
 //! The parser functions for elements and things inside them
 
-use super::document::parse_qualified_name;
+use super::document::{parse_deprecated, parse_qualified_name};
 use super::expressions::parse_expression;
 use super::prelude::*;
 use super::r#type::parse_type;
@@ -14,6 +14,7 @@
 /// Item { }
 /// Item { property: value; SubElement { } }
 /// Item { if true: Rectangle {} }
+/// Item { @deprecated("use bar") in property <int> foo; }
 /// ```
 pub fn parse_element(p: &mut impl Parser) -> bool {
     let mut p = p.start_node(SyntaxKind::Element);
@@ -92,7 +93,7 @@ pub fn parse_element_content(p: &mut impl Parser) {
                     parse_changed_callback(&mut *p);
                 }
                 SyntaxKind::LAngle | SyntaxKind::Identifier if p.peek().as_str() == "property" => {
-                    parse_property_declaration(&mut *p);
+                    parse_property_declaration(&mut *p, None);
                 }
                 SyntaxKind::Identifier
                     if p.nth(1).as_str() == "property"
@@ -101,7 +102,7 @@ pub fn parse_element_content(p: &mut impl Parser) {
                             "in" | "out" | "in_out" | "in-out" | "private"
                         ) =>
                 {
-                    parse_property_declaration(&mut *p);
+                    parse_property_declaration(&mut *p, None);
                 }
                 _ if p.peek().as_str() == "if" => {
                     parse_if_element(&mut *p);
@@ -125,6 +126,15 @@ pub fn parse_element_content(p: &mut impl Parser) {
                     }
                 }
             },
+            SyntaxKind::At if p.nth(1).as_str() == "deprecated" => {
+                let checkpoint = p.checkpoint();
+                parse_deprecated(&mut *p);
+                if p.peek().as_str() == "property" || p.nth(1).as_str() == "property" {
+                    parse_property_declaration(&mut *p, Some(checkpoint));
+                } else {
+                    p.error("Expected property declaration after @deprecated");
+                }
+            }
             SyntaxKind::At => {
                 let checkpoint = p.checkpoint();
                 p.consume();
@@ -393,9 +403,10 @@ fn parse_callback_declaration(p: &mut impl Parser) {
 /// property<string> text: "Something";
 /// property<string> text <=> two.way;
 /// property alias <=> two.way;
+/// in property <int> yyy required;
 /// ```
-fn parse_property_declaration(p: &mut impl Parser) {
-    let checkpoint = p.checkpoint();
+fn parse_property_declaration<P: Parser>(p: &mut P, checkpoint: Option<P::Checkpoint>) {
+    let checkpoint = checkpoint.unwrap_or_else(|| p.checkpoint());
     while matches!(p.peek().as_str(), "in" | "out" | "in-out" | "in_out" | "private") {
         p.consume();
     }
@@ -420,6 +431,10 @@ fn parse_property_declaration(p: &mut impl Parser) {
         p.expect(SyntaxKind::Identifier);
     }
 
+    if p.peek().as_str() == "required" {
+        p.consume();
+    }
+
     match p.nth(0).kind() {
         SyntaxKind::Colon => {
             p.consume();
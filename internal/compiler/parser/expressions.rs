@@ -233,6 +233,7 @@ fn parse_expression_helper(p: &mut impl Parser, precedence: OperatorPrecedence)
 /// @linear-gradient(0deg, blue, red)
 /// @conic-gradient(blue 0deg, red 180deg)
 /// @tr("foo", bar)
+/// @open-url("https://slint.dev")
 /// ```
 fn parse_at_keyword(p: &mut impl Parser) {
     debug_assert_eq!(p.peek().kind(), SyntaxKind::At);
@@ -252,10 +253,13 @@ fn parse_at_keyword(p: &mut impl Parser) {
         "tr" => {
             parse_tr(p);
         }
+        "open-url" | "open_url" => {
+            parse_open_url(p);
+        }
         _ => {
             p.consume();
             p.test(SyntaxKind::Identifier); // consume the identifier, so that autocomplete works
-            p.error("Expected 'image-url', 'tr', 'linear-gradient', 'radial-gradient' or 'conic-gradient' after '@'");
+            p.error("Expected 'image-url', 'tr', 'open-url', 'linear-gradient', 'radial-gradient' or 'conic-gradient' after '@'");
         }
     }
 }
@@ -435,6 +439,23 @@ fn consume_literal(p: &mut impl Parser) -> bool {
     p.expect(SyntaxKind::RParent);
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,AtOpenUrl
+/// @open-url("https://slint.dev")
+/// @open-url("https://slint.dev/?q=" + query)
+/// ```
+fn parse_open_url(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::AtOpenUrl);
+    p.expect(SyntaxKind::At);
+    debug_assert!(matches!(p.peek().as_str(), "open-url" | "open_url"));
+    p.expect(SyntaxKind::Identifier); // "open-url"
+    if !p.expect(SyntaxKind::LParent) {
+        return;
+    }
+    parse_expression(&mut *p);
+    p.expect(SyntaxKind::RParent);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,AtImageUrl
 /// @image-url("foo.png")
@@ -2,10 +2,16 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 mod apply_default_properties_from_style;
+mod aspect_ratio;
+mod backdrop_blur;
 mod binding_analysis;
 mod border_radius;
+pub mod brand_palettes;
+mod check_deprecated;
 mod check_expressions;
 mod check_public_api;
+mod check_required_properties;
+mod check_sandbox;
 mod clip;
 mod collect_custom_fonts;
 mod collect_globals;
@@ -30,17 +36,21 @@
 mod inlining;
 mod lower_absolute_coordinates;
 mod lower_accessibility;
+mod lower_anchors;
 mod lower_component_container;
 mod lower_layout;
 mod lower_menus;
+mod lower_outline;
 mod lower_platform;
 mod lower_popups;
 mod lower_property_to_element;
 mod lower_shadows;
 mod lower_states;
+mod lower_system;
 mod lower_tabwidget;
 mod lower_text_input_interface;
 mod lower_timers;
+mod lower_viewport_visibility;
 pub mod materialize_fake_properties;
 pub mod move_declarations;
 mod optimize_useless_rectangles;
@@ -93,11 +103,13 @@ pub async fn run_passes(
             .await
             .unwrap_or_else(|| panic!("can't load palette"))
     };
+    brand_palettes::apply_brand_palettes(&palette, type_loader, diag).await;
 
     let global_type_registry = type_loader.global_type_registry.clone();
 
     run_import_passes(doc, type_loader, diag);
     check_public_api::check_public_api(doc, &type_loader.compiler_config, diag);
+    check_sandbox::check_sandbox(doc, &type_loader.compiler_config, diag);
 
     let raw_type_loader =
         keep_raw.then(|| crate::typeloader::snapshot_with_extra_doc(type_loader, doc).unwrap());
@@ -126,6 +138,7 @@ pub async fn run_passes(
         );
         repeater_component::process_repeater_components(component);
         lower_popups::lower_popups(component, &doc.local_registry, diag);
+        lower_viewport_visibility::lower_viewport_callbacks(component);
         collect_init_code::collect_init_code(component);
         lower_timers::lower_timers(component, diag);
     });
@@ -145,10 +158,32 @@ pub async fn run_passes(
         border_radius::handle_border_radius(component, diag);
         deprecated_rotation_origin::handle_rotation_origin(component, diag);
         flickable::handle_flickable(component, &global_type_registry.borrow());
+        lower_anchors::lower_anchors(component, diag);
+        aspect_ratio::handle_aspect_ratio(component);
         lower_layout::lower_layouts(component, type_loader, &style_metrics, diag);
         default_geometry::default_geometry(component, diag);
         lower_absolute_coordinates::lower_absolute_coordinates(component);
+        lower_viewport_visibility::lower_visible_in_viewport(component);
         z_order::reorder_by_z_order(component, diag);
+        lower_property_to_element::lower_property_to_element(
+            component,
+            core::iter::once("z"),
+            core::iter::empty(),
+            None,
+            &SmolStr::new_static("ZOrder"),
+            &global_type_registry.borrow(),
+            diag,
+        );
+        backdrop_blur::lower_backdrop_blur(component, &global_type_registry.borrow(), diag);
+        lower_property_to_element::lower_property_to_element(
+            component,
+            core::iter::once("mask-image"),
+            core::iter::empty(),
+            None,
+            &SmolStr::new_static("Mask"),
+            &global_type_registry.borrow(),
+            diag,
+        );
         lower_property_to_element::lower_property_to_element(
             component,
             core::iter::once("opacity"),
@@ -167,6 +202,15 @@ pub async fn run_passes(
             &global_type_registry.borrow(),
             diag,
         );
+        lower_property_to_element::lower_property_to_element(
+            component,
+            core::iter::once("mix-blend-mode"),
+            core::iter::empty(),
+            None,
+            &SmolStr::new_static("Blend"),
+            &global_type_registry.borrow(),
+            diag,
+        );
         visible::handle_visible(component, &global_type_registry.borrow(), diag);
         lower_shadows::lower_shadow_properties(component, &doc.local_registry, diag);
         lower_property_to_element::lower_transform_properties(
@@ -175,6 +219,7 @@ pub async fn run_passes(
             diag,
         );
         clip::handle_clip(component, &global_type_registry.borrow(), diag);
+        lower_outline::lower_outline_properties(component, &global_type_registry.borrow());
         if type_loader.compiler_config.accessibility {
             lower_accessibility::lower_accessibility_properties(component, diag);
         }
@@ -197,6 +242,7 @@ pub async fn run_passes(
 
     doc.visit_all_used_components(|component| {
         lower_platform::lower_platform(component, type_loader);
+        lower_system::lower_system(component);
 
         // Don't perform the empty rectangle removal when debug info is requested, because the resulting
         // item tree ends up with a hierarchy where certain items have children that aren't child elements
@@ -233,14 +279,7 @@ pub async fn run_passes(
         }
     });
 
-    embed_images::embed_images(
-        doc,
-        type_loader.compiler_config.embed_resources,
-        type_loader.compiler_config.const_scale_factor,
-        &type_loader.compiler_config.resource_url_mapper,
-        diag,
-    )
-    .await;
+    embed_images::embed_images(doc, &type_loader.compiler_config, diag).await;
 
     #[cfg(feature = "bundle-translations")]
     if let Some(path) = &type_loader.compiler_config.translation_path_bundle {
@@ -318,5 +357,7 @@ pub fn run_import_passes(
     purity_check::purity_check(doc, diag);
     focus_handling::replace_forward_focus_bindings_with_focus_functions(doc, diag);
     check_expressions::check_expressions(doc, diag);
+    check_required_properties::check_required_properties(doc, diag);
+    check_deprecated::check_deprecated(doc, diag);
     unique_id::check_unique_id(doc, diag);
 }
@@ -69,6 +69,18 @@ pub fn apply_default_properties_from_style(
                         .into(),
                         to: Type::Brush,
                     });
+                    if elem.bindings.contains_key("selectable") {
+                        elem.set_binding_if_not_set("selection-background-color".into(), || {
+                            Expression::Cast {
+                                from: Expression::PropertyReference(NamedReference::new(
+                                    &palette.root_element,
+                                    SmolStr::new_static("selection-background"),
+                                ))
+                                .into(),
+                                to: Type::Color,
+                            }
+                        });
+                    }
                 }
                 "Dialog" | "Window" => {
                     elem.set_binding_if_not_set("background".into(), || Expression::Cast {
@@ -0,0 +1,83 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that turns the `aspect-ratio` property into bindings for the width, height, or the
+//! vertical layout constraints.
+//!
+//! This pass must be run before lower_layout, so that the layout constraints are picked up.
+
+use crate::expression_tree::{Expression, MinMaxOp, NamedReference, Unit};
+use crate::langtype::Type;
+use crate::object_tree::{Component, ElementRc};
+use smol_str::SmolStr;
+use std::rc::Rc;
+
+pub fn handle_aspect_ratio(root_component: &Rc<Component>) {
+    crate::object_tree::recurse_elem_including_sub_components_no_borrow(
+        root_component,
+        &(),
+        &mut |elem, _| {
+            if elem.borrow().repeated.is_some()
+                || elem.borrow().geometry_props.is_none()
+                || !elem.borrow().is_binding_set("aspect-ratio", true)
+            {
+                return;
+            }
+            let width_set = elem.borrow().is_binding_set("width", true);
+            let height_set = elem.borrow().is_binding_set("height", true);
+            if width_set && !height_set {
+                let e = height_from_width(elem);
+                elem.borrow_mut().set_binding_if_not_set(SmolStr::new_static("height"), || e);
+            } else if height_set && !width_set {
+                let e = width_from_height(elem);
+                elem.borrow_mut().set_binding_if_not_set(SmolStr::new_static("width"), || e);
+            } else if !width_set && !height_set {
+                // The width is solved first by the layout, so the height follows it
+                for prop in ["min-height", "max-height", "preferred-height"] {
+                    let e = height_from_width(elem);
+                    elem.borrow_mut().set_binding_if_not_set(SmolStr::new_static(prop), || e);
+                }
+            }
+        },
+    )
+}
+
+/// Returns the binding for the height of an element that has no explicit height and is not in a layout
+pub fn default_height_binding(elem: &ElementRc) -> Option<Expression> {
+    elem.borrow().is_binding_set("aspect-ratio", true).then(|| height_from_width(elem))
+}
+
+fn aspect_ratio(elem: &ElementRc) -> Expression {
+    // A ratio of zero or less is meaningless; avoid dividing by it
+    Expression::MinMax {
+        ty: Type::Float32,
+        op: MinMaxOp::Max,
+        lhs: Box::new(Expression::PropertyReference(NamedReference::new(
+            elem,
+            SmolStr::new_static("aspect-ratio"),
+        ))),
+        rhs: Box::new(Expression::NumberLiteral(0.0001, Unit::None)),
+    }
+}
+
+fn height_from_width(elem: &ElementRc) -> Expression {
+    Expression::BinaryExpression {
+        lhs: Box::new(Expression::PropertyReference(NamedReference::new(
+            elem,
+            SmolStr::new_static("width"),
+        ))),
+        rhs: Box::new(aspect_ratio(elem)),
+        op: '/',
+    }
+}
+
+fn width_from_height(elem: &ElementRc) -> Expression {
+    Expression::BinaryExpression {
+        lhs: Box::new(Expression::PropertyReference(NamedReference::new(
+            elem,
+            SmolStr::new_static("height"),
+        ))),
+        rhs: Box::new(aspect_ratio(elem)),
+        op: '*',
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that lowers synthetic `backdrop-blur` properties to the BackdropBlur element,
+//! which follows the border-radius of the element it blurs the backdrop of.
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{Expression, NamedReference};
+use crate::langtype::Type;
+use crate::object_tree::Component;
+use crate::typeregister::TypeRegister;
+use smol_str::{SmolStr, ToSmolStr};
+use std::rc::Rc;
+
+pub fn lower_backdrop_blur(
+    component: &Rc<Component>,
+    type_register: &TypeRegister,
+    diag: &mut BuildDiagnostics,
+) {
+    let element_name = SmolStr::new_static("BackdropBlur");
+    super::lower_property_to_element::lower_property_to_element(
+        component,
+        core::iter::once("backdrop-blur"),
+        core::iter::empty(),
+        None,
+        &element_name,
+        type_register,
+        diag,
+    );
+
+    crate::object_tree::recurse_elem_including_sub_components_no_borrow(
+        component,
+        &(),
+        &mut |elem, _| {
+            if elem.borrow().base_type.to_smolstr() != element_name {
+                return;
+            }
+            let Some(child) = elem.borrow().children.first().cloned() else { return };
+            if child.borrow().lookup_property("border-radius").property_type == Type::LogicalLength
+            {
+                elem.borrow_mut().set_binding_if_not_set(
+                    SmolStr::new_static("border-radius"),
+                    || {
+                        Expression::PropertyReference(NamedReference::new(
+                            &child,
+                            SmolStr::new_static("border-radius"),
+                        ))
+                    },
+                );
+            }
+        },
+    )
+}
@@ -543,6 +543,20 @@ fn recurse_expression(
                     }
                 }
             }
+            BuiltinFunction::ItemVisibleInViewport => {
+                if let Some(Expression::ElementReference(item)) = arguments.first() {
+                    let mut item = Some(item.upgrade().unwrap());
+                    while let Some(current) = item {
+                        for prop in ["x", "y", "width", "height"] {
+                            vis(
+                                &NamedReference::new(&current, SmolStr::new_static(prop)).into(),
+                                ReadType::NativeRead,
+                            );
+                        }
+                        item = find_parent_element(&current);
+                    }
+                }
+            }
             BuiltinFunction::ItemFontMetrics => {
                 if let Some(Expression::ElementReference(item)) = arguments.first() {
                     let item = item.upgrade().unwrap();
@@ -1,7 +1,8 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-//! Pass that applies the default border-radius to border-top|bottom-left|right-radius.
+//! Pass that applies the default border-radius to border-top|bottom-left|right-radius, and the
+//! default border-width and border-color to the widths and colors of the edges of the border.
 
 use crate::diagnostics::BuildDiagnostics;
 use crate::expression_tree::{Expression, NamedReference};
@@ -16,6 +17,17 @@
     "border-bottom-left-radius",
 ];
 
+const BORDER_EDGE_PROPERTIES: [(&str, &str); 8] = [
+    ("border-top-width", "border-width"),
+    ("border-right-width", "border-width"),
+    ("border-bottom-width", "border-width"),
+    ("border-left-width", "border-width"),
+    ("border-top-color", "border-color"),
+    ("border-right-color", "border-color"),
+    ("border-bottom-color", "border-color"),
+    ("border-left-color", "border-color"),
+];
+
 pub fn handle_border_radius(root_component: &Rc<Component>, _diag: &mut BuildDiagnostics) {
     crate::object_tree::recurse_elem_including_sub_components_no_borrow(
         root_component,
@@ -35,6 +47,23 @@ pub fn handle_border_radius(root_component: &Rc<Component>, _diag: &mut BuildDia
                     });
                 }
             }
+            // When one edge is set, the others keep the width and color of the whole border. The
+            // renderer relies on this to tell whether the edges are used.
+            if bty.name == "Rectangle"
+                && BORDER_EDGE_PROPERTIES
+                    .iter()
+                    .any(|(property_name, _)| elem.borrow().is_binding_set(property_name, true))
+            {
+                for (property_name, default_property) in BORDER_EDGE_PROPERTIES {
+                    if !elem.borrow().is_binding_set(default_property, true) {
+                        continue;
+                    }
+                    let default = NamedReference::new(elem, SmolStr::new_static(default_property));
+                    elem.borrow_mut().set_binding_if_not_set(SmolStr::new(property_name), || {
+                        Expression::PropertyReference(default)
+                    });
+                }
+            }
         },
     )
 }
@@ -0,0 +1,248 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Support for the brand palettes of the std-widgets.
+//!
+//! Each brand palette configured with [`CompilerConfiguration::brand_palettes`](crate::CompilerConfiguration::brand_palettes)
+//! is a file exporting a `Palette` global whose bindings must be constant. The `Brand` enum gets one value
+//! per brand, and the `Palette` of the style gets a `brand` property. The bindings of the palette of the
+//! style are then replaced by a condition that selects the constant of the current brand, so the cost of
+//! these bindings doesn't depend on the number of properties the brands change.
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{Expression, NamedReference};
+use crate::langtype::{ElementType, Enumeration, EnumerationValue, Type};
+use crate::object_tree::{Component, Document, ElementRc, PropertyDeclaration, PropertyVisibility};
+use crate::parser::{syntax_nodes, SyntaxKind};
+use crate::typeregister::TypeRegister;
+use smol_str::SmolStr;
+use std::rc::Rc;
+
+const BRAND_ENUM_NAME: &str = "Brand";
+const BRAND_PROPERTY_NAME: &str = "brand";
+
+/// Registers the `Brand` enum in the global type registry, with the `default` value followed by the
+/// configured brands.
+pub fn register_brand_enum(
+    brand_palettes: &[(String, std::path::PathBuf)],
+    registry: &mut TypeRegister,
+    diag: &mut BuildDiagnostics,
+) {
+    if brand_palettes.is_empty() {
+        return;
+    }
+    let mut names = vec![SmolStr::new_static("default")];
+    for (name, _) in brand_palettes {
+        let valid = name.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        let name = crate::parser::normalize_identifier(name);
+        if !valid {
+            diag.push_error_with_span(
+                format!("Invalid brand palette name '{name}'"),
+                Default::default(),
+            );
+        } else if names.contains(&name) {
+            diag.push_error_with_span(
+                format!("Duplicated brand palette '{name}'"),
+                Default::default(),
+            );
+        } else {
+            names.push(name);
+        }
+    }
+
+    // The generators need the declaration node of the enum, so declare it as if it was in a .slint file
+    let source = format!("export enum {BRAND_ENUM_NAME} {{ {} }}", names.join(", "));
+    let node = crate::parser::parse(source, None, diag);
+    let Some(node) = node
+        .descendants()
+        .find(|n| n.kind() == SyntaxKind::EnumDeclaration)
+        .map(syntax_nodes::EnumDeclaration::from)
+    else {
+        return;
+    };
+    let enumeration = Enumeration {
+        name: BRAND_ENUM_NAME.into(),
+        values: names,
+        default_value: 0,
+        node: Some(node),
+    };
+    registry.insert_type_with_name(Type::Enumeration(Rc::new(enumeration)), BRAND_ENUM_NAME.into());
+}
+
+/// Declares the `brand` property in the `Palette` global of a builtin style, if brand palettes are configured.
+pub fn declare_brand_property(doc: &Document, registry: &TypeRegister) {
+    let ty @ Type::Enumeration(_) = registry.lookup(BRAND_ENUM_NAME) else { return };
+    let Some(palette) = doc.exports.find("Palette").and_then(|x| x.left()) else { return };
+    let mut root = palette.root_element.borrow_mut();
+    if !matches!(root.base_type, ElementType::Global) {
+        return;
+    }
+    root.property_declarations.entry(BRAND_PROPERTY_NAME.into()).or_insert_with(|| {
+        PropertyDeclaration {
+            property_type: ty,
+            visibility: PropertyVisibility::InOut,
+            ..Default::default()
+        }
+    });
+}
+
+/// Replaces the bindings of the `palette` of the style by conditions on the current brand.
+pub async fn apply_brand_palettes(
+    palette: &Rc<Component>,
+    type_loader: &mut crate::typeloader::TypeLoader,
+    diag: &mut BuildDiagnostics,
+) {
+    let brand_palettes = type_loader.compiler_config.brand_palettes.clone();
+    if brand_palettes.is_empty() {
+        return;
+    }
+    let Type::Enumeration(brand_enum) =
+        type_loader.global_type_registry.borrow().lookup(BRAND_ENUM_NAME)
+    else {
+        return;
+    };
+    if !palette.root_element.borrow().property_declarations.contains_key(BRAND_PROPERTY_NAME) {
+        diag.push_error_with_span(
+            format!("The style '{}' doesn't support brand palettes", type_loader.resolved_style),
+            Default::default(),
+        );
+        return;
+    }
+    let brand =
+        NamedReference::new(&palette.root_element, SmolStr::new_static(BRAND_PROPERTY_NAME));
+
+    for (name, path) in brand_palettes {
+        let name = crate::parser::normalize_identifier(&name);
+        let Some(value) = brand_enum.values.iter().position(|v| *v == name) else { continue };
+        let Some(brand_palette) =
+            type_loader.import_component(&path.to_string_lossy(), "Palette", diag).await
+        else {
+            diag.push_error_with_span(
+                format!(
+                    "The brand palette '{name}' must export a global called Palette in '{}'",
+                    path.display()
+                ),
+                Default::default(),
+            );
+            continue;
+        };
+        let condition = Expression::BinaryExpression {
+            lhs: Box::new(Expression::PropertyReference(brand.clone())),
+            rhs: Box::new(Expression::EnumerationValue(EnumerationValue {
+                value,
+                enumeration: brand_enum.clone(),
+            })),
+            op: '=',
+        };
+
+        let bindings = brand_palette.root_element.borrow().bindings.clone();
+        for (prop_name, binding) in bindings {
+            let binding = binding.into_inner();
+            let prop_type = palette.root_element.borrow().lookup_property(&prop_name).property_type;
+            if prop_name == BRAND_PROPERTY_NAME || prop_type == Type::Invalid {
+                diag.push_error(
+                    format!("'{prop_name}' is not a property of the Palette of the style"),
+                    &binding,
+                );
+                continue;
+            }
+            if binding.expression.ty() != prop_type {
+                diag.push_error(
+                    format!("The property '{prop_name}' of the Palette is of type {prop_type}"),
+                    &binding,
+                );
+                continue;
+            }
+            if !binding.expression.is_constant(None) {
+                diag.push_error(
+                    format!(
+                        "The brand palette '{name}' must set '{prop_name}' to a constant value"
+                    ),
+                    &binding,
+                );
+                continue;
+            }
+
+            let (elem, prop_name) = binding_target(&palette.root_element, prop_name, &prop_type);
+            let elem = elem.borrow();
+            let Some(mut target) = elem.bindings.get(&prop_name).map(|b| b.borrow_mut()) else {
+                continue;
+            };
+            let fallback = std::mem::take(&mut target.expression);
+            target.expression = Expression::Condition {
+                condition: Box::new(condition.clone()),
+                true_expr: Box::new(binding.expression.clone()),
+                false_expr: Box::new(fallback),
+            };
+        }
+    }
+}
+
+/// The palette of the styles forwards to another global that is used by the widgets of the style, so
+/// the brand applies to the property of that global.
+fn binding_target(elem: &ElementRc, prop_name: SmolStr, ty: &Type) -> (ElementRc, SmolStr) {
+    let forwarded =
+        elem.borrow().bindings.get(&prop_name).and_then(
+            |b| match crate::passes::ignore_debug_hooks(&b.borrow().expression) {
+                Expression::PropertyReference(nr)
+                    if matches!(nr.element().borrow().base_type, ElementType::Global)
+                        && nr.ty() == *ty
+                        && !Rc::ptr_eq(&nr.element(), elem) =>
+                {
+                    Some(nr.clone())
+                }
+                _ => None,
+            },
+        );
+    match forwarded {
+        Some(nr) if nr.element().borrow().bindings.contains_key(nr.name()) => {
+            binding_target(&nr.element(), nr.name().clone(), ty)
+        }
+        _ => (elem.clone(), prop_name),
+    }
+}
+
+#[test]
+fn test_brand_palettes() {
+    let mut compiler_config =
+        crate::CompilerConfiguration::new(crate::generator::OutputFormat::Interpreter);
+    compiler_config.style = Some("fluent".into());
+    compiler_config.brand_palettes = vec![(
+        "acme".into(),
+        [env!("CARGO_MANIFEST_DIR"), "tests", "typeloader", "brand_palette.slint"].iter().collect(),
+    )];
+    let mut test_diags = crate::diagnostics::BuildDiagnostics::default();
+    let doc_node = crate::parser::parse(
+        r#"
+import { Palette, Button } from "std-widgets.slint";
+export component Foo inherits Window {
+    Button { clicked => { Palette.brand = Brand.acme; } }
+}
+"#
+        .into(),
+        Some(std::path::Path::new("HELLO")),
+        &mut test_diags,
+    );
+    let (doc, diag, _) =
+        spin_on::spin_on(crate::compile_syntax_node(doc_node, test_diags, compiler_config));
+    assert!(!diag.has_errors(), "slint compile error {:#?}", diag.to_string_vec());
+
+    let used_types = doc.used_types.borrow();
+    assert!(used_types
+        .structs_and_enums
+        .iter()
+        .any(|ty| matches!(ty, Type::Enumeration(e) if e.name == BRAND_ENUM_NAME)));
+
+    let mut has_brand_condition = false;
+    for global in &used_types.globals {
+        crate::object_tree::visit_all_expressions(global, |expr, _| {
+            expr.visit_recursive(&mut |e| {
+                if let Expression::EnumerationValue(v) = e {
+                    has_brand_condition |= v.enumeration.name == BRAND_ENUM_NAME && v.value == 1;
+                }
+            })
+        });
+    }
+    assert!(has_brand_condition);
+}
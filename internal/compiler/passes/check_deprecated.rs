@@ -0,0 +1,95 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that warns about the use of properties and components annotated with `@deprecated`
+//! when they are used from another file than the one declaring them
+
+use crate::diagnostics::{BuildDiagnostics, SourceFile, SourceLocation, Spanned};
+use crate::expression_tree::Expression;
+use crate::langtype::ElementType;
+use crate::namedreference::NamedReference;
+use crate::object_tree::{recurse_elem, Document, Element};
+use smol_str::SmolStr;
+
+pub fn check_deprecated(doc: &Document, diag: &mut BuildDiagnostics) {
+    let Some(current_file) = doc.node.as_ref().and_then(|n| n.source_file()).cloned() else {
+        return;
+    };
+    let is_other_file =
+        |file: Option<&SourceFile>| file.is_some_and(|f| f.path() != current_file.path());
+
+    for component in &doc.inner_components {
+        recurse_elem(&component.root_element, &(), &mut |elem, _| {
+            let elem = elem.borrow();
+            if let ElementType::Component(base) = &elem.base_type {
+                if let Some(reason) = &base.deprecated {
+                    if is_other_file(base.node.as_ref().and_then(|n| n.source_file())) {
+                        diag.push_warning(message("component", &base.id, reason), &*elem);
+                    }
+                }
+            }
+
+            for (name, binding) in &elem.bindings {
+                let binding = binding.borrow();
+                if let Some((reason, node)) = deprecated_property(&elem, name) {
+                    if is_other_file(node.source_file()) {
+                        diag.push_warning(message("property", name, &reason), &binding.span);
+                    }
+                }
+                let mut check_reference = |nr: &NamedReference| {
+                    check_named_reference(nr, &binding.span, &is_other_file, diag)
+                };
+                binding.two_way_bindings.iter().for_each(&mut check_reference);
+                binding.expression.visit_recursive(&mut |e| {
+                    if let Expression::PropertyReference(nr) = e {
+                        check_reference(nr)
+                    }
+                });
+            }
+        });
+    }
+}
+
+fn check_named_reference(
+    nr: &NamedReference,
+    span: &Option<SourceLocation>,
+    is_other_file: &dyn Fn(Option<&SourceFile>) -> bool,
+    diag: &mut BuildDiagnostics,
+) {
+    let elem = nr.element();
+    let elem = elem.borrow();
+    if let Some((reason, node)) = deprecated_property(&elem, nr.name()) {
+        if is_other_file(node.source_file()) {
+            diag.push_warning(message("property", nr.name(), &reason), span);
+        }
+    }
+    if let Some(global) = elem.enclosing_component.upgrade().filter(|c| c.is_global()) {
+        if let Some(reason) = &global.deprecated {
+            if is_other_file(global.node.as_ref().and_then(|n| n.source_file())) {
+                diag.push_warning(message("global", &global.id, reason), span);
+            }
+        }
+    }
+}
+
+/// Returns the reason and the declaration node if the property `name` of `elem` is deprecated
+fn deprecated_property(
+    elem: &Element,
+    name: &str,
+) -> Option<(SmolStr, Option<crate::parser::SyntaxNode>)> {
+    if let Some(decl) = elem.property_declarations.get(name) {
+        return decl.deprecated.clone().map(|reason| (reason, decl.node.clone()));
+    }
+    match &elem.base_type {
+        ElementType::Component(c) => deprecated_property(&c.root_element.borrow(), name),
+        _ => None,
+    }
+}
+
+fn message(kind: &str, name: &str, reason: &str) -> String {
+    if reason.is_empty() {
+        format!("The {kind} '{name}' is deprecated")
+    } else {
+        format!("The {kind} '{name}' is deprecated: {reason}")
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that checks that the properties declared as `required` are set when a component is instantiated
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::langtype::ElementType;
+use crate::object_tree::{recurse_elem, Document, ElementRc};
+use smol_str::SmolStr;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+pub fn check_required_properties(doc: &Document, diag: &mut BuildDiagnostics) {
+    for component in &doc.inner_components {
+        if component.is_global() {
+            for (name, decl) in &component.root_element.borrow().property_declarations {
+                if decl.required {
+                    let required_token = decl.node.as_ref().and_then(|n| {
+                        n.children_with_tokens()
+                            .find(|t| t.as_token().is_some_and(|t| t.text() == "required"))
+                    });
+                    diag.push_error(
+                        format!("Property '{name}' of a global cannot be required"),
+                        &required_token,
+                    );
+                }
+            }
+            continue;
+        }
+        recurse_elem(&component.root_element, &(), &mut |elem, _| {
+            // The root element isn't an instantiation: its required properties stay required
+            if !Rc::ptr_eq(elem, &component.root_element) {
+                check_element(elem, diag);
+            }
+        });
+    }
+}
+
+fn check_element(elem: &ElementRc, diag: &mut BuildDiagnostics) {
+    let elem = elem.borrow();
+    // Properties can be set on the element, or on the root element of any component in the inheritance chain
+    let mut set: HashSet<SmolStr> = elem.bindings.keys().cloned().collect();
+    let mut base = elem.base_type.clone();
+    while let ElementType::Component(c) = base {
+        let root = c.root_element.borrow();
+        for (name, decl) in &root.property_declarations {
+            if decl.required && !set.contains(name) {
+                diag.push_error(
+                    format!("Missing binding for required property '{name}' of '{}'", c.id),
+                    &*elem,
+                );
+            }
+        }
+        set.extend(root.bindings.keys().cloned());
+        base = root.base_type.clone();
+    }
+}
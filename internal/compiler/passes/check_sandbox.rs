@@ -0,0 +1,137 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that enforces the limits of the sandbox on the models and on the size of the element tree
+//!
+//! The number of elements is an upper bound computed from the number of rows of the models: array
+//! literals are counted with their length, the repetitions of a `for` over a number are clamped to
+//! the maximum model size, and the models provided by the application count as one row.
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{Expression, MinMaxOp, Unit};
+use crate::langtype::{ElementType, Type};
+use crate::object_tree::{visit_element_expressions, Component, Document, ElementRc};
+use crate::{CompilerConfiguration, SandboxConfiguration};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+pub fn check_sandbox(doc: &Document, config: &CompilerConfiguration, diag: &mut BuildDiagnostics) {
+    let Some(sandbox) = &config.sandbox else { return };
+    let mut checker = SandboxChecker { sandbox, diag, component_sizes: HashMap::new() };
+    for component in doc.exported_roots() {
+        let size = checker.component_size(&component);
+        let root_element = component.root_element.borrow();
+        if size.depth > sandbox.max_element_depth {
+            checker.diag.push_error(
+                format!(
+                    "The element tree of '{}' is {} elements deep, more than the {} allowed in the sandbox",
+                    component.id, size.depth, sandbox.max_element_depth
+                ),
+                &*root_element,
+            );
+        }
+        if size.instances > sandbox.max_element_instances {
+            checker.diag.push_error(
+                format!(
+                    "'{}' can instantiate up to {} elements, more than the {} allowed in the sandbox",
+                    component.id, size.instances, sandbox.max_element_instances
+                ),
+                &*root_element,
+            );
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct TreeSize {
+    depth: usize,
+    instances: usize,
+}
+
+struct SandboxChecker<'a> {
+    sandbox: &'a SandboxConfiguration,
+    diag: &'a mut BuildDiagnostics,
+    /// Components are checked once, even if they are instantiated many times
+    component_sizes: HashMap<*const Component, TreeSize>,
+}
+
+impl SandboxChecker<'_> {
+    fn component_size(&mut self, component: &Rc<Component>) -> TreeSize {
+        if let Some(size) = self.component_sizes.get(&Rc::as_ptr(component)) {
+            return *size;
+        }
+        let size = self.element_size(&component.root_element);
+        self.component_sizes.insert(Rc::as_ptr(component), size);
+        size
+    }
+
+    fn element_size(&mut self, element: &ElementRc) -> TreeSize {
+        let max_model_size = self.sandbox.max_model_size;
+        visit_element_expressions(element, |expr, _, _| {
+            expr.visit_recursive(&mut |expr| {
+                if let Expression::Array { values, .. } = expr {
+                    if values.len() > max_model_size {
+                        self.diag.push_error(
+                            format!(
+                                "Array of {} elements, more than the {max_model_size} allowed in the sandbox",
+                                values.len()
+                            ),
+                            &*element.borrow(),
+                        );
+                    }
+                }
+            })
+        });
+
+        let rows = match &mut element.borrow_mut().repeated {
+            Some(repeated) if repeated.is_conditional_element => 1,
+            Some(repeated) => {
+                // The model is cast to a model when resolved
+                let model = match &mut repeated.model {
+                    Expression::Cast { from, to: Type::Model } => &mut **from,
+                    model => model,
+                };
+                match model {
+                    Expression::Array { values, .. } => values.len(),
+                    model if matches!(model.ty(), Type::Int32 | Type::Float32) => {
+                        let count = std::mem::take(model);
+                        *model = Expression::MinMax {
+                            ty: count.ty(),
+                            op: MinMaxOp::Min,
+                            lhs: Box::new(count),
+                            rhs: Box::new(Expression::NumberLiteral(
+                                max_model_size as _,
+                                Unit::None,
+                            )),
+                        };
+                        max_model_size
+                    }
+                    _ => 1,
+                }
+            }
+            None => 1,
+        };
+
+        let (base, children) = {
+            let element = element.borrow();
+            let base = match &element.base_type {
+                ElementType::Component(base) => Some(base.clone()),
+                _ => None,
+            };
+            (base, element.children.clone())
+        };
+        let base = match base {
+            Some(base) => self.component_size(&base),
+            None => TreeSize { depth: 1, instances: 1 },
+        };
+
+        let mut size = base;
+        for child in &children {
+            let child = self.element_size(child);
+            size.depth = size.depth.max(child.depth.saturating_add(1));
+            size.instances = size.instances.saturating_add(child.instances);
+        }
+        size.instances = size.instances.saturating_mul(rows);
+        size
+    }
+}
@@ -54,6 +54,11 @@ pub fn default_geometry(root_component: &Rc<Component>, diag: &mut BuildDiagnost
             }
 
             if let Some(parent) = parent {
+                if !elem.borrow().child_of_layout && !elem.borrow().is_binding_set("height", true) {
+                    if let Some(e) = super::aspect_ratio::default_height_binding(elem) {
+                        elem.borrow_mut().bindings.insert("height".into(), RefCell::new(e.into()));
+                    }
+                }
                 match builtin_type.default_size_binding {
                     DefaultSizeBinding::None => {
                         if elem.borrow().default_fill_parent.0 {
@@ -11,17 +11,19 @@
 use smol_str::SmolStr;
 use std::cell::RefCell;
 use std::collections::{BTreeMap, HashMap};
-use std::future::Future;
-use std::pin::Pin;
-use std::rc::Rc;
 
 pub async fn embed_images(
     doc: &Document,
-    embed_files: EmbedResourcesKind,
-    scale_factor: f64,
-    resource_url_mapper: &Option<Rc<dyn Fn(&str) -> Pin<Box<dyn Future<Output = Option<String>>>>>>,
+    compiler_config: &crate::CompilerConfiguration,
     diag: &mut BuildDiagnostics,
 ) {
+    let embed_files = compiler_config.embed_resources;
+    let scale_factor = compiler_config.const_scale_factor;
+    let resource_url_mapper = &compiler_config.resource_url_mapper;
+    #[cfg(feature = "software-renderer")]
+    let compress = compiler_config.compress_embedded_textures;
+    #[cfg(not(feature = "software-renderer"))]
+    let compress = false;
     if embed_files == EmbedResourcesKind::Nothing && resource_url_mapper.is_none() {
         return;
     }
@@ -61,6 +63,7 @@ pub async fn embed_images(
                 global_embedded_resources,
                 embed_files,
                 scale_factor,
+                compress,
                 diag,
             )
         });
@@ -86,6 +89,7 @@ fn embed_images_from_expression(
     global_embedded_resources: &RefCell<BTreeMap<SmolStr, EmbeddedResources>>,
     embed_files: EmbedResourcesKind,
     scale_factor: f64,
+    compress: bool,
     diag: &mut BuildDiagnostics,
 ) {
     if let Expression::ImageReference { ref mut resource_ref, source_location, nine_slice: _ } = e {
@@ -103,6 +107,7 @@ fn embed_images_from_expression(
                     embed_files,
                     path,
                     scale_factor,
+                    compress,
                     diag,
                     source_location,
                 );
@@ -120,6 +125,7 @@ fn embed_images_from_expression(
             global_embedded_resources,
             embed_files,
             scale_factor,
+            compress,
             diag,
         )
     });
@@ -130,6 +136,7 @@ fn embed_image(
     embed_files: EmbedResourcesKind,
     path: &str,
     _scale_factor: f64,
+    _compress: bool,
     diag: &mut BuildDiagnostics,
     source_location: &Option<crate::diagnostics::SourceLocation>,
 ) -> ImageReference {
@@ -154,6 +161,7 @@ fn embed_image(
                                 img,
                                 source_format,
                                 original_size,
+                                _compress,
                             ))
                         }
                         Err(err) => {
@@ -209,6 +217,7 @@ fn generate_texture(
     image: image::RgbaImage,
     source_format: SourceFormat,
     original_size: Size,
+    compress: bool,
 ) -> Texture {
     // Analyze each pixels
     let mut top = 0;
@@ -307,15 +316,71 @@ enum ColorState {
     };
 
     let rect = Rect::from_ltrb(left as _, top as _, (right + 1) as _, (bottom + 1) as _).unwrap();
+    let mut data = convert_image(image, source_format, format, rect);
+    let mut format = format;
+    if compress {
+        let compressed_format = match format {
+            PixelFormat::Rgb => Some((PixelFormat::RunLengthEncodedRgb, 3)),
+            PixelFormat::RgbaPremultiplied => {
+                Some((PixelFormat::RunLengthEncodedRgbaPremultiplied, 4))
+            }
+            _ => None,
+        };
+        if let Some((compressed_format, bpp)) = compressed_format {
+            let compressed = run_length_encode(&data, rect.width() as usize * bpp, bpp);
+            if compressed.len() < data.len() {
+                data = compressed;
+                format = compressed_format;
+            }
+        }
+    }
     Texture {
         total_size: Size { width: image.width(), height: image.height() },
         original_size,
         rect,
-        data: convert_image(image, source_format, format, rect),
+        data,
         format,
     }
 }
 
+/// Compresses each line of `data` with the run-length encoding that
+/// `i_slint_core::graphics::decode_run_length_encoded_line` decodes: a table of the offset of each
+/// line, followed by the lines as a sequence of packets that either repeat a pixel or copy pixels.
+#[cfg(feature = "software-renderer")]
+fn run_length_encode(data: &[u8], line_len: usize, bpp: usize) -> Vec<u8> {
+    let lines = data.chunks_exact(line_len);
+    let mut result = vec![0; lines.len() * 4];
+    for (y, line) in lines.enumerate() {
+        let offset = u32::try_from(result.len()).expect("texture too big");
+        result[y * 4..y * 4 + 4].copy_from_slice(&offset.to_le_bytes());
+        let pixels: Vec<&[u8]> = line.chunks_exact(bpp).collect();
+        let mut i = 0;
+        while i < pixels.len() {
+            let run = pixels[i..].iter().take(128).take_while(|p| **p == pixels[i]).count();
+            if run > 1 {
+                result.push(0x80 | (run - 1) as u8);
+                result.extend_from_slice(pixels[i]);
+                i += run;
+            } else {
+                // Copy pixels until the next run of the same pixel
+                let mut len = 1;
+                while len < 128 && i + len < pixels.len() {
+                    if i + len + 1 < pixels.len() && pixels[i + len] == pixels[i + len + 1] {
+                        break;
+                    }
+                    len += 1;
+                }
+                result.push((len - 1) as u8);
+                for p in &pixels[i..i + len] {
+                    result.extend_from_slice(p);
+                }
+                i += len;
+            }
+        }
+    }
+    result
+}
+
 #[cfg(feature = "software-renderer")]
 fn convert_image(
     image: image::RgbaImage,
@@ -353,6 +418,9 @@ fn convert_image(
             })
             .collect(),
         (_, PixelFormat::AlphaMap(_)) => i.pixels().map(|(_, _, p)| p[3]).collect(),
+        (_, PixelFormat::RunLengthEncodedRgb | PixelFormat::RunLengthEncodedRgbaPremultiplied) => {
+            unreachable!("the textures are compressed after the conversion")
+        }
     }
 }
 
@@ -448,6 +448,8 @@ fn duplicate_sub_component(
         private_properties: Default::default(),
         inherits_popup_window: core::cell::Cell::new(false),
         from_library: core::cell::Cell::new(false),
+        deprecated: component_to_duplicate.deprecated.clone(),
+        style: component_to_duplicate.style.clone(),
     };
 
     let new_component = Rc::new(new_component);
@@ -638,6 +640,8 @@ fn component_requires_inlining(component: &Rc<Component>) -> bool {
         if prop.starts_with("drop-shadow-")
             || prop == "opacity"
             || prop == "cache-rendering-hint"
+            || prop == "mask-image"
+            || prop == "mix-blend-mode"
             || prop == "visible"
         {
             return true;
@@ -682,6 +686,11 @@ fn element_require_inlining(elem: &ElementRc) -> bool {
             return true;
         }
 
+        if prop.starts_with("outline-") {
+            // otherwise the outline can't be added as a child of the element
+            return true;
+        }
+
         if prop == "padding"
             || prop == "spacing"
             || prop.starts_with("padding-")
@@ -0,0 +1,210 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that lowers the `anchor-*` properties to bindings of the geometry properties.
+//! For example `anchor-left: other.right + 4px;` becomes `x: self.anchor-left;`, and
+//! `anchor-left` and `anchor-right` together also define the `width`.
+//!
+//! This pass must be run before lower_layout and default_geometry.
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{BindingExpression, Expression, NamedReference, Unit};
+use crate::object_tree::{Component, ElementRc};
+use smol_str::SmolStr;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+struct Axis {
+    pos: &'static str,
+    size: &'static str,
+    start: &'static str,
+    center: &'static str,
+    end: &'static str,
+}
+
+const AXES: [Axis; 2] = [
+    Axis {
+        pos: "x",
+        size: "width",
+        start: "anchor-left",
+        center: "anchor-horizontal-center",
+        end: "anchor-right",
+    },
+    Axis {
+        pos: "y",
+        size: "height",
+        start: "anchor-top",
+        center: "anchor-vertical-center",
+        end: "anchor-bottom",
+    },
+];
+
+fn anchor_names() -> impl Iterator<Item = &'static str> {
+    AXES.iter().flat_map(|a| [a.start, a.center, a.end])
+}
+
+pub fn lower_anchors(component: &Rc<Component>, diag: &mut BuildDiagnostics) {
+    let mut anchored = Vec::new();
+    crate::object_tree::recurse_elem_including_sub_components_no_borrow(
+        component,
+        &(),
+        &mut |elem, _| {
+            if anchor_names().any(|a| elem.borrow().bindings.contains_key(a)) {
+                anchored.push(elem.clone());
+            }
+        },
+    );
+    if anchored.is_empty() {
+        return;
+    }
+
+    let in_cycle = find_cycles(&anchored, diag);
+    for elem in anchored {
+        if !in_cycle.contains(&element_key(&elem)) {
+            for axis in &AXES {
+                lower_axis(&elem, axis, diag);
+            }
+        }
+    }
+}
+
+fn element_key(elem: &ElementRc) -> *const () {
+    Rc::as_ptr(elem) as *const ()
+}
+
+/// Report an error for each element whose anchors depend, directly or not, on its own geometry,
+/// and return the set of these elements.
+fn find_cycles(anchored: &[ElementRc], diag: &mut BuildDiagnostics) -> HashSet<*const ()> {
+    // For each anchored element, the anchored elements whose geometry its anchors refer to
+    let dependencies: HashMap<*const (), Vec<ElementRc>> = anchored
+        .iter()
+        .map(|elem| {
+            let mut deps = Vec::new();
+            for anchor in anchor_names() {
+                if let Some(b) = elem.borrow().bindings.get(anchor) {
+                    b.borrow().expression.visit_recursive(&mut |e| {
+                        if let Expression::PropertyReference(nr) = e {
+                            let other = nr.element();
+                            if !Rc::ptr_eq(&other, elem)
+                                && matches!(nr.name().as_str(), "x" | "y" | "width" | "height")
+                                && anchored.iter().any(|a| Rc::ptr_eq(a, &other))
+                                && !deps.iter().any(|d| Rc::ptr_eq(d, &other))
+                            {
+                                deps.push(other);
+                            }
+                        }
+                    });
+                }
+            }
+            (element_key(elem), deps)
+        })
+        .collect();
+
+    fn reaches(
+        from: &ElementRc,
+        target: *const (),
+        dependencies: &HashMap<*const (), Vec<ElementRc>>,
+        visited: &mut HashSet<*const ()>,
+    ) -> bool {
+        if !visited.insert(element_key(from)) {
+            return false;
+        }
+        dependencies.get(&element_key(from)).is_some_and(|deps| {
+            deps.iter()
+                .any(|d| element_key(d) == target || reaches(d, target, dependencies, visited))
+        })
+    }
+
+    let mut in_cycle = HashSet::new();
+    for elem in anchored {
+        if reaches(elem, element_key(elem), &dependencies, &mut HashSet::new()) {
+            in_cycle.insert(element_key(elem));
+            let elem = elem.borrow();
+            let binding = anchor_names().find_map(|a| elem.bindings.get(a)).unwrap();
+            diag.push_error(
+                format!(
+                    "The anchors of '{}' depend on its own position through the anchors of other elements",
+                    elem.id
+                ),
+                &*binding.borrow(),
+            );
+        }
+    }
+    in_cycle
+}
+
+fn lower_axis(elem: &ElementRc, axis: &Axis, diag: &mut BuildDiagnostics) {
+    let anchor = |name: &'static str| {
+        elem.borrow().bindings.contains_key(name).then(|| {
+            Expression::PropertyReference(NamedReference::new(elem, SmolStr::new_static(name)))
+        })
+    };
+    let (start, center, end) = (anchor(axis.start), anchor(axis.center), anchor(axis.end));
+    let Some(first) = [axis.start, axis.center, axis.end]
+        .into_iter()
+        .find(|a| elem.borrow().bindings.contains_key(*a))
+    else {
+        return;
+    };
+    let span = elem.borrow().bindings.get(first).unwrap().borrow().span.clone();
+
+    let mut error = |msg: String| {
+        let elem = elem.borrow();
+        diag.push_error(msg, &*elem.bindings.get(first).unwrap().borrow());
+    };
+    if center.is_some() && (start.is_some() || end.is_some()) {
+        let other = if start.is_some() { axis.start } else { axis.end };
+        error(format!("Cannot specify both '{other}' and '{}'", axis.center));
+        return;
+    }
+    if elem.borrow().bindings.contains_key(axis.pos) {
+        error(format!("Cannot specify both '{}' and '{first}'", axis.pos));
+        return;
+    }
+
+    let size =
+        || Expression::PropertyReference(NamedReference::new(elem, SmolStr::new_static(axis.size)));
+    let sub = |lhs: Expression, rhs: Expression| Expression::BinaryExpression {
+        lhs: Box::new(lhs),
+        rhs: Box::new(rhs),
+        op: '-',
+    };
+
+    let pos = match (start, center, end) {
+        (Some(start), _, Some(end)) => {
+            if elem.borrow().is_binding_set(axis.size, true) {
+                error(format!(
+                    "Cannot specify '{}' together with both '{}' and '{}'",
+                    axis.size, axis.start, axis.end
+                ));
+                return;
+            }
+            set_binding(elem, axis.size, sub(end, start.clone()), &span);
+            start
+        }
+        (Some(start), _, None) => start,
+        (None, _, Some(end)) => sub(end, size()),
+        (None, Some(center), None) => sub(
+            center,
+            Expression::BinaryExpression {
+                lhs: Box::new(size()),
+                rhs: Box::new(Expression::NumberLiteral(2., Unit::None)),
+                op: '/',
+            },
+        ),
+        (None, None, None) => return,
+    };
+    set_binding(elem, axis.pos, pos, &span);
+}
+
+fn set_binding(
+    elem: &ElementRc,
+    name: &'static str,
+    expression: Expression,
+    span: &Option<crate::diagnostics::SourceLocation>,
+) {
+    let mut binding = BindingExpression::from(expression);
+    binding.span = span.clone();
+    binding.priority = 1;
+    elem.borrow_mut().bindings.insert(SmolStr::new_static(name), binding.into());
+}
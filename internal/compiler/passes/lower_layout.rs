@@ -409,6 +409,17 @@ fn lower_box_layout(
         }
     }
 
+    let baseline_alignment = is_baseline_alignment(layout_element);
+    if baseline_alignment && orientation == Orientation::Vertical {
+        diag.push_warning(
+            "The baseline alignment is only supported in a HorizontalLayout, it behaves like stretch"
+                .into(),
+            &*layout_element.borrow().bindings["alignment"].borrow(),
+        );
+    }
+    // The elements aligned on their baseline, with their baseline and whether their height is fixed
+    let mut baseline_items = Vec::new();
+
     for layout_child in &layout_children {
         if let Some(item) = create_layout_item(layout_child, diag) {
             let index = layout.elems.len() * 2;
@@ -433,19 +444,40 @@ fn lower_box_layout(
                     diag,
                 );
             }
-            if let Some(pad_expr) = pad_expr.clone() {
-                actual_elem.borrow_mut().bindings.insert(pad.into(), RefCell::new(pad_expr.into()));
-            }
-            if !fixed_ortho {
-                actual_elem
-                    .borrow_mut()
-                    .bindings
-                    .insert(ortho.into(), RefCell::new(size_expr.clone().into()));
+            let baseline =
+                (baseline_alignment && orientation == Orientation::Horizontal && rep_idx.is_none())
+                    .then(|| baseline_expression(actual_elem))
+                    .flatten();
+            if let Some(baseline) = baseline {
+                baseline_items.push((actual_elem.clone(), baseline, fixed_ortho));
+            } else {
+                if let Some(pad_expr) = pad_expr.clone() {
+                    actual_elem
+                        .borrow_mut()
+                        .bindings
+                        .insert(pad.into(), RefCell::new(pad_expr.into()));
+                }
+                if !fixed_ortho {
+                    actual_elem
+                        .borrow_mut()
+                        .bindings
+                        .insert(ortho.into(), RefCell::new(size_expr.clone().into()));
+                }
             }
             layout.elems.push(item.item);
         }
     }
     layout_element.borrow_mut().children = layout_children;
+    let mut layout_info_v_expr =
+        Expression::ComputeLayoutInfo(Layout::BoxLayout(layout.clone()), Orientation::Vertical);
+    if !baseline_items.is_empty() {
+        layout_info_v_expr = lower_baseline_alignment(
+            layout_element,
+            &layout.geometry,
+            baseline_items,
+            layout_info_v_expr,
+        );
+    }
     let span = layout_element.borrow().to_source_location();
     layout_cache_prop.element().borrow_mut().bindings.insert(
         layout_cache_prop.name().clone(),
@@ -468,11 +500,7 @@ fn lower_box_layout(
     );
     layout_info_prop_v.element().borrow_mut().bindings.insert(
         layout_info_prop_v.name().clone(),
-        BindingExpression::new_with_span(
-            Expression::ComputeLayoutInfo(Layout::BoxLayout(layout.clone()), Orientation::Vertical),
-            span,
-        )
-        .into(),
+        BindingExpression::new_with_span(layout_info_v_expr, span).into(),
     );
     layout_element.borrow_mut().layout_info_prop = Some((layout_info_prop_h, layout_info_prop_v));
     for d in layout_element.borrow_mut().debug.iter_mut() {
@@ -480,6 +508,147 @@ fn lower_box_layout(
     }
 }
 
+/// Returns true if the `alignment` of the layout is set to the constant `LayoutAlignment.baseline`
+fn is_baseline_alignment(layout_element: &ElementRc) -> bool {
+    layout_element.borrow().bindings.get("alignment").is_some_and(|b| {
+        matches!(
+            super::ignore_debug_hooks(&b.borrow().expression),
+            Expression::EnumerationValue(v) if v.enumeration.values[v.value] == "baseline"
+        )
+    })
+}
+
+/// Returns the distance between the top of the element and the baseline of its text.
+///
+/// That's the `baseline` length property if the element declares one, or the font ascent for
+/// the `Text` and `TextInput` elements. Other elements don't participate in the baseline alignment.
+fn baseline_expression(elem: &ElementRc) -> Option<Expression> {
+    let (has_baseline, has_font_metrics) = {
+        let e = elem.borrow();
+        (
+            e.lookup_property("baseline").property_type == Type::LogicalLength,
+            matches!(e.lookup_property("font-metrics").property_type, Type::Struct(_)),
+        )
+    };
+    if has_baseline {
+        Some(Expression::PropertyReference(NamedReference::new(
+            elem,
+            SmolStr::new_static("baseline"),
+        )))
+    } else if has_font_metrics {
+        Some(Expression::StructFieldAccess {
+            base: Box::new(Expression::PropertyReference(NamedReference::new(
+                elem,
+                SmolStr::new_static("font-metrics"),
+            ))),
+            name: SmolStr::new_static("ascent"),
+        })
+    } else {
+        None
+    }
+}
+
+/// Position the elements of a HorizontalLayout with `alignment: baseline` vertically so that
+/// their baselines are on the same line, with their preferred height.
+///
+/// Returns the vertical layout info of the layout, adjusted so that the layout is high enough
+/// for the shifted elements.
+fn lower_baseline_alignment(
+    layout_element: &ElementRc,
+    geometry: &LayoutGeometry,
+    baseline_items: Vec<(ElementRc, Expression, bool)>,
+    layout_info_v_expr: Expression,
+) -> Expression {
+    let max = |lhs: Expression, rhs: Expression| Expression::MinMax {
+        ty: Type::LogicalLength,
+        op: MinMaxOp::Max,
+        lhs: Box::new(lhs),
+        rhs: Box::new(rhs),
+    };
+    let binary = |lhs: Expression, op: char, rhs: Expression| Expression::BinaryExpression {
+        lhs: Box::new(lhs),
+        rhs: Box::new(rhs),
+        op,
+    };
+
+    let mut baseline_expr = baseline_items
+        .iter()
+        .map(|(_, baseline, _)| baseline.clone())
+        .reduce(max)
+        .expect("baseline_items is not empty");
+    if let Some(p) = &geometry.padding.top {
+        baseline_expr = binary(Expression::PropertyReference(p.clone()), '+', baseline_expr);
+    }
+    let baseline_prop = create_new_prop(
+        layout_element,
+        SmolStr::new_static("layout-baseline"),
+        Type::LogicalLength,
+    );
+    layout_element
+        .borrow_mut()
+        .bindings
+        .insert(baseline_prop.name().clone(), RefCell::new(baseline_expr.into()));
+
+    let mut descent_expr = None;
+    for (elem, baseline, fixed_height) in baseline_items {
+        let y = binary(Expression::PropertyReference(baseline_prop.clone()), '-', baseline.clone());
+        elem.borrow_mut().bindings.insert("y".into(), RefCell::new(y.into()));
+        if !fixed_height {
+            let preferred = Expression::PropertyReference(NamedReference::new(
+                &elem,
+                SmolStr::new_static("preferred-height"),
+            ));
+            elem.borrow_mut().bindings.insert("height".into(), RefCell::new(preferred.into()));
+        }
+        let height = Expression::PropertyReference(NamedReference::new(
+            &elem,
+            SmolStr::new_static("height"),
+        ));
+        let descent = binary(height, '-', baseline);
+        descent_expr = Some(match descent_expr {
+            Some(d) => max(d, descent),
+            None => descent,
+        });
+    }
+
+    // The layout needs room for the highest ascent and the lowest descent
+    let mut required_height = binary(
+        Expression::PropertyReference(baseline_prop),
+        '+',
+        descent_expr.expect("baseline_items is not empty"),
+    );
+    if let Some(p) = &geometry.padding.bottom {
+        required_height = binary(required_height, '+', Expression::PropertyReference(p.clone()));
+    }
+
+    let layout_info_content_prop = create_new_prop(
+        layout_element,
+        SmolStr::new_static("layoutinfo-v-content"),
+        layout_info_type().into(),
+    );
+    layout_element
+        .borrow_mut()
+        .bindings
+        .insert(layout_info_content_prop.name().clone(), RefCell::new(layout_info_v_expr.into()));
+    let layout_info_ty = layout_info_type();
+    let values = layout_info_ty
+        .fields
+        .keys()
+        .map(|name| {
+            let field = Expression::StructFieldAccess {
+                base: Box::new(Expression::PropertyReference(layout_info_content_prop.clone())),
+                name: name.clone(),
+            };
+            let value = match name.as_str() {
+                "min" | "preferred" => max(field, required_height.clone()),
+                _ => field,
+            };
+            (name.clone(), value)
+        })
+        .collect();
+    Expression::Struct { ty: layout_info_ty, values }
+}
+
 fn lower_dialog_layout(
     dialog_element: &ElementRc,
     style_metrics: &Rc<Component>,
@@ -622,6 +791,8 @@ fn lower_dialog_layout(
                                         )),
                                         visibility: PropertyVisibility::InOut,
                                         pure: None,
+                                        required: false,
+                                        deprecated: None,
                                     });
                             }
                         }
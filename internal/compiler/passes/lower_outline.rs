@@ -0,0 +1,100 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that lowers synthetic `outline-*` properties to a Rectangle drawn around the element
+//!
+//! The outline is added as the last child of the element, so that it is drawn over the element
+//! and its children. It runs after the layouts and the clip were lowered, so the outline neither
+//! takes part in the layout nor gets clipped by the element.
+
+use crate::expression_tree::{Expression, NamedReference};
+use crate::langtype::Type;
+use crate::object_tree::{Component, Element, ElementRc};
+use crate::typeregister::TypeRegister;
+use smol_str::{format_smolstr, SmolStr};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub fn lower_outline_properties(component: &Rc<Component>, type_register: &TypeRegister) {
+    crate::object_tree::recurse_elem_including_sub_components_no_borrow(
+        component,
+        &(),
+        &mut |elem, _| {
+            if has_outline(elem) {
+                create_outline_element(elem, type_register);
+            }
+        },
+    )
+}
+
+fn has_outline(elem: &ElementRc) -> bool {
+    let elem = elem.borrow();
+    ["outline-width", "outline-offset", "outline-color", "outline-style"].iter().any(|prop| {
+        elem.bindings.contains_key(*prop)
+            || elem.property_analysis.borrow().get(*prop).is_some_and(|a| a.is_set || a.is_linked)
+    })
+}
+
+fn create_outline_element(elem: &ElementRc, type_register: &TypeRegister) {
+    let prop = |name: &'static str| {
+        Box::new(Expression::PropertyReference(NamedReference::new(
+            elem,
+            SmolStr::new_static(name),
+        )))
+    };
+    // The distance between the element bounds and the outer edge of the outline
+    let extent = || Expression::BinaryExpression {
+        lhs: prop("outline-offset"),
+        rhs: prop("outline-width"),
+        op: '+',
+    };
+    let position = || Expression::UnaryOp { sub: Box::new(extent()), op: '-' };
+    let size = |name: &'static str| Expression::BinaryExpression {
+        lhs: prop(name),
+        rhs: Box::new(Expression::BinaryExpression {
+            lhs: Box::new(Expression::NumberLiteral(2., crate::expression_tree::Unit::None)),
+            rhs: Box::new(extent()),
+            op: '*',
+        }),
+        op: '+',
+    };
+
+    let mut bindings = [
+        ("x", position()),
+        ("y", position()),
+        ("width", size("width")),
+        ("height", size("height")),
+        ("border-width", *prop("outline-width")),
+        ("border-color", *prop("outline-color")),
+        ("border-style", *prop("outline-style")),
+    ]
+    .into_iter()
+    .map(|(name, expr)| (SmolStr::new_static(name), expr))
+    .collect::<Vec<_>>();
+
+    // The outline follows the rounded corners of the element
+    if elem.borrow().lookup_property("border-radius").property_type == Type::LogicalLength
+        && elem.borrow().is_binding_set("border-radius", false)
+    {
+        bindings.push((
+            SmolStr::new_static("border-radius"),
+            Expression::BinaryExpression {
+                lhs: prop("border-radius"),
+                rhs: Box::new(extent()),
+                op: '+',
+            },
+        ));
+    }
+
+    let outline = Element::make_rc(Element {
+        id: format_smolstr!("{}-outline", elem.borrow().id),
+        base_type: type_register.lookup_builtin_element("Rectangle").unwrap(),
+        enclosing_component: elem.borrow().enclosing_component.clone(),
+        bindings: bindings
+            .into_iter()
+            .map(|(name, expr)| (name, RefCell::new(expr.into())))
+            .collect(),
+        ..Default::default()
+    });
+    elem.borrow_mut().children.push(outline);
+}
@@ -0,0 +1,73 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This pass lowers the access to the properties of the global System to builtin function calls.
+
+use crate::expression_tree::{BuiltinFunction, Expression};
+use crate::namedreference::NamedReference;
+use crate::object_tree::{visit_all_expressions, Component};
+use std::rc::Rc;
+
+pub fn lower_system(component: &Rc<Component>) {
+    visit_all_expressions(component, |e, _| {
+        e.visit_recursive_mut(&mut |e| match e {
+            Expression::PropertyReference(nr) if is_system_property(nr) => {
+                *e = getter(nr.name());
+            }
+            Expression::SelfAssignment { lhs, rhs, op, .. } => {
+                if matches!(&**lhs, Expression::PropertyReference(nr) if is_system_property(nr) && nr.name() == "tick-interval")
+                {
+                    let mut rhs = std::mem::take(&mut **rhs);
+                    if *op != '=' {
+                        rhs = Expression::BinaryExpression {
+                            lhs: getter("tick-interval").into(),
+                            rhs: rhs.into(),
+                            op: *op,
+                        };
+                    }
+                    *e = Expression::FunctionCall {
+                        function: BuiltinFunction::SetSystemTickInterval.into(),
+                        arguments: vec![rhs],
+                        source_location: None,
+                    };
+                }
+            }
+            _ => {}
+        })
+    })
+}
+
+fn is_system_property(nr: &NamedReference) -> bool {
+    nr.element().borrow().builtin_type().is_some_and(|bt| bt.name == "System")
+}
+
+fn getter(name: &str) -> Expression {
+    let call = |function: BuiltinFunction| Expression::FunctionCall {
+        function: function.into(),
+        arguments: vec![],
+        source_location: None,
+    };
+    match name {
+        "time-of-day" => call(BuiltinFunction::SystemTimeOfDay),
+        "unix-time" => call(BuiltinFunction::SystemUnixTime),
+        "utc-offset" => call(BuiltinFunction::SystemUtcOffset),
+        "time-zone" => call(BuiltinFunction::SystemTimeZone),
+        "tick-interval" => call(BuiltinFunction::SystemTickInterval),
+        "locale" => call(BuiltinFunction::SystemLocale),
+        "network-online" => call(BuiltinFunction::SystemNetworkOnline),
+        "on-battery" => call(BuiltinFunction::SystemOnBattery),
+        "battery-level" => call(BuiltinFunction::SystemBatteryLevel),
+        "dark-mode" => {
+            let dark = crate::typeregister::BUILTIN
+                .with(|e| e.enums.ColorScheme.clone())
+                .try_value_from_string("dark")
+                .unwrap();
+            Expression::BinaryExpression {
+                lhs: call(BuiltinFunction::ColorScheme).into(),
+                rhs: Expression::EnumerationValue(dark).into(),
+                op: '=',
+            }
+        }
+        _ => unreachable!("Unknown property System.{name}"),
+    }
+}
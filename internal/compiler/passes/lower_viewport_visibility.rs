@@ -0,0 +1,106 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This pass handles the `visible-in-viewport` property and the `entered-viewport`
+//! and `left-viewport` callbacks.
+//!
+//! The callbacks are turned into a change callback on `visible-in-viewport` (plus init code
+//! for the initial state), and `visible-in-viewport` gets a binding that asks the runtime
+//! whether the item intersects the window and all of its clipping ancestors.
+
+use smol_str::SmolStr;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::expression_tree::{BuiltinFunction, Callable, Expression};
+use crate::langtype::ElementType;
+use crate::namedreference::NamedReference;
+use crate::object_tree::{recurse_elem, Component, ElementRc};
+
+/// Must be run before collect_init_code
+pub fn lower_viewport_callbacks(component: &Rc<Component>) {
+    recurse_elem(&component.root_element, &(), &mut |elem, _| {
+        if elem.borrow().repeated.is_some() {
+            if let ElementType::Component(base) = &elem.borrow().base_type {
+                if base.parent_element.upgrade().is_some() {
+                    lower_viewport_callbacks(base);
+                }
+            }
+        }
+        lower_callbacks_for_element(elem);
+    });
+    for popup in component.popup_windows.borrow().iter() {
+        lower_viewport_callbacks(&popup.component);
+    }
+}
+
+fn lower_callbacks_for_element(elem: &ElementRc) {
+    let call = |name: &'static str| {
+        elem.borrow().bindings.contains_key(name).then(|| Expression::FunctionCall {
+            function: Callable::Callback(NamedReference::new(elem, SmolStr::new_static(name))),
+            arguments: vec![],
+            source_location: None,
+        })
+    };
+    let entered = call("entered-viewport");
+    let left = call("left-viewport");
+    if entered.is_none() && left.is_none() {
+        return;
+    }
+
+    let visible = Box::new(Expression::PropertyReference(NamedReference::new(
+        elem,
+        SmolStr::new_static("visible-in-viewport"),
+    )));
+    let no_op = || Box::new(Expression::CodeBlock(vec![]));
+
+    let mut elem = elem.borrow_mut();
+    elem.change_callbacks
+        .entry(SmolStr::new_static("visible-in-viewport"))
+        .or_default()
+        .get_mut()
+        .push(Expression::Condition {
+            condition: visible.clone(),
+            true_expr: entered.clone().map_or_else(no_op, Box::new),
+            false_expr: left.map_or_else(no_op, Box::new),
+        });
+
+    // Change callbacks are not invoked for the initial value, so report it from init
+    if let Some(entered) = entered {
+        let on_init = Expression::Condition {
+            condition: visible,
+            true_expr: Box::new(entered),
+            false_expr: no_op(),
+        };
+        match elem.bindings.get_mut("init") {
+            Some(init) => {
+                let init = &mut init.get_mut().expression;
+                *init = Expression::CodeBlock(vec![std::mem::take(init), on_init]);
+            }
+            None => {
+                elem.bindings.insert("init".into(), RefCell::new(on_init.into()));
+            }
+        }
+    }
+}
+
+/// Must be run after the flickable pass, like lower_absolute_coordinates
+pub fn lower_visible_in_viewport(component: &Rc<Component>) {
+    let mut to_materialize = std::collections::HashSet::new();
+
+    crate::object_tree::visit_all_named_references(component, &mut |nr| {
+        if nr.name() == "visible-in-viewport" {
+            to_materialize.insert(nr.clone());
+        }
+    });
+
+    for nr in to_materialize {
+        let elem = nr.element();
+        let binding = Expression::FunctionCall {
+            function: BuiltinFunction::ItemVisibleInViewport.into(),
+            arguments: vec![Expression::ElementReference(Rc::downgrade(&elem))],
+            source_location: None,
+        };
+        elem.borrow_mut().bindings.insert(nr.name().clone(), RefCell::new(binding.into()));
+    }
+}
@@ -67,7 +67,10 @@ fn can_optimize(elem: &ElementRc) -> bool {
             return false;
         }
     }
-    if analysis.get("absolute-position").is_some_and(|a| a.is_read) {
+    if ["absolute-position", "visible-in-viewport"]
+        .iter()
+        .any(|p| analysis.get(*p).is_some_and(|a| a.is_read))
+    {
         return false;
     }
 
@@ -116,9 +116,10 @@ pub fn resolve_expressions(
     type_loader: &crate::typeloader::TypeLoader,
     diag: &mut BuildDiagnostics,
 ) {
-    resolve_two_way_bindings(doc, &doc.local_registry, diag);
+    resolve_two_way_bindings(doc, diag);
 
     for component in doc.inner_components.iter() {
+        let type_register = doc.type_register_for(component);
         recurse_elem_with_scope(
             &component.root_element,
             ComponentScope(vec![]),
@@ -146,7 +147,7 @@ pub fn resolve_expressions(
                         property_name,
                         property_type(),
                         scope,
-                        &doc.local_registry,
+                        type_register,
                         type_loader,
                         diag,
                     );
@@ -356,6 +357,7 @@ pub fn from_expression_node(node: syntax_nodes::Expression, ctx: &mut LookupCtx)
                     SyntaxKind::AtImageUrl => Some(Self::from_at_image_url_node(node.into(), ctx)),
                     SyntaxKind::AtGradient => Some(Self::from_at_gradient(node.into(), ctx)),
                     SyntaxKind::AtTr => Some(Self::from_at_tr(node.into(), ctx)),
+                    SyntaxKind::AtOpenUrl => Some(Self::from_at_open_url(node.into(), ctx)),
                     SyntaxKind::QualifiedName => Some(Self::from_qualified_name_node(
                         node.clone().into(),
                         ctx,
@@ -468,6 +470,18 @@ fn from_at_image_url_node(node: syntax_nodes::AtImageUrl, ctx: &mut LookupCtx) -
             }
         };
 
+        if let Some(sandbox) =
+            ctx.type_loader.and_then(|loader| loader.compiler_config.sandbox.as_ref())
+        {
+            if !sandbox.allows_path(std::path::Path::new(&absolute_source_path)) {
+                ctx.diag.push_error(
+                    format!("Loading the image \"{absolute_source_path}\" is not allowed in the sandbox"),
+                    &node,
+                );
+                return Self::Invalid;
+            }
+        }
+
         let nine_slice = node
             .children_with_tokens()
             .filter_map(|n| n.into_token())
@@ -704,6 +718,20 @@ enum Stop {
         }
     }
 
+    fn from_at_open_url(node: syntax_nodes::AtOpenUrl, ctx: &mut LookupCtx) -> Expression {
+        let n = node.Expression();
+        let url = Expression::from_expression_node(n.clone(), ctx).maybe_convert_to(
+            Type::String,
+            &n,
+            ctx.diag,
+        );
+        Expression::FunctionCall {
+            function: BuiltinFunction::OpenUrl.into(),
+            arguments: vec![url],
+            source_location: Some(node.to_source_location()),
+        }
+    }
+
     fn from_at_tr(node: syntax_nodes::AtTr, ctx: &mut LookupCtx) -> Expression {
         let Some(string) = node
             .child_text(SyntaxKind::StringLiteral)
@@ -1659,6 +1687,8 @@ fn continue_lookup_within_element(
         } else {
             LookupResult::from(callable).into()
         }
+    } else if let Some(edge) = crate::lookup::anchor_edge(elem, ctx, &prop_name) {
+        maybe_lookup_object(edge.into(), it, ctx)
     } else {
         let mut err = |extra: &str| {
             let what = match &elem.borrow().base_type {
@@ -1769,12 +1799,9 @@ fn maybe_lookup_object(
 }
 
 /// Go through all the two way binding and resolve them first
-fn resolve_two_way_bindings(
-    doc: &Document,
-    type_register: &TypeRegister,
-    diag: &mut BuildDiagnostics,
-) {
+fn resolve_two_way_bindings(doc: &Document, diag: &mut BuildDiagnostics) {
     for component in doc.inner_components.iter() {
+        let type_register = doc.type_register_for(component);
         recurse_elem_with_scope(
             &component.root_element,
             ComponentScope(vec![]),
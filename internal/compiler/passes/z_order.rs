@@ -2,6 +2,10 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 /*! re-order the children by their z-order
+
+When the `z` of all the children of an element are constant, the children are re-ordered at compile time.
+Otherwise, the `z` property is lowered to a `ZOrder` element (see lower_property_to_element) and the
+children are sorted at runtime.
 */
 
 use std::rc::Rc;
@@ -18,13 +22,22 @@ pub fn reorder_by_z_order(root_component: &Rc<Component>, diag: &mut BuildDiagno
         &mut |elem: &ElementRc, _| {
             reorder_children_by_zorder(elem, diag);
         },
-    )
+    );
+    // The root element isn't sorted with siblings, so a constant z has no effect there
+    let root = &root_component.root_element;
+    if !has_dynamic_z(root) {
+        root.borrow_mut().bindings.remove("z");
+    }
 }
 
 fn reorder_children_by_zorder(
     elem: &Rc<std::cell::RefCell<crate::object_tree::Element>>,
     diag: &mut BuildDiagnostics,
 ) {
+    if elem.borrow().children.iter().any(has_dynamic_z) {
+        return;
+    }
+
     // maps indexes to their z order
     let mut children_z_order = vec![];
     for (idx, child_elm) in elem.borrow().children.iter().enumerate() {
@@ -68,6 +81,35 @@ fn reorder_children_by_zorder(
     }
 }
 
+/// Returns true if the z of this child can change at runtime
+fn has_dynamic_z(child: &ElementRc) -> bool {
+    let is_dynamic = |e: &ElementRc| {
+        let e = e.borrow();
+        e.bindings.get("z").is_some_and(|b| {
+            let b = b.borrow();
+            !b.two_way_bindings.is_empty() || !is_const_expr(&b.expression)
+        }) || e.property_analysis.borrow().get("z").is_some_and(|a| a.is_set || a.is_linked)
+    };
+    if is_dynamic(child) {
+        return true;
+    }
+    if child.borrow().repeated.is_some() {
+        if let ElementType::Component(c) = &child.borrow().base_type {
+            return is_dynamic(&c.root_element);
+        }
+    }
+    false
+}
+
+fn is_const_expr(expression: &Expression) -> bool {
+    match super::ignore_debug_hooks(expression) {
+        Expression::NumberLiteral(_, Unit::None) => true,
+        Expression::Cast { from, .. } => is_const_expr(from),
+        Expression::UnaryOp { sub, op: '-' | '+' } => is_const_expr(sub),
+        _ => false,
+    }
+}
+
 fn eval_const_expr(
     expression: &Expression,
     name: &str,
@@ -0,0 +1,352 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Support for the `slint.toml` project configuration.
+//!
+//! A `slint.toml` file configures the compilation of all the `.slint` files in its directory and
+//! sub-directories. The build script, the language server, the viewer and the interpreter look for it,
+//! so the include paths, library paths, style, features and lints only need to be configured once:
+//!
+//! ```toml
+//! style = "fluent"
+//! include-paths = ["ui", "../shared/ui"]
+//!
+//! [library-paths]
+//! material = "libs/material/material.slint"
+//!
+//! [features]
+//! experimental = true
+//!
+//! [lints]
+//! warnings = "deny"
+//! ```
+//!
+//! The relative paths are relative to the directory of the `slint.toml` file.
+
+use crate::diagnostics::{Diagnostic, DiagnosticLevel, SourceFileInner, SourceLocation, Span};
+use crate::CompilerConfiguration;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+/// The name of the project configuration file.
+pub const FILE_NAME: &str = "slint.toml";
+
+/// The level of a lint in the `[lints]` table.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LintLevel {
+    /// Report the diagnostics as warnings. This is the default.
+    Warn,
+    /// Report the diagnostics as errors.
+    Deny,
+}
+
+/// The settings of a `slint.toml` file, with the paths resolved relative to its directory.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProjectConfiguration {
+    /// The path of the `slint.toml` file.
+    pub path: PathBuf,
+    /// The `include-paths` array.
+    pub include_paths: Vec<PathBuf>,
+    /// The `[library-paths]` table.
+    pub library_paths: HashMap<String, PathBuf>,
+    /// The `style` key.
+    pub style: Option<String>,
+    /// The `experimental` key of the `[features]` table.
+    pub enable_experimental: Option<bool>,
+    /// The `accessibility` key of the `[features]` table.
+    pub accessibility: Option<bool>,
+    /// The `debug-info` key of the `[features]` table.
+    pub debug_info: Option<bool>,
+    /// The `warnings` key of the `[lints]` table.
+    pub warnings: Option<LintLevel>,
+    /// The `binding-loop-with-window-layout` key of the `[lints]` table.
+    pub binding_loop_with_window_layout: Option<LintLevel>,
+}
+
+impl ProjectConfiguration {
+    /// Returns the path of the `slint.toml` file that applies to `path`: the first one found in the
+    /// directory of `path` or in one of its parent directories.
+    ///
+    /// The search doesn't go further up than the root of the project: the Cargo workspace (a
+    /// directory with a `Cargo.toml` that has a `[workspace]` table) or the version control
+    /// repository (a directory with `.git`), or else the Cargo package that contains `path`.
+    pub fn find(path: &Path) -> Option<PathBuf> {
+        let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        let root = project_root(&path);
+        for dir in path.ancestors() {
+            let candidate = dir.join(FILE_NAME);
+            if candidate.is_file() {
+                return Some(candidate);
+            }
+            if root == Some(dir) {
+                break;
+            }
+        }
+        None
+    }
+
+    /// Finds the `slint.toml` file that applies to `path` with [`Self::find()`] and loads it.
+    ///
+    /// Returns `Ok(None)` if there is no such file.
+    pub fn find_and_load(path: &Path) -> Result<Option<Self>, Diagnostic> {
+        Self::find(path).map(|path| Self::load(&path)).transpose()
+    }
+
+    /// Loads the `slint.toml` file at `path`.
+    pub fn load(path: &Path) -> Result<Self, Diagnostic> {
+        let source = std::fs::read_to_string(path).map_err(|err| Diagnostic {
+            message: format!("Could not load {}: {}", path.display(), err),
+            span: SourceLocation {
+                source_file: Some(SourceFileInner::from_path_only(path.to_owned())),
+                span: Default::default(),
+            },
+            level: DiagnosticLevel::Error,
+        })?;
+        Self::parse(path.to_owned(), source)
+    }
+
+    /// Parses the `source` of the `slint.toml` file at `path`.
+    pub fn parse(path: PathBuf, source: String) -> Result<Self, Diagnostic> {
+        let document = toml_edit::Document::parse(source.as_str());
+        let source_file = Rc::new(SourceFileInner::new(path.clone(), source.clone()));
+        let error = |message: String, span: Option<std::ops::Range<usize>>| Diagnostic {
+            message,
+            span: SourceLocation {
+                source_file: Some(source_file.clone()),
+                span: Span::new(span.map_or(0, |span| span.start)),
+            },
+            level: DiagnosticLevel::Error,
+        };
+        let document = document.map_err(|e| error(e.message().trim().to_string(), e.span()))?;
+        // The span of the key of an entry of the table, for errors about the key itself
+        let key_span = |table: &dyn toml_edit::TableLike, key: &str| {
+            table.get_key_value(key).and_then(|(key, _)| key.span())
+        };
+
+        let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        let mut result = Self { path, ..Default::default() };
+        for (key, item) in document.iter() {
+            match key {
+                "style" => {
+                    let style = item
+                        .as_str()
+                        .ok_or_else(|| error("'style' must be a string".into(), item.span()))?;
+                    result.style = Some(style.into());
+                }
+                "include-paths" => {
+                    let paths = item.as_array().ok_or_else(|| {
+                        error("'include-paths' must be an array".into(), item.span())
+                    })?;
+                    for path in paths.iter() {
+                        let path = path.as_str().ok_or_else(|| {
+                            error("'include-paths' must only contain strings".into(), path.span())
+                        })?;
+                        result.include_paths.push(dir.join(path));
+                    }
+                }
+                "library-paths" => {
+                    let table = item.as_table_like().ok_or_else(|| {
+                        error("'library-paths' must be a table".into(), item.span())
+                    })?;
+                    for (library, path) in table.iter() {
+                        let path = path.as_str().ok_or_else(|| {
+                            error(
+                                format!("The path of the library '{library}' must be a string"),
+                                path.span(),
+                            )
+                        })?;
+                        result.library_paths.insert(library.into(), dir.join(path));
+                    }
+                }
+                "features" => {
+                    let table = item
+                        .as_table_like()
+                        .ok_or_else(|| error("'features' must be a table".into(), item.span()))?;
+                    for (feature, value) in table.iter() {
+                        let value = value.as_bool().ok_or_else(|| {
+                            error(
+                                format!("The feature '{feature}' must be true or false"),
+                                value.span(),
+                            )
+                        })?;
+                        match feature {
+                            "experimental" => result.enable_experimental = Some(value),
+                            "accessibility" => result.accessibility = Some(value),
+                            "debug-info" => result.debug_info = Some(value),
+                            _ => {
+                                return Err(error(
+                                    format!("Unknown feature '{feature}'"),
+                                    key_span(table, feature),
+                                ))
+                            }
+                        }
+                    }
+                }
+                "lints" => {
+                    let table = item
+                        .as_table_like()
+                        .ok_or_else(|| error("'lints' must be a table".into(), item.span()))?;
+                    for (lint, level) in table.iter() {
+                        let level = match level.as_str() {
+                            Some("warn") => LintLevel::Warn,
+                            Some("deny") => LintLevel::Deny,
+                            _ => {
+                                let message = format!(
+                                    "The level of the lint '{lint}' must be \"warn\" or \"deny\""
+                                );
+                                return Err(error(message, level.span()));
+                            }
+                        };
+                        match lint {
+                            "warnings" => result.warnings = Some(level),
+                            "binding-loop-with-window-layout" => {
+                                result.binding_loop_with_window_layout = Some(level)
+                            }
+                            _ => {
+                                return Err(error(
+                                    format!("Unknown lint '{lint}'"),
+                                    key_span(table, lint),
+                                ))
+                            }
+                        }
+                    }
+                }
+                _ => return Err(error(format!("Unknown key '{key}'"), key_span(&*document, key))),
+            }
+        }
+        Ok(result)
+    }
+
+    /// Applies the settings to the compiler configuration.
+    ///
+    /// The settings of the compiler configuration have precedence: the include paths are appended,
+    /// the library paths are only added for the libraries that aren't configured yet, and the style is
+    /// only set if the configuration doesn't select one. The features and lints that are enabled in
+    /// either place are enabled.
+    ///
+    /// Applying the same project configuration several times doesn't add its include paths again.
+    pub fn apply(&self, config: &mut CompilerConfiguration) {
+        for path in &self.include_paths {
+            if !config.include_paths.contains(path) {
+                config.include_paths.push(path.clone());
+            }
+        }
+        for (library, path) in &self.library_paths {
+            config.library_paths.entry(library.clone()).or_insert_with(|| path.clone());
+        }
+        if config.style.is_none() {
+            config.style = self.style.clone();
+        }
+        if self.enable_experimental == Some(true) {
+            config.enable_experimental = true;
+        }
+        if self.accessibility == Some(false) {
+            config.accessibility = false;
+        }
+        if self.debug_info == Some(true) {
+            config.debug_info = true;
+        }
+        if self.warnings == Some(LintLevel::Deny) {
+            config.deny_warnings = true;
+        }
+        if self.binding_loop_with_window_layout == Some(LintLevel::Deny) {
+            config.error_on_binding_loop_with_window_layout = true;
+        }
+    }
+}
+
+/// Returns the root of the project that contains `path`, where the search for `slint.toml` stops.
+fn project_root(path: &Path) -> Option<&Path> {
+    let is_cargo_workspace = |dir: &Path| {
+        std::fs::read_to_string(dir.join("Cargo.toml")).ok().is_some_and(|manifest| {
+            manifest
+                .parse::<toml_edit::DocumentMut>()
+                .is_ok_and(|manifest| manifest.contains_key("workspace"))
+        })
+    };
+    path.ancestors()
+        .find(|dir| dir.join(".git").exists() || is_cargo_workspace(dir))
+        .or_else(|| path.ancestors().find(|dir| dir.join("Cargo.toml").is_file()))
+}
+
+#[test]
+fn test_parse_project_configuration() {
+    let source = r#"
+style = "fluent"
+include-paths = ["ui", "../shared"]
+
+[library-paths]
+material = "libs/material.slint"
+
+[features]
+experimental = true
+
+[lints]
+warnings = "deny"
+"#;
+    let project =
+        ProjectConfiguration::parse(PathBuf::from("/project/slint.toml"), source.into()).unwrap();
+    assert_eq!(project.style.as_deref(), Some("fluent"));
+    assert_eq!(
+        project.include_paths,
+        [PathBuf::from("/project/ui"), PathBuf::from("/project/../shared")]
+    );
+    assert_eq!(project.library_paths["material"], PathBuf::from("/project/libs/material.slint"));
+    assert_eq!(project.enable_experimental, Some(true));
+    assert_eq!(project.warnings, Some(LintLevel::Deny));
+
+    let mut config = CompilerConfiguration::new(crate::generator::OutputFormat::Interpreter);
+    config.include_paths = vec![PathBuf::from("/explicit")];
+    config.style = Some("material".into());
+    project.apply(&mut config);
+    assert_eq!(config.include_paths[0], PathBuf::from("/explicit"));
+    assert_eq!(config.include_paths.len(), 3);
+    project.apply(&mut config);
+    assert_eq!(config.include_paths.len(), 3);
+    assert_eq!(config.style.as_deref(), Some("material"));
+    assert!(config.enable_experimental);
+    assert!(config.deny_warnings);
+
+    let error = ProjectConfiguration::parse(
+        PathBuf::from("/project/slint.toml"),
+        "[lints]\nwarnings = \"forbid\"\n".into(),
+    )
+    .unwrap_err();
+    assert_eq!(error.message(), "The level of the lint 'warnings' must be \"warn\" or \"deny\"");
+    assert_eq!(error.line_column(), (2, 12));
+    let error = ProjectConfiguration::parse(
+        PathBuf::from("/project/slint.toml"),
+        "style = \"fluent\"\n[features]\nexperimental = true\nfast = true\n".into(),
+    )
+    .unwrap_err();
+    assert_eq!(error.message(), "Unknown feature 'fast'");
+    assert_eq!(error.line_column(), (4, 1));
+    assert!(ProjectConfiguration::parse(PathBuf::from("slint.toml"), "style = [".into()).is_err());
+}
+
+#[test]
+fn test_find_project_configuration() {
+    let dir =
+        std::env::temp_dir().join(format!("slint-project-config-test-{}", std::process::id()));
+    let workspace = dir.join("workspace");
+    let package = workspace.join("app");
+    std::fs::create_dir_all(package.join("ui")).unwrap();
+    std::fs::write(dir.join(FILE_NAME), "style = \"outside\"\n").unwrap();
+    std::fs::write(workspace.join("Cargo.toml"), "[workspace]\nmembers = [\"app\"]\n").unwrap();
+    std::fs::write(package.join("Cargo.toml"), "[package]\nname = \"app\"\n").unwrap();
+
+    // The slint.toml above the workspace root doesn't apply
+    assert_eq!(ProjectConfiguration::find(&package.join("ui/main.slint")), None);
+
+    std::fs::write(workspace.join(FILE_NAME), "style = \"fluent\"\n").unwrap();
+    assert_eq!(
+        ProjectConfiguration::find(&package.join("ui/main.slint")),
+        Some(workspace.join(FILE_NAME))
+    );
+    std::fs::write(package.join(FILE_NAME), "style = \"cupertino\"\n").unwrap();
+    let project = ProjectConfiguration::find_and_load(&package.join("ui/main.slint")).unwrap();
+    assert_eq!(project.unwrap().style.as_deref(), Some("cupertino"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
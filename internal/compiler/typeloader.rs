@@ -300,6 +300,11 @@ fn snapshot_document(&mut self, document: &object_tree::Document) -> object_tree
             inner_components,
             inner_types: document.inner_types.clone(),
             local_registry: document.local_registry.snapshot(self),
+            scoped_registries: document
+                .scoped_registries
+                .iter()
+                .map(|(style, registry)| (style.clone(), registry.snapshot(self)))
+                .collect(),
             custom_fonts: document.custom_fonts.clone(),
             imports: document.imports.clone(),
             exports,
@@ -384,6 +389,8 @@ pub(crate) fn create_component(
                 root_constraints,
                 root_element,
                 from_library: core::cell::Cell::new(false),
+                deprecated: component.deprecated.clone(),
+                style: component.style.clone(),
             }
         });
         self.keep_alive.push((component.clone(), result.clone()));
@@ -538,6 +545,8 @@ fn snapshot_element(
                     is_alias: v.is_alias.as_ref().map(|a| a.snapshot(self)),
                     visibility: v.visibility,
                     pure: v.pure,
+                    required: v.required,
+                    deprecated: v.deprecated.clone(),
                 };
                 (k.clone(), decl)
             })
@@ -980,20 +989,17 @@ pub async fn load_dependencies_recursively<'a>(
         registry_to_populate: &'a Rc<RefCell<TypeRegister>>,
     ) -> (Vec<ImportedTypes>, Exports) {
         let state = RefCell::new(BorrowedTypeLoader { tl: self, diag });
-        Self::load_dependencies_recursively_impl(
-            &state,
-            doc,
-            registry_to_populate,
-            &Default::default(),
-        )
-        .await
+        let (imports, reexports) =
+            Self::load_dependencies_recursively_impl(&state, doc, registry_to_populate, &[]).await;
+        Self::load_scoped_styles(&state, doc, &imports, registry_to_populate, &[]).await;
+        (imports, reexports)
     }
 
     async fn load_dependencies_recursively_impl<'a: 'b, 'b>(
         state: &'a RefCell<BorrowedTypeLoader<'a>>,
         doc: &'b syntax_nodes::Document,
         registry_to_populate: &'b Rc<RefCell<TypeRegister>>,
-        import_stack: &'b HashSet<PathBuf>,
+        import_stack: &'b [PathBuf],
     ) -> (Vec<ImportedTypes>, Exports) {
         let mut imports = vec![];
         let mut dependencies_futures = vec![];
@@ -1005,6 +1011,16 @@ async fn load_dependencies_recursively_impl<'a: 'b, 'b>(
                 ) {
                     import.file = path.to_string_lossy().into_owned();
                 };
+                let mut state = state.borrow_mut();
+                if let Some(sandbox) = &state.tl.compiler_config.sandbox {
+                    if !sandbox.allows_path(Path::new(&import.file)) {
+                        state.diag.push_error(
+                            format!("Importing \"{}\" is not allowed in the sandbox", import.file),
+                            &import.import_uri_token,
+                        );
+                        continue;
+                    }
+                }
                 imports.push(import);
                 continue;
             }
@@ -1059,7 +1075,7 @@ async fn load_dependencies_recursively_impl<'a: 'b, 'b>(
                     state,
                     import.file.as_str(),
                     Some(import.import_uri_token.clone().into()),
-                    import_stack.clone(),
+                    import_stack.to_vec(),
                 )
                 .await;
                 (import, doc_path)
@@ -1095,38 +1111,49 @@ async fn load_dependencies_recursively_impl<'a: 'b, 'b>(
                     }
                     ImportKind::ModuleReexport(export_module_syntax_node) => {
                         let exports = reexports.get_or_insert_with(Exports::default);
-                        if let Some(star_reexport) = export_module_syntax_node.ExportModule().and_then(|x| x.child_token(SyntaxKind::Star))
-                        {
+                        // `export * from "foo"`, or `export { *, Foo as Bar } from "foo"`
+                        let star_reexport = export_module_syntax_node
+                            .ExportModule()
+                            .and_then(|x| x.child_token(SyntaxKind::Star))
+                            .or_else(|| export_module_syntax_node.child_token(SyntaxKind::Star));
+                        if let Some(star_reexport) = &star_reexport {
                             if has_star_reexport {
-                                state.diag.push_error("re-exporting modules is only allowed once per file".into(), &star_reexport);
+                                state.diag.push_error("re-exporting modules is only allowed once per file".into(), star_reexport);
                                 return false;
                             }
                             has_star_reexport = true;
+                        } else if export_module_syntax_node.ExportSpecifier().next().is_none() {
+                            state.diag.push_error("Import names are missing. Please specify which types you would like to re-export".into(), export_module_syntax_node);
+                        }
+                        let mut renamed = HashSet::new();
+                        let e = export_module_syntax_node
+                            .ExportSpecifier()
+                            .filter_map(|e| {
+                                let (imported_name, exported_name) = ExportedName::from_export_specifier(&e);
+                                let Some(r) = doc.exports.find(&imported_name) else {
+                                    state.diag.push_error(format!("No exported type called '{imported_name}' found in \"{}\"", doc_path.display()), &e);
+                                    return None;
+                                };
+                                renamed.insert(imported_name);
+                                // `Foo as _` hides `Foo` from a star re-export
+                                (exported_name.name != "_").then_some((exported_name, r))
+                            })
+                            .collect::<Vec<_>>();
+                        exports.add_reexports(e, state.diag);
+                        if star_reexport.is_some() {
                             exports.add_reexports(
-                                doc.exports.iter().map(|(exported_name, compo_or_type)| {
-                                    let exported_name = ExportedName {
-                                        name: exported_name.name.clone(),
-                                        name_ident: (**export_module_syntax_node).clone(),
-                                    };
-                                    (exported_name, compo_or_type.clone())
-                                }),
+                                doc.exports
+                                    .iter()
+                                    .filter(|(exported_name, _)| !renamed.contains(&exported_name.name))
+                                    .map(|(exported_name, compo_or_type)| {
+                                        let exported_name = ExportedName {
+                                            name: exported_name.name.clone(),
+                                            name_ident: (**export_module_syntax_node).clone(),
+                                        };
+                                        (exported_name, compo_or_type.clone())
+                                    }),
                                 state.diag,
                             );
-                        } else if export_module_syntax_node.ExportSpecifier().next().is_none() {
-                            state.diag.push_error("Import names are missing. Please specify which types you would like to re-export".into(), export_module_syntax_node);
-                        } else {
-                            let e = export_module_syntax_node
-                                .ExportSpecifier()
-                                .filter_map(|e| {
-                                    let (imported_name, exported_name) = ExportedName::from_export_specifier(&e);
-                                    let Some(r) = doc.exports.find(&imported_name) else {
-                                        state.diag.push_error(format!("No exported type called '{imported_name}' found in \"{}\"", doc_path.display()), &e);
-                                        return None;
-                                    };
-                                    Some((exported_name, r))
-                                })
-                                .collect::<Vec<_>>();
-                            exports.add_reexports(e, state.diag);
                         }
                     }
                     ImportKind::FileImport => {
@@ -1199,7 +1226,7 @@ async fn ensure_document_loaded<'a: 'b, 'b>(
         state: &'a RefCell<BorrowedTypeLoader<'a>>,
         file_to_import: &'b str,
         import_token: Option<NodeOrToken>,
-        mut import_stack: HashSet<PathBuf>,
+        mut import_stack: Vec<PathBuf>,
     ) -> Option<PathBuf> {
         let mut borrowed_state = state.borrow_mut();
 
@@ -1256,14 +1283,35 @@ async fn ensure_document_loaded<'a: 'b, 'b>(
             }
         };
 
-        if !import_stack.insert(path_canon.clone()) {
+        if builtin.is_none()
+            && borrowed_state
+                .tl
+                .compiler_config
+                .sandbox
+                .as_ref()
+                .is_some_and(|sandbox| !sandbox.allows_path(&path_canon))
+        {
             borrowed_state.diag.push_error(
-                format!("Recursive import of \"{}\"", path_canon.display()),
+                format!("Importing \"{file_to_import}\" is not allowed in the sandbox"),
                 &import_token,
             );
             return None;
         }
 
+        if let Some(pos) = import_stack.iter().position(|p| *p == path_canon) {
+            let cycle = import_stack[pos..]
+                .iter()
+                .chain(std::iter::once(&path_canon))
+                .map(|p| format!("\"{}\"", p.display()))
+                .join(" -> ");
+            borrowed_state.diag.push_error(
+                format!("Recursive import of \"{}\" (import cycle: {cycle})", path_canon.display()),
+                &import_token,
+            );
+            return None;
+        }
+        import_stack.push(path_canon.clone());
+
         drop(borrowed_state);
 
         let (is_loaded, doc_node) = core::future::poll_fn(|cx| {
@@ -1394,7 +1442,7 @@ pub async fn load_file(
         let doc_node: syntax_nodes::Document =
             crate::parser::parse(source_code, Some(source_path), diag).into();
         let state = RefCell::new(BorrowedTypeLoader { tl: self, diag });
-        Self::load_file_impl(&state, path, doc_node, is_builtin, &Default::default()).await;
+        Self::load_file_impl(&state, path, doc_node, is_builtin, &[]).await;
     }
 
     /// Reload a cached file
@@ -1411,7 +1459,7 @@ pub async fn reload_cached_file(&mut self, path: &Path, diag: &mut BuildDiagnost
             diag.push_internal_error(e.clone());
         }
         let state = RefCell::new(BorrowedTypeLoader { tl: self, diag });
-        Self::load_file_impl(&state, path, doc_node, false, &Default::default()).await;
+        Self::load_file_impl(&state, path, doc_node, false, &[]).await;
     }
 
     /// Load a file, and its dependency, running the full set of passes.
@@ -1430,8 +1478,7 @@ pub async fn load_root_file(
             crate::parser::parse(source_code, Some(source_path), diag).into();
         let parse_errors = diag.iter().cloned().collect();
         let state = RefCell::new(BorrowedTypeLoader { tl: self, diag });
-        let (path, mut doc) =
-            Self::load_doc_no_pass(&state, &path, doc_node, false, &Default::default()).await;
+        let (path, mut doc) = Self::load_doc_no_pass(&state, &path, doc_node, false, &[]).await;
 
         let mut state = state.borrow_mut();
         let state = &mut *state;
@@ -1453,7 +1500,7 @@ async fn load_file_impl<'a>(
         path: &Path,
         doc_node: syntax_nodes::Document,
         is_builtin: bool,
-        import_stack: &HashSet<PathBuf>,
+        import_stack: &[PathBuf],
     ) {
         let parse_errors = state
             .borrow()
@@ -1487,7 +1534,7 @@ async fn load_doc_no_pass<'a>(
         path: &Path,
         dependency_doc: syntax_nodes::Document,
         is_builtin: bool,
-        import_stack: &HashSet<PathBuf>,
+        import_stack: &[PathBuf],
     ) -> (PathBuf, Document) {
         let dependency_registry =
             Rc::new(RefCell::new(TypeRegister::new(&state.borrow().tl.global_type_registry)));
@@ -1500,6 +1547,14 @@ async fn load_doc_no_pass<'a>(
             import_stack,
         )
         .await;
+        Self::load_scoped_styles(
+            state,
+            &dependency_doc,
+            &imports,
+            &dependency_registry,
+            import_stack,
+        )
+        .await;
 
         if state.borrow().diag.has_errors() {
             // If there was error (esp parse error) we don't want to report further error in this document.
@@ -1528,9 +1583,94 @@ async fn load_doc_no_pass<'a>(
             state.diag,
             &dependency_registry,
         );
+        if is_builtin {
+            crate::passes::brand_palettes::declare_brand_property(
+                &doc,
+                &state.tl.global_type_registry.borrow(),
+            );
+        }
         (path.to_owned(), doc)
     }
 
+    /// Load the std-widgets of the styles selected with `style: "..."` in the components of the document,
+    /// and register the widgets that the document imports from "std-widgets.slint" for each of them.
+    ///
+    /// Unknown styles are ignored here, they are reported when creating the components.
+    async fn load_scoped_styles<'a: 'b, 'b>(
+        state: &'a RefCell<BorrowedTypeLoader<'a>>,
+        doc: &'b syntax_nodes::Document,
+        imports: &'b [ImportedTypes],
+        registry_to_populate: &'b Rc<RefCell<TypeRegister>>,
+        import_stack: &'b [PathBuf],
+    ) {
+        let mut styles = doc
+            .Component()
+            .chain(doc.ExportsList().filter_map(|e| e.Component()))
+            .filter_map(|c| object_tree::style_declaration(&c.Element()))
+            .collect::<Vec<_>>();
+        styles.sort_by(|a, b| a.0.cmp(&b.0));
+        styles.dedup_by(|a, b| a.0 == b.0);
+
+        for (style, declaration) in styles {
+            let path = {
+                let mut state = state.borrow_mut();
+                let state = &mut *state;
+                let resolved_style = if style == "native" {
+                    get_native_style(&mut state.diag.all_loaded_files)
+                } else {
+                    style.to_string()
+                };
+                state.tl.find_style_widgets(&resolved_style)
+            };
+            let Some(path) = path else { continue };
+            // Boxed because loading the style recurses into the loading of this document's dependencies
+            let Some(doc_path) = Box::pin(Self::ensure_document_loaded(
+                state,
+                &path.to_string_lossy(),
+                Some(NodeOrToken::Node(declaration.into())),
+                import_stack.to_vec(),
+            ))
+            .await
+            else {
+                continue;
+            };
+
+            let widgets = Rc::new(RefCell::new(TypeRegister::default()));
+            let mut state = state.borrow_mut();
+            let state = &mut *state;
+            let Some(style_doc) = state.tl.get_document(&doc_path) else {
+                panic!("Just loaded document not available")
+            };
+            for import in imports {
+                let ImportKind::ImportList(imported_types) = &import.import_kind else { continue };
+                if import.import_uri_token.text().trim_matches('\"') != "std-widgets.slint" {
+                    continue;
+                }
+                Self::register_imported_types(
+                    style_doc,
+                    import,
+                    ImportedName::extract_imported_names(imported_types),
+                    &widgets,
+                    state.diag,
+                );
+            }
+            registry_to_populate.borrow_mut().add_scoped_style(style, widgets.take());
+        }
+    }
+
+    /// Returns the path of the std-widgets.slint file of a builtin style, or of a custom style found in
+    /// the include paths.
+    fn find_style_widgets(&self, style: &str) -> Option<PathBuf> {
+        self.find_file_in_include_path(None, &format!("{style}/std-widgets.slint"))
+            .map(|(path, _)| path)
+            .or_else(|| {
+                crate::fileaccess::load_file(Path::new(&format!(
+                    "builtin:/{style}/std-widgets.slint"
+                )))
+                .map(|file| file.canon_path)
+            })
+    }
+
     fn register_imported_types(
         doc: &Document,
         import: &ImportedTypes,
@@ -1977,6 +2117,52 @@ fn test_manual_import() {
     assert!(maybe_button_type.is_some());
 }
 
+#[cfg(unix)]
+#[test]
+fn test_sandbox_symlink_escape() {
+    let dir = std::env::temp_dir().join(format!("slint-sandbox-test-{}", std::process::id()));
+    let allowed = dir.join("allowed");
+    let secret = dir.join("secret");
+    std::fs::create_dir_all(&allowed).unwrap();
+    std::fs::create_dir_all(&secret).unwrap();
+    std::fs::write(secret.join("secret.slint"), "export component Secret {}").unwrap();
+    std::fs::write(allowed.join("theme.slint"), "export component Theme {}").unwrap();
+    std::os::unix::fs::symlink(secret.join("secret.slint"), allowed.join("link.slint")).unwrap();
+    std::os::unix::fs::symlink(&secret, allowed.join("linkdir")).unwrap();
+
+    let sandbox =
+        crate::SandboxConfiguration { allowed_paths: vec![allowed.clone()], ..Default::default() };
+    assert!(sandbox.allows_path(&allowed.join("theme.slint")));
+    assert!(sandbox.allows_path(&allowed.join("linkdir/../allowed/theme.slint")));
+    assert!(!sandbox.allows_path(&allowed.join("link.slint")));
+    assert!(!sandbox.allows_path(&allowed.join("linkdir/secret.slint")));
+    assert!(!sandbox.allows_path(&allowed.join("missing.slint")));
+    assert!(!sandbox.allows_path(&allowed.join("../secret/secret.slint")));
+
+    let import = |file: &Path, component: &str| {
+        let mut compiler_config = CompilerConfiguration::sandbox(sandbox.clone());
+        compiler_config.style = Some("fluent".into());
+        let mut build_diagnostics = BuildDiagnostics::default();
+        let mut loader =
+            TypeLoader::new(TypeRegister::builtin(), compiler_config, &mut build_diagnostics);
+        let result = spin_on::spin_on(loader.import_component(
+            &file.to_string_lossy(),
+            component,
+            &mut build_diagnostics,
+        ));
+        (result.is_some(), build_diagnostics.to_string_vec())
+    };
+    assert_eq!(import(&allowed.join("theme.slint"), "Theme"), (true, vec![]));
+    let (imported, diagnostics) = import(&allowed.join("link.slint"), "Secret");
+    assert!(!imported);
+    assert!(
+        diagnostics.iter().any(|d| d.contains("not allowed in the sandbox")),
+        "{diagnostics:?}"
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
 #[test]
 fn test_builtin_style() {
     let test_source_path: PathBuf =
@@ -2037,6 +2223,63 @@ fn test_unknown_style() {
     assert!(diags[0].starts_with("Style FooBar is not known. Use one of the builtin styles ["));
 }
 
+#[test]
+fn test_scoped_style() {
+    let mut compiler_config =
+        CompilerConfiguration::new(crate::generator::OutputFormat::Interpreter);
+    compiler_config.style = Some("fluent".into());
+    let global_registry = TypeRegister::builtin();
+    let mut build_diagnostics = BuildDiagnostics::default();
+    let mut loader = TypeLoader::new(global_registry, compiler_config, &mut build_diagnostics);
+
+    spin_on::spin_on(
+        loader.load_file(
+            Path::new("HELLO"),
+            Path::new("HELLO"),
+            r#"
+import { Button } from "std-widgets.slint";
+export component Card {
+    style: "material";
+    Button { }
+}
+export component App {
+    Button { }
+}
+"#
+            .into(),
+            false,
+            &mut build_diagnostics,
+        ),
+    );
+    assert!(!build_diagnostics.has_errors(), "{:#?}", build_diagnostics.to_string_vec());
+
+    let doc = loader.get_document(Path::new("HELLO")).unwrap();
+    let button_file = |name: &str| {
+        let langtype::ElementType::Component(component) = doc
+            .inner_components
+            .iter()
+            .find(|c| c.id == name)
+            .unwrap()
+            .root_element
+            .borrow()
+            .children[0]
+            .borrow()
+            .base_type
+            .clone()
+        else {
+            panic!("Button should be a component")
+        };
+        let node = component.node.clone().unwrap();
+        node.source_file.path().to_path_buf()
+    };
+    assert!(button_file("Card").starts_with("builtin:/material"));
+    assert!(button_file("App").starts_with("builtin:/fluent"));
+    assert_eq!(
+        doc.inner_components.iter().find(|c| c.id == "Card").unwrap().style.as_deref(),
+        Some("material")
+    );
+}
+
 #[test]
 fn test_library_import() {
     let test_source_path: PathBuf =
@@ -38,6 +38,16 @@
     ("preferred-height", Type::LogicalLength),
     ("horizontal-stretch", Type::Float32),
     ("vertical-stretch", Type::Float32),
+    ("aspect-ratio", Type::Float32),
+];
+
+pub const RESERVED_ANCHOR_PROPERTIES: &[(&str, Type)] = &[
+    ("anchor-left", Type::LogicalLength),
+    ("anchor-right", Type::LogicalLength),
+    ("anchor-horizontal-center", Type::LogicalLength),
+    ("anchor-top", Type::LogicalLength),
+    ("anchor-bottom", Type::LogicalLength),
+    ("anchor-vertical-center", Type::LogicalLength),
 ];
 
 pub const RESERVED_GRIDLAYOUT_PROPERTIES: &[(&str, Type)] = &[
@@ -165,6 +175,8 @@ fn new() -> Self {
     ("clip", Type::Bool),
     ("opacity", Type::Float32),
     ("cache-rendering-hint", Type::Bool),
+    ("backdrop-blur", Type::LogicalLength),
+    ("mask-image", Type::Image),
     ("visible", Type::Bool), // ("enabled", Type::Bool),
 ];
 
@@ -189,6 +201,16 @@ pub fn transform_origin_property() -> (&'static str, Rc<Struct>) {
 pub const DEPRECATED_ROTATION_ORIGIN_PROPERTIES: [(&str, Type); 2] =
     [("rotation-origin-x", Type::LogicalLength), ("rotation-origin-y", Type::LogicalLength)];
 
+pub fn reserved_outline_properties() -> impl Iterator<Item = (&'static str, Type)> {
+    [
+        ("outline-width", Type::LogicalLength),
+        ("outline-offset", Type::LogicalLength),
+        ("outline-color", Type::Brush),
+        ("outline-style", Type::Enumeration(BUILTIN.with(|e| e.enums.BorderStyle.clone()))),
+    ]
+    .into_iter()
+}
+
 pub fn noarg_callback_type() -> Type {
     BUILTIN.with(|types| types.noarg_callback_type.clone())
 }
@@ -232,6 +254,7 @@ pub fn reserved_properties() -> impl Iterator<Item = (&'static str, Type, Proper
     RESERVED_GEOMETRY_PROPERTIES
         .iter()
         .chain(RESERVED_LAYOUT_PROPERTIES.iter())
+        .chain(RESERVED_ANCHOR_PROPERTIES.iter())
         .chain(RESERVED_OTHER_PROPERTIES.iter())
         .chain(RESERVED_DROP_SHADOW_PROPERTIES.iter())
         .chain(RESERVED_TRANSFORM_PROPERTIES.iter())
@@ -241,6 +264,7 @@ pub fn reserved_properties() -> impl Iterator<Item = (&'static str, Type, Proper
             std::iter::once(transform_origin_property())
                 .map(|(k, v)| (k, v.into(), PropertyVisibility::Input)),
         )
+        .chain(reserved_outline_properties().map(|(k, v)| (k, v, PropertyVisibility::Input)))
         .chain(reserved_accessibility_properties().map(|(k, v)| (k, v, PropertyVisibility::Input)))
         .chain(
             RESERVED_GRIDLAYOUT_PROPERTIES
@@ -249,6 +273,12 @@ pub fn reserved_properties() -> impl Iterator<Item = (&'static str, Type, Proper
         )
         .chain(IntoIterator::into_iter([
             ("absolute-position", logical_point_type().into(), PropertyVisibility::Output),
+            ("visible-in-viewport", Type::Bool, PropertyVisibility::Output),
+            (
+                "mix-blend-mode",
+                Type::Enumeration(BUILTIN.with(|e| e.enums.MixBlendMode.clone())),
+                PropertyVisibility::Input,
+            ),
             ("forward-focus", Type::ElementReference, PropertyVisibility::Constexpr),
             (
                 "focus",
@@ -271,7 +301,11 @@ pub fn reserved_properties() -> impl Iterator<Item = (&'static str, Type, Proper
                 PropertyVisibility::Constexpr,
             ),
         ]))
-        .chain(std::iter::once(("init", noarg_callback_type(), PropertyVisibility::Private)))
+        .chain(IntoIterator::into_iter([
+            ("init", noarg_callback_type(), PropertyVisibility::Private),
+            ("entered-viewport", noarg_callback_type(), PropertyVisibility::Private),
+            ("left-viewport", noarg_callback_type(), PropertyVisibility::Private),
+        ]))
 }
 
 /// lookup reserved property injected in every item
@@ -345,6 +379,9 @@ pub struct TypeRegister {
     parent_registry: Option<Rc<RefCell<TypeRegister>>>,
     /// If the lookup function should return types that are marked as internal
     pub(crate) expose_internal_types: bool,
+    /// The types imported from "std-widgets.slint" in the styles declared by the components of a
+    /// document with `style: "..."`, indexed by style.
+    scoped_styles: HashMap<SmolStr, TypeRegister>,
 }
 
 impl TypeRegister {
@@ -366,6 +403,11 @@ pub(crate) fn snapshot(&self, snapshotter: &mut typeloader::Snapshotter) -> Self
                 .as_ref()
                 .map(|tr| snapshotter.snapshot_type_register(tr)),
             expose_internal_types: self.expose_internal_types,
+            scoped_styles: self
+                .scoped_styles
+                .iter()
+                .map(|(k, v)| (k.clone(), v.snapshot(snapshotter)))
+                .collect(),
         }
     }
 
@@ -659,6 +701,46 @@ pub fn lookup_qualified<Member: AsRef<str>>(&self, qualified: &[Member]) -> Type
         self.lookup(qualified[0].as_ref())
     }
 
+    /// Register the types imported from "std-widgets.slint" in the given style, for the components
+    /// that declare this style.
+    pub(crate) fn add_scoped_style(&mut self, style: SmolStr, widgets: TypeRegister) {
+        self.scoped_styles.insert(style, widgets);
+    }
+
+    /// Returns a copy of this register in which the types imported from "std-widgets.slint" are the ones
+    /// of the given style, or None if the style wasn't loaded for the document.
+    pub fn with_scoped_style(&self, style: &str) -> Option<Self> {
+        let mut result = Self {
+            types: self.types.clone(),
+            elements: self.elements.clone(),
+            supported_property_animation_types: self.supported_property_animation_types.clone(),
+            property_animation_type: self.property_animation_type.clone(),
+            empty_type: self.empty_type.clone(),
+            context_restricted_types: self.context_restricted_types.clone(),
+            parent_registry: self.parent_registry.clone(),
+            expose_internal_types: self.expose_internal_types,
+            scoped_styles: Default::default(),
+        };
+        self.visit_scoped_style(style, &mut |widgets| {
+            result.types.extend(widgets.types.iter().map(|(k, v)| (k.clone(), v.clone())));
+            result.elements.extend(widgets.elements.iter().map(|(k, v)| (k.clone(), v.clone())));
+        })
+        .then_some(result)
+    }
+
+    fn visit_scoped_style(&self, style: &str, visitor: &mut dyn FnMut(&TypeRegister)) -> bool {
+        match self.scoped_styles.get(style) {
+            Some(widgets) => {
+                visitor(widgets);
+                true
+            }
+            None => self
+                .parent_registry
+                .as_ref()
+                .is_some_and(|r| r.borrow().visit_scoped_style(style, visitor)),
+        }
+    }
+
     /// Add the component with its defined name
     ///
     /// Returns false if there was already an element with the same name
@@ -0,0 +1,222 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+    Instrumentation of the heap usage, to find out how much heap a user interface needs on devices
+    with a fixed heap size, such as microcontrollers.
+
+    After [`finish_initialization()`], it also verifies that the frames are rendered without
+    allocating, for applications that must not allocate once they're initialized.
+
+    This module is only available with the `alloc-stats` feature.
+*/
+
+#![warn(missing_docs)]
+#![allow(unsafe_code)]
+
+use core::alloc::{GlobalAlloc, Layout};
+use portable_atomic::{AtomicBool, AtomicUsize, Ordering};
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static FRAME_PEAK: AtomicUsize = AtomicUsize::new(0);
+static FAILED: AtomicUsize = AtomicUsize::new(0);
+static FRAME_FAILED: AtomicUsize = AtomicUsize::new(0);
+static LAST_FRAME_PEAK: AtomicUsize = AtomicUsize::new(0);
+static LAST_FRAME_FAILED: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static FRAME_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static LAST_FRAME_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static INITIALIZED: AtomicBool = AtomicBool::new(false);
+
+/// A [`GlobalAlloc`] that forwards the allocations to another allocator, and records the
+/// heap usage reported by [`stats()`] and [`last_frame()`].
+///
+/// Wrap the global allocator of the application with it:
+///
+/// ```rust,ignore
+/// use slint::platform::alloc_stats::CountingAllocator;
+///
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator<embedded_alloc::LlffHeap> =
+///     CountingAllocator::new(embedded_alloc::LlffHeap::empty());
+///
+/// // ...
+/// unsafe { ALLOCATOR.inner().init(HEAP.as_ptr() as usize, HEAP_SIZE) }
+/// ```
+#[derive(Debug, Default)]
+pub struct CountingAllocator<A>(A);
+
+impl<A> CountingAllocator<A> {
+    /// Creates an allocator that forwards to `inner`.
+    pub const fn new(inner: A) -> Self {
+        Self(inner)
+    }
+
+    /// Returns the allocator that the allocations are forwarded to.
+    pub fn inner(&self) -> &A {
+        &self.0
+    }
+}
+
+// Safety: all the functions forward to the inner allocator, and only update the statistics
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { self.0.alloc(layout) };
+        record_allocation(ptr, layout.size());
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { self.0.alloc_zeroed(layout) };
+        record_allocation(ptr, layout.size());
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { self.0.dealloc(ptr, layout) };
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { self.0.realloc(ptr, layout, new_size) };
+        if new_ptr.is_null() {
+            record_allocation(new_ptr, 0);
+        } else if new_size > layout.size() {
+            record_allocation(new_ptr, new_size - layout.size());
+        } else {
+            CURRENT.fetch_sub(layout.size() - new_size, Ordering::Relaxed);
+        }
+        new_ptr
+    }
+}
+
+fn record_allocation(ptr: *mut u8, size: usize) {
+    if ptr.is_null() {
+        FAILED.fetch_add(1, Ordering::Relaxed);
+        FRAME_FAILED.fetch_add(1, Ordering::Relaxed);
+        return;
+    }
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    FRAME_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+    FRAME_PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+/// The heap usage recorded by a [`CountingAllocator`], in bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AllocStats {
+    /// The size of the memory that is currently allocated, or that was allocated at the end of the frame.
+    pub current: usize,
+    /// The maximum size of the allocated memory.
+    pub peak: usize,
+    /// The number of allocations that failed because the heap was exhausted.
+    pub failed_allocations: usize,
+    /// The number of allocations, including the reallocations that grow a block.
+    pub allocations: usize,
+}
+
+/// Returns the heap usage since the start of the application.
+pub fn stats() -> AllocStats {
+    AllocStats {
+        current: CURRENT.load(Ordering::Relaxed),
+        peak: PEAK.load(Ordering::Relaxed),
+        failed_allocations: FAILED.load(Ordering::Relaxed),
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+    }
+}
+
+/// Starts recording the heap usage of a frame.
+///
+/// This is called by [`MinimalSoftwareWindow::draw_if_needed()`](crate::software_renderer::MinimalSoftwareWindow::draw_if_needed),
+/// call it before rendering if the platform uses another window adapter.
+pub fn begin_frame() {
+    FRAME_PEAK.store(CURRENT.load(Ordering::Relaxed), Ordering::Relaxed);
+    FRAME_FAILED.store(0, Ordering::Relaxed);
+    FRAME_ALLOCATIONS.store(0, Ordering::Relaxed);
+}
+
+/// Stops recording the heap usage of a frame, which is then returned by [`last_frame()`].
+///
+/// # Panics
+///
+/// After [`finish_initialization()`], this function panics if the frame allocated memory.
+pub fn end_frame() {
+    let allocations = FRAME_ALLOCATIONS.load(Ordering::Relaxed);
+    LAST_FRAME_PEAK.store(FRAME_PEAK.load(Ordering::Relaxed), Ordering::Relaxed);
+    LAST_FRAME_FAILED.store(FRAME_FAILED.load(Ordering::Relaxed), Ordering::Relaxed);
+    LAST_FRAME_ALLOCATIONS.store(allocations, Ordering::Relaxed);
+    if allocations > 0 && INITIALIZED.load(Ordering::Relaxed) {
+        // Don't panic again for the following frames while unwinding or reporting the panic
+        INITIALIZED.store(false, Ordering::Relaxed);
+        panic!("{allocations} heap allocation(s) while rendering a frame after the initialization");
+    }
+}
+
+/// Marks the end of the initialization of the application: from now on, [`end_frame()`] panics
+/// if a frame allocated memory while it was rendered.
+///
+/// Call this function once the user interface is created and the first frames are rendered, so
+/// that the caches of the renderer are filled. `SoftwareRenderer::reserve_scene_capacity()` makes
+/// room for frames that contain more items than the first ones.
+/// Use it in the test builds of applications that must not allocate after their initialization,
+/// to find the parts of the user interface that still allocate while rendering, such as text
+/// that changes, images that are loaded, or items that are created by a model.
+pub fn finish_initialization() {
+    INITIALIZED.store(true, Ordering::Relaxed);
+}
+
+/// Runs `f` and panics if it allocated memory, for example to verify that the code handling an
+/// input event doesn't allocate.
+///
+/// The allocations of other threads are counted as well.
+#[track_caller]
+pub fn assert_no_allocations<R>(f: impl FnOnce() -> R) -> R {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let result = f();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    assert!(allocations == 0, "{allocations} unexpected heap allocation(s)");
+    result
+}
+
+/// Returns the heap usage of the last frame rendered between [`begin_frame()`] and [`end_frame()`]:
+/// `current` is the size of the allocated memory at the end of the frame, and `peak` the maximum
+/// size while rendering it.
+pub fn last_frame() -> AllocStats {
+    AllocStats {
+        current: CURRENT.load(Ordering::Relaxed),
+        peak: LAST_FRAME_PEAK.load(Ordering::Relaxed),
+        failed_allocations: LAST_FRAME_FAILED.load(Ordering::Relaxed),
+        allocations: LAST_FRAME_ALLOCATIONS.load(Ordering::Relaxed),
+    }
+}
+
+#[test]
+fn test_counting_allocator() {
+    let allocator = CountingAllocator::new(std::alloc::System);
+    let layout = Layout::from_size_align(1024, 8).unwrap();
+    let before = stats();
+
+    begin_frame();
+    let ptr = unsafe { allocator.alloc(layout) };
+    assert!(!ptr.is_null());
+    let ptr = unsafe { allocator.realloc(ptr, layout, 4096) };
+    assert!(!ptr.is_null());
+    unsafe { allocator.dealloc(ptr, Layout::from_size_align(4096, 8).unwrap()) };
+    end_frame();
+
+    let frame = last_frame();
+    assert!(frame.peak >= before.current + 4096);
+    assert_eq!(frame.failed_allocations, 0);
+    assert!(frame.allocations >= 2);
+    assert!(stats().peak >= frame.peak);
+
+    let ptr = assert_no_allocations(core::ptr::null_mut::<u8>);
+    assert!(ptr.is_null());
+    let result = std::panic::catch_unwind(|| {
+        assert_no_allocations(|| unsafe { allocator.dealloc(allocator.alloc(layout), layout) })
+    });
+    assert!(result.is_err());
+}
@@ -231,6 +231,7 @@ pub struct AnimationDriver {
     /// Indicate whether there are any active animations that require a future call to update_animations.
     active_animations: Cell<bool>,
     global_instant: core::pin::Pin<Box<crate::Property<Instant>>>,
+    throttling: Cell<crate::api::AnimationThrottling>,
 }
 
 impl Default for AnimationDriver {
@@ -241,6 +242,7 @@ fn default() -> Self {
                 Instant::default(),
                 "i_slint_core::AnimationDriver::global_instant",
             )),
+            throttling: Cell::default(),
         }
     }
 }
@@ -265,13 +267,25 @@ pub fn has_active_animations(&self) -> bool {
 
     /// Tell the driver that there are active animations
     pub fn set_has_active_animations(&self) {
-        self.active_animations.set(true);
+        if !self.active_animations.replace(true) {
+            crate::platform::request_redraw_at(None);
+        }
     }
     /// The current instant that is to be used for animation
     /// using this function register the current binding as a dependency
     pub fn current_tick(&self) -> Instant {
         self.global_instant.as_ref().get()
     }
+
+    /// Returns when animations stop requesting new frames to save power
+    pub fn throttling(&self) -> crate::api::AnimationThrottling {
+        self.throttling.get()
+    }
+
+    /// Sets when animations stop requesting new frames to save power
+    pub fn set_throttling(&self, throttling: crate::api::AnimationThrottling) {
+        self.throttling.set(throttling);
+    }
 }
 
 crate::thread_local!(
@@ -295,6 +309,11 @@ pub fn animation_tick() -> u64 {
     })
 }
 
+/// Returns the throttling set with [`set_animation_throttling()`](crate::api::set_animation_throttling)
+pub fn animation_throttling() -> crate::api::AnimationThrottling {
+    CURRENT_ANIMATION_DRIVER.with(|driver| driver.throttling())
+}
+
 fn ease_out_bounce_curve(value: f32) -> f32 {
     const N1: f32 = 7.5625;
     const D1: f32 = 2.75;
@@ -7,10 +7,12 @@
 
 #![warn(missing_docs)]
 
+pub use crate::debounce::{CallbackThrottle, Debouncer};
 #[cfg(target_has_atomic = "ptr")]
 pub use crate::future::*;
 use crate::graphics::{Rgba8Pixel, SharedPixelBuffer};
 use crate::input::{KeyEventType, MouseEvent};
+pub use crate::tray_icon::{Menu, MenuItem, TrayIcon};
 use crate::window::{WindowAdapter, WindowInner};
 use alloc::boxed::Box;
 use alloc::string::String;
@@ -301,6 +303,11 @@ pub enum GraphicsAPI<'a> {
         device: wgpu_27::Device,
         /// The WGPU queue for used for command submission.
         queue: wgpu_27::Queue,
+        /// The texture of the window surface that the renderer renders the current frame into, if the
+        /// renderer supports compositing. Commands that render into this texture and that are submitted
+        /// to the queue during [`RenderingState::BeforeRendering`] appear under the UI, and during
+        /// [`RenderingState::AfterRendering`] over the UI.
+        surface_texture: Option<wgpu_27::Texture>,
     },
 }
 
@@ -436,6 +443,156 @@ fn display_handle(
     }
 }
 
+/// An edge or a corner of a window, by which [`Window::start_system_resize()`] resizes it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WindowResizeEdge {
+    /// The top edge.
+    Top,
+    /// The bottom edge.
+    Bottom,
+    /// The left edge.
+    Left,
+    /// The right edge.
+    Right,
+    /// The top left corner.
+    TopLeft,
+    /// The top right corner.
+    TopRight,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The bottom right corner.
+    BottomRight,
+}
+
+/// What a region of a frameless window is used for, when it's drawn by the application.
+/// See [`Window::set_hit_test_regions()`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WindowHitTestArea {
+    /// The title bar, by which the user moves the window.
+    TitleBar,
+    /// A border by which the user resizes the window.
+    ResizeBorder(WindowResizeEdge),
+    /// The button that maximizes and restores the window. On Windows, hovering it shows the snap
+    /// layouts, and clicking it is handled by the system.
+    MaximizeButton,
+}
+
+/// A rectangle of a frameless window with its [`WindowHitTestArea`], for [`Window::set_hit_test_regions()`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WindowHitTestRegion {
+    /// The top left corner of the region, relative to the window.
+    pub position: LogicalPosition,
+    /// The size of the region.
+    pub size: LogicalSize,
+    /// What the region is used for.
+    pub area: WindowHitTestArea,
+}
+
+impl WindowHitTestRegion {
+    /// Returns true if the position, relative to the window, is in the region.
+    pub fn contains(&self, position: LogicalPosition) -> bool {
+        position.x >= self.position.x
+            && position.y >= self.position.y
+            && position.x < self.position.x + self.size.width
+            && position.y < self.position.y + self.size.height
+    }
+}
+
+/// A screen, or monitor, connected to the computer. Use [`screens()`] to list them and
+/// [`Window::set_screen()`] to place a window on one of them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Screen {
+    name: SharedString,
+    position: PhysicalPosition,
+    size: PhysicalSize,
+    scale_factor: f32,
+    is_primary: bool,
+}
+
+impl Screen {
+    /// Creates a screen with the given properties. This is meant for the implementations of
+    /// [`Platform::screens()`](crate::platform::Platform::screens).
+    pub fn new(
+        name: impl Into<SharedString>,
+        position: PhysicalPosition,
+        size: PhysicalSize,
+        scale_factor: f32,
+        is_primary: bool,
+    ) -> Self {
+        Self { name: name.into(), position, size, scale_factor, is_primary }
+    }
+
+    /// The name of the screen reported by the system, such as the model of the monitor or the
+    /// name of its connector. It may be empty.
+    pub fn name(&self) -> SharedString {
+        self.name.clone()
+    }
+
+    /// The position of the top left corner of the screen on the desktop, in physical pixels.
+    pub fn position(&self) -> PhysicalPosition {
+        self.position
+    }
+
+    /// The size of the screen in physical pixels.
+    pub fn size(&self) -> PhysicalSize {
+        self.size
+    }
+
+    /// The scale factor of the screen, which converts logical pixels to physical pixels.
+    pub fn scale_factor(&self) -> f32 {
+        self.scale_factor
+    }
+
+    /// Returns true if the system considers this screen to be the primary one.
+    pub fn is_primary(&self) -> bool {
+        self.is_primary
+    }
+}
+
+/// Returns the screens connected to the computer.
+///
+/// The Slint platform must be initialized, for example by creating a component, before listing
+/// the screens. Returns an error if the platform can't list the screens.
+///
+/// ```rust,no_run
+/// # let window: &slint::Window = todo!();
+/// let screens = slint::screens().unwrap();
+/// // Show the dashboard on the first screen that isn't the primary one
+/// if let Some(screen) = screens.iter().find(|screen| !screen.is_primary()) {
+///     window.set_screen(screen).unwrap();
+/// }
+/// ```
+pub fn screens() -> Result<alloc::vec::Vec<Screen>, PlatformError> {
+    crate::context::with_global_context(
+        || Err(crate::platform::PlatformError::NoPlatform),
+        |ctx| ctx.platform().screens(),
+    )?
+}
+
+/// When the steps of bringing the contents of a window on the screen happened, measured on the
+/// clock of the animations and timers. See [`Window::first_frame_timings()`].
+///
+/// The steps that didn't happen yet are `None`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FirstFrameTimings {
+    /// When the component was created and set as the contents of the window.
+    pub component_created: Option<core::time::Duration>,
+    /// When the window was shown for the first time.
+    pub shown: Option<core::time::Duration>,
+    /// When the renderer finished rendering the first frame of the visible window.
+    pub first_frame_rendered: Option<core::time::Duration>,
+}
+
+impl FirstFrameTimings {
+    /// Returns the time it took from the creation of the component to the first rendered frame.
+    pub fn time_to_first_frame(&self) -> Option<core::time::Duration> {
+        Some(self.first_frame_rendered?.saturating_sub(self.component_created?))
+    }
+}
+
 /// This type represents a window towards the windowing system, that's used to render the
 /// scene of a component. It provides API to control windowing system specific aspects such
 /// as the position on the screen.
@@ -590,6 +747,107 @@ pub fn set_minimized(&self, minimized: bool) {
         self.0.set_minimized(minimized);
     }
 
+    /// Returns if the window stays above the other windows
+    pub fn is_always_on_top(&self) -> bool {
+        self.0.is_always_on_top()
+    }
+
+    /// Keep the window above the other windows, or not. This is the same as setting the
+    /// `always-on-top` property of the `Window` element.
+    pub fn set_always_on_top(&self, always_on_top: bool) {
+        self.0.set_always_on_top(always_on_top);
+    }
+
+    /// Returns if the window is hidden from the taskbar
+    pub fn is_skip_taskbar(&self) -> bool {
+        self.0.is_skip_taskbar()
+    }
+
+    /// Hide the window from the taskbar (or the dock, or the window switcher), or show it there again.
+    /// This is typically used for overlays and on-screen displays.
+    ///
+    /// Supported by the Qt backend, and by the winit backend on Windows.
+    pub fn set_skip_taskbar(&self, skip_taskbar: bool) {
+        self.0.set_skip_taskbar(skip_taskbar);
+    }
+
+    /// Returns the opacity of the whole window, from 0 (transparent) to 1 (opaque)
+    pub fn opacity(&self) -> f32 {
+        self.0.opacity()
+    }
+
+    /// Set the opacity of the whole window, including its frame, from 0 (transparent) to 1 (opaque).
+    /// The value is clamped to this range.
+    ///
+    /// Supported by the Qt backend, and by the winit backend on Windows. On Linux, it requires
+    /// a compositing window manager.
+    pub fn set_opacity(&self, opacity: f32) {
+        self.0.set_opacity(opacity);
+    }
+
+    /// Lets the user move the window with the pointer, as when dragging the title bar drawn by
+    /// the system. Call this when a pointer button is pressed in the title bar drawn by the
+    /// application for a window with `no-frame: true`.
+    ///
+    /// Returns an error if the backend doesn't support it, or if no pointer button is pressed.
+    pub fn start_system_move(&self) -> Result<(), PlatformError> {
+        self.0.start_system_move()
+    }
+
+    /// Lets the user resize the window with the pointer by the given edge or corner, as when
+    /// dragging a border drawn by the system. Call this when a pointer button is pressed in a
+    /// border drawn by the application for a window with `no-frame: true`.
+    ///
+    /// Returns an error if the backend doesn't support it, or if no pointer button is pressed.
+    pub fn start_system_resize(&self, edge: WindowResizeEdge) -> Result<(), PlatformError> {
+        self.0.start_system_resize(edge)
+    }
+
+    /// Sets the regions of a window with `no-frame: true` that are used as title bar, resize
+    /// borders, or maximize button, so that the application can draw its own title bar.
+    ///
+    /// Pressing the pointer in a title bar or a resize border moves or resizes the window, without
+    /// dispatching the event to the elements. When regions overlap, the last one wins. On Windows,
+    /// the regions are reported to the system, which enables its behaviors such as maximizing the
+    /// window with a double click on the title bar and the snap layouts of the maximize button.
+    ///
+    /// ```rust,no_run
+    /// # let window: &slint::Window = todo!();
+    /// use slint::{LogicalPosition, LogicalSize, WindowHitTestArea, WindowHitTestRegion};
+    /// let width = window.size().to_logical(window.scale_factor()).width;
+    /// window.set_hit_test_regions([
+    ///     WindowHitTestRegion {
+    ///         position: LogicalPosition::new(0., 0.),
+    ///         size: LogicalSize::new(width - 96., 32.),
+    ///         area: WindowHitTestArea::TitleBar,
+    ///     },
+    ///     WindowHitTestRegion {
+    ///         position: LogicalPosition::new(width - 64., 0.),
+    ///         size: LogicalSize::new(32., 32.),
+    ///         area: WindowHitTestArea::MaximizeButton,
+    ///     },
+    /// ]);
+    /// ```
+    pub fn set_hit_test_regions(&self, regions: impl IntoIterator<Item = WindowHitTestRegion>) {
+        self.0.set_hit_test_regions(regions.into_iter().collect());
+    }
+
+    /// Returns the screen that the window is on, or `None` if it's not known, for example
+    /// because the window isn't shown yet.
+    pub fn screen(&self) -> Option<Screen> {
+        self.0.screen()
+    }
+
+    /// Moves the window to the top left corner of the `screen`, one of the screens returned by
+    /// [`screens()`]. A fullscreen window becomes fullscreen on that screen. Use
+    /// [`Self::set_position()`] afterwards to place the window elsewhere on the screen, relative to
+    /// [`Screen::position()`].
+    ///
+    /// Returns an error if the backend doesn't support it, or if the screen was disconnected.
+    pub fn set_screen(&self, screen: &Screen) -> Result<(), PlatformError> {
+        self.0.set_screen(screen)
+    }
+
     /// Dispatch a window event to the scene.
     ///
     /// Use this when you're implementing your own backend and want to forward user input events.
@@ -644,6 +902,12 @@ pub fn try_dispatch_event(
             crate::platform::WindowEvent::PointerExited => {
                 self.0.process_mouse_input(MouseEvent::Exit)
             }
+            crate::platform::WindowEvent::PointerGesture { position, gesture } => {
+                self.0.process_mouse_input(MouseEvent::Gesture {
+                    position: position.to_euclid().cast(),
+                    gesture,
+                });
+            }
 
             crate::platform::WindowEvent::KeyPressed { text } => {
                 self.0.process_key_input(crate::input::KeyEvent {
@@ -697,9 +961,14 @@ pub fn try_dispatch_event(
     }
 
     /// Returns true if there is an animation currently active on any property in the Window; false otherwise.
+    ///
+    /// This returns false while the window is hidden, minimized, or fully covered by other windows,
+    /// unless the animations aren't throttled, see [`set_animation_throttling()`].
     pub fn has_active_animations(&self) -> bool {
         // TODO make it really per window.
-        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| driver.has_active_animations())
+        !self.0.animations_throttled()
+            && crate::animations::CURRENT_ANIMATION_DRIVER
+                .with(|driver| driver.has_active_animations())
     }
 
     /// Returns the visibility state of the window. This function can return false even if you previously called show()
@@ -737,12 +1006,134 @@ pub fn window_handle(&self) -> WindowHandle {
         WindowHandle { inner: WindowHandleInner::HandleByAdapter(adapter) }
     }
 
+    /// Enables or disables the layout debug overlay.
+    ///
+    /// When enabled, the boundary of every element is drawn on top of the scene. The padding of
+    /// layouts is shaded in green and the spacing between their elements in orange. Layouts and
+    /// components whose constraints can't be satisfied, because their minimum size is larger than
+    /// their maximum size, are outlined in red.
+    ///
+    /// The overlay can also be enabled for all windows by setting the `SLINT_DEBUG_LAYOUT`
+    /// environment variable.
+    pub fn set_layout_debug_overlay(&self, enabled: bool) {
+        self.0.set_layout_debug_overlay(enabled);
+    }
+
+    /// Returns true if the layout debug overlay is enabled. See [`Self::set_layout_debug_overlay()`].
+    pub fn layout_debug_overlay(&self) -> bool {
+        self.0.layout_debug_overlay()
+    }
+
+    /// Sets whether the edges and the borders of the `Rectangle` elements are rounded to device
+    /// pixels, so that they look sharp with fractional scale factors such as 1.25 or 1.5. This
+    /// applies to the elements whose `pixel-snapping` property is `PixelSnapping.auto`, which is the
+    /// default. It's enabled by default.
+    ///
+    /// Building with `RUSTFLAGS="--cfg slint_no_pixel_snapping"` disables the rounding entirely.
+    pub fn set_pixel_snapping(&self, enabled: bool) {
+        self.0.set_pixel_snapping(enabled);
+    }
+
+    /// Returns true if the elements are rounded to device pixels. See [`Self::set_pixel_snapping()`].
+    pub fn pixel_snapping(&self) -> bool {
+        self.0.pixel_snapping()
+    }
+
+    /// Enables or disables warnings about layout constraints that can't be satisfied.
+    ///
+    /// When enabled, a warning is logged when the minimum size of a component is larger than its
+    /// maximum size, in which case the layout clamps the size. The warning names the elements
+    /// involved and their location in the `.slint` files. Each conflict is reported once, and
+    /// again only if it was resolved in between.
+    ///
+    /// The locations are only known when the `.slint` files were compiled with the
+    /// `SLINT_EMIT_DEBUG_INFO` environment variable set, or when using the interpreter.
+    ///
+    /// The warnings can also be enabled for all windows by setting the `SLINT_DEBUG_LAYOUT`
+    /// environment variable, for example to `warn` to get the warnings without the overlay.
+    pub fn set_layout_constraint_warnings(&self, enabled: bool) {
+        self.0.set_layout_constraint_warnings(enabled);
+    }
+
+    /// Returns true if warnings about unsatisfiable layout constraints are enabled.
+    /// See [`Self::set_layout_constraint_warnings()`].
+    pub fn layout_constraint_warnings(&self) -> bool {
+        self.0.layout_constraint_warnings()
+    }
+
     /// Takes a snapshot of the window contents and returns it as RGBA8 encoded pixel buffer.
     ///
+    /// This is supported by the software, Skia, and FemtoVG renderers, as well as by the Qt backend.
+    /// The parts of the window that aren't covered by an opaque background are transparent.
+    /// The buffer can for example be saved as PNG with the `image` crate, to implement an
+    /// "export as image" feature or to compare the rendering in automated tests:
+    ///
+    /// ```ignore
+    /// let snapshot = ui.window().take_snapshot()?;
+    /// image::save_buffer(
+    ///     "view.png",
+    ///     snapshot.as_bytes(),
+    ///     snapshot.width(),
+    ///     snapshot.height(),
+    ///     image::ColorType::Rgba8,
+    /// )?;
+    /// ```
+    ///
     /// Note that this function may be slow to call as it may need to re-render the scene.
     pub fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
         self.0.window_adapter().renderer().take_snapshot()
     }
+
+    /// Renders the window contents into an image that fits into `max_size`, keeping the aspect ratio.
+    ///
+    /// This is meant for thumbnails and live previews, for example of the screens of an app switcher.
+    /// The window doesn't need to be shown: a component that is created but not shown can be rendered
+    /// with the renderer of the platform, after giving its window a size with [`Self::set_size()`].
+    /// Call this function again to refresh the preview after the component changed.
+    ///
+    /// This requires a renderer that supports [`Self::take_snapshot()`].
+    pub fn take_thumbnail(
+        &self,
+        max_size: PhysicalSize,
+    ) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
+        let snapshot = self.take_snapshot()?;
+        Ok(crate::graphics::image::scale_down_to_fit(snapshot, max_size.width, max_size.height))
+    }
+
+    /// Returns when the steps of bringing the contents of this window on the screen happened, to
+    /// measure and optimize the startup time of the application. Set the `SLINT_DEBUG_PERFORMANCE`
+    /// environment variable to `startup` to log the time to the first frame of each window.
+    pub fn first_frame_timings(&self) -> FirstFrameTimings {
+        self.0.first_frame_timings()
+    }
+
+    /// Registers a callback that is invoked once, after the first frame of this window was rendered.
+    /// If it was already rendered, the callback is invoked from the event loop right away.
+    ///
+    /// Use this to hand off from a splash screen to the user interface: keep the splash screen
+    /// shown until the callback is invoked, so that no empty window is shown in between.
+    ///
+    /// ```rust,no_run
+    /// # let window: &slint::Window = todo!();
+    /// window.on_first_frame_rendered(|| {
+    ///     // Tell the boot splash screen of an embedded Linux system to exit
+    ///     let _ = std::process::Command::new("plymouth").arg("quit").arg("--retain-splash").status();
+    /// });
+    /// ```
+    pub fn on_first_frame_rendered(&self, callback: impl FnOnce() + 'static) {
+        self.0.on_first_frame_rendered(Box::new(callback));
+    }
+
+    /// Evaluates the layout and the geometry of the elements within the window, and instantiates
+    /// their repeated elements, ahead of showing the window.
+    ///
+    /// Call this function while a splash screen is shown, so that showing the window later renders
+    /// the first frame sooner. The contents of the elements with `clip: true` that are outside of
+    /// the visible area of the window, for example because they're scrolled out of view, are
+    /// skipped: they are evaluated when they become visible.
+    pub fn prepare_first_frame(&self) {
+        self.0.prepare_first_frame();
+    }
 }
 
 pub use crate::SharedString;
@@ -839,6 +1230,119 @@ fn upgrade_from_weak_inner(_: &Self::WeakInner) -> Option<Self>
     fn global<'a, T: Global<'a, Self>>(&'a self) -> T
     where
         Self: Sized;
+
+    /// Returns the elements of the scene of the window of this component with their geometry,
+    /// starting with the root element, in depth-first order.
+    ///
+    /// This can be used to build custom inspectors, guided tours, or to check the layout in
+    /// automated tests. The list is a snapshot: elements created later, for example by a `for`
+    /// or `if`, aren't included.
+    fn scene_elements(&self) -> impl Iterator<Item = SceneElement>
+    where
+        Self: Sized,
+    {
+        let root = crate::items::ItemRc::new(WindowInner::from_pub(self.window()).component(), 0);
+        let mut items = SceneElement::for_item(&root, 0);
+        root.visit_descendants(|item| {
+            let mut depth = 0;
+            let mut parent =
+                item.parent_item(crate::item_tree::ParentItemTraversalMode::FindAllParents);
+            while let Some(p) = parent {
+                depth += 1;
+                parent = p.parent_item(crate::item_tree::ParentItemTraversalMode::FindAllParents);
+            }
+            items.extend(SceneElement::for_item(item, depth));
+            core::ops::ControlFlow::<()>::Continue(())
+        });
+        items.into_iter()
+    }
+}
+
+/// An element of the scene of a window, as returned by [`ComponentHandle::scene_elements()`].
+///
+/// When several elements are merged into a single item, for example when a component inherits
+/// `Rectangle`, each of them is returned as a separate `SceneElement`, with the same geometry.
+///
+/// The type name and the id are only available if the code was compiled with debug info,
+/// for example by setting the `SLINT_EMIT_DEBUG_INFO=1` environment variable at build time.
+/// They are always available with the interpreter.
+#[derive(Clone)]
+pub struct SceneElement {
+    item: crate::item_tree::ItemWeak,
+    element_index: usize,
+    depth: usize,
+}
+
+impl core::fmt::Debug for SceneElement {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SceneElement")
+            .field("type_name", &self.type_name())
+            .field("id", &self.id())
+            .field("depth", &self.depth)
+            .finish()
+    }
+}
+
+impl SceneElement {
+    fn for_item(item: &crate::items::ItemRc, depth: usize) -> alloc::vec::Vec<Self> {
+        (0..item.element_count().unwrap_or(1).max(1))
+            .map(|element_index| SceneElement { item: item.downgrade(), element_index, depth })
+            .collect()
+    }
+
+    fn type_name_and_id(&self) -> Option<(SharedString, SharedString)> {
+        self.item.upgrade()?.element_type_names_and_ids(self.element_index)?.into_iter().next()
+    }
+
+    /// Returns the name of the type of the element, for example `Rectangle` or the name of a component.
+    ///
+    /// Returns None if the item was destroyed or if debug info isn't available.
+    pub fn type_name(&self) -> Option<SharedString> {
+        self.type_name_and_id().map(|(type_name, _)| type_name).filter(|t| !t.is_empty())
+    }
+
+    /// Returns the qualified id of the element, in the form `ComponentName::id`.
+    ///
+    /// Returns None if the element doesn't have an id, if the item was destroyed,
+    /// or if debug info isn't available.
+    pub fn id(&self) -> Option<SharedString> {
+        self.type_name_and_id().map(|(_, id)| id).filter(|id| !id.is_empty())
+    }
+
+    /// Returns the depth of the item in the tree. The root item has a depth of zero.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the position of the item within the window. Returns a zero position if the item was destroyed.
+    pub fn absolute_position(&self) -> LogicalPosition {
+        self.item
+            .upgrade()
+            .map(|item| {
+                crate::lengths::logical_position_to_api(item.map_to_window(item.geometry().origin))
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the size of the item. Returns a zero size if the item was destroyed.
+    pub fn size(&self) -> LogicalSize {
+        self.item
+            .upgrade()
+            .map(|item| crate::lengths::logical_size_to_api(item.geometry().size))
+            .unwrap_or_default()
+    }
+
+    /// Returns true if the item is visible in the window, meaning that it isn't clipped away
+    /// by one of its parents. Returns false if the item was destroyed.
+    pub fn is_visible(&self) -> bool {
+        self.item.upgrade().is_some_and(|item| item.is_visible())
+    }
+
+    /// Returns false if the item was destroyed, for example because the `if` condition that
+    /// created it became false.
+    pub fn is_valid(&self) -> bool {
+        self.item.upgrade().is_some()
+    }
 }
 
 mod weak_handle {
@@ -1173,3 +1677,128 @@ pub fn set_xdg_app_id(app_id: impl Into<SharedString>) -> Result<(), PlatformErr
         |ctx| ctx.set_xdg_app_id(app_id.into()),
     )
 }
+
+/// Sets the handler that decides whether `@open-url(...)` in .slint files may open a url.
+///
+/// The handler is called with the url and returns true to let the platform open it, for example
+/// in the web browser, or false to deny it. Applications that want to handle some urls themselves,
+/// such as links to other parts of the application, do so in the handler and return false.
+///
+/// Without a handler, only `http`, `https` and `mailto` urls are opened.
+///
+/// ```rust,no_run
+/// slint::set_open_url_handler(|url| url.starts_with("https://slint.dev/")).unwrap();
+/// ```
+pub fn set_open_url_handler(handler: impl Fn(&str) -> bool + 'static) -> Result<(), PlatformError> {
+    crate::context::with_global_context(
+        || Err(crate::platform::PlatformError::NoPlatform),
+        |ctx| *ctx.0.open_url_handler.borrow_mut() = Some(Box::new(handler)),
+    )
+}
+
+/// Describes when animations stop requesting new frames to save power, see [`set_animation_throttling()`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AnimationThrottling {
+    /// The animations of a window are paused while the window is hidden, minimized, or fully
+    /// covered by other windows. They resume where they would be when the window becomes visible.
+    #[default]
+    WhenHidden,
+    /// Like [`Self::WhenHidden`], and the animations additionally run at a reduced frame rate while
+    /// the computer runs on battery. Detecting the battery is supported by the winit backend on
+    /// Linux and Windows.
+    WhenHiddenOrOnBattery,
+    /// The animations always run at the full frame rate.
+    Never,
+}
+
+/// Sets when animations stop requesting new frames to save power. By default, the animations of
+/// a window are paused while the window is hidden, minimized, or fully covered by other windows.
+///
+/// ```rust,no_run
+/// slint::set_animation_throttling(slint::AnimationThrottling::WhenHiddenOrOnBattery);
+/// ```
+pub fn set_animation_throttling(throttling: AnimationThrottling) {
+    crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| driver.set_throttling(throttling));
+}
+
+/// Provides access to the system clipboard for plain text, HTML and images.
+///
+/// Use [`Clipboard::default_clipboard()`] for the clipboard that copy and paste use, and
+/// [`Clipboard::selection()`] for the primary selection that X11 and Wayland offer on middle click.
+/// On platforms without a selection clipboard, writing to it does nothing and reading from it returns `None`.
+///
+/// The Slint platform must be initialized, for example by creating a component, before using the clipboard.
+///
+/// ```rust,no_run
+/// let clipboard = slint::Clipboard::default_clipboard();
+/// clipboard.set_html("<b>Hello</b>", "Hello");
+/// if let Some(image) = clipboard.image() {
+///     println!("Pasted an image of {}x{} pixels", image.width(), image.height());
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Clipboard(crate::platform::Clipboard);
+
+impl Clipboard {
+    /// Returns the clipboard used for copy and paste.
+    pub fn default_clipboard() -> Self {
+        Self(crate::platform::Clipboard::DefaultClipboard)
+    }
+
+    /// Returns the primary selection clipboard, which holds the currently selected text on X11 and Wayland.
+    pub fn selection() -> Self {
+        Self(crate::platform::Clipboard::SelectionClipboard)
+    }
+
+    fn data(&self) -> Option<crate::platform::ClipboardData> {
+        crate::context::GLOBAL_CONTEXT
+            .with(|ctx| ctx.get().and_then(|ctx| ctx.platform().clipboard_data(self.0.clone())))
+    }
+
+    fn set_data(&self, data: crate::platform::ClipboardData) {
+        crate::context::GLOBAL_CONTEXT.with(|ctx| {
+            if let Some(ctx) = ctx.get() {
+                ctx.platform().set_clipboard_data(data, self.0.clone())
+            }
+        })
+    }
+
+    /// Returns the plain text in the clipboard, if any.
+    pub fn text(&self) -> Option<SharedString> {
+        self.data().and_then(|data| data.text).map(Into::into)
+    }
+
+    /// Replaces the content of the clipboard with the given plain text.
+    pub fn set_text(&self, text: &str) {
+        self.set_data(crate::platform::ClipboardData {
+            text: Some(text.into()),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the HTML in the clipboard, if any.
+    pub fn html(&self) -> Option<SharedString> {
+        self.data().and_then(|data| data.html).map(Into::into)
+    }
+
+    /// Replaces the content of the clipboard with the given HTML. `alt_text` is offered to
+    /// applications that can only paste plain text.
+    pub fn set_html(&self, html: &str, alt_text: &str) {
+        self.set_data(crate::platform::ClipboardData {
+            text: Some(alt_text.into()),
+            html: Some(html.into()),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the image in the clipboard, if any.
+    pub fn image(&self) -> Option<SharedPixelBuffer<Rgba8Pixel>> {
+        self.data().and_then(|data| data.image)
+    }
+
+    /// Replaces the content of the clipboard with the given image.
+    pub fn set_image(&self, image: SharedPixelBuffer<Rgba8Pixel>) {
+        self.set_data(crate::platform::ClipboardData { image: Some(image), ..Default::default() })
+    }
+}
@@ -64,6 +64,46 @@ pub fn new<
         }))))
     }
 
+    /// Create a `ComponentFactory` that doesn't create a new component, but moves the existing
+    /// `component` into the `ComponentContainer` that uses the factory. The component keeps its
+    /// state, and is no longer shown by the `ComponentContainer` it was embedded in before.
+    ///
+    /// Create a new factory each time the component is moved: assigning a `ComponentContainer`
+    /// the factory that it already uses doesn't embed the component again.
+    ///
+    /// The container doesn't show anything if the component can't be embedded, for example
+    /// because it's shown in a different window than the container.
+    pub fn from_component<
+        X: vtable::HasStaticVTable<ItemTreeVTable> + 'static,
+        T: ComponentHandle<WeakInner = vtable::VWeak<ItemTreeVTable, X>> + 'static,
+    >(
+        component: T,
+    ) -> Self {
+        let item_tree = vtable::VRc::into_dyn(component.as_weak().inner().upgrade().unwrap());
+        Self(Some(ComponentFactoryInner(Rc::new(move |ctx| -> Option<ItemTreeRc> {
+            // Refresh the area where the component was shown before
+            let root = crate::items::ItemRc::new(item_tree.clone(), 0);
+            if let Some(window_adapter) = root.window_adapter() {
+                let region = crate::properties::evaluate_no_tracking(|| {
+                    let geometry = root.geometry();
+                    crate::lengths::LogicalRect::new(
+                        root.map_to_window(geometry.origin),
+                        geometry.size,
+                    )
+                });
+                if !region.is_empty() {
+                    window_adapter.renderer().mark_dirty_region(region.into());
+                    window_adapter.request_redraw();
+                }
+            }
+
+            vtable::VRc::borrow_pin(&item_tree)
+                .as_ref()
+                .embed_component(&ctx.parent_item_tree, ctx.parent_item_tree_index)
+                .then(|| item_tree.clone())
+        }))))
+    }
+
     /// Build a `Component`
     pub(crate) fn build(&self, ctx: FactoryContext) -> Option<ItemTreeRc> {
         self.0.as_ref().and_then(move |b| (b.0)(ctx))
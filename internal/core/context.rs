@@ -23,6 +23,10 @@ pub(crate) struct SlintContextInner {
         core::cell::RefCell<Option<alloc::vec::Vec<&'static str>>>,
     pub(crate) window_shown_hook:
         core::cell::RefCell<Option<Box<dyn FnMut(&Rc<dyn crate::platform::WindowAdapter>)>>>,
+    /// The values exposed by the `System` global
+    pub(crate) system: crate::system::SystemState,
+    /// Decides whether `@open-url(...)` may open a url, see [`crate::api::set_open_url_handler`]
+    pub(crate) open_url_handler: core::cell::RefCell<Option<Box<dyn Fn(&str) -> bool>>>,
     #[cfg(all(unix, not(target_os = "macos")))]
     xdg_app_id: core::cell::RefCell<Option<crate::SharedString>>,
     #[cfg(feature = "tr")]
@@ -44,6 +48,8 @@ pub fn new(platform: Box<dyn Platform + 'static>) -> Self {
             translations_dirty: Box::pin(Property::new_named(0, "SlintContext::translations")),
             translations_bundle_languages: Default::default(),
             window_shown_hook: Default::default(),
+            system: Default::default(),
+            open_url_handler: Default::default(),
             #[cfg(all(unix, not(target_os = "macos")))]
             xdg_app_id: Default::default(),
             #[cfg(feature = "tr")]
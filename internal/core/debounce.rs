@@ -0,0 +1,285 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+    Helpers that coalesce rapid invocations of a callback, built on top of the [`Timer`].
+*/
+
+#![warn(missing_docs)]
+
+use crate::timers::{Timer, TimerMode};
+use alloc::boxed::Box;
+use alloc::rc::{Rc, Weak};
+use core::cell::{Cell, RefCell};
+use core::time::Duration;
+
+struct RateLimiterInner<Args> {
+    timer: Timer,
+    delay: Cell<Duration>,
+    /// The arguments of the last call that wasn't forwarded to the callback yet
+    pending: RefCell<Option<Args>>,
+    callback: RefCell<Box<dyn FnMut(Args)>>,
+}
+
+impl<Args: 'static> RateLimiterInner<Args> {
+    fn new(delay: Duration, callback: impl FnMut(Args) + 'static) -> Rc<Self> {
+        Rc::new(Self {
+            timer: Timer::default(),
+            delay: Cell::new(delay),
+            pending: RefCell::new(None),
+            callback: RefCell::new(Box::new(callback)),
+        })
+    }
+
+    /// Starts the timer, which calls `on_timeout` with self when it fires
+    fn start_timer(self: &Rc<Self>, on_timeout: fn(&Rc<Self>)) {
+        let weak: Weak<Self> = Rc::downgrade(self);
+        self.timer.start(TimerMode::SingleShot, self.delay.get(), move || {
+            if let Some(this) = weak.upgrade() {
+                on_timeout(&this);
+            }
+        });
+    }
+
+    /// Invokes the callback with the pending arguments, if any.
+    /// Returns false if there was nothing to invoke.
+    fn invoke_pending(&self) -> bool {
+        // A call from within the callback is ignored
+        let Ok(mut callback) = self.callback.try_borrow_mut() else { return false };
+        let Some(args) = self.pending.borrow_mut().take() else { return false };
+        callback(args);
+        true
+    }
+
+    fn invoke(&self, args: Args) {
+        if let Ok(mut callback) = self.callback.try_borrow_mut() {
+            callback(args);
+        }
+    }
+}
+
+/// A Debouncer forwards calls to a callback only once the calls stopped for a given delay.
+///
+/// Each call to [`Self::call()`] restarts the delay, and when the delay elapses, the callback is
+/// invoked once, with the arguments of the last call. This is typically used for search-as-you-type,
+/// to start the search only once the user stopped typing.
+///
+/// The callback is invoked from the event loop. The Debouncer can be cloned: the clones share the
+/// same pending call. Dropping the last clone cancels the pending call.
+///
+/// ## Example
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint! {
+///     export component SearchBox inherits Window {
+///         callback edited(string);
+///         TextInput { edited => { root.edited(self.text); } }
+///     }
+/// }
+/// let search_box = SearchBox::new().unwrap();
+/// let debouncer = slint::Debouncer::new(std::time::Duration::from_millis(300), |text: slint::SharedString| {
+///     println!("searching for {text}");
+/// });
+/// search_box.on_edited(move |text| debouncer.call(text));
+/// ```
+pub struct Debouncer<Args>(Rc<RateLimiterInner<Args>>);
+
+impl<Args> Clone for Debouncer<Args> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<Args: 'static> Debouncer<Args> {
+    /// Creates a Debouncer that invokes `callback` once the calls stopped for `delay`.
+    pub fn new(delay: Duration, callback: impl FnMut(Args) + 'static) -> Self {
+        Self(RateLimiterInner::new(delay, callback))
+    }
+
+    /// Schedules a call of the callback with `args` after the delay, replacing the arguments of any
+    /// pending call.
+    pub fn call(&self, args: Args) {
+        *self.0.pending.borrow_mut() = Some(args);
+        self.0.start_timer(|this| {
+            this.invoke_pending();
+        });
+    }
+
+    /// Invokes the callback now if a call is pending, instead of waiting for the delay.
+    ///
+    /// This is useful for example when the user presses enter in a search field.
+    pub fn flush(&self) {
+        self.0.timer.stop();
+        self.0.invoke_pending();
+    }
+
+    /// Cancels the pending call, if any.
+    pub fn cancel(&self) {
+        self.0.timer.stop();
+        self.0.pending.borrow_mut().take();
+    }
+
+    /// Returns true if a call is waiting for the delay to elapse.
+    pub fn is_pending(&self) -> bool {
+        self.0.pending.borrow().is_some()
+    }
+
+    /// Returns the delay after which the callback is invoked.
+    pub fn delay(&self) -> Duration {
+        self.0.delay.get()
+    }
+
+    /// Changes the delay. It applies from the next call to [`Self::call()`].
+    pub fn set_delay(&self, delay: Duration) {
+        self.0.delay.set(delay);
+    }
+}
+
+/// A CallbackThrottle forwards calls to a callback at most once per interval.
+///
+/// The first call is forwarded immediately. The calls made during the following interval are
+/// coalesced: at the end of the interval, the callback is invoked once with the arguments of the last
+/// of these calls, and a new interval starts. This is typically used to react to a slider or to the
+/// resizing of a window without doing an expensive computation for each intermediate value.
+///
+/// The CallbackThrottle can be cloned: the clones share the same interval.
+///
+/// ## Example
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint! {
+///     import { Slider } from "std-widgets.slint";
+///     export component Settings inherits Window {
+///         callback volume-changed(float);
+///         Slider { changed(value) => { root.volume-changed(value); } }
+///     }
+/// }
+/// let settings = Settings::new().unwrap();
+/// let throttle = slint::CallbackThrottle::new(std::time::Duration::from_millis(100), |volume: f32| {
+///     println!("new volume {volume}");
+/// });
+/// settings.on_volume_changed(move |volume| throttle.call(volume));
+/// ```
+pub struct CallbackThrottle<Args>(Rc<RateLimiterInner<Args>>);
+
+impl<Args> Clone for CallbackThrottle<Args> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<Args: 'static> CallbackThrottle<Args> {
+    /// Creates a CallbackThrottle that invokes `callback` at most once per `interval`.
+    pub fn new(interval: Duration, callback: impl FnMut(Args) + 'static) -> Self {
+        Self(RateLimiterInner::new(interval, callback))
+    }
+
+    /// Invokes the callback with `args` now if the interval since the last invocation elapsed,
+    /// otherwise at the end of the interval.
+    pub fn call(&self, args: Args) {
+        if self.0.timer.running() {
+            *self.0.pending.borrow_mut() = Some(args);
+            return;
+        }
+        self.start_interval();
+        self.0.invoke(args);
+    }
+
+    fn start_interval(&self) {
+        fn on_interval_end<Args: 'static>(this: &Rc<RateLimiterInner<Args>>) {
+            if this.pending.borrow().is_some() {
+                // Calls made while the callback runs are throttled by a new interval
+                this.start_timer(on_interval_end);
+                this.invoke_pending();
+            }
+        }
+        self.0.start_timer(on_interval_end);
+    }
+
+    /// Cancels the pending call, if any. The next call is forwarded immediately.
+    pub fn cancel(&self) {
+        self.0.timer.stop();
+        self.0.pending.borrow_mut().take();
+    }
+
+    /// Returns true if a call is waiting for the end of the interval.
+    pub fn is_pending(&self) -> bool {
+        self.0.pending.borrow().is_some()
+    }
+
+    /// Returns the minimum interval between two invocations of the callback.
+    pub fn interval(&self) -> Duration {
+        self.0.delay.get()
+    }
+
+    /// Changes the minimum interval between two invocations of the callback.
+    /// It applies from the next interval.
+    pub fn set_interval(&self, interval: Duration) {
+        self.0.delay.set(interval);
+    }
+}
+
+/**
+```rust
+i_slint_backend_testing::init_no_event_loop();
+use slint::{CallbackThrottle, Debouncer};
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+let calls = Rc::new(RefCell::new(Vec::new()));
+let debouncer = Debouncer::new(Duration::from_millis(100), {
+    let calls = calls.clone();
+    move |v: i32| calls.borrow_mut().push(v)
+});
+debouncer.call(1);
+i_slint_core::tests::slint_mock_elapsed_time(60);
+debouncer.clone().call(2);
+assert!(debouncer.is_pending());
+i_slint_core::tests::slint_mock_elapsed_time(60);
+assert!(calls.borrow().is_empty());
+i_slint_core::tests::slint_mock_elapsed_time(50);
+assert_eq!(*calls.borrow(), [2]);
+assert!(!debouncer.is_pending());
+
+debouncer.call(3);
+debouncer.flush();
+assert_eq!(*calls.borrow(), [2, 3]);
+i_slint_core::tests::slint_mock_elapsed_time(200);
+assert_eq!(*calls.borrow(), [2, 3]);
+
+debouncer.call(4);
+debouncer.cancel();
+i_slint_core::tests::slint_mock_elapsed_time(200);
+assert_eq!(*calls.borrow(), [2, 3]);
+
+// Dropping the debouncer cancels the pending call
+debouncer.call(5);
+drop(debouncer);
+i_slint_core::tests::slint_mock_elapsed_time(200);
+assert_eq!(*calls.borrow(), [2, 3]);
+
+calls.borrow_mut().clear();
+let throttle = CallbackThrottle::new(Duration::from_millis(100), {
+    let calls = calls.clone();
+    move |v: i32| calls.borrow_mut().push(v)
+});
+throttle.call(1);
+assert_eq!(*calls.borrow(), [1]);
+i_slint_core::tests::slint_mock_elapsed_time(30);
+throttle.call(2);
+throttle.call(3);
+assert_eq!(*calls.borrow(), [1]);
+i_slint_core::tests::slint_mock_elapsed_time(80);
+assert_eq!(*calls.borrow(), [1, 3]);
+// A new interval started with the trailing call
+throttle.call(4);
+assert_eq!(*calls.borrow(), [1, 3]);
+i_slint_core::tests::slint_mock_elapsed_time(100);
+assert_eq!(*calls.borrow(), [1, 3, 4]);
+// No call during the last interval: the next call is forwarded immediately
+i_slint_core::tests::slint_mock_elapsed_time(100);
+throttle.call(5);
+assert_eq!(*calls.borrow(), [1, 3, 4, 5]);
+```
+*/
+#[cfg(doctest)]
+const _DEBOUNCE_AND_THROTTLE: () = ();
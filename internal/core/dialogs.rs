@@ -0,0 +1,163 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+Native dialogs to open and save files and to pick folders.
+
+The dialogs are shown by the platform with [`Platform::show_file_dialog()`](crate::platform::Platform::show_file_dialog),
+and the functions of this module return futures that complete on the Slint event loop when the user closes the dialog.
+Use them from a future spawned with `slint::spawn_local()`:
+
+```rust,no_run
+# slint::slint!{ export component App inherits Window {} }
+# let app = App::new().unwrap();
+let app_weak = app.as_weak();
+slint::spawn_local(async move {
+    let app = app_weak.unwrap();
+    let options = slint::dialogs::FileDialogOptions::default()
+        .with_title("Open Image")
+        .with_filter("Images", &["png", "jpg"])
+        .with_parent(app.window());
+    if let Ok(Some(path)) = slint::dialogs::open_file(options).await {
+        println!("Opening {}", path.display());
+    }
+})
+.unwrap();
+```
+
+The dialogs don't block the event loop: the windows keep rendering and animating while a dialog is open.
+*/
+
+use crate::api::{PlatformError, Window};
+use crate::window::{WindowAdapter, WindowInner};
+use alloc::rc::{Rc, Weak};
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::path::PathBuf;
+
+/// The kind of a file dialog, passed to [`Platform::show_file_dialog()`](crate::platform::Platform::show_file_dialog).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FileDialogKind {
+    /// Select an existing file to open.
+    OpenFile,
+    /// Select the path of a file to save, which may not exist yet.
+    SaveFile,
+    /// Select an existing folder.
+    PickFolder,
+}
+
+/// A filter of the files shown in a file dialog, such as "Images" for the `png` and `jpg` extensions.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FileFilter {
+    /// The name of the filter shown to the user.
+    pub name: String,
+    /// The file extensions, without the leading dot.
+    pub extensions: Vec<String>,
+}
+
+/// The options of a file dialog.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FileDialogOptions {
+    /// The title of the dialog. If empty, the platform chooses one.
+    pub title: String,
+    /// The folder shown when the dialog opens.
+    pub directory: Option<PathBuf>,
+    /// The file name proposed by a save dialog.
+    pub file_name: Option<String>,
+    /// The filters the user can choose from. If empty, all files are shown.
+    pub filters: Vec<FileFilter>,
+    parent: Option<ParentWindow>,
+}
+
+/// The window a dialog is modal for, compared by identity
+#[derive(Clone)]
+struct ParentWindow(Weak<dyn WindowAdapter>);
+
+impl core::fmt::Debug for ParentWindow {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("ParentWindow")
+    }
+}
+
+impl PartialEq for ParentWindow {
+    fn eq(&self, other: &Self) -> bool {
+        Weak::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for ParentWindow {}
+
+impl FileDialogOptions {
+    /// Returns the options with the given title.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Returns the options with the folder shown when the dialog opens.
+    pub fn with_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    /// Returns the options with the file name proposed by a save dialog.
+    pub fn with_file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Returns the options with the window the dialog belongs to. The dialog is shown above that window,
+    /// which doesn't accept input until the dialog is closed.
+    pub fn with_parent(mut self, window: &Window) -> Self {
+        self.parent =
+            Some(ParentWindow(Rc::downgrade(&WindowInner::from_pub(window).window_adapter())));
+        self
+    }
+
+    /// Returns the window adapter of the window set with [`Self::with_parent()`], if it's still alive.
+    /// Implementations of [`Platform::show_file_dialog()`](crate::platform::Platform::show_file_dialog)
+    /// use it to make the dialog modal for that window.
+    pub fn parent_window_adapter(&self) -> Option<Rc<dyn WindowAdapter>> {
+        self.parent.as_ref().and_then(|parent| parent.0.upgrade())
+    }
+
+    /// Returns the options with an additional filter for files with the given extensions.
+    pub fn with_filter(mut self, name: impl Into<String>, extensions: &[&str]) -> Self {
+        self.filters.push(FileFilter {
+            name: name.into(),
+            extensions: extensions.iter().map(|e| String::from(*e)).collect(),
+        });
+        self
+    }
+}
+
+async fn show_file_dialog(
+    kind: FileDialogKind,
+    options: FileDialogOptions,
+) -> Result<Option<PathBuf>, PlatformError> {
+    let dialog = crate::context::GLOBAL_CONTEXT.with(|ctx| match ctx.get() {
+        Some(ctx) => Ok(ctx.platform().show_file_dialog(kind, options)),
+        None => Err(PlatformError::NoPlatform),
+    })?;
+    dialog.await
+}
+
+/// Shows a dialog to select a file to open. Resolves to the path of the file, or `None` if the
+/// user canceled the dialog.
+pub async fn open_file(options: FileDialogOptions) -> Result<Option<PathBuf>, PlatformError> {
+    show_file_dialog(FileDialogKind::OpenFile, options).await
+}
+
+/// Shows a dialog to select where to save a file. Resolves to the path of the file, or `None` if
+/// the user canceled the dialog. The file may not exist yet.
+pub async fn save_file(options: FileDialogOptions) -> Result<Option<PathBuf>, PlatformError> {
+    show_file_dialog(FileDialogKind::SaveFile, options).await
+}
+
+/// Shows a dialog to select a folder. Resolves to the path of the folder, or `None` if the user
+/// canceled the dialog.
+pub async fn pick_folder(options: FileDialogOptions) -> Result<Option<PathBuf>, PlatformError> {
+    show_file_dialog(FileDialogKind::PickFolder, options).await
+}
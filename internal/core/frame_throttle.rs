@@ -0,0 +1,197 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+    Frame pacing for platforms that drive their own event loop, such as microcontrollers.
+*/
+
+#![warn(missing_docs)]
+
+use core::cell::Cell;
+use core::time::Duration;
+use portable_atomic::{AtomicBool, Ordering};
+
+/// A flag that is raised by the vertical synchronization (vsync) or tearing effect (TE) signal
+/// of a display, and consumed by a [`FrameThrottle`] created with [`FrameThrottle::with_vsync()`].
+///
+/// Call [`Self::notify()`] from the interrupt handler of the signal. This is the only part of the
+/// frame pacing that is safe to call from an interrupt handler.
+///
+/// ```rust
+/// use slint::platform::VsyncSignal;
+/// static VSYNC: VsyncSignal = VsyncSignal::new();
+///
+/// // In the interrupt handler of the TE pin of the display:
+/// VSYNC.notify();
+/// ```
+#[derive(Debug, Default)]
+pub struct VsyncSignal(AtomicBool);
+
+impl VsyncSignal {
+    /// Creates a new signal that wasn't raised yet.
+    pub const fn new() -> Self {
+        Self(AtomicBool::new(false))
+    }
+
+    /// Raises the signal: the display is ready for a new frame.
+    pub fn notify(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    /// Returns true if the signal was raised since the last frame.
+    pub fn is_raised(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+
+    fn take(&self) -> bool {
+        self.0.swap(false, Ordering::AcqRel)
+    }
+}
+
+/// Paces the frames rendered by the event loop of a platform, and computes how long the event loop
+/// can sleep.
+///
+/// Forward [`Platform::request_redraw_at()`](crate::platform::Platform::request_redraw_at) to
+/// [`Self::request_redraw_at()`], then call [`Self::begin_frame()`] before drawing the window in each
+/// iteration of the event loop, and sleep for [`Self::time_until_next_frame()`].
+///
+/// A `FrameThrottle` created with [`Self::new()`] renders at most one frame per interval. When the
+/// display provides a vsync or tearing effect (TE) signal, use [`Self::with_vsync()`] instead, to
+/// render only after the signal, without tearing.
+///
+/// ```rust,no_run
+/// # use std::rc::Rc;
+/// # use slint::platform::{FrameThrottle, Platform, WindowAdapter, software_renderer::MinimalSoftwareWindow};
+/// struct MyPlatform {
+///     window: Rc<MinimalSoftwareWindow>,
+///     frame_throttle: FrameThrottle,
+///     //...
+/// }
+///
+/// impl Platform for MyPlatform {
+///     fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, slint::PlatformError> {
+///         Ok(self.window.clone())
+///     }
+///     fn request_redraw_at(&self, deadline: core::time::Duration) {
+///         self.frame_throttle.request_redraw_at(deadline);
+///     }
+///     fn run_event_loop(&self) -> Result<(), slint::PlatformError> {
+///         loop {
+///             slint::platform::update_timers_and_animations();
+///             // ... dispatch the input events to the window ...
+///             if self.frame_throttle.begin_frame() {
+///                 self.window.draw_if_needed(|renderer| {
+///                     // ... render to the display ...
+///                 });
+///             }
+///             match self.frame_throttle.time_until_next_frame() {
+///                 Some(duration) if duration.is_zero() => continue,
+///                 Some(duration) => { /* ... schedule a timer interrupt in `duration` ... */ }
+///                 None => {}
+///             }
+///             // ... wait for an interrupt ...
+///         }
+///     }
+///     // ...
+/// #   fn duration_since_start(&self) -> core::time::Duration { todo!() }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct FrameThrottle {
+    frame_interval: Duration,
+    vsync: Option<&'static VsyncSignal>,
+    last_frame: Cell<Option<Duration>>,
+    redraw_deadline: Cell<Option<Duration>>,
+}
+
+impl FrameThrottle {
+    /// Creates a `FrameThrottle` that renders at most one frame per `frame_interval`, for displays
+    /// without a vsync signal. For example, use an interval of 16ms for 60 frames per second.
+    pub const fn new(frame_interval: Duration) -> Self {
+        Self {
+            frame_interval,
+            vsync: None,
+            last_frame: Cell::new(None),
+            redraw_deadline: Cell::new(None),
+        }
+    }
+
+    /// Creates a `FrameThrottle` that renders a frame only after the display raised the `signal`.
+    ///
+    /// The interrupt of the signal must be enabled while the event loop sleeps, so that it wakes
+    /// up the event loop when a frame is due.
+    pub const fn with_vsync(signal: &'static VsyncSignal) -> Self {
+        Self {
+            frame_interval: Duration::ZERO,
+            vsync: Some(signal),
+            last_frame: Cell::new(None),
+            redraw_deadline: Cell::new(None),
+        }
+    }
+
+    /// Requests a frame at the given time, as returned by
+    /// [`Platform::duration_since_start()`](crate::platform::Platform::duration_since_start).
+    ///
+    /// Call this function from your implementation of
+    /// [`Platform::request_redraw_at()`](crate::platform::Platform::request_redraw_at).
+    pub fn request_redraw_at(&self, deadline: Duration) {
+        let deadline = self.redraw_deadline.get().map_or(deadline, |d| d.min(deadline));
+        self.redraw_deadline.set(Some(deadline));
+    }
+
+    /// Returns true if a frame was requested.
+    pub fn redraw_pending(&self) -> bool {
+        self.redraw_deadline.get().is_some()
+    }
+
+    /// Returns true if the event loop should render a frame now, that is when a requested frame is
+    /// due and the display is ready for it. Call the `draw_if_needed()` function of the window if it
+    /// returns true.
+    pub fn begin_frame(&self) -> bool {
+        let now = now();
+        if self.redraw_deadline.get().is_none_or(|deadline| deadline > now) {
+            return false;
+        }
+        if let Some(vsync) = self.vsync {
+            if !vsync.take() {
+                return false;
+            }
+        } else if self.last_frame.get().is_some_and(|last| last + self.frame_interval > now) {
+            return false;
+        }
+        self.last_frame.set(Some(now));
+        // Frames requested while drawing are kept for the next iteration
+        self.redraw_deadline.set(None);
+        true
+    }
+
+    /// Returns how long the event loop can sleep before the next call of [`Self::begin_frame()`], or
+    /// before the next timer expires.
+    ///
+    /// `None` is returned when there is no deadline: the event loop can sleep until the next
+    /// interrupt, such as an input event or the vsync signal.
+    pub fn time_until_next_frame(&self) -> Option<Duration> {
+        let now = now();
+        let frame = self.redraw_deadline.get().and_then(|deadline| match self.vsync {
+            Some(vsync) if deadline <= now => vsync.is_raised().then_some(Duration::ZERO),
+            Some(_) => Some(deadline - now),
+            None => {
+                let earliest = self
+                    .last_frame
+                    .get()
+                    .map_or(deadline, |last| deadline.max(last + self.frame_interval));
+                Some(earliest.saturating_sub(now))
+            }
+        });
+        match (frame, crate::platform::duration_until_next_timer_update()) {
+            (Some(frame), Some(timer)) => Some(frame.min(timer)),
+            (frame, timer) => frame.or(timer),
+        }
+    }
+}
+
+fn now() -> Duration {
+    crate::context::GLOBAL_CONTEXT
+        .with(|ctx| ctx.get().map(|ctx| ctx.platform().duration_since_start()))
+        .unwrap_or_default()
+}
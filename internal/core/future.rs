@@ -67,7 +67,14 @@ fn wake(self: alloc::sync::Arc<Self>) {
             let mut cx = core::task::Context::from_waker(&waker);
             if let FutureState::Running(fut) = &mut inner.fut {
                 if self.aborted.load(atomic::Ordering::Relaxed) {
-                    inner.fut = FutureState::Finished(None);
+                    let fut = core::mem::replace(&mut inner.fut, FutureState::Finished(None));
+                    let wakers = core::mem::take(&mut inner.wakers);
+                    // Drop the future without holding the lock, as its destructor may access this task
+                    drop(inner);
+                    drop(fut);
+                    for w in wakers {
+                        w.wake();
+                    }
                 } else {
                     match fut.as_mut().poll(&mut cx) {
                         Poll::Ready(val) => {
@@ -85,11 +92,57 @@ fn wake(self: alloc::sync::Arc<Self>) {
     }
 }
 
+impl<T: 'static> FutureRunner<T> {
+    fn abort(self: alloc::sync::Arc<Self>) {
+        if !self.aborted.swap(true, atomic::Ordering::Relaxed) {
+            // Wake the task so that the event loop drops the future and wakes the tasks awaiting it
+            self.wake();
+        }
+    }
+}
+
+/// Type erased [`FutureRunner`], for [`AbortHandle`]
+trait AbortTask: Send + Sync {
+    fn abort(self: alloc::sync::Arc<Self>);
+    fn is_finished(&self) -> bool;
+}
+
+impl<T: 'static> AbortTask for FutureRunner<T> {
+    fn abort(self: alloc::sync::Arc<Self>) {
+        FutureRunner::abort(self)
+    }
+    fn is_finished(&self) -> bool {
+        matches!(self.inner().fut, FutureState::Finished(_))
+    }
+}
+
 /// The return value of the `spawn_local()` function
 ///
 /// Can be used to abort the future, or to get the value from a different thread with `.await`
 ///
 /// This trait implements future. Polling it after it finished or aborted may result in a panic.
+/// Use [`JoinHandle::join()`] to get an error instead when the task was aborted.
+///
+/// ## Example
+/// ```rust
+/// # i_slint_backend_testing::init_integration_test_with_mock_time();
+/// slint::invoke_from_event_loop(|| {
+///     let download = slint::spawn_local(std::future::pending::<String>()).unwrap();
+///     // Give the abort handle to the UI, for example to a "Cancel" button
+///     let abort_handle = download.abort_handle();
+///     slint::spawn_local(async move {
+///         match download.join().await {
+///             Ok(text) => println!("Downloaded {text}"),
+///             Err(err) => println!("Download failed: {err}"),
+///         }
+///         slint::quit_event_loop().unwrap();
+///     })
+///     .unwrap();
+///     abort_handle.abort();
+/// })
+/// .unwrap();
+/// slint::run_event_loop().unwrap();
+/// ```
 pub struct JoinHandle<T>(alloc::sync::Arc<FutureRunner<T>>);
 
 impl<T> Future for JoinHandle<T> {
@@ -112,19 +165,90 @@ fn poll(self: Pin<&mut Self>, cx: &mut core::task::Context<'_>) -> Poll<Self::Ou
     }
 }
 
-impl<T> JoinHandle<T> {
+impl<T: 'static> JoinHandle<T> {
     /// If the future hasn't completed yet, this will make the event loop stop polling the corresponding future and it will be dropped
     ///
-    /// Once this handle has been aborted, it can no longer be polled
-    pub fn abort(self) {
-        self.0.aborted.store(true, atomic::Ordering::Relaxed);
+    /// Once this handle has been aborted, it can no longer be polled, but [`Self::join()`] returns an error.
+    pub fn abort(&self) {
+        self.0.clone().abort();
+    }
+
+    /// Returns a handle that can abort the task without owning the `JoinHandle`, for example while
+    /// another task awaits the `JoinHandle`.
+    pub fn abort_handle(&self) -> AbortHandle {
+        AbortHandle(self.0.clone())
     }
+
     /// Checks if the task associated with this `JoinHandle` has finished.
+    ///
+    /// A task that was aborted is also considered finished, once its future was dropped.
     pub fn is_finished(&self) -> bool {
         matches!(self.0.inner().fut, FutureState::Finished(_))
     }
+
+    /// Returns true if [`Self::abort()`] or [`AbortHandle::abort()`] was called for this task.
+    pub fn is_aborted(&self) -> bool {
+        self.0.aborted.load(atomic::Ordering::Relaxed)
+    }
+
+    /// Waits for the task to finish, and returns its output, or an error if it was aborted before
+    /// it completed.
+    pub async fn join(self) -> Result<T, JoinError> {
+        core::future::poll_fn(|cx| {
+            let mut inner = self.0.inner();
+            match &mut inner.fut {
+                FutureState::Running(_) => {
+                    let waker = cx.waker();
+                    if !inner.wakers.iter().any(|w| w.will_wake(waker)) {
+                        inner.wakers.push(waker.clone());
+                    }
+                    Poll::Pending
+                }
+                FutureState::Finished(x) => Poll::Ready(x.take().ok_or(JoinError(()))),
+            }
+        })
+        .await
+    }
+}
+
+/// A handle that aborts a task spawned with `spawn_local()`, returned by [`JoinHandle::abort_handle()`]
+///
+/// Unlike the `JoinHandle`, this handle doesn't give access to the output of the task. It can be
+/// cloned, and dropping it doesn't abort the task.
+#[derive(Clone)]
+pub struct AbortHandle(alloc::sync::Arc<dyn AbortTask>);
+
+impl AbortHandle {
+    /// If the future hasn't completed yet, this will make the event loop stop polling the corresponding future and it will be dropped
+    pub fn abort(&self) {
+        self.0.clone().abort();
+    }
+
+    /// Checks if the task associated with this handle has finished.
+    pub fn is_finished(&self) -> bool {
+        self.0.is_finished()
+    }
+}
+
+impl core::fmt::Debug for AbortHandle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AbortHandle").field("finished", &self.is_finished()).finish()
+    }
+}
+
+/// The error returned by [`JoinHandle::join()`] when the task was aborted before it completed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JoinError(());
+
+impl core::fmt::Display for JoinError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("the task was aborted")
+    }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for JoinError {}
+
 #[cfg(feature = "std")]
 #[allow(unsafe_code)]
 // Safety: JoinHandle doesn't access the future, only the
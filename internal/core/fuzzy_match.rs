@@ -0,0 +1,142 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Fuzzy string matching, used by the `CommandPalette` widget to filter its model.
+
+use crate::model::{Model, ModelRc, VecModel};
+use crate::SharedString;
+use alloc::vec::Vec;
+
+const SCORE_MATCH: i32 = 16;
+const BONUS_CONSECUTIVE: i32 = 16;
+const BONUS_WORD_START: i32 = 24;
+const PENALTY_GAP: i32 = 1;
+/// Skipping characters before the first match is penalized, but only up to this amount
+const MAX_LEADING_PENALTY: i32 = 8;
+
+/// Returns a score for how well `pattern` matches `text`, or `None` if it doesn't match.
+///
+/// The pattern matches if all its characters appear in the same order in `text`, ignoring case
+/// and ignoring the white-spaces of the pattern.
+/// Matches at the start of words and consecutive matches get a higher score.
+/// An empty pattern matches everything with a score of 0.
+pub fn fuzzy_match_score(text: &str, pattern: &str) -> Option<i32> {
+    let mut pattern = pattern.chars().filter(|c| !c.is_whitespace()).flat_map(char::to_lowercase);
+    let Some(mut current) = pattern.next() else {
+        return Some(0);
+    };
+
+    let mut score = 0;
+    let mut first_match = true;
+    let mut gap = 0;
+    let mut previous: Option<char> = None;
+    for c in text.chars() {
+        let is_word_start = match previous {
+            None => true,
+            Some(p) => !p.is_alphanumeric() || (p.is_lowercase() && c.is_uppercase()),
+        };
+        previous = Some(c);
+
+        if c.to_lowercase().next() != Some(current) {
+            gap += 1;
+            continue;
+        }
+
+        score += SCORE_MATCH;
+        if is_word_start {
+            score += BONUS_WORD_START;
+        }
+        if first_match {
+            score -= (gap * PENALTY_GAP).min(MAX_LEADING_PENALTY);
+        } else if gap == 0 {
+            score += BONUS_CONSECUTIVE;
+        } else {
+            score -= gap * PENALTY_GAP;
+        }
+        first_match = false;
+        gap = 0;
+
+        match pattern.next() {
+            Some(next) => current = next,
+            None => return Some(score),
+        }
+    }
+    None
+}
+
+/// Returns the indices of the `texts` that match `pattern`, sorted by decreasing score.
+///
+/// Entries with the same score keep their relative order, so an empty pattern
+/// returns all the indices in order.
+pub fn fuzzy_filter<S: AsRef<str>>(texts: impl IntoIterator<Item = S>, pattern: &str) -> Vec<i32> {
+    let mut matches = texts
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, text)| {
+            fuzzy_match_score(text.as_ref(), pattern).map(|score| (score, index as i32))
+        })
+        .collect::<Vec<_>>();
+    matches.sort_by_key(|m| core::cmp::Reverse(m.0));
+    matches.into_iter().map(|(_, index)| index).collect()
+}
+
+/// Same as [`fuzzy_filter`] but operates on a model and returns a model of indices.
+///
+/// The model is tracked, so this can be used from a binding.
+pub fn fuzzy_filter_model(model: &ModelRc<SharedString>, pattern: &str) -> ModelRc<i32> {
+    model.model_tracker().track_row_count_changes();
+    let texts = (0..model.row_count()).filter_map(|row| {
+        model.model_tracker().track_row_data_changes(row);
+        model.row_data(row)
+    });
+    ModelRc::new(VecModel::from(fuzzy_filter(texts, pattern)))
+}
+
+#[cfg(feature = "ffi")]
+mod ffi {
+    #![allow(unsafe_code)]
+
+    use super::*;
+    use crate::slice::Slice;
+    use crate::SharedVector;
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_fuzzy_filter(
+        texts: Slice<SharedString>,
+        pattern: &SharedString,
+        out: &mut SharedVector<i32>,
+    ) {
+        *out = fuzzy_filter(texts.iter(), pattern).into_iter().collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_match_score() {
+        assert_eq!(fuzzy_match_score("Open File", ""), Some(0));
+        assert_eq!(fuzzy_match_score("Open File", "  "), Some(0));
+        assert!(fuzzy_match_score("Open File", "of").is_some());
+        assert!(fuzzy_match_score("Open File", "O F").is_some());
+        assert!(fuzzy_match_score("Open File", "fo").is_none());
+        assert!(fuzzy_match_score("Open", "opened").is_none());
+        assert!(fuzzy_match_score("", "a").is_none());
+
+        // Word starts and consecutive matches are preferred
+        assert!(fuzzy_match_score("Open File", "of") > fuzzy_match_score("Profile", "of"));
+        assert!(fuzzy_match_score("Save As", "sav") > fuzzy_match_score("Show Advanced", "sav"));
+        assert!(fuzzy_match_score("toggleSidebar", "ts") > fuzzy_match_score("tabs", "ts"));
+    }
+
+    #[test]
+    fn test_fuzzy_filter() {
+        let texts = ["Close Window", "Open File", "Save File", "Open Recent"];
+        assert_eq!(fuzzy_filter(texts, ""), [0, 1, 2, 3]);
+        assert!(fuzzy_filter(texts, "xyz").is_empty());
+        assert_eq!(fuzzy_filter(texts, "open"), [1, 3]);
+        assert_eq!(fuzzy_filter(texts, "fi"), [1, 2]);
+        assert_eq!(fuzzy_filter(texts, "ow")[0], 0);
+    }
+}
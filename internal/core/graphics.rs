@@ -230,8 +230,9 @@ pub fn create_graphics_api_wgpu_27(
     instance: wgpu_27::wgpu::Instance,
     device: wgpu_27::wgpu::Device,
     queue: wgpu_27::wgpu::Queue,
+    surface_texture: Option<wgpu_27::wgpu::Texture>,
 ) -> crate::api::GraphicsAPI<'static> {
-    crate::api::GraphicsAPI::WGPU27 { instance, device, queue }
+    crate::api::GraphicsAPI::WGPU27 { instance, device, queue, surface_texture }
 }
 
 /// Internal module for use by cbindgen and the C++ platform API layer.
@@ -5,6 +5,7 @@
 This module contains color related types for the run-time library.
 */
 
+use crate::items::ColorSpace;
 use crate::properties::InterpolatedPropertyValue;
 
 #[cfg(not(feature = "std"))]
@@ -159,6 +160,35 @@ pub fn from_hsva(hue: f32, saturation: f32, value: f32, alpha: f32) -> Self {
         <RgbaColor<f32>>::from(hsva).into()
     }
 
+    /// Converts this color to the HSL color space.
+    pub fn to_hsl(&self) -> HslaColor {
+        let rgba: RgbaColor<f32> = (*self).into();
+        rgba.into()
+    }
+
+    /// Construct a color from the hue, saturation, and lightness HSL color space parameters.
+    ///
+    /// Hue is between 0 and 360, the others parameters between 0 and 1.
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        let hsla = HslaColor { hue, saturation, lightness, alpha };
+        <RgbaColor<f32>>::from(hsla).into()
+    }
+
+    /// Converts this color to the OKLCH color space.
+    pub fn to_oklch(&self) -> OklchColor {
+        let rgba: RgbaColor<f32> = (*self).into();
+        rgba.into()
+    }
+
+    /// Construct a color from the lightness, chroma, and hue OKLCH color space parameters.
+    ///
+    /// Lightness and alpha are between 0 and 1, the chroma is usually between 0 and 0.4, and the
+    /// hue is between 0 and 360. Colors outside of the sRGB gamut are clipped.
+    pub fn from_oklch(lightness: f32, chroma: f32, hue: f32, alpha: f32) -> Self {
+        let oklch = OklchColor { lightness, chroma, hue, alpha };
+        <RgbaColor<f32>>::from(oklch).into()
+    }
+
     /// Returns the red channel of the color as u8 in the range 0..255.
     #[inline(always)]
     pub fn red(self) -> u8 {
@@ -307,6 +337,63 @@ fn lerp(v1: u8, v2: u8, f: f32) -> u8 {
         Self { red, green, blue, alpha }
     }
 
+    /// Like [`Self::mix`], but interpolates in the given color space.
+    ///
+    /// `factor` is clamped to be between `0.0` and `1.0` and then applied to `self` color,
+    /// while `1.0 - factor` is applied to `other`. With [`ColorSpace::Srgb`], this is
+    /// the same as [`Self::mix`]. The hue of the HSL and OKLCH color spaces takes the shorter arc,
+    /// and is ignored for colors that don't have one (gray colors).
+    ///
+    /// ```
+    /// # use i_slint_core::graphics::Color;
+    /// # use i_slint_core::items::ColorSpace;
+    /// let red = Color::from_rgb_u8(255, 0, 0);
+    /// let blue = Color::from_rgb_u8(0, 0, 255);
+    /// assert_eq!(red.mix_in(&blue, 0.5, ColorSpace::Hsl), Color::from_rgb_u8(255, 0, 255));
+    /// ```
+    #[must_use]
+    pub fn mix_in(&self, other: &Self, factor: f32, color_space: ColorSpace) -> Self {
+        fn lerp(v1: f32, v2: f32, f: f32) -> f32 {
+            v1 * f + v2 * (1.0 - f)
+        }
+        // Interpolate along the shorter arc. A hue of `None` means the color has no hue.
+        fn lerp_hue(h1: Option<f32>, h2: Option<f32>, f: f32) -> f32 {
+            match (h1, h2) {
+                (Some(h1), Some(h2)) => {
+                    let delta = num_traits::Euclid::rem_euclid(&(h2 - h1 + 180.), &360.) - 180.;
+                    num_traits::Euclid::rem_euclid(&(h1 + delta * (1.0 - f)), &360.)
+                }
+                (Some(h), None) | (None, Some(h)) => h,
+                (None, None) => 0.,
+            }
+        }
+
+        let factor = factor.clamp(0.0, 1.0);
+        match color_space {
+            ColorSpace::Srgb => self.mix(other, factor),
+            ColorSpace::Hsl => {
+                let (a, b) = (self.to_hsl(), other.to_hsl());
+                let hue = |c: &HslaColor| (c.saturation > 0.).then_some(c.hue);
+                Self::from_hsl(
+                    lerp_hue(hue(&a), hue(&b), factor),
+                    lerp(a.saturation, b.saturation, factor),
+                    lerp(a.lightness, b.lightness, factor),
+                    lerp(a.alpha, b.alpha, factor),
+                )
+            }
+            ColorSpace::Oklch => {
+                let (a, b) = (self.to_oklch(), other.to_oklch());
+                let hue = |c: &OklchColor| (c.chroma > 0.0001).then_some(c.hue);
+                Self::from_oklch(
+                    lerp(a.lightness, b.lightness, factor),
+                    lerp(a.chroma, b.chroma, factor),
+                    lerp_hue(hue(&a), hue(&b), factor),
+                    lerp(a.alpha, b.alpha, factor),
+                )
+            }
+        }
+    }
+
     /// Returns a new version of this color with the opacity set to `alpha`.
     #[must_use]
     pub fn with_alpha(&self, alpha: f32) -> Self {
@@ -314,6 +401,43 @@ pub fn with_alpha(&self, alpha: f32) -> Self {
         rgba.alpha = alpha.clamp(0.0, 1.0);
         rgba.into()
     }
+
+    /// Returns the color in the `#rrggbb` notation, or `#rrggbbaa` if the color is not opaque.
+    pub fn to_hex_string(&self) -> crate::SharedString {
+        if self.alpha == 255 {
+            crate::format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+        } else {
+            crate::format!("#{:02x}{:02x}{:02x}{:02x}", self.red, self.green, self.blue, self.alpha)
+        }
+    }
+
+    /// Parses a color in the `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` notation.
+    /// The leading `#` is optional and surrounding white-spaces are ignored.
+    /// Returns `None` if the string is not a valid color.
+    pub fn from_hex_str(str: &str) -> Option<Self> {
+        let str = str.trim();
+        let str = str.strip_prefix('#').unwrap_or(str);
+        if !str.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |index: usize, len: usize| {
+            let value = u8::from_str_radix(str.get(index * len..(index + 1) * len)?, 16).ok()?;
+            Some(if len == 1 { value * 0x11 } else { value })
+        };
+        let (len, has_alpha) = match str.len() {
+            3 => (1, false),
+            4 => (1, true),
+            6 => (2, false),
+            8 => (2, true),
+            _ => return None,
+        };
+        Some(Self {
+            red: channel(0, len)?,
+            green: channel(1, len)?,
+            blue: channel(2, len)?,
+            alpha: if has_alpha { channel(3, len)? } else { 255 },
+        })
+    }
 }
 
 impl InterpolatedPropertyValue for Color {
@@ -355,30 +479,11 @@ fn eq(&self, other: &Self) -> bool {
 
 impl From<RgbaColor<f32>> for HsvaColor {
     fn from(col: RgbaColor<f32>) -> Self {
-        // RGB to HSL conversion from https://en.wikipedia.org/wiki/HSL_and_HSV#Color_conversion_formulae
-
-        let red = col.red;
-        let green = col.green;
-        let blue = col.blue;
-
-        let min = red.min(green).min(blue);
-        let max = red.max(green).max(blue);
+        // RGB to HSV conversion from https://en.wikipedia.org/wiki/HSL_and_HSV#Color_conversion_formulae
+        let min = col.red.min(col.green).min(col.blue);
+        let max = col.red.max(col.green).max(col.blue);
         let chroma = max - min;
-
-        #[allow(clippy::float_cmp)] // `max` is either `red`, `green` or `blue`
-        let hue = num_traits::Euclid::rem_euclid(
-            &(60.
-                * if chroma == 0.0 {
-                    0.0
-                } else if max == red {
-                    ((green - blue) / chroma) % 6.0
-                } else if max == green {
-                    2. + (blue - red) / chroma
-                } else {
-                    4. + (red - green) / chroma
-                }),
-            &360.0,
-        );
+        let hue = rgb_hue(col.red, col.green, col.blue, max, chroma);
         let saturation = if max == 0. { 0. } else { chroma / max };
 
         Self { hue, saturation, value: max, alpha: col.alpha }
@@ -387,24 +492,9 @@ fn from(col: RgbaColor<f32>) -> Self {
 
 impl From<HsvaColor> for RgbaColor<f32> {
     fn from(col: HsvaColor) -> Self {
-        // RGB to HSL conversion from https://en.wikipedia.org/wiki/HSL_and_HSV#Color_conversion_formulae
-
+        // HSV to RGB conversion from https://en.wikipedia.org/wiki/HSL_and_HSV#Color_conversion_formulae
         let chroma = col.saturation * col.value;
-
-        let hue = num_traits::Euclid::rem_euclid(&col.hue, &360.0);
-
-        let x = chroma * (1. - ((hue / 60.) % 2. - 1.).abs());
-
-        let (red, green, blue) = match (hue / 60.0) as usize {
-            0 => (chroma, x, 0.),
-            1 => (x, chroma, 0.),
-            2 => (0., chroma, x),
-            3 => (0., x, chroma),
-            4 => (x, 0., chroma),
-            5 => (chroma, 0., x),
-            _ => (0., 0., 0.),
-        };
-
+        let (red, green, blue) = hue_chroma_to_rgb(col.hue, chroma);
         let m = col.value - chroma;
 
         Self { red: red + m, green: green + m, blue: blue + m, alpha: col.alpha }
@@ -423,6 +513,194 @@ fn from(value: Color) -> Self {
     }
 }
 
+/// Computes the hue in degrees, as used by the HSV and HSL color spaces
+fn rgb_hue(red: f32, green: f32, blue: f32, max: f32, chroma: f32) -> f32 {
+    #[allow(clippy::float_cmp)] // `max` is either `red`, `green` or `blue`
+    num_traits::Euclid::rem_euclid(
+        &(60.
+            * if chroma == 0.0 {
+                0.0
+            } else if max == red {
+                ((green - blue) / chroma) % 6.0
+            } else if max == green {
+                2. + (blue - red) / chroma
+            } else {
+                4. + (red - green) / chroma
+            }),
+        &360.0,
+    )
+}
+
+/// Computes the red, green, and blue components (before adding the lightness or value offset)
+/// from the hue and chroma, as used by the HSV and HSL color spaces
+fn hue_chroma_to_rgb(hue: f32, chroma: f32) -> (f32, f32, f32) {
+    let hue = num_traits::Euclid::rem_euclid(&hue, &360.0);
+    let x = chroma * (1. - ((hue / 60.) % 2. - 1.).abs());
+    match (hue / 60.0) as usize {
+        0 => (chroma, x, 0.),
+        1 => (x, chroma, 0.),
+        2 => (0., chroma, x),
+        3 => (0., x, chroma),
+        4 => (x, 0., chroma),
+        5 => (chroma, 0., x),
+        _ => (0., 0., 0.),
+    }
+}
+
+/// HslaColor stores the hue, saturation, lightness and alpha components of a color
+/// in the HSL color space as `f32 ` fields.
+/// This is merely a helper struct for use with [`Color`].
+#[derive(Copy, Clone, PartialOrd, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HslaColor {
+    /// The hue component in degrees between 0 and 360.
+    pub hue: f32,
+    /// The saturation component, between 0 and 1.
+    pub saturation: f32,
+    /// The lightness component, between 0 and 1.
+    pub lightness: f32,
+    /// The alpha component, between 0 and 1.
+    pub alpha: f32,
+}
+
+impl PartialEq for HslaColor {
+    fn eq(&self, other: &Self) -> bool {
+        (self.hue - other.hue).abs() < 0.00001
+            && (self.saturation - other.saturation).abs() < 0.00001
+            && (self.lightness - other.lightness).abs() < 0.00001
+            && (self.alpha - other.alpha).abs() < 0.00001
+    }
+}
+
+impl From<RgbaColor<f32>> for HslaColor {
+    fn from(col: RgbaColor<f32>) -> Self {
+        // RGB to HSL conversion from https://en.wikipedia.org/wiki/HSL_and_HSV#Color_conversion_formulae
+        let min = col.red.min(col.green).min(col.blue);
+        let max = col.red.max(col.green).max(col.blue);
+        let chroma = max - min;
+        let hue = rgb_hue(col.red, col.green, col.blue, max, chroma);
+        let lightness = (max + min) / 2.;
+        let saturation = if lightness <= 0. || lightness >= 1. {
+            0.
+        } else {
+            chroma / (1. - (2. * lightness - 1.).abs())
+        };
+        Self { hue, saturation, lightness, alpha: col.alpha }
+    }
+}
+
+impl From<HslaColor> for RgbaColor<f32> {
+    fn from(col: HslaColor) -> Self {
+        // HSL to RGB conversion from https://en.wikipedia.org/wiki/HSL_and_HSV#Color_conversion_formulae
+        let chroma = (1. - (2. * col.lightness - 1.).abs()) * col.saturation;
+        let (red, green, blue) = hue_chroma_to_rgb(col.hue, chroma);
+        let m = col.lightness - chroma / 2.;
+        Self { red: red + m, green: green + m, blue: blue + m, alpha: col.alpha }
+    }
+}
+
+impl From<HslaColor> for Color {
+    fn from(value: HslaColor) -> Self {
+        RgbaColor::from(value).into()
+    }
+}
+
+impl From<Color> for HslaColor {
+    fn from(value: Color) -> Self {
+        value.to_hsl()
+    }
+}
+
+/// OklchColor stores the lightness, chroma, hue and alpha components of a color
+/// in the OKLCH color space as `f32 ` fields.
+/// This is merely a helper struct for use with [`Color`].
+///
+/// OKLCH is the polar form of the [Oklab](https://bottosson.github.io/posts/oklab/) perceptual color space,
+/// so interpolating in it gives smoother transitions than interpolating in sRGB.
+#[derive(Copy, Clone, PartialOrd, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OklchColor {
+    /// The perceived lightness, between 0 and 1.
+    pub lightness: f32,
+    /// The chroma component, from 0 (gray) to about 0.4 for the most saturated colors of the sRGB gamut.
+    pub chroma: f32,
+    /// The hue component in degrees between 0 and 360.
+    pub hue: f32,
+    /// The alpha component, between 0 and 1.
+    pub alpha: f32,
+}
+
+impl PartialEq for OklchColor {
+    fn eq(&self, other: &Self) -> bool {
+        (self.lightness - other.lightness).abs() < 0.00001
+            && (self.chroma - other.chroma).abs() < 0.00001
+            && (self.hue - other.hue).abs() < 0.00001
+            && (self.alpha - other.alpha).abs() < 0.00001
+    }
+}
+
+impl From<RgbaColor<f32>> for OklchColor {
+    fn from(col: RgbaColor<f32>) -> Self {
+        // Conversion from https://bottosson.github.io/posts/oklab/
+        fn to_linear(c: f32) -> f32 {
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+        let (r, g, b) = (to_linear(col.red), to_linear(col.green), to_linear(col.blue));
+
+        let l = (0.41222146 * r + 0.53633255 * g + 0.051445995 * b).cbrt();
+        let m = (0.2119035 * r + 0.6806995 * g + 0.10739696 * b).cbrt();
+        let s = (0.08830246 * r + 0.28171885 * g + 0.6299787 * b).cbrt();
+
+        let lightness = 0.21045426 * l + 0.7936178 * m - 0.004072047 * s;
+        let a = 1.9779985 * l - 2.4285922 * m + 0.4505937 * s;
+        let b = 0.025904037 * l + 0.78277177 * m - 0.80867577 * s;
+
+        let chroma = (a * a + b * b).sqrt();
+        let hue = num_traits::Euclid::rem_euclid(&b.atan2(a).to_degrees(), &360.0);
+        Self { lightness, chroma, hue, alpha: col.alpha }
+    }
+}
+
+impl From<OklchColor> for RgbaColor<f32> {
+    fn from(col: OklchColor) -> Self {
+        // Conversion from https://bottosson.github.io/posts/oklab/
+        fn from_linear(c: f32) -> f32 {
+            let c = if c <= 0.0031308 { 12.92 * c } else { 1.055 * c.powf(1. / 2.4) - 0.055 };
+            c.clamp(0., 1.)
+        }
+        let hue = col.hue.to_radians();
+        let (a, b) = (col.chroma * hue.cos(), col.chroma * hue.sin());
+
+        let l = col.lightness + 0.39633778 * a + 0.21580376 * b;
+        let m = col.lightness - 0.105561346 * a - 0.06385417 * b;
+        let s = col.lightness - 0.08948418 * a - 1.2914855 * b;
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+
+        Self {
+            red: from_linear(4.0767417 * l - 3.3077116 * m + 0.23096994 * s),
+            green: from_linear(-1.268438 * l + 2.6097574 * m - 0.34131938 * s),
+            blue: from_linear(-0.0041960864 * l - 0.7034186 * m + 1.7076147 * s),
+            alpha: col.alpha,
+        }
+    }
+}
+
+impl From<OklchColor> for Color {
+    fn from(value: OklchColor) -> Self {
+        RgbaColor::from(value).into()
+    }
+}
+
+impl From<Color> for OklchColor {
+    fn from(value: Color) -> Self {
+        value.to_oklch()
+    }
+}
+
 #[test]
 fn test_rgb_to_hsv() {
     // White
@@ -477,6 +755,74 @@ fn test_rgb_to_hsv() {
     );
 }
 
+#[test]
+fn test_rgb_to_hsl() {
+    assert_eq!(
+        HslaColor::from(RgbaColor::<f32> { red: 1., green: 1., blue: 1., alpha: 0.5 }),
+        HslaColor { hue: 0., saturation: 0., lightness: 1., alpha: 0.5 }
+    );
+    // Bright greenish, verified via colorizer.org
+    assert_eq!(
+        HslaColor::from(RgbaColor::<f32> { red: 0., green: 0.9, blue: 0., alpha: 1.0 }),
+        HslaColor { hue: 120., saturation: 1., lightness: 0.45, alpha: 1.0 }
+    );
+    assert_eq!(
+        RgbaColor::<f32>::from(HslaColor {
+            hue: 120.,
+            saturation: 1.,
+            lightness: 0.45,
+            alpha: 1.0
+        }),
+        RgbaColor::<f32> { red: 0., green: 0.9, blue: 0., alpha: 1.0 }
+    );
+    // Hue should wrap around 360deg
+    assert_eq!(Color::from_hsl(-240., 1., 0.45, 1.), Color::from_hsl(120., 1., 0.45, 1.));
+
+    let color = Color::from_argb_u8(0xff, 0x8a, 0xc, 0x77);
+    let hsl = color.to_hsl();
+    assert_eq!(Color::from_hsl(hsl.hue, hsl.saturation, hsl.lightness, hsl.alpha), color);
+}
+
+#[test]
+fn test_rgb_to_oklch() {
+    let close = |a: f32, b: f32| (a - b).abs() < 0.001;
+
+    let white = Color::from_rgb_u8(255, 255, 255).to_oklch();
+    assert!(close(white.lightness, 1.) && close(white.chroma, 0.), "{white:?}");
+
+    // Reference values from https://oklch.com
+    let red = Color::from_rgb_u8(255, 0, 0).to_oklch();
+    assert!(close(red.lightness, 0.628) && close(red.chroma, 0.2577), "{red:?}");
+    assert!((red.hue - 29.23).abs() < 0.1, "{red:?}");
+
+    for color in [
+        Color::from_rgb_u8(255, 0, 0),
+        Color::from_rgb_u8(0x12, 0xab, 0x00),
+        Color::from_argb_u8(0x80, 0x8a, 0xc, 0x77),
+        Color::from_rgb_u8(0, 0, 0),
+    ] {
+        let oklch = color.to_oklch();
+        assert_eq!(Color::from_oklch(oklch.lightness, oklch.chroma, oklch.hue, oklch.alpha), color);
+    }
+}
+
+#[test]
+fn test_mix_in() {
+    let red = Color::from_rgb_u8(255, 0, 0);
+    let blue = Color::from_rgb_u8(0, 0, 255);
+    let gray = Color::from_rgb_u8(128, 128, 128);
+
+    assert_eq!(red.mix_in(&blue, 0.5, ColorSpace::Srgb), red.mix(&blue, 0.5));
+    assert_eq!(red.mix_in(&blue, 1., ColorSpace::Oklch), red);
+    assert_eq!(red.mix_in(&blue, 0., ColorSpace::Oklch), blue);
+    // The shorter arc between 0deg and 240deg goes through magenta
+    assert_eq!(red.mix_in(&blue, 0.5, ColorSpace::Hsl), Color::from_rgb_u8(255, 0, 255));
+    // Gray has no hue, so the hue of the other color is kept
+    assert_eq!(red.mix_in(&gray, 0.5, ColorSpace::Hsl).to_hsl().hue.round(), 0.);
+    let oklch = blue.mix_in(&gray, 0.5, ColorSpace::Oklch).to_oklch();
+    assert!((oklch.hue - blue.to_oklch().hue).abs() < 1., "{oklch:?}");
+}
+
 #[test]
 fn test_brighter_darker() {
     let blue = Color::from_rgb_u8(0, 0, 128);
@@ -495,6 +841,21 @@ fn test_transparent_transition() {
     assert_eq!(interpolated, Color::from_argb_u8(191, 211, 211, 211));
 }
 
+#[test]
+fn test_hex_string() {
+    assert_eq!(Color::from_rgb_u8(0x12, 0xab, 0x0).to_hex_string(), "#12ab00");
+    assert_eq!(Color::from_argb_u8(0x80, 0x12, 0xab, 0x0).to_hex_string(), "#12ab0080");
+    assert_eq!(Color::from_hex_str("#12ab00"), Some(Color::from_rgb_u8(0x12, 0xab, 0x0)));
+    assert_eq!(Color::from_hex_str(" 12AB0080 "), Some(Color::from_argb_u8(0x80, 0x12, 0xab, 0)));
+    assert_eq!(Color::from_hex_str("#f0a"), Some(Color::from_rgb_u8(0xff, 0x00, 0xaa)));
+    assert_eq!(Color::from_hex_str("#f0a8"), Some(Color::from_argb_u8(0x88, 0xff, 0x00, 0xaa)));
+    assert_eq!(Color::from_hex_str("#12ab0"), None);
+    assert_eq!(Color::from_hex_str("#12ag00"), None);
+    assert_eq!(Color::from_hex_str("#+2ab00"), None);
+    assert_eq!(Color::from_hex_str("#éab0"), None);
+    assert_eq!(Color::from_hex_str(""), None);
+}
+
 #[cfg(feature = "ffi")]
 pub(crate) mod ffi {
     #![allow(unsafe_code)]
@@ -525,6 +886,17 @@ pub(crate) mod ffi {
         core::ptr::write(out, col1.mix(col2, factor))
     }
 
+    #[unsafe(no_mangle)]
+    pub unsafe extern "C" fn slint_color_mix_in(
+        col1: &Color,
+        col2: &Color,
+        factor: f32,
+        color_space: ColorSpace,
+        out: *mut Color,
+    ) {
+        core::ptr::write(out, col1.mix_in(col2, factor, color_space))
+    }
+
     #[unsafe(no_mangle)]
     pub unsafe extern "C" fn slint_color_with_alpha(col: &Color, alpha: f32, out: *mut Color) {
         core::ptr::write(out, col.with_alpha(alpha))
@@ -545,8 +917,70 @@ pub extern "C" fn slint_color_to_hsva(
         *a = hsv.alpha;
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_color_to_hsl(
+        col: &Color,
+        h: &mut f32,
+        s: &mut f32,
+        l: &mut f32,
+        a: &mut f32,
+    ) {
+        let hsl = col.to_hsl();
+        *h = hsl.hue;
+        *s = hsl.saturation;
+        *l = hsl.lightness;
+        *a = hsl.alpha;
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_color_to_oklch(
+        col: &Color,
+        l: &mut f32,
+        c: &mut f32,
+        h: &mut f32,
+        a: &mut f32,
+    ) {
+        let oklch = col.to_oklch();
+        *l = oklch.lightness;
+        *c = oklch.chroma;
+        *h = oklch.hue;
+        *a = oklch.alpha;
+    }
+
+    /// The color is passed encoded as `0xAARRGGBB`
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_color_to_hex_string(argb: u32, out: &mut crate::SharedString) {
+        *out = Color::from_argb_encoded(argb).to_hex_string();
+    }
+
+    /// Returns false if the string is not a valid color, otherwise store the color encoded
+    /// as `0xAARRGGBB` in `argb`
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_color_from_hex_string(
+        str: &crate::SharedString,
+        argb: &mut u32,
+    ) -> bool {
+        match Color::from_hex_str(str) {
+            Some(color) => {
+                *argb = color.as_argb_encoded();
+                true
+            }
+            None => false,
+        }
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn slint_color_from_hsva(h: f32, s: f32, v: f32, a: f32) -> Color {
         Color::from_hsva(h, s, v, a)
     }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_color_from_hsl(h: f32, s: f32, l: f32, a: f32) -> Color {
+        Color::from_hsl(h, s, l, a)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_color_from_oklch(l: f32, c: f32, h: f32, a: f32) -> Color {
+        Color::from_oklch(l, c, h, a)
+    }
 }
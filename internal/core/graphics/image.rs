@@ -115,6 +115,20 @@ pub fn as_slice(&self) -> &[Pixel] {
     }
 }
 
+impl<Pixel: Clone> SharedPixelBuffer<Pixel> {
+    /// Creates a new SharedPixelBuffer of the same size, where each pixel is converted with the given function.
+    pub fn convert_pixels<Target>(
+        &self,
+        convert: impl FnMut(Pixel) -> Target,
+    ) -> SharedPixelBuffer<Target> {
+        SharedPixelBuffer {
+            width: self.width,
+            height: self.height,
+            data: self.data.iter().cloned().map(convert).collect(),
+        }
+    }
+}
+
 impl<Pixel: Clone + Default> SharedPixelBuffer<Pixel> {
     /// Creates a new SharedPixelBuffer with the given width and height. Each pixel will be initialized with the value
     /// that [`Default::default()`] returns for the Pixel type.
@@ -152,6 +166,59 @@ pub fn clone_from_slice<SourcePixelType>(
 /// Convenience alias for a pixel with four color channels (red, green, blue and alpha), each
 /// encoded as u8.
 pub type Rgba8Pixel = rgb::RGBA8;
+/// Convenience alias for a pixel with a single gray channel, encoded as u8.
+pub type Gray8Pixel = rgb::Gray<u8>;
+
+/// A 16bit pixel that has 5 red bits, 6 green bits and  5 blue bits
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "software-renderer", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct Rgb565Pixel(pub u16);
+
+impl Rgb565Pixel {
+    pub(crate) const R_MASK: u16 = 0b1111_1000_0000_0000;
+    pub(crate) const G_MASK: u16 = 0b0000_0111_1110_0000;
+    pub(crate) const B_MASK: u16 = 0b0000_0000_0001_1111;
+
+    /// Return the red component as a u8.
+    ///
+    /// The bits are shifted so that the result is between 0 and 255
+    fn red(self) -> u8 {
+        ((self.0 & Self::R_MASK) >> 8) as u8
+    }
+    /// Return the green component as a u8.
+    ///
+    /// The bits are shifted so that the result is between 0 and 255
+    fn green(self) -> u8 {
+        ((self.0 & Self::G_MASK) >> 3) as u8
+    }
+    /// Return the blue component as a u8.
+    ///
+    /// The bits are shifted so that the result is between 0 and 255
+    fn blue(self) -> u8 {
+        ((self.0 & Self::B_MASK) << 3) as u8
+    }
+}
+
+impl From<Rgb8Pixel> for Rgb565Pixel {
+    fn from(p: Rgb8Pixel) -> Self {
+        Self(
+            ((p.r as u16 & 0b11111000) << 8) | ((p.g as u16 & 0b11111100) << 3) | (p.b as u16 >> 3),
+        )
+    }
+}
+
+impl From<Rgb565Pixel> for Rgb8Pixel {
+    fn from(p: Rgb565Pixel) -> Self {
+        Rgb8Pixel { r: p.red(), g: p.green(), b: p.blue() }
+    }
+}
+
+impl From<Rgb565Pixel> for Rgba8Pixel {
+    fn from(p: Rgb565Pixel) -> Self {
+        Rgb8Pixel::from(p).into()
+    }
+}
 
 /// SharedImageBuffer is a container for images that are stored in CPU accessible memory.
 ///
@@ -174,6 +241,12 @@ pub enum SharedImageBuffer {
     /// Only construct this format if you know that your pixels are encoded this way. It is more efficient
     /// for rendering.
     RGBA8Premultiplied(SharedPixelBuffer<Rgba8Pixel>),
+    /// This variant holds the data for an image where each pixel is encoded in 16 bits, with 5 bits for
+    /// red, 6 bits for green and 5 bits for blue. This is the format of many MCU framebuffers.
+    RGB565(SharedPixelBuffer<Rgb565Pixel>),
+    /// This variant holds the data for an image where each pixel has a single gray channel encoded as
+    /// unsigned byte, as produced for example by the luma plane of camera or video frames.
+    Gray8(SharedPixelBuffer<Gray8Pixel>),
 }
 
 impl SharedImageBuffer {
@@ -184,6 +257,8 @@ pub fn width(&self) -> u32 {
             Self::RGB8(buffer) => buffer.width(),
             Self::RGBA8(buffer) => buffer.width(),
             Self::RGBA8Premultiplied(buffer) => buffer.width(),
+            Self::RGB565(buffer) => buffer.width(),
+            Self::Gray8(buffer) => buffer.width(),
         }
     }
 
@@ -194,6 +269,8 @@ pub fn height(&self) -> u32 {
             Self::RGB8(buffer) => buffer.height(),
             Self::RGBA8(buffer) => buffer.height(),
             Self::RGBA8Premultiplied(buffer) => buffer.height(),
+            Self::RGB565(buffer) => buffer.height(),
+            Self::Gray8(buffer) => buffer.height(),
         }
     }
 
@@ -204,6 +281,8 @@ pub fn size(&self) -> IntSize {
             Self::RGB8(buffer) => buffer.size(),
             Self::RGBA8(buffer) => buffer.size(),
             Self::RGBA8Premultiplied(buffer) => buffer.size(),
+            Self::RGB565(buffer) => buffer.size(),
+            Self::Gray8(buffer) => buffer.size(),
         }
     }
 }
@@ -220,6 +299,12 @@ fn eq(&self, other: &Self) -> bool {
             Self::RGBA8Premultiplied(lhs_buffer) => {
                 matches!(other, Self::RGBA8Premultiplied(rhs_buffer) if lhs_buffer.data.as_ptr().eq(&rhs_buffer.data.as_ptr()))
             }
+            Self::RGB565(lhs_buffer) => {
+                matches!(other, Self::RGB565(rhs_buffer) if lhs_buffer.data.as_ptr().eq(&rhs_buffer.data.as_ptr()))
+            }
+            Self::Gray8(lhs_buffer) => {
+                matches!(other, Self::Gray8(rhs_buffer) if lhs_buffer.data.as_ptr().eq(&rhs_buffer.data.as_ptr()))
+            }
         }
     }
 }
@@ -241,6 +326,21 @@ pub enum TexturePixelFormat {
     /// and i8::MAX corresponds to 3 pixels inside the shape.
     /// The array must be width * height +1 bytes long. (the extra bit is read but never used)
     SignedDistanceField,
+    /// Red, green, blue. 16bits, 5 bits for red, 6 for green and 5 for blue,
+    /// stored as an u16 in native endianness.
+    Rgb565,
+    /// Gray. 8bits.
+    Gray,
+    /// Alpha map with one alpha value for each of the red, green, and blue sub-pixels. 24bits.
+    /// The color is specified separately. Used for sub-pixel anti-aliased text.
+    SubpixelAlphaMap,
+    /// Red, green, blue. 24bits. Each line is compressed with a run-length encoding, see
+    /// [`decode_run_length_encoded_line()`] for the layout of the data.
+    RunLengthEncodedRgb,
+    /// Red, green, blue, alpha. 32bits. The color are premultiplied by alpha.
+    /// Each line is compressed with a run-length encoding, see [`decode_run_length_encoded_line()`]
+    /// for the layout of the data.
+    RunLengthEncodedRgbaPremultiplied,
 }
 
 impl TexturePixelFormat {
@@ -252,10 +352,66 @@ pub fn bpp(self) -> usize {
             TexturePixelFormat::RgbaPremultiplied => 4,
             TexturePixelFormat::AlphaMap => 1,
             TexturePixelFormat::SignedDistanceField => 1,
+            TexturePixelFormat::Rgb565 => 2,
+            TexturePixelFormat::Gray => 1,
+            TexturePixelFormat::SubpixelAlphaMap => 3,
+            TexturePixelFormat::RunLengthEncodedRgb => 3,
+            TexturePixelFormat::RunLengthEncodedRgbaPremultiplied => 4,
+        }
+    }
+
+    /// For the run-length encoded formats, returns the format of the decoded pixels.
+    pub fn run_length_decoded(self) -> Option<Self> {
+        match self {
+            TexturePixelFormat::RunLengthEncodedRgb => Some(TexturePixelFormat::Rgb),
+            TexturePixelFormat::RunLengthEncodedRgbaPremultiplied => {
+                Some(TexturePixelFormat::RgbaPremultiplied)
+            }
+            _ => None,
         }
     }
 }
 
+/// Decodes `len` pixels of the line `y` of a run-length encoded texture, starting at the column `x`,
+/// and appends them to `out`. `bpp` is the number of bytes of a decoded pixel.
+///
+/// The data starts with a table of one little-endian `u32` per line: the offset of the encoded
+/// line from the start of the data. This allows to decode any line without decoding the ones before.
+/// A line is a sequence of packets starting with a header byte `n`. If the high bit of `n` is set, it's
+/// followed by one pixel that is repeated `(n & 0x7f) + 1` times. Otherwise, it's followed by `n + 1` pixels.
+pub fn decode_run_length_encoded_line(
+    data: &[u8],
+    y: usize,
+    x: usize,
+    len: usize,
+    bpp: usize,
+    out: &mut alloc::vec::Vec<u8>,
+) {
+    let mut pos = u32::from_le_bytes(data[y * 4..y * 4 + 4].try_into().unwrap()) as usize;
+    let end = x + len;
+    // The column of the first pixel of the current packet
+    let mut col = 0;
+    while col < end {
+        let header = data[pos];
+        pos += 1;
+        let count = (header & 0x7f) as usize + 1;
+        let (from, to) = (col.max(x), (col + count).min(end));
+        if header & 0x80 != 0 {
+            let pixel = &data[pos..pos + bpp];
+            for _ in from..to {
+                out.extend_from_slice(pixel);
+            }
+            pos += bpp;
+        } else {
+            if from < to {
+                out.extend_from_slice(&data[pos + (from - col) * bpp..pos + (to - col) * bpp]);
+            }
+            pos += count * bpp;
+        }
+        col += count;
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, PartialEq, Debug)]
 /// Some raw pixel data which is typically stored in the binary
@@ -326,6 +482,8 @@ pub enum ImageCacheKey {
     URL(SharedString) = 2,
     /// The image is identified by the static address of its encoded data.
     EmbeddedData(usize) = 3,
+    /// The image is identified by a hash of its encoded data and its length.
+    EncodedData(u64, usize) = 4,
 }
 
 impl ImageCacheKey {
@@ -345,6 +503,8 @@ pub fn new(resource: &ImageInner) -> Option<Self> {
             ImageInner::BackendStorage(x) => vtable::VRc::borrow(x).cache_key(),
             #[cfg(not(target_arch = "wasm32"))]
             ImageInner::BorrowedOpenGLTexture(..) => return None,
+            #[cfg(not(target_arch = "wasm32"))]
+            ImageInner::BorrowedExternalTexture(..) => return None,
             ImageInner::NineSlice(nine) => vtable::VRc::borrow(nine).cache_key(),
             #[cfg(any(feature = "unstable-wgpu-26", feature = "unstable-wgpu-27"))]
             ImageInner::WGPUTexture(..) => return None,
@@ -360,6 +520,15 @@ pub fn new(resource: &ImageInner) -> Option<Self> {
     pub fn from_embedded_image_data(data: &'static [u8]) -> Self {
         Self::EmbeddedData(data.as_ptr() as usize)
     }
+
+    /// Returns a cache key for encoded image data, computed from its content.
+    #[cfg(feature = "std")]
+    pub fn from_encoded_data(data: &[u8]) -> Self {
+        use core::hash::{Hash, Hasher};
+        let mut hasher = std::hash::DefaultHasher::new();
+        data.hash(&mut hasher);
+        Self::EncodedData(hasher.finish(), data.len())
+    }
 }
 
 /// Represent a nine-slice image with the base image and the 4 borders
@@ -440,6 +609,8 @@ pub enum ImageInner {
     NineSlice(vtable::VRc<OpaqueImageVTable, NineSliceImage>) = 7,
     #[cfg(any(feature = "unstable-wgpu-26", feature = "unstable-wgpu-27"))]
     WGPUTexture(WGPUTexture) = 8,
+    #[cfg(not(target_arch = "wasm32"))]
+    BorrowedExternalTexture(BorrowedExternalTexture) = 9,
 }
 
 impl ImageInner {
@@ -470,12 +641,29 @@ pub fn render_to_buffer(
                     SharedPixelBuffer::<Rgba8Pixel>::new(ts.size.width, ts.size.height);
                 let stride = buffer.width() as usize;
                 let slice = buffer.make_mut_slice();
+                let mut decoded = alloc::vec::Vec::new();
                 for t in ts.textures.iter() {
                     let rect = t.rect.to_usize();
                     for y in 0..rect.height() {
                         let slice = &mut slice[(rect.min_y() + y) * stride..][rect.x_range()];
-                        let source = &ts.data[t.index + y * rect.width() * t.format.bpp()..];
-                        match t.format {
+                        let (format, source) = match t.format.run_length_decoded() {
+                            Some(format) => {
+                                decoded.clear();
+                                decode_run_length_encoded_line(
+                                    &ts.data[t.index..],
+                                    y,
+                                    0,
+                                    rect.width(),
+                                    format.bpp(),
+                                    &mut decoded,
+                                );
+                                (format, decoded.as_slice())
+                            }
+                            None => {
+                                (t.format, &ts.data[t.index + y * rect.width() * t.format.bpp()..])
+                            }
+                        };
+                        match format {
                             TexturePixelFormat::Rgb => {
                                 let mut iter = source.chunks_exact(3).map(|p| Rgba8Pixel {
                                     r: p[0],
@@ -506,6 +694,23 @@ pub fn render_to_buffer(
                                 });
                                 slice.fill_with(|| iter.next().unwrap());
                             }
+                            TexturePixelFormat::Rgb565 => {
+                                let mut iter = source.chunks_exact(2).map(|p| {
+                                    let p: Rgb8Pixel =
+                                        Rgb565Pixel(u16::from_ne_bytes([p[0], p[1]])).into();
+                                    Rgba8Pixel { r: p.r, g: p.g, b: p.b, a: 255 }
+                                });
+                                slice.fill_with(|| iter.next().unwrap());
+                            }
+                            TexturePixelFormat::Gray => {
+                                let mut iter = source.iter().map(|v| Rgba8Pixel {
+                                    r: *v,
+                                    g: *v,
+                                    b: *v,
+                                    a: 255,
+                                });
+                                slice.fill_with(|| iter.next().unwrap());
+                            }
                             TexturePixelFormat::AlphaMap => {
                                 let col = t.color.to_argb_u8();
                                 let mut iter = source.iter().map(|p| {
@@ -519,9 +724,27 @@ pub fn render_to_buffer(
                                 });
                                 slice.fill_with(|| iter.next().unwrap());
                             }
+                            TexturePixelFormat::SubpixelAlphaMap => {
+                                let col = t.color.to_argb_u8();
+                                let mut iter = source.chunks_exact(3).map(|p| {
+                                    let coverage = (p[0] as u32 + p[1] as u32 + p[2] as u32) / 3;
+                                    let a = coverage * col.alpha as u32;
+                                    Rgba8Pixel {
+                                        r: (col.red as u32 * a / (255 * 255)) as u8,
+                                        g: (col.green as u32 * a / (255 * 255)) as u8,
+                                        b: (col.blue as u32 * a / (255 * 255)) as u8,
+                                        a: (a / 255) as u8,
+                                    }
+                                });
+                                slice.fill_with(|| iter.next().unwrap());
+                            }
                             TexturePixelFormat::SignedDistanceField => {
                                 todo!("converting from a signed distance field to an image")
                             }
+                            TexturePixelFormat::RunLengthEncodedRgb
+                            | TexturePixelFormat::RunLengthEncodedRgbaPremultiplied => {
+                                unreachable!("decoded above")
+                            }
                         };
                     }
                 }
@@ -556,6 +779,8 @@ pub fn size(&self) -> IntSize {
             ImageInner::BackendStorage(x) => vtable::VRc::borrow(x).size(),
             #[cfg(not(target_arch = "wasm32"))]
             ImageInner::BorrowedOpenGLTexture(BorrowedOpenGLTexture { size, .. }) => *size,
+            #[cfg(not(target_arch = "wasm32"))]
+            ImageInner::BorrowedExternalTexture(BorrowedExternalTexture { size, .. }) => *size,
             ImageInner::NineSlice(nine) => nine.0.size(),
             #[cfg(any(feature = "unstable-wgpu-26", feature = "unstable-wgpu-27"))]
             ImageInner::WGPUTexture(texture) => texture.size(),
@@ -578,6 +803,8 @@ fn eq(&self, other: &Self) -> bool {
             (Self::BackendStorage(l0), Self::BackendStorage(r0)) => vtable::VRc::ptr_eq(l0, r0),
             #[cfg(not(target_arch = "wasm32"))]
             (Self::BorrowedOpenGLTexture(l0), Self::BorrowedOpenGLTexture(r0)) => l0 == r0,
+            #[cfg(not(target_arch = "wasm32"))]
+            (Self::BorrowedExternalTexture(l0), Self::BorrowedExternalTexture(r0)) => l0 == r0,
             (Self::NineSlice(l), Self::NineSlice(r)) => l.0 == r.0 && l.1 == r.1,
             _ => false,
         }
@@ -718,6 +945,33 @@ pub fn load_from_path(path: &std::path::Path) -> Result<Self, LoadImageError> {
         })
     }
 
+    #[cfg(feature = "image-decoders")]
+    /// Load an Image from the encoded data of an image file, such as the content of a PNG file
+    /// downloaded from the network.
+    ///
+    /// The image is decoded in a background thread, so that the UI isn't blocked. The decoded images
+    /// are cached, keyed by their content: loading the same data several times decodes it only once.
+    ///
+    /// The returned future must be awaited in the thread running the Slint event loop, for example
+    /// in a task started with `slint::spawn_local()`.
+    ///
+    /// ```rust,no_run
+    /// # use i_slint_core::graphics::Image;
+    /// # async fn download(url: &str) -> Vec<u8> { todo!() }
+    /// # async fn example() {
+    /// let bytes = download("https://example.com/avatar.png").await;
+    /// let image = Image::load_from_encoded_bytes_async(bytes).await.unwrap_or_default();
+    /// # }
+    /// ```
+    ///
+    /// Supported formats are the same as for [`Self::load_from_path`].
+    pub fn load_from_encoded_bytes_async(
+        bytes: impl AsRef<[u8]> + Send + 'static,
+    ) -> impl core::future::Future<Output = Result<Self, LoadImageError>> {
+        let load = self::cache::load_image_from_encoded_bytes_async(bytes);
+        async move { load.await.ok_or(LoadImageError(())) }
+    }
+
     /// Creates a new Image from the specified shared pixel buffer, where each pixel has three color
     /// channels (red, green and blue) encoded as u8.
     pub fn from_rgb8(buffer: SharedPixelBuffer<Rgb8Pixel>) -> Self {
@@ -748,11 +1002,58 @@ pub fn from_rgba8_premultiplied(buffer: SharedPixelBuffer<Rgba8Pixel>) -> Self {
         })
     }
 
+    /// Creates a new Image from the specified shared pixel buffer, where each pixel is encoded in 16 bits
+    /// with 5 bits for red, 6 bits for green and 5 bits for blue.
+    ///
+    /// The pixels are kept in this format, so that frames coming from a camera, a video decoder, or an
+    /// MCU framebuffer don't need to be converted before being displayed with the software renderer.
+    pub fn from_rgb565(buffer: SharedPixelBuffer<Rgb565Pixel>) -> Self {
+        Image(ImageInner::EmbeddedImage {
+            cache_key: ImageCacheKey::Invalid,
+            buffer: SharedImageBuffer::RGB565(buffer),
+        })
+    }
+
+    /// Creates a new Image from the specified shared pixel buffer, where each pixel has a single gray
+    /// channel encoded as u8.
+    pub fn from_gray8(buffer: SharedPixelBuffer<Gray8Pixel>) -> Self {
+        Image(ImageInner::EmbeddedImage {
+            cache_key: ImageCacheKey::Invalid,
+            buffer: SharedImageBuffer::Gray8(buffer),
+        })
+    }
+
     /// Returns the pixel buffer for the Image if available in RGB format without alpha.
+    /// Images in the RGB565 or Gray8 format are converted.
     /// Returns None if the pixels cannot be obtained, for example when the image was created from borrowed OpenGL textures.
     pub fn to_rgb8(&self) -> Option<SharedPixelBuffer<Rgb8Pixel>> {
         self.0.render_to_buffer(None).and_then(|image| match image {
             SharedImageBuffer::RGB8(buffer) => Some(buffer),
+            SharedImageBuffer::RGB565(buffer) => Some(buffer.convert_pixels(Into::into)),
+            SharedImageBuffer::Gray8(buffer) => Some(buffer.convert_pixels(Image::gray_to_rgb)),
+            _ => None,
+        })
+    }
+
+    /// Returns the pixel buffer for the Image if available in the RGB565 format.
+    /// Images in the RGB8 format are converted.
+    /// Returns None if the pixels cannot be obtained, or if the image has an alpha channel.
+    pub fn to_rgb565(&self) -> Option<SharedPixelBuffer<Rgb565Pixel>> {
+        self.0.render_to_buffer(None).and_then(|image| match image {
+            SharedImageBuffer::RGB565(buffer) => Some(buffer),
+            SharedImageBuffer::RGB8(buffer) => Some(buffer.convert_pixels(Into::into)),
+            SharedImageBuffer::Gray8(buffer) => {
+                Some(buffer.convert_pixels(|p| Image::gray_to_rgb(p).into()))
+            }
+            _ => None,
+        })
+    }
+
+    /// Returns the pixel buffer for the Image if available in the Gray8 format.
+    /// Returns None if the pixels cannot be obtained, or if the image is not a gray scale image.
+    pub fn to_gray8(&self) -> Option<SharedPixelBuffer<Gray8Pixel>> {
+        self.0.render_to_buffer(None).and_then(|image| match image {
+            SharedImageBuffer::Gray8(buffer) => Some(buffer),
             _ => None,
         })
     }
@@ -772,6 +1073,10 @@ pub fn to_rgba8(&self) -> Option<SharedPixelBuffer<Rgba8Pixel>> {
                 height: buffer.height,
                 data: buffer.data.into_iter().map(Image::premultiplied_rgba_to_rgba).collect(),
             },
+            SharedImageBuffer::RGB565(buffer) => buffer.convert_pixels(Into::into),
+            SharedImageBuffer::Gray8(buffer) => {
+                buffer.convert_pixels(|p| Image::gray_to_rgb(p).into())
+            }
         })
     }
 
@@ -791,9 +1096,18 @@ pub fn to_rgba8_premultiplied(&self) -> Option<SharedPixelBuffer<Rgba8Pixel>> {
                 data: buffer.data.into_iter().map(Image::rgba_to_premultiplied_rgba).collect(),
             },
             SharedImageBuffer::RGBA8Premultiplied(buffer) => buffer,
+            SharedImageBuffer::RGB565(buffer) => buffer.convert_pixels(Into::into),
+            SharedImageBuffer::Gray8(buffer) => {
+                buffer.convert_pixels(|p| Image::gray_to_rgb(p).into())
+            }
         })
     }
 
+    /// Returns the gray pixel expanded to RGB.
+    fn gray_to_rgb(pixel: Gray8Pixel) -> Rgb8Pixel {
+        Rgb8Pixel::new(pixel.value(), pixel.value(), pixel.value())
+    }
+
     /// Returns the pixel converted from premultiplied RGBA to RGBA.
     fn premultiplied_rgba_to_rgba(pixel: Rgba8Pixel) -> Rgba8Pixel {
         if pixel.a == 0 {
@@ -889,6 +1203,43 @@ pub fn load_from_svg_data(buffer: &[u8]) -> Result<Self, LoadImageError> {
         ))))
     }
 
+    /// Creates a new Image from the specified buffer, which contains SVG raw data, rasterized
+    /// at the given size in physical pixels.
+    ///
+    /// The SVG is scaled to fit `size` while preserving its aspect ratio. Unlike images created with
+    /// [`Self::load_from_svg_data`], the resulting image is a pixel image that is not rendered again
+    /// when scaled. To get crisp images on high-DPI displays, multiply the desired logical size by the
+    /// [scale factor](crate::api::Window::scale_factor) of the window.
+    #[cfg(feature = "svg")]
+    pub fn load_from_svg_data_with_size(
+        buffer: &[u8],
+        size: IntSize,
+    ) -> Result<Self, LoadImageError> {
+        Self::load_from_svg_data(buffer)?.rasterize_svg(size)
+    }
+
+    /// Renders an image created from SVG data or from an SVG file into a new pixel image of
+    /// the given size in physical pixels, preserving the aspect ratio.
+    ///
+    /// Use this function to render an SVG again at a new size, for example after the scale factor
+    /// of the window changed. Returns an error if the image is not an SVG, or if the size is empty.
+    ///
+    /// ```
+    /// # use i_slint_core::graphics::{Image, IntSize};
+    /// let svg = r#"<svg width="16" height="16" xmlns="http://www.w3.org/2000/svg"><rect width="16" height="16"/></svg>"#;
+    /// let icon = Image::load_from_svg_data(svg.as_bytes()).unwrap();
+    /// let scale_factor = 2.;
+    /// let size = (icon.size().cast::<f32>() * scale_factor).round().cast();
+    /// let crisp_icon = icon.rasterize_svg(size).unwrap();
+    /// assert_eq!(crisp_icon.size(), IntSize::new(32, 32));
+    /// ```
+    #[cfg(feature = "svg")]
+    pub fn rasterize_svg(&self, size: IntSize) -> Result<Self, LoadImageError> {
+        let ImageInner::Svg(svg) = &self.0 else { return Err(LoadImageError(())) };
+        let buffer = svg.render(Some(size.cast_unit())).map_err(|_| LoadImageError(()))?;
+        Ok(Image(ImageInner::EmbeddedImage { cache_key: ImageCacheKey::Invalid, buffer }))
+    }
+
     /// Sets the nine-slice edges of the image.
     ///
     /// [Nine-slice scaling](https://en.wikipedia.org/wiki/9-slice_scaling) is a method for scaling
@@ -1015,6 +1366,103 @@ pub fn build(self) -> Image {
     }
 }
 
+/// Factory to create [`slint::Image`](crate::graphics::Image) from a texture that's shared by the operating
+/// system, such as the frames decoded by a video player or captured by a camera. The pixels are not copied,
+/// the renderer imports the texture directly on the GPU.
+///
+/// Importing is supported by the Skia renderer:
+///
+///  * DMA-BUFs on Linux, when rendering with OpenGL via EGL.
+///  * IOSurfaces on macOS and iOS, when rendering with Metal.
+///  * Direct3D shared handles on Windows, when rendering with Direct3D.
+///
+/// Other renderers, or other combinations, render nothing for these images.
+///
+/// Complete the builder by calling [`Self::build()`] to create a [`slint::Image`](crate::graphics::Image):
+///
+/// ```no_run
+/// # use i_slint_core::graphics::{BorrowedExternalTextureBuilder, Image, IntSize};
+/// # let (fd, stride) = (3, 400);
+/// const DRM_FORMAT_ARGB8888: u32 = u32::from_le_bytes(*b"AR24");
+/// let image: Image = unsafe {
+///     BorrowedExternalTextureBuilder::new_dma_buf(fd, DRM_FORMAT_ARGB8888, 0, 0, stride, IntSize::new(100, 100))
+/// }
+/// .build();
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub struct BorrowedExternalTextureBuilder(BorrowedExternalTexture);
+
+#[cfg(not(target_arch = "wasm32"))]
+impl BorrowedExternalTextureBuilder {
+    /// Creates a builder for a single plane Linux DMA-BUF.
+    ///
+    /// `fourcc` is the DRM format code of the pixels, such as `DRM_FORMAT_ARGB8888`, and `modifier`
+    /// the DRM format modifier describing their layout in memory (`DRM_FORMAT_MOD_LINEAR` is 0).
+    /// `offset` is the offset of the first pixel in bytes and `stride` the number of bytes per row.
+    ///
+    /// # Safety
+    ///
+    /// The file descriptor must refer to a DMA-BUF holding an image of the given size and format, and must
+    /// remain open for as long as the image is in use. Slint does not take ownership of the file descriptor.
+    /// The producer must not write to the buffer while Slint is rendering it.
+    #[allow(unsafe_code)]
+    pub unsafe fn new_dma_buf(
+        fd: i32,
+        fourcc: u32,
+        modifier: u64,
+        offset: u32,
+        stride: u32,
+        size: IntSize,
+    ) -> Self {
+        Self(BorrowedExternalTexture {
+            handle: ExternalTextureHandle::DmaBuf { fd, fourcc, modifier, offset, stride },
+            size,
+        })
+    }
+
+    /// Creates a builder for an `IOSurfaceRef`, for example the one backing a `CVPixelBuffer`.
+    /// The surface must have the `BGRA` pixel format.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be a valid `IOSurfaceRef` of the given size, and the application must keep a reference
+    /// to it for as long as the image is in use.
+    #[allow(unsafe_code)]
+    pub unsafe fn new_io_surface(
+        io_surface: core::ptr::NonNull<core::ffi::c_void>,
+        size: IntSize,
+    ) -> Self {
+        Self(BorrowedExternalTexture {
+            handle: ExternalTextureHandle::IOSurface(io_surface.as_ptr()),
+            size,
+        })
+    }
+
+    /// Creates a builder for a Direct3D 11 or 12 texture shared through an NT handle, as returned by
+    /// `IDXGIResource1::CreateSharedHandle` or `ID3D12Device::CreateSharedHandle`. The texture must have the
+    /// `DXGI_FORMAT_R8G8B8A8_UNORM` or `DXGI_FORMAT_B8G8R8A8_UNORM` format.
+    ///
+    /// # Safety
+    ///
+    /// The handle must refer to a shared texture of the given size, created on the same adapter that
+    /// Slint renders with, and must remain open for as long as the image is in use.
+    #[allow(unsafe_code)]
+    pub unsafe fn new_d3d_shared_handle(
+        handle: core::ptr::NonNull<core::ffi::c_void>,
+        size: IntSize,
+    ) -> Self {
+        Self(BorrowedExternalTexture {
+            handle: ExternalTextureHandle::D3DSharedHandle(handle.as_ptr()),
+            size,
+        })
+    }
+
+    /// Completes the process of building a slint::Image that holds a borrowed external texture.
+    pub fn build(self) -> Image {
+        Image(ImageInner::BorrowedExternalTexture(self.0))
+    }
+}
+
 /// Load an image from an image embedded in the binary.
 /// This is called by the generated code.
 #[cfg(feature = "image-decoders")]
@@ -1024,6 +1472,75 @@ pub fn load_image_from_embedded_data(data: Slice<'static, u8>, format: Slice<'_,
     })
 }
 
+/// Scale the buffer down so that it fits into `max_width` x `max_height`, keeping the aspect ratio.
+///
+/// Each target pixel is the average of the source pixels it covers, weighted by their alpha.
+/// The buffer is returned unchanged if it already fits.
+pub(crate) fn scale_down_to_fit(
+    buffer: SharedPixelBuffer<Rgba8Pixel>,
+    max_width: u32,
+    max_height: u32,
+) -> SharedPixelBuffer<Rgba8Pixel> {
+    let (width, height) = (buffer.width(), buffer.height());
+    if (width <= max_width && height <= max_height) || width == 0 || height == 0 {
+        return buffer;
+    }
+    let ratio = (max_width as f32 / width as f32).min(max_height as f32 / height as f32);
+    let target_width = ((width as f32 * ratio) as u32).max(1);
+    let target_height = ((height as f32 * ratio) as u32).max(1);
+
+    let source = buffer.as_slice();
+    let mut result = SharedPixelBuffer::<Rgba8Pixel>::new(target_width, target_height);
+    let stride = target_width as usize;
+    for (index, pixel) in result.make_mut_slice().iter_mut().enumerate() {
+        let (tx, ty) = ((index % stride) as u32, (index / stride) as u32);
+        let (x0, x1) = (tx * width / target_width, ((tx + 1) * width / target_width).max(tx + 1));
+        let (y0, y1) =
+            (ty * height / target_height, ((ty + 1) * height / target_height).max(ty + 1));
+        let (x1, y1) = (x1.min(width), y1.min(height));
+
+        let (mut r, mut g, mut b, mut a, mut count) = (0u64, 0u64, 0u64, 0u64, 0u64);
+        for y in y0..y1 {
+            for x in x0..x1 {
+                let p = source[(y * width + x) as usize];
+                r += p.r as u64 * p.a as u64;
+                g += p.g as u64 * p.a as u64;
+                b += p.b as u64 * p.a as u64;
+                a += p.a as u64;
+                count += 1;
+            }
+        }
+        *pixel = r.checked_div(a).map_or(Rgba8Pixel::new(0, 0, 0, 0), |r| {
+            Rgba8Pixel::new(r as u8, (g / a) as u8, (b / a) as u8, (a / count) as u8)
+        });
+    }
+    result
+}
+
+#[test]
+fn test_scale_down_to_fit() {
+    let mut buffer = SharedPixelBuffer::<Rgba8Pixel>::new(4, 2);
+    buffer.make_mut_slice().copy_from_slice(&[
+        Rgba8Pixel::new(255, 0, 0, 255),
+        Rgba8Pixel::new(0, 0, 255, 255),
+        Rgba8Pixel::new(10, 20, 30, 255),
+        Rgba8Pixel::new(99, 99, 99, 0),
+        Rgba8Pixel::new(255, 0, 0, 255),
+        Rgba8Pixel::new(0, 0, 255, 255),
+        Rgba8Pixel::new(10, 20, 30, 255),
+        Rgba8Pixel::new(99, 99, 99, 0),
+    ]);
+    let unchanged = scale_down_to_fit(buffer.clone(), 4, 4);
+    assert_eq!(unchanged.as_slice(), buffer.as_slice());
+
+    let scaled = scale_down_to_fit(buffer, 2, 2);
+    assert_eq!((scaled.width(), scaled.height()), (2, 1));
+    assert_eq!(
+        scaled.as_slice(),
+        &[Rgba8Pixel::new(127, 0, 127, 255), Rgba8Pixel::new(10, 20, 30, 127)]
+    );
+}
+
 #[test]
 fn test_image_size_from_buffer_without_backend() {
     {
@@ -1040,6 +1557,58 @@ fn test_image_size_from_buffer_without_backend() {
     }
 }
 
+#[test]
+fn test_image_from_rgb565_and_gray8() {
+    let mut buffer = SharedPixelBuffer::<Rgb565Pixel>::new(2, 1);
+    buffer.make_mut_slice().copy_from_slice(&[
+        Rgb565Pixel::from(Rgb8Pixel::new(0xf8, 0x00, 0x00)),
+        Rgb565Pixel::from(Rgb8Pixel::new(0x00, 0xfc, 0xf8)),
+    ]);
+    let image = Image::from_rgb565(buffer.clone());
+    assert_eq!(image.size(), [2, 1].into());
+    assert_eq!(image.to_rgb565().as_ref().map(|b| b.as_slice()), Some(buffer.as_slice()));
+    assert!(image.to_gray8().is_none());
+    assert_eq!(
+        image.to_rgb8().unwrap().as_slice(),
+        &[Rgb8Pixel::new(0xf8, 0x00, 0x00), Rgb8Pixel::new(0x00, 0xfc, 0xf8)]
+    );
+    assert_eq!(
+        image.to_rgba8_premultiplied().unwrap().as_slice(),
+        &[Rgba8Pixel::new(0xf8, 0x00, 0x00, 0xff), Rgba8Pixel::new(0x00, 0xfc, 0xf8, 0xff)]
+    );
+
+    let mut buffer = SharedPixelBuffer::<Gray8Pixel>::new(3, 1);
+    for (pixel, gray) in buffer.make_mut_slice().iter_mut().zip([0x10, 0x80, 0xff]) {
+        *pixel.value_mut() = gray;
+    }
+    let image = Image::from_gray8(buffer.clone());
+    assert_eq!(image.size(), [3, 1].into());
+    assert_eq!(image.to_gray8().as_ref().map(|b| b.as_slice()), Some(buffer.as_slice()));
+    assert_eq!(image.to_rgba8().unwrap().as_slice()[1], Rgba8Pixel::new(0x80, 0x80, 0x80, 0xff));
+    assert_eq!(image.to_rgb565().unwrap().as_slice()[2], Rgb8Pixel::new(0xff, 0xff, 0xff).into());
+}
+
+#[test]
+fn test_decode_run_length_encoded_line() {
+    let data = [
+        // The offsets of the two lines
+        8, 0, 0, 0, 12, 0, 0, 0, //
+        // Three times 1, then 2
+        0x82, 1, 0x00, 2, //
+        // 3, 4, 5, 6
+        0x03, 3, 4, 5, 6,
+    ];
+    let decode = |y, x, len| {
+        let mut out = alloc::vec::Vec::new();
+        decode_run_length_encoded_line(&data, y, x, len, 1, &mut out);
+        out
+    };
+    assert_eq!(decode(0, 0, 4), [1, 1, 1, 2]);
+    assert_eq!(decode(0, 2, 2), [1, 2]);
+    assert_eq!(decode(1, 0, 4), [3, 4, 5, 6]);
+    assert_eq!(decode(1, 1, 2), [4, 5]);
+}
+
 #[cfg(feature = "svg")]
 #[test]
 fn test_image_size_from_svg() {
@@ -1049,6 +1618,21 @@ fn test_image_size_from_svg() {
     assert_eq!(image.to_rgba8().unwrap().size(), image.size());
 }
 
+#[cfg(feature = "svg")]
+#[test]
+fn test_rasterize_svg() {
+    let simple_svg = r#"<svg width="320" height="200" xmlns="http://www.w3.org/2000/svg"></svg>"#;
+    let image =
+        Image::load_from_svg_data_with_size(simple_svg.as_bytes(), IntSize::new(640, 400)).unwrap();
+    assert_eq!(image.size(), IntSize::new(640, 400));
+    assert!(!image.0.is_svg());
+    // The aspect ratio is preserved
+    let image = Image::load_from_svg_data(simple_svg.as_bytes()).unwrap();
+    assert_eq!(image.rasterize_svg(IntSize::new(160, 160)).unwrap().size(), IntSize::new(160, 100));
+    assert!(image.rasterize_svg(IntSize::new(0, 0)).is_err());
+    assert!(Image::default().rasterize_svg(IntSize::new(10, 10)).is_err());
+}
+
 #[cfg(feature = "svg")]
 #[test]
 fn test_image_invalid_svg() {
@@ -1337,6 +1921,15 @@ struct Rgba8Pixel {
         a: u8,
     }
 
+    // Expand Gray8Pixel so that cbindgen can see it. (is in fact rgb::Gray<u8>)
+    /// Represents a gray pixel.
+    #[cfg(cbindgen)]
+    #[repr(C)]
+    struct Gray8Pixel {
+        /// gray value (between 0 and 255)
+        v: u8,
+    }
+
     #[cfg(feature = "image-decoders")]
     #[unsafe(no_mangle)]
     pub unsafe extern "C" fn slint_image_load_from_path(path: &SharedString, image: *mut Image) {
@@ -1472,6 +2065,48 @@ pub struct BorrowedOpenGLTexture {
     pub origin: BorrowedOpenGLTextureOrigin,
 }
 
+/// The platform specific handle of a [`BorrowedExternalTexture`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+#[cfg(not(target_arch = "wasm32"))]
+#[repr(C, u8)]
+pub enum ExternalTextureHandle {
+    /// A single plane Linux DMA-BUF.
+    DmaBuf {
+        /// The file descriptor of the buffer.
+        fd: i32,
+        /// The DRM fourcc code of the pixel format.
+        fourcc: u32,
+        /// The DRM format modifier.
+        modifier: u64,
+        /// The offset of the first pixel in the buffer, in bytes.
+        offset: u32,
+        /// The number of bytes per row.
+        stride: u32,
+    },
+    /// An `IOSurfaceRef` on macOS and iOS.
+    IOSurface(*mut core::ffi::c_void),
+    /// A shared NT handle of a Direct3D texture on Windows.
+    D3DSharedHandle(*mut core::ffi::c_void),
+}
+
+/// This structure describes a texture shared by the operating system or another graphics API, which
+/// the renderer imports without copying the pixels. Create it with [`BorrowedExternalTextureBuilder`].
+///
+/// The ownership of the underlying buffer remains with the application.
+/// cbindgen:derive-eq=false
+/// cbindgen:derive-neq=false
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+#[cfg(not(target_arch = "wasm32"))]
+#[repr(C)]
+pub struct BorrowedExternalTexture {
+    /// The handle of the texture.
+    pub handle: ExternalTextureHandle,
+    /// The size of the texture in pixels.
+    pub size: IntSize,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::graphics::Rgba8Pixel;
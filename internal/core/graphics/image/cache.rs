@@ -7,8 +7,10 @@
 
 use super::{CachedPath, Image, ImageCacheKey, ImageInner, SharedImageBuffer, SharedPixelBuffer};
 use crate::{slice::Slice, SharedString};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
 
-struct ImageWeightInBytes;
+pub(crate) struct ImageWeightInBytes;
 
 impl clru::WeightScale<ImageCacheKey, ImageInner> for ImageWeightInBytes {
     fn weight(&self, _key: &ImageCacheKey, value: &ImageInner) -> usize {
@@ -18,6 +20,8 @@ fn weight(&self, _key: &ImageCacheKey, value: &ImageInner) -> usize {
                 SharedImageBuffer::RGB8(pixels) => pixels.as_bytes().len(),
                 SharedImageBuffer::RGBA8(pixels) => pixels.as_bytes().len(),
                 SharedImageBuffer::RGBA8Premultiplied(pixels) => pixels.as_bytes().len(),
+                SharedImageBuffer::RGB565(pixels) => core::mem::size_of_val(pixels.as_slice()),
+                SharedImageBuffer::Gray8(pixels) => pixels.as_bytes().len(),
             },
             #[cfg(feature = "svg")]
             ImageInner::Svg(_) => 512, // Don't know how to measure the size of the parsed SVG tree...
@@ -27,6 +31,8 @@ fn weight(&self, _key: &ImageCacheKey, value: &ImageInner) -> usize {
             ImageInner::BackendStorage(x) => vtable::VRc::borrow(x).size().area() as usize,
             #[cfg(not(target_arch = "wasm32"))]
             ImageInner::BorrowedOpenGLTexture(..) => 0, // Assume storage in GPU memory
+            #[cfg(not(target_arch = "wasm32"))]
+            ImageInner::BorrowedExternalTexture(..) => 0, // Owned by the application
             ImageInner::NineSlice(nine) => self.weight(_key, &nine.0),
             #[cfg(any(feature = "unstable-wgpu-26", feature = "unstable-wgpu-27"))]
             ImageInner::WGPUTexture(..) => 0, // The texture is imported from the application and will never reside in our cache.
@@ -34,21 +40,23 @@ fn weight(&self, _key: &ImageCacheKey, value: &ImageInner) -> usize {
     }
 }
 
+type ImageCacheStorage = clru::CLruCache<
+    ImageCacheKey,
+    ImageInner,
+    std::collections::hash_map::RandomState,
+    ImageWeightInBytes,
+>;
+
 /// Cache used to avoid repeatedly decoding images from disk.
-pub(crate) struct ImageCache(
-    clru::CLruCache<
-        ImageCacheKey,
-        ImageInner,
-        std::collections::hash_map::RandomState,
-        ImageWeightInBytes,
-    >,
-);
+pub(crate) struct ImageCache(pub(crate) ImageCacheStorage);
 
 crate::thread_local!(pub(crate) static IMAGE_CACHE: core::cell::RefCell<ImageCache>  =
     core::cell::RefCell::new(
         ImageCache(
             clru::CLruCache::with_config(
-                clru::CLruCacheConfig::new(core::num::NonZeroUsize::new(5 * 1024 * 1024).unwrap())
+                clru::CLruCacheConfig::new(core::num::NonZeroUsize::new(
+                    crate::resource_cache::ResourceCache::Images.default_limit(),
+                ).unwrap())
                     .with_scale(ImageWeightInBytes)
             )
         )
@@ -67,7 +75,7 @@ fn lookup_image_in_cache_or_create(
             entry.clone()
         } else {
             let new_image = image_create_fn(cache_key.clone())?;
-            self.0.put_with_weight(cache_key, new_image.clone()).ok();
+            put_in_cache(&mut self.0, cache_key, new_image.clone());
             new_image
         }))
     }
@@ -155,6 +163,113 @@ pub(crate) fn load_image_from_embedded_data(
     }
 }
 
+/// The state of encoded data being decoded in the background thread, shared by the futures awaiting it
+#[derive(Default)]
+struct PendingDecode {
+    /// Set once the decoding finished, to `None` if the data couldn't be decoded
+    result: Option<Option<SharedImageBuffer>>,
+    wakers: Vec<core::task::Waker>,
+}
+
+type SharedPendingDecode = std::sync::Arc<std::sync::Mutex<PendingDecode>>;
+
+crate::thread_local!(static PENDING_DECODES: core::cell::RefCell<std::collections::HashMap<ImageCacheKey, SharedPendingDecode>> = Default::default());
+
+/// Implementation of [`Image::load_from_encoded_bytes_async`]
+pub(crate) async fn load_image_from_encoded_bytes_async(
+    bytes: impl AsRef<[u8]> + Send + 'static,
+) -> Option<Image> {
+    let cache_key = ImageCacheKey::from_encoded_data(bytes.as_ref());
+    if let Some(image) =
+        IMAGE_CACHE.with(|global_cache| global_cache.borrow_mut().0.get(&cache_key).cloned())
+    {
+        return Some(Image(image));
+    }
+
+    #[cfg(feature = "svg")]
+    if image::guess_format(bytes.as_ref()).is_err() {
+        // Not a raster image. SVGs are only parsed here, they are rendered when drawn.
+        if let Ok(svg) = super::svg::load_from_data(bytes.as_ref(), cache_key.clone()) {
+            let image = ImageInner::Svg(vtable::VRc::new(svg));
+            replace_cached_image(cache_key, image.clone());
+            return Some(Image(image));
+        }
+    }
+
+    // Images with the same content that are loaded concurrently are decoded only once
+    let pending = PENDING_DECODES.with(|pending| {
+        pending
+            .borrow_mut()
+            .entry(cache_key.clone())
+            .or_insert_with(|| {
+                let pending = SharedPendingDecode::default();
+                decode_in_background(bytes, pending.clone());
+                pending
+            })
+            .clone()
+    });
+    let buffer = core::future::poll_fn(|cx| {
+        let mut pending = pending.lock().unwrap();
+        match &pending.result {
+            Some(result) => core::task::Poll::Ready(result.clone()),
+            None => {
+                if !pending.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+                    pending.wakers.push(cx.waker().clone());
+                }
+                core::task::Poll::Pending
+            }
+        }
+    })
+    .await;
+    PENDING_DECODES.with(|pending| pending.borrow_mut().remove(&cache_key));
+
+    let Some(buffer) = buffer else {
+        crate::debug_log!("Error decoding image from encoded bytes");
+        return None;
+    };
+    let image = ImageInner::EmbeddedImage { cache_key: cache_key.clone(), buffer };
+    replace_cached_image(cache_key, image.clone());
+    Some(Image(image))
+}
+
+/// Decodes the image in the image decoder thread, and wakes the futures awaiting `pending`
+fn decode_in_background(bytes: impl AsRef<[u8]> + Send + 'static, pending: SharedPendingDecode) {
+    type DecodeJob = Box<dyn FnOnce() + Send>;
+    static DECODER_THREAD: std::sync::OnceLock<Option<std::sync::mpsc::Sender<DecodeJob>>> =
+        std::sync::OnceLock::new();
+
+    let job: DecodeJob = Box::new(move || {
+        let result =
+            image::load_from_memory(bytes.as_ref()).ok().map(dynamic_image_to_shared_image_buffer);
+        let wakers = {
+            let mut pending = pending.lock().unwrap();
+            pending.result = Some(result);
+            core::mem::take(&mut pending.wakers)
+        };
+        for waker in wakers {
+            waker.wake();
+        }
+    });
+
+    let sender = DECODER_THREAD.get_or_init(|| {
+        let (sender, receiver) = std::sync::mpsc::channel::<DecodeJob>();
+        std::thread::Builder::new()
+            .name("Slint image decoder".into())
+            .spawn(move || receiver.into_iter().for_each(|job| job()))
+            .ok()?;
+        Some(sender)
+    });
+    match sender {
+        Some(sender) => {
+            if let Err(std::sync::mpsc::SendError(job)) = sender.send(job) {
+                job();
+            }
+        }
+        // Threads are not supported on this platform, decode in this thread
+        None => job(),
+    }
+}
+
 fn dynamic_image_to_shared_image_buffer(dynamic_image: image::DynamicImage) -> SharedImageBuffer {
     if dynamic_image.color().has_alpha() {
         let rgba8image = dynamic_image.to_rgba8();
@@ -178,13 +293,99 @@ pub fn replace_cached_image(key: ImageCacheKey, value: ImageInner) {
     if key == ImageCacheKey::Invalid {
         return;
     }
-    let _ =
-        IMAGE_CACHE.with(|global_cache| global_cache.borrow_mut().0.put_with_weight(key, value));
+    IMAGE_CACHE.with(|global_cache| put_in_cache(&mut global_cache.borrow_mut().0, key, value));
+}
+
+/// Insert the image in the cache, evicting the least recently used images if needed
+fn put_in_cache(cache: &mut ImageCacheStorage, key: ImageCacheKey, value: ImageInner) {
+    crate::resource_cache::put(
+        crate::resource_cache::ResourceCache::Images,
+        cache,
+        key,
+        value,
+        &|key, value| clru::WeightScale::weight(&ImageWeightInBytes, key, value),
+    );
 }
 
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use crate::graphics::Rgba8Pixel;
+    use core::future::Future;
+
+    /// Runs the future to completion in the current thread
+    fn block_on<T>(fut: impl core::future::Future<Output = T>) -> T {
+        struct ThreadWaker(std::thread::Thread);
+        impl std::task::Wake for ThreadWaker {
+            fn wake(self: std::sync::Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+        let mut fut = core::pin::pin!(fut);
+        let waker = std::sync::Arc::new(ThreadWaker(std::thread::current())).into();
+        let mut cx = core::task::Context::from_waker(&waker);
+        loop {
+            match fut.as_mut().poll(&mut cx) {
+                core::task::Poll::Ready(result) => return result,
+                core::task::Poll::Pending => std::thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_load_from_encoded_bytes_async() {
+        let mut png = std::vec::Vec::new();
+        image::RgbImage::from_pixel(8, 4, image::Rgb([0, 0, 255]))
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .unwrap();
+
+        // Both loads share the same decoding
+        let (first, second) = block_on(async {
+            let first = crate::graphics::Image::load_from_encoded_bytes_async(png.clone());
+            let second = crate::graphics::Image::load_from_encoded_bytes_async(png.clone());
+            let mut first = core::pin::pin!(first);
+            let mut second = core::pin::pin!(second);
+            let mut first_result = None;
+            let mut second_result = None;
+            core::future::poll_fn(|cx| {
+                if first_result.is_none() {
+                    first_result = match first.as_mut().poll(cx) {
+                        core::task::Poll::Ready(r) => Some(r),
+                        core::task::Poll::Pending => None,
+                    };
+                }
+                if second_result.is_none() {
+                    second_result = match second.as_mut().poll(cx) {
+                        core::task::Poll::Ready(r) => Some(r),
+                        core::task::Poll::Pending => None,
+                    };
+                }
+                if first_result.is_some() && second_result.is_some() {
+                    core::task::Poll::Ready(())
+                } else {
+                    core::task::Poll::Pending
+                }
+            })
+            .await;
+            (first_result.unwrap().unwrap(), second_result.unwrap().unwrap())
+        });
+        assert_eq!(first.size(), [8, 4].into());
+        assert_eq!(first, second);
+        assert!(first
+            .to_rgba8()
+            .unwrap()
+            .as_slice()
+            .iter()
+            .all(|pixel| *pixel == Rgba8Pixel { r: 0, g: 0, b: 255, a: 255 }));
+
+        // Served from the cache
+        let cached =
+            block_on(crate::graphics::Image::load_from_encoded_bytes_async(png.clone())).unwrap();
+        assert_eq!(cached, first);
+
+        assert!(
+            block_on(crate::graphics::Image::load_from_encoded_bytes_async(b"garbage")).is_err()
+        );
+    }
 
     #[test]
     fn test_path_cache_invalidation() {
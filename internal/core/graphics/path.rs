@@ -245,6 +245,29 @@ pub fn iter(
         }
     }
 
+    /// Returns the path cut into dashes of length `dash`, separated by gaps of length `gap`, for
+    /// renderers that can't stroke dashed lines themselves. Curves are approximated with lines.
+    pub fn dashed(&self, dash: f32, gap: f32) -> lyon_path::Path {
+        let mut builder = lyon_path::Path::builder();
+        for event in self.iter() {
+            builder.path_event(event);
+        }
+        let path = builder.build();
+        let measurements = lyon_algorithms::measure::PathMeasurements::from_path(&path, 0.05);
+        let mut sampler =
+            measurements.create_sampler(&path, lyon_algorithms::measure::SampleType::Distance);
+        let length = sampler.length();
+        let mut dashes = lyon_path::Path::builder();
+        if dash > 0. && gap > 0. {
+            let mut start = 0.;
+            while start < length {
+                sampler.split_range(start..(start + dash).min(length), &mut dashes);
+                start += dash + gap;
+            }
+        }
+        dashes.build()
+    }
+
     /// Applies a transformation on the elements this iterator provides that tries to fit everything
     /// into the specified width/height, respecting the provided viewbox. If no viewbox is specified,
     /// the bounding rectangle of the path is used.
@@ -316,6 +339,58 @@ pub fn iter(self) -> Option<PathDataIterator> {
         .into()
     }
 
+    /// Returns a [`PathBuilder`] to construct a path from Rust code.
+    pub fn builder() -> PathBuilder {
+        PathBuilder::default()
+    }
+
+    /// Returns true if the point is inside the area of the path, using the "nonzero" fill rule.
+    ///
+    /// The point is in the coordinate system of the path, before it is fitted into the geometry
+    /// of a `Path` element. Sub-paths that aren't closed are closed implicitly.
+    pub fn contains(&self, point: crate::api::LogicalPosition) -> bool {
+        let Some(it) = self.clone().iter() else { return false };
+        lyon_algorithms::hit_test::hit_test_path(
+            &lyon_path::math::Point::new(point.x, point.y),
+            it.iter(),
+            lyon_path::FillRule::NonZero,
+            0.1,
+        )
+    }
+
+    /// Returns the path encoded as SVG path commands, as accepted by the `commands` property of
+    /// the `Path` element.
+    ///
+    /// Arcs are approximated with Bézier curves.
+    pub fn to_svg_commands(&self) -> crate::SharedString {
+        use core::fmt::Write;
+        use lyon_path::Event;
+
+        if let PathData::Commands(commands) = self {
+            return commands.clone();
+        }
+        let Some(it) = self.clone().iter() else { return Default::default() };
+        let mut commands = alloc::string::String::new();
+        for event in it.iter() {
+            let separator = if commands.is_empty() { "" } else { " " };
+            let _ = match event {
+                Event::Begin { at } => write!(commands, "{separator}M {} {}", at.x, at.y),
+                Event::Line { to, .. } => write!(commands, "{separator}L {} {}", to.x, to.y),
+                Event::Quadratic { ctrl, to, .. } => {
+                    write!(commands, "{separator}Q {} {} {} {}", ctrl.x, ctrl.y, to.x, to.y)
+                }
+                Event::Cubic { ctrl1, ctrl2, to, .. } => write!(
+                    commands,
+                    "{separator}C {} {} {} {} {} {}",
+                    ctrl1.x, ctrl1.y, ctrl2.x, ctrl2.y, to.x, to.y
+                ),
+                Event::End { close: true, .. } => write!(commands, "{separator}Z"),
+                Event::End { close: false, .. } => Ok(()),
+            };
+        }
+        commands.into()
+    }
+
     fn build_path(element_it: core::slice::Iter<PathElement>) -> lyon_path::Path {
         use lyon_geom::SvgArc;
         use lyon_path::math::{Angle, Point, Vector};
@@ -387,6 +462,142 @@ fn build_path(element_it: core::slice::Iter<PathElement>) -> lyon_path::Path {
     }
 }
 
+/// A builder to construct a [`PathData`] from Rust code, created with [`PathData::builder()`].
+///
+/// The coordinates are in the coordinate system of the path. When the path is shown in a `Path`
+/// element, it is scaled to fit into the element, unless a view-box is set.
+///
+/// ```
+/// # use i_slint_core::graphics::PathData;
+/// # use i_slint_core::api::LogicalPosition;
+/// let triangle = PathData::builder()
+///     .move_to(0., 0.)
+///     .line_to(100., 0.)
+///     .line_to(50., 100.)
+///     .close()
+///     .build();
+/// assert!(triangle.contains(LogicalPosition::new(50., 50.)));
+/// assert!(!triangle.contains(LogicalPosition::new(5., 90.)));
+/// assert_eq!(triangle.to_svg_commands(), "M 0 0 L 100 0 L 50 100 Z");
+/// ```
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct PathBuilder {
+    elements: crate::SharedVector<PathElement>,
+}
+
+impl PathBuilder {
+    /// Starts a new sub-path at the given position.
+    pub fn move_to(mut self, x: f32, y: f32) -> Self {
+        self.elements.push(PathMoveTo { x, y }.into());
+        self
+    }
+
+    /// Adds a straight line from the current position to the given position.
+    pub fn line_to(mut self, x: f32, y: f32) -> Self {
+        self.elements.push(PathLineTo { x, y }.into());
+        self
+    }
+
+    /// Adds a quadratic Bézier curve from the current position to `(x, y)`, with the control point
+    /// `(control_x, control_y)`.
+    pub fn quadratic_to(mut self, control_x: f32, control_y: f32, x: f32, y: f32) -> Self {
+        self.elements.push(PathQuadraticTo { x, y, control_x, control_y }.into());
+        self
+    }
+
+    /// Adds a cubic Bézier curve from the current position to `(x, y)`, with the two control points
+    /// `(control_1_x, control_1_y)` and `(control_2_x, control_2_y)`.
+    pub fn cubic_to(
+        mut self,
+        control_1_x: f32,
+        control_1_y: f32,
+        control_2_x: f32,
+        control_2_y: f32,
+        x: f32,
+        y: f32,
+    ) -> Self {
+        self.elements
+            .push(PathCubicTo { x, y, control_1_x, control_1_y, control_2_x, control_2_y }.into());
+        self
+    }
+
+    /// Adds an elliptical arc from the current position to `(x, y)`, as the `A` command of SVG paths.
+    ///
+    /// `x_rotation` is in degrees. `large_arc` selects the longer of the two possible arcs, and
+    /// `sweep` selects the arc that goes clockwise.
+    #[allow(clippy::too_many_arguments)]
+    pub fn arc_to(
+        mut self,
+        radius_x: f32,
+        radius_y: f32,
+        x_rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        x: f32,
+        y: f32,
+    ) -> Self {
+        self.elements
+            .push(PathArcTo { x, y, radius_x, radius_y, x_rotation, large_arc, sweep }.into());
+        self
+    }
+
+    /// Closes the current sub-path with a straight line to its starting point.
+    pub fn close(mut self) -> Self {
+        self.elements.push(PathElement::Close);
+        self
+    }
+
+    /// Returns the path that was built.
+    pub fn build(self) -> PathData {
+        if self.elements.is_empty() {
+            PathData::None
+        } else {
+            PathData::Elements(self.elements)
+        }
+    }
+}
+
+#[test]
+fn test_path_builder() {
+    use crate::api::LogicalPosition;
+
+    assert_eq!(PathData::builder().build(), PathData::None);
+    assert!(!PathData::None.contains(LogicalPosition::new(0., 0.)));
+    assert_eq!(PathData::None.to_svg_commands(), "");
+
+    let square = PathData::builder()
+        .move_to(0., 0.)
+        .line_to(10., 0.)
+        .line_to(10., 10.)
+        .line_to(0., 10.)
+        .close()
+        .move_to(20., 20.)
+        .cubic_to(25., 20., 30., 25., 30., 30.)
+        .quadratic_to(20., 30., 20., 20.)
+        .build();
+    assert!(square.contains(LogicalPosition::new(5., 5.)));
+    assert!(!square.contains(LogicalPosition::new(15., 15.)));
+    assert_eq!(
+        square.to_svg_commands(),
+        "M 0 0 L 10 0 L 10 10 L 0 10 Z M 20 20 C 25 20 30 25 30 30 Q 20 30 20 20"
+    );
+
+    let circle = PathData::builder()
+        .move_to(0., 50.)
+        .arc_to(50., 50., 0., false, true, 100., 50.)
+        .arc_to(50., 50., 0., false, true, 0., 50.)
+        .close()
+        .build();
+    assert!(circle.contains(LogicalPosition::new(50., 50.)));
+    assert!(circle.contains(LogicalPosition::new(50., 2.)));
+    assert!(!circle.contains(LogicalPosition::new(5., 5.)));
+
+    let commands = PathData::Commands("M 0 0 L 10 0 L 0 10 Z".into());
+    assert!(commands.contains(LogicalPosition::new(2., 2.)));
+    assert_eq!(commands.to_svg_commands(), "M 0 0 L 10 0 L 0 10 Z");
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub(crate) mod ffi {
     #![allow(unsafe_code)]
@@ -32,6 +32,9 @@ pub struct RenderingMetrics {
 
     /// dirty_region
     pub dirty_region: Option<crate::partial_renderer::DirtyRegion>,
+
+    /// The memory used by the caches of the renderer, shown in the overlay.
+    pub cache_statistics: Option<crate::renderer::CacheStatistics>,
 }
 
 impl core::fmt::Display for RenderingMetrics {
@@ -42,10 +45,23 @@ fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if let Some(dirty_region) = &self.dirty_region {
             write!(f, "(dirty: {dirty_region:?})")?
         }
+        if let Some(cache_statistics) = &self.cache_statistics {
+            write!(f, "(caches: {cache_statistics})")?
+        }
         Ok(())
     }
 }
 
+/// Returns true if the `startup` option is set in the `SLINT_DEBUG_PERFORMANCE` environment variable,
+/// in which case the time it took to render the first frame of each window is printed to the console.
+pub fn startup_timings_requested() -> bool {
+    #[cfg(feature = "std")]
+    let options = std::env::var("SLINT_DEBUG_PERFORMANCE").ok();
+    #[cfg(not(feature = "std"))]
+    let options = option_env!("SLINT_DEBUG_PERFORMANCE");
+    options.is_some_and(|options| options.split(',').any(|option| option == "startup"))
+}
+
 struct FrameData {
     timestamp: Instant,
     metrics: RenderingMetrics,
@@ -67,6 +83,7 @@ impl RenderingMetricsCollector {
     ///     * `refresh_full_speed`: frames are continuously rendered
     ///     * `console`: the measurement is printed to the console
     ///     * `overlay`: the measurement is drawn as overlay on top of the scene
+    ///     * `startup`: the time to the first frame of each window is printed to the console, see [`startup_timings_requested()`]
     ///
     /// If enabled, this will also print out some system information such as whether
     /// this is a debug or release build, as well as the provided winsys_info string.
@@ -78,8 +95,10 @@ pub fn new(winsys_info: &str) -> Option<Rc<Self>> {
         let mut output_console = false;
         let mut output_overlay = false;
         let mut refresh_mode = None;
+        let mut startup = false;
         for option in options.split(',') {
             match option {
+                "startup" => startup = true,
                 "console" => output_console = true,
                 "overlay" => output_overlay = true,
                 "refresh_lazy" => refresh_mode = Some(RefreshMode::Lazy),
@@ -88,6 +107,11 @@ pub fn new(winsys_info: &str) -> Option<Rc<Self>> {
             }
         }
 
+        // The startup timings don't need the frame rate to be measured
+        if startup && refresh_mode.is_none() && !output_console && !output_overlay {
+            return None;
+        }
+
         let Some(refresh_mode) = refresh_mode else {
             debug_log!("Missing refresh mode in SLINT_DEBUG_PERFORMANCE. Please specify either refresh_full_speed or refresh_lazy");
             return None;
@@ -165,6 +189,7 @@ pub fn measure_frame_rendered(
         renderer: &mut dyn crate::item_rendering::ItemRenderer,
         metrics: RenderingMetrics,
     ) {
+        let cache_statistics = metrics.cache_statistics;
         self.collected_frame_data_since_second_ago
             .borrow_mut()
             .push(FrameData { timestamp: Instant::now(), metrics });
@@ -175,10 +200,12 @@ pub fn measure_frame_rendered(
         self.trim_frame_data_to_second_boundary();
 
         if self.output_overlay {
-            renderer.draw_string(
-                &format!("FPS: {}", self.collected_frame_data_since_second_ago.borrow().len()),
-                crate::Color::from_rgb_u8(0, 128, 128),
-            );
+            let fps = self.collected_frame_data_since_second_ago.borrow().len();
+            let text = match cache_statistics {
+                Some(cache_statistics) => format!("FPS: {fps} | {cache_statistics}"),
+                None => format!("FPS: {fps}"),
+            };
+            renderer.draw_string(&text, crate::Color::from_rgb_u8(0, 128, 128));
         }
     }
 
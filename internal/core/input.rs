@@ -54,6 +54,8 @@ pub enum MouseEvent {
     Drop(DropEvent),
     /// The mouse exited the item or component
     Exit,
+    /// A gesture was performed on a touchpad, while the pointer is at `position`.
+    Gesture { position: LogicalPoint, gesture: GestureEvent },
 }
 
 impl MouseEvent {
@@ -64,6 +66,7 @@ pub fn position(&self) -> Option<LogicalPoint> {
             MouseEvent::Released { position, .. } => Some(*position),
             MouseEvent::Moved { position } => Some(*position),
             MouseEvent::Wheel { position, .. } => Some(*position),
+            MouseEvent::Gesture { position, .. } => Some(*position),
             MouseEvent::DragMove(e) | MouseEvent::Drop(e) => {
                 Some(crate::lengths::logical_point_from_api(e.position))
             }
@@ -78,6 +81,7 @@ pub fn translate(&mut self, vec: LogicalVector) {
             MouseEvent::Released { position, .. } => Some(position),
             MouseEvent::Moved { position } => Some(position),
             MouseEvent::Wheel { position, .. } => Some(position),
+            MouseEvent::Gesture { position, .. } => Some(position),
             MouseEvent::DragMove(e) | MouseEvent::Drop(e) => {
                 e.position = crate::api::LogicalPosition::from_euclid(
                     crate::lengths::logical_point_from_api(e.position) + vec,
@@ -98,6 +102,7 @@ pub fn transform(&mut self, transform: ItemTransform) {
             MouseEvent::Released { position, .. } => Some(position),
             MouseEvent::Moved { position } => Some(position),
             MouseEvent::Wheel { position, .. } => Some(position),
+            MouseEvent::Gesture { position, .. } => Some(position),
             MouseEvent::DragMove(e) | MouseEvent::Drop(e) => {
                 e.position = crate::api::LogicalPosition::from_euclid(
                     transform
@@ -124,6 +129,47 @@ fn set_click_count(&mut self, count: u8) {
     }
 }
 
+/// A multi-finger gesture performed on a touchpad.
+///
+/// A gesture starts with a `*Begin` variant, is followed by any number of `*Update` variants
+/// and is finished with a `*End` variant of the same kind.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum GestureEvent {
+    /// Two or more fingers started a pinch or rotation gesture.
+    PinchBegin,
+    /// The fingers of a pinch gesture moved.
+    PinchUpdate {
+        /// The scale relative to the distance between the fingers when the gesture began.
+        scale: f32,
+        /// The rotation in degrees since the gesture began, clockwise.
+        rotation: f32,
+    },
+    /// The fingers of a pinch gesture were lifted.
+    PinchEnd {
+        /// True if the gesture was cancelled instead of finished.
+        cancelled: bool,
+    },
+    /// Several fingers started to swipe.
+    SwipeBegin {
+        /// The number of fingers of the swipe.
+        fingers: u32,
+    },
+    /// The fingers of a swipe gesture moved.
+    SwipeUpdate {
+        /// The distance in logical pixels in the horizontal direction since the last update.
+        delta_x: f32,
+        /// The distance in logical pixels in the vertical direction since the last update.
+        delta_y: f32,
+    },
+    /// The fingers of a swipe gesture were lifted.
+    SwipeEnd {
+        /// True if the gesture was cancelled instead of finished.
+        cancelled: bool,
+    },
+}
+
 /// This value is returned by the `input_event` function of an Item
 /// to notify the run-time about how the event was handled and
 /// what the next steps are.
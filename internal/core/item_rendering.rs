@@ -13,13 +13,15 @@
 };
 pub use crate::partial_renderer::CachedRenderingData;
 use crate::window::WindowAdapter;
-use crate::{Brush, SharedString};
+use crate::{Brush, Coord, SharedString};
 #[cfg(feature = "std")]
 use alloc::boxed::Box;
 use alloc::rc::Rc;
 #[cfg(feature = "std")]
 use core::cell::RefCell;
 use core::pin::Pin;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 #[cfg(feature = "std")]
 use std::collections::HashMap;
 use vtable::VRc;
@@ -176,6 +178,12 @@ pub fn render_item_children(
             if matches!(render_result, RenderingResult::ContinueRenderingChildren) {
                 render_item_children(renderer, component, index as isize, window_adapter);
             }
+            if index == 0 && window_adapter.window().0.layout_constraint_warnings() {
+                window_adapter.window().0.check_layout_constraints(component);
+            }
+            if do_draw && window_adapter.window().0.layout_debug_overlay() {
+                draw_layout_debug_outline(renderer, &item_rc, item_geometry.size);
+            }
             renderer.restore_state();
             VisitChildrenResult::CONTINUE
         };
@@ -187,6 +195,140 @@ pub fn render_item_children(
     );
 }
 
+const LAYOUT_DEBUG_OUTLINE: crate::Color = crate::Color::from_argb_u8(0xa0, 0x00, 0x80, 0xff);
+const LAYOUT_DEBUG_CONFLICT: crate::Color = crate::Color::from_argb_u8(0xff, 0xff, 0x00, 0x00);
+const LAYOUT_DEBUG_PADDING: crate::Color = crate::Color::from_argb_u8(0x60, 0x00, 0xc0, 0x40);
+const LAYOUT_DEBUG_SPACING: crate::Color = crate::Color::from_argb_u8(0x60, 0xff, 0x80, 0x00);
+
+/// The outline drawn on top of the items when the layout debug overlay is enabled
+struct LayoutDebugOutline {
+    color: crate::Color,
+    width: LogicalLength,
+}
+
+impl RenderBorderRectangle for LayoutDebugOutline {
+    fn background(self: Pin<&Self>) -> Brush {
+        Brush::default()
+    }
+    fn border_width(self: Pin<&Self>) -> LogicalLength {
+        self.width
+    }
+    fn border_radius(self: Pin<&Self>) -> LogicalBorderRadius {
+        LogicalBorderRadius::default()
+    }
+    fn border_color(self: Pin<&Self>) -> Brush {
+        self.color.into()
+    }
+}
+
+/// The shading of the padding and spacing of layouts in the layout debug overlay
+struct LayoutDebugFill(crate::Color);
+
+impl RenderRectangle for LayoutDebugFill {
+    fn background(self: Pin<&Self>) -> Brush {
+        self.0.into()
+    }
+}
+
+/// Draw the boundary of the item. If the item is a layout, its padding and the spacing between
+/// its children are shaded. Layouts and item trees whose constraints can't be satisfied (minimum
+/// size larger than the maximum size) are highlighted in red.
+fn draw_layout_debug_outline(renderer: &mut dyn ItemRenderer, item_rc: &ItemRc, size: LogicalSize) {
+    let mut is_layout = false;
+    let mut constraints_violated = false;
+    for orientation in
+        [crate::layout::Orientation::Horizontal, crate::layout::Orientation::Vertical]
+    {
+        let layout_info = item_rc.layout_info(orientation);
+        is_layout |= layout_info.is_some();
+        let item_tree_info = (item_rc.index() == 0)
+            .then(|| ItemTreeRc::borrow_pin(item_rc.item_tree()).as_ref().layout_info(orientation));
+        constraints_violated |=
+            layout_info.into_iter().chain(item_tree_info).any(|info| info.min > info.max);
+    }
+
+    if is_layout {
+        draw_layout_debug_gaps(renderer, item_rc, size);
+    }
+
+    let outline = if constraints_violated {
+        LayoutDebugOutline { color: LAYOUT_DEBUG_CONFLICT, width: LogicalLength::new(2 as _) }
+    } else {
+        LayoutDebugOutline { color: LAYOUT_DEBUG_OUTLINE, width: LogicalLength::new(1 as _) }
+    };
+    let outline = Pin::new(&outline);
+    renderer.draw_border_rectangle(outline, item_rc, size, &CachedRenderingData::default());
+}
+
+/// Shade the area of the layout that isn't covered by its children: the padding around the
+/// children, and the spacing between them along each axis.
+fn draw_layout_debug_gaps(renderer: &mut dyn ItemRenderer, item_rc: &ItemRc, size: LogicalSize) {
+    let mut children = alloc::vec::Vec::new();
+    let mut child = item_rc.first_child();
+    while let Some(c) = child {
+        let geometry = c.geometry();
+        if !geometry.is_empty() {
+            children.push(geometry);
+        }
+        child = c.next_sibling();
+    }
+    let Some(content) = children.iter().copied().reduce(|a, b| a.union(&b)) else { return };
+
+    let rect = |x: Coord, y: Coord, width: Coord, height: Coord| {
+        LogicalRect::new(LogicalPoint::new(x, y), LogicalSize::new(width, height))
+    };
+    let mut gaps = alloc::vec![
+        (rect(0 as _, 0 as _, size.width, content.min_y()), LAYOUT_DEBUG_PADDING),
+        (
+            rect(0 as _, content.max_y(), size.width, size.height - content.max_y()),
+            LAYOUT_DEBUG_PADDING,
+        ),
+        (rect(0 as _, content.min_y(), content.min_x(), content.height()), LAYOUT_DEBUG_PADDING),
+        (
+            rect(content.max_x(), content.min_y(), size.width - content.max_x(), content.height()),
+            LAYOUT_DEBUG_PADDING,
+        ),
+    ];
+
+    children
+        .sort_by(|a, b| a.min_x().partial_cmp(&b.min_x()).unwrap_or(core::cmp::Ordering::Equal));
+    let mut end = content.min_x();
+    for child in &children {
+        if child.min_x() > end {
+            gaps.push((
+                rect(end, content.min_y(), child.min_x() - end, content.height()),
+                LAYOUT_DEBUG_SPACING,
+            ));
+        }
+        end = end.max(child.max_x());
+    }
+    children
+        .sort_by(|a, b| a.min_y().partial_cmp(&b.min_y()).unwrap_or(core::cmp::Ordering::Equal));
+    let mut end = content.min_y();
+    for child in &children {
+        if child.min_y() > end {
+            gaps.push((
+                rect(content.min_x(), end, content.width(), child.min_y() - end),
+                LAYOUT_DEBUG_SPACING,
+            ));
+        }
+        end = end.max(child.max_y());
+    }
+
+    for (gap, color) in gaps.into_iter().filter(|(gap, _)| !gap.is_empty()) {
+        renderer.save_state();
+        renderer.translate(gap.origin.to_vector());
+        let fill = LayoutDebugFill(color);
+        renderer.draw_rectangle(
+            Pin::<&LayoutDebugFill>::new(&fill),
+            item_rc,
+            gap.size,
+            &CachedRenderingData::default(),
+        );
+        renderer.restore_state();
+    }
+}
+
 /// Renders the tree of items that component holds, using the specified renderer. Rendering is done
 /// relative to the specified origin.
 pub fn render_component_items(
@@ -271,6 +413,213 @@ pub trait RenderBorderRectangle {
     fn border_width(self: Pin<&Self>) -> LogicalLength;
     fn border_radius(self: Pin<&Self>) -> LogicalBorderRadius;
     fn border_color(self: Pin<&Self>) -> Brush;
+    /// Whether the edges and the border are rounded to device pixels
+    fn pixel_snapping(self: Pin<&Self>) -> PixelSnapping {
+        PixelSnapping::Auto
+    }
+}
+
+/// The widths and brushes of the four edges of the border of a `Rectangle`, for borders that are
+/// dashed or dotted, or whose edges differ. See [`draw_border_edges()`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct BorderEdges {
+    /// The widths of the top, right, bottom and left edges.
+    pub widths: [LogicalLength; 4],
+    /// The brushes of the top, right, bottom and left edges.
+    pub colors: [Brush; 4],
+    /// Whether the edges are solid, dashed or dotted.
+    pub style: BorderStyle,
+}
+
+impl BorderEdges {
+    /// Returns the rectangles that make up the border of a rectangle of the given size, each with
+    /// the brush of its edge. The top and bottom edges span the corners. The dashes and dots start
+    /// at the beginning of each edge and the last one is cut off at its end.
+    pub fn segments(&self, size: LogicalSize) -> alloc::vec::Vec<(LogicalRect, Brush)> {
+        let zero = 0 as Coord;
+        let [top, right, bottom, left] = self.widths.map(|width| width.get());
+        let inner_height = (size.height - top - bottom).max(zero);
+        // The origin, the length, the thickness, and whether the edge is horizontal
+        let edges = [
+            (LogicalPoint::new(zero, zero), size.width, top, true),
+            (LogicalPoint::new(size.width - right, top), inner_height, right, false),
+            (LogicalPoint::new(zero, size.height - bottom), size.width, bottom, true),
+            (LogicalPoint::new(zero, top), inner_height, left, false),
+        ];
+        let mut segments = alloc::vec::Vec::new();
+        for ((origin, length, thickness, horizontal), brush) in edges.into_iter().zip(&self.colors)
+        {
+            if thickness <= zero || length <= zero || brush.is_transparent() {
+                continue;
+            }
+            let (dash, gap) = match self.style {
+                BorderStyle::Dashed => (thickness * (3 as Coord), thickness * (2 as Coord)),
+                BorderStyle::Dotted => (thickness, thickness),
+                _ => (length, zero),
+            };
+            let mut start = zero;
+            while start < length {
+                let end = (start + dash).min(length);
+                let segment = if horizontal {
+                    LogicalRect::new(
+                        origin + LogicalVector::new(start, zero),
+                        LogicalSize::new(end - start, thickness),
+                    )
+                } else {
+                    LogicalRect::new(
+                        origin + LogicalVector::new(zero, start),
+                        LogicalSize::new(thickness, end - start),
+                    )
+                };
+                segments.push((segment, brush.clone()));
+                start = end + gap;
+            }
+        }
+        segments
+    }
+}
+
+/// A rectangle without border, for the background and the segments drawn by [`draw_border_edges()`]
+struct FilledRectangle {
+    background: Brush,
+    radius: LogicalBorderRadius,
+    pixel_snapping: PixelSnapping,
+}
+
+impl RenderBorderRectangle for FilledRectangle {
+    fn background(self: Pin<&Self>) -> Brush {
+        self.background.clone()
+    }
+    fn border_width(self: Pin<&Self>) -> LogicalLength {
+        LogicalLength::default()
+    }
+    fn border_radius(self: Pin<&Self>) -> LogicalBorderRadius {
+        self.radius
+    }
+    fn border_color(self: Pin<&Self>) -> Brush {
+        Brush::default()
+    }
+    fn pixel_snapping(self: Pin<&Self>) -> PixelSnapping {
+        self.pixel_snapping
+    }
+}
+
+/// Draws the background of `rect` and the segments of its border described by `edges`, with
+/// [`ItemRenderer::draw_border_rectangle()`], so that all renderers support them. The border radius
+/// of `rect` only applies to the background.
+pub fn draw_border_edges(
+    renderer: &mut dyn ItemRenderer,
+    rect: Pin<&dyn RenderBorderRectangle>,
+    item_rc: &ItemRc,
+    size: LogicalSize,
+    edges: &BorderEdges,
+    cache: &CachedRenderingData,
+) {
+    let pixel_snapping = rect.pixel_snapping();
+    let background = FilledRectangle {
+        background: rect.background(),
+        radius: rect.border_radius(),
+        pixel_snapping,
+    };
+    let background = Pin::new(&background);
+    renderer.draw_border_rectangle(background, item_rc, size, cache);
+    for (segment, brush) in edges.segments(size) {
+        let segment_rect = FilledRectangle {
+            background: brush,
+            radius: LogicalBorderRadius::default(),
+            pixel_snapping,
+        };
+        let segment_rect = Pin::new(&segment_rect);
+        renderer.save_state();
+        renderer.translate(segment.origin.to_vector());
+        renderer.draw_border_rectangle(
+            segment_rect,
+            item_rc,
+            segment.size,
+            &CachedRenderingData::default(),
+        );
+        renderer.restore_state();
+    }
+}
+
+#[test]
+fn border_edges_segments() {
+    let red = Brush::from(crate::Color::from_rgb_u8(255, 0, 0));
+    let mut edges = BorderEdges {
+        widths: [1., 2., 0., 4.].map(|width| LogicalLength::new(width as Coord)),
+        colors: core::array::from_fn(|_| red.clone()),
+        style: BorderStyle::Solid,
+    };
+    let size = LogicalSize::new(20 as Coord, 10 as Coord);
+    let rects =
+        edges.segments(size).into_iter().map(|(rect, _)| rect).collect::<alloc::vec::Vec<_>>();
+    assert_eq!(
+        rects,
+        [
+            euclid::rect(0 as Coord, 0 as Coord, 20 as Coord, 1 as Coord),
+            euclid::rect(18 as Coord, 1 as Coord, 2 as Coord, 9 as Coord),
+            euclid::rect(0 as Coord, 1 as Coord, 4 as Coord, 9 as Coord),
+        ]
+    );
+
+    edges.style = BorderStyle::Dashed;
+    edges.widths[0] = LogicalLength::new(2 as Coord);
+    edges.colors = [red, Brush::default(), Brush::default(), Brush::default()];
+    let size = LogicalSize::new(22 as Coord, 10 as Coord);
+    let rects =
+        edges.segments(size).into_iter().map(|(rect, _)| rect).collect::<alloc::vec::Vec<_>>();
+    // Dashes of 6 with gaps of 4, the last one cut off
+    assert_eq!(
+        rects,
+        [
+            euclid::rect(0 as Coord, 0 as Coord, 6 as Coord, 2 as Coord),
+            euclid::rect(10 as Coord, 0 as Coord, 6 as Coord, 2 as Coord),
+            euclid::rect(20 as Coord, 0 as Coord, 2 as Coord, 2 as Coord),
+        ]
+    );
+}
+
+/// Returns whether a renderer must round the geometry of an item with the given `policy` to device
+/// pixels, see [`snap_to_device_pixels()`].
+///
+/// This is only the case with fractional scale factors, as the edges already fall on device pixels
+/// otherwise. Building with `--cfg slint_no_pixel_snapping` disables the snapping entirely.
+pub fn pixel_snapping_enabled(
+    policy: PixelSnapping,
+    window: &crate::window::WindowInner,
+    scale_factor: f32,
+) -> bool {
+    if cfg!(slint_no_pixel_snapping) || scale_factor.fract() == 0. {
+        return false;
+    }
+    match policy {
+        PixelSnapping::Always => true,
+        PixelSnapping::Never => false,
+        PixelSnapping::Auto => window.pixel_snapping(),
+    }
+}
+
+/// Rounds the edges of `rect` and its `border_width`, in physical pixels, to device pixels.
+/// `device_origin` is the position of the origin of the coordinate system of `rect` on the device.
+/// A border that isn't empty stays at least one device pixel wide.
+pub fn snap_to_device_pixels<U>(
+    rect: euclid::Rect<f32, U>,
+    border_width: f32,
+    device_origin: euclid::Vector2D<f32, U>,
+) -> (euclid::Rect<f32, U>, f32) {
+    let rect = rect.translate(device_origin).to_box2d().round().to_rect().translate(-device_origin);
+    let border_width = if border_width > 0. { border_width.round().max(1.) } else { 0. };
+    (rect, border_width)
+}
+
+#[test]
+fn snap_to_device_pixels_rounds_edges() {
+    let rect = euclid::rect::<f32, crate::lengths::PhysicalPx>(1.25, 2.5, 10., 3.75);
+    let (snapped, border) = snap_to_device_pixels(rect, 1.25, euclid::vec2(0.5, 0.));
+    assert_eq!(snapped, euclid::rect(1.5, 3., 10., 3.));
+    assert_eq!(border, 1.);
+    assert_eq!(snap_to_device_pixels(rect, 0.25, euclid::vec2(0., 0.)).1, 1.);
+    assert_eq!(snap_to_device_pixels(rect, 0., euclid::vec2(0., 0.)).1, 0.);
 }
 
 /// Trait for an item that represents an Image towards the renderer
@@ -299,6 +648,11 @@ pub trait RenderText {
     fn letter_spacing(self: Pin<&Self>) -> LogicalLength;
     fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle);
     fn is_markdown(self: Pin<&Self>) -> bool;
+    /// The byte range of the selected text, and the color of its background.
+    /// This is only set for the selectable `Text` and `MarkdownText` elements.
+    fn selection(self: Pin<&Self>) -> Option<(core::ops::Range<usize>, crate::Color)> {
+        None
+    }
 }
 
 impl RenderText for (SharedString, Brush) {
@@ -418,6 +772,56 @@ fn visit_layer(
         // Not supported
         RenderingResult::ContinueRenderingChildren
     }
+    /// Blur what was rendered so far below the BackdropBlur element, within its geometry.
+    /// Renderers that can't read back their content leave it unaltered.
+    fn visit_backdrop_blur(
+        &mut self,
+        _backdrop_blur_item: Pin<&BackdropBlur>,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+    /// Render the children of the Mask element multiplied by the alpha channel of its mask image,
+    /// stretched to the geometry of the element. Renderers that can't composite layers
+    /// approximate the mask by clipping the children to the geometry of the element.
+    fn visit_mask(
+        &mut self,
+        _mask_item: Pin<&Mask>,
+        _self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        let clip_region_valid = self.combine_clip(
+            LogicalRect::new(LogicalPoint::default(), size),
+            Default::default(),
+            Default::default(),
+        );
+        if clip_region_valid {
+            RenderingResult::ContinueRenderingChildren
+        } else {
+            RenderingResult::ContinueRenderingWithoutChildren
+        }
+    }
+    /// Render the children of the Blend element into a layer that is blended with the content
+    /// below it with its blend mode. Renderers that can't blend layers draw the children normally.
+    fn visit_blend(
+        &mut self,
+        _blend_item: Pin<&Blend>,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+    /// Render the children of the ShaderEffect element through its shader.
+    /// Renderers that don't support shaders render the children unaltered.
+    fn visit_shader_effect(
+        &mut self,
+        _shader_effect_item: Pin<&ShaderEffect>,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
 
     // Apply the bounds of the Clip element, if enabled. The default implementation calls
     // combine_clip, but the render may choose an alternate way of implementing the clip.
@@ -102,6 +102,15 @@ pub struct ItemTreeVTable {
     pub layout_info:
         extern "C" fn(::core::pin::Pin<VRef<ItemTreeVTable>>, Orientation) -> LayoutInfo,
 
+    /// Returns the layout info computed by the layout at `item_index` via the `result`.
+    /// Returns false if the item is not a layout.
+    pub item_layout_info: extern "C" fn(
+        ::core::pin::Pin<VRef<ItemTreeVTable>>,
+        item_index: u32,
+        orientation: Orientation,
+        result: &mut LayoutInfo,
+    ) -> bool,
+
     /// Returns the item's geometry (relative to its parent item)
     pub item_geometry:
         extern "C" fn(::core::pin::Pin<VRef<ItemTreeVTable>>, item_index: u32) -> LogicalRect,
@@ -359,6 +368,19 @@ pub fn is_visible(&self) -> bool {
             && clip.min.y <= geometry.max.y
     }
 
+    /// Returns true if any part of this item lies within the visible area of its window, taking
+    /// clipping ancestors such as a `Flickable` into account.
+    pub fn is_visible_in_viewport(&self) -> bool {
+        let (clip, geometry) = self.absolute_clip_rect_and_geometry();
+        let mut root = self.clone();
+        while let Some(parent) = root.parent_item(ParentItemTraversalMode::StopAtPopups) {
+            root = parent;
+        }
+        LogicalRect::from_size(root.geometry().size)
+            .intersection(&clip)
+            .is_some_and(|viewport| viewport.intersects(&geometry))
+    }
+
     /// Returns the clip rect that applies to this item (in window coordinates) as well as the
     /// item's (unclipped) geometry (also in window coordinates).
     fn absolute_clip_rect_and_geometry(&self) -> (LogicalRect, LogicalRect) {
@@ -435,6 +457,16 @@ pub fn element_type_names_and_ids(
         &self,
         element_index: usize,
     ) -> Option<Vec<(SharedString, SharedString)>> {
+        self.element_type_names_ids_and_locations(element_index)
+            .map(|infos| infos.into_iter().map(|(type_name, id, _)| (type_name, id)).collect())
+    }
+
+    /// Like [`Self::element_type_names_and_ids`], but also returns the location of each element
+    /// in the `.slint` source, as `file:line:column`, or an empty string if it is not known.
+    pub fn element_type_names_ids_and_locations(
+        &self,
+        element_index: usize,
+    ) -> Option<Vec<(SharedString, SharedString, SharedString)>> {
         let comp_ref_pin = vtable::VRc::borrow_pin(&self.item_tree);
         let mut result = SharedString::new();
         comp_ref_pin.as_ref().item_element_infos(self.index, &mut result).then(|| {
@@ -448,7 +480,8 @@ pub fn element_type_names_and_ids(
                     let mut decoder = encoded_elem_info.split(',');
                     let type_name = decoder.next().unwrap().into();
                     let id = decoder.next().map(Into::into).unwrap_or_default();
-                    (type_name, id)
+                    let location = decoder.next().map(Into::into).unwrap_or_default();
+                    (type_name, id, location)
                 })
                 .collect()
         })
@@ -459,6 +492,17 @@ pub fn geometry(&self) -> LogicalRect {
         comp_ref_pin.as_ref().item_geometry(self.index)
     }
 
+    /// Returns the layout info computed by the layout that this item is the result of,
+    /// or None if the item is not a layout.
+    pub fn layout_info(&self, orientation: Orientation) -> Option<LayoutInfo> {
+        let comp_ref_pin = vtable::VRc::borrow_pin(&self.item_tree);
+        let mut result = LayoutInfo::default();
+        comp_ref_pin
+            .as_ref()
+            .item_layout_info(self.index, orientation, &mut result)
+            .then_some(result)
+    }
+
     pub fn bounding_rect(
         &self,
         geometry: &LogicalRect,
@@ -1230,11 +1274,25 @@ pub fn visit_item_tree<Base>(
     } else {
         match &item_tree_array[index as usize] {
             ItemTreeNode::Item { children_index, children_count, .. } => {
+                let sorted =
+                    sort_by_z_order(*children_index..*children_index + *children_count, |idx| {
+                        match &item_tree_array[idx as usize] {
+                            ItemTreeNode::Item { .. } => {
+                                let item_tree = VRc::borrow_pin(item_tree);
+                                ItemRef::downcast_pin::<crate::items::ZOrder>(
+                                    item_tree.as_ref().get_item_ref(idx),
+                                )
+                                .map(|z_order| z_order.z())
+                            }
+                            ItemTreeNode::DynamicTree { .. } => None,
+                        }
+                    });
                 for c in 0..*children_count {
-                    let idx = match order {
-                        TraversalOrder::BackToFront => *children_index + c,
-                        TraversalOrder::FrontToBack => *children_index + *children_count - c - 1,
+                    let c = match order {
+                        TraversalOrder::BackToFront => c,
+                        TraversalOrder::FrontToBack => *children_count - c - 1,
                     };
+                    let idx = sorted.as_ref().map_or(*children_index + c, |s| s[c as usize]);
                     let maybe_abort_index = visit_at_index(idx);
                     if maybe_abort_index.has_aborted() {
                         return maybe_abort_index;
@@ -1247,6 +1305,30 @@ pub fn visit_item_tree<Base>(
     }
 }
 
+/// Returns the `indices` sorted by the `z` property of the ZOrder items they refer to, or None if
+/// none of them is a ZOrder item. The sort is stable and the other indices have a `z` of 0.
+pub(crate) fn sort_by_z_order(
+    indices: impl Iterator<Item = u32> + Clone,
+    z_of: impl Fn(u32) -> Option<f32>,
+) -> Option<Vec<u32>> {
+    let mut with_z: Vec<(f32, u32)> = Vec::new();
+    for (pos, idx) in indices.clone().enumerate() {
+        if let Some(z) = z_of(idx) {
+            if with_z.is_empty() {
+                with_z.extend(indices.clone().take(pos).map(|idx| (0., idx)));
+            }
+            with_z.push((z, idx));
+        } else if !with_z.is_empty() {
+            with_z.push((0., idx));
+        }
+    }
+    if with_z.is_empty() {
+        return None;
+    }
+    with_z.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    Some(with_z.into_iter().map(|(_, idx)| idx).collect())
+}
+
 #[cfg(feature = "ffi")]
 pub(crate) mod ffi {
     #![allow(unsafe_code)]
@@ -1361,6 +1443,15 @@ fn layout_info(self: core::pin::Pin<&Self>, _1: Orientation) -> LayoutInfo {
             unimplemented!("Not needed for this test")
         }
 
+        fn item_layout_info(
+            self: core::pin::Pin<&Self>,
+            _: u32,
+            _: Orientation,
+            _: &mut LayoutInfo,
+        ) -> bool {
+            false
+        }
+
         fn subtree_index(self: core::pin::Pin<&Self>) -> usize {
             self.subtree_index
         }
@@ -26,7 +26,9 @@
     FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEventResult,
     KeyEventType, MouseEvent,
 };
-use crate::item_rendering::{CachedRenderingData, RenderBorderRectangle, RenderRectangle};
+use crate::item_rendering::{
+    BorderEdges, CachedRenderingData, RenderBorderRectangle, RenderRectangle,
+};
 use crate::item_tree::ItemTreeRc;
 pub use crate::item_tree::{ItemRc, ItemTreeVTable};
 use crate::layout::LayoutInfo;
@@ -59,6 +61,8 @@
 pub use self::image::*;
 mod drag_n_drop;
 pub use drag_n_drop::*;
+mod screen_color_picker;
+pub use screen_color_picker::*;
 #[cfg(feature = "std")]
 mod path;
 #[cfg(feature = "std")]
@@ -76,6 +80,7 @@
 type PointerScrollEventArg = (PointerScrollEvent,);
 type PointArg = (LogicalPosition,);
 type MenuEntryArg = (MenuEntry,);
+type ColorArg = (Color,);
 type MenuEntryModel = crate::model::ModelRc<MenuEntry>;
 
 #[cfg(all(feature = "ffi", windows))]
@@ -541,6 +546,16 @@ pub struct BorderRectangle {
     pub border_bottom_left_radius: Property<LogicalLength>,
     pub border_bottom_right_radius: Property<LogicalLength>,
     pub border_color: Property<Brush>,
+    pub border_top_width: Property<LogicalLength>,
+    pub border_right_width: Property<LogicalLength>,
+    pub border_bottom_width: Property<LogicalLength>,
+    pub border_left_width: Property<LogicalLength>,
+    pub border_top_color: Property<Brush>,
+    pub border_right_color: Property<Brush>,
+    pub border_bottom_color: Property<Brush>,
+    pub border_left_color: Property<Brush>,
+    pub border_style: Property<BorderStyle>,
+    pub pixel_snapping: Property<PixelSnapping>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -607,7 +622,19 @@ fn render(
         self_rc: &ItemRc,
         size: LogicalSize,
     ) -> RenderingResult {
-        (*backend).draw_border_rectangle(self, self_rc, size, &self.cached_rendering_data);
+        match self.border_edges() {
+            Some(edges) => crate::item_rendering::draw_border_edges(
+                &mut **backend,
+                self,
+                self_rc,
+                size,
+                &edges,
+                &self.cached_rendering_data,
+            ),
+            None => {
+                (*backend).draw_border_rectangle(self, self_rc, size, &self.cached_rendering_data)
+            }
+        }
         RenderingResult::ContinueRenderingChildren
     }
 
@@ -643,6 +670,46 @@ fn border_radius(self: Pin<&Self>) -> LogicalBorderRadius {
     fn border_color(self: Pin<&Self>) -> Brush {
         self.border_color()
     }
+    fn pixel_snapping(self: Pin<&Self>) -> PixelSnapping {
+        self.pixel_snapping()
+    }
+}
+
+impl BorderRectangle {
+    /// Returns the edges of the border if it must be drawn with [`draw_border_edges()`](crate::item_rendering::draw_border_edges),
+    /// because it's dashed or dotted, or because its edges differ.
+    fn border_edges(self: Pin<&Self>) -> Option<BorderEdges> {
+        let style = self.border_style();
+        let mut edges = BorderEdges {
+            widths: [
+                self.border_top_width(),
+                self.border_right_width(),
+                self.border_bottom_width(),
+                self.border_left_width(),
+            ],
+            colors: [
+                self.border_top_color(),
+                self.border_right_color(),
+                self.border_bottom_color(),
+                self.border_left_color(),
+            ],
+            style,
+        };
+        // The compiler sets the edges that aren't set to border-width and border-color as soon as
+        // one of them is set, so they're only all zero and transparent when none are used.
+        let edges_used = edges.widths.iter().any(|w| *w > LogicalLength::default())
+            || edges.colors.iter().any(|c| !c.is_transparent());
+        if !edges_used {
+            if style == BorderStyle::Solid {
+                return None;
+            }
+            edges.widths = [self.border_width(); 4];
+            edges.colors = core::array::from_fn(|_| self.border_color());
+        }
+        let uniform = edges.widths.iter().all(|w| *w == self.border_width())
+            && edges.colors.iter().all(|c| *c == self.border_color());
+        (style != BorderStyle::Solid || !uniform).then_some(edges)
+    }
 }
 
 impl ItemConsts for BorderRectangle {
@@ -668,6 +735,10 @@ fn slint_get_FocusScopeVTable() -> FocusScopeVTable for FocusScope
     fn slint_get_SwipeGestureHandlerVTable() -> SwipeGestureHandlerVTable for SwipeGestureHandler
 }
 
+declare_item_vtable! {
+    fn slint_get_PinchGestureHandlerVTable() -> PinchGestureHandlerVTable for PinchGestureHandler
+}
+
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
 #[pin]
@@ -1021,6 +1092,513 @@ impl ItemConsts for Layer {
     fn slint_get_LayerVTable() -> LayerVTable for Layer
 }
 
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+/// The ZOrder Item is not meant to be used directly by the .slint code, instead, the `z: xxx` property
+/// should be used when its value is not a constant. The children of an item are visited sorted by the
+/// `z` of the ZOrder items among them.
+pub struct ZOrder {
+    pub z: Property<f32>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for ZOrder {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: &MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: &MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn capture_key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+
+    fn bounding_rect(
+        self: core::pin::Pin<&Self>,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+        geometry: LogicalRect,
+    ) -> LogicalRect {
+        geometry
+    }
+
+    fn clips_children(self: core::pin::Pin<&Self>) -> bool {
+        false
+    }
+}
+
+impl ItemConsts for ZOrder {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        ZOrder,
+        CachedRenderingData,
+    > = ZOrder::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+    fn slint_get_ZOrderVTable() -> ZOrderVTable for ZOrder
+}
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+/// The BackdropBlur Item is not meant to be used directly by the .slint code, instead, the `backdrop-blur: xxx` property should be used
+pub struct BackdropBlur {
+    pub backdrop_blur: Property<LogicalLength>,
+    pub border_radius: Property<LogicalLength>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for BackdropBlur {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: &MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: &MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn capture_key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        backend: &mut ItemRendererRef,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        backend.visit_backdrop_blur(self, self_rc, size)
+    }
+
+    fn bounding_rect(
+        self: core::pin::Pin<&Self>,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+        geometry: LogicalRect,
+    ) -> LogicalRect {
+        geometry
+    }
+
+    fn clips_children(self: core::pin::Pin<&Self>) -> bool {
+        false
+    }
+}
+
+impl ItemConsts for BackdropBlur {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        BackdropBlur,
+        CachedRenderingData,
+    > = BackdropBlur::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+    fn slint_get_BackdropBlurVTable() -> BackdropBlurVTable for BackdropBlur
+}
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+/// The Mask Item is not meant to be used directly by the .slint code, instead, the `mask-image: xxx` property should be used
+pub struct Mask {
+    pub mask_image: Property<Image>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for Mask {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: &MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: &MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn capture_key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        backend: &mut ItemRendererRef,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        backend.visit_mask(self, self_rc, size)
+    }
+
+    fn bounding_rect(
+        self: core::pin::Pin<&Self>,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+        geometry: LogicalRect,
+    ) -> LogicalRect {
+        geometry
+    }
+
+    fn clips_children(self: core::pin::Pin<&Self>) -> bool {
+        true
+    }
+}
+
+impl ItemConsts for Mask {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Mask, CachedRenderingData> =
+        Mask::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+    fn slint_get_MaskVTable() -> MaskVTable for Mask
+}
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+/// The Blend Item is not meant to be used directly by the .slint code, instead, the `mix-blend-mode: xxx` property should be used
+pub struct Blend {
+    pub mix_blend_mode: Property<MixBlendMode>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for Blend {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: &MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: &MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn capture_key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        backend: &mut ItemRendererRef,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        backend.visit_blend(self, self_rc, size)
+    }
+
+    fn bounding_rect(
+        self: core::pin::Pin<&Self>,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+        geometry: LogicalRect,
+    ) -> LogicalRect {
+        geometry
+    }
+
+    fn clips_children(self: core::pin::Pin<&Self>) -> bool {
+        false
+    }
+}
+
+impl ItemConsts for Blend {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        Blend,
+        CachedRenderingData,
+    > = Blend::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+    fn slint_get_BlendVTable() -> BlendVTable for Blend
+}
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+/// The implementation of the `ShaderEffect` element, which renders its children through a user provided fragment shader
+pub struct ShaderEffect {
+    pub shader: Property<SharedString>,
+    pub time: Property<f32>,
+    pub param1: Property<f32>,
+    pub param2: Property<f32>,
+    pub param3: Property<f32>,
+    pub param4: Property<f32>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for ShaderEffect {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: &MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: &MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn capture_key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        backend: &mut ItemRendererRef,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        backend.visit_shader_effect(self, self_rc, size)
+    }
+
+    fn bounding_rect(
+        self: core::pin::Pin<&Self>,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+        geometry: LogicalRect,
+    ) -> LogicalRect {
+        geometry
+    }
+
+    fn clips_children(self: core::pin::Pin<&Self>) -> bool {
+        // The shader output is limited to the geometry of the item
+        true
+    }
+}
+
+impl ItemConsts for ShaderEffect {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        ShaderEffect,
+        CachedRenderingData,
+    > = ShaderEffect::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+declare_item_vtable! {
+    fn slint_get_ShaderEffectVTable() -> ShaderEffectVTable for ShaderEffect
+}
+
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
 #[pin]
@@ -1142,6 +1720,10 @@ fn slint_get_DragAreaVTable() -> DragAreaVTable for DragArea
     fn slint_get_DropAreaVTable() -> DropAreaVTable for DropArea
 }
 
+declare_item_vtable! {
+    fn slint_get_ScreenColorPickerVTable() -> ScreenColorPickerVTable for ScreenColorPicker
+}
+
 /// The implementation of the `PropertyAnimation` element
 #[repr(C)]
 #[derive(FieldOffsets, SlintElement, Clone, Debug)]
@@ -1825,3 +2407,13 @@ pub struct $Name {
     let self_rc = ItemRc::new(self_component.clone(), self_index);
     self_rc.map_to_window(Default::default())
 }
+
+#[cfg(feature = "ffi")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn slint_item_visible_in_viewport(
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+) -> bool {
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    self_rc.is_visible_in_viewport()
+}
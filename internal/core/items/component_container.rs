@@ -119,12 +119,24 @@ pub fn ensure_updated(self: Pin<&Self>) {
         self.item_tree.replace(product);
     }
 
+    /// Returns the embedded item tree, unless it was moved into another container since.
+    fn embedded_item_tree(self: Pin<&Self>) -> Option<ItemTreeRc> {
+        let item_tree = self.item_tree.borrow().clone()?;
+        let mut parent_node = ItemWeak::default();
+        vtable::VRc::borrow_pin(&item_tree).as_ref().parent_node(&mut parent_node);
+        let embedding_node = ItemRc::new(
+            self.my_component.get()?.upgrade()?,
+            *self.embedding_item_tree_index.get()?,
+        );
+        (parent_node == embedding_node.downgrade()).then_some(item_tree)
+    }
+
     pub fn subtree_range(self: Pin<&Self>) -> IndexRange {
-        IndexRange { start: 0, end: if self.item_tree.borrow().is_some() { 1 } else { 0 } }
+        IndexRange { start: 0, end: if self.embedded_item_tree().is_some() { 1 } else { 0 } }
     }
 
     pub fn subtree_component(self: Pin<&Self>) -> ItemTreeWeak {
-        self.item_tree.borrow().as_ref().map_or(ItemTreeWeak::default(), vtable::VRc::downgrade)
+        self.embedded_item_tree().as_ref().map_or(ItemTreeWeak::default(), vtable::VRc::downgrade)
     }
 
     pub fn visit_children_item(
@@ -133,7 +145,7 @@ pub fn visit_children_item(
         order: TraversalOrder,
         visitor: vtable::VRefMut<ItemVisitorVTable>,
     ) -> VisitChildrenResult {
-        let rc = self.item_tree.borrow().clone();
+        let rc = self.embedded_item_tree();
         if let Some(rc) = &rc {
             vtable::VRc::borrow_pin(rc).as_ref().visit_children_item(-1, order, visitor)
         } else {
@@ -173,7 +185,7 @@ fn layout_info(
         _self_rc: &ItemRc,
     ) -> LayoutInfo {
         self.ensure_updated();
-        if let Some(rc) = self.item_tree.borrow().clone() {
+        if let Some(rc) = self.embedded_item_tree() {
             vtable::VRc::borrow_pin(&rc).as_ref().layout_info(orientation)
         } else {
             Default::default()
@@ -251,9 +263,7 @@ fn clips_children(self: core::pin::Pin<&Self>) -> bool {
 
 impl RenderRectangle for ComponentContainer {
     fn background(self: Pin<&Self>) -> crate::Brush {
-        self.item_tree
-            .borrow()
-            .clone()
+        self.embedded_item_tree()
             .and_then(|item_tree| {
                 let item_tree = vtable::VRc::borrow_pin(&item_tree);
                 let root_item = item_tree.as_ref().get_item_ref(0);
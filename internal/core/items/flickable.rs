@@ -405,7 +405,7 @@ fn handle_mouse_filter(
             MouseEvent::Pressed { .. } | MouseEvent::Released { .. } => {
                 InputEventFilterResult::ForwardAndIgnore
             }
-            MouseEvent::DragMove(..) | MouseEvent::Drop(..) => {
+            MouseEvent::DragMove(..) | MouseEvent::Drop(..) | MouseEvent::Gesture { .. } => {
                 InputEventFilterResult::ForwardAndIgnore
             }
         }
@@ -532,7 +532,9 @@ fn handle_mouse(
                 }
                 InputEventResult::EventAccepted
             }
-            MouseEvent::DragMove(..) | MouseEvent::Drop(..) => InputEventResult::EventIgnored,
+            MouseEvent::DragMove(..) | MouseEvent::Drop(..) | MouseEvent::Gesture { .. } => {
+                InputEventResult::EventIgnored
+            }
         }
     }
 
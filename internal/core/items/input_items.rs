@@ -8,8 +8,8 @@
 };
 use crate::api::LogicalPosition;
 use crate::input::{
-    FocusEvent, FocusEventResult, FocusReason, InputEventFilterResult, InputEventResult, KeyEvent,
-    KeyEventResult, KeyEventType, MouseEvent,
+    FocusEvent, FocusEventResult, FocusReason, GestureEvent, InputEventFilterResult,
+    InputEventResult, KeyEvent, KeyEventResult, KeyEventType, MouseEvent,
 };
 use crate::item_rendering::CachedRenderingData;
 use crate::layout::{LayoutInfo, Orientation};
@@ -206,7 +206,9 @@ fn input_event(
                     }
                 }
             }
-            MouseEvent::DragMove(..) | MouseEvent::Drop(..) => InputEventResult::EventIgnored,
+            MouseEvent::DragMove(..) | MouseEvent::Drop(..) | MouseEvent::Gesture { .. } => {
+                InputEventResult::EventIgnored
+            }
         }
     }
 
@@ -532,7 +534,7 @@ fn input_event_filter_before_children(
             MouseEvent::Pressed { .. } | MouseEvent::Released { .. } => {
                 InputEventFilterResult::ForwardAndIgnore
             }
-            MouseEvent::DragMove(..) | MouseEvent::Drop(..) => {
+            MouseEvent::DragMove(..) | MouseEvent::Drop(..) | MouseEvent::Gesture { .. } => {
                 InputEventFilterResult::ForwardAndIgnore
             }
         }
@@ -582,7 +584,9 @@ fn input_event(
                 }
             }
             MouseEvent::Wheel { .. } => InputEventResult::EventIgnored,
-            MouseEvent::DragMove(..) | MouseEvent::Drop(..) => InputEventResult::EventIgnored,
+            MouseEvent::DragMove(..) | MouseEvent::Drop(..) | MouseEvent::Gesture { .. } => {
+                InputEventResult::EventIgnored
+            }
         }
     }
 
@@ -682,3 +686,166 @@ fn is_over_threshold(self: Pin<&Self>, position: &LogicalPoint) -> bool {
     let self_rc = ItemRc::new(self_component.clone(), self_index);
     s.cancel(window_adapter, &self_rc);
 }
+
+/// The implementation of the `PinchGestureHandler` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct PinchGestureHandler {
+    pub enabled: Property<bool>,
+
+    pub active: Property<bool>,
+    pub scale: Property<f32>,
+    pub rotation: Property<f32>,
+
+    pub started: Callback<VoidArg>,
+    pub updated: Callback<VoidArg>,
+    pub ended: Callback<VoidArg>,
+    pub cancelled: Callback<VoidArg>,
+
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for PinchGestureHandler {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: &MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            self.cancel_impl();
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+        // Let the children handle the gesture first, so that nested handlers get a chance
+        if matches!(event, MouseEvent::Gesture { .. }) {
+            InputEventFilterResult::ForwardEvent
+        } else {
+            InputEventFilterResult::ForwardAndIgnore
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: &MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        match event {
+            MouseEvent::Gesture { gesture: GestureEvent::PinchBegin, .. } => {
+                self.cancel_impl();
+                Self::FIELD_OFFSETS.scale.apply_pin(self).set(1.);
+                Self::FIELD_OFFSETS.rotation.apply_pin(self).set(0.);
+                Self::FIELD_OFFSETS.active.apply_pin(self).set(true);
+                Self::FIELD_OFFSETS.started.apply_pin(self).call(&());
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Gesture {
+                gesture: GestureEvent::PinchUpdate { scale, rotation }, ..
+            } => {
+                if !self.active() {
+                    return InputEventResult::EventIgnored;
+                }
+                Self::FIELD_OFFSETS.scale.apply_pin(self).set(*scale);
+                Self::FIELD_OFFSETS.rotation.apply_pin(self).set(*rotation);
+                Self::FIELD_OFFSETS.updated.apply_pin(self).call(&());
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Gesture { gesture: GestureEvent::PinchEnd { cancelled }, .. } => {
+                if !self.active() {
+                    return InputEventResult::EventIgnored;
+                }
+                if *cancelled {
+                    self.cancel_impl();
+                } else {
+                    Self::FIELD_OFFSETS.active.apply_pin(self).set(false);
+                    Self::FIELD_OFFSETS.ended.apply_pin(self).call(&());
+                }
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Exit => {
+                self.cancel_impl();
+                InputEventResult::EventIgnored
+            }
+            // Keep the grab if the pointer moves during the pinch
+            _ if self.active() => InputEventResult::GrabMouse,
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn capture_key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _event: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+
+    fn bounding_rect(
+        self: core::pin::Pin<&Self>,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+        mut geometry: LogicalRect,
+    ) -> LogicalRect {
+        geometry.size = LogicalSize::zero();
+        geometry
+    }
+
+    fn clips_children(self: core::pin::Pin<&Self>) -> bool {
+        false
+    }
+}
+
+impl ItemConsts for PinchGestureHandler {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl PinchGestureHandler {
+    fn cancel_impl(self: Pin<&Self>) {
+        if self.active() {
+            Self::FIELD_OFFSETS.active.apply_pin(self).set(false);
+            Self::FIELD_OFFSETS.cancelled.apply_pin(self).call(&());
+        }
+    }
+}
@@ -8,7 +8,9 @@
 Lookup the [`crate::items`] module documentation.
 */
 
-use super::{FillRule, Item, ItemConsts, ItemRc, ItemRendererRef, LineCap, RenderingResult};
+use super::{
+    FillRule, Item, ItemConsts, ItemRc, ItemRendererRef, LineCap, LineJoin, RenderingResult,
+};
 use crate::graphics::{Brush, PathData, PathDataIterator};
 use crate::input::{
     FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
@@ -41,6 +43,9 @@ pub struct Path {
     pub stroke: Property<Brush>,
     pub stroke_width: Property<LogicalLength>,
     pub stroke_line_cap: Property<LineCap>,
+    pub stroke_line_join: Property<LineJoin>,
+    pub stroke_dash_length: Property<LogicalLength>,
+    pub stroke_dash_gap: Property<LogicalLength>,
     pub viewbox_x: Property<f32>,
     pub viewbox_y: Property<f32>,
     pub viewbox_width: Property<f32>,
@@ -175,6 +180,13 @@ pub fn fitted_path_events(
         elements_iter.fit(bounds_width.get() as _, bounds_height.get() as _, maybe_viewbox);
         (offset, elements_iter).into()
     }
+
+    /// Returns the length of the dashes and of the gaps between them if the stroke is dashed.
+    pub fn stroke_dash_pattern(self: Pin<&Self>) -> Option<(LogicalLength, LogicalLength)> {
+        let dash = self.stroke_dash_length();
+        let gap = self.stroke_dash_gap();
+        (dash > LogicalLength::zero() && gap > LogicalLength::zero()).then_some((dash, gap))
+    }
 }
 
 impl ItemConsts for Path {
@@ -0,0 +1,164 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use super::{ColorArg, Item, ItemConsts, ItemRc, RenderingResult, VoidArg};
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+    KeyEventResult, MouseEvent,
+};
+use crate::item_rendering::{CachedRenderingData, ItemRenderer};
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::{LogicalRect, LogicalSize};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::{WindowAdapter, WindowInner};
+use crate::{Callback, Property};
+use alloc::rc::Rc;
+use const_field_offset::FieldOffsets;
+use core::pin::Pin;
+use i_slint_core_macros::*;
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+/// The implementation of the internal `ScreenColorPicker` element, used by the eyedropper of the
+/// `ColorPicker` widget to pick a color with [`Platform::pick_screen_color()`](crate::platform::Platform::pick_screen_color).
+pub struct ScreenColorPicker {
+    pub picking: Property<bool>,
+    pub picked: Callback<ColorArg>,
+    pub failed: Callback<VoidArg>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for ScreenColorPicker {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: &MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: &MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn capture_key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _: &mut &mut dyn ItemRenderer,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+
+    fn bounding_rect(
+        self: core::pin::Pin<&Self>,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+        mut geometry: LogicalRect,
+    ) -> LogicalRect {
+        geometry.size = LogicalSize::zero();
+        geometry
+    }
+
+    fn clips_children(self: core::pin::Pin<&Self>) -> bool {
+        false
+    }
+}
+
+impl ItemConsts for ScreenColorPicker {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        ScreenColorPicker,
+        CachedRenderingData,
+    > = ScreenColorPicker::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl ScreenColorPicker {
+    /// Lets the user pick a color on the screen. `picked` is invoked with the color, nothing is
+    /// invoked if the user cancels, and `failed` is invoked if the platform can't pick colors.
+    pub fn pick(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
+        if self.picking() {
+            return;
+        }
+        let ctx = WindowInner::from_pub(window_adapter.window()).context().clone();
+        let picker = ctx.platform().pick_screen_color();
+        let self_weak = self_rc.downgrade();
+        let result = ctx.spawn_local(async move {
+            let color = picker.await;
+            let Some(picker) = self_weak.upgrade() else { return };
+            let Some(picker) = picker.downcast::<ScreenColorPicker>() else { return };
+            let picker = picker.as_pin_ref();
+            picker.picking.set(false);
+            match color {
+                Ok(Some(color)) => Self::FIELD_OFFSETS.picked.apply_pin(picker).call(&(color,)),
+                Ok(None) => {}
+                Err(err) => {
+                    crate::debug_log!("Picking a color from the screen failed: {err}");
+                    Self::FIELD_OFFSETS.failed.apply_pin(picker).call(&());
+                }
+            }
+        });
+        match result {
+            Ok(_) => self.picking.set(true),
+            Err(_) => Self::FIELD_OFFSETS.failed.apply_pin(self).call(&()),
+        }
+    }
+}
+
+#[cfg(feature = "ffi")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn slint_screencolorpicker_pick(
+    s: Pin<&ScreenColorPicker>,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    s.pick(window_adapter, &self_rc);
+}
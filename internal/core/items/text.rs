@@ -58,6 +58,9 @@ pub struct ComplexText {
     pub stroke: Property<Brush>,
     pub stroke_width: Property<LogicalLength>,
     pub stroke_style: Property<TextStrokeStyle>,
+    pub selectable: Property<bool>,
+    pub selection_background_color: Property<Color>,
+    selection_range: Property<TextSelectionRange>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -90,11 +93,11 @@ fn input_event_filter_before_children(
 
     fn input_event(
         self: Pin<&Self>,
-        _: &MouseEvent,
-        _window_adapter: &Rc<dyn WindowAdapter>,
-        _self_rc: &ItemRc,
+        event: &MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
     ) -> InputEventResult {
-        InputEventResult::EventIgnored
+        selectable_text_input_event(self, event, window_adapter, self_rc)
     }
 
     fn capture_key_event(
@@ -108,20 +111,20 @@ fn capture_key_event(
 
     fn key_event(
         self: Pin<&Self>,
-        _: &KeyEvent,
-        _window_adapter: &Rc<dyn WindowAdapter>,
-        _self_rc: &ItemRc,
+        event: &KeyEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
     ) -> KeyEventResult {
-        KeyEventResult::EventIgnored
+        selectable_text_key_event(self, event, window_adapter, self_rc)
     }
 
     fn focus_event(
         self: Pin<&Self>,
-        _: &FocusEvent,
-        _window_adapter: &Rc<dyn WindowAdapter>,
+        event: &FocusEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
         _self_rc: &ItemRc,
     ) -> FocusEventResult {
-        FocusEventResult::FocusIgnored
+        selectable_text_focus_event(self, event, window_adapter)
     }
 
     fn render(
@@ -204,6 +207,27 @@ fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle) {
     fn is_markdown(self: Pin<&Self>) -> bool {
         false
     }
+
+    fn selection(self: Pin<&Self>) -> Option<(core::ops::Range<usize>, Color)> {
+        let selection = self.selection_range();
+        (selection.start < selection.end).then(|| {
+            (selection.start as usize..selection.end as usize, self.selection_background_color())
+        })
+    }
+}
+
+impl SelectableText for ComplexText {
+    fn is_selectable(self: Pin<&Self>) -> bool {
+        self.selectable()
+    }
+
+    fn displayed_text(self: Pin<&Self>) -> SharedString {
+        self.text()
+    }
+
+    fn set_selection(self: Pin<&Self>, range: core::ops::Range<usize>) {
+        self.selection_range.set(range.into());
+    }
 }
 
 impl ComplexText {
@@ -241,6 +265,9 @@ pub struct MarkdownText {
     pub stroke: Property<Brush>,
     pub stroke_width: Property<LogicalLength>,
     pub stroke_style: Property<TextStrokeStyle>,
+    pub selectable: Property<bool>,
+    pub selection_background_color: Property<Color>,
+    selection_range: Property<TextSelectionRange>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -273,11 +300,11 @@ fn input_event_filter_before_children(
 
     fn input_event(
         self: Pin<&Self>,
-        _: &MouseEvent,
-        _window_adapter: &Rc<dyn WindowAdapter>,
-        _self_rc: &ItemRc,
+        event: &MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
     ) -> InputEventResult {
-        InputEventResult::EventIgnored
+        selectable_text_input_event(self, event, window_adapter, self_rc)
     }
 
     fn capture_key_event(
@@ -291,20 +318,20 @@ fn capture_key_event(
 
     fn key_event(
         self: Pin<&Self>,
-        _: &KeyEvent,
-        _window_adapter: &Rc<dyn WindowAdapter>,
-        _self_rc: &ItemRc,
+        event: &KeyEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
     ) -> KeyEventResult {
-        KeyEventResult::EventIgnored
+        selectable_text_key_event(self, event, window_adapter, self_rc)
     }
 
     fn focus_event(
         self: Pin<&Self>,
-        _: &FocusEvent,
-        _window_adapter: &Rc<dyn WindowAdapter>,
+        event: &FocusEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
         _self_rc: &ItemRc,
     ) -> FocusEventResult {
-        FocusEventResult::FocusIgnored
+        selectable_text_focus_event(self, event, window_adapter)
     }
 
     fn render(
@@ -387,6 +414,30 @@ fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle) {
     fn is_markdown(self: Pin<&Self>) -> bool {
         true
     }
+
+    fn selection(self: Pin<&Self>) -> Option<(core::ops::Range<usize>, Color)> {
+        let selection = self.selection_range();
+        (selection.start < selection.end).then(|| {
+            (selection.start as usize..selection.end as usize, self.selection_background_color())
+        })
+    }
+}
+
+impl SelectableText for MarkdownText {
+    fn is_selectable(self: Pin<&Self>) -> bool {
+        self.selectable()
+    }
+
+    fn displayed_text(self: Pin<&Self>) -> SharedString {
+        #[cfg(all(feature = "shared-parley", feature = "experimental-rich-text"))]
+        return crate::textlayout::sharedparley::rich_text_plain_text(&self.text());
+        #[cfg(not(all(feature = "shared-parley", feature = "experimental-rich-text")))]
+        self.text()
+    }
+
+    fn set_selection(self: Pin<&Self>, range: core::ops::Range<usize>) {
+        self.selection_range.set(range.into());
+    }
 }
 
 impl MarkdownText {
@@ -634,6 +685,204 @@ fn text_layout_info(
     }
 }
 
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq)]
+/// Similar as `core::ops::Range<u32>` but `repr(C)`
+///
+/// This is the selected range of a selectable `Text` or `MarkdownText`
+struct TextSelectionRange {
+    start: u32,
+    end: u32,
+}
+
+impl From<core::ops::Range<usize>> for TextSelectionRange {
+    fn from(value: core::ops::Range<usize>) -> Self {
+        Self { start: value.start as u32, end: value.end as u32 }
+    }
+}
+
+/// The `Text` and `MarkdownText` elements, whose text can be selected when `selectable` is true.
+/// The selection can span several elements and is handled by [`crate::text_selection`].
+trait SelectableText: RenderText {
+    fn is_selectable(self: Pin<&Self>) -> bool;
+    /// The text as it is displayed. The selection is a byte range in this text.
+    fn displayed_text(self: Pin<&Self>) -> SharedString;
+    fn set_selection(self: Pin<&Self>, range: core::ops::Range<usize>);
+}
+
+fn as_selectable_text(item: &ItemRc) -> Option<Pin<&dyn SelectableText>> {
+    let item_ref = item.borrow();
+    if let Some(text) = ItemRef::downcast_pin::<ComplexText>(item_ref) {
+        return Some(text);
+    }
+    ItemRef::downcast_pin::<MarkdownText>(item_ref).map(|text| text as Pin<&dyn SelectableText>)
+}
+
+/// Returns the displayed text of `item` if it is a `Text` or a `MarkdownText` with `selectable: true`
+pub(crate) fn selectable_text(item: &ItemRc) -> Option<SharedString> {
+    as_selectable_text(item).filter(|text| text.is_selectable()).map(|text| text.displayed_text())
+}
+
+/// Sets the range of the text of a selectable `Text` or `MarkdownText` that is highlighted as selected
+pub(crate) fn set_text_selection_range(item: &ItemRc, range: core::ops::Range<usize>) {
+    if let Some(text) = as_selectable_text(item) {
+        text.set_selection(range);
+    }
+}
+
+/// Returns the byte offset in the displayed text of a selectable `Text` or `MarkdownText` at `pos`,
+/// relative to the element
+pub(crate) fn selectable_text_byte_offset_for_position(
+    item: &ItemRc,
+    pos: LogicalPoint,
+    window_adapter: &Rc<dyn WindowAdapter>,
+) -> usize {
+    let Some(text) = as_selectable_text(item) else { return 0 };
+    let window_inner = WindowInner::from_pub(window_adapter.window());
+    let scale_factor = ScaleFactor::new(window_inner.scale_factor());
+    let byte_offset = window_adapter.renderer().text_byte_offset_for_position(
+        text,
+        item.geometry().size,
+        pos,
+        text.font_request(item),
+        scale_factor,
+    );
+    byte_offset.min(text.displayed_text().len())
+}
+
+fn copy_text_selection(window_adapter: &Rc<dyn WindowAdapter>, clipboard: Clipboard) {
+    let window_inner = WindowInner::from_pub(window_adapter.window());
+    let text = window_inner.text_selection.borrow().selected_text();
+    if !text.is_empty() {
+        window_inner.ctx.platform().set_clipboard_text(&text, clipboard);
+    }
+}
+
+fn selectable_text_input_event(
+    text: Pin<&dyn SelectableText>,
+    event: &MouseEvent,
+    window_adapter: &Rc<dyn WindowAdapter>,
+    self_rc: &ItemRc,
+) -> InputEventResult {
+    if !text.is_selectable() {
+        return InputEventResult::EventIgnored;
+    }
+    let window_inner = WindowInner::from_pub(window_adapter.window());
+    match event {
+        MouseEvent::Pressed { position, button: PointerEventButton::Left, click_count } => {
+            let byte_offset =
+                selectable_text_byte_offset_for_position(self_rc, *position, window_adapter);
+
+            // Keep the focus on another selectable element so that the selection isn't cleared
+            // when extending it to this element with shift
+            let focus_item = window_inner.focus_item.borrow().upgrade();
+            if focus_item.as_ref().is_none_or(|item| selectable_text(item).is_none()) {
+                window_inner.set_focus_item(self_rc, true, FocusReason::PointerClick);
+            }
+
+            let mut selection = window_inner.text_selection.borrow_mut();
+            if window_inner.modifiers.get().shift() && selection.is_active() {
+                selection.extend_to(self_rc, byte_offset);
+            } else {
+                let displayed_text = text.displayed_text();
+                match click_count % 3 {
+                    0 => selection.start(self_rc, byte_offset),
+                    1 => selection.select(
+                        self_rc,
+                        prev_word_boundary(&displayed_text, byte_offset)
+                            ..next_word_boundary(&displayed_text, byte_offset),
+                    ),
+                    _ => selection.select(self_rc, 0..displayed_text.len()),
+                }
+            }
+            selection.set_pressed(true);
+            InputEventResult::GrabMouse
+        }
+        MouseEvent::Released { button: PointerEventButton::Left, .. } => {
+            window_inner.text_selection.borrow_mut().set_pressed(false);
+            copy_text_selection(window_adapter, Clipboard::SelectionClipboard);
+            InputEventResult::EventAccepted
+        }
+        MouseEvent::Moved { position } => {
+            if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                x.set_mouse_cursor(super::MouseCursor::Text);
+            }
+            let mut selection = window_inner.text_selection.borrow_mut();
+            if selection.pressed() {
+                let position =
+                    self_rc.map_to_window(self_rc.geometry().origin + position.to_vector());
+                selection.extend_to_position(position, window_adapter);
+                return InputEventResult::GrabMouse;
+            }
+            InputEventResult::EventAccepted
+        }
+        MouseEvent::Exit => {
+            if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                x.set_mouse_cursor(super::MouseCursor::Default);
+            }
+            InputEventResult::EventAccepted
+        }
+        _ => InputEventResult::EventIgnored,
+    }
+}
+
+fn selectable_text_key_event(
+    text: Pin<&dyn SelectableText>,
+    event: &KeyEvent,
+    window_adapter: &Rc<dyn WindowAdapter>,
+    self_rc: &ItemRc,
+) -> KeyEventResult {
+    if !text.is_selectable() || event.event_type != KeyEventType::KeyPressed {
+        return KeyEventResult::EventIgnored;
+    }
+    let window_inner = WindowInner::from_pub(window_adapter.window());
+    match event.shortcut() {
+        Some(StandardShortcut::Copy) => {
+            copy_text_selection(window_adapter, Clipboard::DefaultClipboard);
+            return KeyEventResult::EventAccepted;
+        }
+        Some(StandardShortcut::SelectAll) => {
+            window_inner.text_selection.borrow_mut().select_all(self_rc);
+            return KeyEventResult::EventAccepted;
+        }
+        _ => (),
+    }
+    match event.text_shortcut() {
+        Some(TextShortcut::Move(
+            direction @ (TextCursorDirection::Forward | TextCursorDirection::Backward),
+        )) if event.modifiers.shift => {
+            window_inner
+                .text_selection
+                .borrow_mut()
+                .move_focus(matches!(direction, TextCursorDirection::Forward));
+            KeyEventResult::EventAccepted
+        }
+        _ => KeyEventResult::EventIgnored,
+    }
+}
+
+fn selectable_text_focus_event(
+    text: Pin<&dyn SelectableText>,
+    event: &FocusEvent,
+    window_adapter: &Rc<dyn WindowAdapter>,
+) -> FocusEventResult {
+    if !text.is_selectable() {
+        return FocusEventResult::FocusIgnored;
+    }
+    match event {
+        // The text is only focused to receive the copy shortcuts, it isn't in the tab chain
+        FocusEvent::FocusIn(FocusReason::TabNavigation) => FocusEventResult::FocusIgnored,
+        FocusEvent::FocusIn(_)
+        | FocusEvent::FocusOut(FocusReason::WindowActivation | FocusReason::PopupActivation) => {
+            FocusEventResult::FocusAccepted
+        }
+        FocusEvent::FocusOut(_) => {
+            WindowInner::from_pub(window_adapter.window()).text_selection.borrow_mut().clear();
+            FocusEventResult::FocusAccepted
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Default, Clone, Copy, PartialEq)]
 /// Similar as `Option<core::ops::Range<i32>>` but `repr(C)`
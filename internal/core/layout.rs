@@ -537,7 +537,9 @@ pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> S
     let spacings = data.spacing * num_spacings;
 
     let align = match data.alignment {
-        LayoutAlignment::Stretch => {
+        // The baseline alignment only affects the position on the cross axis, which is computed
+        // by the compiler.
+        LayoutAlignment::Stretch | LayoutAlignment::Baseline => {
             grid_internal::layout_items(
                 &mut layout_data,
                 data.padding.begin,
@@ -628,7 +630,7 @@ pub fn box_layout_info(
     alignment: LayoutAlignment,
 ) -> LayoutInfo {
     let count = cells.len();
-    let is_stretch = alignment == LayoutAlignment::Stretch;
+    let is_stretch = matches!(alignment, LayoutAlignment::Stretch | LayoutAlignment::Baseline);
     if count < 1 {
         let mut info = LayoutInfo::default();
         info.min = padding.begin + padding.end;
@@ -25,13 +25,20 @@
 use std::thread_local;
 
 pub mod accessibility;
+#[cfg(feature = "alloc-stats")]
+pub mod alloc_stats;
 pub mod animations;
 pub mod api;
 pub mod callbacks;
 pub mod component_factory;
 pub mod context;
 pub mod date_time;
+pub mod debounce;
+#[cfg(feature = "std")]
+pub mod dialogs;
+pub mod frame_throttle;
 pub mod future;
+pub mod fuzzy_match;
 pub mod graphics;
 pub mod input;
 pub mod item_focus;
@@ -44,8 +51,10 @@
 pub mod model;
 pub mod partial_renderer;
 pub mod platform;
+pub mod process;
 pub mod properties;
 pub mod renderer;
+pub mod resource_cache;
 #[cfg(feature = "rtti")]
 pub mod rtti;
 pub mod sharedvector;
@@ -53,10 +62,14 @@
 #[cfg(feature = "software-renderer")]
 pub mod software_renderer;
 pub mod string;
+pub mod system;
 pub mod tests;
+pub mod text_selection;
 pub mod textlayout;
 pub mod timers;
 pub mod translations;
+pub mod tray_icon;
+pub mod tsv;
 pub mod window;
 
 #[doc(inline)]
@@ -11,7 +11,7 @@
 use crate::layout::Orientation;
 use crate::lengths::{LogicalLength, RectLengths};
 use crate::{Coord, Property, SharedString, SharedVector};
-pub use adapters::{FilterModel, MapModel, ReverseModel, SortModel};
+pub use adapters::{BiMapModel, FilterModel, MapModel, ReverseModel, SortModel};
 use alloc::boxed::Box;
 use alloc::rc::Rc;
 use alloc::vec::Vec;
@@ -22,10 +22,20 @@
 use euclid::num::{Ceil, Floor};
 pub use model_peer::*;
 use once_cell::unsync::OnceCell;
+pub use page_model::{PageModel, PageProvider};
+pub use paged_model::{PagedModel, PagedSource};
 use pin_project::pin_project;
+pub use ring_buffer_model::RingBufferModel;
+#[cfg(feature = "std")]
+pub use shared_model::{SharedModel, SharedModelSender};
 
 mod adapters;
 mod model_peer;
+mod page_model;
+mod paged_model;
+mod ring_buffer_model;
+#[cfg(feature = "std")]
+mod shared_model;
 
 type ItemTreeRc<C> = vtable::VRc<crate::item_tree::ItemTreeVTable, C>;
 
@@ -255,6 +265,19 @@ fn map<F, U>(self, map_function: F) -> MapModel<Self, F>
         MapModel::new(self, map_function)
     }
 
+    /// Returns a new editable Model where all elements are mapped by the function `map_function`,
+    /// and where [`set_row_data`](Model::set_row_data) writes back to this model by the
+    /// function `reverse_function`.
+    /// This is a shortcut for [`BiMapModel::new()`].
+    fn bimap<F, R, U>(self, map_function: F, reverse_function: R) -> BiMapModel<Self, F, R>
+    where
+        Self: Sized + 'static,
+        F: Fn(Self::Data) -> U + 'static,
+        R: Fn(Self::Data, U) -> Self::Data + 'static,
+    {
+        BiMapModel::new(self, map_function, reverse_function)
+    }
+
     /// Returns a new Model where the elements are filtered by the function `filter_function`.
     /// This is a shortcut for [`FilterModel::new()`].
     fn filter<F>(self, filter_function: F) -> FilterModel<Self, F>
@@ -294,6 +317,36 @@ fn reverse(self) -> ReverseModel<Self>
     {
         ReverseModel::new(self)
     }
+
+    /// Subscribes the `observer` to the changes of this model, including the changes made from
+    /// `.slint` code, for example when the user reorders the rows of a list with drag and drop.
+    ///
+    /// The observer is notified until the returned [`ModelSubscription`] is dropped.
+    ///
+    /// ```
+    /// # use std::{cell::Cell, rc::Rc};
+    /// # use slint::{Model, ModelExt, ModelObserver, VecModel};
+    /// struct ChangeCounter(Rc<Cell<usize>>);
+    /// impl ModelObserver for ChangeCounter {
+    ///     fn row_changed(&self, _row: usize) {
+    ///         self.0.set(self.0.get() + 1);
+    ///     }
+    /// }
+    ///
+    /// let model = VecModel::from(vec![1, 2, 3]);
+    /// let counter = Rc::new(Cell::new(0));
+    /// let subscription = model.subscribe(ChangeCounter(counter.clone()));
+    ///
+    /// model.set_row_data(1, 42);
+    /// assert_eq!(counter.get(), 1);
+    ///
+    /// drop(subscription);
+    /// model.set_row_data(2, 42);
+    /// assert_eq!(counter.get(), 1);
+    /// ```
+    fn subscribe(&self, observer: impl ModelObserver + 'static) -> ModelSubscription {
+        ModelSubscription::new(self.model_tracker(), Box::new(observer))
+    }
 }
 
 impl<T: Model> ModelExt for T {}
@@ -365,14 +418,32 @@ fn set_row_data(&self, row: usize, data: Self::Data) {
 pub struct VecModel<T> {
     array: RefCell<Vec<T>>,
     notify: ModelNotify,
+    batch: VecModelBatchState,
 }
 
 impl<T> Default for VecModel<T> {
     fn default() -> Self {
-        Self { array: Default::default(), notify: Default::default() }
+        Self { array: Default::default(), notify: Default::default(), batch: Default::default() }
     }
 }
 
+/// The change accumulated while a [`VecModelBatch`] is active
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+enum PendingChange {
+    #[default]
+    None,
+    /// `count` rows were inserted contiguously at `index`
+    Added { index: usize, count: usize },
+    /// Anything else: the peers will be reset
+    Reset,
+}
+
+#[derive(Default)]
+struct VecModelBatchState {
+    depth: Cell<usize>,
+    pending: Cell<PendingChange>,
+}
+
 impl<T: 'static> VecModel<T> {
     /// Allocate a new model from a slice
     pub fn from_slice(slice: &[T]) -> ModelRc<T>
@@ -385,14 +456,14 @@ pub fn from_slice(slice: &[T]) -> ModelRc<T>
     /// Add a row at the end of the model
     pub fn push(&self, value: T) {
         self.array.borrow_mut().push(value);
-        self.notify.row_added(self.array.borrow().len() - 1, 1)
+        self.notify_row_added(self.array.borrow().len() - 1, 1)
     }
 
     /// Inserts a row at position index. All rows after that are shifted.
     /// This function panics if index is > row_count().
     pub fn insert(&self, index: usize, value: T) {
         self.array.borrow_mut().insert(index, value);
-        self.notify.row_added(index, 1)
+        self.notify_row_added(index, 1)
     }
 
     /// Remove the row at the given index from the model
@@ -400,14 +471,14 @@ pub fn insert(&self, index: usize, value: T) {
     /// Returns the removed row
     pub fn remove(&self, index: usize) -> T {
         let r = self.array.borrow_mut().remove(index);
-        self.notify.row_removed(index, 1);
+        self.notify_row_removed(index, 1);
         r
     }
 
     /// Replace inner Vec with new data
     pub fn set_vec(&self, new: impl Into<Vec<T>>) {
         *self.array.borrow_mut() = new.into();
-        self.notify.reset();
+        self.notify_reset();
     }
 
     /// Extend the model with the content of the iterator
@@ -419,7 +490,53 @@ pub fn extend<I: IntoIterator<Item = T>>(&self, iter: I) {
         array.extend(iter);
         let count = array.len() - old_idx;
         drop(array);
-        self.notify.row_added(old_idx, count);
+        self.notify_row_added(old_idx, count);
+    }
+
+    /// Appends all the elements produced by the iterator to the model, and notify
+    /// the views with a single `row_added` notification.
+    ///
+    /// This is the same as [`Self::extend`].
+    pub fn extend_from_iter<I: IntoIterator<Item = T>>(&self, iter: I) {
+        self.extend(iter)
+    }
+
+    /// Removes the specified range from the model and replaces it with the elements
+    /// of `replace_with`. Returns the removed rows.
+    ///
+    /// Rows that are replaced in place are reported as changed, and the
+    /// difference in length is reported with a single `row_added` or `row_removed`
+    /// notification.
+    ///
+    /// Similar to [`Vec::splice`], but the removed rows are returned eagerly.
+    /// This function panics if the range is out of bounds.
+    pub fn splice<R, I>(&self, range: R, replace_with: I) -> Vec<T>
+    where
+        R: core::ops::RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        let mut array = self.array.borrow_mut();
+        let start = match range.start_bound() {
+            core::ops::Bound::Included(&s) => s,
+            core::ops::Bound::Excluded(&s) => s + 1,
+            core::ops::Bound::Unbounded => 0,
+        };
+        let old_len = array.len();
+        let removed: Vec<T> = array.splice(range, replace_with).collect();
+        let new_len = array.len();
+        drop(array);
+
+        let inserted = new_len + removed.len() - old_len;
+        let common = inserted.min(removed.len());
+        for row in start..start + common {
+            self.notify_row_changed(row);
+        }
+        if inserted > common {
+            self.notify_row_added(start + common, inserted - common);
+        } else if removed.len() > common {
+            self.notify_row_removed(start + common, removed.len() - common);
+        }
+        removed
     }
 
     /// Clears the model, removing all values
@@ -427,7 +544,7 @@ pub fn extend<I: IntoIterator<Item = T>>(&self, iter: I) {
     /// Similar to [`Vec::clear`]
     pub fn clear(&self) {
         self.array.borrow_mut().clear();
-        self.notify.reset();
+        self.notify_reset();
     }
 
     /// Swaps two elements in the model.
@@ -437,8 +554,104 @@ pub fn swap(&self, a: usize, b: usize) {
         }
 
         self.array.borrow_mut().swap(a, b);
-        self.notify.row_changed(a);
-        self.notify.row_changed(b);
+        self.notify_row_changed(a);
+        self.notify_row_changed(b);
+    }
+
+    /// Start a batch of modifications.
+    ///
+    /// While the returned guard is alive, the changes done to this model are not forwarded
+    /// to the views. When the guard is dropped (or [`VecModelBatch::end_batch`] is called),
+    /// the views receive a single notification: a `row_added` notification if the only
+    /// modifications were contiguous insertions, or a reset otherwise.
+    ///
+    /// Batches can be nested, the notification is sent when the outermost batch ends.
+    ///
+    /// ```
+    /// # use i_slint_core::model::{Model, VecModel};
+    /// let model = VecModel::from(vec![1, 2, 3]);
+    /// {
+    ///     let _batch = model.begin_batch();
+    ///     for i in 4..1000 {
+    ///         model.push(i);
+    ///     }
+    ///     // No notification was sent so far
+    /// }
+    /// // The views got notified that 996 rows were added at index 3
+    /// assert_eq!(model.row_count(), 999);
+    /// ```
+    #[must_use = "the batch ends when the guard is dropped"]
+    pub fn begin_batch(&self) -> VecModelBatch<'_, T> {
+        self.batch.depth.set(self.batch.depth.get() + 1);
+        VecModelBatch { model: self }
+    }
+
+    fn end_batch(&self) {
+        let depth = self.batch.depth.get() - 1;
+        self.batch.depth.set(depth);
+        if depth > 0 {
+            return;
+        }
+        match self.batch.pending.take() {
+            PendingChange::None => {}
+            PendingChange::Added { index, count } => self.notify.row_added(index, count),
+            PendingChange::Reset => self.notify.reset(),
+        }
+    }
+
+    fn is_batching(&self) -> bool {
+        self.batch.depth.get() > 0
+    }
+
+    fn notify_row_added(&self, index: usize, count: usize) {
+        if !self.is_batching() {
+            return self.notify.row_added(index, count);
+        }
+        let pending = match self.batch.pending.get() {
+            PendingChange::None => PendingChange::Added { index, count },
+            PendingChange::Added { index: i, count: c } if (i..=i + c).contains(&index) => {
+                PendingChange::Added { index: i, count: c + count }
+            }
+            _ => PendingChange::Reset,
+        };
+        self.batch.pending.set(pending);
+    }
+
+    fn notify_row_removed(&self, index: usize, count: usize) {
+        if !self.is_batching() {
+            return self.notify.row_removed(index, count);
+        }
+        let pending = match self.batch.pending.get() {
+            // Removing rows that were added in the same batch
+            PendingChange::Added { index: i, count: c } if index >= i && index + count <= i + c => {
+                if c == count {
+                    PendingChange::None
+                } else {
+                    PendingChange::Added { index: i, count: c - count }
+                }
+            }
+            _ => PendingChange::Reset,
+        };
+        self.batch.pending.set(pending);
+    }
+
+    fn notify_row_changed(&self, row: usize) {
+        if !self.is_batching() {
+            return self.notify.row_changed(row);
+        }
+        match self.batch.pending.get() {
+            // Changing a row that was added in the same batch
+            PendingChange::Added { index, count } if (index..index + count).contains(&row) => {}
+            _ => self.batch.pending.set(PendingChange::Reset),
+        }
+    }
+
+    fn notify_reset(&self) {
+        if self.is_batching() {
+            self.batch.pending.set(PendingChange::Reset);
+        } else {
+            self.notify.reset();
+        }
     }
 }
 
@@ -452,13 +665,154 @@ pub fn extend_from_slice(&self, src: &[T]) {
 
         array.extend_from_slice(src);
         drop(array);
-        self.notify.row_added(old_idx, src.len());
+        self.notify_row_added(old_idx, src.len());
+    }
+}
+
+impl<T: PartialEq + 'static> VecModel<T> {
+    /// Replace the content of the model with the items of the iterator, and notify the views
+    /// with the minimal set of changes.
+    ///
+    /// Rows of the old and the new data are matched using the key returned by `key_fn`.
+    /// Rows whose key disappeared are reported as removed, new keys are reported as added,
+    /// and rows that kept their key but whose data differ are reported as changed.
+    /// Rows that moved relative to the others are reported as removed and added again.
+    ///
+    /// Unlike [`Self::set_vec`], this doesn't reset the views, so the scroll position
+    /// and the state of the items of a `ListView` are preserved.
+    ///
+    /// ```
+    /// # use i_slint_core::model::{Model, VecModel};
+    /// let model = VecModel::from(vec![(1, "one"), (2, "two"), (3, "three")]);
+    /// // Removes the row with key 2, changes the row with key 3 and adds one row at the end.
+    /// model.update_from_iter_keyed([(1, "one"), (3, "THREE"), (4, "four")], |(k, _)| *k);
+    /// assert_eq!(model.row_data(1), Some((3, "THREE")));
+    /// ```
+    pub fn update_from_iter_keyed<K: Ord>(
+        &self,
+        iter: impl IntoIterator<Item = T>,
+        key_fn: impl Fn(&T) -> K,
+    ) {
+        let new = iter.into_iter().collect::<Vec<T>>();
+
+        // For each new key, the index of its first occurrence in the new data
+        let mut new_index_by_key = alloc::collections::BTreeMap::new();
+        for (index, value) in new.iter().enumerate() {
+            new_index_by_key.entry(key_fn(value)).or_insert(index);
+        }
+
+        // For each old row, the index in the new data of the row with the same key
+        let old_to_new = self
+            .array
+            .borrow()
+            .iter()
+            .map(|value| new_index_by_key.remove(&key_fn(value)))
+            .collect::<Vec<_>>();
+        drop(new_index_by_key);
+
+        // The old rows that are kept are the longest sequence of rows whose order did not change
+        let mut kept_old = alloc::vec![false; old_to_new.len()];
+        let mut kept_new = alloc::vec![false; new.len()];
+        for old_index in longest_increasing_subsequence(&old_to_new) {
+            kept_old[old_index] = true;
+            kept_new[old_to_new[old_index].unwrap()] = true;
+        }
+
+        // Remove the rows that are not kept, starting from the end so indices stay valid
+        let mut end = kept_old.len();
+        while end > 0 {
+            if kept_old[end - 1] {
+                end -= 1;
+                continue;
+            }
+            let mut start = end - 1;
+            while start > 0 && !kept_old[start - 1] {
+                start -= 1;
+            }
+            self.array.borrow_mut().drain(start..end);
+            self.notify_row_removed(start, end - start);
+            end = start;
+        }
+
+        // Insert the new rows and update the kept ones
+        let mut pos = 0;
+        let mut new = new.into_iter().zip(kept_new).peekable();
+        while let Some((value, kept)) = new.next() {
+            if kept {
+                let mut array = self.array.borrow_mut();
+                if array[pos] != value {
+                    array[pos] = value;
+                    drop(array);
+                    self.notify_row_changed(pos);
+                }
+                pos += 1;
+            } else {
+                let mut inserted = alloc::vec![value];
+                while let Some((value, _)) = new.next_if(|(_, kept)| !kept) {
+                    inserted.push(value);
+                }
+                let count = inserted.len();
+                self.array.borrow_mut().splice(pos..pos, inserted);
+                self.notify_row_added(pos, count);
+                pos += count;
+            }
+        }
+    }
+}
+
+/// Returns the indices in `values` of the longest strictly increasing subsequence
+/// of the `Some` values, in increasing order.
+fn longest_increasing_subsequence(values: &[Option<usize>]) -> Vec<usize> {
+    // tails[l] is the index in values of the smallest tail of all increasing subsequences of length l+1
+    let mut tails: Vec<usize> = Vec::new();
+    let mut predecessors = alloc::vec![None; values.len()];
+    for (index, value) in values.iter().enumerate() {
+        let Some(value) = *value else { continue };
+        let len = tails.partition_point(|&t| values[t].unwrap() < value);
+        predecessors[index] = len.checked_sub(1).map(|l| tails[l]);
+        if len == tails.len() {
+            tails.push(index);
+        } else {
+            tails[len] = index;
+        }
+    }
+    let mut result = Vec::with_capacity(tails.len());
+    let mut current = tails.last().copied();
+    while let Some(index) = current {
+        result.push(index);
+        current = predecessors[index];
+    }
+    result.reverse();
+    result
+}
+
+/// Guard returned by [`VecModel::begin_batch`].
+///
+/// The notifications of the model are coalesced until this guard is dropped.
+pub struct VecModelBatch<'a, T: 'static> {
+    model: &'a VecModel<T>,
+}
+
+impl<T: 'static> VecModelBatch<'_, T> {
+    /// End the batch and send the accumulated notification to the views.
+    ///
+    /// This is the same as dropping the guard.
+    pub fn end_batch(self) {}
+}
+
+impl<T: 'static> Drop for VecModelBatch<'_, T> {
+    fn drop(&mut self) {
+        self.model.end_batch();
     }
 }
 
 impl<T> From<Vec<T>> for VecModel<T> {
     fn from(array: Vec<T>) -> Self {
-        VecModel { array: RefCell::new(array), notify: Default::default() }
+        VecModel {
+            array: RefCell::new(array),
+            notify: Default::default(),
+            batch: Default::default(),
+        }
     }
 }
 
@@ -482,7 +836,7 @@ fn row_data(&self, row: usize) -> Option<Self::Data> {
     fn set_row_data(&self, row: usize, data: Self::Data) {
         if row < self.row_count() {
             self.array.borrow_mut()[row] = data;
-            self.notify.row_changed(row);
+            self.notify_row_changed(row);
         }
     }
 
@@ -1311,8 +1665,16 @@ pub fn visit(
     ) -> crate::item_tree::VisitChildrenResult {
         // We can't keep self.inner borrowed because the event might modify the model
         let count = self.0.inner.borrow().instances.len() as u32;
+        let sorted = crate::item_tree::sort_by_z_order(0..count, |i| {
+            let c = self.0.inner.borrow().instances.get(i as usize).and_then(|c| c.1.clone())?;
+            crate::items::ItemRef::downcast_pin::<crate::items::ZOrder>(
+                c.as_pin_ref().get_item_ref(0),
+            )
+            .map(|z_order| z_order.z())
+        });
         for i in 0..count {
             let i = if order == TraversalOrder::BackToFront { i } else { count - i - 1 };
+            let i = sorted.as_ref().map_or(i, |s| s[i as usize]);
             let c = self.0.inner.borrow().instances.get(i as usize).and_then(|c| c.1.clone());
             if let Some(c) = c {
                 if c.as_pin_ref().visit_children_item(-1, order, visitor.borrow_mut()).has_aborted()
@@ -1681,6 +2043,160 @@ fn test_vecmodel_swap() {
         view.clear();
     }
 
+    #[test]
+    fn test_vecmodel_splice() {
+        let view = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+
+        let model = Rc::new(VecModel::from(vec![1, 2, 3, 4]));
+        model.model_tracker().attach_peer(Pin::as_ref(&view).model_peer());
+        *view.model.borrow_mut() =
+            Some(std::rc::Rc::downgrade(&(model.clone() as Rc<dyn Model<Data = i32>>)));
+
+        assert_eq!(model.splice(1..3, [5, 6, 7, 8]), vec![2, 3]);
+        assert_eq!(&*view.changed_rows.borrow(), &[(1, 6), (2, 6)]);
+        assert_eq!(&*view.added_rows.borrow(), &[(3, 2, 6)]);
+        assert!(view.removed_rows.borrow().is_empty());
+        assert_eq!(*view.reset.borrow(), 0);
+        view.clear();
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![1, 5, 6, 7, 8, 4]);
+
+        assert_eq!(model.splice(..4, [9]), vec![1, 5, 6, 7]);
+        assert_eq!(&*view.changed_rows.borrow(), &[(0, 3)]);
+        assert!(view.added_rows.borrow().is_empty());
+        assert_eq!(&*view.removed_rows.borrow(), &[(1, 3, 3)]);
+        assert_eq!(*view.reset.borrow(), 0);
+        view.clear();
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![9, 8, 4]);
+    }
+
+    #[test]
+    fn test_vecmodel_batch() {
+        let view = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+
+        let model = Rc::new(VecModel::from(vec![1, 2, 3, 4]));
+        model.model_tracker().attach_peer(Pin::as_ref(&view).model_peer());
+        *view.model.borrow_mut() =
+            Some(std::rc::Rc::downgrade(&(model.clone() as Rc<dyn Model<Data = i32>>)));
+
+        {
+            let _batch = model.begin_batch();
+            for i in 5..100 {
+                model.push(i);
+            }
+            model.set_row_data(50, 42);
+            model.extend_from_iter([100, 101]);
+            {
+                let _nested = model.begin_batch();
+                model.remove(10);
+            }
+            assert!(view.added_rows.borrow().is_empty());
+        }
+        assert!(view.changed_rows.borrow().is_empty());
+        assert_eq!(&*view.added_rows.borrow(), &[(4, 96, 100)]);
+        assert!(view.removed_rows.borrow().is_empty());
+        assert_eq!(*view.reset.borrow(), 0);
+        view.clear();
+
+        let batch = model.begin_batch();
+        model.push(102);
+        model.set_row_data(0, 0);
+        batch.end_batch();
+        assert!(view.changed_rows.borrow().is_empty());
+        assert!(view.added_rows.borrow().is_empty());
+        assert!(view.removed_rows.borrow().is_empty());
+        assert_eq!(*view.reset.borrow(), 1);
+        view.clear();
+
+        drop(model.begin_batch());
+        assert!(view.changed_rows.borrow().is_empty());
+        assert!(view.added_rows.borrow().is_empty());
+        assert!(view.removed_rows.borrow().is_empty());
+        assert_eq!(*view.reset.borrow(), 0);
+    }
+
+    #[test]
+    fn test_model_subscribe() {
+        #[derive(Default)]
+        struct Recorder(RefCell<Vec<std::string::String>>);
+        impl ModelObserver for Rc<Recorder> {
+            fn row_changed(&self, row: usize) {
+                self.0.borrow_mut().push(std::format!("changed {row}"));
+            }
+            fn row_added(&self, index: usize, count: usize) {
+                self.0.borrow_mut().push(std::format!("added {index} {count}"));
+            }
+            fn row_removed(&self, index: usize, count: usize) {
+                self.0.borrow_mut().push(std::format!("removed {index} {count}"));
+            }
+            fn reset(&self) {
+                self.0.borrow_mut().push("reset".into());
+            }
+        }
+
+        let model = Rc::new(VecModel::from(vec![1, 2, 3]));
+        let model_rc = ModelRc::from(model.clone());
+        let recorder = Rc::new(Recorder::default());
+        let subscription = model_rc.subscribe(recorder.clone());
+
+        model.push(4);
+        model_rc.set_row_data(0, 10);
+        model.remove(1);
+        model.set_vec(vec![5, 6]);
+        assert_eq!(*recorder.0.borrow(), ["added 3 1", "changed 0", "removed 1 1", "reset"]);
+
+        drop(subscription);
+        model.push(7);
+        assert_eq!(recorder.0.borrow().len(), 4);
+    }
+
+    #[test]
+    fn test_vecmodel_update_from_iter_keyed() {
+        let view = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+
+        let model = Rc::new(VecModel::from(vec![1, 2, 3, 4, 5, 6]));
+        model.model_tracker().attach_peer(Pin::as_ref(&view).model_peer());
+        *view.model.borrow_mut() =
+            Some(std::rc::Rc::downgrade(&(model.clone() as Rc<dyn Model<Data = i32>>)));
+
+        // Same data: no notification
+        model.update_from_iter_keyed([1, 2, 3, 4, 5, 6], |x| *x);
+        assert!(view.changed_rows.borrow().is_empty());
+        assert!(view.added_rows.borrow().is_empty());
+        assert!(view.removed_rows.borrow().is_empty());
+        assert_eq!(*view.reset.borrow(), 0);
+
+        // Removals and insertions
+        model.update_from_iter_keyed([0, 1, 2, 5, 6, 7, 8], |x| *x);
+        assert!(view.changed_rows.borrow().is_empty());
+        assert_eq!(&*view.removed_rows.borrow(), &[(2, 2, 4)]);
+        assert_eq!(&*view.added_rows.borrow(), &[(0, 1, 5), (5, 2, 7)]);
+        assert_eq!(*view.reset.borrow(), 0);
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![0, 1, 2, 5, 6, 7, 8]);
+        view.clear();
+
+        // Move a row
+        model.update_from_iter_keyed([0, 6, 1, 2, 5, 7, 8], |x| *x);
+        assert!(view.changed_rows.borrow().is_empty());
+        assert_eq!(&*view.removed_rows.borrow(), &[(4, 1, 6)]);
+        assert_eq!(&*view.added_rows.borrow(), &[(1, 1, 7)]);
+        assert_eq!(*view.reset.borrow(), 0);
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![0, 6, 1, 2, 5, 7, 8]);
+        view.clear();
+
+        // Changed data with the same key
+        model.update_from_iter_keyed([0, 6, 11, 12, 5, 7, 8], |x| *x % 10);
+        assert_eq!(&*view.changed_rows.borrow(), &[(2, 7), (3, 7)]);
+        assert!(view.removed_rows.borrow().is_empty());
+        assert!(view.added_rows.borrow().is_empty());
+        assert_eq!(*view.reset.borrow(), 0);
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![0, 6, 11, 12, 5, 7, 8]);
+        view.clear();
+
+        model.update_from_iter_keyed([], |x| *x);
+        assert_eq!(&*view.removed_rows.borrow(), &[(0, 7, 0)]);
+        assert_eq!(model.row_count(), 0);
+    }
+
     #[test]
     fn modeliter_in_bounds() {
         struct TestModel {
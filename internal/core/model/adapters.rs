@@ -276,6 +276,142 @@ fn test_map_model() {
     assert_eq!(map.row_data(1).unwrap(), "2");
 }
 
+/// Provides rows that are generated by a map function based on the rows of another Model,
+/// and that can be edited.
+///
+/// This is like [`MapModel`], but with a second function that maps the values back. When
+/// [`set_row_data`](Model::set_row_data) is called on the `BiMapModel`, the reverse function is called
+/// with the current row of the source model and the new value, and the returned row is written back
+/// to the source model. This allows two-way editing of projected rows, for example from a `LineEdit`
+/// bound to a field of a struct in the model.
+///
+/// Generic parameters:
+/// * `M` the type of the wrapped `Model`.
+/// * `F` the map function.
+/// * `R` the reverse function.
+///
+/// ## Example
+///
+/// ```
+/// # use std::rc::Rc;
+/// # use slint::{Model, VecModel, SharedString, BiMapModel};
+/// #[derive(Clone)]
+/// struct Person {
+///     name: SharedString,
+///     age: u32,
+/// }
+///
+/// let model = Rc::new(VecModel::from(vec![
+///     Person { name: "Hans".into(), age: 42 },
+///     Person { name: "Max".into(), age: 23 },
+/// ]));
+///
+/// let names = BiMapModel::new(
+///     model.clone(),
+///     |person| person.name,
+///     |person, name| Person { name, ..person },
+/// );
+///
+/// names.set_row_data(1, "Minnie".into());
+///
+/// assert_eq!(names.row_data(1).unwrap(), SharedString::from("Minnie"));
+/// assert_eq!(model.row_data(1).unwrap().name, SharedString::from("Minnie"));
+/// assert_eq!(model.row_data(1).unwrap().age, 23);
+/// ```
+///
+/// Alternatively you can use the shortcut [`ModelExt::bimap`].
+pub struct BiMapModel<M, F, R> {
+    wrapped_model: M,
+    map_function: F,
+    reverse_function: R,
+}
+
+impl<M, F, R, T, U> Model for BiMapModel<M, F, R>
+where
+    M: 'static,
+    F: 'static,
+    R: 'static,
+    F: Fn(T) -> U,
+    R: Fn(T, U) -> T,
+    M: Model<Data = T>,
+{
+    type Data = U;
+
+    fn row_count(&self) -> usize {
+        self.wrapped_model.row_count()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        self.wrapped_model.row_data(row).map(|x| (self.map_function)(x))
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        if let Some(source) = self.wrapped_model.row_data(row) {
+            self.wrapped_model.set_row_data(row, (self.reverse_function)(source, data));
+        }
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        self.wrapped_model.model_tracker()
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+impl<M, F, R, T, U> BiMapModel<M, F, R>
+where
+    M: 'static,
+    F: 'static,
+    R: 'static,
+    F: Fn(T) -> U,
+    R: Fn(T, U) -> T,
+    M: Model<Data = T>,
+{
+    /// Creates a new BiMapModel based on the given `wrapped_model`, `map_function` and
+    /// `reverse_function`.
+    /// Alternatively you can use [`ModelExt::bimap`] on your Model.
+    pub fn new(wrapped_model: M, map_function: F, reverse_function: R) -> Self {
+        Self { wrapped_model, map_function, reverse_function }
+    }
+
+    /// Returns a reference to the inner model
+    pub fn source_model(&self) -> &M {
+        &self.wrapped_model
+    }
+}
+
+#[test]
+fn test_bimap_model() {
+    use alloc::string::ToString;
+    use tests_helper::*;
+    let wrapped_rc = Rc::new(VecModel::from(std::vec![(1, 'a'), (2, 'b'), (3, 'c')]));
+    let map = Rc::new(BiMapModel::new(
+        wrapped_rc.clone(),
+        |(number, _)| number.to_string(),
+        |(_, letter), text: alloc::string::String| (text.parse().unwrap_or_default(), letter),
+    ));
+
+    let _checker = ModelChecker::new(map.clone());
+
+    let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+    map.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+    map.set_row_data(1, "42".into());
+    assert_eq!(wrapped_rc.row_data(1).unwrap(), (42, 'b'));
+    assert_eq!(map.row_data(1).unwrap(), "42");
+    assert_eq!(*observer.changed_rows.borrow(), [1]);
+
+    wrapped_rc.set_row_data(2, (7, 'z'));
+    assert_eq!(map.row_data(2).unwrap(), "7");
+    assert_eq!(*observer.changed_rows.borrow(), [1, 2]);
+
+    // Out of bounds rows are ignored
+    map.set_row_data(5, "10".into());
+    assert_eq!(wrapped_rc.row_count(), 3);
+}
+
 struct FilterModelInner<M, F>
 where
     M: Model + 'static,
@@ -680,18 +816,49 @@ impl<M, S> ModelChangeListener for SortModelInner<M, S>
 {
     fn row_changed(self: Pin<&Self>, row: usize) {
         if self.sorted_rows_dirty.get() {
-            self.reset();
+            // The mapping is dirty either because no view has read the rows yet, or because
+            // reset() already notified the views, which then read all the rows again. Either way,
+            // no view holds data from before this change, and the mapping is rebuilt with the new
+            // data on the next access: another reset would only make the views reload twice.
             return;
         }
 
+        let changed_data = self.wrapped_model.row_data(row);
         let mut mapping = self.mapping.borrow_mut();
         let removed_index = mapping.iter().position(|r| *r == row).unwrap();
+
+        // Compares the data of the row at the given sorted index with the changed data.
+        // Rows without data are sorted last.
+        let cmp_with = |sorted_index: usize| -> core::cmp::Ordering {
+            let existing = self.wrapped_model.row_data(mapping[sorted_index]);
+            match (&existing, &changed_data) {
+                (Some(existing), Some(changed)) => {
+                    self.sort_helper.borrow_mut().cmp(existing, changed)
+                }
+                (Some(_), None) => core::cmp::Ordering::Less,
+                (None, Some(_)) => core::cmp::Ordering::Greater,
+                (None, None) => core::cmp::Ordering::Equal,
+            }
+        };
+
+        // Fast path: the row is still in order with its neighbors, so it doesn't move.
+        // This also keeps rows that compare equal at their current position.
+        let in_order_with_previous =
+            removed_index == 0 || cmp_with(removed_index - 1) != core::cmp::Ordering::Greater;
+        let in_order_with_next = removed_index + 1 >= mapping.len()
+            || cmp_with(removed_index + 1) != core::cmp::Ordering::Less;
+        if in_order_with_previous && in_order_with_next {
+            drop(mapping);
+            self.notify.row_changed(removed_index);
+            return;
+        }
+
         mapping.remove(removed_index);
 
-        let insertion_index = if let Some(changed_data) = self.wrapped_model.row_data(row) {
+        let insertion_index = if let Some(changed_data) = &changed_data {
             mapping.partition_point(|existing_row| {
                 self.wrapped_model.row_data(*existing_row).is_some_and(|existing| {
-                    self.sort_helper.borrow_mut().cmp(&existing, &changed_data)
+                    self.sort_helper.borrow_mut().cmp(&existing, changed_data)
                         == core::cmp::Ordering::Less
                 })
             })
@@ -703,12 +870,10 @@ fn row_changed(self: Pin<&Self>, row: usize) {
 
         drop(mapping);
 
-        if insertion_index == removed_index {
-            self.notify.row_changed(removed_index);
-        } else {
-            self.notify.row_removed(removed_index, 1);
-            self.notify.row_added(insertion_index, 1);
-        }
+        // The row moved: report it as a removal followed by an insertion of a single row, so
+        // that views only update the affected rows and keep their scroll position.
+        self.notify.row_removed(removed_index, 1);
+        self.notify.row_added(insertion_index, 1);
     }
 
     fn row_added(self: Pin<&Self>, index: usize, count: usize) {
@@ -1114,6 +1279,44 @@ fn test_sorted_model_changed() {
         assert_eq!(sorted_model.row_data(3).unwrap(), 3);
     }
 
+    #[test]
+    fn test_sorted_model_changed_incremental() {
+        let wrapped_rc = Rc::new(VecModel::from(vec![(1, 'a'), (2, 'b'), (2, 'c'), (3, 'd')]));
+        let sorted_model =
+            Rc::new(SortModel::new(wrapped_rc.clone(), |lhs: &(i32, char), rhs: &(i32, char)| {
+                lhs.0.cmp(&rhs.0)
+            }));
+
+        let _checker = ModelChecker::new(sorted_model.clone());
+
+        let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        sorted_model.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+        assert_eq!(sorted_model.row_count(), 4);
+
+        // Changing a row that compares equal to its neighbor doesn't move it
+        wrapped_rc.set_row_data(2, (2, 'x'));
+        assert!(observer.added_rows.borrow().is_empty());
+        assert!(observer.removed_rows.borrow().is_empty());
+        assert_eq!(*observer.changed_rows.borrow(), [2]);
+        assert_eq!(*observer.reset.borrow(), 0);
+        observer.clear();
+        assert_eq!(sorted_model.row_data(1).unwrap(), (2, 'b'));
+        assert_eq!(sorted_model.row_data(2).unwrap(), (2, 'x'));
+
+        // Moving the last row to the front is reported as a single removal and insertion
+        wrapped_rc.set_row_data(3, (0, 'd'));
+        assert_eq!(*observer.removed_rows.borrow(), [(3, 1)]);
+        assert_eq!(*observer.added_rows.borrow(), [(0, 1)]);
+        assert!(observer.changed_rows.borrow().is_empty());
+        assert_eq!(*observer.reset.borrow(), 0);
+        observer.clear();
+        assert_eq!(
+            (0..4).map(|row| sorted_model.row_data(row).unwrap().1).collect::<Vec<_>>(),
+            ['d', 'a', 'b', 'x']
+        );
+    }
+
     #[test]
     fn test_sorted_model_source_model() {
         let wrapped_rc = Rc::new(VecModel::from(vec![3, 4, 1, 2]));
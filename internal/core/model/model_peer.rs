@@ -177,3 +177,63 @@ pub fn get(self: Pin<&Self>) -> Pin<&T> {
         self.project_ref().data
     }
 }
+
+/// Receives the notifications of the changes to a [`Model`], see [`ModelExt::subscribe`].
+///
+/// All functions have an empty default implementation, so that only the notifications of
+/// interest need to be implemented.
+///
+/// The observer is called synchronously while the model is being modified, so it must not modify
+/// the model itself. Use for example a [`Timer`](crate::timers::Timer) or
+/// `slint::invoke_from_event_loop` to defer such modifications.
+pub trait ModelObserver {
+    /// Called when the data of the given row was changed.
+    fn row_changed(&self, _row: usize) {}
+    /// Called when `count` rows were inserted at `index`.
+    fn row_added(&self, _index: usize, _count: usize) {}
+    /// Called when `count` rows were removed at `index`.
+    fn row_removed(&self, _index: usize, _count: usize) {}
+    /// Called when the model changed in a way that can't be described by the other notifications,
+    /// and all the rows must be reloaded.
+    fn reset(&self) {}
+}
+
+struct ModelObserverAdapter(Box<dyn ModelObserver>);
+
+impl ModelChangeListener for ModelObserverAdapter {
+    fn row_changed(self: Pin<&Self>, row: usize) {
+        self.0.row_changed(row)
+    }
+    fn row_added(self: Pin<&Self>, index: usize, count: usize) {
+        self.0.row_added(index, count)
+    }
+    fn row_removed(self: Pin<&Self>, index: usize, count: usize) {
+        self.0.row_removed(index, count)
+    }
+    fn reset(self: Pin<&Self>) {
+        self.0.reset()
+    }
+}
+
+/// A handle to a [`ModelObserver`] that was subscribed with [`ModelExt::subscribe`].
+///
+/// The observer is notified of the changes of the model until this handle is dropped.
+#[must_use = "the observer is unsubscribed when the ModelSubscription is dropped"]
+pub struct ModelSubscription {
+    // Dropping the container detaches it from the model
+    _container: Pin<Box<ModelChangeListenerContainer<ModelObserverAdapter>>>,
+}
+
+impl ModelSubscription {
+    pub(super) fn new(tracker: &dyn ModelTracker, observer: Box<dyn ModelObserver>) -> Self {
+        let container = Box::pin(ModelChangeListenerContainer::new(ModelObserverAdapter(observer)));
+        tracker.attach_peer(container.as_ref().model_peer());
+        Self { _container: container }
+    }
+}
+
+impl core::fmt::Debug for ModelSubscription {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ModelSubscription").finish_non_exhaustive()
+    }
+}
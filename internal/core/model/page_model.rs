@@ -0,0 +1,222 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the [`PageModel`], which renders the pages of a document for the `DocumentView` widget.
+
+use super::*;
+use crate::graphics::Image;
+use alloc::collections::VecDeque;
+
+/// A source of pages displayed by a `DocumentView`, such as a PDF document.
+///
+/// Implement this trait with the library of your choice (for example pdfium, poppler or mupdf),
+/// and use it with a [`PageModel`].
+pub trait PageProvider {
+    /// Returns the number of pages in the document
+    fn page_count(&self) -> usize;
+
+    /// Renders the page at `index`, `scale` being the number of pixels per point of the page.
+    ///
+    /// Return a default `Image` if the page can't be rendered.
+    fn render_page(&self, index: usize, scale: f32) -> Image;
+
+    /// Returns the text within an area of the page at `index`, as selected by the user in the `DocumentView`.
+    ///
+    /// The coordinates are relative to the size of the page, between 0 and 1.
+    /// The default implementation returns `None`, meaning that the document doesn't support text selection.
+    fn text_in_area(
+        &self,
+        _index: usize,
+        _x: f32,
+        _y: f32,
+        _width: f32,
+        _height: f32,
+    ) -> Option<SharedString> {
+        None
+    }
+}
+
+/// A [`Model`] of the pages of a document, rendered on demand by a [`PageProvider`].
+///
+/// The pages are only rendered when they are requested by the view, which is the case for the
+/// visible pages of a `DocumentView`. The most recently rendered pages are kept in a cache.
+///
+/// ```
+/// # use i_slint_core::model::{Model, PageModel, PageProvider};
+/// # use i_slint_core::graphics::{Image, SharedPixelBuffer, Rgba8Pixel};
+/// struct BlankDocument;
+/// impl PageProvider for BlankDocument {
+///     fn page_count(&self) -> usize {
+///         10
+///     }
+///     fn render_page(&self, _index: usize, scale: f32) -> Image {
+///         // An A4 page is 595x842 points
+///         let width = (595. * scale) as u32;
+///         let height = (842. * scale) as u32;
+///         Image::from_rgba8(SharedPixelBuffer::<Rgba8Pixel>::new(width, height))
+///     }
+/// }
+///
+/// let model = PageModel::new(BlankDocument);
+/// assert_eq!(model.row_count(), 10);
+/// assert_eq!(model.row_data(3).unwrap().size().width, 595);
+/// ```
+pub struct PageModel<P> {
+    provider: P,
+    scale: Cell<f32>,
+    cache_size: Cell<usize>,
+    /// The rendered pages, the most recently used being at the end
+    cache: RefCell<VecDeque<(usize, Image)>>,
+    notify: ModelNotify,
+}
+
+impl<P: PageProvider> PageModel<P> {
+    /// Creates a new model rendering the pages of `provider` at a scale of one pixel per point.
+    pub fn new(provider: P) -> Self {
+        Self {
+            provider,
+            scale: Cell::new(1.),
+            cache_size: Cell::new(8),
+            cache: Default::default(),
+            notify: Default::default(),
+        }
+    }
+
+    /// Returns a reference to the page provider
+    pub fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    /// Returns the number of pixels per point used to render the pages
+    pub fn scale(&self) -> f32 {
+        self.scale.get()
+    }
+
+    /// Sets the number of pixels per point used to render the pages.
+    ///
+    /// Use a higher value when the document is zoomed in or on high DPI screens.
+    /// The visible pages are rendered again.
+    pub fn set_scale(&self, scale: f32) {
+        if self.scale.replace(scale) != scale {
+            self.reload();
+        }
+    }
+
+    /// Sets the maximum number of rendered pages that are kept in memory. The default is 8.
+    pub fn set_cache_size(&self, cache_size: usize) {
+        self.cache_size.set(cache_size);
+        let mut cache = self.cache.borrow_mut();
+        let excess = cache.len().saturating_sub(cache_size);
+        cache.drain(..excess);
+    }
+
+    /// Discards the rendered pages and notifies the views.
+    ///
+    /// Call this function when the content of the document changed.
+    pub fn reload(&self) {
+        self.cache.borrow_mut().clear();
+        self.notify.reset();
+    }
+
+    /// Returns the text within an area of a page, as reported by the `text-selected` callback
+    /// of the `DocumentView`.
+    pub fn text_in_area(
+        &self,
+        index: usize,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+    ) -> Option<SharedString> {
+        self.provider.text_in_area(index, x, y, width, height)
+    }
+}
+
+impl<P: PageProvider + 'static> Model for PageModel<P> {
+    type Data = Image;
+
+    fn row_count(&self) -> usize {
+        self.provider.page_count()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        if row >= self.row_count() {
+            return None;
+        }
+        let mut cache = self.cache.borrow_mut();
+        if let Some(pos) = cache.iter().position(|(index, _)| *index == row) {
+            let entry = cache.remove(pos).unwrap();
+            let image = entry.1.clone();
+            cache.push_back(entry);
+            return Some(image);
+        }
+        let image = self.provider.render_page(row, self.scale.get());
+        if self.cache_size.get() > 0 {
+            if cache.len() >= self.cache_size.get() {
+                cache.pop_front();
+            }
+            cache.push_back((row, image.clone()));
+        }
+        Some(image)
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec;
+    use crate::graphics::{Rgba8Pixel, SharedPixelBuffer};
+
+    #[derive(Default)]
+    struct TestDocument {
+        rendered: RefCell<Vec<(usize, f32)>>,
+    }
+
+    impl PageProvider for TestDocument {
+        fn page_count(&self) -> usize {
+            5
+        }
+
+        fn render_page(&self, index: usize, scale: f32) -> Image {
+            self.rendered.borrow_mut().push((index, scale));
+            let size = ((index as f32 + 1.) * 10. * scale) as u32;
+            Image::from_rgba8(SharedPixelBuffer::<Rgba8Pixel>::new(size, size))
+        }
+    }
+
+    #[test]
+    fn test_page_model() {
+        let model = PageModel::new(TestDocument::default());
+        model.set_cache_size(2);
+        assert_eq!(model.row_count(), 5);
+        assert!(model.row_data(5).is_none());
+        assert!(model.provider().rendered.borrow().is_empty());
+
+        assert_eq!(model.row_data(1).unwrap().size().width, 20);
+        assert_eq!(model.row_data(2).unwrap().size().width, 30);
+        // Cached
+        assert_eq!(model.row_data(1).unwrap().size().width, 20);
+        assert_eq!(*model.provider().rendered.borrow(), vec![(1, 1.), (2, 1.)]);
+
+        // Page 2 is the least recently used, it gets evicted
+        model.row_data(3);
+        model.row_data(1);
+        model.row_data(2);
+        assert_eq!(*model.provider().rendered.borrow(), vec![(1, 1.), (2, 1.), (3, 1.), (2, 1.)]);
+
+        model.provider().rendered.borrow_mut().clear();
+        model.set_scale(2.);
+        assert_eq!(model.row_data(2).unwrap().size().width, 60);
+        assert_eq!(*model.provider().rendered.borrow(), vec![(2, 2.)]);
+
+        assert!(model.text_in_area(0, 0., 0., 1., 1.).is_none());
+    }
+}
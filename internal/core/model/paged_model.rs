@@ -0,0 +1,287 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the [`PagedModel`], which loads the rows of a large data source by pages, on demand.
+
+use super::*;
+use alloc::collections::VecDeque;
+use core::ops::Range;
+
+/// A source of rows loaded by pages by a [`PagedModel`], such as the result of a database query.
+pub trait PagedSource {
+    /// The type of the rows
+    type Data: Clone;
+
+    /// Returns the number of rows of the source.
+    ///
+    /// This is called when the model is created and when it is refreshed, the result is cached by the model.
+    fn row_count(&self) -> usize;
+
+    /// Loads `count` rows, starting at the row `offset`.
+    ///
+    /// May return fewer rows if the source has fewer rows than expected, for example because it
+    /// changed since [`Self::row_count()`] was called.
+    fn fetch_rows(&self, offset: usize, count: usize) -> Vec<Self::Data>;
+
+    /// Writes back the row at `row`, when [`Model::set_row_data()`] is called on the model.
+    ///
+    /// Return false if the row can't be written, in which case the model is left unchanged.
+    /// The default implementation returns false, meaning that the source is read-only.
+    fn write_row(&self, _row: usize, _data: &Self::Data) -> bool {
+        false
+    }
+}
+
+/// A [`Model`] that loads the rows of a [`PagedSource`] lazily, by pages.
+///
+/// The pages are only loaded when rows are requested by the view, which is the case for the
+/// visible rows of a `ListView` or a `StandardTableView`. The most recently used pages are kept in a cache.
+///
+/// When the source changes, notify the model with [`Self::refresh()`], or with [`Self::rows_changed()`],
+/// [`Self::rows_inserted()`] and [`Self::rows_removed()`] to only reload what changed.
+///
+/// ```
+/// # use i_slint_core::model::{Model, PagedModel, PagedSource};
+/// struct Squares;
+/// impl PagedSource for Squares {
+///     type Data = u64;
+///     fn row_count(&self) -> usize {
+///         1_000_000
+///     }
+///     fn fetch_rows(&self, offset: usize, count: usize) -> Vec<u64> {
+///         (offset as u64..(offset + count) as u64).map(|x| x * x).collect()
+///     }
+/// }
+///
+/// let model = PagedModel::new(Squares);
+/// assert_eq!(model.row_count(), 1_000_000);
+/// assert_eq!(model.row_data(1000), Some(1_000_000));
+/// ```
+pub struct PagedModel<S: PagedSource> {
+    source: S,
+    page_size: Cell<usize>,
+    cache_size: Cell<usize>,
+    row_count: Cell<usize>,
+    /// The loaded pages with their index, the most recently used being at the end
+    pages: RefCell<VecDeque<(usize, Vec<S::Data>)>>,
+    notify: ModelNotify,
+}
+
+impl<S: PagedSource> PagedModel<S> {
+    /// Creates a new model loading the rows of `source` by pages of 100 rows.
+    pub fn new(source: S) -> Self {
+        Self {
+            row_count: Cell::new(source.row_count()),
+            source,
+            page_size: Cell::new(100),
+            cache_size: Cell::new(8),
+            pages: Default::default(),
+            notify: Default::default(),
+        }
+    }
+
+    /// Returns a reference to the source
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    /// Returns the number of rows loaded at once
+    pub fn page_size(&self) -> usize {
+        self.page_size.get()
+    }
+
+    /// Sets the number of rows loaded at once. The default is 100.
+    pub fn set_page_size(&self, page_size: usize) {
+        let page_size = page_size.max(1);
+        if self.page_size.replace(page_size) != page_size {
+            self.pages.borrow_mut().clear();
+        }
+    }
+
+    /// Sets the maximum number of pages that are kept in memory. The default is 8.
+    pub fn set_cache_size(&self, cache_size: usize) {
+        self.cache_size.set(cache_size);
+        let mut pages = self.pages.borrow_mut();
+        let excess = pages.len().saturating_sub(cache_size);
+        pages.drain(..excess);
+    }
+
+    /// Discards the loaded pages, queries the row count again and notifies the views.
+    ///
+    /// Call this function when the content of the source changed.
+    pub fn refresh(&self) {
+        self.pages.borrow_mut().clear();
+        self.row_count.set(self.source.row_count());
+        self.notify.reset();
+    }
+
+    /// Reloads the rows in `range` and notifies the views that they changed.
+    pub fn rows_changed(&self, range: Range<usize>) {
+        self.discard_pages(|first_row, last_row| first_row < range.end && last_row > range.start);
+        for row in range.start..range.end.min(self.row_count.get()) {
+            self.notify.row_changed(row);
+        }
+    }
+
+    /// Notifies the views that `count` rows were inserted in the source at `index`.
+    pub fn rows_inserted(&self, index: usize, count: usize) {
+        self.discard_pages(|_, last_row| last_row > index);
+        self.row_count.set(self.row_count.get() + count);
+        self.notify.row_added(index, count);
+    }
+
+    /// Notifies the views that `count` rows were removed from the source at `index`.
+    pub fn rows_removed(&self, index: usize, count: usize) {
+        self.discard_pages(|_, last_row| last_row > index);
+        self.row_count.set(self.row_count.get().saturating_sub(count));
+        self.notify.row_removed(index, count);
+    }
+
+    /// Discards the pages for which `predicate(first_row, last_row + 1)` returns true
+    fn discard_pages(&self, predicate: impl Fn(usize, usize) -> bool) {
+        let page_size = self.page_size.get();
+        self.pages
+            .borrow_mut()
+            .retain(|(page, _)| !predicate(page * page_size, (page + 1) * page_size));
+    }
+}
+
+impl<S: PagedSource + 'static> Model for PagedModel<S> {
+    type Data = S::Data;
+
+    fn row_count(&self) -> usize {
+        self.row_count.get()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        if row >= self.row_count() {
+            return None;
+        }
+        let page_size = self.page_size.get();
+        let (page, offset) = (row / page_size, row % page_size);
+        let mut pages = self.pages.borrow_mut();
+        if let Some(pos) = pages.iter().position(|(index, _)| *index == page) {
+            let entry = pages.remove(pos).unwrap();
+            let data = entry.1.get(offset).cloned();
+            pages.push_back(entry);
+            return data;
+        }
+        let rows = self.source.fetch_rows(page * page_size, page_size);
+        let data = rows.get(offset).cloned();
+        if self.cache_size.get() > 0 {
+            if pages.len() >= self.cache_size.get() {
+                pages.pop_front();
+            }
+            pages.push_back((page, rows));
+        }
+        data
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        if row >= self.row_count() || !self.source.write_row(row, &data) {
+            return;
+        }
+        let page_size = self.page_size.get();
+        if let Some((_, rows)) =
+            self.pages.borrow_mut().iter_mut().find(|(index, _)| *index == row / page_size)
+        {
+            if let Some(cached) = rows.get_mut(row % page_size) {
+                *cached = data;
+            }
+        }
+        self.notify.row_changed(row);
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec;
+
+    #[derive(Default)]
+    struct TestSource {
+        rows: RefCell<Vec<i32>>,
+        fetched: RefCell<Vec<(usize, usize)>>,
+    }
+
+    impl PagedSource for TestSource {
+        type Data = i32;
+
+        fn row_count(&self) -> usize {
+            self.rows.borrow().len()
+        }
+
+        fn fetch_rows(&self, offset: usize, count: usize) -> Vec<i32> {
+            self.fetched.borrow_mut().push((offset, count));
+            self.rows.borrow().iter().skip(offset).take(count).copied().collect()
+        }
+
+        fn write_row(&self, row: usize, data: &i32) -> bool {
+            if *data < 0 {
+                return false;
+            }
+            self.rows.borrow_mut()[row] = *data;
+            true
+        }
+    }
+
+    #[test]
+    fn test_paged_model() {
+        let source = TestSource::default();
+        *source.rows.borrow_mut() = (0..25).collect();
+        let model = PagedModel::new(source);
+        model.set_page_size(10);
+        model.set_cache_size(2);
+        assert_eq!(model.row_count(), 25);
+        assert_eq!(model.row_data(25), None);
+        assert!(model.source().fetched.borrow().is_empty());
+
+        assert_eq!(model.row_data(3), Some(3));
+        assert_eq!(model.row_data(7), Some(7));
+        assert_eq!(model.row_data(24), Some(24));
+        assert_eq!(*model.source().fetched.borrow(), vec![(0, 10), (20, 10)]);
+
+        // Page 0 is the least recently used, it gets evicted
+        model.row_data(12);
+        model.row_data(0);
+        assert_eq!(*model.source().fetched.borrow(), vec![(0, 10), (20, 10), (10, 10), (0, 10)]);
+
+        // Write back
+        model.set_row_data(1, 100);
+        model.set_row_data(2, -1);
+        assert_eq!(model.row_data(1), Some(100));
+        assert_eq!(model.row_data(2), Some(2));
+        assert_eq!(model.source().rows.borrow()[1], 100);
+
+        // Incremental updates
+        model.source().fetched.borrow_mut().clear();
+        model.source().rows.borrow_mut()[15] = 150;
+        model.rows_changed(15..16);
+        assert_eq!(model.row_data(15), Some(150));
+        assert_eq!(model.row_data(5), Some(5));
+        assert_eq!(*model.source().fetched.borrow(), vec![(10, 10)]);
+
+        model.source().rows.borrow_mut().insert(12, -12);
+        model.rows_inserted(12, 1);
+        assert_eq!(model.row_count(), 26);
+        assert_eq!(model.row_data(12), Some(-12));
+        assert_eq!(model.row_data(25), Some(24));
+        model.source().rows.borrow_mut().drain(0..2);
+        model.rows_removed(0, 2);
+        assert_eq!(model.row_count(), 24);
+        assert_eq!(model.row_data(0), Some(2));
+
+        model.source().rows.borrow_mut().clear();
+        model.refresh();
+        assert_eq!(model.row_count(), 0);
+        assert_eq!(model.row_data(0), None);
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the [`RingBufferModel`], a model of fixed capacity for streamed data.
+
+use super::*;
+use alloc::collections::VecDeque;
+
+/// A [`Model`] that keeps the last `capacity` values pushed into it, such as the most recent
+/// audio samples displayed by a `Waveform` widget.
+///
+/// The storage is allocated once. When the model is full, pushing values drops the oldest ones
+/// and notifies the views that the rows changed, so that the views reuse their items.
+///
+/// ```
+/// # use i_slint_core::model::{Model, RingBufferModel};
+/// let model = RingBufferModel::new(4);
+/// model.extend([1., 2., 3.]);
+/// assert_eq!(model.iter().collect::<Vec<f32>>(), vec![1., 2., 3.]);
+/// model.extend([4., 5.]);
+/// assert_eq!(model.iter().collect::<Vec<f32>>(), vec![2., 3., 4., 5.]);
+/// ```
+pub struct RingBufferModel<T> {
+    buffer: RefCell<VecDeque<T>>,
+    capacity: usize,
+    notify: ModelNotify,
+}
+
+impl<T: Clone + 'static> RingBufferModel<T> {
+    /// Creates an empty model that holds at most `capacity` rows
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            buffer: RefCell::new(VecDeque::with_capacity(capacity)),
+            capacity,
+            notify: Default::default(),
+        }
+    }
+
+    /// Returns the maximum number of rows of the model
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Appends a value at the end of the model, removing the first row if the model is full
+    pub fn push(&self, value: T) {
+        self.extend(core::iter::once(value))
+    }
+
+    /// Appends the values at the end of the model, removing the first rows if the model is full.
+    ///
+    /// The views are notified once for all the values.
+    pub fn extend(&self, values: impl IntoIterator<Item = T>) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut buffer = self.buffer.borrow_mut();
+        let old_len = buffer.len();
+        let mut dropped = false;
+        for value in values {
+            if buffer.len() == self.capacity {
+                buffer.pop_front();
+                dropped = true;
+            }
+            buffer.push_back(value);
+        }
+        let new_len = buffer.len();
+        drop(buffer);
+        if dropped {
+            // All the rows moved: report the existing rows as changed, then the added ones
+            for row in 0..old_len {
+                self.notify.row_changed(row);
+            }
+        }
+        if new_len > old_len {
+            self.notify.row_added(old_len, new_len - old_len);
+        }
+    }
+
+    /// Removes all the rows of the model
+    pub fn clear(&self) {
+        let mut buffer = self.buffer.borrow_mut();
+        let len = buffer.len();
+        buffer.clear();
+        drop(buffer);
+        if len > 0 {
+            self.notify.row_removed(0, len);
+        }
+    }
+}
+
+impl<T: Clone + 'static> Model for RingBufferModel<T> {
+    type Data = T;
+
+    fn row_count(&self) -> usize {
+        self.buffer.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        self.buffer.borrow().get(row).cloned()
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        if row < self.row_count() {
+            self.buffer.borrow_mut()[row] = data;
+            self.notify.row_changed(row);
+        }
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec;
+
+    #[derive(Default)]
+    struct Notifications {
+        changed: RefCell<Vec<usize>>,
+        added: RefCell<Vec<(usize, usize)>>,
+        removed: RefCell<Vec<(usize, usize)>>,
+    }
+
+    impl ModelObserver for Rc<Notifications> {
+        fn row_changed(&self, row: usize) {
+            self.changed.borrow_mut().push(row);
+        }
+        fn row_added(&self, index: usize, count: usize) {
+            self.added.borrow_mut().push((index, count));
+        }
+        fn row_removed(&self, index: usize, count: usize) {
+            self.removed.borrow_mut().push((index, count));
+        }
+    }
+
+    #[test]
+    fn test_ring_buffer_model() {
+        let model = RingBufferModel::new(3);
+        let notifications = Rc::new(Notifications::default());
+        let _subscription = model.subscribe(notifications.clone());
+
+        model.push(1);
+        model.extend([2, 3]);
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(*notifications.added.borrow(), vec![(0, 1), (1, 2)]);
+        assert!(notifications.changed.borrow().is_empty());
+
+        notifications.added.borrow_mut().clear();
+        model.extend([4, 5]);
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![3, 4, 5]);
+        assert_eq!(*notifications.changed.borrow(), vec![0, 1, 2]);
+        assert!(notifications.added.borrow().is_empty());
+
+        // More values than the capacity
+        model.extend(6..10);
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![7, 8, 9]);
+
+        model.set_row_data(5, 42);
+        model.set_row_data(1, 42);
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![7, 42, 9]);
+
+        model.clear();
+        assert_eq!(model.row_count(), 0);
+        assert_eq!(*notifications.removed.borrow(), vec![(0, 3)]);
+        assert_eq!(model.capacity(), 3);
+
+        let empty = RingBufferModel::new(0);
+        empty.push(1);
+        assert_eq!(empty.row_count(), 0);
+    }
+}
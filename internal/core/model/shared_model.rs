@@ -0,0 +1,323 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the [`SharedModel`], a [`VecModel`] that can be modified from any thread.
+
+use super::*;
+use crate::api::EventLoopError;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// A modification queued by a [`SharedModelSender`]
+enum Mutation<T> {
+    Push(T),
+    Insert(usize, T),
+    Remove(usize),
+    SetRowData(usize, T),
+    Extend(Vec<T>),
+    SetVec(Vec<T>),
+    Clear,
+}
+
+struct Queue<T> {
+    mutations: Vec<Mutation<T>>,
+    /// True when a call to [`SharedModel::apply_pending`] was scheduled in the event loop
+    scheduled: bool,
+    /// True when the [`SharedModel`] was dropped
+    closed: bool,
+}
+
+type QueueHandle<T> = Arc<Mutex<Queue<T>>>;
+
+crate::thread_local! {
+    /// The functions applying the queued mutations of the [`SharedModel`]s alive in this thread, by id
+    static APPLY_FUNCTIONS: RefCell<HashMap<usize, Rc<dyn Fn()>>> = RefCell::default();
+}
+
+static NEXT_ID: core::sync::atomic::AtomicUsize = core::sync::atomic::AtomicUsize::new(0);
+
+fn apply_mutations<T: Clone + 'static>(model: &VecModel<T>, queue: &Mutex<Queue<T>>) {
+    let mutations = {
+        let mut queue = queue.lock().unwrap();
+        queue.scheduled = false;
+        core::mem::take(&mut queue.mutations)
+    };
+    if mutations.is_empty() {
+        return;
+    }
+    let _batch = model.begin_batch();
+    for mutation in mutations {
+        // The sender doesn't know the row count when queuing, so out of range mutations are ignored
+        match mutation {
+            Mutation::Push(value) => model.push(value),
+            Mutation::Insert(index, value) => {
+                if index <= model.row_count() {
+                    model.insert(index, value);
+                }
+            }
+            Mutation::Remove(index) => {
+                if index < model.row_count() {
+                    model.remove(index);
+                }
+            }
+            Mutation::SetRowData(row, value) => model.set_row_data(row, value),
+            Mutation::Extend(values) => model.extend(values),
+            Mutation::SetVec(values) => model.set_vec(values),
+            Mutation::Clear => model.clear(),
+        }
+    }
+}
+
+/// A [`Model`] backed by a [`VecModel`] that can be modified from any thread through a [`SharedModelSender`].
+///
+/// The `SharedModel` itself lives in the thread running the event loop, where it is used as a model
+/// for the views. The modifications done through the senders are queued and applied to the model
+/// with [`invoke_from_event_loop()`](crate::api::invoke_from_event_loop), in the order in which
+/// they were sent.
+///
+/// ```
+/// # use i_slint_core::model::{Model, SharedModel};
+/// let model = SharedModel::from(vec![1, 2, 3]);
+/// let sender = model.sender();
+/// std::thread::spawn(move || {
+///     // Within the thread, compute the data and send it to the model
+///     sender.push(4).ok();
+///     sender.remove(0).ok();
+/// })
+/// .join()
+/// .unwrap();
+/// // The modifications are applied in the next iteration of the event loop.
+/// // Without event loop, apply them explicitly:
+/// model.apply_pending();
+/// assert_eq!(model.iter().collect::<Vec<_>>(), vec![2, 3, 4]);
+/// ```
+pub struct SharedModel<T: 'static> {
+    model: Rc<VecModel<T>>,
+    queue: QueueHandle<T>,
+    id: usize,
+}
+
+impl<T: Clone + 'static> SharedModel<T> {
+    /// Creates a new empty model
+    pub fn new() -> Self {
+        Self::from(Vec::new())
+    }
+
+    /// Returns a handle that can be sent to other threads to modify this model
+    pub fn sender(&self) -> SharedModelSender<T> {
+        SharedModelSender { queue: self.queue.clone(), id: self.id }
+    }
+
+    /// Applies the modifications queued by the senders right away, instead of waiting for the event loop.
+    pub fn apply_pending(&self) {
+        apply_mutations(&self.model, &self.queue);
+    }
+}
+
+impl<T: Clone + 'static> Default for SharedModel<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + 'static> From<Vec<T>> for SharedModel<T> {
+    fn from(array: Vec<T>) -> Self {
+        let model = Rc::new(VecModel::from(array));
+        let queue: QueueHandle<T> =
+            Arc::new(Mutex::new(Queue { mutations: Vec::new(), scheduled: false, closed: false }));
+        let id = NEXT_ID.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+        let apply: Rc<dyn Fn()> = {
+            let model = Rc::downgrade(&model);
+            let queue = queue.clone();
+            Rc::new(move || {
+                if let Some(model) = model.upgrade() {
+                    apply_mutations(&model, &queue);
+                }
+            })
+        };
+        APPLY_FUNCTIONS.with(|functions| functions.borrow_mut().insert(id, apply));
+        Self { model, queue, id }
+    }
+}
+
+impl<T: 'static> Drop for SharedModel<T> {
+    fn drop(&mut self) {
+        // The thread local might already be destroyed if the model is dropped while the thread exits
+        let _ = APPLY_FUNCTIONS.try_with(|functions| functions.borrow_mut().remove(&self.id));
+        let mut queue = self.queue.lock().unwrap();
+        queue.closed = true;
+        queue.mutations.clear();
+    }
+}
+
+impl<T: Clone + 'static> Model for SharedModel<T> {
+    type Data = T;
+
+    fn row_count(&self) -> usize {
+        self.model.row_count()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        self.model.row_data(row)
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        self.model.set_row_data(row, data)
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        self.model.model_tracker()
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+/// The write half of a [`SharedModel`].
+///
+/// This handle is `Send` and `Sync` when `T` is `Send`, and can be cloned and used from any thread.
+/// The modifications are queued, and applied to the model in the thread running the event loop.
+/// Since the row count may change before a modification is applied, the modifications that refer
+/// to rows that are out of range at that time are ignored.
+///
+/// The functions return an error if the event loop could not be notified. The modification stays
+/// in the queue and is applied with the next successful one, or by [`SharedModel::apply_pending`].
+/// Modifications sent after the `SharedModel` was dropped are discarded.
+pub struct SharedModelSender<T> {
+    queue: QueueHandle<T>,
+    id: usize,
+}
+
+impl<T> Clone for SharedModelSender<T> {
+    fn clone(&self) -> Self {
+        Self { queue: self.queue.clone(), id: self.id }
+    }
+}
+
+impl<T> core::fmt::Debug for SharedModelSender<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SharedModelSender").field("id", &self.id).finish()
+    }
+}
+
+impl<T: Send + 'static> SharedModelSender<T> {
+    fn send(&self, mutation: Mutation<T>) -> Result<(), EventLoopError> {
+        {
+            let mut queue = self.queue.lock().unwrap();
+            if queue.closed {
+                return Ok(());
+            }
+            queue.mutations.push(mutation);
+            if core::mem::replace(&mut queue.scheduled, true) {
+                return Ok(());
+            }
+        }
+        let id = self.id;
+        crate::api::invoke_from_event_loop(move || {
+            let apply = APPLY_FUNCTIONS.with(|functions| functions.borrow().get(&id).cloned());
+            if let Some(apply) = apply {
+                apply();
+            }
+        })
+        .inspect_err(|_| self.queue.lock().unwrap().scheduled = false)
+    }
+
+    /// Add a row at the end of the model
+    pub fn push(&self, value: T) -> Result<(), EventLoopError> {
+        self.send(Mutation::Push(value))
+    }
+
+    /// Inserts a row at position index. All rows after that are shifted.
+    pub fn insert(&self, index: usize, value: T) -> Result<(), EventLoopError> {
+        self.send(Mutation::Insert(index, value))
+    }
+
+    /// Remove the row at the given index from the model
+    pub fn remove(&self, index: usize) -> Result<(), EventLoopError> {
+        self.send(Mutation::Remove(index))
+    }
+
+    /// Replace the data of the row at the given index
+    pub fn set_row_data(&self, row: usize, value: T) -> Result<(), EventLoopError> {
+        self.send(Mutation::SetRowData(row, value))
+    }
+
+    /// Extend the model with the content of the iterator
+    pub fn extend(&self, iter: impl IntoIterator<Item = T>) -> Result<(), EventLoopError> {
+        self.send(Mutation::Extend(iter.into_iter().collect()))
+    }
+
+    /// Replace the content of the model
+    pub fn set_vec(&self, new: impl Into<Vec<T>>) -> Result<(), EventLoopError> {
+        self.send(Mutation::SetVec(new.into()))
+    }
+
+    /// Clears the model, removing all values
+    pub fn clear(&self) -> Result<(), EventLoopError> {
+        self.send(Mutation::Clear)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec;
+
+    #[derive(Default)]
+    struct Notifications {
+        added: RefCell<Vec<(usize, usize)>>,
+        reset: Cell<usize>,
+    }
+
+    impl ModelObserver for Rc<Notifications> {
+        fn row_added(&self, index: usize, count: usize) {
+            self.added.borrow_mut().push((index, count));
+        }
+        fn reset(&self) {
+            self.reset.set(self.reset.get() + 1);
+        }
+    }
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_shared_model() {
+        assert_send_sync::<SharedModelSender<crate::SharedString>>();
+
+        let model = SharedModel::from(vec![1, 2, 3]);
+        let notifications = Rc::new(Notifications::default());
+        let _subscription = model.subscribe(notifications.clone());
+
+        let sender = model.sender();
+        std::thread::spawn(move || {
+            // There is no event loop in this test, so the modifications are only queued
+            assert!(sender.push(4).is_err());
+            assert!(sender.remove(0).is_err());
+            assert!(sender.set_row_data(10, 42).is_err());
+            assert!(sender.remove(10).is_err());
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(model.row_count(), 3);
+        model.apply_pending();
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![2, 3, 4]);
+        assert_eq!(notifications.reset.get(), 1);
+
+        let sender = model.sender();
+        std::thread::spawn(move || {
+            assert!(sender.extend([5, 6]).is_err());
+        })
+        .join()
+        .unwrap();
+        model.apply_pending();
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![2, 3, 4, 5, 6]);
+        assert_eq!(*notifications.added.borrow(), vec![(3, 2)]);
+
+        // Nothing is queued anymore
+        model.apply_pending();
+        assert_eq!(model.row_count(), 5);
+        assert_eq!(notifications.reset.get(), 1);
+    }
+}
@@ -22,7 +22,10 @@
 use crate::item_tree::{ItemTreeRc, ItemTreeWeak, ItemVisitorResult};
 #[cfg(feature = "std")]
 use crate::items::Path;
-use crate::items::{BoxShadow, Clip, ItemRc, ItemRef, Opacity, RenderingResult, TextInput};
+use crate::items::{
+    BackdropBlur, Blend, BoxShadow, Clip, ItemRc, ItemRef, Mask, Opacity, RenderingResult,
+    ShaderEffect, TextInput,
+};
 use crate::lengths::{
     ItemTransform, LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalPx, LogicalRect,
     LogicalSize, LogicalVector,
@@ -681,6 +684,10 @@ fn filter_item(
 
     forward_rendering_call!(fn visit_clip(Clip) -> RenderingResult);
     forward_rendering_call!(fn visit_opacity(Opacity) -> RenderingResult);
+    forward_rendering_call!(fn visit_shader_effect(ShaderEffect) -> RenderingResult);
+    forward_rendering_call!(fn visit_backdrop_blur(BackdropBlur) -> RenderingResult);
+    forward_rendering_call!(fn visit_mask(Mask) -> RenderingResult);
+    forward_rendering_call!(fn visit_blend(Blend) -> RenderingResult);
 
     fn combine_clip(
         &mut self,
@@ -7,11 +7,22 @@
 
 #![warn(missing_docs)]
 
+#[cfg(feature = "alloc-stats")]
+pub use crate::alloc_stats;
 pub use crate::api::PlatformError;
 use crate::api::{LogicalPosition, LogicalSize};
-pub use crate::renderer::Renderer;
+pub use crate::frame_throttle::{FrameThrottle, VsyncSignal};
+use crate::graphics::{Rgba8Pixel, SharedPixelBuffer};
+pub use crate::process::{set_process_environment, ProcessEnvironment};
+pub use crate::renderer::{CacheStatistics, Renderer};
+pub use crate::resource_cache;
 #[cfg(feature = "software-renderer")]
 pub use crate::software_renderer;
+pub use crate::system::{
+    set_system_battery_status, set_system_locale, set_system_network_online, set_wall_clock,
+    wall_clock_changed, WallClock,
+};
+pub use crate::tray_icon::{TrayIconAdapter, TrayIconEvents};
 #[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
 use crate::unsafe_single_threaded::OnceCell;
 pub use crate::window::{LayoutConstraints, WindowAdapter, WindowProperties};
@@ -102,6 +113,17 @@ fn duration_since_start(&self) -> core::time::Duration {
         unimplemented!("The platform abstraction must implement `duration_since_start`")
     }
 
+    /// Slint calls this function when the windows need to be redrawn, or the timers updated with
+    /// [`update_timers_and_animations()`], at the given time at the latest. The `deadline` is a
+    /// duration since the start of the program, like [`Self::duration_since_start()`].
+    ///
+    /// The deadline is the current time when a window needs to be redrawn, for example when an
+    /// animation is running, and the time the next timer expires otherwise.
+    ///
+    /// Platforms that drive their own event loop can forward this function to a [`FrameThrottle`].
+    /// The default implementation does nothing.
+    fn request_redraw_at(&self, _deadline: core::time::Duration) {}
+
     /// Returns the current interval to internal measure the duration to send a double click event.
     ///
     /// A double click event is a series of two pointer clicks.
@@ -132,6 +154,90 @@ fn clipboard_text(&self, _clipboard: Clipboard) -> Option<String> {
         None
     }
 
+    /// Sends the given data into the system clipboard, replacing its previous content. All representations
+    /// set in `data` are offered together, so that the application pasting can choose the one it supports best.
+    ///
+    /// The default implementation only stores the text, using [`Self::set_clipboard_text`].
+    /// If the platform doesn't support the specified clipboard, this function should do nothing
+    fn set_clipboard_data(&self, data: ClipboardData, clipboard: Clipboard) {
+        if let Some(text) = data.text {
+            self.set_clipboard_text(&text, clipboard);
+        }
+    }
+
+    /// Returns a copy of the data stored in the system clipboard, if any.
+    ///
+    /// The default implementation only provides the text, using [`Self::clipboard_text`].
+    /// If the platform doesn't support the specified clipboard, the function should return None
+    fn clipboard_data(&self, clipboard: Clipboard) -> Option<ClipboardData> {
+        self.clipboard_text(clipboard)
+            .map(|text| ClipboardData { text: Some(text), ..Default::default() })
+    }
+
+    /// Opens the given url with the application the user configured for it, such as the web browser.
+    ///
+    /// This is called by `@open-url(...)` in .slint files, after the handler set with
+    /// [`set_open_url_handler()`](crate::api::set_open_url_handler) allowed it.
+    /// The default implementation returns an error.
+    fn open_url(&self, _url: &str) -> Result<(), PlatformError> {
+        Err(PlatformError::Other("Opening urls is not supported by this platform".into()))
+    }
+
+    /// Returns the screens connected to the computer, for [`screens()`](crate::api::screens).
+    ///
+    /// The default implementation returns an error.
+    fn screens(&self) -> Result<alloc::vec::Vec<crate::api::Screen>, PlatformError> {
+        Err(PlatformError::Other("Listing the screens is not supported by this platform".into()))
+    }
+
+    /// Shows a native dialog to open or save a file, or to pick a folder, for the functions of
+    /// the [`dialogs`](crate::dialogs) module. The returned future is polled on the event loop and
+    /// resolves to the selected path, or `None` if the user canceled the dialog.
+    ///
+    /// The dialog must not block the event loop while it's open. Make it modal for the window returned
+    /// by [`FileDialogOptions::parent_window_adapter()`](crate::dialogs::FileDialogOptions::parent_window_adapter).
+    ///
+    /// The default implementation returns an error.
+    #[cfg(feature = "std")]
+    fn show_file_dialog(
+        &self,
+        _kind: crate::dialogs::FileDialogKind,
+        _options: crate::dialogs::FileDialogOptions,
+    ) -> core::pin::Pin<
+        Box<dyn core::future::Future<Output = Result<Option<std::path::PathBuf>, PlatformError>>>,
+    > {
+        Box::pin(core::future::ready(Err(PlatformError::Other(
+            "File dialogs are not supported by this platform".into(),
+        ))))
+    }
+
+    /// Lets the user pick the color of a pixel on the screen, for the eyedropper of the `ColorPicker`
+    /// widget. The returned future is polled on the event loop and resolves to the picked color,
+    /// or `None` if the user canceled.
+    ///
+    /// The default implementation returns an error, and the widget invokes its `eyedropper-requested`
+    /// callback instead.
+    fn pick_screen_color(
+        &self,
+    ) -> core::pin::Pin<
+        Box<dyn core::future::Future<Output = Result<Option<crate::Color>, PlatformError>>>,
+    > {
+        Box::pin(core::future::ready(Err(PlatformError::Other(
+            "Picking colors from the screen is not supported by this platform".into(),
+        ))))
+    }
+
+    /// Creates an icon in the system tray, for [`TrayIcon::new()`](crate::api::TrayIcon::new).
+    /// The implementation reports the interactions with the icon through `events`.
+    ///
+    /// The default implementation returns an error.
+    fn create_tray_icon(
+        &self,
+        _events: TrayIconEvents,
+    ) -> Result<Box<dyn TrayIconAdapter>, PlatformError> {
+        Err(PlatformError::Other("Tray icons are not supported by this platform".into()))
+    }
+
     /// This function is called when debug() is used in .slint files. The implementation
     /// should direct the output to some developer visible terminal. The default implementation
     /// uses stderr if available, or `console.log` when targeting wasm.
@@ -163,6 +269,21 @@ pub enum Clipboard {
     SelectionClipboard = 1,
 }
 
+/// The content of a clipboard, used in [`Platform::clipboard_data`] and [`Platform::set_clipboard_data`]
+///
+/// The same content can be offered in several representations at once, for example
+/// as HTML with a plain text fallback.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default)]
+pub struct ClipboardData {
+    /// The plain text representation.
+    pub text: Option<String>,
+    /// The HTML representation (`text/html`).
+    pub html: Option<String>,
+    /// The image representation.
+    pub image: Option<SharedPixelBuffer<Rgba8Pixel>>,
+}
+
 /// Trait that is returned by the [`Platform::new_event_loop_proxy`]
 ///
 /// This are the implementation details for the function that may need to
@@ -268,6 +389,20 @@ pub fn update_timers_and_animations() {
     crate::animations::update_animations();
     crate::timers::TimerList::maybe_activate_timers(crate::animations::Instant::now());
     crate::properties::ChangeTracker::run_change_handlers();
+    crate::resource_cache::dispatch_notifications();
+    if let Some(timeout) = crate::timers::TimerList::next_timeout() {
+        request_redraw_at(Some(core::time::Duration::from_millis(timeout.0)));
+    }
+}
+
+/// Forwards to [`Platform::request_redraw_at()`], with the current time if `deadline` is `None`.
+pub(crate) fn request_redraw_at(deadline: Option<core::time::Duration>) {
+    crate::context::GLOBAL_CONTEXT.with(|ctx| {
+        if let Some(ctx) = ctx.get() {
+            let platform = ctx.platform();
+            platform.request_redraw_at(deadline.unwrap_or_else(|| platform.duration_since_start()));
+        }
+    })
 }
 
 /// Returns the duration before the next timer is expected to be activated. This is the
@@ -292,6 +427,7 @@ pub fn duration_until_next_timer_update() -> Option<core::time::Duration> {
 
 // reexport key enum to the public api
 pub use crate::input::key_codes::Key;
+pub use crate::input::GestureEvent;
 pub use crate::input::PointerEventButton;
 
 /// A event that describes user input or windowing system events.
@@ -332,6 +468,15 @@ pub enum WindowEvent {
     },
     /// The pointer exited the window.
     PointerExited,
+    /// A gesture was performed on a touchpad while the pointer was at `position`.
+    ///
+    /// Backends that receive gestures, such as pinching to zoom, from the input devices dispatch
+    /// them with this event. They are delivered to the elements under the pointer.
+    PointerGesture {
+        position: LogicalPosition,
+        /// The state of the gesture.
+        gesture: GestureEvent,
+    },
     /// A key was pressed.
     KeyPressed {
         /// The unicode representation of the key pressed.
@@ -412,6 +557,7 @@ pub fn position(&self) -> Option<LogicalPosition> {
             WindowEvent::PointerReleased { position, .. } => Some(*position),
             WindowEvent::PointerMoved { position } => Some(*position),
             WindowEvent::PointerScrolled { position, .. } => Some(*position),
+            WindowEvent::PointerGesture { position, .. } => Some(*position),
             _ => None,
         }
     }
@@ -0,0 +1,171 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+This module contains the functions behind the `Process` namespace in .slint files.
+
+The namespace gives .slint files access to the command line arguments and the environment
+variables, and lets them exit the application. All accesses go through the [`ProcessEnvironment`]
+set by the application, so that it stays in control of what the UI can see and do.
+*/
+
+use crate::model::{ModelRc, VecModel};
+use crate::SharedString;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+
+/// The hooks through which the `Process` namespace in .slint files accesses the process.
+///
+/// With the `process` feature, the default implementation uses the arguments and the environment
+/// of the current process, and exits the process. Without it, there are no arguments nor environment
+/// variables, and exiting does nothing. Set another implementation with [`set_process_environment()`],
+/// for example to hide some environment variables from the UI or to save data before exiting.
+pub trait ProcessEnvironment {
+    /// Returns the command line arguments, for `Process.arguments`. The first one is usually
+    /// the path of the executable.
+    fn arguments(&self) -> Vec<SharedString>;
+
+    /// Returns the value of the environment variable `name`, for `Process.env(name)`, or `None`
+    /// if it is not set.
+    fn env_var(&self, name: &str) -> Option<SharedString>;
+
+    /// Called by `Process.exit(code)` to exit the application with the given exit code.
+    fn exit(&self, code: i32);
+}
+
+/// The default [`ProcessEnvironment`]
+struct DefaultProcessEnvironment;
+
+#[cfg(feature = "process")]
+impl ProcessEnvironment for DefaultProcessEnvironment {
+    fn arguments(&self) -> Vec<SharedString> {
+        std::env::args_os().map(|arg| arg.to_string_lossy().as_ref().into()).collect()
+    }
+
+    fn env_var(&self, name: &str) -> Option<SharedString> {
+        std::env::var_os(name).map(|value| value.to_string_lossy().as_ref().into())
+    }
+
+    fn exit(&self, code: i32) {
+        std::process::exit(code)
+    }
+}
+
+#[cfg(not(feature = "process"))]
+impl ProcessEnvironment for DefaultProcessEnvironment {
+    fn arguments(&self) -> Vec<SharedString> {
+        Vec::new()
+    }
+
+    fn env_var(&self, _name: &str) -> Option<SharedString> {
+        None
+    }
+
+    fn exit(&self, _code: i32) {}
+}
+
+crate::thread_local! {
+    static PROCESS_ENVIRONMENT: RefCell<Option<Box<dyn ProcessEnvironment>>> = RefCell::new(None)
+}
+
+fn with_process_environment<R>(f: impl FnOnce(&dyn ProcessEnvironment) -> R) -> R {
+    PROCESS_ENVIRONMENT.with(|env| match env.borrow().as_deref() {
+        Some(env) => f(env),
+        None => f(&DefaultProcessEnvironment),
+    })
+}
+
+/// Sets the hooks used by the `Process` namespace in .slint files.
+pub fn set_process_environment(environment: Box<dyn ProcessEnvironment>) {
+    PROCESS_ENVIRONMENT.with(|env| *env.borrow_mut() = Some(environment));
+}
+
+/// Returns the command line arguments as a model, for `Process.arguments`
+pub fn process_arguments() -> ModelRc<SharedString> {
+    ModelRc::new(VecModel::from(with_process_environment(|env| env.arguments())))
+}
+
+/// Returns the value of an environment variable, or an empty string if it's not set, for `Process.env(name)`
+pub fn process_env(name: &str) -> SharedString {
+    with_process_environment(|env| env.env_var(name)).unwrap_or_default()
+}
+
+/// Exits the application, for `Process.exit(code)`
+pub fn process_exit(code: i32) {
+    // Don't keep the environment borrowed, in case the hook accesses it
+    let environment = PROCESS_ENVIRONMENT.with(|env| env.borrow_mut().take());
+    match &environment {
+        Some(env) => env.exit(code),
+        None => DefaultProcessEnvironment.exit(code),
+    }
+    PROCESS_ENVIRONMENT.with(|env| {
+        let mut env = env.borrow_mut();
+        if env.is_none() {
+            *env = environment;
+        }
+    });
+}
+
+#[cfg(feature = "ffi")]
+mod ffi {
+    #![allow(unsafe_code)]
+
+    use super::*;
+    use crate::SharedVector;
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_process_arguments(out: &mut SharedVector<SharedString>) {
+        *out = with_process_environment(|env| env.arguments()).into_iter().collect();
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_process_env(name: &SharedString, out: &mut SharedString) {
+        *out = process_env(name)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_process_exit(code: i32) {
+        process_exit(code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Model;
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    struct TestEnvironment(Rc<Cell<Option<i32>>>);
+
+    impl ProcessEnvironment for TestEnvironment {
+        fn arguments(&self) -> Vec<SharedString> {
+            alloc::vec!["app".into(), "--verbose".into()]
+        }
+
+        fn env_var(&self, name: &str) -> Option<SharedString> {
+            (name == "HOME").then(|| "/home/user".into())
+        }
+
+        fn exit(&self, code: i32) {
+            self.0.set(Some(code));
+        }
+    }
+
+    #[test]
+    fn test_process_environment() {
+        let exit_code = Rc::new(Cell::new(None));
+        set_process_environment(Box::new(TestEnvironment(exit_code.clone())));
+        let arguments = process_arguments();
+        assert_eq!(arguments.row_count(), 2);
+        assert_eq!(arguments.row_data(1), Some("--verbose".into()));
+        assert_eq!(process_env("HOME"), "/home/user");
+        assert_eq!(process_env("PATH"), "");
+        process_exit(3);
+        assert_eq!(exit_code.get(), Some(3));
+        // The environment is still set after exiting
+        process_exit(4);
+        assert_eq!(exit_code.get(), Some(4));
+    }
+}
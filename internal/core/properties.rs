@@ -49,6 +49,32 @@ pub fn push_front(&mut self, value: T) -> Pin<&T> {
             unsafe { Pin::new_unchecked(&self.0.as_ref().unwrap().value) }
         }
 
+        /// Same as `push_front`, but reuses the allocation of the first node of `spare` if any.
+        ///
+        /// Safety: nothing may point to the values of the nodes of `spare`, as they are replaced.
+        pub unsafe fn push_front_reusing(&mut self, value: T, spare: &mut Self) -> Pin<&T> {
+            let Some(mut node) = spare.0.take() else { return self.push_front(value) };
+            // Safety: the caller guarantees that the value isn't referenced, so it can be replaced
+            let node_mut = unsafe { Pin::get_unchecked_mut(node.as_mut()) };
+            spare.0 = node_mut.next.take();
+            node_mut.value = value;
+            node_mut.next = self.0.take();
+            self.0 = Some(node);
+            // Safety: we can project from SingleLinkedListPinNode
+            unsafe { Pin::new_unchecked(&self.0.as_ref().unwrap().value) }
+        }
+
+        /// Moves all the nodes of this list to the front of `other`
+        pub fn move_to(&mut self, other: &mut Self) {
+            while let Some(mut node) = self.0.take() {
+                // Safety: we don't touch the `node.value` which is the one protected by the Pin
+                let node_mut = unsafe { Pin::get_unchecked_mut(node.as_mut()) };
+                self.0 = node_mut.next.take();
+                node_mut.next = other.0.take();
+                other.0 = Some(node);
+            }
+        }
+
         #[allow(unused)]
         pub fn iter(&self) -> impl Iterator<Item = Pin<&T>> {
             struct I<'a, T>(&'a NodePtr<T>);
@@ -81,6 +107,32 @@ fn test_list() {
         );
     }
     #[test]
+    fn reuse_nodes() {
+        let mut head = SingleLinkedListPinHead::default();
+        let mut spare = SingleLinkedListPinHead::default();
+        head.push_front(1);
+        head.push_front(2);
+        let nodes = |head: &SingleLinkedListPinHead<i32>| {
+            let mut nodes = head
+                .iter()
+                .map(|x: Pin<&i32>| x.get_ref() as *const i32)
+                .collect::<std::vec::Vec<_>>();
+            nodes.sort();
+            nodes
+        };
+        let allocated = nodes(&head);
+        head.move_to(&mut spare);
+        assert!(head.iter().next().is_none());
+        unsafe { head.push_front_reusing(3, &mut spare) };
+        unsafe { head.push_front_reusing(4, &mut spare) };
+        assert!(spare.iter().next().is_none());
+        assert_eq!(nodes(&head), allocated);
+        assert_eq!(
+            head.iter().map(|x: Pin<&i32>| *x.get_ref()).collect::<std::vec::Vec<i32>>(),
+            std::vec![4, 3]
+        );
+    }
+    #[test]
     fn big_list() {
         // should not stack overflow
         let mut head = SingleLinkedListPinHead::default();
@@ -274,6 +326,13 @@ fn drop(&mut self) {
 /// constant and cannot have dependencies
 static CONSTANT_PROPERTY_SENTINEL: u32 = 0;
 
+crate::thread_local! {
+    /// The nodes of the dependencies that were removed before re-evaluating a binding, so that
+    /// registering the new dependencies doesn't allocate
+    static SPARE_DEP_NODES: Cell<single_linked_list_pin::SingleLinkedListPinHead<DependencyNode>> =
+        Default::default()
+}
+
 /// The return value of a binding
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 enum BindingResult {
@@ -414,12 +473,31 @@ fn register_self_as_dependency(
     ) {
         let node = DependencyNode::new(self.get_ref() as *const _);
         let mut dep_nodes = self.dep_nodes.take();
-        let node = dep_nodes.push_front(node);
+        let mut spare_nodes = SPARE_DEP_NODES.try_with(Cell::take).unwrap_or_default();
+        // Safety: the spare nodes were removed from the dependency lists by `clear_dep_nodes()`
+        let node = unsafe { dep_nodes.push_front_reusing(node, &mut spare_nodes) };
+        let _ = SPARE_DEP_NODES.try_with(|spare| spare.set(spare_nodes));
         unsafe { DependencyListHead::append(&*property_that_will_notify, node) }
         self.dep_nodes.set(dep_nodes);
     }
 }
 
+impl<B> BindingHolder<B> {
+    /// Removes the binding from the dependency lists of the properties, so that it can be
+    /// re-evaluated from scratch. The nodes are kept to register the new dependencies.
+    fn clear_dep_nodes(&self) {
+        let mut dep_nodes = self.dep_nodes.take();
+        for node in dep_nodes.iter() {
+            node.remove();
+        }
+        let _ = SPARE_DEP_NODES.try_with(|spare| {
+            let mut spare_nodes = spare.take();
+            dep_nodes.move_to(&mut spare_nodes);
+            spare.set(spare_nodes);
+        });
+    }
+}
+
 fn alloc_binding_holder<B: BindingCallable + 'static>(binding: B) -> *mut BindingHolder {
     /// Safety: _self must be a pointer that comes from a `Box<BindingHolder<B>>::into_raw()`
     unsafe fn binding_drop<B>(_self: *mut BindingHolder) {
@@ -640,7 +718,7 @@ unsafe fn update<T>(&self, value: *mut T) {
             if let Some(mut binding) = binding {
                 if binding.dirty.get() {
                     // clear all the nodes so that we can start from scratch
-                    binding.dep_nodes.set(Default::default());
+                    binding.clear_dep_nodes();
                     let r = (binding.vtable.evaluate)(
                         binding.as_mut().get_unchecked_mut() as *mut BindingHolder,
                         value as *mut (),
@@ -1124,6 +1202,27 @@ unsafe fn intercept_set_binding(
             );
         }
     }
+
+    /// Calls `callback` with the new value when the value of this property changes.
+    ///
+    /// The callback is called until the returned [`PropertySubscription`] is dropped.
+    /// It is not called immediately when the property changes, but later when the event loop runs
+    /// the change handlers, so that a property that changes several times in a row only
+    /// results in a single call.
+    pub fn on_change(
+        self: Pin<Rc<Self>>,
+        mut callback: impl FnMut(&T) + 'static,
+    ) -> PropertySubscription {
+        PropertySubscription::new(
+            self,
+            |prop| Some(prop.as_ref().get()),
+            move |value: &Option<T>| {
+                if let Some(value) = value {
+                    callback(value)
+                }
+            },
+        )
+    }
 }
 
 #[test]
@@ -1472,7 +1571,7 @@ pub fn evaluate<R>(self: Pin<&Self>, f: impl FnOnce() -> R) -> R {
     /// any changes to accessed properties will not propagate to the other tracker.
     pub fn evaluate_as_dependency_root<R>(self: Pin<&Self>, f: impl FnOnce() -> R) -> R {
         // clear all the nodes so that we can start from scratch
-        self.holder.dep_nodes.set(Default::default());
+        self.holder.clear_dep_nodes();
 
         // Safety: it is safe to project the holder as we don't implement drop or unpin
         let pinned_holder = unsafe {
@@ -222,6 +222,41 @@ pub(super) unsafe fn set_internal(&self, raw: *mut BindingHolder) {
     }
 }
 
+/// A handle to a callback that is called when the value of a property changes,
+/// as returned by [`Property::on_change`](super::Property::on_change).
+///
+/// The callback is called until this handle is dropped.
+/// Like change callbacks declared in `.slint` files, the callback is not called immediately when
+/// the property changes, but later, when the event loop runs the queued change handlers.
+#[must_use = "the callback is no longer called when the PropertySubscription is dropped"]
+pub struct PropertySubscription {
+    // Dropping the tracker removes it from the dependencies of the property
+    _tracker: ChangeTracker,
+}
+
+impl PropertySubscription {
+    /// Creates a subscription that calls `callback` when the value returned by `eval_fn` changes.
+    ///
+    /// `eval_fn` is evaluated once when the subscription is created, and the callback is only called
+    /// for the subsequent changes.
+    pub fn new<Data: 'static, T: Default + PartialEq + 'static>(
+        data: Data,
+        eval_fn: impl Fn(&Data) -> T + 'static,
+        callback: impl FnMut(&T) + 'static,
+    ) -> Self {
+        let callback = core::cell::RefCell::new(callback);
+        let tracker = ChangeTracker::default();
+        tracker.init(data, eval_fn, move |_, value| (callback.borrow_mut())(value));
+        Self { _tracker: tracker }
+    }
+}
+
+impl core::fmt::Debug for PropertySubscription {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PropertySubscription").finish_non_exhaustive()
+    }
+}
+
 #[test]
 fn change_tracker() {
     use super::Property;
@@ -383,3 +418,36 @@ fn change_mutliple_dependencies() {
     ChangeTracker::run_change_handlers();
     assert_eq!(result.borrow().as_str(), "[13][31][94]");
 }
+
+#[test]
+fn property_subscription() {
+    use super::Property;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::vec::Vec;
+    let prop = Rc::pin(Property::new(1));
+    let values = Rc::new(RefCell::new(Vec::new()));
+
+    let subscription = prop.clone().on_change({
+        let values = values.clone();
+        move |value| values.borrow_mut().push(*value)
+    });
+    ChangeTracker::run_change_handlers();
+    assert!(values.borrow().is_empty());
+
+    prop.as_ref().set(2);
+    prop.as_ref().set(3);
+    assert!(values.borrow().is_empty());
+    ChangeTracker::run_change_handlers();
+    assert_eq!(*values.borrow(), [3]);
+
+    // Setting the same value doesn't call the callback
+    prop.as_ref().set(3);
+    ChangeTracker::run_change_handlers();
+    assert_eq!(*values.borrow(), [3]);
+
+    drop(subscription);
+    prop.as_ref().set(4);
+    ChangeTracker::run_change_handlers();
+    assert_eq!(*values.borrow(), [3]);
+}
@@ -275,6 +275,27 @@ pub fn set_animated_value(&self, value: T, animation_data: PropertyAnimation) {
         );
     }
 
+    /// Change the value of this property by animating it from its current value to `value`,
+    /// over the given `duration`, following the `easing` curve.
+    ///
+    /// This is a shortcut for [`Self::set_animated_value`] with an animation that has no delay
+    /// and runs once.
+    pub fn set_animated(
+        &self,
+        value: T,
+        duration: core::time::Duration,
+        easing: crate::animations::EasingCurve,
+    ) {
+        self.set_animated_value(
+            value,
+            PropertyAnimation {
+                duration: duration.as_millis().try_into().unwrap_or(i32::MAX),
+                easing,
+                ..Default::default()
+            },
+        )
+    }
+
     /// Set a binding to this property.
     ///
     pub fn set_animated_binding(
@@ -472,6 +493,27 @@ fn properties_test_animation_triggered_by_set() {
         compo.width.handle.access(|binding| assert!(binding.is_none()));
     }
 
+    #[test]
+    fn properties_test_set_animated() {
+        let compo = Component::new_test_component();
+
+        compo.width.set(100);
+        let start_time = crate::animations::current_tick();
+
+        compo.width.set_animated(200, DURATION, crate::animations::EasingCurve::Linear);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 2));
+        assert_eq!(get_prop_value(&compo.width), 150);
+        assert_eq!(get_prop_value(&compo.width_times_two), 300);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION));
+        assert_eq!(get_prop_value(&compo.width), 200);
+        compo.width.handle.access(|binding| assert!(binding.is_none()));
+    }
+
     #[test]
     fn properties_test_delayed_animation_triggered_by_set() {
         let compo = Component::new_test_component();
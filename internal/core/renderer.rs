@@ -18,9 +18,59 @@
 /// meaning that you are not expected to implement this trait
 /// yourself, but you should use the provided one from Slint such as
 /// [`SoftwareRenderer`](crate::software_renderer::SoftwareRenderer)
-pub trait Renderer: RendererSealed {}
+pub trait Renderer: RendererSealed {
+    /// Returns the memory used by the caches of the renderer, to tune the limits of an embedded
+    /// device with the functions of the [`resource_cache`](crate::resource_cache) module.
+    fn cache_statistics(&self) -> CacheStatistics {
+        self.collect_cache_statistics()
+    }
+}
 impl<T: RendererSealed> Renderer for T {}
 
+/// The memory used by the caches of a renderer, returned by [`Renderer::cache_statistics()`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct CacheStatistics {
+    /// The size in bytes of the decoded images in the image cache, which is shared by all renderers.
+    pub image_cache_bytes: usize,
+    /// The size in bytes of the rasterized glyphs cached by the renderer, or `None` if it's unknown.
+    pub glyph_cache_bytes: Option<usize>,
+    /// The fraction of the limit of the glyph cache in use, from 0 to 1, or `None` if the
+    /// cache isn't limited.
+    pub glyph_cache_occupancy: Option<f32>,
+    /// The GPU memory in bytes used for textures, or `None` if the renderer can't query it.
+    pub texture_memory_bytes: Option<usize>,
+}
+
+impl CacheStatistics {
+    /// Returns the statistics of the caches shared by all the renderers, to be completed by the
+    /// renderer.
+    pub fn shared() -> Self {
+        Self {
+            image_cache_bytes: crate::resource_cache::cache_usage(
+                crate::resource_cache::ResourceCache::Images,
+            ),
+            ..Default::default()
+        }
+    }
+}
+
+impl core::fmt::Display for CacheStatistics {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "images: {} KiB", self.image_cache_bytes / 1024)?;
+        if let Some(glyph_cache_bytes) = self.glyph_cache_bytes {
+            write!(f, ", glyphs: {} KiB", glyph_cache_bytes / 1024)?;
+        }
+        if let Some(occupancy) = self.glyph_cache_occupancy {
+            write!(f, " ({:.0}%)", occupancy * 100.)?;
+        }
+        if let Some(texture_memory_bytes) = self.texture_memory_bytes {
+            write!(f, ", textures: {} KiB", texture_memory_bytes / 1024)?;
+        }
+        Ok(())
+    }
+}
+
 /// Implementation details behind [`Renderer`], but since this
 /// trait is not exported in the public API, it is not possible for the
 /// users to re-implement these functions.
@@ -67,6 +117,20 @@ fn text_input_cursor_rect_for_byte_offset(
         scale_factor: ScaleFactor,
     ) -> LogicalRect;
 
+    /// Like [`Self::text_input_byte_offset_for_position`], but for the text of a `Text` or `MarkdownText`
+    /// element of the given size. This is used for the selection of the elements with `selectable: true`.
+    /// For markdown, the byte offset is in the text as it is displayed, without the markup.
+    fn text_byte_offset_for_position(
+        &self,
+        _text: Pin<&dyn crate::item_rendering::RenderText>,
+        _size: LogicalSize,
+        _pos: LogicalPoint,
+        _font_request: crate::graphics::FontRequest,
+        _scale_factor: ScaleFactor,
+    ) -> usize {
+        0
+    }
+
     /// Clear the caches for the items that are being removed
     fn free_graphics_resources(
         &self,
@@ -132,4 +196,10 @@ fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError>
 
     /// Whether the renderer supports transformations such as rotations and scaling or not.
     fn supports_transformations(&self) -> bool;
+
+    /// Implementation of [`Renderer::cache_statistics()`]. The default implementation only
+    /// reports the caches shared by all renderers.
+    fn collect_cache_statistics(&self) -> CacheStatistics {
+        CacheStatistics::shared()
+    }
 }
@@ -0,0 +1,309 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+Limits of the caches of decoded images and rasterized glyphs.
+
+Decoded images and the glyphs rasterized by the software renderer are cached, so that they don't
+need to be decoded or rasterized again. The caches are bounded by a size in bytes: when an entry is
+added to a full cache, the least recently used entries are evicted. Devices with little memory can
+lower the limits, and be notified of the evictions and of the resources that are too large to be
+cached at all.
+
+The caches belong to the thread running the Slint event loop, the functions of this module must be
+called from that thread. The handlers are called from the event loop, in
+[`update_timers_and_animations()`](crate::platform::update_timers_and_animations), after the caches
+changed. They can change the limits or clear the caches.
+
+```rust,no_run
+use slint::platform::resource_cache::{self, ResourceCache};
+
+resource_cache::set_cache_limit(ResourceCache::Images, 512 * 1024);
+resource_cache::set_out_of_memory_handler(|cache, size| {
+    eprintln!("A resource of {size} bytes doesn't fit in the {cache:?} cache");
+    resource_cache::clear_cache(ResourceCache::Glyphs);
+});
+```
+*/
+
+#![warn(missing_docs)]
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+
+/// A cache whose size is limited, for the functions of this module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceCache {
+    /// The images decoded from files or from data embedded in the binary, shared by all renderers.
+    Images,
+    /// The glyphs rasterized from vector fonts by the software renderer.
+    Glyphs,
+}
+
+impl ResourceCache {
+    /// Returns the size in bytes the cache is limited to, until it's changed with [`set_cache_limit()`].
+    pub const fn default_limit(self) -> usize {
+        match self {
+            Self::Images => 5 * 1024 * 1024,
+            Self::Glyphs => 1024 * 1024,
+        }
+    }
+}
+
+type CacheHandler = Box<dyn Fn(ResourceCache, usize)>;
+
+#[derive(Default)]
+struct CacheNotifications {
+    eviction_handler: Option<CacheHandler>,
+    out_of_memory_handler: Option<CacheHandler>,
+    /// The evicted sizes, reported to the eviction handler
+    evicted: Vec<(ResourceCache, usize)>,
+    /// The sizes of the resources that were too large, reported to the out of memory handler
+    out_of_memory: Vec<(ResourceCache, usize)>,
+}
+
+crate::thread_local!(static NOTIFICATIONS: RefCell<CacheNotifications> = Default::default());
+
+/// Sets the maximum size of the cache in bytes, and evicts the least recently used entries until
+/// the cache fits. A limit of zero disables the cache.
+pub fn set_cache_limit(cache: ResourceCache, limit_in_bytes: usize) {
+    match cache {
+        ResourceCache::Images => {
+            #[cfg(feature = "image-decoders")]
+            crate::graphics::cache::IMAGE_CACHE.with(|c| {
+                resize(cache, &mut c.borrow_mut().0, limit_in_bytes, &|k, v| {
+                    clru::WeightScale::weight(&crate::graphics::cache::ImageWeightInBytes, k, v)
+                })
+            });
+        }
+        ResourceCache::Glyphs => {
+            #[cfg(feature = "software-renderer-systemfonts")]
+            crate::software_renderer::fonts::vectorfont::GLYPH_CACHE.with(|c| {
+                resize(cache, &mut *c.borrow_mut(), limit_in_bytes, &|k, v| {
+                    clru::WeightScale::weight(
+                        &crate::software_renderer::fonts::vectorfont::RenderableGlyphWeightScale,
+                        k,
+                        v,
+                    )
+                })
+            });
+        }
+    }
+    let _ = limit_in_bytes;
+}
+
+/// Returns the maximum size of the cache in bytes.
+pub fn cache_limit(cache: ResourceCache) -> usize {
+    match cache {
+        #[cfg(feature = "image-decoders")]
+        ResourceCache::Images => crate::graphics::cache::IMAGE_CACHE.with(|c| limit(&c.borrow().0)),
+        #[cfg(feature = "software-renderer-systemfonts")]
+        ResourceCache::Glyphs => {
+            crate::software_renderer::fonts::vectorfont::GLYPH_CACHE.with(|c| limit(&*c.borrow()))
+        }
+        #[allow(unreachable_patterns)]
+        _ => cache.default_limit(),
+    }
+}
+
+/// Returns the size in bytes of the entries currently in the cache.
+pub fn cache_usage(cache: ResourceCache) -> usize {
+    match cache {
+        #[cfg(feature = "image-decoders")]
+        ResourceCache::Images => {
+            crate::graphics::cache::IMAGE_CACHE.with(|c| c.borrow().0.weight())
+        }
+        #[cfg(feature = "software-renderer-systemfonts")]
+        ResourceCache::Glyphs => {
+            crate::software_renderer::fonts::vectorfont::GLYPH_CACHE.with(|c| c.borrow().weight())
+        }
+        #[allow(unreachable_patterns)]
+        _ => 0,
+    }
+}
+
+/// Removes all the entries from the cache. Resources still in use stay alive until they're no
+/// longer used, but they'll be decoded or rasterized again the next time they're needed.
+pub fn clear_cache(cache: ResourceCache) {
+    let evicted = cache_usage(cache);
+    match cache {
+        ResourceCache::Images => {
+            #[cfg(feature = "image-decoders")]
+            crate::graphics::cache::IMAGE_CACHE.with(|c| c.borrow_mut().0.clear());
+        }
+        ResourceCache::Glyphs => {
+            #[cfg(feature = "software-renderer-systemfonts")]
+            crate::software_renderer::fonts::vectorfont::GLYPH_CACHE
+                .with(|c| c.borrow_mut().clear());
+        }
+    }
+    if evicted > 0 {
+        NOTIFICATIONS.with(|n| n.borrow_mut().evicted.push((cache, evicted)));
+    }
+}
+
+/// Sets the handler called with the cache and the number of bytes evicted from it, when entries
+/// are evicted to make room for new ones, to apply a lower limit, or because the cache was cleared.
+pub fn set_eviction_handler(handler: impl Fn(ResourceCache, usize) + 'static) {
+    NOTIFICATIONS.with(|n| n.borrow_mut().eviction_handler = Some(Box::new(handler)));
+}
+
+/// Sets the handler called with the cache and the size in bytes of a resource larger than the limit
+/// of the cache. The resource is used, but it isn't cached and is decoded or rasterized again each
+/// time it's needed. The handler can free memory, or raise the limit with [`set_cache_limit()`].
+pub fn set_out_of_memory_handler(handler: impl Fn(ResourceCache, usize) + 'static) {
+    NOTIFICATIONS.with(|n| n.borrow_mut().out_of_memory_handler = Some(Box::new(handler)));
+}
+
+/// Calls the handlers with the notifications queued since the last call.
+pub(crate) fn dispatch_notifications() {
+    let (evicted, out_of_memory) = NOTIFICATIONS.with(|n| {
+        let mut n = n.borrow_mut();
+        (core::mem::take(&mut n.evicted), core::mem::take(&mut n.out_of_memory))
+    });
+    if evicted.is_empty() && out_of_memory.is_empty() {
+        return;
+    }
+    // Take the handlers out while they run, so that they can replace themselves
+    let (eviction_handler, out_of_memory_handler) = NOTIFICATIONS.with(|n| {
+        let mut n = n.borrow_mut();
+        (n.eviction_handler.take(), n.out_of_memory_handler.take())
+    });
+    if let Some(handler) = &eviction_handler {
+        evicted.into_iter().for_each(|(cache, size)| handler(cache, size));
+    }
+    if let Some(handler) = &out_of_memory_handler {
+        out_of_memory.into_iter().for_each(|(cache, size)| handler(cache, size));
+    }
+    NOTIFICATIONS.with(|n| {
+        let mut n = n.borrow_mut();
+        if n.eviction_handler.is_none() {
+            n.eviction_handler = eviction_handler;
+        }
+        if n.out_of_memory_handler.is_none() {
+            n.out_of_memory_handler = out_of_memory_handler;
+        }
+    });
+}
+
+#[cfg(any(feature = "image-decoders", feature = "software-renderer-systemfonts"))]
+type Cache<K, V, W> = clru::CLruCache<K, V, std::collections::hash_map::RandomState, W>;
+
+#[cfg(any(feature = "image-decoders", feature = "software-renderer-systemfonts"))]
+fn limit<K: Eq + core::hash::Hash, V, W: clru::WeightScale<K, V>>(cache: &Cache<K, V, W>) -> usize {
+    // A limit of zero is stored as a capacity of one, as clru requires a non-zero capacity
+    if cache.capacity() <= 1 {
+        0
+    } else {
+        cache.capacity()
+    }
+}
+
+#[cfg(any(feature = "image-decoders", feature = "software-renderer-systemfonts"))]
+fn evict_until<K: Eq + core::hash::Hash, V, W: clru::WeightScale<K, V>>(
+    kind: ResourceCache,
+    cache: &mut Cache<K, V, W>,
+    fits: impl Fn(&Cache<K, V, W>) -> bool,
+    weight: &dyn Fn(&K, &V) -> usize,
+) {
+    let mut evicted = 0;
+    while !fits(cache) {
+        let Some((key, value)) = cache.pop_back() else { break };
+        evicted += weight(&key, &value);
+    }
+    if evicted > 0 {
+        NOTIFICATIONS.with(|n| n.borrow_mut().evicted.push((kind, evicted)));
+    }
+}
+
+#[cfg(any(feature = "image-decoders", feature = "software-renderer-systemfonts"))]
+fn resize<K: Eq + core::hash::Hash, V, W: clru::WeightScale<K, V>>(
+    kind: ResourceCache,
+    cache: &mut Cache<K, V, W>,
+    limit_in_bytes: usize,
+    weight: &dyn Fn(&K, &V) -> usize,
+) {
+    // clru counts one byte per entry in addition to their weight
+    evict_until(kind, cache, |cache| cache.len() + cache.weight() <= limit_in_bytes, weight);
+    cache.resize(core::num::NonZeroUsize::new(limit_in_bytes.max(1)).unwrap());
+}
+
+/// Inserts the entry in the cache after evicting the least recently used entries to make room for
+/// it, or reports it to the out of memory handler if it's larger than the limit of the cache.
+#[cfg(any(feature = "image-decoders", feature = "software-renderer-systemfonts"))]
+pub(crate) fn put<K: Eq + core::hash::Hash, V, W: clru::WeightScale<K, V>>(
+    kind: ResourceCache,
+    cache: &mut Cache<K, V, W>,
+    key: K,
+    value: V,
+    weight: &dyn Fn(&K, &V) -> usize,
+) {
+    let size = weight(&key, &value);
+    if size >= limit(cache).max(1) {
+        NOTIFICATIONS.with(|n| n.borrow_mut().out_of_memory.push((kind, size)));
+        return;
+    }
+    evict_until(
+        kind,
+        cache,
+        |cache| cache.len() + cache.weight() + size < cache.capacity(),
+        weight,
+    );
+    cache.put_with_weight(key, value).ok();
+}
+
+#[cfg(all(test, feature = "image-decoders"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_cache_limits() {
+        let evicted = alloc::rc::Rc::new(core::cell::Cell::new(0));
+        let out_of_memory = alloc::rc::Rc::new(core::cell::Cell::new(0));
+        let e = evicted.clone();
+        set_eviction_handler(move |cache, size| {
+            assert_eq!(cache, ResourceCache::Images);
+            e.set(e.get() + size);
+        });
+        let o = out_of_memory.clone();
+        set_out_of_memory_handler(move |_, size| o.set(o.get() + size));
+
+        let load = |color: u8| {
+            let mut png = Vec::new();
+            image::RgbImage::from_pixel(10, 10, image::Rgb([color, 0, 0]))
+                .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+                .unwrap();
+            let png: &'static [u8] = png.leak();
+            crate::graphics::load_image_from_embedded_data(
+                crate::slice::Slice::from_slice(png),
+                crate::slice::Slice::from_slice(b"png"),
+            )
+        };
+
+        clear_cache(ResourceCache::Images);
+        set_cache_limit(ResourceCache::Images, 1000);
+        assert_eq!(cache_limit(ResourceCache::Images), 1000);
+        dispatch_notifications();
+        evicted.set(0);
+
+        // Each image takes 300 bytes, the fourth one evicts the first one
+        for color in 0..4 {
+            assert_eq!(load(color).size(), [10, 10].into());
+        }
+        assert_eq!(cache_usage(ResourceCache::Images), 900);
+        dispatch_notifications();
+        assert_eq!(evicted.get(), 300);
+
+        set_cache_limit(ResourceCache::Images, 100);
+        assert_eq!(cache_usage(ResourceCache::Images), 0);
+        assert_eq!(load(5).size(), [10, 10].into());
+        assert_eq!(cache_usage(ResourceCache::Images), 0);
+        dispatch_notifications();
+        assert_eq!(evicted.get(), 1200);
+        assert_eq!(out_of_memory.get(), 300);
+
+        set_cache_limit(ResourceCache::Images, ResourceCache::Images.default_limit());
+    }
+}
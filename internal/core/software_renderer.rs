@@ -9,8 +9,10 @@
 
 mod draw_functions;
 mod fixed;
-mod fonts;
+pub(crate) mod fonts;
 mod minimal_software_window;
+#[cfg(feature = "software-renderer-parallel")]
+mod parallel;
 mod scene;
 
 use self::fonts::GlyphRenderer;
@@ -91,6 +93,71 @@ pub fn angle(self) -> f32 {
     }
 }
 
+/// The arrangement of the red, green, and blue sub-pixels of the display, used for sub-pixel
+/// anti-aliasing of text.
+///
+/// Argument to be passed in [`SoftwareRenderer::set_text_subpixel_layout`].
+#[non_exhaustive]
+#[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum SubpixelLayout {
+    /// Text is rendered with grayscale anti-aliasing
+    #[default]
+    None,
+    /// The sub-pixels are arranged horizontally, in the order red, green, blue
+    Rgb,
+    /// The sub-pixels are arranged horizontally, in the order blue, green, red
+    Bgr,
+}
+
+impl SubpixelLayout {
+    /// The layout of the sub-pixels relative to the content rendered with the given rotation
+    fn rotated(self, rotation: RenderingRotation) -> Self {
+        match (rotation, self) {
+            (RenderingRotation::NoRotation, _) => self,
+            (RenderingRotation::Rotate180, Self::Rgb) => Self::Bgr,
+            (RenderingRotation::Rotate180, Self::Bgr) => Self::Rgb,
+            (RenderingRotation::Rotate180, Self::None) => Self::None,
+            // The sub-pixels are vertical relative to the text, fall back to grayscale
+            (RenderingRotation::Rotate90 | RenderingRotation::Rotate270, _) => Self::None,
+        }
+    }
+}
+
+/// How the colors of gradients are dithered when rendering into a buffer with less than 8 bits
+/// per color component, such as [`Rgb565Pixel`]. Dithering hides the banding of gradients.
+///
+/// Argument to be passed in [`SoftwareRenderer::set_dithering`].
+#[non_exhaustive]
+#[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Dithering {
+    /// The colors are truncated to the precision of the buffer
+    #[default]
+    None,
+    /// Ordered dithering with a fixed 4x4 Bayer pattern
+    Ordered,
+    /// Ordered dithering with a pattern that changes on every frame, so that the colors average
+    /// out over time on displays that are refreshed fast enough. The pattern only changes in the
+    /// areas that are redrawn.
+    Temporal,
+}
+
+/// How the scene is scaled when rendered into the buffer, in addition to the window's scale factor.
+///
+/// Argument to be passed in [`SoftwareRenderer::set_render_scale`].
+#[non_exhaustive]
+#[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum RenderScale {
+    /// The scene is rendered at the resolution of the buffer
+    #[default]
+    Native,
+    /// The scene is rendered at the given multiple of the buffer's resolution and then downsampled,
+    /// which gives smoother edges on displays with a low pixel density.
+    Supersample(u8),
+    /// The scene is rendered at the given fraction of the buffer's resolution and each pixel is then
+    /// enlarged to a square block of that many pixels, for a retro look.
+    PixelArt(u8),
+}
+
 #[derive(Copy, Clone, Debug)]
 struct RotationInfo {
     orientation: RenderingRotation,
@@ -208,6 +275,28 @@ pub struct PhysicalRegion {
 }
 
 impl PhysicalRegion {
+    /// Map the logical dirty region to the physical region of the buffer: each rectangle is
+    /// scaled, clipped to the screen, and rotated on its own, so that a small change on a
+    /// rotated display only causes a small part of the buffer to be redrawn.
+    fn from_dirty_region(
+        dirty_region: &DirtyRegion,
+        factor: ScaleFactor,
+        rotation: RotationInfo,
+    ) -> Self {
+        let screen_rect = PhysicalRect::from_size(rotation.screen_size);
+        let mut result = Self::default();
+        for r in dirty_region.iter() {
+            let Some(r) =
+                (r.cast() * factor).to_rect().round_out().cast().intersection(&screen_rect)
+            else {
+                continue;
+            };
+            result.rectangles[result.count] = r.transformed(rotation).to_box2d();
+            result.count += 1;
+        }
+        result
+    }
+
     fn iter_box(&self) -> impl Iterator<Item = euclid::Box2D<i16, PhysicalPx>> + '_ {
         (0..self.count).map(|x| self.rectangles[x])
     }
@@ -223,6 +312,29 @@ fn bounding_rect(&self) -> PhysicalRect {
         r.to_rect()
     }
 
+    /// Scale the region by `numerator / denominator`, rounding outwards, and clip it to `bounds`.
+    fn scaled(&self, numerator: i16, denominator: i16, bounds: PhysicalSize) -> Self {
+        let scale = |v: i16, round_up: i32| {
+            ((v as i32 * numerator as i32 + round_up) / denominator as i32) as i16
+        };
+        let bounds = euclid::Box2D::from_size(bounds);
+        let mut result = Self::default();
+        for r in self.iter_box() {
+            let r = euclid::Box2D::new(
+                euclid::point2(scale(r.min.x, 0), scale(r.min.y, 0)),
+                euclid::point2(
+                    scale(r.max.x, denominator as i32 - 1),
+                    scale(r.max.y, denominator as i32 - 1),
+                ),
+            );
+            if let Some(r) = r.intersection(&bounds) {
+                result.rectangles[result.count] = r;
+                result.count += 1;
+            }
+        }
+        result
+    }
+
     /// Returns the size of the bounding box of this region.
     pub fn bounding_box_size(&self) -> crate::api::PhysicalSize {
         let bb = self.bounding_rect();
@@ -266,6 +378,40 @@ pub fn iter(
         })
     }
 
+    /// Returns an iterator over the tiles of a grid of `tile_size` that intersect this region.
+    ///
+    /// This is useful for displays that are updated in blocks of fixed size: only the tiles
+    /// returned here need to be flushed to the display after rendering.
+    /// The tiles are returned row by row, and each tile is returned only once. The tiles on the
+    /// right and bottom edges may extend beyond the size of the screen.
+    ///
+    /// Panics if the width or the height of `tile_size` is zero.
+    pub fn iter_tiles(
+        &self,
+        tile_size: crate::api::PhysicalSize,
+    ) -> impl Iterator<Item = (crate::api::PhysicalPosition, crate::api::PhysicalSize)> + '_ {
+        assert!(tile_size.width > 0 && tile_size.height > 0, "tile size must not be empty");
+        let (tile_width, tile_height) = (tile_size.width as i32, tile_size.height as i32);
+        let mut tiles = Vec::<(i32, i32)>::new();
+        for (pos, size) in self.iter() {
+            let columns = pos.x.div_euclid(tile_width)
+                ..=(pos.x + size.width as i32 - 1).div_euclid(tile_width);
+            for row in pos.y.div_euclid(tile_height)
+                ..=(pos.y + size.height as i32 - 1).div_euclid(tile_height)
+            {
+                tiles.extend(columns.clone().map(|column| (row, column)));
+            }
+        }
+        tiles.sort_unstable();
+        tiles.dedup();
+        tiles.into_iter().map(move |(row, column)| {
+            (
+                crate::api::PhysicalPosition { x: column * tile_width, y: row * tile_height },
+                tile_size,
+            )
+        })
+    }
+
     fn intersection(&self, clip: &PhysicalRect) -> PhysicalRegion {
         let mut res = Self::default();
         let clip = clip.to_box2d();
@@ -313,6 +459,94 @@ fn region_iter() {
     assert_eq!(iter.next(), None);
 }
 
+#[test]
+fn region_iter_tiles() {
+    let mut region = PhysicalRegion::default();
+    let tile = crate::api::PhysicalSize { width: 8, height: 4 };
+    assert_eq!(region.iter_tiles(tile).next(), None);
+    region.rectangles[0] =
+        euclid::Box2D::from_origin_and_size(euclid::point2(1, 1), euclid::size2(2, 3));
+    region.rectangles[1] =
+        euclid::Box2D::from_origin_and_size(euclid::point2(6, 2), euclid::size2(3, 20));
+    region.count = 2;
+    let t = |x, y| (crate::api::PhysicalPosition { x, y }, tile);
+    assert_eq!(
+        region.iter_tiles(tile).collect::<Vec<_>>(),
+        vec![
+            t(0, 0),
+            t(8, 0),
+            t(0, 4),
+            t(8, 4),
+            t(0, 8),
+            t(8, 8),
+            t(0, 12),
+            t(8, 12),
+            t(0, 16),
+            t(8, 16),
+            t(0, 20),
+            t(8, 20)
+        ]
+    );
+}
+
+#[test]
+fn region_from_rotated_dirty_region() {
+    let mut dirty_region = DirtyRegion::default();
+    dirty_region.add_rect(LogicalRect::new(LogicalPoint::new(10., 5.), LogicalSize::new(20., 4.)));
+    // Outside of the screen
+    dirty_region
+        .add_rect(LogicalRect::new(LogicalPoint::new(200., 200.), LogicalSize::new(10., 10.)));
+    let screen_size = PhysicalSize::new(100, 50);
+    let region = |orientation| {
+        PhysicalRegion::from_dirty_region(
+            &dirty_region,
+            ScaleFactor::new(1.),
+            RotationInfo { orientation, screen_size },
+        )
+        .iter()
+        .collect::<Vec<_>>()
+    };
+    let r = |x, y, width, height| {
+        (crate::api::PhysicalPosition { x, y }, crate::api::PhysicalSize { width, height })
+    };
+    assert_eq!(region(RenderingRotation::NoRotation), vec![r(10, 5, 20, 4)]);
+    assert_eq!(region(RenderingRotation::Rotate90), vec![r(41, 10, 4, 20)]);
+    assert_eq!(region(RenderingRotation::Rotate180), vec![r(70, 41, 20, 4)]);
+    assert_eq!(region(RenderingRotation::Rotate270), vec![r(5, 70, 4, 20)]);
+}
+
+#[test]
+fn region_scaled() {
+    let mut region = PhysicalRegion::default();
+    region.rectangles[0] =
+        euclid::Box2D::from_origin_and_size(euclid::point2(1, 3), euclid::size2(5, 2));
+    region.count = 1;
+    let r = |x, y, width, height| {
+        (crate::api::PhysicalPosition { x, y }, crate::api::PhysicalSize { width, height })
+    };
+    let bounds = PhysicalSize::new(100, 9);
+    assert_eq!(region.scaled(2, 1, bounds).iter().collect::<Vec<_>>(), vec![r(2, 6, 10, 3)]);
+    assert_eq!(region.scaled(1, 2, bounds).iter().collect::<Vec<_>>(), vec![r(0, 1, 3, 2)]);
+}
+
+#[test]
+fn subpixel_layout_rotated() {
+    use RenderingRotation::*;
+    assert_eq!(SubpixelLayout::Rgb.rotated(NoRotation), SubpixelLayout::Rgb);
+    assert_eq!(SubpixelLayout::Rgb.rotated(Rotate180), SubpixelLayout::Bgr);
+    assert_eq!(SubpixelLayout::Bgr.rotated(Rotate180), SubpixelLayout::Rgb);
+    assert_eq!(SubpixelLayout::Rgb.rotated(Rotate90), SubpixelLayout::None);
+    assert_eq!(SubpixelLayout::Bgr.rotated(Rotate270), SubpixelLayout::None);
+}
+
+#[test]
+fn snap_scale_factor_rounds() {
+    assert_eq!(snap_scale_factor(1.), 1.);
+    assert_eq!(snap_scale_factor(1.2500001), 1.25);
+    assert_eq!(snap_scale_factor(1.7499999), 1.75);
+    assert_eq!(snap_scale_factor(0.001), 0.001);
+}
+
 /// Computes what are the x ranges that intersects the region for specified y line.
 ///
 /// This uses a mutable reference to a Vec so that the memory is re-used between calls.
@@ -423,6 +657,17 @@ pub struct SoftwareRenderer {
     partial_rendering_state: PartialRenderingState,
     maybe_window_adapter: RefCell<Option<Weak<dyn crate::window::WindowAdapter>>>,
     rotation: Cell<RenderingRotation>,
+    text_subpixel_layout: Cell<SubpixelLayout>,
+    dithering: Cell<Dithering>,
+    /// Incremented on each frame rendered with the temporal dithering
+    dither_phase: Cell<u8>,
+    render_scale: Cell<RenderScale>,
+    deterministic_rendering: Cell<bool>,
+    /// The buffer the scene is rendered into with RenderScale::Supersample, kept between frames
+    supersample_buffer: RefCell<Vec<PremultipliedRgbaColor>>,
+    /// The buffers used while rendering a frame, kept between frames so that they don't need
+    /// to be allocated again
+    scene_buffers: RefCell<SceneBuffers>,
     rendering_metrics_collector: Option<Rc<RenderingMetricsCollector>>,
 }
 
@@ -433,6 +678,13 @@ fn default() -> Self {
             prev_frame_dirty: Default::default(),
             maybe_window_adapter: Default::default(),
             rotation: Default::default(),
+            text_subpixel_layout: Default::default(),
+            dithering: Default::default(),
+            dither_phase: Default::default(),
+            render_scale: Default::default(),
+            deterministic_rendering: Cell::new(deterministic_rendering_requested()),
+            supersample_buffer: Default::default(),
+            scene_buffers: Default::default(),
             rendering_metrics_collector: RenderingMetricsCollector::new("software"),
             repaint_buffer_type: Default::default(),
         }
@@ -468,11 +720,34 @@ pub fn repaint_buffer_type(&self) -> RepaintBufferType {
         self.repaint_buffer_type.get()
     }
 
+    /// Reserve room in the buffers that hold a frame while it's rendered, for frames with up to
+    /// `items` drawing commands and with items nested up to `depth` levels deep.
+    ///
+    /// Each rectangle, border, image, and glyph of a text is one drawing command.
+    /// These buffers are kept between frames and grow to the size of the largest frame rendered
+    /// so far. Call this function during the initialization of the application, so that
+    /// rendering the frames afterwards doesn't need to allocate more memory, also when the
+    /// first frame is smaller than the others.
+    /// The `alloc-stats` feature provides a way to verify that.
+    pub fn reserve_scene_capacity(&self, items: usize, depth: usize) {
+        let mut buffers = self.scene_buffers.borrow_mut();
+        buffers.items.reserve(items);
+        buffers.vectors.reserve(items);
+        // One state is saved for each level of nesting
+        buffers.state_stack.reserve(depth);
+        buffers.line_ranges.reserve(PHYSICAL_REGION_MAX_SIZE);
+    }
+
     /// Set how the window need to be rotated in the buffer.
     ///
-    /// This is typically used to implement screen rotation in software
+    /// This is typically used to implement screen rotation in software.
+    ///
+    /// Changing the rotation causes the next frame to be fully redrawn. Rendering with the same
+    /// rotation afterwards only redraws the dirty region, mapped to the rotated buffer.
     pub fn set_rendering_rotation(&self, rotation: RenderingRotation) {
-        self.rotation.set(rotation)
+        if self.rotation.replace(rotation) != rotation {
+            self.partial_rendering_state.force_screen_refresh();
+        }
     }
 
     /// Return the current rotation. See [`Self::set_rendering_rotation()`]
@@ -480,6 +755,119 @@ pub fn rendering_rotation(&self) -> RenderingRotation {
         self.rotation.get()
     }
 
+    /// Enable sub-pixel anti-aliasing of text, for a display with the given arrangement of sub-pixels.
+    ///
+    /// Sub-pixel anti-aliasing triples the horizontal resolution of the glyphs, which makes text
+    /// more legible on displays with a low pixel density. It only applies to text rendered with
+    /// vector fonts, and only if the display isn't rotated by 90 or 270 degrees. Other text, such as
+    /// text rendered with embedded bitmap fonts, keeps using grayscale anti-aliasing.
+    ///
+    /// The default is [`SubpixelLayout::None`], which disables sub-pixel anti-aliasing.
+    pub fn set_text_subpixel_layout(&self, layout: SubpixelLayout) {
+        if self.text_subpixel_layout.replace(layout) != layout {
+            self.partial_rendering_state.force_screen_refresh();
+        }
+    }
+
+    /// Return the current sub-pixel layout. See [`Self::set_text_subpixel_layout()`]
+    pub fn text_subpixel_layout(&self) -> SubpixelLayout {
+        self.text_subpixel_layout.get()
+    }
+
+    /// Set how the gradients are dithered when rendering into a buffer with less than 8 bits
+    /// per color component, such as [`Rgb565Pixel`]. Buffers with 8 bits per component
+    /// are not affected.
+    ///
+    /// The default is [`Dithering::None`].
+    pub fn set_dithering(&self, dithering: Dithering) {
+        if self.dithering.replace(dithering) != dithering {
+            self.partial_rendering_state.force_screen_refresh();
+        }
+    }
+
+    /// Return the current dithering. See [`Self::set_dithering()`]
+    pub fn dithering(&self) -> Dithering {
+        self.dithering.get()
+    }
+
+    /// Set how the scene is scaled when rendered into the buffer.
+    ///
+    /// With [`RenderScale::Supersample`] the scene is rendered into an internal buffer that's larger
+    /// than the target buffer, and with [`RenderScale::PixelArt`] into a corner of the target buffer
+    /// before being enlarged. Either way, the whole window is redrawn on every frame. This only
+    /// applies to [`Self::render()`], not to [`Self::render_by_line()`].
+    ///
+    /// The default is [`RenderScale::Native`].
+    pub fn set_render_scale(&self, render_scale: RenderScale) {
+        if self.render_scale.replace(render_scale) != render_scale {
+            self.supersample_buffer.take();
+            self.partial_rendering_state.force_screen_refresh();
+        }
+    }
+
+    /// Return the current render scale. See [`Self::set_render_scale()`]
+    pub fn render_scale(&self) -> RenderScale {
+        self.render_scale.get()
+    }
+
+    /// Make the output of the renderer independent of the platform, for golden-image tests that
+    /// compare screenshots taken on different operating systems.
+    ///
+    /// When enabled, text is rendered with grayscale anti-aliasing regardless of
+    /// [`Self::set_text_subpixel_layout()`], the temporal dithering uses the same pattern on every
+    /// frame, and the scale factor of the window is rounded to a multiple of 1/64 so that the
+    /// slightly different values reported by the platforms result in the same pixel sizes.
+    /// Glyph coverage is always blended linearly, without gamma correction.
+    ///
+    /// The fonts are shared by all renderers, so they can't be selected here. Set the
+    /// `SLINT_DETERMINISTIC_RENDERING` environment variable before starting the application to
+    /// not load the fonts of the system and use the bundled DejaVu Sans instead of them,
+    /// in addition to the fonts registered by the application. The environment variable also
+    /// enables this setting by default.
+    pub fn set_deterministic_rendering(&self, enabled: bool) {
+        if self.deterministic_rendering.replace(enabled) != enabled {
+            self.partial_rendering_state.force_screen_refresh();
+        }
+    }
+
+    /// Return whether the rendering is deterministic. See [`Self::set_deterministic_rendering()`]
+    pub fn deterministic_rendering(&self) -> bool {
+        self.deterministic_rendering.get()
+    }
+
+    /// Returns the sub-pixel layout to render the text with
+    fn effective_text_subpixel_layout(&self) -> SubpixelLayout {
+        if self.deterministic_rendering.get() {
+            SubpixelLayout::None
+        } else {
+            self.text_subpixel_layout.get()
+        }
+    }
+
+    /// Returns the scale factor of the window to render with
+    fn effective_scale_factor(&self, window: &WindowInner) -> f32 {
+        let scale_factor = window.scale_factor();
+        if self.deterministic_rendering.get() {
+            snap_scale_factor(scale_factor)
+        } else {
+            scale_factor
+        }
+    }
+
+    /// Returns the dithering pattern to use for the next frame
+    fn next_dither_pattern(&self) -> Option<draw_functions::DitherPattern> {
+        match self.dithering.get() {
+            Dithering::None => None,
+            Dithering::Ordered => Some(Default::default()),
+            Dithering::Temporal if self.deterministic_rendering.get() => Some(Default::default()),
+            Dithering::Temporal => {
+                let phase = self.dither_phase.get();
+                self.dither_phase.set(phase.wrapping_add(1));
+                Some(draw_functions::DitherPattern { phase })
+            }
+        }
+    }
+
     /// Render the window to the given frame buffer.
     ///
     /// The renderer uses a cache internally and will only render the part of the window
@@ -518,6 +906,90 @@ pub fn render_into_buffer(&self, buffer: &mut impl TargetPixelBuffer) -> Physica
     fn render_buffer_impl(
         &self,
         buffer: &mut impl target_pixel_buffer::TargetPixelBuffer,
+    ) -> PhysicalRegion {
+        match self.render_scale.get() {
+            RenderScale::Supersample(n) if n > 1 => self.render_supersampled(buffer, n as usize),
+            RenderScale::PixelArt(n) if n > 1 => self.render_pixel_art(buffer, n as usize),
+            _ => self.render_scaled(buffer, 1.),
+        }
+    }
+
+    /// Render the scene at `n` times the resolution into the supersample buffer, and then average
+    /// each block of `n`×`n` pixels into one pixel of `buffer`.
+    fn render_supersampled<B: target_pixel_buffer::TargetPixelBuffer>(
+        &self,
+        buffer: &mut B,
+        n: usize,
+    ) -> PhysicalRegion {
+        let width = buffer.line_slice(0).len();
+        let num_lines = buffer.num_lines();
+        let mut supersample_buffer = self.supersample_buffer.borrow_mut();
+        supersample_buffer.resize(width * n * num_lines * n, PremultipliedRgbaColor::background());
+
+        self.partial_rendering_state.force_screen_refresh();
+        let region = self.render_scaled(
+            &mut TargetPixelSlice { data: &mut supersample_buffer[..], pixel_stride: width * n },
+            n as f32,
+        );
+
+        let samples = (n * n) as u32;
+        for y in 0..num_lines {
+            let line = buffer.line_slice(y);
+            for (x, pixel) in line.iter_mut().enumerate() {
+                let mut sum = [0u32; 4];
+                for sample_line in supersample_buffer[y * n * width * n..].chunks(width * n).take(n)
+                {
+                    for c in &sample_line[x * n..(x + 1) * n] {
+                        sum[0] += c.red as u32;
+                        sum[1] += c.green as u32;
+                        sum[2] += c.blue as u32;
+                        sum[3] += c.alpha as u32;
+                    }
+                }
+                let mut p = <B::TargetPixel as TargetPixel>::background();
+                p.blend(PremultipliedRgbaColor {
+                    red: (sum[0] / samples) as u8,
+                    green: (sum[1] / samples) as u8,
+                    blue: (sum[2] / samples) as u8,
+                    alpha: (sum[3] / samples) as u8,
+                });
+                *pixel = p;
+            }
+        }
+
+        region.scaled(1, n as i16, euclid::size2(width as _, num_lines as _))
+    }
+
+    /// Render the scene at a `n`th of the resolution into the top left corner of `buffer`, and then
+    /// enlarge each pixel to a block of `n`×`n` pixels.
+    fn render_pixel_art(
+        &self,
+        buffer: &mut impl target_pixel_buffer::TargetPixelBuffer,
+        n: usize,
+    ) -> PhysicalRegion {
+        self.partial_rendering_state.force_screen_refresh();
+        let region = self.render_scaled(buffer, 1. / n as f32);
+
+        let width = buffer.line_slice(0).len();
+        let num_lines = buffer.num_lines();
+        let mut source_line = Vec::with_capacity(width.div_ceil(n));
+        // Go backwards so that the source lines are read before they are overwritten
+        for y in (0..num_lines).rev() {
+            source_line.clear();
+            source_line.extend_from_slice(&buffer.line_slice(y / n)[..width.div_ceil(n)]);
+            for (x, pixel) in buffer.line_slice(y).iter_mut().enumerate() {
+                *pixel = source_line[x / n];
+            }
+        }
+
+        region.scaled(n as i16, 1, euclid::size2(width as _, num_lines as _))
+    }
+
+    /// Render the scene into `buffer`, with the window's scale factor multiplied by `extra_scale`
+    fn render_scaled(
+        &self,
+        buffer: &mut impl target_pixel_buffer::TargetPixelBuffer,
+        extra_scale: f32,
     ) -> PhysicalRegion {
         let pixels_per_line = buffer.line_slice(0).len();
         let num_lines = buffer.num_lines();
@@ -528,7 +1000,7 @@ fn render_buffer_impl(
             return Default::default();
         };
         let window_inner = WindowInner::from_pub(window.window());
-        let factor = ScaleFactor::new(window_inner.scale_factor());
+        let factor = ScaleFactor::new(self.effective_scale_factor(window_inner) * extra_scale);
         let rotation = self.rotation.get();
         let (size, background) = if let Some(window_item) =
             window_inner.window_item().as_ref().map(|item| item.as_pin_ref())
@@ -555,17 +1027,25 @@ fn render_buffer_impl(
             },
             "buffer of size {} with {pixels_per_line} pixels per line is too small to handle a window of size {size:?}", buffer_pixel_count
         );
-        let buffer_renderer = SceneBuilder::new(
+        let mut scene_buffers = self.scene_buffers.take();
+        let mut buffer_renderer = SceneBuilder::new(
             size,
             factor,
             window_inner,
-            RenderToBuffer { buffer, dirty_range_cache: vec![], dirty_region: Default::default() },
+            RenderToBuffer {
+                buffer,
+                dirty_range_cache: core::mem::take(&mut scene_buffers.line_ranges),
+                dirty_region: Default::default(),
+                dither: self.next_dither_pattern(),
+            },
             rotation,
+            self.effective_text_subpixel_layout(),
         );
+        buffer_renderer.state_stack = core::mem::take(&mut scene_buffers.state_stack);
         let mut renderer = self.partial_rendering_state.create_partial_renderer(buffer_renderer);
         let window_adapter = renderer.window_adapter.clone();
 
-        window_inner
+        let dirty_region = window_inner
             .draw_contents(|components| {
                 let logical_size = (size.cast() / factor).cast();
 
@@ -589,21 +1069,8 @@ fn render_buffer_impl(
                 }
 
                 let rotation = RotationInfo { orientation: rotation, screen_size: size };
-                let screen_rect = PhysicalRect::from_size(size);
-                let mut i = renderer.dirty_region.iter().filter_map(|r| {
-                    (r.cast() * factor)
-                        .to_rect()
-                        .round_out()
-                        .cast()
-                        .intersection(&screen_rect)?
-                        .transformed(rotation)
-                        .into()
-                });
-                let dirty_region = PhysicalRegion {
-                    rectangles: core::array::from_fn(|_| i.next().unwrap_or_default().to_box2d()),
-                    count: renderer.dirty_region.iter().count(),
-                };
-                drop(i);
+                let dirty_region =
+                    PhysicalRegion::from_dirty_region(&renderer.dirty_region, factor, rotation);
 
                 renderer.actual_renderer.processor.dirty_region = dirty_region.clone();
                 if !renderer
@@ -638,7 +1105,23 @@ fn render_buffer_impl(
 
                 dirty_region
             })
-            .unwrap_or_default()
+            .unwrap_or_default();
+
+        let buffer_renderer = renderer.into_inner();
+        scene_buffers.line_ranges = buffer_renderer.processor.dirty_range_cache;
+        scene_buffers.state_stack = buffer_renderer.state_stack;
+        self.scene_buffers.replace(scene_buffers);
+        dirty_region
+    }
+
+    /// Keep the buffers of a scene that was rendered for the next frame.
+    fn recycle_scene(&self, scene: Scene, mut vectors: SceneVectors) {
+        let mut buffers = self.scene_buffers.borrow_mut();
+        buffers.items = scene.items;
+        buffers.items.clear();
+        buffers.line_ranges = scene.current_line_ranges;
+        vectors.clear();
+        buffers.vectors = vectors;
     }
 
     fn measure_frame_rendered(&self, renderer: &mut dyn ItemRenderer) {
@@ -646,6 +1129,7 @@ fn measure_frame_rendered(&self, renderer: &mut dyn ItemRenderer) {
             let prev_frame_dirty = self.prev_frame_dirty.take();
             let m = crate::graphics::rendering_metrics_collector::RenderingMetrics {
                 dirty_region: Some(prev_frame_dirty.clone()),
+                cache_statistics: Some(self.collect_cache_statistics()),
                 ..Default::default()
             };
             self.prev_frame_dirty.set(prev_frame_dirty);
@@ -701,7 +1185,7 @@ pub fn render_by_line(&self, line_buffer: impl LineBufferProvider) -> PhysicalRe
         if let Some(window_item) = crate::items::ItemRef::downcast_pin::<crate::items::WindowItem>(
             component.as_ref().get_item_ref(0),
         ) {
-            let factor = ScaleFactor::new(window_inner.scale_factor());
+            let factor = ScaleFactor::new(self.effective_scale_factor(window_inner));
             let size = LogicalSize::from_lengths(window_item.width(), window_item.height()).cast()
                 * factor;
             render_window_frame_by_line(
@@ -877,6 +1361,73 @@ fn text_input_byte_offset_for_position(
         }
     }
 
+    fn text_byte_offset_for_position(
+        &self,
+        text: Pin<&dyn crate::item_rendering::RenderText>,
+        size: LogicalSize,
+        pos: LogicalPoint,
+        font_request: crate::graphics::FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> usize {
+        let font = fonts::match_font(&font_request, scale_factor);
+
+        match (font, parley_disabled()) {
+            #[cfg(feature = "software-renderer-systemfonts")]
+            (fonts::Font::VectorFont(_), false) => sharedparley::text_byte_offset_for_position(
+                text,
+                size,
+                pos,
+                font_request,
+                scale_factor,
+            ),
+            #[cfg(feature = "software-renderer-systemfonts")]
+            (fonts::Font::VectorFont(vf), true) => {
+                let string = text.text();
+                let max_size = (size.cast() * scale_factor).cast();
+                let pos = (pos.cast() * scale_factor)
+                    .clamp(euclid::point2(0., 0.), euclid::point2(i16::MAX, i16::MAX).cast())
+                    .cast();
+                let (horizontal_alignment, vertical_alignment) = text.alignment();
+
+                let paragraph = TextParagraphLayout {
+                    string: &string,
+                    layout: fonts::text_layout_for_font(&vf, &font_request, scale_factor),
+                    max_width: max_size.width_length(),
+                    max_height: max_size.height_length(),
+                    horizontal_alignment,
+                    vertical_alignment,
+                    wrap: text.wrap(),
+                    overflow: text.overflow(),
+                    single_line: false,
+                };
+
+                paragraph.byte_offset_for_position((pos.x_length(), pos.y_length()))
+            }
+            (fonts::Font::PixelFont(pf), _) => {
+                let string = text.text();
+                let max_size = (size.cast() * scale_factor).cast();
+                let pos = (pos.cast() * scale_factor)
+                    .clamp(euclid::point2(0., 0.), euclid::point2(i16::MAX, i16::MAX).cast())
+                    .cast();
+                let (horizontal_alignment, vertical_alignment) = text.alignment();
+
+                let paragraph = TextParagraphLayout {
+                    string: &string,
+                    layout: fonts::text_layout_for_font(&pf, &font_request, scale_factor),
+                    max_width: max_size.width_length(),
+                    max_height: max_size.height_length(),
+                    horizontal_alignment,
+                    vertical_alignment,
+                    wrap: text.wrap(),
+                    overflow: text.overflow(),
+                    single_line: false,
+                };
+
+                paragraph.byte_offset_for_position((pos.x_length(), pos.y_length()))
+            }
+        }
+    }
+
     fn text_input_cursor_rect_for_byte_offset(
         &self,
         text_input: Pin<&crate::items::TextInput>,
@@ -1028,7 +1579,8 @@ fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError>
             return Err("take_snapshot() called on window with invalid size".into());
         };
 
-        let mut target_buffer = SharedPixelBuffer::<crate::graphics::Rgb8Pixel>::new(width, height);
+        // Render with alpha, so that the transparent parts of the window stay transparent in the snapshot
+        let mut target_buffer = SharedPixelBuffer::<PremultipliedRgbaColor>::new(width, height);
 
         self.set_repaint_buffer_type(RepaintBufferType::NewBuffer);
         self.render(target_buffer.make_mut_slice(), width as usize);
@@ -1042,7 +1594,16 @@ fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError>
             .iter_mut()
             .zip(target_buffer.as_slice().iter())
         {
-            *target_pixel.rgb_mut() = *source_pixel;
+            let demultiply = |c: u8| match source_pixel.alpha {
+                0 => 0,
+                alpha => (c as u16 * 255 / alpha as u16).min(255) as u8,
+            };
+            *target_pixel = Rgba8Pixel::new(
+                demultiply(source_pixel.red),
+                demultiply(source_pixel.green),
+                demultiply(source_pixel.blue),
+                source_pixel.alpha,
+            );
         }
         Ok(target_buffer_with_alpha)
     }
@@ -1050,6 +1611,42 @@ fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError>
     fn supports_transformations(&self) -> bool {
         false
     }
+
+    fn collect_cache_statistics(&self) -> crate::renderer::CacheStatistics {
+        #[allow(unused_mut)]
+        let mut statistics = crate::renderer::CacheStatistics::shared();
+        #[cfg(feature = "software-renderer-systemfonts")]
+        {
+            use crate::resource_cache::{cache_limit, cache_usage, ResourceCache};
+            let bytes = cache_usage(ResourceCache::Glyphs);
+            let limit = cache_limit(ResourceCache::Glyphs);
+            statistics.glyph_cache_bytes = Some(bytes);
+            statistics.glyph_cache_occupancy =
+                Some(if limit > 0 { bytes as f32 / limit as f32 } else { 1. });
+        }
+        statistics
+    }
+}
+
+/// Whether the `SLINT_DETERMINISTIC_RENDERING` environment variable requests the deterministic
+/// rendering by default. See [`SoftwareRenderer::set_deterministic_rendering()`]
+fn deterministic_rendering_requested() -> bool {
+    #[cfg(feature = "std")]
+    {
+        std::env::var_os("SLINT_DETERMINISTIC_RENDERING").is_some()
+    }
+    #[cfg(not(feature = "std"))]
+    false
+}
+
+/// Rounds the scale factor to a multiple of 1/64, which is exact in floating point
+fn snap_scale_factor(scale_factor: f32) -> f32 {
+    let snapped = (scale_factor * 64.).round() / 64.;
+    if snapped > 0. {
+        snapped
+    } else {
+        scale_factor
+    }
 }
 
 fn parley_disabled() -> bool {
@@ -1069,13 +1666,32 @@ fn render_window_frame_by_line(
     mut line_buffer: impl LineBufferProvider,
 ) -> PhysicalRegion {
     let mut scene = prepare_scene(window, size, renderer);
-
-    let to_draw_tr = scene.dirty_region.bounding_rect();
+    let dither = renderer.next_dither_pattern();
 
     let mut background_color = TargetPixel::background();
     // FIXME gradient
     TargetPixel::blend(&mut background_color, background.color().into());
 
+    let vectors = core::mem::take(&mut scene.vectors);
+    render_scene_lines(&mut scene, &vectors, background_color, dither, &mut line_buffer);
+    let dirty_region = scene.dirty_region.clone();
+    renderer.recycle_scene(scene, vectors);
+    dirty_region
+}
+
+/// Render the lines of the scene's dirty region into the line buffer.
+///
+/// The `vectors` are the ones of the scene, taken out of it so that they can be shared
+/// between scenes rendering different parts of the same frame.
+fn render_scene_lines<B: LineBufferProvider>(
+    scene: &mut Scene,
+    vectors: &SceneVectors,
+    background_color: B::TargetPixel,
+    dither: Option<draw_functions::DitherPattern>,
+    line_buffer: &mut B,
+) {
+    let to_draw_tr = scene.dirty_region.bounding_rect();
+
     while scene.current_line < to_draw_tr.origin.y_length() + to_draw_tr.size.height_length() {
         for r in &scene.current_line_ranges {
             line_buffer.process_line(
@@ -1109,7 +1725,7 @@ fn render_window_frame_by_line(
                                 TargetPixel::blend_slice(range_buffer, color);
                             }
                             SceneCommand::Texture { texture_index } => {
-                                let texture = &scene.vectors.textures[texture_index as usize];
+                                let texture = &vectors.textures[texture_index as usize];
                                 draw_functions::draw_texture_line(
                                     &PhysicalRect { origin: span.pos, size: span.size },
                                     scene.current_line,
@@ -1120,8 +1736,7 @@ fn render_window_frame_by_line(
                                 );
                             }
                             SceneCommand::SharedBuffer { shared_buffer_index } => {
-                                let texture = scene.vectors.shared_buffers
-                                    [shared_buffer_index as usize]
+                                let texture = vectors.shared_buffers[shared_buffer_index as usize]
                                     .as_texture();
                                 draw_functions::draw_texture_line(
                                     &PhysicalRect { origin: span.pos, size: span.size },
@@ -1133,8 +1748,7 @@ fn render_window_frame_by_line(
                                 );
                             }
                             SceneCommand::RoundedRectangle { rectangle_index } => {
-                                let rr =
-                                    &scene.vectors.rounded_rectangles[rectangle_index as usize];
+                                let rr = &vectors.rounded_rectangles[rectangle_index as usize];
                                 draw_functions::draw_rounded_rectangle_line(
                                     &PhysicalRect { origin: span.pos, size: span.size },
                                     scene.current_line,
@@ -1145,8 +1759,7 @@ fn render_window_frame_by_line(
                                 );
                             }
                             SceneCommand::LinearGradient { linear_gradient_index } => {
-                                let g =
-                                    &scene.vectors.linear_gradients[linear_gradient_index as usize];
+                                let g = &vectors.linear_gradients[linear_gradient_index as usize];
 
                                 draw_functions::draw_linear_gradient(
                                     &PhysicalRect { origin: span.pos, size: span.size },
@@ -1154,11 +1767,11 @@ fn render_window_frame_by_line(
                                     g,
                                     range_buffer,
                                     extra_left_clip,
+                                    dither,
                                 );
                             }
                             SceneCommand::RadialGradient { radial_gradient_index } => {
-                                let g =
-                                    &scene.vectors.radial_gradients[radial_gradient_index as usize];
+                                let g = &vectors.radial_gradients[radial_gradient_index as usize];
                                 draw_functions::draw_radial_gradient(
                                     &PhysicalRect { origin: span.pos, size: span.size },
                                     scene.current_line,
@@ -1166,11 +1779,11 @@ fn render_window_frame_by_line(
                                     range_buffer,
                                     extra_left_clip,
                                     extra_right_clip,
+                                    dither,
                                 );
                             }
                             SceneCommand::ConicGradient { conic_gradient_index } => {
-                                let g =
-                                    &scene.vectors.conic_gradients[conic_gradient_index as usize];
+                                let g = &vectors.conic_gradients[conic_gradient_index as usize];
                                 draw_functions::draw_conic_gradient(
                                     &PhysicalRect { origin: span.pos, size: span.size },
                                     scene.current_line,
@@ -1178,6 +1791,7 @@ fn render_window_frame_by_line(
                                     range_buffer,
                                     extra_left_clip,
                                     extra_right_clip,
+                                    dither,
                                 );
                             }
                         }
@@ -1190,7 +1804,6 @@ fn render_window_frame_by_line(
             scene.next_line();
         }
     }
-    scene.dirty_region
 }
 
 fn prepare_scene(
@@ -1198,14 +1811,20 @@ fn prepare_scene(
     size: PhysicalSize,
     software_renderer: &SoftwareRenderer,
 ) -> Scene {
-    let factor = ScaleFactor::new(window.scale_factor());
-    let prepare_scene = SceneBuilder::new(
+    let factor = ScaleFactor::new(software_renderer.effective_scale_factor(window));
+    let mut scene_buffers = software_renderer.scene_buffers.take();
+    let mut prepare_scene = SceneBuilder::new(
         size,
         factor,
         window,
-        PrepareScene::default(),
+        PrepareScene {
+            items: core::mem::take(&mut scene_buffers.items),
+            vectors: core::mem::take(&mut scene_buffers.vectors),
+        },
         software_renderer.rotation.get(),
+        software_renderer.effective_text_subpixel_layout(),
     );
+    prepare_scene.state_stack = core::mem::take(&mut scene_buffers.state_stack);
     let mut renderer =
         software_renderer.partial_rendering_state.create_partial_renderer(prepare_scene);
     let window_adapter = renderer.window_adapter.clone();
@@ -1234,21 +1853,7 @@ fn prepare_scene(
 
         let rotation =
             RotationInfo { orientation: software_renderer.rotation.get(), screen_size: size };
-        let screen_rect = PhysicalRect::from_size(size);
-        let mut i = renderer.dirty_region.iter().filter_map(|r| {
-            (r.cast() * factor)
-                .to_rect()
-                .round_out()
-                .cast()
-                .intersection(&screen_rect)?
-                .transformed(rotation)
-                .into()
-        });
-        dirty_region = PhysicalRegion {
-            rectangles: core::array::from_fn(|_| i.next().unwrap_or_default().to_box2d()),
-            count: renderer.dirty_region.iter().count(),
-        };
-        drop(i);
+        dirty_region = PhysicalRegion::from_dirty_region(&renderer.dirty_region, factor, rotation);
 
         for (component, origin) in components {
             if let Some(component) = ItemTreeWeak::upgrade(component) {
@@ -1265,6 +1870,9 @@ fn prepare_scene(
     software_renderer.measure_frame_rendered(&mut renderer);
 
     let prepare_scene = renderer.into_inner();
+    scene_buffers.state_stack = prepare_scene.state_stack;
+    let line_ranges = core::mem::take(&mut scene_buffers.line_ranges);
+    software_renderer.scene_buffers.replace(scene_buffers);
 
     /* // visualize dirty regions
     let mut prepare_scene = prepare_scene;
@@ -1284,7 +1892,12 @@ fn prepare_scene(
         )
     } // */
 
-    Scene::new(prepare_scene.processor.items, prepare_scene.processor.vectors, dirty_region)
+    Scene::new(
+        prepare_scene.processor.items,
+        prepare_scene.processor.vectors,
+        dirty_region,
+        line_ranges,
+    )
 }
 
 trait ProcessScene {
@@ -1303,6 +1916,18 @@ fn process_target_texture(
     fn process_conic_gradient(&mut self, geometry: PhysicalRect, gradient: ConicGradientCommand);
 }
 
+/// Returns the center of the `geometry` of a gradient, relative to the `clipped` rectangle it's drawn in.
+fn gradient_center(
+    geometry: &euclid::Rect<f32, PhysicalPx>,
+    clipped: &euclid::Rect<f32, PhysicalPx>,
+) -> (PhysicalLength, PhysicalLength) {
+    let center = geometry.center();
+    (
+        PhysicalLength::new((center.x - clipped.min_x()) as i16),
+        PhysicalLength::new((center.y - clipped.min_y()) as i16),
+    )
+}
+
 fn process_rectangle_impl(
     processor: &mut dyn ProcessScene,
     args: &target_pixel_buffer::DrawRectangleArgs,
@@ -1401,13 +2026,11 @@ fn process_rectangle_impl(
         }
         Color::default()
     } else if let Brush::RadialGradient(g) = &args.background {
-        // Calculate absolute center position of the original geometry
-        let absolute_center_x = geom.min_x() + geom.width() / 2.0;
-        let absolute_center_y = geom.min_y() + geom.height() / 2.0;
-
-        // Convert to coordinates relative to the clipped rectangle
-        let center_x = PhysicalLength::new((absolute_center_x - clipped.min_x()) as i16);
-        let center_y = PhysicalLength::new((absolute_center_y - clipped.min_y()) as i16);
+        let (center_x, center_y) = gradient_center(&geom, &clipped);
+        let radius = PhysicalLength::new(
+            (0.5 * (geom.width() * geom.width() + geom.height() * geom.height()).sqrt()).ceil()
+                as i16,
+        );
 
         let radial_grad = RadialGradientCommand {
             stops: g
@@ -1420,11 +2043,13 @@ fn process_rectangle_impl(
                 .collect(),
             center_x,
             center_y,
+            radius,
         };
 
         processor.process_radial_gradient(clipped.cast(), radial_grad);
         Color::default()
     } else if let Brush::ConicGradient(g) = &args.background {
+        let (center_x, center_y) = gradient_center(&geom, &clipped);
         let conic_grad = ConicGradientCommand {
             stops: g
                 .stops()
@@ -1434,6 +2059,8 @@ fn process_rectangle_impl(
                     stop
                 })
                 .collect(),
+            center_x,
+            center_y,
         };
 
         processor.process_conic_gradient(clipped.cast(), conic_grad);
@@ -1523,6 +2150,7 @@ struct RenderToBuffer<'a, TargetPixelBuffer> {
     buffer: &'a mut TargetPixelBuffer,
     dirty_range_cache: Vec<core::ops::Range<i16>>,
     dirty_region: PhysicalRegion,
+    dither: Option<draw_functions::DitherPattern>,
 }
 
 impl<B: target_pixel_buffer::TargetPixelBuffer> RenderToBuffer<'_, B> {
@@ -1604,7 +2232,9 @@ fn process_target_texture(
         texture: &target_pixel_buffer::DrawTextureArgs,
         clip: PhysicalRect,
     ) {
-        if self.buffer.draw_texture(texture, &self.dirty_region.intersection(&clip)) {
+        if !texture.is_run_length_encoded()
+            && self.buffer.draw_texture(texture, &self.dirty_region.intersection(&clip))
+        {
             return;
         }
 
@@ -1647,6 +2277,7 @@ fn process_simple_rectangle(&mut self, geometry: PhysicalRect, color: Premultipl
     }
 
     fn process_linear_gradient(&mut self, geometry: PhysicalRect, g: LinearGradientCommand) {
+        let dither = self.dither;
         self.foreach_ranges(&geometry, |line, buffer, extra_left_clip, _extra_right_clip| {
             draw_functions::draw_linear_gradient(
                 &geometry,
@@ -1654,10 +2285,12 @@ fn process_linear_gradient(&mut self, geometry: PhysicalRect, g: LinearGradientC
                 &g,
                 buffer,
                 extra_left_clip,
+                dither,
             );
         });
     }
     fn process_radial_gradient(&mut self, geometry: PhysicalRect, g: RadialGradientCommand) {
+        let dither = self.dither;
         self.foreach_ranges(&geometry, |line, buffer, extra_left_clip, extra_right_clip| {
             draw_functions::draw_radial_gradient(
                 &geometry,
@@ -1666,10 +2299,12 @@ fn process_radial_gradient(&mut self, geometry: PhysicalRect, g: RadialGradientC
                 buffer,
                 extra_left_clip,
                 extra_right_clip,
+                dither,
             );
         });
     }
     fn process_conic_gradient(&mut self, geometry: PhysicalRect, g: ConicGradientCommand) {
+        let dither = self.dither;
         self.foreach_ranges(&geometry, |line, buffer, extra_left_clip, extra_right_clip| {
             draw_functions::draw_conic_gradient(
                 &geometry,
@@ -1678,17 +2313,26 @@ fn process_conic_gradient(&mut self, geometry: PhysicalRect, g: ConicGradientCom
                 buffer,
                 extra_left_clip,
                 extra_right_clip,
+                dither,
             );
         });
     }
 }
 
-#[derive(Default)]
 struct PrepareScene {
     items: Vec<SceneItem>,
     vectors: SceneVectors,
 }
 
+/// The buffers that [`SoftwareRenderer`] keeps between frames, see [`SoftwareRenderer::reserve_scene_capacity()`]
+#[derive(Default)]
+struct SceneBuffers {
+    items: Vec<SceneItem>,
+    vectors: SceneVectors,
+    line_ranges: Vec<core::ops::Range<i16>>,
+    state_stack: Vec<RenderState>,
+}
+
 impl ProcessScene for PrepareScene {
     fn process_scene_texture(&mut self, geometry: PhysicalRect, texture: SceneTexture<'static>) {
         let texture_index = self.vectors.textures.len() as u16;
@@ -1718,6 +2362,7 @@ fn process_target_texture(
                     data: texture_data.data,
                     format: texture_data.pixel_format,
                     pixel_stride,
+                    encoded_source: texture_data.encoded_source(),
                     extra,
                 });
                 self.items.push(SceneItem {
@@ -1823,6 +2468,8 @@ struct SceneBuilder<'a, T> {
     scale_factor: ScaleFactor,
     window: &'a WindowInner,
     rotation: RotationInfo,
+    /// The sub-pixel layout used for the text, relative to the rotated content
+    text_subpixel_layout: SubpixelLayout,
 }
 
 impl<'a, T: ProcessScene> SceneBuilder<'a, T> {
@@ -1832,6 +2479,7 @@ fn new(
         window: &'a WindowInner,
         processor: T,
         orientation: RenderingRotation,
+        text_subpixel_layout: SubpixelLayout,
     ) -> Self {
         Self {
             processor,
@@ -1847,6 +2495,7 @@ fn new(
             scale_factor,
             window,
             rotation: RotationInfo { orientation, screen_size },
+            text_subpixel_layout: text_subpixel_layout.rotated(orientation),
         }
     }
 
@@ -1951,15 +2600,27 @@ fn draw_image_impl(
                         }
                     });
 
+                    let texture_data = if t.format.run_length_decoded().is_some() {
+                        // The lines are decoded while drawing, so pass the whole encoded texture
+                        let mut texture_data = target_pixel_buffer::TextureData::new(
+                            &data.as_slice()[t.index..],
+                            t.format,
+                            pixel_stride * bpp,
+                            src_rect.size().cast(),
+                        );
+                        texture_data.encoded_origin =
+                            (src_rect.min - t_rect.min).to_point().cast().cast_unit();
+                        texture_data
+                    } else {
+                        target_pixel_buffer::TextureData::new(
+                            &data.as_slice()[t.index..][start * bpp..end * bpp],
+                            t.format,
+                            pixel_stride * bpp,
+                            src_rect.size().cast(),
+                        )
+                    };
                     let t = target_pixel_buffer::DrawTextureArgs {
-                        data: target_pixel_buffer::TextureDataContainer::Static(
-                            target_pixel_buffer::TextureData::new(
-                                &data.as_slice()[t.index..][start * bpp..end * bpp],
-                                t.format,
-                                pixel_stride * bpp,
-                                src_rect.size().cast(),
-                            ),
-                        ),
+                        data: target_pixel_buffer::TextureDataContainer::Static(texture_data),
                         colorize: (color.alpha() > 0).then_some(color),
                         alpha,
                         dst_x: target_rect.origin.x as _,
@@ -2073,8 +2734,10 @@ fn draw_text_paragraph<Font>(
                     }
                     let scale_delta = paragraph.layout.font.scale_delta();
                     for positioned_glyph in glyphs {
-                        let Some(glyph) =
-                            paragraph.layout.font.render_glyph(positioned_glyph.glyph_id)
+                        let Some(glyph) = paragraph
+                            .layout
+                            .font
+                            .render_glyph(positioned_glyph.glyph_id, self.text_subpixel_layout)
                         else {
                             continue;
                         };
@@ -2141,6 +2804,7 @@ fn draw_text_paragraph<Font>(
                                         data,
                                         pixel_stride,
                                         format: TexturePixelFormat::SignedDistanceField,
+                                        encoded_source: None,
                                         extra: SceneTextureExtra {
                                             colorize: color,
                                             // color already is mixed with global alpha
@@ -2170,10 +2834,12 @@ fn draw_text_paragraph<Font>(
                             }
                             fonts::GlyphAlphaMap::Shared(data) => {
                                 let source_rect = euclid::rect(0, 0, glyph.width.0, glyph.height.0);
+                                let (data, width) = (data.clone(), glyph.pixel_stride);
                                 target_pixel_buffer::TextureDataContainer::Shared {
-                                    buffer: SharedBufferData::AlphaMap {
-                                        data: data.clone(),
-                                        width: glyph.pixel_stride,
+                                    buffer: if glyph.subpixel {
+                                        SharedBufferData::SubpixelAlphaMap { data, width }
+                                    } else {
+                                        SharedBufferData::AlphaMap { data, width }
                                     },
                                     source_rect,
                                 }
@@ -2284,9 +2950,23 @@ fn draw_border_rectangle(
     ) {
         let geom = LogicalRect::from(size);
         if self.should_draw(&geom) {
-            let geom = (geom.translate(self.current_state.offset.to_vector()).cast()
-                * self.scale_factor)
-                .transformed(self.rotation);
+            let mut geom =
+                geom.translate(self.current_state.offset.to_vector()).cast() * self.scale_factor;
+            let mut border = rect.border_width().cast() * self.scale_factor;
+            if crate::item_rendering::pixel_snapping_enabled(
+                rect.pixel_snapping(),
+                self.window,
+                self.scale_factor.get(),
+            ) {
+                let (snapped_geom, snapped_border) = crate::item_rendering::snap_to_device_pixels(
+                    geom,
+                    border.get(),
+                    Default::default(),
+                );
+                geom = snapped_geom;
+                border = Length::new(snapped_border);
+            }
+            let geom = geom.transformed(self.rotation);
 
             let clipped =
                 (self.current_state.clip.translate(self.current_state.offset.to_vector()).cast()
@@ -2300,7 +2980,6 @@ fn draw_border_rectangle(
                 .min(BorderRadius::from_length(geom.width_length() / 2.))
                 .min(BorderRadius::from_length(geom.height_length() / 2.));
 
-            let border = rect.border_width().cast() * self.scale_factor;
             let border_color =
                 if border.get() > 0.01 { rect.border_color() } else { Default::default() };
 
@@ -2440,7 +3119,14 @@ fn draw_text(
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
+                let selection =
+                    text.selection().map(|(selection, selection_background)| SelectionInfo {
+                        selection_background: self.alpha_color(selection_background),
+                        selection_color: color,
+                        selection,
+                    });
+
+                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
             }
             (fonts::Font::PixelFont(pf), _) => {
                 let string = text.text();
@@ -2481,7 +3167,14 @@ fn draw_text(
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
+                let selection =
+                    text.selection().map(|(selection, selection_background)| SelectionInfo {
+                        selection_background: self.alpha_color(selection_background),
+                        selection_color: color,
+                        selection,
+                    });
+
+                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
             }
         }
     }
@@ -2931,7 +3624,7 @@ fn draw_glyph_run(
 
         for positioned_glyph in glyphs_it {
             let Some(glyph) = std::num::NonZero::new(positioned_glyph.id as u16)
-                .and_then(|id| font.render_vector_glyph(id))
+                .and_then(|id| font.render_vector_glyph(id, self.text_subpixel_layout))
             else {
                 continue;
             };
@@ -2957,9 +3650,16 @@ fn draw_glyph_run(
             let data = {
                 let source_rect = euclid::rect(0, 0, glyph.width.0, glyph.height.0);
                 target_pixel_buffer::TextureDataContainer::Shared {
-                    buffer: SharedBufferData::AlphaMap {
-                        data: glyph.alpha_map,
-                        width: glyph.pixel_stride,
+                    buffer: if glyph.subpixel {
+                        SharedBufferData::SubpixelAlphaMap {
+                            data: glyph.alpha_map,
+                            width: glyph.pixel_stride,
+                        }
+                    } else {
+                        SharedBufferData::AlphaMap {
+                            data: glyph.alpha_map,
+                            width: glyph.pixel_stride,
+                        }
                     },
                     source_rect,
                 }
@@ -7,6 +7,7 @@
 //! on the line buffer
 
 use super::{Fixed, PhysicalLength, PhysicalRect};
+pub use crate::graphics::Rgb565Pixel;
 use crate::graphics::{Rgb8Pixel, TexturePixelFormat};
 use crate::lengths::{PointLengths, SizeLengths};
 use crate::Color;
@@ -25,10 +26,22 @@ pub(super) fn draw_texture_line(
     extra_clip_begin: i16,
     extra_clip_end: i16,
 ) {
+    if texture.encoded_source.is_some() {
+        return draw_run_length_encoded_texture_line(
+            span,
+            line,
+            texture,
+            line_buffer,
+            extra_clip_begin,
+            extra_clip_end,
+        );
+    }
+
     let super::SceneTexture {
         data,
         format,
         pixel_stride,
+        encoded_source: _,
         extra: super::SceneTextureExtra { colorize, alpha, rotation, dx, dy, off_x, off_y },
     } = *texture;
 
@@ -205,6 +218,32 @@ fn fetch_blend_pixel(
                     }
                 }
             }
+            TexturePixelFormat::Rgb565 => {
+                for pix in line_buffer {
+                    let pos = pos(2).0;
+                    let p: Rgb8Pixel =
+                        Rgb565Pixel(u16::from_ne_bytes([data[pos], data[pos + 1]])).into();
+                    if alpha == 0xff {
+                        *pix = TargetPixel::from_rgb(p.r, p.g, p.b);
+                    } else {
+                        pix.blend(PremultipliedRgbaColor::premultiply(Color::from_argb_u8(
+                            alpha, p.r, p.g, p.b,
+                        )))
+                    }
+                }
+            }
+            TexturePixelFormat::Gray => {
+                for pix in line_buffer {
+                    let v = data[pos(1).0];
+                    if alpha == 0xff {
+                        *pix = TargetPixel::from_rgb(v, v, v);
+                    } else {
+                        pix.blend(PremultipliedRgbaColor::premultiply(Color::from_argb_u8(
+                            alpha, v, v, v,
+                        )))
+                    }
+                }
+            }
             TexturePixelFormat::Rgba => {
                 if color.alpha() == 0 {
                     for pix in line_buffer {
@@ -280,6 +319,17 @@ fn fetch_blend_pixel(
                     pix.blend(c);
                 }
             }
+            TexturePixelFormat::SubpixelAlphaMap => {
+                let color = Rgb8Pixel::new(color.red(), color.green(), color.blue());
+                let coverage = |c: u8| ((c as u16 * alpha as u16) / 255) as u8;
+                for pix in line_buffer {
+                    let pos = pos(3).0;
+                    pix.blend_subpixel(
+                        color,
+                        [coverage(data[pos]), coverage(data[pos + 1]), coverage(data[pos + 2])],
+                    );
+                }
+            }
             TexturePixelFormat::SignedDistanceField => {
                 const RANGE: i32 = 6;
                 let factor = (362 * 256 / delta.0) * RANGE; // 362 ≃ 255 * sqrt(2)
@@ -306,10 +356,88 @@ fn fetch_blend_pixel(
                     pix.blend(c);
                 }
             }
+            TexturePixelFormat::RunLengthEncodedRgb
+            | TexturePixelFormat::RunLengthEncodedRgbaPremultiplied => {
+                unreachable!("decoded in draw_run_length_encoded_texture_line")
+            }
         };
     }
 }
 
+/// Draw one line of a run-length encoded texture in the line buffer.
+///
+/// Only the source line that is needed (or the source column, when the rendering is rotated) is
+/// decoded, and then drawn like an uncompressed texture of one line (or column).
+fn draw_run_length_encoded_texture_line(
+    span: &PhysicalRect,
+    line: PhysicalLength,
+    texture: &super::SceneTexture,
+    line_buffer: &mut [impl TargetPixel],
+    extra_clip_begin: i16,
+    extra_clip_end: i16,
+) {
+    let (Some(source), Some(format)) =
+        (texture.encoded_source, texture.format.run_length_decoded())
+    else {
+        return;
+    };
+    let extra = texture.extra;
+    let bpp = format.bpp();
+    let (x, y) = (source.origin.x as usize, source.origin.y as usize);
+    let (width, height) = (source.size.width as usize, source.size.height as usize);
+
+    let target_y = line - span.origin.y_length();
+    let target_y = if extra.rotation.mirror_width() {
+        span.size.height - target_y.get() - 1
+    } else {
+        target_y.get()
+    } as i32;
+
+    let mut decoded = alloc::vec::Vec::new();
+    let decoded_texture = if !extra.rotation.is_transpose() {
+        let row = Fixed::<i32, 8>::from_fixed(extra.off_y)
+            + Fixed::<i32, 8>::from_fixed(extra.dy) * target_y;
+        let row = (row.truncate() % height as i32) as usize;
+        crate::graphics::decode_run_length_encoded_line(
+            texture.data,
+            y + row,
+            x,
+            width,
+            bpp,
+            &mut decoded,
+        );
+        super::SceneTexture {
+            data: &decoded,
+            format,
+            pixel_stride: width as u16,
+            encoded_source: None,
+            extra: super::SceneTextureExtra { off_y: Default::default(), ..extra },
+        }
+    } else {
+        let col = Fixed::<i32, 8>::from_fixed(extra.off_x)
+            + Fixed::<i32, 8>::from_fixed(extra.dx) * target_y;
+        let col = (col.truncate() % width as i32) as usize;
+        for row in 0..height {
+            crate::graphics::decode_run_length_encoded_line(
+                texture.data,
+                y + row,
+                x + col,
+                1,
+                bpp,
+                &mut decoded,
+            );
+        }
+        super::SceneTexture {
+            data: &decoded,
+            format,
+            pixel_stride: 1,
+            encoded_source: None,
+            extra: super::SceneTextureExtra { off_x: Default::default(), ..extra },
+        }
+    };
+    draw_texture_line(span, line, &decoded_texture, line_buffer, extra_clip_begin, extra_clip_end);
+}
+
 /// draw one line of the rounded rectangle in the line buffer
 #[allow(clippy::unnecessary_cast)] // Coord
 pub(super) fn draw_rounded_rectangle_line(
@@ -553,6 +681,7 @@ pub(super) fn draw_linear_gradient(
     g: &super::LinearGradientCommand,
     mut buffer: &mut [impl TargetPixel],
     extra_left_clip: i16,
+    dither: Option<DitherPattern>,
 ) {
     let fill_col1 = g.flags & 0b010 != 0;
     let fill_col2 = g.flags & 0b100 != 0;
@@ -572,7 +701,8 @@ pub(super) fn draw_linear_gradient(
         };
         if (fill_col1 || p >= 0) && (fill_col2 || p < 255) {
             let col = interpolate_color(p.clamp(0, 255) as u32, color1, color2);
-            TargetPixel::blend_slice(buffer, col);
+            let x = rect.min_x() as i32 + extra_left_clip as i32;
+            blend_slice_dithered(buffer, col, dither, x, line.get() as i32);
         }
         return;
     }
@@ -587,6 +717,9 @@ pub(super) fn draw_linear_gradient(
         + extra_left_clip as i32;
 
     let len = ((255 * size_x) / start) as usize;
+    // The position of the first pixel of the buffer, for the dithering
+    let mut buffer_x = rect.min_x() as i32 + extra_left_clip as i32;
+    let buffer_y = line.get() as i32;
 
     if x < 0 {
         let l = (-x as usize).min(buffer.len());
@@ -598,6 +731,7 @@ pub(super) fn draw_linear_gradient(
             TargetPixel::blend_slice(&mut buffer[..l], g.color2);
         }
         buffer = &mut buffer[l..];
+        buffer_x += l as i32;
         x = 0;
     }
 
@@ -631,7 +765,22 @@ pub(super) fn draw_linear_gradient(
     let mut b = ((color1.blue as u32) << 15).wrapping_add((x * db) as _);
     let mut a = ((color1.alpha as u32) << 15).wrapping_add((x * da) as _);
 
-    if color1.alpha == 255 && color2.alpha == 255 {
+    if let Some(dither) = dither {
+        for (i, pix) in buffer.iter_mut().enumerate() {
+            let threshold = dither.threshold(buffer_x + i as i32, buffer_y);
+            let (red, green, blue, alpha) =
+                ((r >> 15) as u8, (g >> 15) as u8, (b >> 15) as u8, (a >> 15) as u8);
+            if color1.alpha == 255 && color2.alpha == 255 {
+                *pix = TargetPixel::from_rgb_dithered(red, green, blue, threshold);
+            } else {
+                pix.blend_dithered(PremultipliedRgbaColor { red, green, blue, alpha }, threshold);
+            }
+            r = r.wrapping_add(dr as _);
+            g = g.wrapping_add(dg as _);
+            b = b.wrapping_add(db as _);
+            a = a.wrapping_add(da as _);
+        }
+    } else if color1.alpha == 255 && color2.alpha == 255 {
         buffer.fill_with(|| {
             let pix = TargetPixel::from_rgb((r >> 15) as u8, (g >> 15) as u8, (b >> 15) as u8);
             r = r.wrapping_add(dr as _);
@@ -663,24 +812,15 @@ pub(super) fn draw_radial_gradient(
     buffer: &mut [impl TargetPixel],
     extra_left_clip: i16,
     _extra_right_clip: i16,
+    dither: Option<DitherPattern>,
 ) {
     if g.stops.is_empty() {
         return;
     }
 
-    let center_x = (rect.min_x() + g.center_x.get()) as i32;
-    let center_y = (rect.min_y() + g.center_y.get()) as i32;
-
-    // Calculate the maximum radius (distance from center to corner)
-    let max_radius = {
-        let dx1 = ((rect.min_x() as i32) - center_x).abs();
-        let dx2 = ((rect.max_x() as i32) - center_x).abs();
-        let dy1 = ((rect.min_y() as i32) - center_y).abs();
-        let dy2 = ((rect.max_y() as i32) - center_y).abs();
-        let max_dx = dx1.max(dx2) as f32;
-        let max_dy = dy1.max(dy2) as f32;
-        (max_dx * max_dx + max_dy * max_dy).sqrt()
-    };
+    let center_x = rect.min_x() as i32 + g.center_x.get() as i32;
+    let center_y = rect.min_y() as i32 + g.center_y.get() as i32;
+    let max_radius = g.radius.get().max(1) as f32;
 
     let start_x = rect.min_x() + extra_left_clip;
     // Use the absolute line position for distance calculation
@@ -723,7 +863,13 @@ pub(super) fn draw_radial_gradient(
             }
         }
 
-        pixel.blend(super::PremultipliedRgbaColor::from(color));
+        let color = super::PremultipliedRgbaColor::from(color);
+        match dither {
+            Some(dither) => {
+                pixel.blend_dithered(color, dither.threshold(x as i32, line.get() as i32))
+            }
+            None => pixel.blend(color),
+        }
     }
 }
 
@@ -735,14 +881,14 @@ pub(super) fn draw_conic_gradient(
     buffer: &mut [impl TargetPixel],
     extra_left_clip: i16,
     _extra_right_clip: i16,
+    dither: Option<DitherPattern>,
 ) {
     if g.stops.is_empty() {
         return;
     }
 
-    // Center is always the center of the rectangle
-    let center_x = (rect.min_x() + rect.width() / 2) as f32;
-    let center_y = (rect.min_y() + rect.height() / 2) as f32;
+    let center_x = (rect.min_x() as i32 + g.center_x.get() as i32) as f32;
+    let center_y = (rect.min_y() as i32 + g.center_y.get() as i32) as f32;
 
     let start_x = rect.min_x() + extra_left_clip;
     let y = line.get() as f32;
@@ -799,7 +945,14 @@ pub(super) fn draw_conic_gradient(
             }
         }
 
-        pixel.blend(super::PremultipliedRgbaColor::from(color));
+        let color = super::PremultipliedRgbaColor::from(color);
+        match dither {
+            Some(dither) => {
+                let threshold = dither.threshold((start_x + i as i16) as i32, line.get() as i32);
+                pixel.blend_dithered(color, threshold)
+            }
+            None => pixel.blend(color),
+        }
     }
 }
 
@@ -862,6 +1015,78 @@ fn blend_slice(slice: &mut [Self], color: PremultipliedRgbaColor) {
     fn background() -> Self {
         Self::from_rgb(0, 0, 0)
     }
+
+    /// Create a pixel from the red, green, blue component in the range 0..=255, using the
+    /// dithering `threshold` in the range 0..16 to round the components when the pixel has
+    /// less than 8 bits per component.
+    ///
+    /// The default implementation ignores the threshold.
+    fn from_rgb_dithered(red: u8, green: u8, blue: u8, threshold: u8) -> Self {
+        let _ = threshold;
+        Self::from_rgb(red, green, blue)
+    }
+
+    /// Blend a single pixel with a color, using the dithering `threshold` in the range 0..16 to
+    /// round the result when the pixel has less than 8 bits per component.
+    ///
+    /// The default implementation ignores the threshold.
+    fn blend_dithered(&mut self, color: PremultipliedRgbaColor, threshold: u8) {
+        let _ = threshold;
+        self.blend(color)
+    }
+
+    /// Blend a single pixel with an opaque color, using a separate coverage for the red, green,
+    /// and blue sub-pixels. This is used for sub-pixel anti-aliased text.
+    ///
+    /// The default implementation blends with the average of the three coverages.
+    fn blend_subpixel(&mut self, color: Rgb8Pixel, coverage: [u8; 3]) {
+        let alpha = ((coverage[0] as u16 + coverage[1] as u16 + coverage[2] as u16) / 3) as u8;
+        self.blend(PremultipliedRgbaColor::premultiply(Color::from_argb_u8(
+            alpha, color.r, color.g, color.b,
+        )));
+    }
+}
+
+/// The 4x4 Bayer matrix used for ordered dithering
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// The ordered dithering pattern applied to gradients. See [`super::Dithering`]
+#[derive(Clone, Copy, Debug, Default)]
+pub(super) struct DitherPattern {
+    /// Offset of the thresholds, changed on every frame for the temporal dithering
+    pub phase: u8,
+}
+
+impl DitherPattern {
+    /// The dithering threshold, in the range 0..16, for the pixel at the given position
+    pub fn threshold(self, x: i32, y: i32) -> u8 {
+        (BAYER_4X4[(y & 3) as usize][(x & 3) as usize] + 4 * (self.phase & 3)) & 15
+    }
+}
+
+/// Blend a color to all the pixels of the slice, which starts at the position (x, y),
+/// with dithering if enabled
+fn blend_slice_dithered<T: TargetPixel>(
+    buffer: &mut [T],
+    color: PremultipliedRgbaColor,
+    dither: Option<DitherPattern>,
+    x: i32,
+    y: i32,
+) {
+    let Some(dither) = dither else { return T::blend_slice(buffer, color) };
+    for (i, pix) in buffer.iter_mut().enumerate() {
+        let threshold = dither.threshold(x + i as i32, y);
+        if color.alpha == u8::MAX {
+            *pix = T::from_rgb_dithered(color.red, color.green, color.blue, threshold);
+        } else {
+            pix.blend_dithered(color, threshold);
+        }
+    }
+}
+
+/// Blend one color component of a pixel with the coverage of the corresponding sub-pixel
+fn blend_subpixel_component(target: u8, source: u8, coverage: u8) -> u8 {
+    ((source as u16 * coverage as u16 + target as u16 * (u8::MAX - coverage) as u16) / 255) as u8
 }
 
 impl TargetPixel for crate::graphics::image::Rgb8Pixel {
@@ -875,6 +1100,12 @@ fn blend(&mut self, color: PremultipliedRgbaColor) {
     fn from_rgb(r: u8, g: u8, b: u8) -> Self {
         Self::new(r, g, b)
     }
+
+    fn blend_subpixel(&mut self, color: Rgb8Pixel, coverage: [u8; 3]) {
+        self.r = blend_subpixel_component(self.r, color.r, coverage[0]);
+        self.g = blend_subpixel_component(self.g, color.g, coverage[1]);
+        self.b = blend_subpixel_component(self.b, color.b, coverage[2]);
+    }
 }
 
 impl TargetPixel for PremultipliedRgbaColor {
@@ -894,35 +1125,13 @@ fn from_rgb(r: u8, g: u8, b: u8) -> Self {
     fn background() -> Self {
         Self { red: 0, green: 0, blue: 0, alpha: 0 }
     }
-}
-
-/// A 16bit pixel that has 5 red bits, 6 green bits and  5 blue bits
-#[repr(transparent)]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, bytemuck::Pod, bytemuck::Zeroable)]
-pub struct Rgb565Pixel(pub u16);
 
-impl Rgb565Pixel {
-    const R_MASK: u16 = 0b1111_1000_0000_0000;
-    const G_MASK: u16 = 0b0000_0111_1110_0000;
-    const B_MASK: u16 = 0b0000_0000_0001_1111;
-
-    /// Return the red component as a u8.
-    ///
-    /// The bits are shifted so that the result is between 0 and 255
-    fn red(self) -> u8 {
-        ((self.0 & Self::R_MASK) >> 8) as u8
-    }
-    /// Return the green component as a u8.
-    ///
-    /// The bits are shifted so that the result is between 0 and 255
-    fn green(self) -> u8 {
-        ((self.0 & Self::G_MASK) >> 3) as u8
-    }
-    /// Return the blue component as a u8.
-    ///
-    /// The bits are shifted so that the result is between 0 and 255
-    fn blue(self) -> u8 {
-        ((self.0 & Self::B_MASK) << 3) as u8
+    fn blend_subpixel(&mut self, color: Rgb8Pixel, coverage: [u8; 3]) {
+        self.red = blend_subpixel_component(self.red, color.r, coverage[0]);
+        self.green = blend_subpixel_component(self.green, color.g, coverage[1]);
+        self.blue = blend_subpixel_component(self.blue, color.b, coverage[2]);
+        let alpha = coverage.into_iter().max().unwrap_or_default();
+        self.alpha = blend_subpixel_component(self.alpha, u8::MAX, alpha);
     }
 }
 
@@ -949,19 +1158,27 @@ fn blend(&mut self, color: PremultipliedRgbaColor) {
     }
 
     fn from_rgb(r: u8, g: u8, b: u8) -> Self {
-        Self(((r as u16 & 0b11111000) << 8) | ((g as u16 & 0b11111100) << 3) | (b as u16 >> 3))
+        Rgb8Pixel { r, g, b }.into()
     }
-}
 
-impl From<Rgb8Pixel> for Rgb565Pixel {
-    fn from(p: Rgb8Pixel) -> Self {
-        Self::from_rgb(p.r, p.g, p.b)
+    fn blend_subpixel(&mut self, color: Rgb8Pixel, coverage: [u8; 3]) {
+        let mut rgb: Rgb8Pixel = (*self).into();
+        rgb.blend_subpixel(color, coverage);
+        *self = rgb.into();
+    }
+
+    fn from_rgb_dithered(r: u8, g: u8, b: u8, threshold: u8) -> Self {
+        // The 5 bits components drop 8 levels, and the 6 bits component drops 4 levels
+        let (t5, t6) = (threshold >> 1, threshold >> 2);
+        Self::from_rgb(r.saturating_add(t5), g.saturating_add(t6), b.saturating_add(t5))
     }
-}
 
-impl From<Rgb565Pixel> for Rgb8Pixel {
-    fn from(p: Rgb565Pixel) -> Self {
-        Rgb8Pixel { r: p.red(), g: p.green(), b: p.blue() }
+    fn blend_dithered(&mut self, color: PremultipliedRgbaColor, threshold: u8) {
+        // The low bits of the converted components are zero, so the rounding preserves the
+        // existing color where it isn't covered
+        let mut rgb: Rgb8Pixel = (*self).into();
+        rgb.blend(color);
+        *self = Self::from_rgb_dithered(rgb.r, rgb.g, rgb.b, threshold);
     }
 }
 
@@ -975,3 +1192,42 @@ fn rgb565() {
     let pix888: Rgb8Pixel = pix565.into();
     assert_eq!(pix565, pix888.into());
 }
+
+#[test]
+fn blend_subpixel() {
+    let mut pix = Rgb8Pixel::new(0, 0, 0);
+    pix.blend_subpixel(Rgb8Pixel::new(255, 255, 255), [255, 128, 0]);
+    assert_eq!(pix, Rgb8Pixel::new(255, 128, 0));
+
+    let mut pix = PremultipliedRgbaColor::background();
+    pix.blend_subpixel(Rgb8Pixel::new(200, 100, 50), [255, 255, 0]);
+    assert_eq!((pix.red, pix.green, pix.blue, pix.alpha), (200, 100, 0, 255));
+
+    let mut pix = Rgb565Pixel::from_rgb(0, 0, 0);
+    pix.blend_subpixel(Rgb8Pixel::new(255, 255, 255), [0, 0, 255]);
+    assert_eq!(pix, Rgb565Pixel::from_rgb(0, 0, 255));
+}
+
+#[test]
+fn rgb565_dithering() {
+    let pattern = DitherPattern::default();
+    let mut seen = [false; 16];
+    for (x, y) in (0..4).flat_map(|y| (0..4).map(move |x| (x, y))) {
+        seen[pattern.threshold(x, y) as usize] = true;
+    }
+    assert!(seen.iter().all(|s| *s));
+
+    // A value half-way between two levels is rounded up for half of the pixels
+    let rounded_up = (0..16)
+        .filter(|t| Rgb565Pixel::from_rgb_dithered(0x84, 0x82, 0x84, *t) == Rgb565Pixel(0x8c31))
+        .count();
+    assert_eq!(rounded_up, 8);
+    assert_eq!(Rgb565Pixel::from_rgb_dithered(0xff, 0xff, 0xff, 15), Rgb565Pixel(0xffff));
+
+    // A transparent color doesn't change the pixel
+    let mut pix = Rgb565Pixel(0x1234);
+    for t in 0..16 {
+        pix.blend_dithered(PremultipliedRgbaColor::default(), t);
+        assert_eq!(pix, Rgb565Pixel(0x1234));
+    }
+}
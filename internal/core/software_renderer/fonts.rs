@@ -1,11 +1,11 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use alloc::rc::Rc;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::cell::RefCell;
 
-use super::{Fixed, PhysicalLength, PhysicalSize};
+use super::{Fixed, PhysicalLength, PhysicalSize, SubpixelLayout};
 use crate::graphics::{BitmapFont, FontRequest};
 use crate::lengths::{LogicalLength, ScaleFactor};
 use crate::textlayout::TextLayout;
@@ -18,7 +18,7 @@
 #[derive(derive_more::From, Clone)]
 pub enum GlyphAlphaMap {
     Static(&'static [u8]),
-    Shared(Rc<[u8]>),
+    Shared(Arc<[u8]>),
 }
 
 #[derive(Clone)]
@@ -30,6 +30,8 @@ pub struct RenderableGlyph {
     pub alpha_map: GlyphAlphaMap,
     pub pixel_stride: u16,
     pub sdf: bool,
+    /// The alpha map has three bytes per pixel, for the red, green, and blue sub-pixels
+    pub subpixel: bool,
 }
 
 impl RenderableGlyph {
@@ -46,9 +48,11 @@ pub struct RenderableVectorGlyph {
     pub y: Fixed<i32, 8>,
     pub width: PhysicalLength,
     pub height: PhysicalLength,
-    pub alpha_map: Rc<[u8]>,
+    pub alpha_map: Arc<[u8]>,
     pub pixel_stride: u16,
     pub bounds: fontdue::OutlineBounds,
+    /// The alpha map has three bytes per pixel, for the red, green, and blue sub-pixels
+    pub subpixel: bool,
 }
 
 #[cfg(feature = "software-renderer-systemfonts")]
@@ -59,7 +63,12 @@ pub fn size(&self) -> PhysicalSize {
 }
 
 pub trait GlyphRenderer {
-    fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> Option<RenderableGlyph>;
+    /// Render the glyph. Fonts that support it use sub-pixel anti-aliasing for the given layout.
+    fn render_glyph(
+        &self,
+        glyph_id: core::num::NonZeroU16,
+        subpixel_layout: SubpixelLayout,
+    ) -> Option<RenderableGlyph>;
     /// The amount of pixel in the original image that correspond to one pixel in the rendered image
     fn scale_delta(&self) -> Fixed<u16, 8>;
 }
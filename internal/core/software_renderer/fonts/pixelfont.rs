@@ -32,7 +32,11 @@ pub fn glyph_id_to_glyph_index(id: core::num::NonZeroU16) -> usize {
 }
 
 impl GlyphRenderer for PixelFont {
-    fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> Option<RenderableGlyph> {
+    fn render_glyph(
+        &self,
+        glyph_id: core::num::NonZeroU16,
+        _subpixel_layout: super::SubpixelLayout,
+    ) -> Option<RenderableGlyph> {
         let glyph_index = Self::glyph_id_to_glyph_index(glyph_id);
         let bitmap_glyph = &self.glyphs.glyph_data[glyph_index];
         if bitmap_glyph.data.len() == 0 {
@@ -63,6 +67,7 @@ fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> Option<RenderableGlyp
             alpha_map: bitmap_glyph.data.as_slice().into(),
             pixel_stride: bitmap_glyph.width as u16,
             sdf: self.bitmap_font.sdf,
+            subpixel: false,
         })
     }
     fn scale_delta(&self) -> Fixed<u16, 8> {
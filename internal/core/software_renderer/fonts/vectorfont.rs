@@ -4,6 +4,7 @@
 use core::num::NonZeroU16;
 
 use alloc::rc::Rc;
+use alloc::sync::Arc;
 use skrifa::MetadataProvider;
 
 use crate::lengths::PhysicalPx;
@@ -12,16 +13,16 @@
 use crate::textlayout::{Glyph, TextShaper};
 use i_slint_common::sharedfontique::fontique;
 
-use super::RenderableVectorGlyph;
+use super::{RenderableVectorGlyph, SubpixelLayout};
 
 // A length in font design space.
 struct FontUnit;
 type FontLength = euclid::Length<i32, FontUnit>;
 type FontScaleFactor = euclid::Scale<f32, FontUnit, PhysicalPx>;
 
-type GlyphCacheKey = (u64, u32, PhysicalLength, core::num::NonZeroU16);
+type GlyphCacheKey = (u64, u32, PhysicalLength, core::num::NonZeroU16, SubpixelLayout);
 
-struct RenderableGlyphWeightScale;
+pub(crate) struct RenderableGlyphWeightScale;
 
 impl clru::WeightScale<GlyphCacheKey, RenderableVectorGlyph> for RenderableGlyphWeightScale {
     fn weight(&self, _: &GlyphCacheKey, value: &RenderableVectorGlyph) -> usize {
@@ -29,17 +30,19 @@ fn weight(&self, _: &GlyphCacheKey, value: &RenderableVectorGlyph) -> usize {
     }
 }
 
-type GlyphCache = clru::CLruCache<
+pub(crate) type GlyphCache = clru::CLruCache<
     GlyphCacheKey,
     RenderableVectorGlyph,
     std::collections::hash_map::RandomState,
     RenderableGlyphWeightScale,
 >;
 
-crate::thread_local!(static GLYPH_CACHE: core::cell::RefCell<GlyphCache>  =
+crate::thread_local!(pub(crate) static GLYPH_CACHE: core::cell::RefCell<GlyphCache>  =
     core::cell::RefCell::new(
         clru::CLruCache::with_config(
-            clru::CLruCacheConfig::new(core::num::NonZeroUsize::new(1024 * 1024).unwrap())
+            clru::CLruCacheConfig::new(core::num::NonZeroUsize::new(
+                crate::resource_cache::ResourceCache::Glyphs.default_limit(),
+            ).unwrap())
                 .with_scale(RenderableGlyphWeightScale)
         )
     )
@@ -97,22 +100,37 @@ pub fn new_from_blob_and_index(
         }
     }
 
+    /// Render the glyph. Unless the `subpixel_layout` is [`SubpixelLayout::None`], the alpha map
+    /// has three bytes per pixel, with the alpha of the red, green, and blue sub-pixels.
     pub fn render_vector_glyph(
         &self,
         glyph_id: core::num::NonZeroU16,
+        subpixel_layout: SubpixelLayout,
     ) -> Option<RenderableVectorGlyph> {
         GLYPH_CACHE.with(|cache| {
             let mut cache = cache.borrow_mut();
 
-            let cache_key = (self.font_blob.id(), self.font_index, self.pixel_size, glyph_id);
+            let cache_key =
+                (self.font_blob.id(), self.font_index, self.pixel_size, glyph_id, subpixel_layout);
 
             if let Some(entry) = cache.get(&cache_key) {
                 Some(entry.clone())
             } else {
-                let (metrics, alpha_map) =
-                    self.fontdue_font.rasterize_indexed(glyph_id.get(), self.pixel_size.get() as _);
+                let px = self.pixel_size.get() as _;
+                let (metrics, mut alpha_map) = match subpixel_layout {
+                    SubpixelLayout::None => self.fontdue_font.rasterize_indexed(glyph_id.get(), px),
+                    SubpixelLayout::Rgb | SubpixelLayout::Bgr => {
+                        self.fontdue_font.rasterize_indexed_subpixel(glyph_id.get(), px)
+                    }
+                };
+                if subpixel_layout == SubpixelLayout::Bgr {
+                    // fontdue computes the coverage for an RGB layout, which is mirrored for BGR
+                    for pixel in alpha_map.chunks_exact_mut(3) {
+                        pixel.swap(0, 2);
+                    }
+                }
 
-                let alpha_map: Rc<[u8]> = alpha_map.into();
+                let alpha_map: Arc<[u8]> = alpha_map.into();
 
                 let glyph = super::RenderableVectorGlyph {
                     x: Fixed::from_integer(metrics.xmin.try_into().unwrap()),
@@ -122,9 +140,18 @@ pub fn render_vector_glyph(
                     alpha_map,
                     pixel_stride: metrics.width.try_into().unwrap(),
                     bounds: metrics.bounds,
+                    subpixel: subpixel_layout != SubpixelLayout::None,
                 };
 
-                cache.put_with_weight(cache_key, glyph.clone()).ok();
+                crate::resource_cache::put(
+                    crate::resource_cache::ResourceCache::Glyphs,
+                    &mut *cache,
+                    cache_key,
+                    glyph.clone(),
+                    &|key, value| {
+                        clru::WeightScale::weight(&RenderableGlyphWeightScale, key, value)
+                    },
+                );
                 Some(glyph)
             }
         })
@@ -200,8 +227,12 @@ fn cap_height(&self) -> PhysicalLength {
 }
 
 impl super::GlyphRenderer for VectorFont {
-    fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> Option<super::RenderableGlyph> {
-        self.render_vector_glyph(glyph_id).map(|glyph| super::RenderableGlyph {
+    fn render_glyph(
+        &self,
+        glyph_id: core::num::NonZeroU16,
+        subpixel_layout: SubpixelLayout,
+    ) -> Option<super::RenderableGlyph> {
+        self.render_vector_glyph(glyph_id, subpixel_layout).map(|glyph| super::RenderableGlyph {
             x: glyph.x,
             y: glyph.y,
             width: glyph.width,
@@ -209,6 +240,7 @@ fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> Option<super::Rendera
             alpha_map: glyph.alpha_map.into(),
             pixel_stride: glyph.pixel_stride,
             sdf: false,
+            subpixel: glyph.subpixel,
         })
     }
 
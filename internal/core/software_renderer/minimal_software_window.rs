@@ -38,7 +38,11 @@ pub fn new(repaint_buffer_type: RepaintBufferType) -> Rc<Self> {
     /// Return true if something was redrawn.
     pub fn draw_if_needed(&self, render_callback: impl FnOnce(&SoftwareRenderer)) -> bool {
         if self.needs_redraw.replace(false) || self.renderer.rendering_metrics_collector.is_some() {
+            #[cfg(feature = "alloc-stats")]
+            crate::alloc_stats::begin_frame();
             render_callback(&self.renderer);
+            #[cfg(feature = "alloc-stats")]
+            crate::alloc_stats::end_frame();
             true
         } else {
             false
@@ -58,7 +62,11 @@ pub async fn draw_async_if_needed(
         render_callback: impl AsyncFnOnce(&SoftwareRenderer),
     ) -> bool {
         if self.needs_redraw.replace(false) || self.renderer.rendering_metrics_collector.is_some() {
+            #[cfg(feature = "alloc-stats")]
+            crate::alloc_stats::begin_frame();
             render_callback(&self.renderer).await;
+            #[cfg(feature = "alloc-stats")]
+            crate::alloc_stats::end_frame();
             true
         } else {
             false
@@ -94,7 +102,9 @@ fn set_size(&self, size: crate::api::WindowSize) {
     }
 
     fn request_redraw(&self) {
-        self.needs_redraw.set(true);
+        if !self.needs_redraw.replace(true) {
+            crate::platform::request_redraw_at(None);
+        }
     }
 }
 
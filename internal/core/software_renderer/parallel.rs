@@ -0,0 +1,114 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Rendering of the scene in horizontal bands on the rayon thread pool
+
+use super::*;
+use rayon::prelude::*;
+
+/// The minimum number of lines of a band, so that small dirty regions are not split too much
+const MIN_BAND_HEIGHT: usize = 16;
+
+/// The lines `first_line..` of the buffer
+struct BandLineBuffer<'a, T> {
+    data: &'a mut [T],
+    pixel_stride: usize,
+    first_line: usize,
+}
+
+impl<T: TargetPixel> LineBufferProvider for BandLineBuffer<'_, T> {
+    type TargetPixel = T;
+
+    fn process_line(
+        &mut self,
+        line: usize,
+        range: core::ops::Range<usize>,
+        render_fn: impl FnOnce(&mut [Self::TargetPixel]),
+    ) {
+        let begin = (line - self.first_line) * self.pixel_stride;
+        render_fn(&mut self.data[begin..][range]);
+    }
+}
+
+impl SoftwareRenderer {
+    /// Render the window to the given frame buffer, splitting the dirty region in horizontal
+    /// bands that are rendered in parallel on the [rayon](https://docs.rs/rayon) thread pool.
+    ///
+    /// This produces the same result as [`Self::render()`], with the same meaning for the
+    /// `pixel_stride` and the returned region. It is only faster when large parts of the window
+    /// need to be redrawn, for example with large windows on a desktop.
+    pub fn render_parallel(
+        &self,
+        buffer: &mut [impl TargetPixel + Send + Sync],
+        pixel_stride: usize,
+    ) -> PhysicalRegion {
+        let Some(window) = self.maybe_window_adapter.borrow().as_ref().and_then(|w| w.upgrade())
+        else {
+            return Default::default();
+        };
+        let window_inner = WindowInner::from_pub(window.window());
+        let component_rc = window_inner.component();
+        let component = crate::item_tree::ItemTreeRc::borrow_pin(&component_rc);
+        let Some(window_item) = crate::items::ItemRef::downcast_pin::<crate::items::WindowItem>(
+            component.as_ref().get_item_ref(0),
+        ) else {
+            return Default::default();
+        };
+        let factor = ScaleFactor::new(self.effective_scale_factor(window_inner));
+        let size =
+            LogicalSize::from_lengths(window_item.width(), window_item.height()).cast() * factor;
+
+        let mut scene = prepare_scene(window_inner, size.cast(), self);
+        let dither = self.next_dither_pattern();
+
+        let mut background_color = TargetPixel::background();
+        // FIXME gradient
+        TargetPixel::blend(&mut background_color, window_item.background().color().into());
+
+        let dirty_region = scene.dirty_region.clone();
+        let bounding_rect = dirty_region.bounding_rect();
+        if bounding_rect.is_empty() {
+            return dirty_region;
+        }
+        let items = core::mem::take(&mut scene.items);
+        let vectors = &scene.vectors;
+
+        let band_height = (bounding_rect.height() as usize)
+            .div_ceil(rayon::current_num_threads() * 4)
+            .max(MIN_BAND_HEIGHT);
+        let first_line = bounding_rect.min_y() as usize;
+        let last_line = bounding_rect.max_y() as usize;
+        let bands = buffer[first_line * pixel_stride..].par_chunks_mut(band_height * pixel_stride);
+        bands.enumerate().for_each(|(index, data)| {
+            let band_line = first_line + index * band_height;
+            if band_line >= last_line {
+                return;
+            }
+            let band = PhysicalRect::new(
+                PhysicalPoint::new(bounding_rect.min_x(), band_line as _),
+                PhysicalSize::new(
+                    bounding_rect.width(),
+                    band_height.min(last_line - band_line) as _,
+                ),
+            );
+            let region = dirty_region.intersection(&band);
+            if region.bounding_rect().is_empty() {
+                return;
+            }
+            let mut band_scene =
+                Scene::new(items.clone(), Default::default(), region, Default::default());
+            render_scene_lines(
+                &mut band_scene,
+                vectors,
+                background_color,
+                dither,
+                &mut BandLineBuffer { data, pixel_stride, first_line: band_line },
+            );
+        });
+
+        scene.items = items;
+        let vectors = core::mem::take(&mut scene.vectors);
+        self.recycle_scene(scene, vectors);
+        dirty_region
+    }
+}
@@ -10,7 +10,7 @@
 use crate::graphics::{SharedImageBuffer, TexturePixelFormat};
 use crate::lengths::{PointLengths as _, SizeLengths as _};
 use crate::Color;
-use alloc::rc::Rc;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use euclid::Length;
 
@@ -24,6 +24,29 @@ pub struct SceneVectors {
     pub conic_gradients: Vec<ConicGradientCommand>,
 }
 
+impl SceneVectors {
+    /// Remove all the commands, but keep the allocated memory
+    pub fn clear(&mut self) {
+        self.textures.clear();
+        self.rounded_rectangles.clear();
+        self.shared_buffers.clear();
+        self.linear_gradients.clear();
+        self.radial_gradients.clear();
+        self.conic_gradients.clear();
+    }
+
+    /// Reserve room for `additional` commands of each kind, as the commands of a scene could all
+    /// be of the same kind
+    pub fn reserve(&mut self, additional: usize) {
+        self.textures.reserve(additional);
+        self.rounded_rectangles.reserve(additional);
+        self.shared_buffers.reserve(additional);
+        self.linear_gradients.reserve(additional);
+        self.radial_gradients.reserve(additional);
+        self.conic_gradients.reserve(additional);
+    }
+}
+
 pub struct Scene {
     /// the next line to be processed
     pub(super) current_line: PhysicalLength,
@@ -51,6 +74,7 @@ pub fn new(
         mut items: Vec<SceneItem>,
         vectors: SceneVectors,
         dirty_region: PhysicalRegion,
+        current_line_ranges: Vec<core::ops::Range<i16>>,
     ) -> Self {
         let current_line =
             dirty_region.iter_box().map(|x| x.min.y_length()).min().unwrap_or_default();
@@ -65,7 +89,7 @@ pub fn new(
             future_items_index: current_items_index,
             vectors,
             dirty_region,
-            current_line_ranges: Default::default(),
+            current_line_ranges,
             range_valid_until_line: Default::default(),
         };
         r.recompute_ranges();
@@ -297,12 +321,18 @@ pub struct SceneTexture<'a> {
     pub format: TexturePixelFormat,
     /// number of pixels between two lines in the source
     pub pixel_stride: u16,
+    /// For the run-length encoded formats, `data` is the whole encoded texture, and this is
+    /// the rectangle of the pixels to draw within it.
+    pub encoded_source: Option<PhysicalRect>,
 
     pub extra: SceneTextureExtra,
 }
 
 impl<'a> SceneTexture<'a> {
     pub fn source_size(&self) -> PhysicalSize {
+        if let Some(source) = self.encoded_source {
+            return source.size;
+        }
         let mut len = self.data.len();
         if self.format == TexturePixelFormat::SignedDistanceField {
             len -= 1;
@@ -330,6 +360,7 @@ pub fn from_target_texture(
                 data: source.data,
                 pixel_stride: (source.byte_stride / source.pixel_format.bpp()) as u16,
                 format: source.pixel_format,
+                encoded_source: source.encoded_source(),
                 extra,
             },
             geometry,
@@ -417,7 +448,15 @@ pub fn from_target_texture(
 #[derive(Clone)]
 pub enum SharedBufferData {
     SharedImage(SharedImageBuffer),
-    AlphaMap { data: Rc<[u8]>, width: u16 },
+    AlphaMap {
+        data: Arc<[u8]>,
+        width: u16,
+    },
+    /// Like AlphaMap, but with three bytes per pixel: the alpha of the red, green, and blue sub-pixels
+    SubpixelAlphaMap {
+        data: Arc<[u8]>,
+        width: u16,
+    },
 }
 
 impl SharedBufferData {
@@ -425,6 +464,7 @@ pub fn width(&self) -> usize {
         match self {
             SharedBufferData::SharedImage(image) => image.width() as usize,
             SharedBufferData::AlphaMap { width, .. } => *width as usize,
+            SharedBufferData::SubpixelAlphaMap { width, .. } => *width as usize,
         }
     }
     #[allow(unused)]
@@ -432,6 +472,9 @@ pub fn height(&self) -> usize {
         match self {
             SharedBufferData::SharedImage(image) => image.height() as usize,
             SharedBufferData::AlphaMap { data, width, .. } => data.len() / *width as usize,
+            SharedBufferData::SubpixelAlphaMap { data, width, .. } => {
+                data.len() / (*width as usize * 3)
+            }
         }
     }
 }
@@ -453,12 +496,14 @@ pub fn as_texture(&self) -> SceneTexture<'_> {
                 data: &b.as_bytes()[start * 3..end * 3],
                 pixel_stride: stride as u16,
                 format: TexturePixelFormat::Rgb,
+                encoded_source: None,
                 extra: self.extra,
             },
             SharedBufferData::SharedImage(SharedImageBuffer::RGBA8(b)) => SceneTexture {
                 data: &b.as_bytes()[start * 4..end * 4],
                 pixel_stride: stride as u16,
                 format: TexturePixelFormat::Rgba,
+                encoded_source: None,
                 extra: self.extra,
             },
             SharedBufferData::SharedImage(SharedImageBuffer::RGBA8Premultiplied(b)) => {
@@ -466,13 +511,36 @@ pub fn as_texture(&self) -> SceneTexture<'_> {
                     data: &b.as_bytes()[start * 4..end * 4],
                     pixel_stride: stride as u16,
                     format: TexturePixelFormat::RgbaPremultiplied,
+                    encoded_source: None,
                     extra: self.extra,
                 }
             }
+            SharedBufferData::SharedImage(SharedImageBuffer::RGB565(b)) => SceneTexture {
+                data: &bytemuck::cast_slice(b.as_slice())[start * 2..end * 2],
+                pixel_stride: stride as u16,
+                format: TexturePixelFormat::Rgb565,
+                encoded_source: None,
+                extra: self.extra,
+            },
+            SharedBufferData::SharedImage(SharedImageBuffer::Gray8(b)) => SceneTexture {
+                data: &b.as_bytes()[start..end],
+                pixel_stride: stride as u16,
+                format: TexturePixelFormat::Gray,
+                encoded_source: None,
+                extra: self.extra,
+            },
             SharedBufferData::AlphaMap { data, width } => SceneTexture {
                 data: &data[start..end],
                 pixel_stride: *width,
                 format: TexturePixelFormat::AlphaMap,
+                encoded_source: None,
+                extra: self.extra,
+            },
+            SharedBufferData::SubpixelAlphaMap { data, width } => SceneTexture {
+                data: &data[start * 3..end * 3],
+                pixel_stride: *width,
+                format: TexturePixelFormat::SubpixelAlphaMap,
+                encoded_source: None,
                 extra: self.extra,
             },
         }
@@ -539,12 +607,15 @@ pub struct RadialGradientCommand {
     /// Center of the gradient relative to the item position
     pub center_x: PhysicalLength,
     pub center_y: PhysicalLength,
+    /// Distance from the center at which the last stop is reached: half the diagonal of the
+    /// unclipped item
+    pub radius: PhysicalLength,
 }
 
 /// Conic gradient that interpolates colors around a center point
 ///
 /// The gradient creates a color transition that rotates around the center of the
-/// rectangle being drawn. The angle positions are specified in the gradient stops,
+/// item, even if only a clipped part of it is drawn. The angle positions are specified in the gradient stops,
 /// where 0 = 0 degrees (north) and 1 = 360 degrees. Colors are interpolated based
 /// on the angle from north, going clockwise.
 #[derive(Debug)]
@@ -552,4 +623,7 @@ pub struct ConicGradientCommand {
     /// The gradient stops (colors and normalized angle positions)
     /// Position 0 = 0 degrees (north), 1 = 360 degrees
     pub stops: crate::SharedVector<crate::graphics::GradientStop>,
+    /// Center of the gradient relative to the item position
+    pub center_x: PhysicalLength,
+    pub center_y: PhysicalLength,
 }
@@ -20,6 +20,9 @@ pub struct TextureData<'a> {
     pub width: u32,
     /// The height of the texture in pixels.
     pub height: u32,
+    /// For the run-length encoded formats, `data` is the whole encoded texture, and this is
+    /// the position of the pixels to draw within it.
+    pub(super) encoded_origin: PhysicalPoint,
 }
 
 impl<'a> TextureData<'a> {
@@ -30,7 +33,18 @@ pub fn new(
         size: IntSize,
     ) -> Self {
         let (width, height) = (size.width, size.height);
-        Self { data, pixel_format, byte_stride, width, height }
+        Self { data, pixel_format, byte_stride, width, height, encoded_origin: Default::default() }
+    }
+
+    /// For the run-length encoded formats, returns the rectangle of the pixels to draw within the
+    /// encoded texture.
+    pub(super) fn encoded_source(&self) -> Option<PhysicalRect> {
+        self.pixel_format.run_length_decoded().map(|_| {
+            PhysicalRect::new(
+                self.encoded_origin,
+                PhysicalSize::new(self.width as _, self.height as _),
+            )
+        })
     }
 }
 
@@ -119,17 +133,46 @@ pub fn source(&self) -> TextureData<'_> {
                             size,
                         )
                     }
+                    SharedBufferData::SharedImage(SharedImageBuffer::RGB565(b)) => {
+                        TextureData::new(
+                            &bytemuck::cast_slice(b.as_slice())[start * 2..end * 2],
+                            TexturePixelFormat::Rgb565,
+                            stride * 2,
+                            size,
+                        )
+                    }
+                    SharedBufferData::SharedImage(SharedImageBuffer::Gray8(b)) => TextureData::new(
+                        &b.as_bytes()[start..end],
+                        TexturePixelFormat::Gray,
+                        stride,
+                        size,
+                    ),
                     SharedBufferData::AlphaMap { data, .. } => TextureData::new(
                         &data[start..end],
                         TexturePixelFormat::AlphaMap,
                         stride,
                         size,
                     ),
+                    SharedBufferData::SubpixelAlphaMap { data, .. } => TextureData::new(
+                        &data[start * 3..end * 3],
+                        TexturePixelFormat::SubpixelAlphaMap,
+                        stride * 3,
+                        size,
+                    ),
                 }
             }
         }
     }
 
+    /// Run-length encoded textures are always drawn by the software renderer, which decodes
+    /// them line by line.
+    pub(super) fn is_run_length_encoded(&self) -> bool {
+        match &self.data {
+            TextureDataContainer::Static(data) => data.pixel_format.run_length_decoded().is_some(),
+            TextureDataContainer::Shared { .. } => false,
+        }
+    }
+
     pub(super) fn source_size(&self) -> PhysicalSize {
         match &self.data {
             TextureDataContainer::Static(data) => {
@@ -0,0 +1,380 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+This module contains the values exposed by the `System` global in .slint files.
+
+The values are stored in properties of the [`SlintContext`](crate::SlintContext), so that the
+bindings that read them are re-evaluated when they change.
+*/
+
+use crate::timers::{Timer, TimerMode};
+use crate::{Property, SharedString};
+use alloc::boxed::Box;
+use core::cell::RefCell;
+use core::pin::Pin;
+
+const MILLISECONDS_PER_DAY: i64 = 24 * 60 * 60 * 1000;
+
+/// A source of wall-clock time, such as the clock of the operating system, or the real-time clock
+/// (RTC) of a microcontroller.
+///
+/// The wall-clock time is used by the `System` global, for clocks and schedules. It's distinct
+/// from the monotonic time of [`Platform::duration_since_start()`](crate::platform::Platform::duration_since_start)
+/// used for animations and timers: adjusting the wall clock or changing the time zone doesn't affect
+/// running animations.
+///
+/// With the `std` feature, the default wall clock is the clock of the operating system, in its local time zone.
+/// Without it, there is no default wall clock and the time is the time elapsed since the start of the
+/// application, in UTC. Set a wall clock with [`set_wall_clock()`].
+pub trait WallClock {
+    /// Returns the number of milliseconds elapsed since the Unix epoch (1970-01-01 00:00:00 UTC)
+    fn unix_time_millis(&self) -> i64;
+
+    /// Returns the offset of the local time zone from UTC in seconds, at the given time.
+    ///
+    /// The default implementation returns 0, meaning that the local time is UTC.
+    fn utc_offset_seconds(&self, _unix_time_millis: i64) -> i32 {
+        0
+    }
+
+    /// Returns the name of the local time zone, such as `Europe/Berlin`, or an empty string if it is unknown.
+    fn time_zone_name(&self) -> SharedString {
+        SharedString::default()
+    }
+}
+
+/// The default [`WallClock`]
+struct DefaultWallClock;
+
+#[cfg(feature = "std")]
+impl WallClock for DefaultWallClock {
+    fn unix_time_millis(&self) -> i64 {
+        chrono::Utc::now().timestamp_millis()
+    }
+
+    fn utc_offset_seconds(&self, unix_time_millis: i64) -> i32 {
+        use chrono::TimeZone;
+        chrono::DateTime::from_timestamp_millis(unix_time_millis).map_or(0, |utc| {
+            chrono::Local.offset_from_utc_datetime(&utc.naive_utc()).local_minus_utc()
+        })
+    }
+
+    fn time_zone_name(&self) -> SharedString {
+        if let Ok(tz) = std::env::var("TZ") {
+            return tz.trim_start_matches(':').into();
+        }
+        // /etc/localtime is usually a link to /usr/share/zoneinfo/<name>
+        #[cfg(unix)]
+        if let Ok(path) = std::fs::read_link("/etc/localtime") {
+            if let Some((_, name)) = path.to_string_lossy().split_once("zoneinfo/") {
+                return name.into();
+            }
+        }
+        SharedString::default()
+    }
+}
+
+/// Without a clock, this is the time since the start of the application
+#[cfg(not(feature = "std"))]
+impl WallClock for DefaultWallClock {
+    fn unix_time_millis(&self) -> i64 {
+        crate::animations::Instant::now().0 as i64
+    }
+}
+
+crate::thread_local! {
+    /// The clock passed to [`set_wall_clock()`] before the context was created
+    static PENDING_WALL_CLOCK: RefCell<Option<Box<dyn WallClock>>> = const { RefCell::new(None) };
+}
+
+/// The state of the `System` global, stored in the context
+pub(crate) struct SystemState {
+    /// Marked dirty at every tick of the timer, so that the bindings reading the time are re-evaluated
+    time_dirty: Pin<Box<Property<()>>>,
+    /// In milliseconds
+    tick_interval: Pin<Box<Property<i64>>>,
+    /// Only started once the time is read
+    tick_timer: Timer,
+    wall_clock: RefCell<Box<dyn WallClock>>,
+    locale: Pin<Box<Property<SharedString>>>,
+    network_online: Pin<Box<Property<bool>>>,
+    on_battery: Pin<Box<Property<bool>>>,
+    battery_level: Pin<Box<Property<f32>>>,
+}
+
+impl Default for SystemState {
+    fn default() -> Self {
+        Self {
+            time_dirty: Box::pin(Property::new_named((), "System::time")),
+            tick_interval: Box::pin(Property::new_named(1000, "System::tick-interval")),
+            tick_timer: Timer::default(),
+            wall_clock: RefCell::new(
+                PENDING_WALL_CLOCK
+                    .with(|pending| pending.borrow_mut().take())
+                    .unwrap_or_else(|| Box::new(DefaultWallClock)),
+            ),
+            locale: Box::pin(Property::new_named(detect_locale(), "System::locale")),
+            network_online: Box::pin(Property::new_named(true, "System::network-online")),
+            on_battery: Box::pin(Property::new_named(false, "System::on-battery")),
+            battery_level: Box::pin(Property::new_named(1., "System::battery-level")),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+fn detect_locale() -> SharedString {
+    sys_locale::get_locale().map(Into::into).unwrap_or_default()
+}
+
+#[cfg(not(feature = "std"))]
+fn detect_locale() -> SharedString {
+    SharedString::default()
+}
+
+fn with_system_state<R>(f: impl FnOnce(&SystemState) -> R) -> Option<R> {
+    crate::context::GLOBAL_CONTEXT.with(|ctx| ctx.get().map(|ctx| f(&ctx.0.system)))
+}
+
+fn with_wall_clock<R>(f: impl FnOnce(&dyn WallClock) -> R) -> R {
+    let mut f = Some(f);
+    with_system_state(|state| (f.take().unwrap())(&**state.wall_clock.borrow())).unwrap_or_else(
+        || {
+            PENDING_WALL_CLOCK.with(|pending| match &*pending.borrow() {
+                Some(clock) => (f.take().unwrap())(&**clock),
+                None => (f.take().unwrap())(&DefaultWallClock),
+            })
+        },
+    )
+}
+
+/// Returns the Unix time and the offset of the local time zone, in milliseconds
+fn wall_clock_now() -> (i64, i64) {
+    with_wall_clock(|clock| {
+        let unix_time = clock.unix_time_millis();
+        (unix_time, clock.utc_offset_seconds(unix_time) as i64 * 1000)
+    })
+}
+
+/// Returns the number of milliseconds since midnight, in the local time zone
+fn local_time_of_day() -> i64 {
+    let (unix_time, utc_offset) = wall_clock_now();
+    (unix_time + utc_offset).rem_euclid(MILLISECONDS_PER_DAY)
+}
+
+/// Registers a dependency of the current binding to the time, and makes sure the timer runs
+fn track_time() {
+    with_system_state(|state| {
+        state.time_dirty.as_ref().get();
+        if !state.tick_timer.running() {
+            schedule_next_tick(state);
+        }
+    });
+}
+
+/// Starts the timer so that it fires at the next multiple of the tick interval, so that a clock
+/// showing the seconds changes when the second changes.
+fn schedule_next_tick(state: &SystemState) {
+    let interval = state.tick_interval.as_ref().get_untracked().max(1);
+    let delay = interval - local_time_of_day() % interval;
+    state.tick_timer.start(
+        TimerMode::SingleShot,
+        core::time::Duration::from_millis(delay as u64),
+        || {
+            with_system_state(|state| {
+                state.time_dirty.as_ref().mark_dirty();
+                schedule_next_tick(state);
+            });
+        },
+    );
+}
+
+/// Returns the number of milliseconds since midnight in the local time zone, for `System.time-of-day`.
+///
+/// The current binding is re-evaluated at every tick interval.
+pub fn system_time_of_day() -> i64 {
+    track_time();
+    local_time_of_day()
+}
+
+/// Returns the number of milliseconds since the Unix epoch, for `System.unix-time`.
+///
+/// The current binding is re-evaluated at every tick interval.
+pub fn system_unix_time() -> i64 {
+    track_time();
+    wall_clock_now().0
+}
+
+/// Returns the offset of the local time zone from UTC in milliseconds, for `System.utc-offset`.
+///
+/// The current binding is re-evaluated at every tick interval, as the offset changes with daylight saving time.
+pub fn system_utc_offset() -> i64 {
+    track_time();
+    wall_clock_now().1
+}
+
+/// Returns the name of the local time zone, for `System.time-zone`
+pub fn system_time_zone() -> SharedString {
+    track_time();
+    with_wall_clock(|clock| clock.time_zone_name())
+}
+
+/// Sets the source of the wall-clock time used by the `System` global, for example the real-time
+/// clock of a microcontroller.
+///
+/// This function can be called before the platform is set: the clock is then used once Slint is
+/// initialized on this thread.
+pub fn set_wall_clock(clock: Box<dyn WallClock>) {
+    let mut clock = Some(clock);
+    with_system_state(|state| *state.wall_clock.borrow_mut() = clock.take().unwrap());
+    if let Some(clock) = clock {
+        PENDING_WALL_CLOCK.with(|pending| *pending.borrow_mut() = Some(clock));
+    }
+    wall_clock_changed();
+}
+
+/// Notifies Slint that the wall-clock time or the time zone changed, for example after the clock
+/// was synchronized with a time server.
+///
+/// The bindings using the time in the `System` global are re-evaluated.
+pub fn wall_clock_changed() {
+    with_system_state(|state| {
+        state.time_dirty.as_ref().mark_dirty();
+        if state.tick_timer.running() {
+            schedule_next_tick(state);
+        }
+    });
+}
+
+/// Returns the interval in milliseconds at which `System.time-of-day` is updated
+pub fn system_tick_interval() -> i64 {
+    with_system_state(|state| state.tick_interval.as_ref().get()).unwrap_or(1000)
+}
+
+/// Sets the interval in milliseconds at which `System.time-of-day` is updated
+pub fn set_system_tick_interval(interval: i64) {
+    with_system_state(|state| {
+        let interval = interval.clamp(1, MILLISECONDS_PER_DAY);
+        if state.tick_interval.as_ref().get_untracked() != interval {
+            state.tick_interval.as_ref().set(interval);
+            if state.tick_timer.running() {
+                schedule_next_tick(state);
+            }
+        }
+    });
+}
+
+/// Returns the locale of the system, for `System.locale`, such as `en-US`
+pub fn system_locale() -> SharedString {
+    with_system_state(|state| state.locale.as_ref().get()).unwrap_or_else(detect_locale)
+}
+
+/// Returns whether the network is reachable, for `System.network-online`
+pub fn system_network_online() -> bool {
+    with_system_state(|state| state.network_online.as_ref().get()).unwrap_or(true)
+}
+
+/// Returns whether the system runs on battery, for `System.on-battery`
+pub fn system_on_battery() -> bool {
+    with_system_state(|state| state.on_battery.as_ref().get()).unwrap_or(false)
+}
+
+/// Returns the charge of the battery between 0 and 1, for `System.battery-level`
+pub fn system_battery_level() -> f32 {
+    with_system_state(|state| state.battery_level.as_ref().get()).unwrap_or(1.)
+}
+
+/// Sets the value of the `System.locale` property.
+///
+/// The locale is detected when the platform is initialized. Call this function if the platform
+/// gets notified that the locale of the system changed.
+pub fn set_system_locale(locale: SharedString) {
+    with_system_state(|state| state.locale.as_ref().set(locale));
+}
+
+/// Sets the value of the `System.network-online` property, `true` by default.
+///
+/// The winit backend monitors the network with NetworkManager on Linux. Otherwise, call this
+/// function from the platform or from the application when the connectivity of the system changes.
+pub fn set_system_network_online(online: bool) {
+    with_system_state(|state| state.network_online.as_ref().set(online));
+}
+
+/// Sets the values of the `System.on-battery` and `System.battery-level` properties, `false` and
+/// `1.0` by default. `level` is the charge of the battery, between 0 and 1.
+///
+/// The winit backend monitors the battery on Linux. Otherwise, call this function from the
+/// platform or from the application when the power source or the charge changes.
+pub fn set_system_battery_status(on_battery: bool, level: f32) {
+    with_system_state(|state| {
+        state.on_battery.as_ref().set(on_battery);
+        state.battery_level.as_ref().set(level.clamp(0., 1.));
+    });
+}
+
+#[cfg(feature = "ffi")]
+mod ffi {
+    #![allow(unsafe_code)]
+
+    use super::*;
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_system_time_of_day() -> i64 {
+        system_time_of_day()
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_system_unix_time() -> i64 {
+        system_unix_time()
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_system_utc_offset() -> i64 {
+        system_utc_offset()
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_system_time_zone(out: &mut SharedString) {
+        *out = system_time_zone()
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_system_tick_interval() -> i64 {
+        system_tick_interval()
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_system_set_tick_interval(interval: i64) {
+        set_system_tick_interval(interval)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_system_locale(out: &mut SharedString) {
+        *out = system_locale()
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_system_network_online() -> bool {
+        system_network_online()
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_system_set_network_online(online: bool) {
+        set_system_network_online(online)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_system_on_battery() -> bool {
+        system_on_battery()
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_system_battery_level() -> f32 {
+        system_battery_level()
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_system_set_battery_status(on_battery: bool, level: f32) {
+        set_system_battery_status(on_battery, level)
+    }
+}
@@ -0,0 +1,319 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+The selection of the `Text` and `MarkdownText` elements with `selectable: true`.
+
+A window has a single text selection that goes from an anchor to a focus, each being a byte offset
+in the text of a selectable element. All the selectable elements between the anchor and the focus
+in the order of the item tree are selected, so that a selection can span the paragraphs of a help
+page or the messages of a chat log. The selectable elements that are considered are the ones of the
+window, or of the popup, that contains the element where the selection started.
+*/
+
+use crate::item_tree::{ItemRc, ItemWeak, ParentItemTraversalMode};
+use crate::lengths::{LogicalPoint, LogicalRect};
+use crate::window::WindowAdapter;
+use crate::SharedString;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::ops::{ControlFlow, Range};
+
+#[derive(Clone)]
+struct SelectionEnd {
+    item: ItemWeak,
+    byte_offset: usize,
+}
+
+/// The text selection of a window
+#[derive(Default)]
+pub struct TextSelection {
+    anchor: Option<SelectionEnd>,
+    focus: Option<SelectionEnd>,
+    /// The elements whose selected range was set, so that it can be reset when the selection changes
+    highlighted: Vec<ItemWeak>,
+    /// Whether the selection is being extended with the mouse
+    pressed: bool,
+}
+
+impl TextSelection {
+    /// Starts a new, empty, selection at the given byte offset of the text of `item`
+    pub fn start(&mut self, item: &ItemRc, byte_offset: usize) {
+        self.select(item, byte_offset..byte_offset);
+    }
+
+    /// Selects the given byte range of the text of `item`
+    pub fn select(&mut self, item: &ItemRc, range: Range<usize>) {
+        self.anchor = Some(SelectionEnd { item: item.downgrade(), byte_offset: range.start });
+        self.focus = Some(SelectionEnd { item: item.downgrade(), byte_offset: range.end });
+        self.update();
+    }
+
+    /// Moves the focus of the selection to the given byte offset of the text of `item`
+    pub fn extend_to(&mut self, item: &ItemRc, byte_offset: usize) {
+        if self.anchor.is_none() {
+            return self.start(item, byte_offset);
+        }
+        self.focus = Some(SelectionEnd { item: item.downgrade(), byte_offset });
+        self.update();
+    }
+
+    /// Moves the focus of the selection to the selectable element at `position`, in window coordinates.
+    /// When there is no selectable element at that position, the selection extends to the end of the
+    /// last element before the position, in reading order.
+    pub fn extend_to_position(
+        &mut self,
+        position: LogicalPoint,
+        window_adapter: &Rc<dyn WindowAdapter>,
+    ) {
+        let Some(anchor_item) = self.anchor.as_ref().and_then(|anchor| anchor.item.upgrade())
+        else {
+            return;
+        };
+        let items = selectable_items(&scope_of(&anchor_item));
+        let geometries = items
+            .iter()
+            .map(|(item, _)| {
+                let geometry = item.geometry();
+                LogicalRect::new(item.map_to_window(geometry.origin), geometry.size)
+            })
+            .collect::<Vec<_>>();
+        let Some(index) = item_at_position(&geometries, position) else {
+            return;
+        };
+        let (item, text) = &items[index];
+        let geometry = geometries[index];
+        let byte_offset = if position.y >= geometry.max_y() {
+            text.len()
+        } else if position.y < geometry.min_y() {
+            0
+        } else {
+            crate::items::selectable_text_byte_offset_for_position(
+                item,
+                position - geometry.origin.to_vector(),
+                window_adapter,
+            )
+        };
+        self.extend_to(item, byte_offset);
+    }
+
+    /// Selects the text of all the selectable elements of the window, or the popup, containing `item`
+    pub fn select_all(&mut self, item: &ItemRc) {
+        let items = selectable_items(&scope_of(item));
+        let (Some((first, _)), Some((last, last_text))) = (items.first(), items.last()) else {
+            return;
+        };
+        self.anchor = Some(SelectionEnd { item: first.downgrade(), byte_offset: 0 });
+        self.focus = Some(SelectionEnd { item: last.downgrade(), byte_offset: last_text.len() });
+        self.update();
+    }
+
+    /// Moves the focus of the selection by one grapheme forward or backward, to the next or previous
+    /// selectable element when the focus is at the end or at the start of the text of its element.
+    pub fn move_focus(&mut self, forward: bool) {
+        let Some(focus) = self.focus.clone() else { return };
+        let Some(focus_item) = focus.item.upgrade() else { return };
+        let items = selectable_items(&scope_of(&focus_item));
+        let Some(index) = items.iter().position(|(item, _)| *item == focus_item) else {
+            return;
+        };
+        let text = &items[index].1;
+        let byte_offset = focus.byte_offset.min(text.len());
+        if forward {
+            if byte_offset < text.len() {
+                self.extend_to(&focus_item, next_grapheme_boundary(text, byte_offset));
+            } else if let Some((next, _)) = items.get(index + 1) {
+                self.extend_to(next, 0);
+            }
+        } else if byte_offset > 0 {
+            self.extend_to(&focus_item, prev_grapheme_boundary(text, byte_offset));
+        } else if let Some((prev, prev_text)) = index.checked_sub(1).and_then(|i| items.get(i)) {
+            self.extend_to(prev, prev_text.len());
+        }
+    }
+
+    /// Removes the selection
+    pub fn clear(&mut self) {
+        self.anchor = None;
+        self.focus = None;
+        self.pressed = false;
+        self.update();
+    }
+
+    /// Returns true if there is a selection, even an empty one
+    pub fn is_active(&self) -> bool {
+        self.anchor.as_ref().is_some_and(|anchor| anchor.item.upgrade().is_some())
+    }
+
+    /// Returns whether the selection is being extended with the mouse
+    pub fn pressed(&self) -> bool {
+        self.pressed
+    }
+
+    /// Sets whether the selection is being extended with the mouse
+    pub fn set_pressed(&mut self, pressed: bool) {
+        self.pressed = pressed;
+    }
+
+    /// Returns the selected text, the text of the different elements being separated by new lines
+    pub fn selected_text(&self) -> SharedString {
+        let ranges = self.selected_ranges();
+        let mut result = SharedString::default();
+        for (index, (_, text, range)) in ranges.iter().enumerate() {
+            if index > 0 {
+                result.push_str("\n");
+            }
+            result.push_str(text.get(range.clone()).unwrap_or_default());
+        }
+        result
+    }
+
+    fn selected_ranges(&self) -> Vec<(ItemRc, SharedString, Range<usize>)> {
+        let (Some(anchor), Some(focus)) = (&self.anchor, &self.focus) else {
+            return Vec::new();
+        };
+        let (Some(anchor_item), Some(focus_item)) = (anchor.item.upgrade(), focus.item.upgrade())
+        else {
+            return Vec::new();
+        };
+        let items = selectable_items(&scope_of(&anchor_item));
+        let position = |item: &ItemRc| items.iter().position(|(i, _)| i == item);
+        let (Some(anchor_index), Some(focus_index)) =
+            (position(&anchor_item), position(&focus_item))
+        else {
+            return Vec::new();
+        };
+        let lengths = items.iter().map(|(_, text)| text.len()).collect::<Vec<_>>();
+        selected_ranges(
+            &lengths,
+            (anchor_index, anchor.byte_offset),
+            (focus_index, focus.byte_offset),
+        )
+        .into_iter()
+        .map(|(index, range)| {
+            let (item, text) = items[index].clone();
+            (item, text, range)
+        })
+        .collect()
+    }
+
+    /// Sets the selected range of the elements after a change of the selection
+    fn update(&mut self) {
+        let ranges = self.selected_ranges();
+        for item in self.highlighted.drain(..).filter_map(|item| item.upgrade()) {
+            if !ranges.iter().any(|(selected, ..)| *selected == item) {
+                crate::items::set_text_selection_range(&item, 0..0);
+            }
+        }
+        for (item, _, range) in &ranges {
+            crate::items::set_text_selection_range(item, range.clone());
+        }
+        self.highlighted = ranges.iter().map(|(item, ..)| item.downgrade()).collect();
+    }
+}
+
+/// Returns the root item of the window, or of the popup, containing `item`
+fn scope_of(item: &ItemRc) -> ItemRc {
+    let mut scope = item.clone();
+    while let Some(parent) = scope.parent_item(ParentItemTraversalMode::StopAtPopups) {
+        scope = parent;
+    }
+    scope
+}
+
+/// Returns the visible selectable elements in `scope`, in the order of the item tree, with their text
+fn selectable_items(scope: &ItemRc) -> Vec<(ItemRc, SharedString)> {
+    let mut items = Vec::new();
+    let mut visit = |item: &ItemRc| {
+        if let Some(text) = crate::items::selectable_text(item) {
+            if item.is_visible() {
+                items.push((item.clone(), text));
+            }
+        }
+        ControlFlow::<()>::Continue(())
+    };
+    let _ = visit(scope);
+    scope.visit_descendants(visit);
+    items
+}
+
+/// Returns the selected byte range of each element between the anchor and the focus, given the length
+/// of the text of the elements. The anchor and the focus are given as the index of their element and
+/// the byte offset in its text.
+fn selected_ranges(
+    lengths: &[usize],
+    anchor: (usize, usize),
+    focus: (usize, usize),
+) -> Vec<(usize, Range<usize>)> {
+    let (start, end) = if anchor <= focus { (anchor, focus) } else { (focus, anchor) };
+    (start.0..=end.0)
+        .filter_map(|index| {
+            let len = *lengths.get(index)?;
+            let from = if index == start.0 { start.1.min(len) } else { 0 };
+            let to = if index == end.0 { end.1.min(len) } else { len };
+            (from < to).then_some((index, from..to))
+        })
+        .collect()
+}
+
+/// Returns the index of the rectangle that contains `position`. If none does, returns the last one
+/// that is before `position` in reading order: above it, or on the same row and on its left.
+fn item_at_position(geometries: &[LogicalRect], position: LogicalPoint) -> Option<usize> {
+    geometries
+        .iter()
+        .position(|geometry| geometry.contains(position))
+        .or_else(|| {
+            geometries.iter().rposition(|geometry| {
+                geometry.max_y() <= position.y
+                    || (geometry.min_y() <= position.y && geometry.min_x() <= position.x)
+            })
+        })
+        .or_else(|| (!geometries.is_empty()).then_some(0))
+}
+
+fn next_grapheme_boundary(text: &str, byte_offset: usize) -> usize {
+    use unicode_segmentation::UnicodeSegmentation;
+    text[byte_offset..].graphemes(true).next().map_or(text.len(), |g| byte_offset + g.len())
+}
+
+fn prev_grapheme_boundary(text: &str, byte_offset: usize) -> usize {
+    use unicode_segmentation::UnicodeSegmentation;
+    text[..byte_offset].graphemes(true).next_back().map_or(0, |g| byte_offset - g.len())
+}
+
+#[test]
+fn test_selected_ranges() {
+    let lengths = [5, 0, 10, 3];
+    // Within a single element, in both directions
+    assert_eq!(selected_ranges(&lengths, (0, 1), (0, 4)), [(0, 1..4)]);
+    assert_eq!(selected_ranges(&lengths, (0, 4), (0, 1)), [(0, 1..4)]);
+    assert!(selected_ranges(&lengths, (2, 3), (2, 3)).is_empty());
+    // Across elements, skipping the empty one
+    assert_eq!(selected_ranges(&lengths, (0, 2), (3, 1)), [(0, 2..5), (2, 0..10), (3, 0..1)]);
+    assert_eq!(
+        selected_ranges(&lengths, (3, 1), (0, 2)),
+        selected_ranges(&lengths, (0, 2), (3, 1))
+    );
+    // Offsets past the end of the text are clamped
+    assert_eq!(selected_ranges(&lengths, (2, 8), (3, 100)), [(2, 8..10), (3, 0..3)]);
+}
+
+#[test]
+fn test_item_at_position() {
+    let rect = |x, y, w, h| {
+        LogicalRect::new(LogicalPoint::new(x, y), crate::lengths::LogicalSize::new(w, h))
+    };
+    // Two paragraphs on top of each other, and a third one next to the second one
+    let geometries = [rect(0., 0., 100., 20.), rect(0., 30., 50., 20.), rect(60., 30., 40., 20.)];
+    assert_eq!(item_at_position(&geometries, LogicalPoint::new(10., 10.)), Some(0));
+    assert_eq!(item_at_position(&geometries, LogicalPoint::new(70., 35.)), Some(2));
+    // In the gap between the first and the second paragraph
+    assert_eq!(item_at_position(&geometries, LogicalPoint::new(10., 25.)), Some(0));
+    // In the gap between the second and the third one
+    assert_eq!(item_at_position(&geometries, LogicalPoint::new(55., 35.)), Some(1));
+    // Below everything
+    assert_eq!(item_at_position(&geometries, LogicalPoint::new(10., 100.)), Some(2));
+    // Above everything
+    assert_eq!(item_at_position(&geometries, LogicalPoint::new(10., -10.)), Some(0));
+    assert_eq!(item_at_position(&[], LogicalPoint::new(10., 10.)), None);
+}
@@ -72,6 +72,7 @@ pub fn text_size(
         let mut max_line_width = Font::Length::zero();
         let mut line_count: i16 = 0;
         let shape_buffer = ShapeBuffer::new(self, text);
+        let text = shape_buffer.shaped_text(text);
 
         for line in TextLineBreaker::<Font>::new(text, &shape_buffer, max_width, None, text_wrap) {
             max_line_width = euclid::approxord::max(max_line_width, line.text_width);
@@ -119,7 +120,12 @@ pub fn layout_lines<R>(
     ) -> Result<Font::Length, R> {
         let wrap = self.wrap != TextWrap::NoWrap;
         let elide = self.overflow == TextOverflow::Elide;
-        let elide_glyph = if elide {
+
+        let shape_buffer = ShapeBuffer::new(&self.layout, self.string);
+        // When the heap is exhausted, the text that could be shaped ends with an ellipsis
+        let shaped_text = shape_buffer.shaped_text(self.string);
+
+        let elide_glyph = if elide || shape_buffer.truncated {
             self.layout.font.glyph_for_char('…').filter(|glyph| glyph.glyph_id.is_some())
         } else {
             None
@@ -127,11 +133,9 @@ pub fn layout_lines<R>(
         let elide_width = elide_glyph.as_ref().map_or(Font::Length::zero(), |g| g.advance);
         let max_width_without_elision = self.max_width - elide_width;
 
-        let shape_buffer = ShapeBuffer::new(&self.layout, self.string);
-
         let new_line_break_iter = || {
             TextLineBreaker::<Font>::new(
-                self.string,
+                shaped_text,
                 &shape_buffer,
                 if wrap { Some(self.max_width) } else { None },
                 if elide { Some(self.layout.font.max_lines(self.max_height)) } else { None },
@@ -162,9 +166,10 @@ pub fn layout_lines<R>(
         let mut process_line = |line: &TextLine<Font::Length>, glyphs: &[Glyph<Font::Length>]| {
             let elide_long_line =
                 elide && (self.single_line || !wrap) && line.text_width > self.max_width;
-            let elide_last_line = elide
+            let elide_last_line = (elide
                 && line.glyph_range.end < glyphs.len()
-                && y + self.layout.font.height() * two > self.max_height;
+                && y + self.layout.font.height() * two > self.max_height)
+                || (shape_buffer.truncated && line.glyph_range.end >= glyphs.len());
 
             let text_width = || {
                 if elide_long_line || elide_last_line {
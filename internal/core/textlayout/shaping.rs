@@ -169,6 +169,9 @@ pub struct TextRun {
 pub struct ShapeBuffer<Length> {
     pub glyphs: Vec<Glyph<Length>>,
     pub text_runs: Vec<TextRun>,
+    /// True if the heap was exhausted while shaping, in which case only the beginning of the
+    /// text was shaped.
+    pub truncated: bool,
 }
 
 impl<Length> ShapeBuffer<Length> {
@@ -178,39 +181,91 @@ pub fn new<Font>(layout: &TextLayout<Font>, text: &str) -> Self
         Length: Copy + core::ops::AddAssign,
     {
         let mut glyphs = Vec::new();
-        let text_runs = ShapeBoundaries::new(text)
-            .scan(0, |run_start, run_end| {
-                let glyphs_start = glyphs.len();
-
-                layout.font.shape_text(&text[*run_start..run_end], &mut glyphs);
-
-                if let Some(letter_spacing) = layout.letter_spacing {
-                    if glyphs.len() > glyphs_start {
-                        let mut last_byte_offset = glyphs[glyphs_start].text_byte_offset;
-                        for index in glyphs_start + 1..glyphs.len() {
-                            let current_glyph_byte_offset = glyphs[index].text_byte_offset;
-                            if current_glyph_byte_offset != last_byte_offset {
-                                let previous_glyph = &mut glyphs[index - 1];
-                                previous_glyph.advance += letter_spacing;
-                            }
-                            last_byte_offset = current_glyph_byte_offset;
-                        }
+        let mut text_runs = Vec::new();
+        let mut truncated = false;
+        let mut run_start = 0;
+        for run_end in ShapeBoundaries::new(text) {
+            let glyphs_start = glyphs.len();
+            let run_text = &text[run_start..run_end];
+
+            // The buffers are only grown with try_reserve, so that running out of memory on
+            // devices without much heap truncates the text instead of aborting.
+            if text_runs.try_reserve(1).is_err() {
+                truncated = true;
+                break;
+            }
+            let mut capacity = run_text.len();
+            let shaped = loop {
+                if glyphs.try_reserve(capacity).is_err() {
+                    break false;
+                }
+                let mut storage = BoundedGlyphs { glyphs: &mut glyphs, overflowed: false };
+                layout.font.shape_text(run_text, &mut storage);
+                if !storage.overflowed {
+                    break true;
+                }
+                // More glyphs than bytes, for example from decomposed ligatures
+                glyphs.truncate(glyphs_start);
+                capacity = (capacity * 2).max(1);
+            };
+            if !shaped {
+                glyphs.truncate(glyphs_start);
+                truncated = true;
+                break;
+            }
 
-                        glyphs.last_mut().unwrap().advance += letter_spacing;
+            if let Some(letter_spacing) = layout.letter_spacing {
+                if glyphs.len() > glyphs_start {
+                    let mut last_byte_offset = glyphs[glyphs_start].text_byte_offset;
+                    for index in glyphs_start + 1..glyphs.len() {
+                        let current_glyph_byte_offset = glyphs[index].text_byte_offset;
+                        if current_glyph_byte_offset != last_byte_offset {
+                            let previous_glyph = &mut glyphs[index - 1];
+                            previous_glyph.advance += letter_spacing;
+                        }
+                        last_byte_offset = current_glyph_byte_offset;
                     }
+
+                    glyphs.last_mut().unwrap().advance += letter_spacing;
                 }
+            }
 
-                let run = TextRun {
-                    byte_range: Range { start: *run_start, end: run_end },
-                    glyph_range: Range { start: glyphs_start, end: glyphs.len() },
-                };
-                *run_start = run_end;
+            text_runs.push(TextRun {
+                byte_range: Range { start: run_start, end: run_end },
+                glyph_range: Range { start: glyphs_start, end: glyphs.len() },
+            });
+            run_start = run_end;
+        }
 
-                Some(run)
-            })
-            .collect();
+        Self { glyphs, text_runs, truncated }
+    }
+
+    /// Returns the part of the text that was shaped, which is all of it unless the buffer is truncated.
+    pub fn shaped_text<'a>(&self, text: &'a str) -> &'a str {
+        if self.truncated {
+            &text[..self.text_runs.last().map_or(0, |run| run.byte_range.end)]
+        } else {
+            text
+        }
+    }
+}
 
-        Self { glyphs, text_runs }
+/// Glyph storage that never grows the vector beyond its reserved capacity. The glyphs that
+/// don't fit are dropped, and reported with `overflowed`.
+struct BoundedGlyphs<'a, Length> {
+    glyphs: &'a mut Vec<Glyph<Length>>,
+    overflowed: bool,
+}
+
+impl<Length> core::iter::Extend<Glyph<Length>> for BoundedGlyphs<'_, Length> {
+    fn extend<T: IntoIterator<Item = Glyph<Length>>>(&mut self, iter: T) {
+        for glyph in iter {
+            if self.glyphs.len() < self.glyphs.capacity() {
+                self.glyphs.push(glyph);
+            } else {
+                self.overflowed = true;
+            }
+        }
     }
 }
 
@@ -277,6 +277,7 @@ fn layout(text: Text, scale_factor: ScaleFactor, mut options: LayoutOptions) ->
                                 parley::StyleProperty::FontStyle(parley::style::FontStyle::Italic)
                             }
                             Style::Strikethrough => parley::StyleProperty::Strikethrough(true),
+                            Style::Link => parley::StyleProperty::Underline(true),
                             Style::Strong => {
                                 parley::StyleProperty::FontWeight(parley::style::FontWeight::BOLD)
                             }
@@ -342,10 +343,15 @@ fn layout(text: Text, scale_factor: ScaleFactor, mut options: LayoutOptions) ->
                 }
             }
             Text::RichText(rich_text) => {
+                // The ranges are in the plain text of the paragraphs joined with new lines,
+                // see `rich_text_plain_text`
+                let mut start = 0;
                 for paragraph in rich_text.paragraphs {
+                    let range = start..start + paragraph.text.len();
+                    start = range.end + 1;
                     paragraphs.push(paragraph_from_text(
                         &paragraph.text,
-                        0..0,
+                        range,
                         Some(paragraph.formatting),
                     ));
                 }
@@ -711,6 +717,7 @@ enum Style {
     Strong,
     Strikethrough,
     Code,
+    Link,
 }
 
 #[derive(Debug, PartialEq)]
@@ -761,6 +768,14 @@ fn begin_paragraph(&mut self, indentation: u32, list_item_type: Option<ListItemT
     }
 }
 
+/// Returns the text of the markdown as it is displayed, without the markup, with the paragraphs
+/// separated by new lines. The selection of a `MarkdownText` refers to byte offsets in this text.
+#[cfg(feature = "experimental-rich-text")]
+pub fn rich_text_plain_text(markdown: &str) -> SharedString {
+    let paragraphs = parse_markdown(markdown).paragraphs;
+    paragraphs.iter().map(|paragraph| paragraph.text.as_str()).collect::<Vec<_>>().join("\n").into()
+}
+
 #[cfg(feature = "experimental-rich-text")]
 fn parse_markdown(string: &str) -> RichText {
     let parser =
@@ -814,11 +829,11 @@ fn parse_markdown(string: &str) -> RichText {
                     pulldown_cmark::Tag::Strong => Some(Style::Strong),
                     pulldown_cmark::Tag::Emphasis => Some(Style::Emphasis),
                     pulldown_cmark::Tag::Strikethrough => Some(Style::Strikethrough),
+                    pulldown_cmark::Tag::Link { .. } => Some(Style::Link),
                     pulldown_cmark::Tag::Paragraph
                     | pulldown_cmark::Tag::List(_)
                     | pulldown_cmark::Tag::Item => unreachable!(),
                     pulldown_cmark::Tag::Heading { .. }
-                    | pulldown_cmark::Tag::Link { .. }
                     | pulldown_cmark::Tag::Image { .. }
                     | pulldown_cmark::Tag::DefinitionList
                     | pulldown_cmark::Tag::DefinitionListTitle
@@ -951,6 +966,13 @@ fn markdown_parsing() {
             },
         ]
     );
+    assert_eq!(
+        parse_markdown("See [the website](https://slint.dev)").paragraphs,
+        [RichTextParagraph {
+            text: "See the website".into(),
+            formatting: std::vec![FormattedSpan { range: 4..15, style: Style::Link }]
+        }]
+    );
 }
 
 pub fn draw_text(
@@ -1007,6 +1029,8 @@ pub fn draw_text(
 
     let text_overflow = text.overflow();
 
+    let selection = text.selection();
+
     let layout = layout(
         layout_text,
         scale_factor,
@@ -1037,6 +1061,12 @@ pub fn draw_text(
     };
 
     if render {
+        if let Some((selection, selection_background_color)) = selection {
+            layout.selection_geometry(selection, |selection_rect| {
+                item_renderer.fill_rectangle(selection_rect, selection_background_color);
+            });
+        }
+
         layout.draw(
             item_renderer,
             platform_fill_brush,
@@ -1197,6 +1227,51 @@ pub fn text_input_byte_offset_for_position(
     visual_representation.map_byte_offset_from_byte_offset_in_visual_text(byte_offset)
 }
 
+pub fn text_byte_offset_for_position(
+    text: Pin<&dyn crate::item_rendering::RenderText>,
+    size: LogicalSize,
+    pos: LogicalPoint,
+    font_request: FontRequest,
+    scale_factor: ScaleFactor,
+) -> usize {
+    let pos: PhysicalPoint = pos * scale_factor;
+    let str = text.text();
+
+    if size.width_length().get() <= 0. || size.height_length().get() <= 0. || pos.y < 0. {
+        return 0;
+    }
+
+    #[cfg(feature = "experimental-rich-text")]
+    let layout_text = if text.is_markdown() {
+        Text::RichText(parse_markdown(&str))
+    } else {
+        Text::PlainText(&str)
+    };
+
+    #[cfg(not(feature = "experimental-rich-text"))]
+    let layout_text = Text::PlainText(&str);
+
+    let (horizontal_align, vertical_align) = text.alignment();
+
+    let layout = layout(
+        layout_text,
+        scale_factor,
+        LayoutOptions {
+            horizontal_align,
+            vertical_align,
+            max_height: Some(size.height_length()),
+            max_width: Some(size.width_length()),
+            stroke: None,
+            font_request: Some(font_request),
+            text_wrap: text.wrap(),
+            text_overflow: text.overflow(),
+            selection: None,
+            selection_foreground_color: None,
+        },
+    );
+    layout.byte_offset_from_point(pos)
+}
+
 pub fn text_input_cursor_rect_for_byte_offset(
     text_input: Pin<&crate::items::TextInput>,
     byte_offset: usize,
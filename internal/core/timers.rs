@@ -18,6 +18,10 @@
 };
 
 use crate::animations::Instant;
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+use std::time;
+#[cfg(all(feature = "std", target_arch = "wasm32"))]
+use web_time as time;
 
 type TimerCallback = Box<dyn FnMut()>;
 type SingleShotTimerCallback = Box<dyn FnOnce()>;
@@ -33,6 +37,12 @@ pub enum TimerMode {
     SingleShot,
     /// A Repeated timer is fired repeatedly until it is stopped or dropped.
     Repeated,
+    /// A FixedRate timer is fired repeatedly until it is stopped or dropped, like a
+    /// [`Repeated`](TimerMode::Repeated) timer. But the next timeout is computed from the previous
+    /// timeout instead of from the time the timer was fired, so that the timer doesn't drift when
+    /// the event loop is late. If the timer is late by more than an interval, the missed timeouts
+    /// are skipped and the timer fires only once.
+    FixedRate,
 }
 
 /// Timer is a handle to the timer system that triggers a callback after a specified
@@ -92,6 +102,44 @@ pub fn start(
         });
     }
 
+    /// Same as [`Self::start()`], but the timer fires for the first time at the given `deadline`
+    /// instead of after the `interval`. If the deadline is in the past, the timer fires as soon as possible.
+    ///
+    /// Combined with the [`FixedRate`](TimerMode::FixedRate) mode, this schedules a callback at
+    /// fixed times, for example every full minute.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # i_slint_backend_testing::init_no_event_loop();
+    /// use slint::{Timer, TimerMode};
+    /// use std::time::{Duration, Instant};
+    /// let timer = Timer::default();
+    /// timer.start_at(TimerMode::FixedRate, Instant::now() + Duration::from_secs(5), Duration::from_secs(60), move || {
+    ///    println!("This will be printed in 5 seconds, and then every minute.");
+    /// });
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn start_at(
+        &self,
+        mode: TimerMode,
+        deadline: time::Instant,
+        interval: core::time::Duration,
+        callback: impl FnMut() + 'static,
+    ) {
+        let timeout = Instant::now() + deadline.saturating_duration_since(time::Instant::now());
+        let _ = CURRENT_TIMERS.try_with(|timers| {
+            let mut timers = timers.borrow_mut();
+            let id = timers.start_or_restart_timer_at(
+                self.id(),
+                mode,
+                interval,
+                CallbackVariant::MultiFire(Box::new(callback)),
+                timeout,
+            );
+            self.set_id(Some(id));
+        });
+    }
+
     /// Starts the timer with the duration and the callback to called when the
     /// timer fires. It is fired only once and then deleted.
     ///
@@ -128,6 +176,49 @@ pub fn stop(&self) {
         }
     }
 
+    /// Pauses the timer. The time that remains until the timer fires is kept, and the timer
+    /// continues from there when [`Self::resume()`] is called.
+    ///
+    /// Does nothing if the timer is not running.
+    pub fn pause(&self) {
+        if let Some(id) = self.id() {
+            let _ = CURRENT_TIMERS.try_with(|timers| {
+                timers.borrow_mut().pause_timer(id);
+            });
+        }
+    }
+
+    /// Resumes a timer that was paused with [`Self::pause()`]. The timer fires after the time
+    /// that remained when it was paused.
+    ///
+    /// Does nothing if the timer is not paused.
+    pub fn resume(&self) {
+        if let Some(id) = self.id() {
+            let _ = CURRENT_TIMERS.try_with(|timers| {
+                timers.borrow_mut().resume_timer(id);
+            });
+        }
+    }
+
+    /// Returns true if the timer was paused with [`Self::pause()`] and not resumed yet.
+    pub fn paused(&self) -> bool {
+        self.id()
+            .and_then(|timer_id| {
+                CURRENT_TIMERS
+                    .try_with(|timers| timers.borrow().timers[timer_id].paused_remaining.is_some())
+                    .ok()
+            })
+            .unwrap_or(false)
+    }
+
+    /// Returns the time that remains until the timer fires, or None if the timer is neither
+    /// running nor paused.
+    pub fn remaining(&self) -> Option<core::time::Duration> {
+        self.id().and_then(|timer_id| {
+            CURRENT_TIMERS.try_with(|timers| timers.borrow().remaining(timer_id)).ok().flatten()
+        })
+    }
+
     /// Restarts the timer. If the timer was previously started by calling [`Self::start()`]
     /// with a duration and callback, then the time when the callback will be next invoked
     /// is re-calculated to be in the specified duration relative to when this function is called.
@@ -216,6 +307,8 @@ struct TimerData {
     removed: bool,
     /// true if it is in the cached the active_timers list in the maybe_activate_timers stack
     being_activated: bool,
+    /// The time that remained until the timeout when the timer was paused
+    paused_remaining: Option<core::time::Duration>,
 
     callback: CallbackVariant,
 }
@@ -279,10 +372,20 @@ pub fn maybe_activate_timers(now: Instant) -> bool {
                     assert!(!timer.being_activated);
                     timer.being_activated = true;
 
-                    if matches!(timers.timers[expired_timer.id].mode, TimerMode::Repeated) {
-                        timers.activate_timer(expired_timer.id);
-                    } else {
-                        timers.timers[expired_timer.id].running = false;
+                    match timers.timers[expired_timer.id].mode {
+                        TimerMode::SingleShot => timers.timers[expired_timer.id].running = false,
+                        TimerMode::Repeated => timers.activate_timer(expired_timer.id),
+                        TimerMode::FixedRate => {
+                            let timeout = next_fixed_rate_timeout(
+                                expired_timer.timeout,
+                                timers.timers[expired_timer.id].duration,
+                                now,
+                            );
+                            timers.register_active_timer(ActiveTimer {
+                                id: expired_timer.id,
+                                timeout,
+                            });
+                        }
                     }
                 }
 
@@ -350,6 +453,17 @@ fn start_or_restart_timer(
         mode: TimerMode,
         duration: core::time::Duration,
         callback: CallbackVariant,
+    ) -> usize {
+        self.start_or_restart_timer_at(id, mode, duration, callback, Instant::now() + duration)
+    }
+
+    fn start_or_restart_timer_at(
+        &mut self,
+        id: Option<usize>,
+        mode: TimerMode,
+        duration: core::time::Duration,
+        callback: CallbackVariant,
+        timeout: Instant,
     ) -> usize {
         let mut timer_data = TimerData {
             duration,
@@ -358,6 +472,7 @@ fn start_or_restart_timer(
             removed: false,
             callback,
             being_activated: false,
+            paused_remaining: None,
         };
         let inactive_timer_id = if let Some(id) = id {
             self.deactivate_timer(id);
@@ -367,11 +482,12 @@ fn start_or_restart_timer(
         } else {
             self.timers.insert(timer_data)
         };
-        self.activate_timer(inactive_timer_id);
+        self.register_active_timer(ActiveTimer { id: inactive_timer_id, timeout });
         inactive_timer_id
     }
 
     fn deactivate_timer(&mut self, id: usize) {
+        self.timers[id].paused_remaining = None;
         let mut i = 0;
         while i < self.active_timers.len() {
             if self.active_timers[i].id == id {
@@ -401,6 +517,28 @@ fn register_active_timer(&mut self, new_active_timer: ActiveTimer) {
         self.timers[new_active_timer.id].running = true;
     }
 
+    fn pause_timer(&mut self, id: usize) {
+        if let Some(remaining) = self.remaining(id).filter(|_| self.timers[id].running) {
+            self.deactivate_timer(id);
+            self.timers[id].paused_remaining = Some(remaining);
+        }
+    }
+
+    fn resume_timer(&mut self, id: usize) {
+        if let Some(remaining) = self.timers[id].paused_remaining.take() {
+            self.register_active_timer(ActiveTimer { id, timeout: Instant::now() + remaining });
+        }
+    }
+
+    fn remaining(&self, id: usize) -> Option<core::time::Duration> {
+        match self.active_timers.iter().find(|t| t.id == id) {
+            Some(active_timer) => Some(core::time::Duration::from_millis(
+                active_timer.timeout.0.saturating_sub(Instant::now().0),
+            )),
+            None => self.timers[id].paused_remaining,
+        }
+    }
+
     fn remove_timer(&mut self, id: usize) -> CallbackVariant {
         self.deactivate_timer(id);
         let t = &mut self.timers[id];
@@ -424,6 +562,21 @@ fn set_interval(&mut self, id: usize, duration: core::time::Duration) {
     }
 }
 
+/// Returns the first timeout of a [`FixedRate`](TimerMode::FixedRate) timer that is after `now`,
+/// counting whole intervals from the timeout that just expired.
+fn next_fixed_rate_timeout(
+    expired: Instant,
+    interval: core::time::Duration,
+    now: Instant,
+) -> Instant {
+    let interval = interval.as_millis() as u64;
+    if interval == 0 {
+        return now;
+    }
+    let missed_intervals = now.0.saturating_sub(expired.0) / interval;
+    Instant(expired.0 + (missed_intervals + 1) * interval)
+}
+
 crate::thread_local!(static CURRENT_TIMERS : RefCell<TimerList> = RefCell::default());
 
 #[cfg(feature = "ffi")]
@@ -537,6 +690,53 @@ pub extern "C" fn slint_timer_running(id: usize) -> bool {
         running
     }
 
+    /// Pause a timer
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_timer_pause(id: usize) {
+        if id == 0 {
+            return;
+        }
+        let timer = Timer { id: Cell::new(NonZeroUsize::new(id)), _phantom: Default::default() };
+        timer.pause();
+        timer.id.take(); // Make sure that dropping the Timer doesn't unregister it. C++ will call destroy() in the destructor.
+    }
+
+    /// Resume a paused timer
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_timer_resume(id: usize) {
+        if id == 0 {
+            return;
+        }
+        let timer = Timer { id: Cell::new(NonZeroUsize::new(id)), _phantom: Default::default() };
+        timer.resume();
+        timer.id.take(); // Make sure that dropping the Timer doesn't unregister it. C++ will call destroy() in the destructor.
+    }
+
+    /// Returns true if the timer is paused; false otherwise.
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_timer_paused(id: usize) -> bool {
+        if id == 0 {
+            return false;
+        }
+        let timer = Timer { id: Cell::new(NonZeroUsize::new(id)), _phantom: Default::default() };
+        let paused = timer.paused();
+        timer.id.take(); // Make sure that dropping the Timer doesn't unregister it. C++ will call destroy() in the destructor.
+        paused
+    }
+
+    /// Returns the remaining time in milliseconds until the timer fires, or -1 if the timer is
+    /// neither running nor paused.
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_timer_remaining(id: usize) -> i64 {
+        if id == 0 {
+            return -1;
+        }
+        let timer = Timer { id: Cell::new(NonZeroUsize::new(id)), _phantom: Default::default() };
+        let val = timer.remaining().map_or(-1, |remaining| remaining.as_millis() as i64);
+        timer.id.take(); // Make sure that dropping the Timer doesn't unregister it. C++ will call destroy() in the destructor.
+        val
+    }
+
     /// Returns the interval in milliseconds. 0 when the timer was never started.
     #[unsafe(no_mangle)]
     pub extern "C" fn slint_timer_interval(id: usize) -> u64 {
@@ -1206,3 +1406,105 @@ fn drop(&mut self) {
  */
 #[cfg(doctest)]
 const _TIMER_AT_EXIT: () = ();
+
+/**
+ * Test pausing and resuming a timer
+```rust
+i_slint_backend_testing::init_no_event_loop();
+use slint::{Timer, TimerMode};
+use std::{rc::Rc, cell::Cell, time::Duration};
+
+let called = Rc::new(Cell::new(0));
+let timer = Timer::default();
+assert_eq!(timer.remaining(), None);
+timer.start(TimerMode::Repeated, Duration::from_millis(100), {
+    let called = called.clone();
+    move || called.set(called.get() + 1)
+});
+assert_eq!(timer.remaining(), Some(Duration::from_millis(100)));
+i_slint_core::tests::slint_mock_elapsed_time(60);
+assert_eq!(timer.remaining(), Some(Duration::from_millis(40)));
+
+timer.pause();
+assert!(timer.paused());
+assert!(!timer.running());
+i_slint_core::tests::slint_mock_elapsed_time(500);
+assert_eq!(called.get(), 0);
+assert_eq!(timer.remaining(), Some(Duration::from_millis(40)));
+
+timer.resume();
+assert!(!timer.paused());
+assert!(timer.running());
+i_slint_core::tests::slint_mock_elapsed_time(30);
+assert_eq!(called.get(), 0);
+i_slint_core::tests::slint_mock_elapsed_time(10);
+assert_eq!(called.get(), 1);
+assert_eq!(timer.remaining(), Some(Duration::from_millis(100)));
+
+// Resuming a timer that is not paused does nothing
+timer.resume();
+assert_eq!(timer.remaining(), Some(Duration::from_millis(100)));
+
+// Stopping a paused timer forgets the remaining time
+timer.pause();
+timer.stop();
+assert!(!timer.paused());
+assert_eq!(timer.remaining(), None);
+timer.resume();
+assert!(!timer.running());
+```
+ */
+#[cfg(doctest)]
+const _PAUSE_RESUME: () = ();
+
+/**
+ * Test that a FixedRate timer doesn't drift when it fires late, unlike a Repeated timer
+```rust
+i_slint_backend_testing::init_no_event_loop();
+use slint::{Timer, TimerMode};
+use std::{rc::Rc, cell::Cell, time::{Duration, Instant}};
+
+let fixed_rate_called = Rc::new(Cell::new(0));
+let repeated_called = Rc::new(Cell::new(0));
+let fixed_rate_timer = Timer::default();
+fixed_rate_timer.start(TimerMode::FixedRate, Duration::from_millis(100), {
+    let fixed_rate_called = fixed_rate_called.clone();
+    move || fixed_rate_called.set(fixed_rate_called.get() + 1)
+});
+let repeated_timer = Timer::default();
+repeated_timer.start(TimerMode::Repeated, Duration::from_millis(100), {
+    let repeated_called = repeated_called.clone();
+    move || repeated_called.set(repeated_called.get() + 1)
+});
+
+// Both fire late, at 130ms
+i_slint_core::tests::slint_mock_elapsed_time(130);
+assert_eq!(fixed_rate_called.get(), 1);
+assert_eq!(repeated_called.get(), 1);
+assert_eq!(fixed_rate_timer.remaining(), Some(Duration::from_millis(70)));
+assert_eq!(repeated_timer.remaining(), Some(Duration::from_millis(100)));
+
+// The missed timeouts at 200ms and 300ms are skipped
+i_slint_core::tests::slint_mock_elapsed_time(220);
+assert_eq!(fixed_rate_called.get(), 2);
+assert_eq!(fixed_rate_timer.remaining(), Some(Duration::from_millis(50)));
+i_slint_core::tests::slint_mock_elapsed_time(50);
+assert_eq!(fixed_rate_called.get(), 3);
+
+// start_at fires at the deadline, then after each interval
+let at_called = Rc::new(Cell::new(0));
+let at_timer = Timer::default();
+at_timer.start_at(TimerMode::FixedRate, Instant::now() + Duration::from_secs(10), Duration::from_millis(100), {
+    let at_called = at_called.clone();
+    move || at_called.set(at_called.get() + 1)
+});
+assert!(at_timer.remaining().unwrap() > Duration::from_millis(9000));
+i_slint_core::tests::slint_mock_elapsed_time(9000);
+assert_eq!(at_called.get(), 0);
+i_slint_core::tests::slint_mock_elapsed_time(1000);
+assert_eq!(at_called.get(), 1);
+assert_eq!(at_timer.interval(), Duration::from_millis(100));
+```
+ */
+#[cfg(doctest)]
+const _FIXED_RATE_AND_START_AT: () = ();
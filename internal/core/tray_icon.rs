@@ -0,0 +1,223 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+This module contains the icon in the system tray, or notification area, and its menu.
+
+The [`TrayIcon`] is the public API. Platforms implement it with a [`TrayIconAdapter`] returned by
+[`Platform::create_tray_icon()`](crate::platform::Platform::create_tray_icon), and report user
+interactions through the [`TrayIconEvents`].
+*/
+
+use crate::api::PlatformError;
+use crate::graphics::Image;
+use crate::SharedString;
+use alloc::boxed::Box;
+use alloc::rc::{Rc, Weak};
+use alloc::vec::Vec;
+use core::cell::RefCell;
+
+/// The menu of a [`TrayIcon`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Menu {
+    /// The items of the menu, from top to bottom.
+    pub items: Vec<MenuItem>,
+}
+
+impl Menu {
+    /// Creates a menu with the given items.
+    pub fn new(items: impl IntoIterator<Item = MenuItem>) -> Self {
+        Self { items: items.into_iter().collect() }
+    }
+}
+
+/// An item of a [`Menu`]: an entry that can be activated, a separator, or a sub menu.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct MenuItem {
+    /// The id passed to the callback set with [`TrayIcon::on_menu_item_activated()`].
+    pub id: SharedString,
+    /// The text of the item.
+    pub title: SharedString,
+    /// Whether the item can be activated.
+    pub enabled: bool,
+    /// `Some` for an item that shows a check mark, with whether it's checked.
+    pub checked: Option<bool>,
+    /// Whether the item is a separator, in which case the other fields are ignored.
+    pub is_separator: bool,
+    /// The items of the sub menu, if the item opens one.
+    pub sub_menu: Option<Menu>,
+}
+
+impl MenuItem {
+    /// Creates an enabled item with the given `id` and `title`.
+    pub fn new(id: impl Into<SharedString>, title: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            enabled: true,
+            checked: None,
+            is_separator: false,
+            sub_menu: None,
+        }
+    }
+
+    /// Creates a separator.
+    pub fn separator() -> Self {
+        Self { is_separator: true, ..Self::new("", "") }
+    }
+
+    /// Creates an item that opens `sub_menu`.
+    pub fn sub_menu(title: impl Into<SharedString>, sub_menu: Menu) -> Self {
+        Self { sub_menu: Some(sub_menu), ..Self::new("", title) }
+    }
+
+    /// Returns the item, enabled or disabled.
+    pub fn with_enabled(self, enabled: bool) -> Self {
+        Self { enabled, ..self }
+    }
+
+    /// Returns the item with a check mark, checked or not.
+    pub fn with_checked(self, checked: bool) -> Self {
+        Self { checked: Some(checked), ..self }
+    }
+}
+
+/// This trait represents the tray icon of a platform, created by
+/// [`Platform::create_tray_icon()`](crate::platform::Platform::create_tray_icon).
+pub trait TrayIconAdapter {
+    /// Shows or hides the icon in the tray.
+    fn set_visible(&self, visible: bool) -> Result<(), PlatformError>;
+    /// Sets the image of the icon.
+    fn set_icon(&self, icon: &Image) -> Result<(), PlatformError>;
+    /// Sets the text shown when hovering the icon.
+    fn set_tooltip(&self, tooltip: &str) -> Result<(), PlatformError>;
+    /// Sets the menu shown for the icon, usually on right click.
+    fn set_menu(&self, menu: &Menu) -> Result<(), PlatformError>;
+}
+
+#[derive(Default)]
+struct TrayIconCallbacks {
+    activated: RefCell<Option<Box<dyn FnMut()>>>,
+    menu_item_activated: RefCell<Option<Box<dyn FnMut(&str)>>>,
+}
+
+/// Passed to [`Platform::create_tray_icon()`](crate::platform::Platform::create_tray_icon), for the
+/// platform to report the interactions with the icon.
+#[derive(Clone)]
+pub struct TrayIconEvents(Weak<TrayIconCallbacks>);
+
+impl TrayIconEvents {
+    /// Call this when the icon is activated, usually by clicking it.
+    pub fn activated(&self) {
+        let Some(callbacks) = self.0.upgrade() else { return };
+        // Take the callback out while it runs, so that it can replace itself
+        let callback = callbacks.activated.take();
+        if let Some(mut callback) = callback {
+            callback();
+            callbacks.activated.borrow_mut().get_or_insert(callback);
+        }
+    }
+
+    /// Call this when the menu item with the given `id` is activated.
+    pub fn menu_item_activated(&self, id: &str) {
+        let Some(callbacks) = self.0.upgrade() else { return };
+        let callback = callbacks.menu_item_activated.take();
+        if let Some(mut callback) = callback {
+            callback(id);
+            callbacks.menu_item_activated.borrow_mut().get_or_insert(callback);
+        }
+    }
+}
+
+/// An icon in the system tray, also called notification area, with a tooltip and a menu.
+///
+/// Applications that keep running in the background can hide their windows and keep a tray icon, to
+/// let the user open them again. The icon stays in the tray as long as the `TrayIcon` exists.
+///
+/// The Slint platform must be initialized, for example by creating a component, before creating a
+/// tray icon. Not all platforms support tray icons.
+///
+/// ```rust,no_run
+/// use slint::{Image, Menu, MenuItem, TrayIcon};
+///
+/// let tray_icon = TrayIcon::new().unwrap();
+/// tray_icon.set_icon(&Image::load_from_path(std::path::Path::new("icon.png")).unwrap()).unwrap();
+/// tray_icon.set_tooltip("My Application").unwrap();
+/// let menu = Menu::new([
+///     MenuItem::new("show", "Show"),
+///     MenuItem::separator(),
+///     MenuItem::new("quit", "Quit"),
+/// ]);
+/// tray_icon.set_menu(&menu).unwrap();
+/// tray_icon.on_menu_item_activated(|id| match id {
+///     "quit" => slint::quit_event_loop().unwrap(),
+///     _ => { /* show the window */ }
+/// });
+/// ```
+pub struct TrayIcon {
+    adapter: Box<dyn TrayIconAdapter>,
+    callbacks: Rc<TrayIconCallbacks>,
+}
+
+impl TrayIcon {
+    /// Creates a visible tray icon, without an image.
+    ///
+    /// Returns an error if there is no platform, or if it doesn't support tray icons.
+    pub fn new() -> Result<Self, PlatformError> {
+        let callbacks = Rc::new(TrayIconCallbacks::default());
+        let events = TrayIconEvents(Rc::downgrade(&callbacks));
+        let adapter = crate::context::GLOBAL_CONTEXT.with(|ctx| match ctx.get() {
+            Some(ctx) => ctx.platform().create_tray_icon(events),
+            None => Err(PlatformError::NoPlatform),
+        })?;
+        Ok(Self { adapter, callbacks })
+    }
+
+    /// Shows or hides the icon.
+    pub fn set_visible(&self, visible: bool) -> Result<(), PlatformError> {
+        self.adapter.set_visible(visible)
+    }
+
+    /// Sets the image of the icon.
+    pub fn set_icon(&self, icon: &Image) -> Result<(), PlatformError> {
+        self.adapter.set_icon(icon)
+    }
+
+    /// Sets the text shown when the pointer hovers the icon.
+    pub fn set_tooltip(&self, tooltip: &str) -> Result<(), PlatformError> {
+        self.adapter.set_tooltip(tooltip)
+    }
+
+    /// Sets the menu shown for the icon, usually on right click.
+    pub fn set_menu(&self, menu: &Menu) -> Result<(), PlatformError> {
+        self.adapter.set_menu(menu)
+    }
+
+    /// Sets the callback invoked when the icon is activated, usually by clicking it.
+    pub fn on_activated(&self, callback: impl FnMut() + 'static) {
+        *self.callbacks.activated.borrow_mut() = Some(Box::new(callback));
+    }
+
+    /// Sets the callback invoked with the [`id`](MenuItem::id) of the menu item activated by the user.
+    pub fn on_menu_item_activated(&self, callback: impl FnMut(&str) + 'static) {
+        *self.callbacks.menu_item_activated.borrow_mut() = Some(Box::new(callback));
+    }
+}
+
+#[test]
+fn test_tray_icon_events() {
+    let callbacks = Rc::new(TrayIconCallbacks::default());
+    let events = TrayIconEvents(Rc::downgrade(&callbacks));
+    let activated = Rc::new(RefCell::new(Vec::<SharedString>::new()));
+    let a = activated.clone();
+    *callbacks.menu_item_activated.borrow_mut() =
+        Some(Box::new(move |id| a.borrow_mut().push(id.into())));
+    events.menu_item_activated("show");
+    events.menu_item_activated("quit");
+    events.activated();
+    assert_eq!(*activated.borrow(), ["show", "quit"]);
+    drop(callbacks);
+    events.menu_item_activated("show");
+    assert_eq!(activated.borrow().len(), 2);
+}
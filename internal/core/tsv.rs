@@ -0,0 +1,182 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Tab-separated values, used by the `TableView` widget to copy the selected cells to the clipboard.
+
+use crate::model::{Model, ModelRc, StandardListViewItem};
+use crate::SharedString;
+use core::ops::Range;
+
+/// Formats the cells as tab-separated values: the cells of a row are separated by tabs,
+/// and the rows by newlines.
+///
+/// Tabs and newlines within the cells are replaced by spaces so that the structure of the table is kept.
+pub fn cells_to_tsv<S: AsRef<str>>(
+    rows: impl IntoIterator<Item = impl IntoIterator<Item = S>>,
+) -> SharedString {
+    let mut out = SharedString::default();
+    for (row_index, row) in rows.into_iter().enumerate() {
+        if row_index > 0 {
+            out.push_str("\n");
+        }
+        for (column_index, cell) in row.into_iter().enumerate() {
+            if column_index > 0 {
+                out.push_str("\t");
+            }
+            let cell = cell.as_ref();
+            if cell.contains(['\t', '\n', '\r']) {
+                out.push_str(&cell.replace(['\t', '\n', '\r'], " "));
+            } else {
+                out.push_str(cell);
+            }
+        }
+    }
+    out
+}
+
+/// The maximum number of cells that [`table_cells_to_tsv`] formats, so that copying a huge
+/// selection can't exhaust the memory. Larger selections are truncated to the rows that fit.
+pub const MAX_TSV_CELLS: usize = 1 << 20;
+
+/// Returns the rows and the columns of the cells between the given corners, which can be given
+/// in any order, that are within a table of `row_count` rows where a row has `column_count(row)` columns.
+///
+/// The range is truncated to at most [`MAX_TSV_CELLS`] cells.
+pub fn table_cell_range(
+    row_count: usize,
+    column_count: impl Fn(usize) -> usize,
+    start_row: i32,
+    start_column: i32,
+    end_row: i32,
+    end_column: i32,
+) -> (Range<usize>, Range<usize>) {
+    let range = |a: i32, b: i32, len: usize| {
+        let start = a.min(b).max(0) as usize;
+        let end = (a.max(b) as i64 + 1).max(0) as usize;
+        start.min(len)..end.min(len)
+    };
+    let mut rows = range(start_row, end_row, row_count);
+    rows.end = rows.end.min(rows.start.saturating_add(MAX_TSV_CELLS));
+    let column_count = rows.clone().map(column_count).max().unwrap_or(0);
+    let columns = range(start_column, end_column, column_count.min(MAX_TSV_CELLS));
+    if columns.is_empty() {
+        return (0..0, 0..0);
+    }
+    rows.end = rows.end.min(rows.start + MAX_TSV_CELLS / columns.len());
+    (rows, columns)
+}
+
+/// Same as [`cells_to_tsv`] for the cells of a `TableView` model that are within the given range.
+///
+/// The range includes both corners, which can be given in any order. It is limited to the
+/// cells of the model, see [`table_cell_range`].
+pub fn table_cells_to_tsv(
+    rows: &ModelRc<ModelRc<StandardListViewItem>>,
+    start_row: i32,
+    start_column: i32,
+    end_row: i32,
+    end_column: i32,
+) -> SharedString {
+    let (row_range, column_range) = table_cell_range(
+        rows.row_count(),
+        |row| rows.row_data(row).map_or(0, |row| row.row_count()),
+        start_row,
+        start_column,
+        end_row,
+        end_column,
+    );
+    cells_to_tsv(row_range.map(|row| {
+        let row = rows.row_data(row).unwrap_or_default();
+        column_range
+            .clone()
+            .map(move |column| row.row_data(column).map(|cell| cell.text).unwrap_or_default())
+    }))
+}
+
+#[cfg(feature = "ffi")]
+mod ffi {
+    #![allow(unsafe_code)]
+
+    use super::*;
+    use crate::slice::Slice;
+
+    /// Formats the `cells`, given row after row, as tab-separated values
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_cells_to_tsv(
+        cells: Slice<SharedString>,
+        column_count: usize,
+        out: &mut SharedString,
+    ) {
+        *out = cells_to_tsv(cells.chunks(column_count.max(1)));
+    }
+
+    /// Computes the [`table_cell_range`] of a table of `row_count` rows and `column_count` columns
+    #[allow(clippy::too_many_arguments)]
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_table_cell_range(
+        row_count: usize,
+        column_count: usize,
+        start_row: i32,
+        start_column: i32,
+        end_row: i32,
+        end_column: i32,
+        rows_start: &mut usize,
+        rows_end: &mut usize,
+        columns_start: &mut usize,
+        columns_end: &mut usize,
+    ) {
+        let (row_range, column_range) = table_cell_range(
+            row_count,
+            |_| column_count,
+            start_row,
+            start_column,
+            end_row,
+            end_column,
+        );
+        (*rows_start, *rows_end) = (row_range.start, row_range.end);
+        (*columns_start, *columns_end) = (column_range.start, column_range.end);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::VecModel;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_cells_to_tsv() {
+        assert_eq!(cells_to_tsv([["a", "b"], ["c", "d"]]), "a\tb\nc\td");
+        assert_eq!(cells_to_tsv([["with\ttab", "multi\nline"]]), "with tab\tmulti line");
+        assert_eq!(cells_to_tsv(Vec::<Vec<&str>>::new()), "");
+    }
+
+    #[test]
+    fn test_table_cells_to_tsv() {
+        let rows: ModelRc<ModelRc<StandardListViewItem>> = ModelRc::new(VecModel::from(vec![
+            ModelRc::new(VecModel::from(vec!["a".into(), "b".into(), "c".into()])),
+            ModelRc::new(VecModel::from(vec!["d".into(), "e".into(), "f".into()])),
+        ]));
+        assert_eq!(table_cells_to_tsv(&rows, 0, 0, 0, 0), "a");
+        assert_eq!(table_cells_to_tsv(&rows, 1, 2, 0, 1), "b\tc\ne\tf");
+        assert_eq!(table_cells_to_tsv(&rows, 1, 1, 2, 3), "e\tf");
+        assert_eq!(table_cells_to_tsv(&rows, -1, -1, -1, -1), "");
+        assert_eq!(table_cells_to_tsv(&rows, 2, 0, 5, 2), "");
+    }
+
+    #[test]
+    fn test_table_cell_range() {
+        assert_eq!(table_cell_range(2, |row| row + 1, 1, 3, 0, 0), (0..2, 0..2));
+        assert_eq!(table_cell_range(2, |_| 3, -5, -5, -1, 1), (0..0, 0..0));
+        assert_eq!(table_cell_range(0, |_| 3, 0, 0, 1, 1), (0..0, 0..0));
+        assert_eq!(
+            table_cell_range(usize::MAX, |_| 1000, 0, 0, i32::MAX, i32::MAX),
+            (0..MAX_TSV_CELLS / 1000, 0..1000)
+        );
+        assert_eq!(
+            table_cell_range(10, |_| usize::MAX, i32::MAX, i32::MAX, 0, 0),
+            (0..1, 0..MAX_TSV_CELLS)
+        );
+    }
+}
@@ -7,15 +7,16 @@
 //! Exposed Window API
 
 use crate::api::{
-    CloseRequestResponse, LogicalPosition, PhysicalPosition, PhysicalSize, PlatformError, Window,
-    WindowPosition, WindowSize,
+    CloseRequestResponse, FirstFrameTimings, LogicalPosition, PhysicalPosition, PhysicalSize,
+    PlatformError, Screen, Window, WindowHitTestArea, WindowHitTestRegion, WindowPosition,
+    WindowResizeEdge, WindowSize,
 };
 use crate::input::{
     key_codes, ClickState, FocusEvent, FocusReason, InternalKeyboardModifierState, KeyEvent,
     KeyEventType, MouseEvent, MouseInputState, PointerEventButton, TextCursorBlinker,
 };
 use crate::item_tree::{
-    ItemRc, ItemTreeRc, ItemTreeRef, ItemTreeVTable, ItemTreeWeak, ItemWeak,
+    ItemRc, ItemTreeRc, ItemTreeRef, ItemTreeVTable, ItemTreeWeak, ItemVisitorResult, ItemWeak,
     ParentItemTraversalMode,
 };
 use crate::items::{ColorScheme, InputType, ItemRef, MouseCursor, PopupClosePolicy};
@@ -252,6 +253,28 @@ fn bring_to_front(&self) -> Result<(), PlatformError> {
         Ok(())
     }
 
+    /// Starts moving the window with the pressed pointer, for [`Window::start_system_move()`].
+    fn start_system_move(&self) -> Result<(), PlatformError> {
+        Err(PlatformError::Other("Moving the window is not supported by this backend".into()))
+    }
+
+    /// Starts resizing the window by `edge` with the pressed pointer, for [`Window::start_system_resize()`].
+    fn start_system_resize(&self, _edge: WindowResizeEdge) -> Result<(), PlatformError> {
+        Err(PlatformError::Other("Resizing the window is not supported by this backend".into()))
+    }
+
+    /// Returns the screen the window is on, for [`Window::screen()`].
+    fn screen(&self) -> Option<Screen> {
+        None
+    }
+
+    /// Moves the window to the screen, for [`Window::set_screen()`].
+    fn set_screen(&self, _screen: &Screen) -> Result<(), PlatformError> {
+        Err(PlatformError::Other(
+            "Placing windows on screens is not supported by this backend".into(),
+        ))
+    }
+
     /// Return the inset of the safe area of the Window in physical pixels.
     /// This is necessary to avoid overlapping system UI such as notches or system bars.
     fn safe_area_inset(&self) -> crate::lengths::PhysicalInset {
@@ -372,6 +395,21 @@ pub fn is_maximized(&self) -> bool {
     pub fn is_minimized(&self) -> bool {
         self.0.minimized.get()
     }
+
+    /// true if the window should stay above the other windows, otherwise false
+    pub fn is_always_on_top(&self) -> bool {
+        self.0.is_always_on_top()
+    }
+
+    /// true if the window should not be shown in the taskbar, otherwise false
+    pub fn is_skip_taskbar(&self) -> bool {
+        self.0.skip_taskbar.get()
+    }
+
+    /// The opacity of the whole window, from 0 (transparent) to 1 (opaque)
+    pub fn opacity(&self) -> f32 {
+        self.0.opacity.get()
+    }
 }
 
 struct WindowPropertiesTracker {
@@ -444,6 +482,9 @@ struct WindowPinnedFields {
     active: Property<bool>,
     #[pin]
     text_input_focused: Property<bool>,
+    /// See [`WindowInner::set_pixel_snapping`]
+    #[pin]
+    pixel_snapping: Property<bool>,
 }
 
 /// Inner datastructure for the [`crate::api::Window`]
@@ -459,6 +500,8 @@ pub struct WindowInner {
     pub focus_item: RefCell<crate::item_tree::ItemWeak>,
     /// The last text that was sent to the input method
     pub(crate) last_ime_text: RefCell<SharedString>,
+    /// The selection of the `Text` and `MarkdownText` elements with `selectable: true`
+    pub(crate) text_selection: RefCell<crate::text_selection::TextSelection>,
     /// Don't let ComponentContainers's instantiation change the focus.
     /// This is a workaround for a recursion when instantiating ComponentContainer because the
     /// init code for the component might have code that sets the focus, but we don't want that
@@ -469,6 +512,9 @@ pub struct WindowInner {
     pinned_fields: Pin<Box<WindowPinnedFields>>,
     maximized: Cell<bool>,
     minimized: Cell<bool>,
+    occluded: Cell<bool>,
+    skip_taskbar: Cell<bool>,
+    opacity: Cell<f32>,
 
     /// Stack of currently active popups
     active_popups: RefCell<Vec<PopupWindow>>,
@@ -476,9 +522,59 @@ pub struct WindowInner {
     had_popup_on_press: Cell<bool>,
     close_requested: Callback<(), CloseRequestResponse>,
     click_state: ClickState,
+    /// Draw the boundaries of the items on top of the scene, see [`Self::set_layout_debug_overlay`]
+    layout_debug_overlay: Cell<bool>,
+    /// Warn about unsatisfiable layout constraints, see [`Self::set_layout_constraint_warnings`]
+    layout_constraint_warnings: Cell<bool>,
+    /// The item trees and orientations for which a conflict was reported and not resolved since
+    reported_layout_conflicts: RefCell<Vec<(ItemTreeWeak, crate::layout::Orientation)>>,
+    /// See [`Window::set_hit_test_regions()`]
+    hit_test_regions: RefCell<Vec<WindowHitTestRegion>>,
+    /// See [`Window::first_frame_timings()`]
+    first_frame_timings: Cell<FirstFrameTimings>,
+    /// The callbacks registered with [`Window::on_first_frame_rendered()`] that wait for the first frame
+    first_frame_rendered_callbacks: RefCell<Vec<Box<dyn FnOnce()>>>,
     pub(crate) ctx: once_cell::unsync::Lazy<crate::SlintContext>,
 }
 
+/// Reads the `SLINT_DEBUG_LAYOUT` environment variable and returns whether the layout debug overlay
+/// and the layout constraint warnings are requested. `warn` only enables the warnings, any other
+/// value but `0` enables both.
+fn layout_debug_requested() -> (bool, bool) {
+    #[cfg(feature = "std")]
+    let value = std::env::var("SLINT_DEBUG_LAYOUT").ok();
+    #[cfg(not(feature = "std"))]
+    let value = option_env!("SLINT_DEBUG_LAYOUT");
+    match value.as_deref() {
+        None | Some("0") => (false, false),
+        Some("warn") => (false, true),
+        Some(_) => (true, true),
+    }
+}
+
+/// Returns a description of the first element of the item, and of the elements it is based on,
+/// for use in diagnostics. For example: `MyPanel App::panel (app.slint:12:5), based on VerticalLayout (panel.slint:4:5)`
+fn describe_elements(item: &ItemRc) -> alloc::string::String {
+    let Some(infos) = item.element_type_names_ids_and_locations(0).filter(|i| !i.is_empty()) else {
+        return "an element (set SLINT_EMIT_DEBUG_INFO=1 when compiling to know which)".into();
+    };
+    infos
+        .iter()
+        .map(|(type_name, id, location)| {
+            let mut description = alloc::string::String::from(type_name.as_str());
+            if !id.is_empty() {
+                description.push(' ');
+                description.push_str(id);
+            }
+            if !location.is_empty() {
+                description.push_str(&alloc::format!(" ({location})"));
+            }
+            description
+        })
+        .collect::<Vec<_>>()
+        .join(", based on ")
+}
+
 impl Drop for WindowInner {
     fn drop(&mut self) {
         if let Some(existing_blinker) = self.cursor_blinker.borrow().upgrade() {
@@ -508,6 +604,8 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             redraw_tracker.set_debug_name("i_slint_core::Window::redraw_tracker".into());
         }
 
+        let (layout_debug_overlay, layout_constraint_warnings) = layout_debug_requested();
+
         Self {
             window_adapter_weak,
             component: Default::default(),
@@ -523,11 +621,16 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
                     false,
                     "i_slint_core::Window::text_input_focused",
                 ),
+                pixel_snapping: Property::new_named(true, "i_slint_core::Window::pixel_snapping"),
             }),
             maximized: Cell::new(false),
             minimized: Cell::new(false),
+            occluded: Cell::new(false),
+            skip_taskbar: Cell::new(false),
+            opacity: Cell::new(1.),
             focus_item: Default::default(),
             last_ime_text: Default::default(),
+            text_selection: Default::default(),
             cursor_blinker: Default::default(),
             active_popups: Default::default(),
             next_popup_id: Cell::new(NonZeroU32::MIN),
@@ -535,6 +638,12 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             close_requested: Default::default(),
             click_state: ClickState::default(),
             prevent_focus_change: Default::default(),
+            layout_debug_overlay: Cell::new(layout_debug_overlay),
+            layout_constraint_warnings: Cell::new(layout_constraint_warnings),
+            reported_layout_conflicts: Default::default(),
+            hit_test_regions: Default::default(),
+            first_frame_timings: Default::default(),
+            first_frame_rendered_callbacks: Default::default(),
             // The ctx is lazy so that a Window can be initialized before the backend.
             // (for example in test_empty_window)
             ctx: once_cell::unsync::Lazy::new(|| {
@@ -543,6 +652,99 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
         }
     }
 
+    /// Returns true if the items with the `PixelSnapping::Auto` policy are rounded to device pixels.
+    pub fn pixel_snapping(&self) -> bool {
+        self.pinned_fields.as_ref().project_ref().pixel_snapping.get()
+    }
+
+    /// Sets whether the items with the `PixelSnapping::Auto` policy are rounded to device pixels.
+    /// The renderers track this property, so the window is redrawn when it changes.
+    pub fn set_pixel_snapping(&self, enabled: bool) {
+        self.pinned_fields.pixel_snapping.set(enabled)
+    }
+
+    /// Returns true if the layout debug overlay is drawn on top of the scene.
+    pub fn layout_debug_overlay(&self) -> bool {
+        self.layout_debug_overlay.get()
+    }
+
+    /// Enables or disables the overlay that shows the boundaries of the items and the
+    /// layout constraints that can't be satisfied.
+    pub fn set_layout_debug_overlay(&self, enabled: bool) {
+        if self.layout_debug_overlay.replace(enabled) != enabled {
+            // The outlines are drawn over every item, so the partial renderers must not reuse
+            // any part of the previous frame.
+            if let Some(window_adapter) = self.window_adapter_weak.upgrade() {
+                let window_rect = LogicalRect::new(
+                    LogicalPoint::default(),
+                    window_adapter.size().to_logical(self.scale_factor()).to_euclid(),
+                );
+                window_adapter.renderer().mark_dirty_region(window_rect.into());
+                window_adapter.request_redraw();
+            }
+        }
+    }
+
+    /// Returns true if unsatisfiable layout constraints are reported with a warning.
+    pub fn layout_constraint_warnings(&self) -> bool {
+        self.layout_constraint_warnings.get()
+    }
+
+    /// Enables or disables the warnings about layout constraints that can't be satisfied.
+    /// See [`Self::check_layout_constraints`].
+    pub fn set_layout_constraint_warnings(&self, enabled: bool) {
+        if self.layout_constraint_warnings.replace(enabled) != enabled {
+            self.reported_layout_conflicts.borrow_mut().clear();
+            if let Some(window_adapter) = self.window_adapter_weak.upgrade() {
+                window_adapter.request_redraw();
+            }
+        }
+    }
+
+    /// Checks if the minimum size of the given item tree is larger than its maximum size, in which
+    /// case the layout clamps the size. A warning naming the elements involved and their location
+    /// in the `.slint` sources is logged the first time the conflict is seen. It is reported again
+    /// only if the conflict was resolved in between.
+    ///
+    /// Returns the warnings that were logged by this call.
+    pub fn check_layout_constraints(&self, item_tree: &ItemTreeRc) -> Vec<SharedString> {
+        let mut warnings = Vec::new();
+        let weak = ItemTreeRc::downgrade(item_tree);
+        let mut reported = self.reported_layout_conflicts.borrow_mut();
+        reported.retain(|(item_tree, _)| item_tree.upgrade().is_some());
+        for orientation in
+            [crate::layout::Orientation::Horizontal, crate::layout::Orientation::Vertical]
+        {
+            let info = ItemTreeRc::borrow_pin(item_tree).as_ref().layout_info(orientation);
+            let position = reported
+                .iter()
+                .position(|(t, o)| *o == orientation && vtable::VWeak::ptr_eq(t, &weak));
+            match (info.min > info.max, position) {
+                (true, None) => {
+                    reported.push((weak.clone(), orientation));
+                    let dimension = match orientation {
+                        crate::layout::Orientation::Horizontal => "width",
+                        crate::layout::Orientation::Vertical => "height",
+                    };
+                    let warning: SharedString = alloc::format!(
+                        "Slint: The layout constraints of {} cannot be satisfied: the minimum {dimension} ({}) is larger than the maximum {dimension} ({}). The {dimension} is clamped to the maximum.",
+                        describe_elements(&ItemRc::new(item_tree.clone(), 0)),
+                        info.min,
+                        info.max,
+                    )
+                    .into();
+                    crate::debug_log!("{warning}");
+                    warnings.push(warning);
+                }
+                (false, Some(position)) => {
+                    reported.swap_remove(position);
+                }
+                _ => {}
+            }
+        }
+        warnings
+    }
+
     /// Associates this window with the specified component. Further event handling and rendering, etc. will be
     /// done with that component.
     pub fn set_component(&self, component: &ItemTreeRc) {
@@ -551,6 +753,7 @@ pub fn set_component(&self, component: &ItemTreeRc) {
         self.mouse_input_state.replace(Default::default());
         self.modifiers.replace(Default::default());
         self.component.replace(ItemTreeRc::downgrade(component));
+        self.record_first_frame_timing(|timings| &mut timings.component_created);
         self.pinned_fields.window_properties_tracker.set_dirty(); // component changed, layout constraints for sure must be re-calculated
         let window_adapter = self.window_adapter();
         window_adapter.renderer().set_window_adapter(&window_adapter);
@@ -591,6 +794,45 @@ pub fn active_popups(&self) -> core::cell::Ref<'_, [PopupWindow]> {
         core::cell::Ref::map(self.active_popups.borrow(), |v| v.as_slice())
     }
 
+    /// Starts a drag operation carrying `data` of the given `mime_type`, as if a `DragArea`
+    /// had started it. The data is delivered to the `DropArea` elements under the pointer
+    /// until the left button is released or the pointer leaves the window.
+    pub fn start_drag(&self, mime_type: SharedString, data: SharedString) {
+        let mut mouse_input_state = self.mouse_input_state.take();
+        mouse_input_state.drag_data =
+            Some(crate::items::DropEvent { mime_type, data, position: Default::default() });
+        self.mouse_input_state.set(mouse_input_state);
+    }
+
+    /// Receive data dragged from outside of the application, for example files from a file manager.
+    ///
+    /// `event.position` is the pointer position in logical coordinates of the window. When `dropped`
+    /// is false, the data is hovering over the window and the `DropArea` elements under the pointer
+    /// are asked whether they accept it. When `dropped` is true, the data is delivered to the
+    /// `DropArea` under the pointer. Call [`Self::process_external_drag_exit`] when the drag
+    /// leaves the window without dropping.
+    pub fn process_external_drag(&self, event: crate::items::DropEvent, dropped: bool) {
+        let position = event.position.to_euclid();
+        let mut mouse_input_state = self.mouse_input_state.take();
+        mouse_input_state.drag_data = Some(event);
+        self.mouse_input_state.set(mouse_input_state);
+        self.process_mouse_input(if dropped {
+            MouseEvent::Released { position, button: PointerEventButton::Left, click_count: 0 }
+        } else {
+            MouseEvent::Moved { position }
+        });
+    }
+
+    /// Cancels a drag of external data previously reported with [`Self::process_external_drag`].
+    pub fn process_external_drag_exit(&self) {
+        let mouse_input_state = self.mouse_input_state.take();
+        let dragging = mouse_input_state.drag_data.is_some();
+        self.mouse_input_state.set(mouse_input_state);
+        if dragging {
+            self.process_mouse_input(MouseEvent::Exit);
+        }
+    }
+
     /// Receive a mouse event and pass it to the items of the component to
     /// change their state.
     pub fn process_mouse_input(&self, mut event: MouseEvent) {
@@ -1117,29 +1359,118 @@ pub fn draw_contents<T>(
         render_components: impl FnOnce(&[(ItemTreeWeak, LogicalPoint)]) -> T,
     ) -> Option<T> {
         let component_weak = ItemTreeRc::downgrade(&self.try_component()?);
-        Some(self.pinned_fields.as_ref().project_ref().redraw_tracker.evaluate_as_dependency_root(
-            || {
-                if !self
-                    .active_popups
-                    .borrow()
-                    .iter()
-                    .any(|p| matches!(p.location, PopupWindowLocation::ChildWindow(..)))
-                {
-                    render_components(&[(component_weak, LogicalPoint::default())])
-                } else {
-                    let borrow = self.active_popups.borrow();
-                    let mut item_trees = Vec::with_capacity(borrow.len() + 1);
-                    item_trees.push((component_weak, LogicalPoint::default()));
-                    for popup in borrow.iter() {
-                        if let PopupWindowLocation::ChildWindow(location) = &popup.location {
-                            item_trees.push((ItemTreeRc::downgrade(&popup.component), *location));
+        let result =
+            self.pinned_fields.as_ref().project_ref().redraw_tracker.evaluate_as_dependency_root(
+                || {
+                    if !self
+                        .active_popups
+                        .borrow()
+                        .iter()
+                        .any(|p| matches!(p.location, PopupWindowLocation::ChildWindow(..)))
+                    {
+                        render_components(&[(component_weak, LogicalPoint::default())])
+                    } else {
+                        let borrow = self.active_popups.borrow();
+                        let mut item_trees = Vec::with_capacity(borrow.len() + 1);
+                        item_trees.push((component_weak, LogicalPoint::default()));
+                        for popup in borrow.iter() {
+                            if let PopupWindowLocation::ChildWindow(location) = &popup.location {
+                                item_trees
+                                    .push((ItemTreeRc::downgrade(&popup.component), *location));
+                            }
                         }
+                        drop(borrow);
+                        render_components(&item_trees)
                     }
-                    drop(borrow);
-                    render_components(&item_trees)
+                },
+            );
+        // Snapshots of a window that isn't shown are not the first frame on the screen
+        if self.is_visible() && self.first_frame_timings.get().first_frame_rendered.is_none() {
+            self.record_first_frame_timing(|timings| &mut timings.first_frame_rendered);
+            self.first_frame_rendered();
+        }
+        Some(result)
+    }
+
+    fn record_first_frame_timing(
+        &self,
+        step: impl FnOnce(&mut FirstFrameTimings) -> &mut Option<core::time::Duration>,
+    ) {
+        let mut timings = self.first_frame_timings.get();
+        let timing = step(&mut timings);
+        if timing.is_none() {
+            *timing = Some(core::time::Duration::from_millis(crate::animations::Instant::now().0));
+            self.first_frame_timings.set(timings);
+        }
+    }
+
+    /// Logs the first frame timings if requested and invokes the callbacks waiting for the first frame.
+    fn first_frame_rendered(&self) {
+        if crate::graphics::rendering_metrics_collector::startup_timings_requested() {
+            let timings = self.first_frame_timings.get();
+            let since_shown = timings
+                .first_frame_rendered
+                .zip(timings.shown)
+                .map(|(rendered, shown)| rendered.saturating_sub(shown))
+                .unwrap_or_default();
+            crate::debug_log!(
+                "Slint: first frame rendered {}ms after the creation of the component ({}ms after showing the window)",
+                timings.time_to_first_frame().unwrap_or_default().as_millis(),
+                since_shown.as_millis()
+            );
+        }
+        let callbacks = core::mem::take(&mut *self.first_frame_rendered_callbacks.borrow_mut());
+        if !callbacks.is_empty() {
+            // Invoke the callbacks from the event loop, once the backend has presented the frame
+            crate::timers::Timer::single_shot(Default::default(), move || {
+                for callback in callbacks {
+                    callback();
+                }
+            });
+        }
+    }
+
+    /// See [`Window::first_frame_timings()`]
+    pub fn first_frame_timings(&self) -> FirstFrameTimings {
+        self.first_frame_timings.get()
+    }
+
+    /// See [`Window::on_first_frame_rendered()`]
+    pub fn on_first_frame_rendered(&self, callback: Box<dyn FnOnce()>) {
+        if self.first_frame_timings.get().first_frame_rendered.is_some() {
+            crate::timers::Timer::single_shot(Default::default(), callback);
+        } else {
+            self.first_frame_rendered_callbacks.borrow_mut().push(callback);
+        }
+    }
+
+    /// See [`Window::prepare_first_frame()`]
+    pub fn prepare_first_frame(&self) {
+        let Some(component) = self.try_component() else { return };
+        let window_rect = LogicalRect::new(
+            LogicalPoint::default(),
+            self.window_adapter().size().to_logical(self.scale_factor()).to_euclid(),
+        );
+        crate::item_tree::visit_items(
+            &component,
+            crate::item_tree::TraversalOrder::BackToFront,
+            |item_tree, item, index, (offset, clip)| {
+                // Reading the geometry evaluates the bindings of the layout, and visiting the
+                // children instantiates the repeated elements.
+                let geometry = vtable::VRc::borrow_pin(item_tree)
+                    .as_ref()
+                    .item_geometry(index)
+                    .translate(*offset);
+                if !item.as_ref().clips_children() {
+                    ItemVisitorResult::Continue((geometry.origin.to_vector(), *clip))
+                } else if let Some(clip) = geometry.intersection(clip) {
+                    ItemVisitorResult::Continue((geometry.origin.to_vector(), clip))
+                } else {
+                    ItemVisitorResult::SkipChildren
                 }
             },
-        ))
+            (crate::lengths::LogicalVector::default(), window_rect),
+        );
     }
 
     /// Registers the window with the windowing system, in order to render the component's items and react
@@ -1152,6 +1483,7 @@ pub fn show(&self) -> Result<(), PlatformError> {
             }
         }
 
+        self.record_first_frame_timing(|timings| &mut timings.shown);
         self.update_window_properties();
         self.window_adapter().set_visible(true)?;
         // Make sure that the window's inner size is in sync with the root window item's
@@ -1448,6 +1780,28 @@ pub fn set_text_input_focused(&self, value: bool) {
         self.pinned_fields.text_input_focused.set(value)
     }
 
+    /// Sets the text of the default clipboard of the platform
+    pub fn set_clipboard_text(&self, text: &str) {
+        self.ctx.platform().set_clipboard_text(text, crate::platform::Clipboard::DefaultClipboard)
+    }
+
+    /// Opens the url with the platform, if the application's open url handler allows it.
+    /// Without a handler, only `http`, `https` and `mailto` urls are allowed.
+    pub fn open_url(&self, url: &str) {
+        let allowed = match self.ctx.0.open_url_handler.borrow().as_ref() {
+            Some(handler) => handler(url),
+            None => ["http://", "https://", "mailto:"].iter().any(|scheme| {
+                url.get(..scheme.len()).is_some_and(|s| s.eq_ignore_ascii_case(scheme))
+            }),
+        };
+        if !allowed {
+            return;
+        }
+        if let Err(err) = self.ctx.platform().open_url(url) {
+            crate::debug_log!("Error opening url {url}: {err}");
+        }
+    }
+
     /// Returns true if the window is visible
     pub fn is_visible(&self) -> bool {
         self.strong_component_ref.borrow().is_some()
@@ -1561,6 +1915,108 @@ pub fn set_minimized(&self, minimized: bool) {
         self.update_window_properties()
     }
 
+    /// Returns if the windowing system reported the window as fully covered by other windows
+    pub fn is_occluded(&self) -> bool {
+        self.occluded.get()
+    }
+
+    /// Called by the backend when the window becomes fully covered by other windows, or visible again
+    pub fn set_occluded(&self, occluded: bool) {
+        self.occluded.set(occluded);
+    }
+
+    /// Returns true if the animations of this window don't need new frames because nobody can
+    /// see them, according to [`set_animation_throttling()`](crate::api::set_animation_throttling)
+    pub fn animations_throttled(&self) -> bool {
+        crate::animations::animation_throttling() != crate::api::AnimationThrottling::Never
+            && (!self.is_visible() || self.is_minimized() || self.is_occluded())
+    }
+
+    /// Returns if the window stays above the other windows
+    pub fn is_always_on_top(&self) -> bool {
+        self.window_item().is_some_and(|window_item| window_item.as_pin_ref().always_on_top())
+    }
+
+    /// Set whether the window stays above the other windows
+    pub fn set_always_on_top(&self, always_on_top: bool) {
+        if let Some(window_item) = self.window_item() {
+            window_item.as_pin_ref().always_on_top.set(always_on_top);
+            self.update_window_properties()
+        }
+    }
+
+    /// Returns if the window is hidden from the taskbar
+    pub fn is_skip_taskbar(&self) -> bool {
+        self.skip_taskbar.get()
+    }
+
+    /// Set whether the window is hidden from the taskbar
+    pub fn set_skip_taskbar(&self, skip_taskbar: bool) {
+        self.skip_taskbar.set(skip_taskbar);
+        self.update_window_properties()
+    }
+
+    /// Returns the opacity of the whole window
+    pub fn opacity(&self) -> f32 {
+        self.opacity.get()
+    }
+
+    /// Set the opacity of the whole window, clamped between 0 and 1
+    pub fn set_opacity(&self, opacity: f32) {
+        self.opacity.set(if opacity.is_nan() { 1. } else { opacity.clamp(0., 1.) });
+        self.update_window_properties()
+    }
+
+    /// Starts moving the window, see [`Window::start_system_move()`]
+    pub fn start_system_move(&self) -> Result<(), PlatformError> {
+        match self.window_adapter().internal(crate::InternalToken) {
+            Some(internal) => internal.start_system_move(),
+            None => Err(PlatformError::Other(
+                "Moving the window is not supported by this backend".into(),
+            )),
+        }
+    }
+
+    /// Starts resizing the window, see [`Window::start_system_resize()`]
+    pub fn start_system_resize(&self, edge: WindowResizeEdge) -> Result<(), PlatformError> {
+        match self.window_adapter().internal(crate::InternalToken) {
+            Some(internal) => internal.start_system_resize(edge),
+            None => Err(PlatformError::Other(
+                "Resizing the window is not supported by this backend".into(),
+            )),
+        }
+    }
+
+    /// Returns the screen the window is on, see [`Window::screen()`]
+    pub fn screen(&self) -> Option<Screen> {
+        self.window_adapter().internal(crate::InternalToken)?.screen()
+    }
+
+    /// Moves the window to the screen, see [`Window::set_screen()`]
+    pub fn set_screen(&self, screen: &Screen) -> Result<(), PlatformError> {
+        match self.window_adapter().internal(crate::InternalToken) {
+            Some(internal) => internal.set_screen(screen),
+            None => Err(PlatformError::Other(
+                "Placing windows on screens is not supported by this backend".into(),
+            )),
+        }
+    }
+
+    /// Sets the regions of a frameless window, see [`Window::set_hit_test_regions()`]
+    pub fn set_hit_test_regions(&self, regions: Vec<WindowHitTestRegion>) {
+        *self.hit_test_regions.borrow_mut() = regions;
+    }
+
+    /// Returns the area of the frameless window at the position, from the regions set with
+    /// [`Window::set_hit_test_regions()`]. Returns `None` if the window has a frame.
+    pub fn hit_test(&self, position: LogicalPosition) -> Option<WindowHitTestArea> {
+        if !self.window_item().is_some_and(|window_item| window_item.as_pin_ref().no_frame()) {
+            return None;
+        }
+        let regions = self.hit_test_regions.borrow();
+        regions.iter().rev().find(|region| region.contains(position)).map(|region| region.area)
+    }
+
     /// Returns the (context global) xdg app id for use with wayland and x11.
     pub fn xdg_app_id(&self) -> Option<SharedString> {
         self.ctx.xdg_app_id()
@@ -1716,8 +2172,27 @@ pub enum GraphicsAPI {
         WindowInner::from_pub(window_adapter.window()).set_text_input_focused(value)
     }
 
-    /// Sets the focus item.
+    /// Sets the text of the default clipboard.
+    #[unsafe(no_mangle)]
+    pub unsafe extern "C" fn slint_windowrc_set_clipboard_text(
+        handle: *const WindowAdapterRcOpaque,
+        text: &SharedString,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        WindowInner::from_pub(window_adapter.window()).set_clipboard_text(text.as_str())
+    }
+
+    /// Opens the url, if allowed by the application.
     #[unsafe(no_mangle)]
+    pub unsafe extern "C" fn slint_windowrc_open_url(
+        handle: *const WindowAdapterRcOpaque,
+        url: &SharedString,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        WindowInner::from_pub(window_adapter.window()).open_url(url.as_str())
+    }
+
+    /// Sets the focus item.
     pub unsafe extern "C" fn slint_windowrc_set_focus_item(
         handle: *const WindowAdapterRcOpaque,
         focus_item: &ItemRc,
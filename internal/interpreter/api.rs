@@ -6,10 +6,10 @@
 #[cfg(feature = "internal")]
 use i_slint_core::component_factory::FactoryContext;
 use i_slint_core::graphics::euclid::approxeq::ApproxEq as _;
-use i_slint_core::model::{Model, ModelExt, ModelRc};
+use i_slint_core::model::{Model, ModelExt, ModelRc, VecModel};
 #[cfg(feature = "internal")]
 use i_slint_core::window::WindowInner;
-use i_slint_core::{PathData, SharedVector};
+use i_slint_core::SharedVector;
 use smol_str::SmolStr;
 use std::collections::HashMap;
 use std::future::Future;
@@ -18,14 +18,20 @@
 
 #[doc(inline)]
 pub use i_slint_compiler::diagnostics::{Diagnostic, DiagnosticLevel};
+pub use i_slint_compiler::SandboxConfiguration;
 
 pub use i_slint_core::api::*;
 // keep in sync with api/rs/slint/lib.rs
 pub use i_slint_backend_selector::api::*;
 pub use i_slint_core::graphics::{
-    Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
+    Brush, Color, Gray8Pixel, HslaColor, Image, LoadImageError, OklchColor, Rgb565Pixel, Rgb8Pixel,
+    Rgba8Pixel, RgbaColor, SharedPixelBuffer,
 };
+pub use i_slint_core::graphics::{PathBuilder, PathData};
+pub use i_slint_core::items::ColorSpace;
 use i_slint_core::items::*;
+// Disambiguate from the `MenuItem` element of `i_slint_core::items`
+pub use i_slint_core::api::MenuItem;
 
 use crate::dynamic_item_tree::{ErasedItemTreeBox, WindowOptions};
 
@@ -108,6 +114,11 @@ pub enum Value {
     /// Correspond to the `image` type in .slint
     Image(Image) = 4,
     /// A model (that includes array in .slint)
+    ///
+    /// It converts from and into a [`ModelRc`] of any Rust type that converts from and into
+    /// `Value`, from an `Rc` of a custom [`Model`] implementation, and from and into a `Vec`.
+    /// When the model is backed by a Rust model, the changes that the `.slint` code makes to the
+    /// rows, such as `values[0] = 42;`, are forwarded to [`Model::set_row_data()`] of the Rust model.
     Model(ModelRc<Value>) = 5,
     /// An object
     Struct(Struct) = 6,
@@ -452,6 +463,37 @@ fn try_from(v: Value) -> Result<ModelRc<T>, Self::Error> {
     }
 }
 
+impl<T: Into<Value> + TryFrom<Value> + 'static, M: Model<Data = T> + 'static> From<Rc<M>>
+    for Value
+{
+    fn from(m: Rc<M>) -> Self {
+        ModelRc::from(m).into()
+    }
+}
+
+/// Creates a [`VecModel`] with the elements, which can be retrieved with `ModelRc<T>::try_from()`
+/// and downcast with [`ModelRc::as_any()`] to observe the changes made by the `.slint` code.
+impl<T: Clone + Into<Value> + TryFrom<Value> + 'static> From<Vec<T>> for Value {
+    fn from(v: Vec<T>) -> Self {
+        ModelRc::new(VecModel::from(v)).into()
+    }
+}
+
+/// Copies the rows of a `Value::Model`. Fails if the value isn't a model or if one of the rows
+/// can't be converted.
+impl<T: TryFrom<Value>> TryFrom<Value> for Vec<T> {
+    type Error = Value;
+    fn try_from(v: Value) -> Result<Vec<T>, Self::Error> {
+        match &v {
+            Value::Model(m) => match m.iter().map(T::try_from).collect::<Result<Vec<T>, _>>() {
+                Ok(rows) => Ok(rows),
+                Err(_) => Err(v),
+            },
+            _ => Err(v),
+        }
+    }
+}
+
 #[test]
 fn value_model_conversion() {
     use i_slint_core::model::*;
@@ -673,7 +715,14 @@ pub async fn build_from_path<P: AsRef<Path>>(
             }
         };
 
-        let r = crate::dynamic_item_tree::load(source, path.into(), self.config.clone()).await;
+        let mut config = self.config.clone();
+        if let Err(d) = apply_project_configuration(path, &mut config) {
+            self.diagnostics = vec![d];
+            return None;
+        }
+
+        let r =
+            crate::dynamic_item_tree::load(source, path.into(), config, &Default::default()).await;
         self.diagnostics = r.diagnostics.into_iter().collect();
         r.components.into_values().next()
     }
@@ -699,16 +748,46 @@ pub async fn build_from_source(
         source_code: String,
         path: PathBuf,
     ) -> Option<ComponentDefinition> {
-        let r = crate::dynamic_item_tree::load(source_code, path, self.config.clone()).await;
+        let r = crate::dynamic_item_tree::load(
+            source_code,
+            path,
+            self.config.clone(),
+            &Default::default(),
+        )
+        .await;
         self.diagnostics = r.diagnostics.into_iter().collect();
         r.components.into_values().next()
     }
 }
 
+/// Applies the `slint.toml` file that applies to the file at `path` to the configuration.
+///
+/// The untrusted code of a sandbox must not be configured by the files around it, and a source read
+/// from stdin has no directory. The `SLINT_STYLE` environment variable has precedence over the style
+/// of the project.
+fn apply_project_configuration(
+    path: &Path,
+    config: &mut i_slint_compiler::CompilerConfiguration,
+) -> Result<(), i_slint_compiler::diagnostics::Diagnostic> {
+    if config.sandbox.is_some() || !path.is_file() {
+        return Ok(());
+    }
+    if let Some(project_config) =
+        i_slint_compiler::project_config::ProjectConfiguration::find_and_load(path)?
+    {
+        if config.style.is_none() {
+            config.style = std::env::var("SLINT_STYLE").ok();
+        }
+        project_config.apply(config);
+    }
+    Ok(())
+}
+
 /// This is the entry point of the crate, it can be used to load a `.slint` file and
 /// compile it into a [`CompilationResult`].
 pub struct Compiler {
     config: i_slint_compiler::CompilerConfiguration,
+    native_globals: crate::global_component::NativeGlobals,
 }
 
 impl Default for Compiler {
@@ -716,7 +795,7 @@ fn default() -> Self {
         let config = i_slint_compiler::CompilerConfiguration::new(
             i_slint_compiler::generator::OutputFormat::Interpreter,
         );
-        Self { config }
+        Self { config, native_globals: Default::default() }
     }
 }
 
@@ -738,6 +817,28 @@ pub fn compiler_configuration(
         &mut self.config
     }
 
+    /// Returns a Compiler for untrusted .slint code, such as UI themes provided by the users of the
+    /// application.
+    ///
+    /// Imports, fonts and images are only loaded from the [allowed paths](SandboxConfiguration::allowed_paths),
+    /// and the compilation fails if the UI could create too many elements.
+    /// The file loader set with [`Self::set_file_loader`] is only called for allowed paths.
+    ///
+    /// ```rust
+    /// use slint_interpreter::{Compiler, SandboxConfiguration};
+    ///
+    /// let mut sandbox = SandboxConfiguration::default();
+    /// sandbox.allowed_paths.push("/usr/share/my-app/themes".into());
+    /// let compiler = Compiler::sandbox(sandbox);
+    /// let result = spin_on::spin_on(compiler.build_from_path("/usr/share/my-app/themes/dark.slint"));
+    /// ```
+    pub fn sandbox(sandbox: SandboxConfiguration) -> Self {
+        Self {
+            config: i_slint_compiler::CompilerConfiguration::sandbox(sandbox),
+            native_globals: Default::default(),
+        }
+    }
+
     /// Sets the include paths used for looking up `.slint` imports to the specified vector of paths.
     pub fn set_include_paths(&mut self, include_paths: Vec<std::path::PathBuf>) {
         self.config.include_paths = include_paths;
@@ -758,6 +859,19 @@ pub fn library_paths(&self) -> &HashMap<String, PathBuf> {
         &self.config.library_paths
     }
 
+    /// Sets the brand palettes, as pairs of the brand name and the path to a `.slint` file that
+    /// exports a `Palette` global with the colors of that brand.
+    ///
+    /// The brands are selected at run-time with the `brand` property of the `Palette` global.
+    pub fn set_brand_palettes(&mut self, brand_palettes: Vec<(String, PathBuf)>) {
+        self.config.brand_palettes = brand_palettes;
+    }
+
+    /// Returns the brand palettes the component compiler is currently configured with.
+    pub fn brand_palettes(&self) -> &[(String, PathBuf)] {
+        &self.config.brand_palettes
+    }
+
     /// Sets the style to be used for widgets.
     ///
     /// Use the "material" style as widget style when compiling:
@@ -798,6 +912,50 @@ pub fn set_file_loader(
             Some(Rc::new(move |path| file_loader_fallback(Path::new(path.as_str()))));
     }
 
+    /// Registers a global singleton implemented in Rust, which the .slint code imports from
+    /// `"@native"`.
+    ///
+    /// The properties and callbacks of the global are declared by [`NativeGlobal::declarations()`].
+    /// When the .slint code invokes one of the callbacks, the call is forwarded to
+    /// [`NativeGlobal::invoke()`]. The application reads and changes the properties of the
+    /// global with [`ComponentInstance::get_global_property()`] and
+    /// [`ComponentInstance::set_global_property()`].
+    ///
+    /// ```rust
+    /// # i_slint_backend_testing::init_no_event_loop();
+    /// use slint_interpreter::{Compiler, NativeGlobal, SharedString, Value};
+    ///
+    /// struct Backend;
+    /// impl NativeGlobal for Backend {
+    ///     fn declarations(&self) -> String {
+    ///         "in-out property <string> user: \"World\"; callback greet(string) -> string;".into()
+    ///     }
+    ///     fn invoke(&self, name: &str, args: &[Value]) -> Value {
+    ///         assert_eq!(name, "greet");
+    ///         let user: SharedString = args[0].clone().try_into().unwrap();
+    ///         Value::from(SharedString::from(format!("Hello, {user}!")))
+    ///     }
+    /// }
+    ///
+    /// let code = r#"
+    ///     import { Backend } from "@native";
+    ///     export component App {
+    ///         out property <string> greeting: Backend.greet(Backend.user);
+    ///     }
+    /// "#;
+    /// let mut compiler = Compiler::default();
+    /// compiler.register_global("Backend", Backend);
+    /// let result = spin_on::spin_on(compiler.build_from_source(code.into(), Default::default()));
+    /// let instance = result.component("App").unwrap().create().unwrap();
+    /// assert_eq!(
+    ///     instance.get_property("greeting").unwrap(),
+    ///     Value::from(SharedString::from("Hello, World!"))
+    /// );
+    /// ```
+    pub fn register_global(&mut self, name: &str, global: impl NativeGlobal + 'static) {
+        self.native_globals.insert(normalize_identifier(name), Rc::new(global));
+    }
+
     /// Compile a .slint file
     ///
     /// Returns a structure that holds the diagnostics and the compiled components.
@@ -834,7 +992,21 @@ pub async fn build_from_path<P: AsRef<Path>>(&self, path: P) -> CompilationResul
             }
         };
 
-        crate::dynamic_item_tree::load(source, path.into(), self.config.clone()).await
+        let mut config = self.build_config();
+        if let Err(d) = apply_project_configuration(path, &mut config) {
+            let mut diagnostics = i_slint_compiler::diagnostics::BuildDiagnostics::default();
+            diagnostics.push_compiler_error(d);
+            return CompilationResult {
+                components: HashMap::new(),
+                diagnostics: diagnostics.into_iter().collect(),
+                #[cfg(feature = "internal")]
+                structs_and_enums: Vec::new(),
+                #[cfg(feature = "internal")]
+                named_exports: Vec::new(),
+            };
+        }
+
+        crate::dynamic_item_tree::load(source, path.into(), config, &self.native_globals).await
     }
 
     /// Compile some .slint code
@@ -850,10 +1022,51 @@ pub async fn build_from_path<P: AsRef<Path>>(&self, path: P) -> CompilationResul
     /// If that is not used, then it is fine to use a very simple executor, such as the one
     /// provided by the `spin_on` crate
     pub async fn build_from_source(&self, source_code: String, path: PathBuf) -> CompilationResult {
-        crate::dynamic_item_tree::load(source_code, path, self.config.clone()).await
+        crate::dynamic_item_tree::load(source_code, path, self.build_config(), &self.native_globals)
+            .await
+    }
+
+    /// Returns the configuration of a build, with the `"@native"` library that declares the
+    /// globals registered with [`Self::register_global()`]
+    fn build_config(&self) -> i_slint_compiler::CompilerConfiguration {
+        let mut config = self.config.clone();
+        if self.native_globals.is_empty() {
+            return config;
+        }
+        let native_globals_path = PathBuf::from(crate::global_component::NATIVE_GLOBALS_PATH);
+        config.library_paths.insert("native".into(), native_globals_path.clone());
+        if let Some(sandbox) = config.sandbox.as_mut() {
+            sandbox.allowed_paths.push(native_globals_path);
+        }
+        let source = crate::global_component::native_globals_source(&self.native_globals);
+        type FileLoaderResult =
+            core::pin::Pin<Box<dyn Future<Output = Option<std::io::Result<String>>>>>;
+        let fallback = config.open_import_fallback.take();
+        config.open_import_fallback = Some(Rc::new(move |path| -> FileLoaderResult {
+            if path == crate::global_component::NATIVE_GLOBALS_PATH {
+                Box::pin(core::future::ready(Some(Ok(source.clone()))))
+            } else if let Some(fallback) = &fallback {
+                fallback(path)
+            } else {
+                Box::pin(core::future::ready(None))
+            }
+        }));
+        config
     }
 }
 
+/// A global singleton implemented in Rust, registered with [`Compiler::register_global()`].
+pub trait NativeGlobal {
+    /// Returns the declarations of the properties and callbacks of the global in the .slint
+    /// language, as in the body of a `global` declaration. For example:
+    /// `out property <bool> online; callback fetch(string) -> string;`
+    fn declarations(&self) -> String;
+
+    /// Called when the .slint code invokes the callback `name` of the global, with the name as it
+    /// is declared, but with `-` instead of `_`. Returns the return value of the callback.
+    fn invoke(&self, name: &str, args: &[Value]) -> Value;
+}
+
 /// The result of a compilation
 ///
 /// If [`Self::has_errors()`] is true, then the compilation failed.
@@ -998,6 +1211,14 @@ pub fn create_with_existing_window(
         ))
     }
 
+    /// Creates a [`ComponentInstancePool`] with `count` instances of this component created ahead of time.
+    pub fn create_pool(&self, count: usize) -> Result<ComponentInstancePool, PlatformError> {
+        let pool =
+            ComponentInstancePool { definition: self.clone(), instances: Default::default() };
+        pool.prewarm(count)?;
+        Ok(pool)
+    }
+
     /// Private implementation of create
     pub(crate) fn create_with_options(
         &self,
@@ -1041,6 +1262,16 @@ pub fn properties(&self) -> impl Iterator<Item = (String, ValueType)> + '_ {
         })
     }
 
+    /// Returns the names of the properties declared as `required`.
+    ///
+    /// These properties must be set after creating an instance of the component, before showing it.
+    pub fn required_properties(&self) -> impl Iterator<Item = String> + '_ {
+        // We create here a 'static guard, because unfortunately the returned type would be restricted to the guard lifetime
+        // which is not required, but this is safe because there is only one instance of the unerased type
+        let guard = unsafe { generativity::Guard::new(generativity::Id::new()) };
+        self.inner.unerase(guard).required_properties().map(|name| name.to_string())
+    }
+
     /// Returns the names of all publicly declared callbacks.
     pub fn callbacks(&self) -> impl Iterator<Item = String> + '_ {
         // We create here a 'static guard, because unfortunately the returned type would be restricted to the guard lifetime
@@ -1296,6 +1527,244 @@ pub fn set_property(&self, name: &str, value: Value) -> Result<(), SetPropertyEr
         d.set_property(comp.borrow(), &name, value)
     }
 
+    /// Restore the `in` and `in-out` properties of this component to their initial value,
+    /// as if the instance was just created, without re-creating it.
+    ///
+    /// Properties declared with a binding get their binding back. Callback handlers and the
+    /// state of the elements that aren't exposed as public properties aren't affected.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # i_slint_backend_testing::init_no_event_loop();
+    /// use slint_interpreter::{Compiler, Value};
+    /// let code = r#"
+    ///     export component MyWin inherits Window {
+    ///         in-out property <int> counter: 42;
+    ///         in property <int> factor: 2;
+    ///         out property <int> double: counter * factor;
+    ///     }
+    /// "#;
+    /// let result = spin_on::spin_on(
+    ///     Compiler::default().build_from_source(code.into(), Default::default()));
+    /// assert_eq!(result.diagnostics().count(), 0, "{:?}", result.diagnostics().collect::<Vec<_>>());
+    /// let instance = result.component("MyWin").unwrap().create().unwrap();
+    /// instance.set_property("counter", Value::from(5)).unwrap();
+    /// assert_eq!(instance.get_property("double").unwrap(), Value::from(10));
+    /// instance.reset_properties();
+    /// assert_eq!(instance.get_property("counter").unwrap(), Value::from(42));
+    /// assert_eq!(instance.get_property("double").unwrap(), Value::from(84));
+    /// ```
+    pub fn reset_properties(&self) {
+        generativity::make_guard!(guard);
+        let comp = self.inner.unerase(guard);
+        comp.description()
+            .reset_public_properties(comp.borrow())
+            .expect("the instance was created from this description");
+    }
+
+    /// Returns the values of the `in` and `in-out` properties of this component as a JSON object,
+    /// to restore them later with [`Self::restore_state()`], for example after reloading the component.
+    ///
+    /// Structs, enums, and models are saved with their contents. The properties with values that
+    /// can't be serialized, such as images that weren't loaded from a file, are left out.
+    ///
+    /// *Note*: This function is behind the `internal-json` feature flag, which isn't semver compatible.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # i_slint_backend_testing::init_no_event_loop();
+    /// use slint_interpreter::{Compiler, Value};
+    /// let code = r#"
+    ///     export component MyWin inherits Window {
+    ///         in-out property <int> counter: 42;
+    ///     }
+    /// "#;
+    /// let result = spin_on::spin_on(
+    ///     Compiler::default().build_from_source(code.into(), Default::default()));
+    /// let definition = result.component("MyWin").unwrap();
+    /// let instance = definition.create().unwrap();
+    /// instance.set_property("counter", Value::from(5)).unwrap();
+    /// let state = instance.save_state();
+    /// assert_eq!(state, serde_json::json!({ "counter": 5 }));
+    ///
+    /// let reloaded = definition.create().unwrap();
+    /// reloaded.restore_state(&state).unwrap();
+    /// assert_eq!(reloaded.get_property("counter").unwrap(), Value::from(5));
+    /// ```
+    #[cfg(feature = "internal-json")]
+    pub fn save_state(&self) -> serde_json::Value {
+        generativity::make_guard!(guard);
+        let comp = self.inner.unerase(guard);
+        let description = comp.description();
+        let elem = description.original.root_element.borrow();
+        let state = elem
+            .property_declarations
+            .iter()
+            .filter(|(_, decl)| is_state_property(decl))
+            .filter_map(|(name, _)| {
+                let value = description.get_property(comp.borrow(), name).ok()?;
+                Some((name.to_string(), crate::json::value_to_json(&value).ok()?))
+            })
+            .collect();
+        serde_json::Value::Object(state)
+    }
+
+    /// Sets the `in` and `in-out` properties of this component to the values in `state`, which
+    /// was returned by [`Self::save_state()`].
+    ///
+    /// The component may have changed since the state was saved. The properties that don't exist
+    /// anymore, or whose values don't match their type anymore, are skipped, and the other properties
+    /// are restored. In that case, the returned error lists the skipped properties.
+    ///
+    /// *Note*: This function is behind the `internal-json` feature flag, which isn't semver compatible.
+    #[cfg(feature = "internal-json")]
+    pub fn restore_state(&self, state: &serde_json::Value) -> Result<(), String> {
+        let serde_json::Value::Object(state) = state else {
+            return Err("The state must be a JSON object".into());
+        };
+        generativity::make_guard!(guard);
+        let comp = self.inner.unerase(guard);
+        let description = comp.description();
+        let elem = description.original.root_element.borrow();
+        let mut errors = Vec::new();
+        for (name, value) in state {
+            let name = normalize_identifier(name);
+            let result = match elem.property_declarations.get(&name) {
+                Some(decl) if is_state_property(decl) => {
+                    crate::json::value_from_json(&decl.property_type, value).and_then(|value| {
+                        description
+                            .set_property(comp.borrow(), &name, value)
+                            .map_err(|err| err.to_string())
+                    })
+                }
+                _ => Err(SetPropertyError::NoSuchProperty.to_string()),
+            };
+            if let Err(err) = result {
+                errors.push(format!("{name} ({err})"));
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Could not restore the properties: {}", errors.join(", ")))
+        }
+    }
+
+    /// Sets properties of this component and of its exported global singletons from a JSON object,
+    /// for example to show the component with sample data.
+    ///
+    /// Each key of `data` names an `in` or `in-out` property of the component, or an exported
+    /// global singleton, whose value is an object with the values of the `in` and `in-out`
+    /// properties of the global. The properties of the component take precedence over globals
+    /// with the same name. Arrays are converted to models, and objects to structs.
+    ///
+    /// The keys that don't match a property, and the values that don't match the type of their
+    /// property, are skipped, and the other properties are set. In that case, the returned error
+    /// lists the skipped properties.
+    ///
+    /// *Note*: This function is behind the `internal-json` feature flag, which isn't semver compatible.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # i_slint_backend_testing::init_no_event_loop();
+    /// use slint_interpreter::{Compiler, Value, SharedString};
+    /// let code = r#"
+    ///     export global Settings {
+    ///         in property <string> user-name;
+    ///     }
+    ///     export component MyWin inherits Window {
+    ///         in property <[int]> values;
+    ///     }
+    /// "#;
+    /// let result = spin_on::spin_on(
+    ///     Compiler::default().build_from_source(code.into(), Default::default()));
+    /// let instance = result.component("MyWin").unwrap().create().unwrap();
+    /// instance.set_json_data(&serde_json::json!({
+    ///     "values": [1, 2, 3],
+    ///     "Settings": { "user-name": "Alice" },
+    /// })).unwrap();
+    /// assert_eq!(
+    ///     instance.get_global_property("Settings", "user-name").unwrap(),
+    ///     Value::from(SharedString::from("Alice"))
+    /// );
+    /// ```
+    #[cfg(feature = "internal-json")]
+    pub fn set_json_data(&self, data: &serde_json::Value) -> Result<(), String> {
+        let serde_json::Value::Object(data) = data else {
+            return Err("The data must be a JSON object".into());
+        };
+        generativity::make_guard!(guard);
+        let comp = self.inner.unerase(guard);
+        let description = comp.description();
+        let elem = description.original.root_element.borrow();
+        let mut errors = Vec::new();
+        for (name, value) in data {
+            let name = normalize_identifier(name);
+            if let Some(decl) = elem.property_declarations.get(&name) {
+                let result = if is_state_property(decl) {
+                    crate::json::value_from_json(&decl.property_type, value).and_then(|value| {
+                        description
+                            .set_property(comp.borrow(), &name, value)
+                            .map_err(|err| err.to_string())
+                    })
+                } else {
+                    Err(SetPropertyError::AccessDenied.to_string())
+                };
+                if let Err(err) = result {
+                    errors.push(format!("{name} ({err})"));
+                }
+                continue;
+            }
+
+            let Some(properties) = description.global_properties(&name) else {
+                errors.push(format!("{name} ({})", SetPropertyError::NoSuchProperty));
+                continue;
+            };
+            let serde_json::Value::Object(values) = value else {
+                errors.push(format!("{name} (the value of a global must be a JSON object)"));
+                continue;
+            };
+            let properties = properties
+                .filter(|(_, ty, visibility)| {
+                    ty.is_property_type()
+                        && matches!(
+                            visibility,
+                            i_slint_compiler::object_tree::PropertyVisibility::Input
+                                | i_slint_compiler::object_tree::PropertyVisibility::InOut
+                        )
+                })
+                .map(|(prop_name, ty, _)| (normalize_identifier(&prop_name), ty))
+                .collect::<HashMap<_, _>>();
+            let Ok(global) = description.get_global(comp.borrow(), &name) else {
+                errors.push(format!("{name} ({})", SetPropertyError::NoSuchProperty));
+                continue;
+            };
+            for (prop_name, value) in values {
+                let prop_name = normalize_identifier(prop_name);
+                let result = match properties.get(&prop_name) {
+                    Some(ty) => crate::json::value_from_json(ty, value).and_then(|value| {
+                        global
+                            .as_ref()
+                            .set_property(&prop_name, value)
+                            .map_err(|err| err.to_string())
+                    }),
+                    None => Err(SetPropertyError::NoSuchProperty.to_string()),
+                };
+                if let Err(err) = result {
+                    errors.push(format!("{name}.{prop_name} ({err})"));
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Could not set the properties: {}", errors.join(", ")))
+        }
+    }
+
     /// Set a handler for the callback with the given name. A callback with that
     /// name must be defined in the document otherwise an error will be returned.
     ///
@@ -1535,6 +2004,16 @@ pub fn element_node_at_source_code_position(
     }
 }
 
+/// Returns true for the properties that [`ComponentInstance::save_state()`] saves: the public
+/// `in` and `in-out` properties.
+#[cfg(feature = "internal-json")]
+fn is_state_property(decl: &i_slint_compiler::object_tree::PropertyDeclaration) -> bool {
+    use i_slint_compiler::object_tree::PropertyVisibility;
+    decl.expose_in_public_api
+        && decl.property_type.is_property_type()
+        && matches!(decl.visibility, PropertyVisibility::Input | PropertyVisibility::InOut)
+}
+
 impl ComponentHandle for ComponentInstance {
     type WeakInner = vtable::VWeak<ItemTreeVTable, crate::dynamic_item_tree::ErasedItemTreeBox>;
 
@@ -1579,6 +2058,100 @@ fn global<'a, T: Global<'a, Self>>(&'a self) -> T
     }
 }
 
+/// A pool of instances of a [`ComponentDefinition`], created with [`ComponentDefinition::create_pool`].
+///
+/// Creating an instance requires instantiating all its elements, which can take a noticeable
+/// time for large components. A pool creates the instances ahead of time, so that showing a
+/// screen only requires taking an instance from the pool. When the screen is no longer needed,
+/// the instance is put back into the pool with [`Self::release`], which hides it and restores
+/// its properties to their initial value.
+///
+/// ## Examples
+///
+/// ```
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use slint_interpreter::{Compiler, Value};
+/// let code = r#"
+///     export component Screen inherits Window {
+///         in-out property <string> title: "Welcome";
+///     }
+/// "#;
+/// let result = spin_on::spin_on(
+///     Compiler::default().build_from_source(code.into(), Default::default()));
+/// let pool = result.component("Screen").unwrap().create_pool(2).unwrap();
+/// assert_eq!(pool.available(), 2);
+///
+/// let screen = pool.acquire().unwrap();
+/// assert_eq!(pool.available(), 1);
+/// screen.set_property("title", Value::from(slint_interpreter::SharedString::from("Hello"))).unwrap();
+///
+/// pool.release(screen).unwrap();
+/// let screen = pool.acquire().unwrap();
+/// assert_eq!(screen.get_property("title").unwrap(), Value::from(slint_interpreter::SharedString::from("Welcome")));
+/// ```
+pub struct ComponentInstancePool {
+    definition: ComponentDefinition,
+    instances: core::cell::RefCell<Vec<ComponentInstance>>,
+}
+
+impl ComponentInstancePool {
+    /// Return the [`ComponentDefinition`] of the instances in this pool.
+    pub fn definition(&self) -> &ComponentDefinition {
+        &self.definition
+    }
+
+    /// Returns the number of instances that can be acquired without creating a new one.
+    pub fn available(&self) -> usize {
+        self.instances.borrow().len()
+    }
+
+    /// Create instances until at least `count` instances are available in the pool.
+    pub fn prewarm(&self, count: usize) -> Result<(), PlatformError> {
+        while self.available() < count {
+            let instance = self.definition.create()?;
+            self.instances.borrow_mut().push(instance);
+        }
+        Ok(())
+    }
+
+    /// Take an instance out of the pool, or create a new one if the pool is empty.
+    ///
+    /// The instance isn't shown. Call [`ComponentHandle::show`] to show it.
+    pub fn acquire(&self) -> Result<ComponentInstance, PlatformError> {
+        let instance = self.instances.borrow_mut().pop();
+        match instance {
+            Some(instance) => Ok(instance),
+            None => self.definition.create(),
+        }
+    }
+
+    /// Hide the instance, restore its properties with [`ComponentInstance::reset_properties`],
+    /// and put it back into the pool.
+    ///
+    /// Returns the instance back as an error if it wasn't created from the definition of this pool.
+    pub fn release(&self, instance: ComponentInstance) -> Result<(), ComponentInstance> {
+        if !instance.definition().inner.ptr_eq(&self.definition.inner) {
+            return Err(instance);
+        }
+        // The instance may not have a window, in which case there is nothing to hide
+        instance.hide().ok();
+        instance.reset_properties();
+        self.instances.borrow_mut().push(instance);
+        Ok(())
+    }
+
+    /// Drop the instances of the pool that exceed `count`.
+    pub fn shrink_to(&self, count: usize) {
+        let removed = {
+            let mut instances = self.instances.borrow_mut();
+            let count = count.min(instances.len());
+            instances.split_off(count)
+        };
+        // Dropping an instance may run code that accesses the pool
+        drop(removed);
+    }
+}
+
 impl From<ComponentInstance>
     for vtable::VRc<i_slint_core::item_tree::ItemTreeVTable, ErasedItemTreeBox>
 {
@@ -1963,6 +2536,47 @@ fn call_functions() {
     assert_eq!(instance.get_global_property("Gl", "q"), Ok(Value::String("Hello".into())));
 }
 
+#[test]
+fn sandbox() {
+    i_slint_backend_testing::init_no_event_loop();
+    let mut sandbox = SandboxConfiguration::default();
+    sandbox.allowed_paths.push("/themes".into());
+    sandbox.max_model_size = 10;
+    sandbox.max_element_instances = 100;
+    let compiler = Compiler::sandbox(sandbox);
+    let build = |source: &str| {
+        spin_on::spin_on(compiler.build_from_source(source.into(), "/themes/theme.slint".into()))
+    };
+
+    let result = build(r#"import { Foo } from "/etc/foo.slint"; export component Test {}"#);
+    assert!(result.diagnostics().any(|d| d.message().contains("not allowed in the sandbox")));
+
+    let result = build(r#"export component Test { Image { source: @image-url("/etc/a.png"); } }"#);
+    assert!(result.diagnostics().any(|d| d.message().contains("not allowed in the sandbox")));
+
+    let result =
+        build(r#"export component Test { for x in [1,2,3,4,5,6,7,8,9,10,11]: Rectangle {} }"#);
+    assert!(result.has_errors());
+
+    let result = build(
+        r#"export component Test { for x in 1000: Rectangle { for y in 1000: Rectangle {} } }"#,
+    );
+    assert!(result.diagnostics().any(|d| d.message().contains("elements")));
+
+    let result = build(
+        r#"
+    import { Button } from "std-widgets.slint";
+    export component Test {
+        in property <int> count: 1000;
+        HorizontalLayout {
+            for x in count: Rectangle {}
+        }
+        Button {}
+    }"#,
+    );
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+}
+
 #[test]
 fn component_definition_struct_properties() {
     i_slint_backend_testing::init_no_event_loop();
@@ -2070,6 +2684,188 @@ fn check_model(val: Value, r: &[f64]) {
     check_model(instance.get_property("prop").unwrap(), &[]);
 }
 
+#[test]
+fn component_definition_rust_model_properties() {
+    use i_slint_core::model::*;
+    i_slint_backend_testing::init_no_event_loop();
+    let code = r#"
+        export component Dummy {
+            in-out property <[int]> values;
+            public function bump() { values[0] = values[0] + 1; }
+        }"#;
+    let comp_def = spin_on::spin_on(Compiler::default().build_from_source(code.into(), "".into()))
+        .component("Dummy")
+        .unwrap();
+    let instance = comp_def.create().unwrap();
+
+    instance.set_property("values", vec![1, 2, 3].into()).unwrap();
+    let values: Vec<i32> = instance.get_property("values").unwrap().try_into().unwrap();
+    assert_eq!(values, vec![1, 2, 3]);
+    let strings: Result<Vec<SharedString>, _> = instance.get_property("values").unwrap().try_into();
+    assert!(strings.is_err());
+
+    // The changes made by the .slint code are visible in the Rust model
+    let model = Rc::new(VecModel::<i32>::from(vec![10, 20]));
+    instance.set_property("values", model.clone().into()).unwrap();
+    instance.invoke("bump", &[]).unwrap();
+    assert_eq!(model.row_data(0), Some(11));
+
+    let model: ModelRc<i32> = instance.get_property("values").unwrap().try_into().unwrap();
+    assert!(model.as_any().downcast_ref::<VecModel<i32>>().is_some());
+}
+
+#[cfg(feature = "internal-json")]
+#[test]
+fn component_instance_save_and_restore_state() {
+    i_slint_backend_testing::init_no_event_loop();
+    let code = r#"
+        export enum Mode { off, on }
+        export struct Entry { name: string, mode: Mode }
+        export component Dummy {
+            in-out property <int> count: 1;
+            in property <Mode> mode;
+            in-out property <[Entry]> entries;
+            out property <int> double: count * 2;
+            property <int> private-count: 3;
+        }"#;
+    let comp_def = spin_on::spin_on(Compiler::default().build_from_source(code.into(), "".into()))
+        .component("Dummy")
+        .unwrap();
+    let instance = comp_def.create().unwrap();
+
+    let entry: Struct = [
+        ("name".to_string(), Value::String("first".into())),
+        ("mode".to_string(), Value::EnumerationValue("Mode".into(), "on".into())),
+    ]
+    .into_iter()
+    .collect();
+    instance.set_property("count", Value::Number(5.)).unwrap();
+    instance.set_property("mode", Value::EnumerationValue("Mode".into(), "on".into())).unwrap();
+    instance.set_property("entries", vec![Value::Struct(entry)].into()).unwrap();
+
+    let state = instance.save_state();
+    assert_eq!(
+        state,
+        serde_json::json!({
+            "count": 5,
+            "mode": "Mode.on",
+            "entries": [{ "name": "first", "mode": "Mode.on" }],
+        })
+    );
+
+    let restored = comp_def.create().unwrap();
+    assert_eq!(restored.restore_state(&state), Ok(()));
+    assert_eq!(restored.save_state(), state);
+    assert_eq!(restored.get_property("double").unwrap(), Value::Number(10.));
+
+    // The properties that don't match the component anymore are skipped
+    let state =
+        serde_json::json!({ "count": "many", "double": 4, "removed": 1, "mode": "Mode.off" });
+    let err = restored.restore_state(&state).unwrap_err();
+    assert!(err.contains("count") && err.contains("double") && err.contains("removed"), "{err}");
+    assert_eq!(
+        restored.get_property("mode").unwrap(),
+        Value::EnumerationValue("Mode".into(), "off".into())
+    );
+    assert_eq!(restored.get_property("count").unwrap(), Value::Number(5.));
+}
+
+#[cfg(feature = "internal-json")]
+#[test]
+fn component_instance_set_json_data() {
+    i_slint_backend_testing::init_no_event_loop();
+    let code = r#"
+        export struct Entry { name: string, done: bool }
+        export global Settings {
+            in property <string> user_name;
+            in-out property <int> level;
+            out property <int> score;
+        }
+        export component Dummy {
+            in property <[Entry]> entries;
+            in property <Entry> current;
+            out property <int> count: entries.length;
+            out property <string> greeting: "Hello " + Settings.user-name;
+        }"#;
+    let instance = spin_on::spin_on(Compiler::default().build_from_source(code.into(), "".into()))
+        .component("Dummy")
+        .unwrap()
+        .create()
+        .unwrap();
+
+    let data = serde_json::json!({
+        "entries": [{ "name": "first", "done": true }, { "name": "second", "done": false }],
+        "current": { "name": "third", "done": false },
+        "Settings": { "user-name": "Alice", "level": 3 },
+    });
+    assert_eq!(instance.set_json_data(&data), Ok(()));
+    assert_eq!(instance.get_property("count").unwrap(), Value::Number(2.));
+    assert_eq!(instance.get_property("greeting").unwrap(), Value::String("Hello Alice".into()));
+    let Value::Struct(current) = instance.get_property("current").unwrap() else { panic!() };
+    assert_eq!(current.get_field("name"), Some(&Value::String("third".into())));
+    assert_eq!(instance.get_global_property("Settings", "level").unwrap(), Value::Number(3.));
+
+    // The keys that don't match are skipped, the others are set
+    let data = serde_json::json!({
+        "count": 4,
+        "missing": 1,
+        "Settings": { "score": 2, "level": "high", "user_name": "Bob" },
+    });
+    let err = instance.set_json_data(&data).unwrap_err();
+    assert!(
+        err.contains("count")
+            && err.contains("missing")
+            && err.contains("Settings.score")
+            && err.contains("Settings.level"),
+        "{err}"
+    );
+    assert_eq!(instance.get_property("greeting").unwrap(), Value::String("Hello Bob".into()));
+    assert_eq!(instance.get_global_property("Settings", "level").unwrap(), Value::Number(3.));
+}
+
+#[test]
+fn compiler_register_global() {
+    i_slint_backend_testing::init_no_event_loop();
+
+    struct Counter(Rc<core::cell::Cell<i32>>);
+    impl NativeGlobal for Counter {
+        fn declarations(&self) -> String {
+            "in property <int> step: 1; callback add-to-count(int) -> int;".into()
+        }
+        fn invoke(&self, name: &str, args: &[Value]) -> Value {
+            assert_eq!(name, "add-to-count");
+            let step: f64 = args[0].clone().try_into().unwrap();
+            self.0.set(self.0.get() + step as i32);
+            Value::Number(self.0.get() as f64)
+        }
+    }
+
+    let code = r#"
+        import { Counter } from "@native";
+        export component Dummy {
+            out property <int> count;
+            init => { count = Counter.add-to-count(Counter.step); }
+            public function add() { count = Counter.add_to_count(Counter.step); }
+        }"#;
+    let value = Rc::new(core::cell::Cell::new(10));
+    let mut compiler = Compiler::default();
+    compiler.register_global("Counter", Counter(value.clone()));
+    let result = spin_on::spin_on(compiler.build_from_source(code.into(), "".into()));
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    let instance = result.component("Dummy").unwrap().create().unwrap();
+
+    // The callbacks are set before the init callbacks run
+    assert_eq!(instance.get_property("count").unwrap(), Value::Number(11.));
+    instance.set_global_property("Counter", "step", Value::Number(5.)).unwrap();
+    instance.invoke("add", &[]).unwrap();
+    assert_eq!(instance.get_property("count").unwrap(), Value::Number(16.));
+    assert_eq!(value.get(), 16);
+
+    // Without registered globals, "@native" can't be imported
+    let result = spin_on::spin_on(Compiler::default().build_from_source(code.into(), "".into()));
+    assert!(result.has_errors());
+}
+
 #[test]
 fn lang_type_to_value_type() {
     use i_slint_compiler::langtype::Struct as LangStruct;
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use crate::api::{CompilationResult, ComponentDefinition, Value};
-use crate::global_component::CompiledGlobalCollection;
+use crate::global_component::{CompiledGlobalCollection, NativeGlobals};
 use crate::{dynamic_type, eval};
 use core::ptr::NonNull;
 use dynamic_type::{Instance, InstanceBox};
@@ -223,6 +223,15 @@ fn subtree_index(self: Pin<&Self>) -> usize {
         self.borrow().as_ref().subtree_index()
     }
 
+    fn item_layout_info(
+        self: Pin<&Self>,
+        item_index: u32,
+        orientation: Orientation,
+        result: &mut LayoutInfo,
+    ) -> bool {
+        self.borrow().as_ref().item_layout_info(item_index, orientation, result)
+    }
+
     fn item_geometry(self: Pin<&Self>, item_index: u32) -> i_slint_core::lengths::LogicalRect {
         self.borrow().as_ref().item_geometry(item_index)
     }
@@ -289,7 +298,7 @@ fn drop(&mut self) {
 pub(crate) struct ComponentExtraData {
     pub(crate) globals: OnceCell<crate::global_component::GlobalStorage>,
     pub(crate) self_weak: OnceCell<ErasedItemTreeBoxWeak>,
-    pub(crate) embedding_position: OnceCell<(ItemTreeWeak, u32)>,
+    pub(crate) embedding_position: std::cell::RefCell<Option<(ItemTreeWeak, u32)>>,
 }
 
 struct ErasedRepeaterWithinComponent<'id>(RepeaterWithinItemTree<'id, 'static>);
@@ -346,6 +355,11 @@ pub fn unerase<'a, 'id>(
             >(&self.0)
         }
     }
+
+    /// Returns true if both refer to the same ItemTreeDescription
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
 }
 impl<'id> From<Rc<ItemTreeDescription<'id>>> for ErasedItemTreeDescription {
     fn from(from: Rc<ItemTreeDescription<'id>>) -> Self {
@@ -490,6 +504,14 @@ pub fn properties(
         internal_properties_to_public(self.public_properties.iter())
     }
 
+    /// List the names of the public properties declared as `required`
+    pub fn required_properties(&self) -> impl Iterator<Item = SmolStr> + '_ {
+        self.public_properties
+            .iter()
+            .filter(|(_, v)| v.expose_in_public_api && v.required)
+            .map(|(name, _)| name.clone())
+    }
+
     /// List names of exported global singletons
     pub fn global_names(&self) -> impl Iterator<Item = SmolStr> + '_ {
         self.compiled_globals
@@ -595,6 +617,87 @@ pub fn set_binding(
         Ok(())
     }
 
+    /// Restore the public properties that can be set from outside (`in` and `in-out`)
+    /// to their initial value or binding, as when the instance was created.
+    ///
+    /// Properties that are linked with a two-way binding and that don't have an initial
+    /// expression are left untouched.
+    /// Returns an error if the instance does not corresponds to this ItemTreeDescription.
+    pub fn reset_public_properties(&self, component: ItemTreeRefPin) -> Result<(), ()> {
+        if !core::ptr::eq((&self.ct) as *const _, component.get_vtable() as *const _) {
+            return Err(());
+        }
+        generativity::make_guard!(guard);
+        // Safety: we just verified that the component has the right vtable
+        let c = unsafe { InstanceRef::from_pin_ref(component, guard) };
+        let self_weak = c.self_weak().get().unwrap().clone();
+
+        let root_element = &self.original.root_element;
+        for (name, decl) in &root_element.borrow().property_declarations {
+            if !decl.expose_in_public_api
+                || decl.visibility == object_tree::PropertyVisibility::Output
+                || !decl.property_type.is_property_type()
+            {
+                continue;
+            }
+            let (elem, prop_name) = match &decl.is_alias {
+                Some(alias) => (alias.element(), alias.name().clone()),
+                None => (root_element.clone(), name.clone()),
+            };
+
+            let binding = elem.borrow().bindings.get(&prop_name).map(|b| {
+                let b = b.borrow();
+                (
+                    b.expression.clone(),
+                    b.analysis.as_ref().is_some_and(|a| a.is_const),
+                    b.animation.clone(),
+                    !b.two_way_bindings.is_empty(),
+                )
+            });
+            let (expression, is_const, animation) = match binding {
+                Some((Expression::Invalid, _, _, true)) => continue,
+                Some((Expression::Invalid, _, _, false)) | None => {
+                    let value = eval::default_value_for_type(&decl.property_type);
+                    eval::store_property(c, &elem, &prop_name, value).map_err(|_| ())?;
+                    continue;
+                }
+                Some((expression, is_const, animation, _)) => (expression, is_const, animation),
+            };
+
+            if is_const {
+                let value = eval::eval_expression(
+                    &expression,
+                    &mut eval::EvalLocalContext::from_component_instance(c),
+                );
+                eval::store_property(c, &elem, &prop_name, value).map_err(|_| ())?;
+                continue;
+            }
+
+            let binding = Box::new(make_binding_eval_closure(expression, &self_weak));
+            let animation = animation_for_property(c, &animation);
+            if Rc::ptr_eq(&elem, root_element) {
+                let x = self.custom_properties.get(&prop_name).ok_or(())?;
+                unsafe {
+                    x.prop.set_binding(
+                        Pin::new_unchecked(&*c.as_ptr().add(x.offset)),
+                        binding,
+                        animation,
+                    )?
+                };
+            } else {
+                let item_info = &self.items[elem.borrow().id.as_str()];
+                let item = unsafe { item_info.item_from_item_tree(c.as_ptr()) };
+                item_info
+                    .rtti
+                    .properties
+                    .get(prop_name.as_str())
+                    .ok_or(())?
+                    .set_binding(item, binding, animation);
+            }
+        }
+        Ok(())
+    }
+
     /// Return the value of a property
     ///
     /// Returns an error if the component is not an instance corresponding to this ItemTreeDescription,
@@ -830,6 +933,7 @@ pub async fn load(
     source: String,
     path: std::path::PathBuf,
     mut compiler_config: CompilerConfiguration,
+    native_globals: &NativeGlobals,
 ) -> CompilationResult {
     // If the native style should be Qt, resolve it here as we know that we have it
     let is_native = match &compiler_config.style {
@@ -898,7 +1002,7 @@ pub async fn load(
 
     let doc = loader.get_document(&path).unwrap();
 
-    let compiled_globals = Rc::new(CompiledGlobalCollection::compile(doc));
+    let compiled_globals = Rc::new(CompiledGlobalCollection::compile(doc, native_globals));
     let mut components = HashMap::new();
 
     let popup_menu_description = if let Some(popup_menu_impl) = &doc.popup_menu_impl {
@@ -990,6 +1094,7 @@ fn generate_rtti() -> HashMap<&'static str, Rc<ItemRTTI>> {
             rtti_for::<TouchArea>(),
             rtti_for::<FocusScope>(),
             rtti_for::<SwipeGestureHandler>(),
+            rtti_for::<PinchGestureHandler>(),
             rtti_for::<Path>(),
             rtti_for::<Flickable>(),
             rtti_for::<WindowItem>(),
@@ -999,8 +1104,14 @@ fn generate_rtti() -> HashMap<&'static str, Rc<ItemRTTI>> {
             rtti_for::<Transform>(),
             rtti_for::<Opacity>(),
             rtti_for::<Layer>(),
+            rtti_for::<ShaderEffect>(),
+            rtti_for::<BackdropBlur>(),
+            rtti_for::<Mask>(),
+            rtti_for::<Blend>(),
+            rtti_for::<ZOrder>(),
             rtti_for::<DragArea>(),
             rtti_for::<DropArea>(),
+            rtti_for::<ScreenColorPicker>(),
             rtti_for::<ContextMenu>(),
             rtti_for::<MenuItem>(),
         ]
@@ -1345,6 +1456,7 @@ macro_rules! match_enum_type {
     let t = ItemTreeVTable {
         visit_children_item,
         layout_info,
+        item_layout_info,
         get_item_ref,
         get_item_tree,
         get_subtree_range,
@@ -2024,9 +2136,10 @@ extern "C" fn subtree_index(component: ItemTreeRefPin) -> usize {
             .extra_data_offset
             .apply(instance_ref.as_ref())
             .embedding_position
-            .get()
+            .borrow()
+            .clone()
         {
-            (parent_component.upgrade(), *parent_index)
+            (parent_component.upgrade(), parent_index)
         } else {
             (None, u32::MAX)
         }
@@ -2064,8 +2177,51 @@ extern "C" fn subtree_index(component: ItemTreeRefPin) -> usize {
         }
     }
 
+    // An item tree that is already shown can only be moved within its window
+    if let Some(window_adapter) =
+        instance_ref.description.window_adapter_offset.apply(instance_ref.as_ref()).get()
+    {
+        let mut parent_window_adapter = None;
+        if let Some(prc) = parent_component.upgrade() {
+            vtable::VRc::borrow_pin(&prc)
+                .as_ref()
+                .window_adapter(false, &mut parent_window_adapter);
+        }
+        if !parent_window_adapter.is_some_and(|w| Rc::ptr_eq(&w, window_adapter)) {
+            return false;
+        }
+    }
+
     let extra_data = instance_ref.description.extra_data_offset.apply(instance_ref.as_ref());
-    extra_data.embedding_position.set((parent_component.clone(), parent_item_tree_index)).is_ok()
+    extra_data.embedding_position.replace(Some((parent_component.clone(), parent_item_tree_index)));
+    true
+}
+
+#[cfg_attr(not(feature = "ffi"), i_slint_core_macros::remove_extern)]
+extern "C" fn item_layout_info(
+    component: ItemTreeRefPin,
+    item_index: u32,
+    orientation: Orientation,
+    result: &mut LayoutInfo,
+) -> bool {
+    generativity::make_guard!(guard);
+    let instance_ref = unsafe { InstanceRef::from_pin_ref(component, guard) };
+    let elem = instance_ref.description.original_elements[item_index as usize].borrow();
+    let nr = elem
+        .layout_info_prop(crate::eval_layout::from_runtime(orientation))
+        .filter(|_| elem.debug.iter().any(|d| d.layout.is_some()))
+        .cloned();
+    drop(elem);
+    match nr {
+        Some(nr) => {
+            *result = eval::load_property(instance_ref, &nr.element(), nr.name())
+                .unwrap()
+                .try_into()
+                .unwrap();
+            true
+        }
+        None => false,
+    }
 }
 
 #[cfg_attr(not(feature = "ffi"), i_slint_core_macros::remove_extern)]
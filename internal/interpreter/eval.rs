@@ -1001,6 +1001,42 @@ fn call_builtin_function(
                 panic!("First argument not a color");
             }
         }
+        BuiltinFunction::ColorHslaStruct => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to ColorHslaStruct")
+            }
+            if let Value::Brush(brush) = eval_expression(&arguments[0], local_context) {
+                let color = brush.color().to_hsl();
+                let values = IntoIterator::into_iter([
+                    ("hue".to_string(), Value::Number(color.hue.into())),
+                    ("saturation".to_string(), Value::Number(color.saturation.into())),
+                    ("lightness".to_string(), Value::Number(color.lightness.into())),
+                    ("alpha".to_string(), Value::Number(color.alpha.into())),
+                ])
+                .collect();
+                Value::Struct(values)
+            } else {
+                panic!("First argument not a color");
+            }
+        }
+        BuiltinFunction::ColorOklchStruct => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to ColorOklchStruct")
+            }
+            if let Value::Brush(brush) = eval_expression(&arguments[0], local_context) {
+                let color = brush.color().to_oklch();
+                let values = IntoIterator::into_iter([
+                    ("lightness".to_string(), Value::Number(color.lightness.into())),
+                    ("chroma".to_string(), Value::Number(color.chroma.into())),
+                    ("hue".to_string(), Value::Number(color.hue.into())),
+                    ("alpha".to_string(), Value::Number(color.alpha.into())),
+                ])
+                .collect();
+                Value::Struct(values)
+            } else {
+                panic!("First argument not a color");
+            }
+        }
         BuiltinFunction::ColorBrighter => {
             if arguments.len() != 2 {
                 panic!("internal error: incorrect argument count to ColorBrighter")
@@ -1073,6 +1109,21 @@ fn call_builtin_function(
 
             color_a.mix(&color_b, factor as _).into()
         }
+        BuiltinFunction::ColorMixIn => {
+            if arguments.len() != 4 {
+                panic!("internal error: incorrect argument count to ColorMixIn")
+            }
+            let Value::Brush(color_a) = eval_expression(&arguments[0], local_context) else {
+                panic!("First argument not a color");
+            };
+            let Value::Brush(color_b) = eval_expression(&arguments[1], local_context) else {
+                panic!("Second argument not a color");
+            };
+            let factor: f32 = eval_expression(&arguments[2], local_context).try_into().unwrap();
+            let color_space: corelib::items::ColorSpace =
+                eval_expression(&arguments[3], local_context).try_into().unwrap();
+            color_a.color().mix_in(&color_b.color(), factor, color_space).into()
+        }
         BuiltinFunction::ColorWithAlpha => {
             if arguments.len() != 2 {
                 panic!("internal error: incorrect argument count to ColorWithAlpha")
@@ -1136,6 +1187,22 @@ fn call_builtin_function(
             let a = (1. * a).clamp(0., 1.);
             Value::Brush(Brush::SolidColor(Color::from_hsva(h, s, v, a)))
         }
+        BuiltinFunction::Hsl => {
+            let h: f32 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let s: f32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let l: f32 = eval_expression(&arguments[2], local_context).try_into().unwrap();
+            let a: f32 = eval_expression(&arguments[3], local_context).try_into().unwrap();
+            let (s, l, a) = (s.clamp(0., 1.), l.clamp(0., 1.), a.clamp(0., 1.));
+            Value::Brush(Brush::SolidColor(Color::from_hsl(h, s, l, a)))
+        }
+        BuiltinFunction::Oklch => {
+            let l: f32 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let c: f32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let h: f32 = eval_expression(&arguments[2], local_context).try_into().unwrap();
+            let a: f32 = eval_expression(&arguments[3], local_context).try_into().unwrap();
+            let (l, c, a) = (l.clamp(0., 1.), c.max(0.), a.clamp(0., 1.));
+            Value::Brush(Brush::SolidColor(Color::from_oklch(l, c, h, a)))
+        }
         BuiltinFunction::ColorScheme => local_context
             .component_instance
             .window_adapter()
@@ -1235,6 +1302,105 @@ fn call_builtin_function(
                     .unwrap_or_default(),
             ))
         }
+        BuiltinFunction::FuzzyFilter => {
+            let Value::Model(model) = eval_expression(&arguments[0], local_context) else {
+                panic!("First argument not an array");
+            };
+            let pattern: SharedString =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            model.model_tracker().track_row_count_changes();
+            let texts = (0..model.row_count()).map(|row| {
+                SharedString::try_from(model.row_data_tracked(row).unwrap_or_default())
+                    .unwrap_or_default()
+            });
+            Value::Model(ModelRc::new(VecModel::from(
+                i_slint_core::fuzzy_match::fuzzy_filter(texts, &pattern)
+                    .into_iter()
+                    .map(|x| Value::Number(x as f64))
+                    .collect::<Vec<_>>(),
+            )))
+        }
+        BuiltinFunction::ColorToHex => {
+            let Value::Brush(brush) = eval_expression(&arguments[0], local_context) else {
+                panic!("First argument not a color");
+            };
+            Value::String(brush.color().to_hex_string())
+        }
+        BuiltinFunction::ParseColorHex => {
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let Value::Brush(fallback) = eval_expression(&arguments[1], local_context) else {
+                panic!("Second argument not a color");
+            };
+            Value::Brush(Brush::SolidColor(
+                Color::from_hex_str(s.as_str()).unwrap_or(fallback.color()),
+            ))
+        }
+        BuiltinFunction::TableCellsToTsv => {
+            let Value::Model(rows) = eval_expression(&arguments[0], local_context) else {
+                panic!("First argument not an array");
+            };
+            let start_row: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let start_column: i32 =
+                eval_expression(&arguments[2], local_context).try_into().unwrap();
+            let end_row: i32 = eval_expression(&arguments[3], local_context).try_into().unwrap();
+            let end_column: i32 = eval_expression(&arguments[4], local_context).try_into().unwrap();
+            let row_model = |row: &Option<Value>| match row {
+                Some(Value::Model(row)) => Some(row.clone()),
+                _ => None,
+            };
+            let (row_range, column_range) = i_slint_core::tsv::table_cell_range(
+                rows.row_count(),
+                |row| row_model(&rows.row_data_tracked(row)).map_or(0, |row| row.row_count()),
+                start_row,
+                start_column,
+                end_row,
+                end_column,
+            );
+            let cell_text = |row: &Option<ModelRc<Value>>, column: usize| -> SharedString {
+                let Some(row) = row else { return Default::default() };
+                match row.row_data(column) {
+                    Some(Value::Struct(cell)) => cell
+                        .get_field("text")
+                        .cloned()
+                        .unwrap_or_default()
+                        .try_into()
+                        .unwrap_or_default(),
+                    _ => Default::default(),
+                }
+            };
+            Value::String(i_slint_core::tsv::cells_to_tsv(row_range.map(|row| {
+                let row = row_model(&rows.row_data_tracked(row));
+                column_range.clone().map(|column| cell_text(&row, column)).collect::<Vec<_>>()
+            })))
+        }
+        BuiltinFunction::SetClipboardText => {
+            let text: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            local_context
+                .component_instance
+                .access_window(|window| window.set_clipboard_text(text.as_str()));
+            Value::Void
+        }
+        BuiltinFunction::OpenUrl => {
+            let url: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            local_context.component_instance.access_window(|window| window.open_url(url.as_str()));
+            Value::Void
+        }
+        BuiltinFunction::ProcessArguments => {
+            let args = i_slint_core::process::process_arguments();
+            Value::Model(ModelRc::new(VecModel::from_iter(args.iter().map(Value::String))))
+        }
+        BuiltinFunction::ProcessEnv => {
+            let name: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::String(i_slint_core::process::process_env(name.as_str()))
+        }
+        BuiltinFunction::ProcessExit => {
+            let code: i32 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            i_slint_core::process::process_exit(code);
+            Value::Void
+        }
         BuiltinFunction::TextInputFocused => Value::Bool(
             local_context.component_instance.access_window(|window| window.text_input_focused())
                 as _,
@@ -1247,6 +1413,32 @@ fn call_builtin_function(
             });
             Value::Void
         }
+        BuiltinFunction::SystemTimeOfDay => {
+            Value::Number(i_slint_core::system::system_time_of_day() as f64)
+        }
+        BuiltinFunction::SystemUnixTime => {
+            Value::Number(i_slint_core::system::system_unix_time() as f64)
+        }
+        BuiltinFunction::SystemUtcOffset => {
+            Value::Number(i_slint_core::system::system_utc_offset() as f64)
+        }
+        BuiltinFunction::SystemTimeZone => Value::String(i_slint_core::system::system_time_zone()),
+        BuiltinFunction::SystemTickInterval => {
+            Value::Number(i_slint_core::system::system_tick_interval() as f64)
+        }
+        BuiltinFunction::SetSystemTickInterval => {
+            let interval: f64 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            i_slint_core::system::set_system_tick_interval(interval as i64);
+            Value::Void
+        }
+        BuiltinFunction::SystemLocale => Value::String(i_slint_core::system::system_locale()),
+        BuiltinFunction::SystemNetworkOnline => {
+            Value::Bool(i_slint_core::system::system_network_online())
+        }
+        BuiltinFunction::SystemOnBattery => Value::Bool(i_slint_core::system::system_on_battery()),
+        BuiltinFunction::SystemBatteryLevel => {
+            Value::Number(i_slint_core::system::system_battery_level() as f64)
+        }
         BuiltinFunction::ImplicitLayoutInfo(orient) => {
             let component = local_context.component_instance;
             if let [Expression::ElementReference(item)] = arguments {
@@ -1300,6 +1492,34 @@ fn call_builtin_function(
                 panic!("internal error: argument to SetFocusItem must be an element")
             }
         }
+        BuiltinFunction::ItemVisibleInViewport => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to ItemVisibleInViewport")
+            }
+
+            let component = local_context.component_instance;
+
+            if let Expression::ElementReference(item) = &arguments[0] {
+                generativity::make_guard!(guard);
+
+                let item = item.upgrade().unwrap();
+                let enclosing_component = enclosing_component_for_element(&item, component, guard);
+                let description = enclosing_component.description;
+
+                let item_info = &description.items[item.borrow().id.as_str()];
+
+                let item_comp = enclosing_component.self_weak().get().unwrap().upgrade().unwrap();
+
+                let item_rc = corelib::items::ItemRc::new(
+                    vtable::VRc::into_dyn(item_comp),
+                    item_info.item_index(),
+                );
+
+                Value::Bool(item_rc.is_visible_in_viewport())
+            } else {
+                panic!("internal error: argument to ItemVisibleInViewport must be an element")
+            }
+        }
         BuiltinFunction::RegisterCustomFontByPath => {
             if arguments.len() != 1 {
                 panic!("internal error: incorrect argument count to RegisterCustomFontByPath")
@@ -1402,6 +1622,11 @@ fn call_item_member_function(nr: &NamedReference, local_context: &mut EvalLocalC
             "cancel" => s.cancel(&window_adapter, &item_rc),
             _ => panic!("internal: Unknown member function {name} called on SwipeGestureHandler"),
         }
+    } else if let Some(s) = ItemRef::downcast_pin::<corelib::items::ScreenColorPicker>(item_ref) {
+        match name {
+            "pick" => s.pick(&window_adapter, &item_rc),
+            _ => panic!("internal: Unknown member function {name} called on ScreenColorPicker"),
+        }
     } else if let Some(s) = ItemRef::downcast_pin::<corelib::items::ContextMenu>(item_ref) {
         match name {
             "close" => s.close(&window_adapter, &item_rc),
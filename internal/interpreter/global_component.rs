@@ -1,14 +1,15 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use crate::api::Value;
+use crate::api::{NativeGlobal, Value};
 use crate::dynamic_item_tree::{
     ErasedItemTreeBox, ErasedItemTreeDescription, PopupMenuDescription,
 };
 use crate::SetPropertyError;
 use core::cell::RefCell;
 use core::pin::Pin;
-use i_slint_compiler::langtype::ElementType;
+use i_slint_compiler::diagnostics::Spanned;
+use i_slint_compiler::langtype::{ElementType, Type};
 use i_slint_compiler::namedreference::NamedReference;
 use i_slint_compiler::object_tree::{Component, Document, PropertyDeclaration};
 use i_slint_core::item_tree::ItemTreeVTable;
@@ -17,6 +18,22 @@
 use std::collections::{BTreeMap, HashMap};
 use std::rc::Rc;
 
+/// The path of the virtual file that declares the globals registered with
+/// [`Compiler::register_global()`](crate::Compiler::register_global), imported as `"@native"`.
+pub const NATIVE_GLOBALS_PATH: &str = "native:/globals.slint";
+
+/// The globals registered with [`Compiler::register_global()`](crate::Compiler::register_global),
+/// by name
+pub type NativeGlobals = BTreeMap<SmolStr, Rc<dyn NativeGlobal>>;
+
+/// Returns the source code of the virtual file that declares the native globals
+pub fn native_globals_source(native_globals: &NativeGlobals) -> String {
+    native_globals
+        .iter()
+        .map(|(name, global)| format!("export global {name} {{\n{}\n}}\n", global.declarations()))
+        .collect()
+}
+
 pub struct CompiledGlobalCollection {
     /// compiled globals
     pub compiled_globals: Vec<CompiledGlobal>,
@@ -26,7 +43,7 @@ pub struct CompiledGlobalCollection {
 }
 
 impl CompiledGlobalCollection {
-    pub fn compile(doc: &Document) -> Self {
+    pub fn compile(doc: &Document, native_globals: &NativeGlobals) -> Self {
         let mut exported_globals_by_name = BTreeMap::new();
         let compiled_globals = doc
             .used_types
@@ -35,7 +52,7 @@ pub fn compile(doc: &Document) -> Self {
             .iter()
             .enumerate()
             .map(|(index, component)| {
-                let mut global = generate(component);
+                let mut global = generate(component, native_globals);
 
                 if !component.exported_global_names.borrow().is_empty() {
                     global.extend_public_properties(
@@ -92,6 +109,8 @@ pub enum CompiledGlobal {
     Component {
         component: ErasedItemTreeDescription,
         public_properties: BTreeMap<SmolStr, PropertyDeclaration>,
+        /// The implementation of the callbacks, for the globals declared in [`NATIVE_GLOBALS_PATH`]
+        native: Option<Rc<dyn NativeGlobal>>,
     },
 }
 
@@ -195,7 +214,7 @@ fn instantiate(name: &str) -> Pin<Rc<dyn GlobalComponent>> {
                 element.native_class.class_name.as_ref(),
             )
         }
-        CompiledGlobal::Component { component, .. } => {
+        CompiledGlobal::Component { component, native, .. } => {
             generativity::make_guard!(guard);
             let description = component.unerase(guard);
             let inst = crate::dynamic_item_tree::instantiate(
@@ -205,6 +224,20 @@ fn instantiate(name: &str) -> Pin<Rc<dyn GlobalComponent>> {
                 None,
                 GlobalStorage::Weak(Rc::downgrade(globals)),
             );
+            if let Some(native) = native {
+                // Before the setup code, so that the `init` callbacks can call the native global
+                generativity::make_guard!(guard);
+                let comp = inst.unerase(guard);
+                let root_element = comp.description().original.root_element.clone();
+                for (name, decl) in &root_element.borrow().property_declarations {
+                    if !matches!(decl.property_type, Type::Callback { .. }) {
+                        continue;
+                    }
+                    let (native, callback) = (native.clone(), name.clone());
+                    let handler = Box::new(move |args: &[Value]| native.invoke(&callback, args));
+                    comp.description().set_callback_handler(comp.borrow(), &name, handler).ok();
+                }
+            }
             inst.run_setup_code();
             Rc::pin(GlobalComponentInstance(inst))
         }
@@ -338,10 +371,15 @@ fn eval_function(self: Pin<&Self>, _fn_name: &str, _args: Vec<Value>) -> Result<
     }
 }
 
-fn generate(component: &Rc<Component>) -> CompiledGlobal {
+fn generate(component: &Rc<Component>, native_globals: &NativeGlobals) -> CompiledGlobal {
     debug_assert!(component.is_global());
     match &component.root_element.borrow().base_type {
         ElementType::Global => {
+            let is_native = component
+                .node
+                .as_ref()
+                .and_then(|node| node.source_file())
+                .is_some_and(|file| file.path() == std::path::Path::new(NATIVE_GLOBALS_PATH));
             generativity::make_guard!(guard);
             CompiledGlobal::Component {
                 component: crate::dynamic_item_tree::generate_item_tree(
@@ -353,6 +391,9 @@ fn generate(component: &Rc<Component>) -> CompiledGlobal {
                 )
                 .into(),
                 public_properties: Default::default(),
+                native: is_native
+                    .then(|| native_globals.get(component.id.as_str()).cloned())
+                    .flatten(),
             }
         }
         ElementType::Builtin(b) => CompiledGlobal::Builtin {
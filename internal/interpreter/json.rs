@@ -87,26 +87,29 @@ fn string_to_color(s: &str) -> Option<i_slint_core::Color> {
             },
             langtype::Type::Brush => {
                 fn string_to_brush(input: &str) -> Result<i_slint_core::graphics::Brush, String> {
+                    /// Parses the stops, whose position is given in `unit`, `scale` of which is the full gradient
                     fn parse_stops<'a>(
                         it: impl Iterator<Item = &'a str>,
+                        unit: &str,
+                        scale: f32,
                     ) -> Result<Vec<i_slint_core::graphics::GradientStop>, String>
                     {
                         it.filter(|part| !part.is_empty()).map(|part| {
                             let sub_parts = part.split_whitespace().collect::<Vec<_>>();
                             if sub_parts.len() != 2 {
-                                Err("A gradient stop must consist of a color and a position in '%' separated by whitespace".into())
+                                Err(format!("A gradient stop must consist of a color and a position in '{unit}' separated by whitespace"))
                             } else {
                                 let color = string_to_color(sub_parts[0]);
                                 let position = {
-                                    if let Some(percent_value) = sub_parts[1].strip_suffix("%") {
-                                        percent_value.parse::<f32>().map_err(|_| format!("Could not parse position '{}' as number", sub_parts[1]))
+                                    if let Some(value) = sub_parts[1].strip_suffix(unit) {
+                                        value.parse::<f32>().map_err(|_| format!("Could not parse position '{}' as number", sub_parts[1]))
                                     } else {
-                                        Err(format!("The position '{}' does not end in '%'", sub_parts[1]))
+                                        Err(format!("The position '{}' does not end in '{unit}'", sub_parts[1]))
                                     }
                                 };
 
                                 match (color, position) {
-                                    (Some(c), Ok(p)) => Ok(i_slint_core::graphics::GradientStop { color: c, position: p / 100.0}),
+                                    (Some(c), Ok(p)) => Ok(i_slint_core::graphics::GradientStop { color: c, position: p / scale}),
                                     (_, Err(e)) => Err(e),
                                     (None, _) => Err(format!("'{}' is not a color", sub_parts[0])),
                                 }
@@ -142,16 +145,23 @@ fn parse_stops<'a>(
 
                         Ok(i_slint_core::graphics::LinearGradientBrush::new(
                             angle,
-                            parse_stops(split)?.drain(..),
+                            parse_stops(split, "%", 100.0)?.drain(..),
                         )
                         .into())
                     } else if let Some(radial) = input.strip_prefix("@radial-gradient(circle") {
                         let split = radial.split(',').map(|p| p.trim());
 
                         Ok(i_slint_core::graphics::RadialGradientBrush::new_circle(
-                            parse_stops(split)?.drain(..),
+                            parse_stops(split, "%", 100.0)?.drain(..),
                         )
                         .into())
+                    } else if let Some(conic) = input.strip_prefix("@conic-gradient(") {
+                        let split = conic.split(',').map(|p| p.trim());
+
+                        Ok(i_slint_core::graphics::ConicGradientBrush::new(parse_stops(
+                            split, "deg", 360.0,
+                        )?)
+                        .into())
                     } else {
                         Err(format!("Could not parse gradient from '{input}'"))
                     }
@@ -234,6 +244,23 @@ fn gradient_to_string_helper<'a>(
         serde_json::Value::String(gradient)
     }
 
+    fn conic_gradient_to_string<'a>(
+        stops: impl Iterator<Item = &'a i_slint_core::graphics::GradientStop>,
+    ) -> serde_json::Value {
+        let mut gradient = String::from("@conic-gradient(");
+
+        for (i, stop) in stops.enumerate() {
+            if i > 0 {
+                gradient += ", ";
+            }
+            gradient += &format!("{} {}deg", color_to_string(&stop.color), stop.position * 360.0);
+        }
+
+        gradient += ")";
+
+        serde_json::Value::String(gradient)
+    }
+
     match value {
         Value::Void => Ok(serde_json::Value::Null),
         Value::Bool(b) => Ok((*b).into()),
@@ -279,6 +306,7 @@ fn gradient_to_string_helper<'a>(
             Brush::RadialGradient(rg) => {
                 Ok(gradient_to_string_helper("@radial-gradient(circle".into(), rg.stops()))
             }
+            Brush::ConicGradient(cg) => Ok(conic_gradient_to_string(cg.stops())),
             _ => Err("Cannot serialize an unknown brush type".into()),
         },
         Value::PathData(_) => Err("Cannot serialize path data".into()),
@@ -429,6 +457,34 @@ fn test_from_json() {
     )
     .is_err());
 
+    let v = value_from_json_str(
+        &langtype::Type::Brush,
+        "\"@conic-gradient(#ff0000ff 0deg, #00ff00ff 90deg, #0000ffff 360deg)\"",
+    )
+    .unwrap();
+    assert_eq!(
+        v,
+        Value::Brush(Brush::ConicGradient(i_slint_core::graphics::ConicGradientBrush::new([
+            i_slint_core::graphics::GradientStop {
+                position: 0.0,
+                color: Color::from_argb_u8(0xff, 0xff, 0x00, 0x00)
+            },
+            i_slint_core::graphics::GradientStop {
+                position: 0.25,
+                color: Color::from_argb_u8(0xff, 0x00, 0xff, 0x00)
+            },
+            i_slint_core::graphics::GradientStop {
+                position: 1.0,
+                color: Color::from_argb_u8(0xff, 0x00, 0x00, 0xff)
+            }
+        ])))
+    );
+    assert!(value_from_json_str(
+        &langtype::Type::Brush,
+        "\"@conic-gradient(#ff0000ff 0%, #0000ffff 100%)\""
+    )
+    .is_err());
+
     assert!(value_from_json_str(
         &langtype::Type::Brush,
         "\"@other-gradient(circle, #ff0000ff 0%, #00ff00ff 50%, #0000ffff 100%)\""
@@ -542,4 +598,23 @@ fn test_to_json() {
     )))
     .unwrap();
     assert_eq!(&v, "\"@radial-gradient(circle, #ff0000 0%, #00ff00 50%, #0000ff 100%)\"");
+
+    let v = value_to_json_string(&Value::Brush(Brush::ConicGradient(
+        i_slint_core::graphics::ConicGradientBrush::new([
+            i_slint_core::graphics::GradientStop {
+                position: 0.0,
+                color: Color::from_argb_u8(0xff, 0xff, 0x00, 0x00),
+            },
+            i_slint_core::graphics::GradientStop {
+                position: 0.5,
+                color: Color::from_argb_u8(0xff, 0x00, 0xff, 0x00),
+            },
+            i_slint_core::graphics::GradientStop {
+                position: 1.0,
+                color: Color::from_argb_u8(0xff, 0x00, 0x00, 0xff),
+            },
+        ]),
+    )))
+    .unwrap();
+    assert_eq!(&v, "\"@conic-gradient(#ff0000 0deg, #00ff00 180deg, #0000ff 360deg)\"");
 }
@@ -44,3 +44,121 @@ fn reuse_window() {
         instance
     };
 }
+
+#[test]
+fn reset_properties_and_pool() {
+    i_slint_backend_testing::init_no_event_loop();
+    use crate::{Compiler, SharedString, Value};
+    let code = r#"
+        export component Screen inherits Window {
+            in-out property <string> name <=> input.text;
+            in property <int> count;
+            in-out property <int> twice: count * 2;
+            out property <int> total: count + twice;
+            input := TextInput { text: "initial"; }
+        }
+        export component Other inherits Window {}
+    "#;
+    let result =
+        spin_on::spin_on(Compiler::default().build_from_source(code.into(), Default::default()));
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    let definition = result.component("Screen").unwrap();
+    let pool = definition.create_pool(1).unwrap();
+    assert_eq!(pool.available(), 1);
+
+    let instance = pool.acquire().unwrap();
+    assert_eq!(pool.available(), 0);
+    instance.set_property("name", Value::from(SharedString::from("changed"))).unwrap();
+    instance.set_property("count", Value::from(3)).unwrap();
+    instance.set_property("twice", Value::from(100)).unwrap();
+    assert_eq!(instance.get_property("total").unwrap(), Value::from(103));
+
+    assert!(pool.release(instance).is_ok());
+    assert_eq!(pool.available(), 1);
+    let instance = pool.acquire().unwrap();
+    assert_eq!(instance.get_property("name").unwrap(), Value::from(SharedString::from("initial")));
+    assert_eq!(instance.get_property("count").unwrap(), Value::from(0));
+    assert_eq!(instance.get_property("total").unwrap(), Value::from(0));
+    // The binding is restored
+    instance.set_property("count", Value::from(4)).unwrap();
+    assert_eq!(instance.get_property("twice").unwrap(), Value::from(8));
+
+    let other = result.component("Other").unwrap().create().unwrap();
+    assert!(pool.release(other).is_err());
+    pool.prewarm(3).unwrap();
+    assert_eq!(pool.available(), 3);
+    pool.shrink_to(1);
+    assert_eq!(pool.available(), 1);
+}
+
+#[test]
+fn layout_constraint_warnings() {
+    i_slint_backend_testing::init_no_event_loop();
+    use crate::{Compiler, ComponentHandle, Value};
+    let code = r#"
+        export component Main inherits Window {
+            in property <length> minimum: 200px;
+            min-width: minimum;
+            max-width: 100px;
+        }
+    "#;
+    let result =
+        spin_on::spin_on(Compiler::default().build_from_source(code.into(), "main.slint".into()));
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    let instance = result.component("Main").unwrap().create().unwrap();
+    let window = i_slint_core::window::WindowInner::from_pub(instance.window());
+    let item_tree = window.component();
+
+    let warnings = window.check_layout_constraints(&item_tree);
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("The layout constraints of Window Main::root"), "{}", warnings[0]);
+    assert!(warnings[0].contains("(main.slint:2:"), "{}", warnings[0]);
+    assert!(warnings[0].contains("minimum width (200) is larger than the maximum width (100)"));
+    // Only reported once
+    assert!(window.check_layout_constraints(&item_tree).is_empty());
+
+    instance.set_property("minimum", Value::Number(50.)).unwrap();
+    assert!(window.check_layout_constraints(&item_tree).is_empty());
+    instance.set_property("minimum", Value::Number(150.)).unwrap();
+    assert_eq!(window.check_layout_constraints(&item_tree).len(), 1);
+}
+
+#[test]
+fn scene_elements() {
+    i_slint_backend_testing::init_no_event_loop();
+    use crate::{Compiler, ComponentHandle, LogicalPosition, LogicalSize};
+    let code = r#"
+        export component Main inherits Window {
+            width: 100px;
+            height: 100px;
+            header := Rectangle {
+                background: red;
+                x: 10px;
+                y: 20px;
+                width: 30px;
+                height: 40px;
+                label := Text { x: 5px; y: 5px; width: 10px; height: 10px; }
+            }
+            Rectangle { x: 200px; width: 10px; height: 10px; background: blue; }
+        }
+    "#;
+    let result =
+        spin_on::spin_on(Compiler::default().build_from_source(code.into(), Default::default()));
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    let instance = result.component("Main").unwrap().create().unwrap();
+
+    let elements = instance.scene_elements().collect::<Vec<_>>();
+    assert_eq!(elements[0].depth(), 0);
+    assert_eq!(elements[0].size(), LogicalSize::new(100., 100.));
+
+    let label = elements.iter().find(|e| e.id().as_deref() == Some("Main::label")).unwrap();
+    assert_eq!(label.type_name().as_deref(), Some("Text"));
+    assert_eq!(label.depth(), 2);
+    assert_eq!(label.absolute_position(), LogicalPosition::new(15., 25.));
+    assert_eq!(label.size(), LogicalSize::new(10., 10.));
+    assert!(label.is_visible());
+
+    let header = elements.iter().find(|e| e.id().as_deref() == Some("Main::header")).unwrap();
+    assert_eq!(header.depth(), 1);
+    assert_eq!(header.absolute_position(), LogicalPosition::new(10., 20.));
+}
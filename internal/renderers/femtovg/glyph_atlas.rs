@@ -0,0 +1,79 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use std::collections::HashMap;
+
+/// Identifies a glyph rasterized in FemtoVG's glyph atlas: the font, the bits of the font size,
+/// and the glyph id.
+type GlyphKey = (femtovg::FontId, u32, u16);
+
+/// Estimates the GPU memory used by FemtoVG's glyph atlas, which grows with every glyph that is
+/// rendered in a new font or size. The atlas can't shrink, so when it exceeds the budget, the
+/// renderer replaces the canvas and only the glyphs drawn after that are rasterized again.
+#[derive(Default)]
+pub struct GlyphAtlasUsage {
+    budget: Option<usize>,
+    /// The frame in which each glyph was last drawn
+    glyphs: HashMap<GlyphKey, u64>,
+    bytes: usize,
+    frame: u64,
+}
+
+impl GlyphAtlasUsage {
+    pub fn set_budget(&mut self, budget: Option<usize>) {
+        self.budget = budget;
+        if budget.is_none() {
+            self.glyphs.clear();
+            self.bytes = 0;
+        }
+    }
+
+    pub fn begin_frame(&mut self) {
+        self.frame += 1;
+    }
+
+    pub fn record(&mut self, font_id: femtovg::FontId, font_size: f32, glyph_id: u16) {
+        if self.budget.is_none() {
+            return;
+        }
+        let frame = self.frame;
+        let last_used =
+            self.glyphs.entry((font_id, font_size.to_bits(), glyph_id)).or_insert_with(|| {
+                self.bytes += glyph_bytes(font_size);
+                frame
+            });
+        *last_used = frame;
+    }
+
+    /// Returns the estimated size of the atlas and the fraction of the budget it uses. The glyphs
+    /// are only tracked when there is a budget, so this returns `None` otherwise.
+    pub fn statistics(&self) -> Option<(usize, f32)> {
+        let budget = self.budget?;
+        Some((self.bytes, if budget > 0 { self.bytes as f32 / budget as f32 } else { 1. }))
+    }
+
+    /// Returns true if the atlas exceeds the budget and some of its glyphs were not drawn in the
+    /// current frame. These are forgotten, as the caller is expected to rebuild the atlas.
+    pub fn evict_if_over_budget(&mut self) -> bool {
+        let Some(budget) = self.budget else { return false };
+        if self.bytes <= budget {
+            return false;
+        }
+        let frame = self.frame;
+        let count = self.glyphs.len();
+        self.glyphs.retain(|_, last_used| *last_used == frame);
+        if self.glyphs.len() == count {
+            // Everything is in use, rebuilding the atlas wouldn't free anything.
+            return false;
+        }
+        self.bytes =
+            self.glyphs.keys().map(|(_, size, _)| glyph_bytes(f32::from_bits(*size))).sum();
+        true
+    }
+}
+
+/// The glyphs are rasterized in a single channel texture, with a pixel of padding on each side.
+fn glyph_bytes(font_size: f32) -> usize {
+    let side = font_size.ceil().max(0.) as usize + 2;
+    side * side
+}
@@ -208,7 +208,11 @@ pub fn new_from_image(
                 return None;
             }
             _ => {
-                let buffer = image.render_to_buffer(target_size_for_scalable_source)?;
+                let mut buffer = image.render_to_buffer(target_size_for_scalable_source)?;
+                if let SharedImageBuffer::RGB565(pixels) = &buffer {
+                    // femtovg doesn't support 16 bit textures, convert once to RGB8
+                    buffer = SharedImageBuffer::RGB8(pixels.convert_pixels(Into::into));
+                }
                 let (image_source, flags) = image_buffer_to_image_source(&buffer);
                 canvas.borrow_mut().create_image(image_source, image_flags | flags).unwrap()
             }
@@ -297,6 +301,15 @@ pub(crate) fn drain(&mut self) {
     pub(crate) fn clear(&mut self) {
         self.0.clear();
     }
+
+    /// Estimates the GPU memory used by the cached textures, assuming four bytes per pixel.
+    pub(crate) fn texture_memory_bytes(&self) -> usize {
+        self.0
+            .values()
+            .filter_map(|texture| texture.size())
+            .map(|size| size.area() as usize * 4)
+            .sum()
+    }
 }
 
 fn image_buffer_to_image_source(
@@ -324,6 +337,14 @@ fn image_buffer_to_image_source(
             },
             femtovg::ImageFlags::PREMULTIPLIED,
         ),
+        SharedImageBuffer::Gray8(buffer) => (
+            {
+                imgref::ImgRef::new(buffer.as_slice(), buffer.width() as _, buffer.height() as _)
+                    .into()
+            },
+            femtovg::ImageFlags::empty(),
+        ),
+        SharedImageBuffer::RGB565(_) => unreachable!("RGB565 buffers are converted to RGB8 first"),
     }
 }
 
@@ -16,7 +16,8 @@
     RenderRectangle, RenderText,
 };
 use i_slint_core::items::{
-    self, Clip, FillRule, ImageRendering, ImageTiling, ItemRc, Layer, Opacity, RenderingResult,
+    self, BackdropBlur, Blend, Clip, FillRule, ImageRendering, ImageTiling, ItemRc, Layer, Mask,
+    MixBlendMode, Opacity, RenderingResult,
 };
 use i_slint_core::lengths::{
     LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector,
@@ -84,6 +85,7 @@ struct State {
 pub struct GLItemRenderer<'a, R: femtovg::Renderer + TextureImporter> {
     graphics_cache: &'a ItemGraphicsCache<R>,
     texture_cache: &'a RefCell<super::images::TextureCache<R>>,
+    glyph_atlas_usage: &'a RefCell<super::glyph_atlas::GlyphAtlasUsage>,
     box_shadow_cache: FemtovgBoxShadowCache<R>,
     canvas: CanvasRc<R>,
     // Textures from layering or tiling that were scheduled for rendering where we can't delete the femtovg::ImageId yet
@@ -130,6 +132,41 @@ fn rect_with_radius_to_path(
     path
 }
 
+/// Converts a path to be stroked, such as the dashes of a dashed stroke, to physical pixels
+fn stroke_path_to_femtovg(path: &lyon_path::Path, scale_factor: f32) -> femtovg::Path {
+    let mut femtovg_path = femtovg::Path::new();
+    for event in path.iter() {
+        match event {
+            lyon_path::Event::Begin { at } => {
+                femtovg_path.move_to(at.x * scale_factor, at.y * scale_factor)
+            }
+            lyon_path::Event::Line { to, .. } => {
+                femtovg_path.line_to(to.x * scale_factor, to.y * scale_factor)
+            }
+            lyon_path::Event::Quadratic { ctrl, to, .. } => femtovg_path.quad_to(
+                ctrl.x * scale_factor,
+                ctrl.y * scale_factor,
+                to.x * scale_factor,
+                to.y * scale_factor,
+            ),
+            lyon_path::Event::Cubic { ctrl1, ctrl2, to, .. } => femtovg_path.bezier_to(
+                ctrl1.x * scale_factor,
+                ctrl1.y * scale_factor,
+                ctrl2.x * scale_factor,
+                ctrl2.y * scale_factor,
+                to.x * scale_factor,
+                to.y * scale_factor,
+            ),
+            lyon_path::Event::End { close, .. } => {
+                if close {
+                    femtovg_path.close()
+                }
+            }
+        }
+    }
+    femtovg_path
+}
+
 fn rect_to_path(r: PhysicalRect) -> femtovg::Path {
     rect_with_radius_to_path(r, PhysicalBorderRadius::default())
 }
@@ -248,6 +285,26 @@ fn draw_border_rectangle(
             rect.border_width() * self.scale_factor
         };
 
+        if i_slint_core::item_rendering::pixel_snapping_enabled(
+            rect.pixel_snapping(),
+            self.window(),
+            self.scale_factor.get(),
+        ) {
+            let transform = self.canvas.borrow().transform();
+            let (x, y) = transform.transform_point(0., 0.);
+            // Only a translation keeps the edges parallel to the device pixels
+            if transform.transform_point(1., 1.) == (x + 1., y + 1.) {
+                let (snapped_geometry, snapped_border_width) =
+                    i_slint_core::item_rendering::snap_to_device_pixels(
+                        geometry,
+                        border_width.get(),
+                        euclid::vec2(x, y),
+                    );
+                geometry = snapped_geometry;
+                border_width = PhysicalLength::new(snapped_border_width);
+            }
+        }
+
         // Radius of rounded rect if we were to just fill the rectangle, without a border.
         let mut fill_radius = rect.border_radius() * self.scale_factor;
 
@@ -461,17 +518,30 @@ fn add_point(&mut self, p: Point) {
                 items::LineCap::Round => femtovg::LineCap::Round,
                 items::LineCap::Square => femtovg::LineCap::Square,
             });
+            paint.set_line_join(match path.stroke_line_join() {
+                items::LineJoin::Miter => femtovg::LineJoin::Miter,
+                items::LineJoin::Round => femtovg::LineJoin::Round,
+                items::LineJoin::Bevel => femtovg::LineJoin::Bevel,
+            });
             paint.set_anti_alias(anti_alias);
             paint
         });
 
+        // femtovg can't stroke dashed lines, so stroke the dashes instead
+        let dashes = border_paint.as_ref().and(path.stroke_dash_pattern()).map(|(dash, gap)| {
+            stroke_path_to_femtovg(
+                &path_events.dashed(dash.get() as f32, gap.get() as f32),
+                self.scale_factor.get(),
+            )
+        });
+
         self.canvas.borrow_mut().save_with(|canvas| {
             canvas.translate(offset.x, offset.y);
             if let Some(fill_paint) = &fill_paint {
                 canvas.fill_path(&femtovg_path, fill_paint);
             }
             if let Some(border_paint) = &border_paint {
-                canvas.stroke_path(&femtovg_path, border_paint);
+                canvas.stroke_path(dashes.as_ref().unwrap_or(&femtovg_path), border_paint);
             }
         })
     }
@@ -626,7 +696,7 @@ fn visit_opacity(
     ) -> RenderingResult {
         let opacity = opacity_item.opacity();
         if Opacity::need_layer(item_rc, opacity) {
-            self.render_and_blend_layer(opacity, item_rc)
+            self.render_and_blend_layer(opacity, None, item_rc)
         } else {
             self.apply_opacity(opacity);
             self.graphics_cache.release(item_rc);
@@ -641,13 +711,171 @@ fn visit_layer(
         _size: LogicalSize,
     ) -> RenderingResult {
         if layer_item.cache_rendering_hint() {
-            self.render_and_blend_layer(1.0, self_rc)
+            self.render_and_blend_layer(1.0, None, self_rc)
         } else {
             self.graphics_cache.release(self_rc);
             RenderingResult::ContinueRenderingChildren
         }
     }
 
+    fn visit_blend(
+        &mut self,
+        blend_item: Pin<&Blend>,
+        item_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        // Only the blend modes that are blend functions of the GPU are supported. They are exact
+        // over opaque content.
+        let blend_factors = match blend_item.mix_blend_mode() {
+            MixBlendMode::Multiply => {
+                (femtovg::BlendFactor::DstColor, femtovg::BlendFactor::OneMinusSrcAlpha)
+            }
+            MixBlendMode::Screen => {
+                (femtovg::BlendFactor::One, femtovg::BlendFactor::OneMinusSrcColor)
+            }
+            _ => {
+                self.graphics_cache.release(item_rc);
+                return RenderingResult::ContinueRenderingChildren;
+            }
+        };
+        self.render_and_blend_layer(1.0, Some(blend_factors), item_rc)
+    }
+
+    fn visit_backdrop_blur(
+        &mut self,
+        backdrop_blur_item: Pin<&BackdropBlur>,
+        _self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        let blur = backdrop_blur_item.backdrop_blur() * self.scale_factor;
+        let size = size * self.scale_factor;
+        if blur.get() <= 0. || size.is_empty() {
+            return RenderingResult::ContinueRenderingChildren;
+        }
+
+        // FemtoVG has no backdrop filters, so read back what was rendered so far and blur
+        // the part of it that is below the item.
+        let (backdrop, offset) = {
+            let mut canvas = self.canvas.borrow_mut();
+            let (x, y) = canvas.transform().transform_point(0., 0.);
+            let Ok(screenshot) = canvas.screenshot() else {
+                return RenderingResult::ContinueRenderingChildren;
+            };
+            let (left, top) = (x.max(0.).round() as usize, y.max(0.).round() as usize);
+            let width = (size.width.ceil() as usize).min(screenshot.width().saturating_sub(left));
+            let height = (size.height.ceil() as usize).min(screenshot.height().saturating_sub(top));
+            if width == 0 || height == 0 {
+                return RenderingResult::ContinueRenderingChildren;
+            }
+            let Ok(image_id) = canvas.create_image(
+                screenshot.sub_image(left, top, width, height),
+                femtovg::ImageFlags::empty(),
+            ) else {
+                return RenderingResult::ContinueRenderingChildren;
+            };
+            (Texture::adopt(&self.canvas, image_id), (left as f32 - x, top as f32 - y))
+        };
+
+        let blurred =
+            backdrop.filter(femtovg::ImageFilter::GaussianBlur { sigma: blur.get() / 2. });
+        self.textures_to_delete_after_flush.borrow_mut().push(backdrop);
+
+        let (width, height) = match blurred.size() {
+            Some(size) => (size.width as f32, size.height as f32),
+            None => return RenderingResult::ContinueRenderingChildren,
+        };
+        let radius = backdrop_blur_item.border_radius() * self.scale_factor;
+        let mut path = femtovg::Path::new();
+        path.rounded_rect(0., 0., width, height, radius.get());
+        let paint = blurred.as_paint().with_anti_alias(false);
+
+        self.canvas.borrow_mut().save_with(|canvas| {
+            canvas.translate(offset.0, offset.1);
+            canvas.fill_path(&path, &paint);
+        });
+        self.textures_to_delete_after_flush.borrow_mut().push(blurred);
+
+        RenderingResult::ContinueRenderingChildren
+    }
+
+    fn visit_mask(
+        &mut self,
+        mask_item: Pin<&Mask>,
+        item_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        let mask_image = mask_item.mask_image();
+        let image_inner: &ImageInner = (&mask_image).into();
+        let target_size = (size * self.scale_factor).ceil().try_cast::<u32>();
+        let create_texture = || {
+            Texture::new_from_image(
+                image_inner,
+                &self.canvas,
+                target_size,
+                ImageRendering::Smooth,
+                Default::default(),
+            )
+        };
+        let mask_texture = TextureCacheKey::new(
+            image_inner,
+            target_size,
+            ImageRendering::Smooth,
+            Default::default(),
+        )
+        .and_then(|cache_key| {
+            self.texture_cache
+                .borrow_mut()
+                .lookup_image_in_cache_or_create(cache_key, create_texture)
+        })
+        .or_else(create_texture);
+        let Some(mask_texture) = mask_texture.filter(|texture| texture.size().is_some()) else {
+            return RenderingResult::ContinueRenderingChildren;
+        };
+
+        let Some((layer_image, layer_size)) = self
+            .render_layer(item_rc, &|| LogicalRect::from(size))
+            .and_then(|image| image.size().map(|size| (image, size)))
+        else {
+            return RenderingResult::ContinueRenderingWithoutChildren;
+        };
+        let Some(masked_image) =
+            Texture::new_empty_on_gpu(&self.canvas, layer_size.width, layer_size.height)
+        else {
+            return RenderingResult::ContinueRenderingWithoutChildren;
+        };
+
+        let (width, height) = (layer_size.width as f32, layer_size.height as f32);
+        let mut layer_path = femtovg::Path::new();
+        layer_path.rect(0., 0., width, height);
+
+        {
+            let mut canvas = self.canvas.borrow_mut();
+            canvas.save();
+            canvas.set_render_target(masked_image.as_render_target());
+            canvas.reset();
+
+            // Copy the children, then keep them only where the mask is opaque
+            canvas.global_composite_operation(femtovg::CompositeOperation::Copy);
+            canvas.fill_path(&layer_path, &layer_image.as_paint().with_anti_alias(false));
+            canvas.global_composite_operation(femtovg::CompositeOperation::DestinationIn);
+            canvas.fill_path(
+                &layer_path,
+                &femtovg::Paint::image(mask_texture.id, 0., 0., width, height, 0., 1.0)
+                    .with_anti_alias(false),
+            );
+
+            canvas.restore();
+            canvas.set_render_target(self.current_render_target());
+        }
+
+        self.canvas
+            .borrow_mut()
+            .fill_path(&layer_path, &masked_image.as_paint().with_anti_alias(false));
+        self.textures_to_delete_after_flush.borrow_mut().push(masked_image);
+
+        RenderingResult::ContinueRenderingWithoutChildren
+    }
+
     fn visit_clip(
         &mut self,
         clip_item: Pin<&Clip>,
@@ -964,10 +1192,14 @@ fn draw_glyph_run(
     ) {
         let font_id = font_cache::FONT_CACHE.with(|cache| cache.borrow_mut().font(font));
 
-        let glyphs_it = glyphs_it.map(|glyph| femtovg::PositionedGlyph {
-            x: glyph.x,
-            y: glyph.y + y_offset.get(),
-            glyph_id: glyph.id as u16,
+        let mut glyph_atlas_usage = self.glyph_atlas_usage.borrow_mut();
+        let glyphs_it = glyphs_it.map(|glyph| {
+            glyph_atlas_usage.record(font_id, font_size.get(), glyph.id as u16);
+            femtovg::PositionedGlyph {
+                x: glyph.x,
+                y: glyph.y + y_offset.get(),
+                glyph_id: glyph.id as u16,
+            }
         });
 
         let mut canvas = self.canvas.borrow_mut();
@@ -1008,6 +1240,7 @@ pub(super) fn new(
         canvas: &CanvasRc<R>,
         graphics_cache: &'a ItemGraphicsCache<R>,
         texture_cache: &'a RefCell<super::images::TextureCache<R>>,
+        glyph_atlas_usage: &'a RefCell<super::glyph_atlas::GlyphAtlasUsage>,
         window: &'a i_slint_core::api::Window,
         width: u32,
         height: u32,
@@ -1016,6 +1249,7 @@ pub(super) fn new(
         Self {
             graphics_cache,
             texture_cache,
+            glyph_atlas_usage,
             box_shadow_cache: Default::default(),
             canvas: canvas.clone(),
             textures_to_delete_after_flush: Default::default(),
@@ -1119,7 +1353,14 @@ fn render_layer(
         cache_entry.map(|item_cache_entry| item_cache_entry.as_texture().clone())
     }
 
-    fn render_and_blend_layer(&mut self, alpha_tint: f32, item_rc: &ItemRc) -> RenderingResult {
+    /// Renders the children of the item into a layer and draws it with the alpha tint. The layer is
+    /// blended with the source and destination factors of `blend_factors` for the colors, if set.
+    fn render_and_blend_layer(
+        &mut self,
+        alpha_tint: f32,
+        blend_factors: Option<(femtovg::BlendFactor, femtovg::BlendFactor)>,
+        item_rc: &ItemRc,
+    ) -> RenderingResult {
         let current_clip = self.get_current_clip();
         if let Some((layer_image, layer_size)) = self
             .render_layer(item_rc, &|| {
@@ -1144,7 +1385,17 @@ fn render_and_blend_layer(&mut self, alpha_tint: f32, item_rc: &ItemRc) -> Rende
                 layer_image.as_paint_with_alpha(alpha_tint).with_anti_alias(false);
 
             layer_path.rect(0., 0., layer_size.width as _, layer_size.height as _);
-            self.canvas.borrow_mut().fill_path(&layer_path, &layer_image_paint);
+            self.canvas.borrow_mut().save_with(|canvas| {
+                if let Some((src_rgb, dst_rgb)) = blend_factors {
+                    canvas.global_composite_blend_func_separate(
+                        src_rgb,
+                        dst_rgb,
+                        femtovg::BlendFactor::One,
+                        femtovg::BlendFactor::OneMinusSrcAlpha,
+                    );
+                }
+                canvas.fill_path(&layer_path, &layer_image_paint);
+            });
         }
         RenderingResult::ContinueRenderingWithoutChildren
     }
@@ -21,7 +21,7 @@
     LogicalLength, LogicalPoint, LogicalRect, LogicalSize, PhysicalPx, ScaleFactor,
 };
 use i_slint_core::platform::PlatformError;
-use i_slint_core::renderer::RendererSealed;
+use i_slint_core::renderer::{CacheStatistics, RendererSealed};
 use i_slint_core::textlayout::sharedparley;
 use i_slint_core::window::{WindowAdapter, WindowInner};
 use i_slint_core::Brush;
@@ -36,6 +36,7 @@
 use self::itemrenderer::CanvasRc;
 
 mod font_cache;
+mod glyph_atlas;
 mod images;
 mod itemrenderer;
 #[cfg(feature = "opengl")]
@@ -73,6 +74,8 @@ fn resize(
         width: NonZeroU32,
         height: NonZeroU32,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+    /// Creates a new canvas for the current graphics context, to replace the existing one.
+    fn create_canvas(&self) -> Option<femtovg::Canvas<Self::Renderer>>;
 }
 
 /// Use the FemtoVG renderer when implementing a custom Slint platform where you deliver events to
@@ -86,11 +89,19 @@ pub struct FemtoVGRenderer<B: GraphicsBackend> {
     texture_cache: RefCell<images::TextureCache<B::Renderer>>,
     rendering_metrics_collector: RefCell<Option<Rc<RenderingMetricsCollector>>>,
     rendering_first_time: Cell<bool>,
+    glyph_atlas_usage: RefCell<glyph_atlas::GlyphAtlasUsage>,
     // Last field, so that it's dropped last and for example the OpenGL context exists and is current when destroying the FemtoVG canvas
     graphics_backend: B,
 }
 
 impl<B: GraphicsBackend> FemtoVGRenderer<B> {
+    /// Limits the GPU memory, in bytes, that the atlas of rasterized glyphs may use. When the atlas
+    /// exceeds this budget, the glyphs that were not drawn in the last frame are evicted.
+    /// By default, or with `None`, the atlas grows without limit.
+    pub fn set_glyph_cache_budget(&self, budget: Option<usize>) {
+        self.glyph_atlas_usage.borrow_mut().set_budget(budget);
+    }
+
     /// Render the scene using OpenGL.
     pub fn render(&self) -> Result<(), i_slint_core::platform::PlatformError> {
         self.internal_render_with_post_callback(
@@ -194,10 +205,13 @@ fn internal_render_with_post_callback(
 
                 self.graphics_cache.clear_cache_if_scale_factor_changed(window);
 
+                self.glyph_atlas_usage.borrow_mut().begin_frame();
+
                 let mut item_renderer = self::itemrenderer::GLItemRenderer::new(
                     &canvas,
                     &self.graphics_cache,
                     &self.texture_cache,
+                    &self.glyph_atlas_usage,
                     window,
                     width.get(),
                     height.get(),
@@ -240,7 +254,8 @@ fn internal_render_with_post_callback(
                 }
 
                 if let Some(collector) = &self.rendering_metrics_collector.borrow().as_ref() {
-                    let metrics = item_renderer.metrics();
+                    let mut metrics = item_renderer.metrics();
+                    metrics.cache_statistics = Some(self.collect_cache_statistics());
                     collector.measure_frame_rendered(&mut item_renderer, metrics);
                 }
 
@@ -251,6 +266,16 @@ fn internal_render_with_post_callback(
                 // avoid GPU memory leaks.
                 self.texture_cache.borrow_mut().drain();
                 drop(item_renderer);
+
+                if self.glyph_atlas_usage.borrow_mut().evict_if_over_budget() {
+                    // FemtoVG's glyph atlas can't shrink, so replace the canvas with a new one. The textures
+                    // of the old canvas must be released first, as they are deleted through the canvas.
+                    if let Some(new_canvas) = self.graphics_backend.create_canvas() {
+                        self.graphics_cache.clear_all();
+                        self.texture_cache.borrow_mut().clear();
+                        *canvas.borrow_mut() = new_canvas;
+                    }
+                }
                 Ok(())
             })
             .unwrap_or(Ok(()))?;
@@ -318,6 +343,17 @@ fn text_input_byte_offset_for_position(
         )
     }
 
+    fn text_byte_offset_for_position(
+        &self,
+        text: Pin<&dyn i_slint_core::item_rendering::RenderText>,
+        size: LogicalSize,
+        pos: LogicalPoint,
+        font_request: FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> usize {
+        sharedparley::text_byte_offset_for_position(text, size, pos, font_request, scale_factor)
+    }
+
     fn text_input_cursor_rect_for_byte_offset(
         &self,
         text_input: Pin<&i_slint_core::items::TextInput>,
@@ -420,6 +456,16 @@ fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError>
     fn supports_transformations(&self) -> bool {
         true
     }
+
+    fn collect_cache_statistics(&self) -> CacheStatistics {
+        let mut statistics = CacheStatistics::shared();
+        if let Some((bytes, occupancy)) = self.glyph_atlas_usage.borrow().statistics() {
+            statistics.glyph_cache_bytes = Some(bytes);
+            statistics.glyph_cache_occupancy = Some(occupancy);
+        }
+        statistics.texture_memory_bytes = Some(self.texture_cache.borrow().texture_memory_bytes());
+        statistics
+    }
 }
 
 impl<B: GraphicsBackend> Drop for FemtoVGRenderer<B> {
@@ -468,6 +514,7 @@ fn new_suspended() -> Self {
             texture_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
             rendering_first_time: Cell::new(true),
+            glyph_atlas_usage: Default::default(),
             graphics_backend: B::new_suspended(),
         }
     }
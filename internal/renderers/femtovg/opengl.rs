@@ -236,6 +236,27 @@ fn resize(
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         self.opengl_context.borrow().resize(width, height)
     }
+
+    fn create_canvas(&self) -> Option<femtovg::Canvas<Self::Renderer>> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let gl_renderer = unsafe {
+            femtovg::renderer::OpenGl::new_from_function_cstr(|name| {
+                self.opengl_context.borrow().get_proc_address(name)
+            })
+            .ok()?
+        };
+
+        #[cfg(target_arch = "wasm32")]
+        let gl_renderer =
+            femtovg::renderer::OpenGl::new_from_html_canvas(self.html_canvas.borrow().as_ref()?)
+                .ok()?;
+
+        femtovg::Canvas::new_with_text_context(
+            gl_renderer,
+            crate::font_cache::FONT_CACHE.with(|cache| cache.borrow().text_context.clone()),
+        )
+        .ok()
+    }
 }
 
 impl FemtoVGRenderer<OpenGLBackend> {
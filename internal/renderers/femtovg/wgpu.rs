@@ -15,6 +15,8 @@ pub struct WGPUBackend {
     queue: RefCell<Option<wgpu::Queue>>,
     surface_config: RefCell<Option<wgpu::SurfaceConfiguration>>,
     surface: RefCell<Option<wgpu::Surface<'static>>>,
+    /// The texture of the frame that is being rendered, for compositing in the rendering notifier
+    current_surface_texture: RefCell<Option<wgpu::Texture>>,
 }
 
 pub struct WGPUWindowSurface {
@@ -39,6 +41,7 @@ fn new_suspended() -> Self {
             queue: Default::default(),
             surface_config: Default::default(),
             surface: Default::default(),
+            current_surface_texture: Default::default(),
         }
     }
 
@@ -58,6 +61,7 @@ fn begin_surface_rendering(
             .unwrap()
             .get_current_texture()
             .expect("unable to get next texture from swapchain");
+        *self.current_surface_texture.borrow_mut() = Some(frame.texture.clone());
         Ok(WGPUWindowSurface { surface_texture: frame })
     }
 
@@ -69,6 +73,7 @@ fn present_surface(
         &self,
         surface: Self::WindowSurface,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.current_surface_texture.borrow_mut().take();
         surface.surface_texture.present();
         Ok(())
     }
@@ -83,7 +88,10 @@ fn with_graphics_api<R>(
         let queue = self.queue.borrow().clone();
         if let (Some(instance), Some(device), Some(queue)) = (instance, device, queue) {
             Ok(callback(Some(i_slint_core::graphics::create_graphics_api_wgpu_27(
-                instance, device, queue,
+                instance,
+                device,
+                queue,
+                self.current_surface_texture.borrow().clone(),
             ))))
         } else {
             Ok(callback(None))
@@ -120,6 +128,16 @@ fn resize(
         self.surface.borrow_mut().as_mut().unwrap().configure(device, surface_config);
         Ok(())
     }
+
+    fn create_canvas(&self) -> Option<femtovg::Canvas<Self::Renderer>> {
+        let device = self.device.borrow().clone()?;
+        let queue = self.queue.borrow().clone()?;
+        femtovg::Canvas::new_with_text_context(
+            femtovg::renderer::WGPURenderer::new(device, queue),
+            crate::font_cache::FONT_CACHE.with(|cache| cache.borrow().text_context.clone()),
+        )
+        .ok()
+    }
 }
 
 impl FemtoVGRenderer<WGPUBackend> {
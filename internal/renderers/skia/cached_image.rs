@@ -70,6 +70,7 @@ pub(crate) fn as_skia_image(
                 SharedImageBuffer::RGB8(_) => unreachable!(),
                 SharedImageBuffer::RGBA8(_) => unreachable!(),
                 SharedImageBuffer::RGBA8Premultiplied(pixels) => pixels,
+                SharedImageBuffer::RGB565(_) | SharedImageBuffer::Gray8(_) => unreachable!(),
             };
 
             let image_info = skia_safe::ImageInfo::new(
@@ -92,6 +93,9 @@ pub(crate) fn as_skia_image(
         ImageInner::BorrowedOpenGLTexture(texture) => {
             surface.and_then(|surface| surface.import_opengl_texture(canvas, texture))
         }
+        ImageInner::BorrowedExternalTexture(texture) => {
+            surface.and_then(|surface| surface.import_external_texture(canvas, texture))
+        }
         ImageInner::NineSlice(n) => as_skia_image(
             n.image(),
             target_size_fn,
@@ -138,6 +142,27 @@ fn image_buffer_to_skia_image(buffer: &SharedImageBuffer) -> Option<skia_safe::I
             skia_safe::ColorType::RGBA8888,
             skia_safe::AlphaType::Premul,
         ),
+        SharedImageBuffer::RGB565(pixels) => {
+            let bytes = pixels
+                .as_slice()
+                .iter()
+                .flat_map(|pixel| pixel.0.to_ne_bytes())
+                .collect::<Vec<u8>>();
+            (
+                skia_safe::Data::new_copy(&*bytes),
+                pixels.width() as usize * 2,
+                pixels.size(),
+                skia_safe::ColorType::RGB565,
+                skia_safe::AlphaType::Opaque,
+            )
+        }
+        SharedImageBuffer::Gray8(pixels) => (
+            skia_safe::Data::new_copy(pixels.as_bytes()),
+            pixels.width() as usize,
+            pixels.size(),
+            skia_safe::ColorType::Gray8,
+            skia_safe::AlphaType::Opaque,
+        ),
     };
     let image_info = skia_safe::ImageInfo::new(
         skia_safe::ISize::new(size.width as i32, size.height as i32),
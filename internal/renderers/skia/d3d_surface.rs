@@ -15,10 +15,12 @@
 use windows::Win32::Graphics::Direct3D12::{
     D3D12CreateDevice, ID3D12CommandQueue, ID3D12Device, ID3D12Fence, ID3D12Resource,
     D3D12_COMMAND_LIST_TYPE_DIRECT, D3D12_COMMAND_QUEUE_DESC, D3D12_FENCE_FLAG_NONE,
-    D3D12_RESOURCE_STATE_PRESENT,
+    D3D12_RESOURCE_STATE_COMMON, D3D12_RESOURCE_STATE_PRESENT,
 };
 use windows::Win32::Graphics::Dxgi::{
-    Common::{DXGI_FORMAT, DXGI_FORMAT_R8G8B8A8_UNORM, DXGI_SAMPLE_DESC},
+    Common::{
+        DXGI_FORMAT, DXGI_FORMAT_B8G8R8A8_UNORM, DXGI_FORMAT_R8G8B8A8_UNORM, DXGI_SAMPLE_DESC,
+    },
     CreateDXGIFactory2, IDXGIFactory4, IDXGISwapChain3, DXGI_ADAPTER_FLAG, DXGI_ADAPTER_FLAG_NONE,
     DXGI_ADAPTER_FLAG_SOFTWARE, DXGI_CREATE_FACTORY_FLAGS, DXGI_PRESENT, DXGI_SWAP_CHAIN_DESC1,
     DXGI_SWAP_CHAIN_FLAG, DXGI_SWAP_EFFECT_FLIP_DISCARD, DXGI_USAGE_RENDER_TARGET_OUTPUT,
@@ -253,6 +255,7 @@ fn wait_for_buffer(&mut self, buffer_index: usize) -> Result<(), PlatformError>
 /// argument is ignored, as it has no meaning on Windows.
 pub struct D3DSurface {
     swap_chain: RefCell<SwapChain>,
+    device: ID3D12Device,
 }
 
 impl super::Surface for D3DSurface {
@@ -392,7 +395,7 @@ fn new(
             &dxgi_factory,
         )?);
 
-        Ok(Self { swap_chain })
+        Ok(Self { swap_chain, device })
     }
 
     fn name(&self) -> &'static str {
@@ -437,4 +440,52 @@ fn bits_per_pixel(&self) -> Result<u8, i_slint_core::platform::PlatformError> {
             }
         })
     }
+
+    fn import_external_texture(
+        &self,
+        canvas: &skia_safe::Canvas,
+        texture: &i_slint_core::graphics::BorrowedExternalTexture,
+    ) -> Option<skia_safe::Image> {
+        let i_slint_core::graphics::ExternalTextureHandle::D3DSharedHandle(handle) = texture.handle
+        else {
+            return None;
+        };
+
+        let mut resource: Option<ID3D12Resource> = None;
+        unsafe { self.device.OpenSharedHandle(HANDLE(handle), &mut resource) }.ok()?;
+        let resource = resource?;
+
+        let format = unsafe { resource.GetDesc() }.Format;
+        let color_type = match format {
+            DXGI_FORMAT_R8G8B8A8_UNORM => skia_safe::ColorType::RGBA8888,
+            DXGI_FORMAT_B8G8R8A8_UNORM => skia_safe::ColorType::BGRA8888,
+            _ => return None,
+        };
+
+        let texture_info = skia_safe::gpu::d3d::TextureResourceInfo {
+            resource,
+            alloc: None,
+            resource_state: D3D12_RESOURCE_STATE_COMMON,
+            format,
+            sample_count: 1,
+            level_count: 1,
+            sample_quality_pattern: DXGI_STANDARD_MULTISAMPLE_QUALITY_PATTERN,
+            protected: skia_safe::gpu::Protected::No,
+        };
+        let backend_texture = skia_safe::gpu::BackendTexture::new_d3d(
+            (texture.size.width as i32, texture.size.height as i32),
+            &texture_info,
+        );
+
+        unsafe {
+            skia_safe::image::Image::from_texture(
+                canvas.recording_context().as_mut().unwrap(),
+                &backend_texture,
+                skia_safe::gpu::SurfaceOrigin::TopLeft,
+                color_type,
+                skia_safe::AlphaType::Premul,
+                None,
+            )
+        }
+    }
 }
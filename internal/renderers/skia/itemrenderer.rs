@@ -3,6 +3,8 @@
 
 // cSpell: ignore rrect
 
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::pin::Pin;
 
 use super::{PhysicalBorderRadius, PhysicalLength, PhysicalPoint, PhysicalRect, PhysicalSize};
@@ -13,7 +15,10 @@
 use i_slint_core::item_rendering::{
     CachedRenderingData, ItemCache, ItemRenderer, ItemRendererFeatures, RenderImage, RenderText,
 };
-use i_slint_core::items::{ImageFit, ImageRendering, ItemRc, Layer, Opacity, RenderingResult};
+use i_slint_core::items::{
+    BackdropBlur, Blend, ImageFit, ImageRendering, ItemRc, Layer, Mask, MixBlendMode, Opacity,
+    RenderingResult, ShaderEffect,
+};
 use i_slint_core::lengths::{
     LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalPx, LogicalRect, LogicalSize,
     LogicalVector, PhysicalPx, RectLengths, ScaleFactor, SizeLengths,
@@ -438,6 +443,25 @@ fn draw_border_rectangle(
             rect.border_width() * self.scale_factor
         };
 
+        if i_slint_core::item_rendering::pixel_snapping_enabled(
+            rect.pixel_snapping(),
+            self.window(),
+            self.scale_factor.get(),
+        ) {
+            let local_to_device = self.canvas.local_to_device_as_3x3();
+            // Only a translation keeps the edges parallel to the device pixels
+            if local_to_device.is_translate() {
+                let (snapped_geometry, snapped_border_width) =
+                    i_slint_core::item_rendering::snap_to_device_pixels(
+                        geometry,
+                        border_width.get(),
+                        euclid::vec2(local_to_device.translate_x(), local_to_device.translate_y()),
+                    );
+                geometry = snapped_geometry;
+                border_width = PhysicalLength::new(snapped_border_width);
+            }
+        }
+
         // Radius of rounded rect if we were to just fill the rectangle, without a border.
         let mut fill_radius = rect.border_radius() * self.scale_factor;
         // Skia's border radius on stroke is in the middle of the border. But we want it to be the radius of the rectangle itself.
@@ -649,6 +673,15 @@ fn draw_path(
                 i_slint_core::items::LineCap::Round => skia_safe::PaintCap::Round,
                 i_slint_core::items::LineCap::Square => skia_safe::PaintCap::Square,
             });
+            border_paint.set_stroke_join(match path.stroke_line_join() {
+                i_slint_core::items::LineJoin::Miter => skia_safe::PaintJoin::Miter,
+                i_slint_core::items::LineJoin::Round => skia_safe::PaintJoin::Round,
+                i_slint_core::items::LineJoin::Bevel => skia_safe::PaintJoin::Bevel,
+            });
+            if let Some((dash, gap)) = path.stroke_dash_pattern() {
+                let intervals = [(dash * self.scale_factor).get(), (gap * self.scale_factor).get()];
+                border_paint.set_path_effect(skia_safe::PathEffect::dash(&intervals, 0.));
+            }
             border_paint.set_stroke(true);
             self.canvas.draw_path(&skpath, &border_paint);
         }
@@ -899,6 +932,198 @@ fn visit_layer(
             RenderingResult::ContinueRenderingChildren
         }
     }
+
+    fn visit_backdrop_blur(
+        &mut self,
+        backdrop_blur_item: Pin<&BackdropBlur>,
+        _self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        let blur = backdrop_blur_item.backdrop_blur() * self.scale_factor;
+        if blur.get() <= 0. {
+            return RenderingResult::ContinueRenderingChildren;
+        }
+        let Some(filter) = skia_safe::image_filters::blur(
+            (blur.get() / 2., blur.get() / 2.),
+            TileMode::Clamp,
+            None,
+            None,
+        ) else {
+            return RenderingResult::ContinueRenderingChildren;
+        };
+
+        let rect = to_skia_rect(&PhysicalRect::from_size(size * self.scale_factor));
+        let radius = (backdrop_blur_item.border_radius() * self.scale_factor).get();
+
+        // An empty layer with a backdrop filter replaces the content below with its blurred version.
+        self.canvas.save();
+        self.canvas.clip_rrect(skia_safe::RRect::new_rect_xy(rect, radius, radius), None, true);
+        self.canvas.save_layer(
+            &skia_safe::canvas::SaveLayerRec::default().bounds(&rect).backdrop(&filter),
+        );
+        self.canvas.restore();
+        self.canvas.restore();
+
+        RenderingResult::ContinueRenderingChildren
+    }
+
+    fn visit_shader_effect(
+        &mut self,
+        shader_effect_item: Pin<&ShaderEffect>,
+        item_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        let Some(effect) = runtime_effect(&shader_effect_item.shader()) else {
+            return RenderingResult::ContinueRenderingChildren;
+        };
+
+        let physical_size = size * self.scale_factor;
+        let mut builder = skia_safe::runtime_effect::RuntimeShaderBuilder::new(effect);
+        // Uniforms that the shader doesn't declare are ignored.
+        builder.set_uniform_float("size", &[physical_size.width, physical_size.height]).ok();
+        builder.set_uniform_float("time", &[shader_effect_item.time()]).ok();
+        builder
+            .set_uniform_float(
+                "params",
+                &[
+                    shader_effect_item.param1(),
+                    shader_effect_item.param2(),
+                    shader_effect_item.param3(),
+                    shader_effect_item.param4(),
+                ],
+            )
+            .ok();
+
+        let Some(filter) = skia_safe::image_filters::runtime_shader(&builder, "content", None)
+        else {
+            return RenderingResult::ContinueRenderingChildren;
+        };
+
+        let mut paint = self.default_paint().unwrap_or_default();
+        paint.set_image_filter(filter);
+        let bounds = to_skia_rect(&PhysicalRect::from_size(physical_size));
+        self.canvas
+            .save_layer(&skia_safe::canvas::SaveLayerRec::default().bounds(&bounds).paint(&paint));
+        self.state_stack.push(self.current_state);
+        self.current_state.alpha = 1.0;
+
+        let window_adapter = WindowInner::from_pub(self.window).window_adapter();
+
+        i_slint_core::item_rendering::render_item_children(
+            self,
+            item_rc.item_tree(),
+            item_rc.index() as isize,
+            &window_adapter,
+        );
+
+        self.current_state = self.state_stack.pop().unwrap();
+        self.canvas.restore();
+        RenderingResult::ContinueRenderingWithoutChildren
+    }
+
+    fn visit_blend(
+        &mut self,
+        blend_item: Pin<&Blend>,
+        item_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        let blend_mode = match blend_item.mix_blend_mode() {
+            MixBlendMode::Normal => return RenderingResult::ContinueRenderingChildren,
+            MixBlendMode::Multiply => skia_safe::BlendMode::Multiply,
+            MixBlendMode::Screen => skia_safe::BlendMode::Screen,
+            MixBlendMode::Overlay => skia_safe::BlendMode::Overlay,
+            MixBlendMode::Darken => skia_safe::BlendMode::Darken,
+            MixBlendMode::Lighten => skia_safe::BlendMode::Lighten,
+            MixBlendMode::ColorDodge => skia_safe::BlendMode::ColorDodge,
+            MixBlendMode::ColorBurn => skia_safe::BlendMode::ColorBurn,
+            MixBlendMode::HardLight => skia_safe::BlendMode::HardLight,
+            MixBlendMode::SoftLight => skia_safe::BlendMode::SoftLight,
+            MixBlendMode::Difference => skia_safe::BlendMode::Difference,
+            MixBlendMode::Exclusion => skia_safe::BlendMode::Exclusion,
+            MixBlendMode::Hue => skia_safe::BlendMode::Hue,
+            MixBlendMode::Saturation => skia_safe::BlendMode::Saturation,
+            MixBlendMode::Color => skia_safe::BlendMode::Color,
+            MixBlendMode::Luminosity => skia_safe::BlendMode::Luminosity,
+        };
+
+        // The layer is blended with the content below it when it's restored
+        let mut paint = self.default_paint().unwrap_or_default();
+        paint.set_blend_mode(blend_mode);
+        self.canvas.save_layer(&skia_safe::canvas::SaveLayerRec::default().paint(&paint));
+        self.state_stack.push(self.current_state);
+        self.current_state.alpha = 1.0;
+
+        let window_adapter = WindowInner::from_pub(self.window).window_adapter();
+
+        i_slint_core::item_rendering::render_item_children(
+            self,
+            item_rc.item_tree(),
+            item_rc.index() as isize,
+            &window_adapter,
+        );
+
+        self.current_state = self.state_stack.pop().unwrap();
+        self.canvas.restore();
+        RenderingResult::ContinueRenderingWithoutChildren
+    }
+
+    fn visit_mask(
+        &mut self,
+        mask_item: Pin<&Mask>,
+        item_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        let mask_image = self.image_cache.get_or_update_cache_entry(item_rc, || {
+            super::cached_image::as_skia_image(
+                mask_item.mask_image(),
+                // Read the geometry in the cache entry update, to render scalable images again on resize
+                &|| item_rc.geometry().size,
+                ImageFit::Fill,
+                self.scale_factor,
+                self.canvas,
+                self.surface,
+            )
+        });
+        let Some(mask_image) = mask_image else {
+            return RenderingResult::ContinueRenderingChildren;
+        };
+
+        let bounds = to_skia_rect(&PhysicalRect::from_size(size * self.scale_factor));
+        self.canvas.save();
+        self.canvas.clip_rect(bounds, None, None);
+        self.canvas.save_layer(
+            &skia_safe::canvas::SaveLayerRec::default()
+                .bounds(&bounds)
+                .paint(&self.default_paint().unwrap_or_default()),
+        );
+        self.state_stack.push(self.current_state);
+        self.current_state.alpha = 1.0;
+
+        let window_adapter = WindowInner::from_pub(self.window).window_adapter();
+
+        i_slint_core::item_rendering::render_item_children(
+            self,
+            item_rc.item_tree(),
+            item_rc.index() as isize,
+            &window_adapter,
+        );
+
+        // Keep the rendered children only where the mask is opaque
+        let mut mask_paint = skia_safe::Paint::default();
+        mask_paint.set_blend_mode(skia_safe::BlendMode::DstIn);
+        self.canvas.draw_image_rect_with_sampling_options(
+            mask_image,
+            None,
+            bounds,
+            skia_safe::sampling_options::FilterMode::Linear,
+            &mask_paint,
+        );
+
+        self.current_state = self.state_stack.pop().unwrap();
+        self.canvas.restore();
+        self.canvas.restore();
+        RenderingResult::ContinueRenderingWithoutChildren
+    }
 }
 
 impl GlyphRenderer for SkiaItemRenderer<'_> {
@@ -1008,6 +1233,31 @@ pub fn from_skia_rect(rect: &skia_safe::Rect) -> PhysicalRect {
     euclid::Box2D::new(top_left, bottom_right).to_rect()
 }
 
+/// Returns the compiled SkSL shader for the given source, or None if it fails to compile.
+/// Compiled shaders are cached, as a `ShaderEffect` is typically re-rendered every frame.
+fn runtime_effect(source: &SharedString) -> Option<skia_safe::RuntimeEffect> {
+    if source.is_empty() {
+        return None;
+    }
+    type RuntimeEffectCache = HashMap<SharedString, Option<skia_safe::RuntimeEffect>>;
+    thread_local! {
+        static RUNTIME_EFFECTS: RefCell<RuntimeEffectCache> = Default::default();
+    }
+    RUNTIME_EFFECTS.with(|cache| {
+        cache
+            .borrow_mut()
+            .entry(source.clone())
+            .or_insert_with(|| {
+                skia_safe::RuntimeEffect::make_for_shader(source.as_str(), None)
+                    .map_err(|error| {
+                        i_slint_core::debug_log!("Error compiling ShaderEffect shader: {error}")
+                    })
+                    .ok()
+            })
+            .clone()
+    })
+}
+
 pub fn to_skia_rect(rect: &PhysicalRect) -> skia_safe::Rect {
     skia_safe::Rect::from_xywh(rect.origin.x, rect.origin.y, rect.size.width, rect.size.height)
 }
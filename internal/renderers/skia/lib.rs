@@ -16,7 +16,9 @@
     SetRenderingNotifierError, Window,
 };
 use i_slint_core::graphics::euclid::{self, Vector2D};
-use i_slint_core::graphics::rendering_metrics_collector::RenderingMetricsCollector;
+use i_slint_core::graphics::rendering_metrics_collector::{
+    RenderingMetrics, RenderingMetricsCollector,
+};
 use i_slint_core::graphics::RequestedGraphicsAPI;
 use i_slint_core::graphics::{BorderRadius, FontRequest, SharedPixelBuffer};
 use i_slint_core::item_rendering::{ItemCache, ItemRenderer};
@@ -154,11 +156,15 @@ struct SkiaSharedContextInner {
 #[derive(Clone, Default)]
 pub struct SkiaSharedContext(#[allow(dead_code)] Rc<SkiaSharedContextInner>);
 
+// Reference counted, so that the callback can be replaced while it's being invoked.
+type CanvasRenderingNotifier = Rc<RefCell<Box<dyn FnMut(RenderingState, &skia_safe::Canvas, f32)>>>;
+
 /// Use the SkiaRenderer when implementing a custom Slint platform where you deliver events to
 /// Slint and want the scene to be rendered using Skia as underlying graphics library.
 pub struct SkiaRenderer {
     maybe_window_adapter: RefCell<Option<Weak<dyn WindowAdapter>>>,
     rendering_notifier: RefCell<Option<Box<dyn RenderingNotifier>>>,
+    canvas_rendering_notifier: RefCell<Option<CanvasRenderingNotifier>>,
     image_cache: ItemCache<Option<skia_safe::Image>>,
     path_cache: ItemCache<Option<(Vector2D<f32, PhysicalPx>, skia_safe::Path)>>,
     rendering_metrics_collector: RefCell<Option<Rc<RenderingMetricsCollector>>>,
@@ -177,6 +183,8 @@ pub struct SkiaRenderer {
     /// Tracking dirty regions indexed by buffer age - 1. More than 3 back buffers aren't supported, but also unlikely to happen.
     dirty_region_history: RefCell<[DirtyRegion; 3]>,
     shared_context: SkiaSharedContext,
+    /// The GPU memory used by Skia's resource cache after the last frame, if rendering on the GPU.
+    texture_memory_bytes: Cell<Option<usize>>,
 }
 
 impl SkiaRenderer {
@@ -184,6 +192,7 @@ pub fn default(context: &SkiaSharedContext) -> Self {
         Self {
             maybe_window_adapter: Default::default(),
             rendering_notifier: Default::default(),
+            canvas_rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
@@ -195,6 +204,7 @@ pub fn default(context: &SkiaSharedContext) -> Self {
             dirty_region_debug_mode: Default::default(),
             dirty_region_history: Default::default(),
             shared_context: context.clone(),
+            texture_memory_bytes: Default::default(),
         }
     }
 
@@ -204,6 +214,7 @@ pub fn default_software(context: &SkiaSharedContext) -> Self {
         Self {
             maybe_window_adapter: Default::default(),
             rendering_notifier: Default::default(),
+            canvas_rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
@@ -228,6 +239,7 @@ pub fn default_software(context: &SkiaSharedContext) -> Self {
             dirty_region_debug_mode: Default::default(),
             dirty_region_history: Default::default(),
             shared_context: context.clone(),
+            texture_memory_bytes: Default::default(),
         }
     }
 
@@ -237,6 +249,7 @@ pub fn default_opengl(context: &SkiaSharedContext) -> Self {
         Self {
             maybe_window_adapter: Default::default(),
             rendering_notifier: Default::default(),
+            canvas_rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
@@ -261,6 +274,7 @@ pub fn default_opengl(context: &SkiaSharedContext) -> Self {
             dirty_region_debug_mode: Default::default(),
             dirty_region_history: Default::default(),
             shared_context: context.clone(),
+            texture_memory_bytes: Default::default(),
         }
     }
 
@@ -270,6 +284,7 @@ pub fn default_metal(context: &SkiaSharedContext) -> Self {
         Self {
             maybe_window_adapter: Default::default(),
             rendering_notifier: Default::default(),
+            canvas_rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
@@ -294,6 +309,7 @@ pub fn default_metal(context: &SkiaSharedContext) -> Self {
             dirty_region_debug_mode: Default::default(),
             dirty_region_history: Default::default(),
             shared_context: context.clone(),
+            texture_memory_bytes: Default::default(),
         }
     }
 
@@ -303,6 +319,7 @@ pub fn default_vulkan(context: &SkiaSharedContext) -> Self {
         Self {
             maybe_window_adapter: Default::default(),
             rendering_notifier: Default::default(),
+            canvas_rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
@@ -327,6 +344,7 @@ pub fn default_vulkan(context: &SkiaSharedContext) -> Self {
             dirty_region_debug_mode: Default::default(),
             dirty_region_history: Default::default(),
             shared_context: context.clone(),
+            texture_memory_bytes: Default::default(),
         }
     }
 
@@ -336,6 +354,7 @@ pub fn default_direct3d(context: &SkiaSharedContext) -> Self {
         Self {
             maybe_window_adapter: Default::default(),
             rendering_notifier: Default::default(),
+            canvas_rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
@@ -360,6 +379,7 @@ pub fn default_direct3d(context: &SkiaSharedContext) -> Self {
             dirty_region_debug_mode: Default::default(),
             dirty_region_history: Default::default(),
             shared_context: context.clone(),
+            texture_memory_bytes: Default::default(),
         }
     }
 
@@ -369,6 +389,7 @@ pub fn default_wgpu_26(context: &SkiaSharedContext) -> Self {
         Self {
             maybe_window_adapter: Default::default(),
             rendering_notifier: Default::default(),
+            canvas_rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
@@ -393,6 +414,7 @@ pub fn default_wgpu_26(context: &SkiaSharedContext) -> Self {
             dirty_region_debug_mode: Default::default(),
             dirty_region_history: Default::default(),
             shared_context: context.clone(),
+            texture_memory_bytes: Default::default(),
         }
     }
 
@@ -402,6 +424,7 @@ pub fn default_wgpu_27(context: &SkiaSharedContext) -> Self {
         Self {
             maybe_window_adapter: Default::default(),
             rendering_notifier: Default::default(),
+            canvas_rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
@@ -426,6 +449,7 @@ pub fn default_wgpu_27(context: &SkiaSharedContext) -> Self {
             dirty_region_debug_mode: Default::default(),
             dirty_region_history: Default::default(),
             shared_context: context.clone(),
+            texture_memory_bytes: Default::default(),
         }
     }
 
@@ -451,6 +475,7 @@ pub fn new_with_surface(
         Self {
             maybe_window_adapter: Default::default(),
             rendering_notifier: Default::default(),
+            canvas_rendering_notifier: Default::default(),
             image_cache: Default::default(),
             path_cache: Default::default(),
             rendering_metrics_collector: Default::default(),
@@ -464,6 +489,7 @@ pub fn new_with_surface(
             dirty_region_debug_mode: Default::default(),
             dirty_region_history: Default::default(),
             shared_context: context.clone(),
+            texture_memory_bytes: Default::default(),
         }
     }
 
@@ -588,7 +614,7 @@ fn render_to_canvas(
         skia_canvas: &skia_safe::Canvas,
         rotation_angle_degrees: f32,
         translation: (f32, f32),
-        gr_context: Option<&mut skia_safe::gpu::DirectContext>,
+        mut gr_context: Option<&mut skia_safe::gpu::DirectContext>,
         back_buffer_age: u8,
         surface: Option<&dyn Surface>,
         window: &i_slint_core::api::Window,
@@ -603,7 +629,7 @@ fn render_to_canvas(
             .draw_contents(|components| {
                 self.render_components_to_canvas(
                     skia_canvas,
-                    gr_context,
+                    gr_context.as_deref_mut(),
                     back_buffer_age,
                     surface,
                     window,
@@ -614,6 +640,10 @@ fn render_to_canvas(
             .unwrap_or_default();
 
         if let Some(callback) = self.rendering_notifier.borrow_mut().as_mut() {
+            // Submit Slint's rendering before the callback submits its overlay to the same queue.
+            if let Some(ctx) = gr_context.as_mut() {
+                ctx.flush_and_submit();
+            }
             if let Some(surface) = surface {
                 surface.with_graphics_api(&mut |api| {
                     callback.notify(RenderingState::AfterRendering, &api)
@@ -743,9 +773,10 @@ fn render_components_to_canvas(
             if let Some(callback) = self.rendering_notifier.borrow_mut().as_mut() {
                 // For the BeforeRendering rendering notifier callback it's important that this happens *after* clearing
                 // the back buffer, in order to allow the callback to provide its own rendering of the background.
-                // Skia's clear() will merely schedule a clear call, so flush right away to make it immediate.
+                // Skia's clear() will merely schedule a clear call, so flush and submit right away to make it
+                // immediate, also for callbacks that submit to a WGPU queue.
                 if let Some(ctx) = gr_context.as_mut() {
-                    ctx.flush(None);
+                    ctx.flush_and_submit();
                 }
 
                 if let Some(surface) = surface {
@@ -755,6 +786,12 @@ fn render_components_to_canvas(
                 }
             }
 
+            self.notify_canvas_rendering_notifier(
+                RenderingState::BeforeRendering,
+                skia_canvas,
+                window,
+            );
+
             for (component, origin) in components {
                 if let Some(component) = ItemTreeWeak::upgrade(component) {
                     i_slint_core::item_rendering::render_component_items(
@@ -766,6 +803,12 @@ fn render_components_to_canvas(
                 }
             }
 
+            self.notify_canvas_rendering_notifier(
+                RenderingState::AfterRendering,
+                skia_canvas,
+                window,
+            );
+
             if let Some(path) = dirty_region_to_visualize {
                 let mut paint = skia_safe::Paint::new(
                     skia_safe::Color4f { a: 0.5, r: 1.0, g: 0., b: 0. },
@@ -775,8 +818,17 @@ fn render_components_to_canvas(
                 skia_canvas.draw_path(&path, &paint);
             }
 
+            self.texture_memory_bytes
+                .set(gr_context.as_deref().map(|ctx| ctx.resource_cache_usage().resource_bytes));
+
             if let Some(collector) = &self.rendering_metrics_collector.borrow_mut().as_ref() {
-                collector.measure_frame_rendered(item_renderer, Default::default());
+                let metrics = RenderingMetrics {
+                    cache_statistics: Some(
+                        i_slint_core::renderer::RendererSealed::collect_cache_statistics(self),
+                    ),
+                    ..Default::default()
+                };
+                collector.measure_frame_rendered(item_renderer, metrics);
                 if collector.refresh_mode()
                     == i_slint_core::graphics::rendering_metrics_collector::RefreshMode::FullSpeed
                 {
@@ -810,9 +862,55 @@ pub fn set_pre_present_callback(&self, callback: Option<Box<dyn FnMut()>>) {
         *self.pre_present_callback.borrow_mut() = callback;
     }
 
+    /// Sets the specified callback, that's invoked with the Skia canvas that the scene is rendered into.
+    ///
+    /// The callback is invoked with [`RenderingState::BeforeRendering`] after the window background was
+    /// cleared and before any Slint items are drawn, so that it can draw an underlay, and with
+    /// [`RenderingState::AfterRendering`] after all items were drawn, so that it can draw an overlay.
+    /// Other rendering states are not reported.
+    ///
+    /// The canvas is in physical pixel coordinates, with the window's rotation applied and clipped
+    /// to the window. The last parameter is the window's scale factor; call `canvas.scale()` with it
+    /// to draw in logical coordinates. Changes to the canvas state made by the callback are reverted
+    /// after it returns.
+    ///
+    /// Partial rendering is disabled while a callback is set, since Slint can't know which areas
+    /// the callback paints to. Call [`Window::request_redraw()`](i_slint_core::api::Window::request_redraw)
+    /// when the custom content changes. The callback may call this function to replace or remove itself.
+    pub fn set_canvas_rendering_notifier(
+        &self,
+        callback: Option<Box<dyn FnMut(RenderingState, &skia_safe::Canvas, f32)>>,
+    ) {
+        *self.canvas_rendering_notifier.borrow_mut() =
+            callback.map(|callback| Rc::new(RefCell::new(callback)));
+    }
+
+    fn notify_canvas_rendering_notifier(
+        &self,
+        state: RenderingState,
+        skia_canvas: &skia_safe::Canvas,
+        window: &i_slint_core::api::Window,
+    ) {
+        // Don't hold the borrow during the call, as the callback may set a new callback.
+        let Some(callback) = self.canvas_rendering_notifier.borrow().clone() else {
+            return;
+        };
+        let size = window.size();
+        skia_canvas.save();
+        skia_canvas.clip_rect(
+            skia_safe::Rect::from_wh(size.width as f32, size.height as f32),
+            None,
+            false,
+        );
+        (callback.borrow_mut())(state, skia_canvas, window.scale_factor());
+        skia_canvas.restore();
+    }
+
     fn partial_rendering_state(&self) -> Option<&PartialRenderingState> {
         // We don't know where the application might render to, so disable partial rendering.
-        if self.rendering_notifier.borrow().is_some() {
+        if self.rendering_notifier.borrow().is_some()
+            || self.canvas_rendering_notifier.borrow().is_some()
+        {
             None
         } else {
             self.partial_rendering_state.as_ref()
@@ -855,6 +953,17 @@ fn text_input_byte_offset_for_position(
         )
     }
 
+    fn text_byte_offset_for_position(
+        &self,
+        text: std::pin::Pin<&dyn i_slint_core::item_rendering::RenderText>,
+        size: LogicalSize,
+        pos: LogicalPoint,
+        font_request: FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> usize {
+        sharedparley::text_byte_offset_for_position(text, size, pos, font_request, scale_factor)
+    }
+
     fn text_input_cursor_rect_for_byte_offset(
         &self,
         text_input: std::pin::Pin<&i_slint_core::items::TextInput>,
@@ -975,6 +1084,19 @@ fn mark_dirty_region(&self, region: DirtyRegion) {
     fn supports_transformations(&self) -> bool {
         true
     }
+
+    fn collect_cache_statistics(&self) -> i_slint_core::renderer::CacheStatistics {
+        let mut statistics = i_slint_core::renderer::CacheStatistics::shared();
+        let glyph_cache_bytes = skia_safe::graphics::font_cache_used();
+        let glyph_cache_limit = skia_safe::graphics::font_cache_limit();
+        statistics.glyph_cache_bytes = Some(glyph_cache_bytes);
+        if glyph_cache_limit > 0 {
+            statistics.glyph_cache_occupancy =
+                Some(glyph_cache_bytes as f32 / glyph_cache_limit as f32);
+        }
+        statistics.texture_memory_bytes = self.texture_memory_bytes.get();
+        statistics
+    }
 }
 
 impl Drop for SkiaRenderer {
@@ -1042,6 +1164,14 @@ fn import_opengl_texture(
         None
     }
 
+    fn import_external_texture(
+        &self,
+        _canvas: &skia_safe::Canvas,
+        _texture: &i_slint_core::graphics::BorrowedExternalTexture,
+    ) -> Option<skia_safe::Image> {
+        None
+    }
+
     #[cfg(any(feature = "unstable-wgpu-26", feature = "unstable-wgpu-27"))]
     fn import_wgpu_texture(
         &self,
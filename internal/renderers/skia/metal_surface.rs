@@ -7,7 +7,9 @@
 use objc2::rc::autoreleasepool;
 use objc2::{rc::Retained, runtime::ProtocolObject};
 use objc2_core_foundation::CGSize;
-use objc2_metal::{MTLCommandBuffer, MTLCommandQueue, MTLDevice, MTLPixelFormat, MTLTexture};
+use objc2_metal::{
+    MTLCommandBuffer, MTLCommandQueue, MTLDevice, MTLPixelFormat, MTLTexture, MTLTextureDescriptor,
+};
 use objc2_quartz_core::{CAMetalDrawable, CAMetalLayer};
 
 use skia_safe::gpu::mtl;
@@ -265,4 +267,52 @@ fn bits_per_pixel(&self) -> Result<u8, i_slint_core::platform::PlatformError> {
             }
         })
     }
+
+    fn import_external_texture(
+        &self,
+        canvas: &skia_safe::Canvas,
+        texture: &i_slint_core::graphics::BorrowedExternalTexture,
+    ) -> Option<skia_safe::Image> {
+        let i_slint_core::graphics::ExternalTextureHandle::IOSurface(io_surface) = texture.handle
+        else {
+            return None;
+        };
+        // SAFETY: The builder requires the pointer to be a valid `IOSurfaceRef`.
+        let io_surface: &objc2_io_surface::IOSurfaceRef =
+            unsafe { &*(io_surface as *const objc2_io_surface::IOSurfaceRef) };
+
+        let metal_texture = unsafe {
+            let descriptor =
+                MTLTextureDescriptor::texture2DDescriptorWithPixelFormat_width_height_mipmapped(
+                    MTLPixelFormat::BGRA8Unorm,
+                    texture.size.width as usize,
+                    texture.size.height as usize,
+                    false,
+                );
+            self.command_queue.device().newTextureWithDescriptor_iosurface_plane(
+                &descriptor,
+                io_surface,
+                0,
+            )
+        }?;
+
+        unsafe {
+            let texture_info =
+                mtl::TextureInfo::new(Retained::as_ptr(&metal_texture) as mtl::Handle);
+            let backend_texture = skia_safe::gpu::backend_textures::make_mtl(
+                (texture.size.width as i32, texture.size.height as i32),
+                skia_safe::gpu::Mipmapped::No,
+                &texture_info,
+                "Borrowed IOSurface",
+            );
+            skia_safe::image::Image::from_texture(
+                canvas.recording_context().as_mut().unwrap(),
+                &backend_texture,
+                skia_safe::gpu::SurfaceOrigin::TopLeft,
+                skia_safe::ColorType::BGRA8888,
+                skia_safe::AlphaType::Premul,
+                None,
+            )
+        }
+    }
 }
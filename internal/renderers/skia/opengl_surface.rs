@@ -25,6 +25,9 @@ pub struct OpenGLSurface {
     gr_context: RefCell<skia_safe::gpu::DirectContext>,
     glutin_context: glutin::context::PossiblyCurrentContext,
     glutin_surface: glutin::surface::Surface<glutin::surface::WindowSurface>,
+    gl: glow::Context,
+    /// Textures created when importing external textures, deleted once the frame is rendered.
+    imported_textures: RefCell<Vec<glow::NativeTexture>>,
 }
 
 impl super::Surface for OpenGLSurface {
@@ -108,6 +111,10 @@ fn render(
         );
         skia_canvas.restore();
 
+        for texture in self.imported_textures.borrow_mut().drain(..) {
+            unsafe { glow::HasContext::delete_texture(&self.gl, texture) };
+        }
+
         if let Some(pre_present_callback) = pre_present_callback.borrow_mut().as_mut() {
             pre_present_callback();
         }
@@ -180,6 +187,157 @@ fn import_opengl_texture(
             )
         }
     }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn import_external_texture(
+        &self,
+        canvas: &skia_safe::Canvas,
+        texture: &i_slint_core::graphics::BorrowedExternalTexture,
+    ) -> Option<skia_safe::Image> {
+        use glow::HasContext;
+        use glutin::display::{AsRawDisplay, RawDisplay};
+        use std::ffi::c_void;
+
+        const EGL_NONE: i32 = 0x3038;
+        const EGL_WIDTH: i32 = 0x3057;
+        const EGL_HEIGHT: i32 = 0x3056;
+        const EGL_LINUX_DMA_BUF_EXT: u32 = 0x3270;
+        const EGL_LINUX_DRM_FOURCC_EXT: i32 = 0x3271;
+        const EGL_DMA_BUF_PLANE0_FD_EXT: i32 = 0x3272;
+        const EGL_DMA_BUF_PLANE0_OFFSET_EXT: i32 = 0x3273;
+        const EGL_DMA_BUF_PLANE0_PITCH_EXT: i32 = 0x3274;
+        const EGL_DMA_BUF_PLANE0_MODIFIER_LO_EXT: i32 = 0x3443;
+        const EGL_DMA_BUF_PLANE0_MODIFIER_HI_EXT: i32 = 0x3444;
+        const GL_TEXTURE_EXTERNAL_OES: u32 = 0x8D65;
+        const DRM_FORMAT_MOD_INVALID: u64 = 0x00ff_ffff_ffff_ffff;
+
+        type CreateImageFn = unsafe extern "system" fn(
+            *const c_void,
+            *const c_void,
+            u32,
+            *const c_void,
+            *const i32,
+        ) -> *const c_void;
+        type DestroyImageFn = unsafe extern "system" fn(*const c_void, *const c_void) -> u32;
+        type ImageTargetTextureFn = unsafe extern "system" fn(u32, *const c_void);
+
+        let i_slint_core::graphics::ExternalTextureHandle::DmaBuf {
+            fd,
+            fourcc,
+            modifier,
+            offset,
+            stride,
+        } = texture.handle
+        else {
+            return None;
+        };
+
+        let display = self.glutin_context.display();
+        let RawDisplay::Egl(egl_display) = display.raw_display() else {
+            return None;
+        };
+
+        let (create_image, destroy_image, image_target_texture) = unsafe {
+            let create_image = display.get_proc_address(c"eglCreateImageKHR");
+            let destroy_image = display.get_proc_address(c"eglDestroyImageKHR");
+            let image_target_texture = display.get_proc_address(c"glEGLImageTargetTexture2DOES");
+            if create_image.is_null() || destroy_image.is_null() || image_target_texture.is_null() {
+                return None;
+            }
+            (
+                std::mem::transmute::<*const c_void, CreateImageFn>(create_image),
+                std::mem::transmute::<*const c_void, DestroyImageFn>(destroy_image),
+                std::mem::transmute::<*const c_void, ImageTargetTextureFn>(image_target_texture),
+            )
+        };
+
+        let mut attributes = vec![
+            EGL_WIDTH,
+            texture.size.width as i32,
+            EGL_HEIGHT,
+            texture.size.height as i32,
+            EGL_LINUX_DRM_FOURCC_EXT,
+            fourcc as i32,
+            EGL_DMA_BUF_PLANE0_FD_EXT,
+            fd,
+            EGL_DMA_BUF_PLANE0_OFFSET_EXT,
+            offset as i32,
+            EGL_DMA_BUF_PLANE0_PITCH_EXT,
+            stride as i32,
+        ];
+        if modifier != DRM_FORMAT_MOD_INVALID {
+            attributes.extend([
+                EGL_DMA_BUF_PLANE0_MODIFIER_LO_EXT,
+                modifier as u32 as i32,
+                EGL_DMA_BUF_PLANE0_MODIFIER_HI_EXT,
+                (modifier >> 32) as u32 as i32,
+            ]);
+        }
+        attributes.push(EGL_NONE);
+
+        let texture_id = unsafe {
+            let egl_image = create_image(
+                egl_display,
+                std::ptr::null(),
+                EGL_LINUX_DMA_BUF_EXT,
+                std::ptr::null(),
+                attributes.as_ptr(),
+            );
+            if egl_image.is_null() {
+                return None;
+            }
+
+            let texture_id = self.gl.create_texture().ok();
+            if let Some(texture_id) = texture_id {
+                // External textures are sampled through GL_TEXTURE_EXTERNAL_OES, which lets the driver
+                // convert formats such as YUV, and have no mipmaps.
+                self.gl.bind_texture(GL_TEXTURE_EXTERNAL_OES, Some(texture_id));
+                self.gl.tex_parameter_i32(
+                    GL_TEXTURE_EXTERNAL_OES,
+                    glow::TEXTURE_MIN_FILTER,
+                    glow::LINEAR as i32,
+                );
+                self.gl.tex_parameter_i32(
+                    GL_TEXTURE_EXTERNAL_OES,
+                    glow::TEXTURE_MAG_FILTER,
+                    glow::LINEAR as i32,
+                );
+                image_target_texture(GL_TEXTURE_EXTERNAL_OES, egl_image);
+                self.gl.bind_texture(GL_TEXTURE_EXTERNAL_OES, None);
+            }
+            // The texture keeps the buffer alive
+            destroy_image(egl_display, egl_image);
+            texture_id?
+        };
+        self.imported_textures.borrow_mut().push(texture_id);
+
+        // Skia caches the GL state, which was changed behind its back.
+        if let Some(mut direct_context) = canvas.direct_context() {
+            direct_context.reset(None);
+        }
+
+        unsafe {
+            let mut texture_info = skia_safe::gpu::gl::TextureInfo::from_target_and_id(
+                GL_TEXTURE_EXTERNAL_OES,
+                texture_id.0.get(),
+            );
+            texture_info.format = glow::RGBA8;
+            let backend_texture = skia_safe::gpu::backend_textures::make_gl(
+                (texture.size.width as _, texture.size.height as _),
+                skia_safe::gpu::Mipmapped::No,
+                texture_info,
+                "Borrowed external texture",
+            );
+            skia_safe::image::Image::from_texture(
+                canvas.recording_context().as_mut().unwrap(),
+                &backend_texture,
+                skia_safe::gpu::SurfaceOrigin::TopLeft,
+                skia_safe::ColorType::RGBA8888,
+                skia_safe::AlphaType::Premul,
+                None,
+            )
+        }
+    }
 }
 
 impl OpenGLSurface {
@@ -217,14 +375,15 @@ pub fn new_with_config(
 
         glutin_surface.resize(&current_glutin_context, width, height);
 
+        let gl = unsafe {
+            glow::Context::from_loader_function_cstr(|name| {
+                current_glutin_context.display().get_proc_address(name) as *const _
+            })
+        };
+
         let fb_info = {
             use glow::HasContext;
 
-            let gl = unsafe {
-                glow::Context::from_loader_function_cstr(|name| {
-                    current_glutin_context.display().get_proc_address(name) as *const _
-                })
-            };
             let fboid = unsafe { gl.get_parameter_i32(glow::FRAMEBUFFER_BINDING) };
 
             skia_safe::gpu::gl::FramebufferInfo {
@@ -273,6 +432,8 @@ pub fn new_with_config(
             gr_context: RefCell::new(gr_context),
             glutin_context: current_glutin_context,
             glutin_surface,
+            gl,
+            imported_textures: Default::default(),
         })
     }
 
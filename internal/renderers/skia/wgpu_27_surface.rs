@@ -30,6 +30,8 @@ pub struct WGPUSurface {
     surface_config: RefCell<wgpu::SurfaceConfiguration>,
     surface: wgpu::Surface<'static>,
     textures_to_transition_for_sampling: RefCell<Vec<wgpu::Texture>>,
+    /// The texture of the frame that is being rendered, for compositing in the rendering notifier
+    current_surface_texture: RefCell<Option<wgpu::Texture>>,
     backend: Backend,
 }
 
@@ -82,6 +84,7 @@ fn new(
             surface_config: surface_config.into(),
             surface,
             textures_to_transition_for_sampling: RefCell::new(Vec::new()),
+            current_surface_texture: Default::default(),
             backend,
         })
     }
@@ -130,6 +133,8 @@ fn render(
         let mut skia_surface = skia_surface
             .ok_or_else(|| PlatformError::from("Failed to create Skia surface from WGPU"))?;
 
+        *self.current_surface_texture.borrow_mut() = Some(frame.texture.clone());
+
         callback(skia_surface.canvas(), Some(gr_context), 0);
 
         let textures_to_transition = self.textures_to_transition_for_sampling.take();
@@ -155,6 +160,7 @@ fn render(
             pre_present_callback();
         }
 
+        self.current_surface_texture.borrow_mut().take();
         frame.present();
 
         Ok(())
@@ -175,6 +181,7 @@ fn with_graphics_api(&self, callback: &mut dyn FnMut(GraphicsAPI<'_>)) {
             self.instance.clone(),
             self.device.clone(),
             self.queue.clone(),
+            self.current_surface_texture.borrow().clone(),
         );
         callback(api)
     }
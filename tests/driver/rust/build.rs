@@ -98,6 +98,11 @@ fn generate_macro(
         output.write_all(b"#[test] #[ignore = \"string template don't work in macros\"] fn ignored_because_string_template() {{}}")?;
         return Ok(false);
     }
+    if source.contains("//c-abi") {
+        // The test functions call the C ABI entry points, which the macro doesn't generate
+        output.write_all(b"#[test] #[ignore = \"the C ABI is only generated at build time\"] fn ignored_because_c_abi() {{}}")?;
+        return Ok(false);
+    }
     // to silence all the warnings in .slint files that would be turned into errors
     output.write_all(b"#![allow(deprecated)]")?;
     let include_paths = test_driver_lib::extract_include_paths(source);
@@ -173,6 +178,7 @@ fn generate_source(
         compiler_config.translation_domain =
             Some(testcase.absolute_path.file_stem().unwrap().to_str().unwrap().to_string());
     }
+    compiler_config.generate_c_abi = source.contains("//c-abi");
     let (root_component, diag, loader) =
         spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
 
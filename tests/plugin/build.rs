@@ -0,0 +1,7 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+fn main() {
+    let config = slint_build::CompilerConfiguration::new().with_c_abi(true);
+    slint_build::compile_with_config("plugin.slint", config).unwrap();
+}
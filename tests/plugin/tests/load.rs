@@ -0,0 +1,98 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Load the library built from plugin.slint with slint::plugin, like a host application would.
+
+use slint::platform::{PointerEventButton, WindowEvent};
+use slint::plugin::{Plugin, PluginError, SetPropertyError, Value, ValueType};
+use slint::{Color, LogicalPosition, PhysicalSize, Rgba8Pixel};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn load_plugin() -> Plugin {
+    // The test executable is in target/<profile>/deps, next to the cdylib
+    let exe = std::env::current_exe().unwrap();
+    let name =
+        format!("{}test_plugin{}", std::env::consts::DLL_PREFIX, std::env::consts::DLL_SUFFIX);
+    let path = exe
+        .ancestors()
+        .skip(1)
+        .take(2)
+        .map(|dir| dir.join(&name))
+        .find(|path| path.exists())
+        .unwrap_or_else(|| panic!("{name} was not built next to {}", exe.display()));
+    // Safety: the library is built from this crate with the C ABI generation mode
+    unsafe { slint::plugin::load(path) }.unwrap()
+}
+
+// The plugin can only be embedded once per process, so everything is tested in one function.
+#[test]
+fn load_and_embed() {
+    let plugin = load_plugin();
+
+    let panel = plugin.component("Panel").unwrap();
+    assert_eq!(plugin.components().len(), 1);
+    let fill = panel.properties().iter().find(|p| p.name() == "fill").unwrap();
+    assert_eq!(fill.value_type(), ValueType::Color);
+    assert!(panel.properties().iter().find(|p| p.name() == "reply").unwrap().is_read_only());
+    assert_eq!(panel.callbacks()[0].args(), &[ValueType::Number]);
+    assert_eq!(panel.callbacks()[0].return_type(), ValueType::String);
+    assert_eq!(panel.functions()[0].name(), "double");
+    assert!(matches!(plugin.create("Unknown"), Err(PluginError::NoSuchComponent(_))));
+
+    plugin.enable_embedding().unwrap();
+    assert!(matches!(plugin.enable_embedding(), Err(PluginError::EmbeddingUnsupported)));
+
+    let component = plugin.create("Panel").unwrap();
+
+    assert_eq!(component.invoke("double", &[Value::Number(21.)]), Some(Value::Number(42.)));
+    assert_eq!(component.invoke("double", &[]), None);
+    assert_eq!(component.set_property("reply", "x".into()), Err(SetPropertyError::NoSuchProperty));
+    assert_eq!(component.set_property("clicks", true.into()), Err(SetPropertyError::WrongType));
+
+    let pixel = |frame: &slint::SharedPixelBuffer<Rgba8Pixel>, x: u32, y: u32| {
+        frame.as_slice()[(y * frame.width() + x) as usize]
+    };
+
+    let frame = component.render(PhysicalSize::new(20, 10), 1.).unwrap();
+    assert_eq!((frame.width(), frame.height()), (20, 10));
+    assert_eq!(pixel(&frame, 0, 0), Rgba8Pixel::new(0xff, 0, 0, 0xff));
+    assert_eq!(pixel(&frame, 19, 9), Rgba8Pixel::new(0xff, 0, 0, 0xff));
+
+    component.set_property("fill", Color::from_rgb_u8(0, 0, 0xff).into()).unwrap();
+    let frame = component.render(PhysicalSize::new(40, 20), 2.).unwrap();
+    assert_eq!((frame.width(), frame.height()), (40, 20));
+    assert_eq!(pixel(&frame, 39, 19), Rgba8Pixel::new(0, 0, 0xff, 0xff));
+
+    let calls = Rc::new(RefCell::new(Vec::new()));
+    assert!(component.on_callback("clicked", {
+        let calls = calls.clone();
+        move |args| {
+            calls.borrow_mut().push(args.to_vec());
+            Value::String("thanks".into())
+        }
+    }));
+    assert!(!component.on_callback("unknown", |_| Value::Void));
+
+    // 2x scale factor: the logical size of the component is 20x10
+    let position = LogicalPosition::new(10., 5.);
+    assert!(component.dispatch_event(&WindowEvent::PointerMoved { position }));
+    assert!(component.dispatch_event(&WindowEvent::PointerPressed {
+        position,
+        button: PointerEventButton::Left
+    }));
+    assert!(component.dispatch_event(&WindowEvent::PointerReleased {
+        position,
+        button: PointerEventButton::Left
+    }));
+    plugin.update_timers_and_animations();
+
+    assert_eq!(*calls.borrow(), vec![vec![Value::Number(1.)]]);
+    assert_eq!(component.get_property("clicks"), Some(Value::Number(1.)));
+    assert_eq!(component.get_property("reply"), Some(Value::String("thanks".into())));
+
+    assert!(component.dispatch_event(&WindowEvent::KeyPressed { text: "a".into() }));
+    assert!(!component.dispatch_event(&WindowEvent::WindowActiveChanged(true)));
+
+    assert!(plugin.run_event_loop().is_err());
+}
@@ -153,6 +153,8 @@ fn main() -> std::io::Result<()> {
 
     testing::assert_with_render_by_line(screenshot, window.clone(), &options);
 
+    testing::assert_with_render_parallel(screenshot, window.clone(), &options);
+
     Ok(())
     }}",
         )?;
@@ -307,6 +307,27 @@ pub fn assert_with_render_by_line(
     }
 }
 
+pub fn assert_with_render_parallel(
+    path: &str,
+    window: Rc<MinimalSoftwareWindow>,
+    options: &TestCaseOptions,
+) {
+    let s = window.size();
+    let mut rendering = SharedPixelBuffer::<Rgb8Pixel>::new(s.width, s.height);
+
+    window.request_redraw();
+    window.draw_if_needed(|renderer| {
+        renderer.mark_dirty_region(
+            LogicalRect::from_size(euclid::size2(s.width as f32, s.height as f32)).into(),
+        );
+        let stride = rendering.width() as usize;
+        renderer.render_parallel(rendering.make_mut_slice(), stride);
+    });
+    if let Err(reason) = compare_images(path, &rendering, RenderingRotation::NoRotation, options) {
+        panic!("Image comparison failure for parallel rendering for {path}: {reason}");
+    }
+}
+
 pub fn screenshot_render_by_line(
     window: Rc<MinimalSoftwareWindow>,
     region: Option<IntRect>,
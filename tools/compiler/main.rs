@@ -57,6 +57,11 @@ struct Cli {
     #[arg(short = 'L', name = "library path", number_of_values = 1)]
     library_paths: Vec<String>,
 
+    /// Define additional palettes for the widgets in the format `<brand>=<path>`.
+    /// The path is a .slint file that exports a `Palette` global with the colors of the brand.
+    #[arg(long = "brand-palette", name = "brand palette", number_of_values = 1)]
+    brand_palettes: Vec<String>,
+
     /// Specify the path to the main .slint file to compile.
     /// Use '-' to read from stdin.
     #[arg(name = "file")]
@@ -80,6 +85,12 @@ struct Cli {
     #[arg(long, name = "value", value_enum)]
     embed_resources: Option<Embedding>,
 
+    /// Compress the images embedded for the software renderer with a run-length encoding.
+    /// The images are decoded line by line while rendering.
+    #[cfg(feature = "software-renderer")]
+    #[arg(long = "compress-textures")]
+    compress_textures: bool,
+
     /// Set the output file for the generated code.
     /// Use '-' to output to stdout.
     #[arg(short = 'o', name = "output file", default_value = "-")]
@@ -108,6 +119,13 @@ struct Cli {
     /// If specified multiple times, the definitions are split across multiple `.cpp` files.
     #[arg(long = "cpp-file", name = "output .cpp file", number_of_values = 1)]
     cpp_files: Vec<std::path::PathBuf>,
+
+    /// Generate `extern "C"` entry points for the exported components (Rust output only).
+    ///
+    /// The generated code can be built as a `cdylib` and loaded at runtime by a host
+    /// application, as described in `slint_component_abi.h`. This is experimental.
+    #[arg(long = "c-abi")]
+    c_abi: bool,
 }
 
 fn main() -> std::io::Result<()> {
@@ -174,12 +192,22 @@ fn main() -> std::io::Result<()> {
         };
     }
 
+    #[cfg(feature = "software-renderer")]
+    {
+        compiler_config.compress_embedded_textures = args.compress_textures;
+    }
+
     compiler_config.include_paths = args.include_paths;
     compiler_config.library_paths = args
         .library_paths
         .iter()
         .filter_map(|entry| entry.split('=').collect_tuple().map(|(k, v)| (k.into(), v.into())))
         .collect();
+    compiler_config.brand_palettes = args
+        .brand_palettes
+        .iter()
+        .filter_map(|entry| entry.split_once('=').map(|(k, v)| (k.into(), v.into())))
+        .collect();
     if let Some(style) = args.style {
         compiler_config.style = Some(style);
     }
@@ -189,6 +217,28 @@ fn main() -> std::io::Result<()> {
     if let Some(path) = args.bundle_translations {
         compiler_config.translation_path_bundle = Some(path);
     }
+    if args.c_abi {
+        if matches!(format, generator::OutputFormat::Rust) {
+            compiler_config.generate_c_abi = true;
+        } else {
+            eprintln!("--c-abi is only supported with the Rust output format - ignoring");
+        }
+    }
+    let project_config = project_config::ProjectConfiguration::find_and_load(&args.path);
+    let project_config_path = match project_config {
+        Ok(project_config) => project_config.map(|project_config| {
+            // The SLINT_STYLE environment variable has precedence over the style of the project
+            if compiler_config.style.is_none() {
+                compiler_config.style = std::env::var("SLINT_STYLE").ok();
+            }
+            project_config.apply(&mut compiler_config);
+            project_config.path
+        }),
+        Err(err) => {
+            diag.push_compiler_error(err);
+            None
+        }
+    };
     let syntax_node = syntax_node.expect("diags contained no compilation errors");
     let (doc, diag, loader) =
         spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
@@ -211,6 +261,9 @@ fn main() -> std::io::Result<()> {
                 write!(f, " {}", x.display())?;
             }
         }
+        if let Some(project_config_path) = &project_config_path {
+            write!(f, " {}", project_config_path.display())?;
+        }
         for resource in doc.embedded_file_resources.borrow().keys() {
             if !fileaccess::load_file(std::path::Path::new(resource))
                 .is_some_and(|f| f.is_builtin())
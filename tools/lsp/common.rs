@@ -537,6 +537,9 @@ pub struct PreviewConfig {
     pub style: String,
     pub include_paths: Vec<PathBuf>,
     pub library_paths: HashMap<String, PathBuf>,
+    /// The properties of the previewed component and of its globals, read from the JSON file
+    /// that is configured in `slint.preview.data`.
+    pub data: Option<serde_json::Value>,
 }
 
 /// The Component to preview
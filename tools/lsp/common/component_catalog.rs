@@ -13,8 +13,10 @@
 #[cfg(feature = "preview-engine")]
 fn builtin_component_info(name: &str) -> ComponentInformation {
     let is_layout = matches!(name, "GridLayout" | "HorizontalLayout" | "VerticalLayout");
-    let is_interactive =
-        matches!(name, "Flickable" | "FocusScope" | "SwipeGestureHandler" | "TouchArea");
+    let is_interactive = matches!(
+        name,
+        "Flickable" | "FocusScope" | "PinchGestureHandler" | "SwipeGestureHandler" | "TouchArea"
+    );
 
     let default_properties = match name {
         "Text" | "TextInput" => vec![PropertyChange::new("text", format!("\"{name}\""))],
@@ -23,7 +25,8 @@ fn builtin_component_info(name: &str) -> ComponentInformation {
     };
 
     let component = {
-        if ["Flickable", "SwipeGestureHandler", "TouchArea"].contains(&name) {
+        if ["Flickable", "PinchGestureHandler", "SwipeGestureHandler", "TouchArea"].contains(&name)
+        {
             "Gestures"
         } else if ["FocusScope", "TextInput"].contains(&name) {
             "Keyboard Input"
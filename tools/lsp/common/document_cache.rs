@@ -6,6 +6,7 @@
 use i_slint_compiler::diagnostics::{BuildDiagnostics, SourceFile};
 use i_slint_compiler::object_tree::Document;
 use i_slint_compiler::parser::{syntax_nodes, TextSize};
+use i_slint_compiler::project_config::ProjectConfiguration;
 use i_slint_compiler::typeloader::TypeLoader;
 use i_slint_compiler::typeregister::TypeRegister;
 use lsp_types::Url;
@@ -44,6 +45,8 @@ pub struct CompilerConfiguration {
     pub include_paths: Vec<std::path::PathBuf>,
     pub library_paths: HashMap<String, std::path::PathBuf>,
     pub style: Option<String>,
+    /// The `slint.toml` of the workspace, applied after the settings above
+    pub project_config: Option<ProjectConfiguration>,
     pub open_import_fallback: Option<OpenImportFallback>,
     pub resource_url_mapper:
         Option<Rc<dyn Fn(&str) -> Pin<Box<dyn Future<Output = Option<String>>>>>>,
@@ -57,6 +60,7 @@ fn default() -> Self {
             include_paths: std::mem::take(&mut cc.include_paths),
             library_paths: std::mem::take(&mut cc.library_paths),
             style: std::mem::take(&mut cc.style),
+            project_config: None,
             open_import_fallback: None,
             resource_url_mapper: std::mem::take(&mut cc.resource_url_mapper),
         }
@@ -64,20 +68,56 @@ fn default() -> Self {
 }
 
 impl CompilerConfiguration {
-    fn build(mut self) -> (i_slint_compiler::CompilerConfiguration, Option<OpenImportFallback>) {
+    fn build(
+        mut self,
+    ) -> (i_slint_compiler::CompilerConfiguration, Settings, Option<OpenImportFallback>) {
+        let settings = Settings {
+            style: std::mem::take(&mut self.style),
+            include_paths: std::mem::take(&mut self.include_paths),
+            library_paths: std::mem::take(&mut self.library_paths),
+            project_config: std::mem::take(&mut self.project_config),
+        };
         let mut result = default_cc();
-        result.include_paths = std::mem::take(&mut self.include_paths);
-        result.library_paths = std::mem::take(&mut self.library_paths);
-        result.style = std::mem::take(&mut self.style);
         result.resource_url_mapper = std::mem::take(&mut self.resource_url_mapper);
+        settings.apply(&mut result);
 
-        (result, self.open_import_fallback)
+        (result, settings, self.open_import_fallback)
+    }
+}
+
+/// The settings configured explicitly, on the command line or in the editor, and the `slint.toml`
+/// of the workspace that completes them
+#[derive(Clone, Default)]
+struct Settings {
+    style: Option<String>,
+    include_paths: Vec<PathBuf>,
+    library_paths: HashMap<String, PathBuf>,
+    project_config: Option<ProjectConfiguration>,
+}
+
+impl Settings {
+    fn apply(&self, compiler_config: &mut i_slint_compiler::CompilerConfiguration) {
+        let default = default_cc();
+        compiler_config.style = self.style.clone();
+        compiler_config.include_paths = self.include_paths.clone();
+        compiler_config.library_paths = self.library_paths.clone();
+        // Undo the features and lints of a previous slint.toml
+        compiler_config.enable_experimental = default.enable_experimental;
+        compiler_config.accessibility = default.accessibility;
+        compiler_config.debug_info = default.debug_info;
+        compiler_config.deny_warnings = default.deny_warnings;
+        compiler_config.error_on_binding_loop_with_window_layout =
+            default.error_on_binding_loop_with_window_layout;
+        if let Some(project_config) = &self.project_config {
+            project_config.apply(compiler_config);
+        }
     }
 }
 
 /// A cache of loaded documents
 pub struct DocumentCache {
     type_loader: TypeLoader,
+    settings: Settings,
     open_import_fallback: Option<OpenImportFallback>,
     source_file_versions: Rc<RefCell<SourceFileVersionMap>>,
 }
@@ -129,7 +169,7 @@ fn wire_up_import_fallback(
     }
 
     pub fn new(config: CompilerConfiguration) -> Self {
-        let (mut compiler_config, open_import_fallback) = config.build();
+        let (mut compiler_config, settings, open_import_fallback) = config.build();
 
         let (open_import_fallback, source_file_versions) = Self::wire_up_import_fallback(
             &mut compiler_config,
@@ -143,6 +183,7 @@ pub fn new(config: CompilerConfiguration) -> Self {
                 compiler_config,
                 &mut BuildDiagnostics::default(),
             ),
+            settings,
             open_import_fallback,
             source_file_versions,
         }
@@ -159,15 +200,28 @@ pub fn new_from_raw_parts(
             source_file_versions,
         );
 
-        Self { type_loader, open_import_fallback, source_file_versions }
+        let settings = Settings {
+            style: type_loader.compiler_config.style.clone(),
+            include_paths: type_loader.compiler_config.include_paths.clone(),
+            library_paths: type_loader.compiler_config.library_paths.clone(),
+            project_config: None,
+        };
+        Self { type_loader, settings, open_import_fallback, source_file_versions }
+    }
+
+    /// Returns the callback that reads the files that aren't Slint documents open in the editor.
+    pub fn open_import_fallback(&self) -> Option<OpenImportFallback> {
+        self.open_import_fallback.clone()
     }
 
     pub fn snapshot(&self) -> Option<Self> {
         let open_import_fallback = self.open_import_fallback.clone();
         let source_file_versions =
             Rc::new(RefCell::new(self.source_file_versions.borrow().clone()));
-        i_slint_compiler::typeloader::snapshot(&self.type_loader)
-            .map(|tl| Self::new_from_raw_parts(tl, open_import_fallback, source_file_versions))
+        i_slint_compiler::typeloader::snapshot(&self.type_loader).map(|tl| Self {
+            settings: self.settings.clone(),
+            ..Self::new_from_raw_parts(tl, open_import_fallback, source_file_versions)
+        })
     }
 
     pub fn resolve_import_path(
@@ -284,25 +338,48 @@ pub async fn reconfigure(
 
         if let Some(s) = style {
             if s.is_empty() {
-                self.type_loader.compiler_config.style = None;
+                self.settings.style = None;
             } else {
-                self.type_loader.compiler_config.style = Some(s);
+                self.settings.style = Some(s);
             }
         }
 
         if let Some(ip) = include_paths {
-            self.type_loader.compiler_config.include_paths = ip;
+            self.settings.include_paths = ip;
         }
 
         if let Some(lp) = library_paths {
-            self.type_loader.compiler_config.library_paths = lp;
+            self.settings.library_paths = lp;
         }
 
+        self.apply_settings().await;
+
+        Ok(self.compiler_configuration())
+    }
+
+    /// Replaces the `slint.toml` of the workspace, for example after it was edited.
+    pub async fn set_project_configuration(
+        &mut self,
+        project_config: Option<ProjectConfiguration>,
+    ) -> CompilerConfiguration {
+        self.settings.project_config = project_config;
+        self.apply_settings().await;
+        self.compiler_configuration()
+    }
+
+    async fn apply_settings(&mut self) {
+        self.settings.apply(&mut self.type_loader.compiler_config);
+        // The type loader resolves the style when it's created
+        self.type_loader.resolved_style = TypeLoader::new(
+            TypeRegister::builtin(),
+            self.type_loader.compiler_config.clone(),
+            &mut BuildDiagnostics::default(),
+        )
+        .resolved_style;
+
         self.invalidate_everything();
 
         self.preload_builtins().await;
-
-        Ok(self.compiler_configuration())
     }
 
     pub async fn preload_builtins(&mut self) {
@@ -356,6 +433,7 @@ pub fn compiler_configuration(&self) -> CompilerConfiguration {
             include_paths: self.type_loader.compiler_config.include_paths.clone(),
             library_paths: self.type_loader.compiler_config.library_paths.clone(),
             style: self.type_loader.compiler_config.style.clone(),
+            project_config: None,       // Already applied to the settings above
             open_import_fallback: None, // We need to re-generate this anyway
             resource_url_mapper: self.type_loader.compiler_config.resource_url_mapper.clone(),
         }
@@ -499,4 +577,34 @@ fn test_element_at_position_child() {
         assert_eq!(base_type_at_position(&dc, &url, 28, 8), Some("Text".to_string()));
         assert_eq!(base_type_at_position(&dc, &url, 51, 4), Some("VerticalBox".to_string()));
     }
+
+    #[test]
+    fn test_project_configuration() {
+        let project_config = ProjectConfiguration::parse(
+            PathBuf::from("/project/slint.toml"),
+            "style = \"cupertino\"\ninclude-paths = [\"ui\"]\n[features]\nexperimental = true\n"
+                .into(),
+        )
+        .unwrap();
+        let mut dc = DocumentCache::new(CompilerConfiguration {
+            include_paths: vec![PathBuf::from("/explicit")],
+            project_config: Some(project_config),
+            ..Default::default()
+        });
+        let cc = dc.compiler_configuration();
+        assert_eq!(cc.style.as_deref(), Some("cupertino"));
+        assert_eq!(cc.include_paths, [PathBuf::from("/explicit"), PathBuf::from("/project/ui")]);
+        assert!(dc.type_loader.compiler_config.enable_experimental);
+
+        // The explicit settings have precedence
+        let cc = spin_on::spin_on(dc.reconfigure(Some("fluent".into()), None, None)).unwrap();
+        assert_eq!(cc.style.as_deref(), Some("fluent"));
+        assert_eq!(dc.type_loader.resolved_style, "fluent");
+        assert_eq!(cc.include_paths.len(), 2);
+
+        // Removing the slint.toml removes its settings
+        let cc = spin_on::spin_on(dc.set_project_configuration(None));
+        assert_eq!(cc.include_paths, [PathBuf::from("/explicit")]);
+        assert!(!dc.type_loader.compiler_config.enable_experimental);
+    }
 }
@@ -150,6 +150,8 @@ pub struct Context {
     pub to_show: RefCell<Option<common::PreviewComponent>>,
     /// File currently open in the editor
     pub open_urls: RefCell<HashSet<lsp_types::Url>>,
+    /// The JSON file with the data to show in the preview, from the `slint.preview.data` setting
+    pub preview_data_path: RefCell<Option<PathBuf>>,
     pub to_preview: Rc<dyn common::LspToPreview>,
 }
 
@@ -857,6 +859,30 @@ pub async fn trigger_file_watcher(
     url: lsp_types::Url,
     typ: lsp_types::FileChangeType,
 ) -> common::Result<()> {
+    let is_project_config = common::uri_to_file(&url).is_some_and(|path| {
+        path.file_name() == Some(i_slint_compiler::project_config::FILE_NAME.as_ref())
+    });
+    if is_project_config {
+        reload_project_configuration(ctx).await;
+        return Ok(());
+    }
+
+    let is_preview_data = ctx
+        .preview_data_path
+        .borrow()
+        .as_ref()
+        .is_some_and(|path| common::uri_to_file(&url).as_ref() == Some(path));
+    if is_preview_data {
+        // Send the new data without reloading the preview
+        let data = load_preview_data(ctx).await?;
+        let config = {
+            let mut config = ctx.preview_config.borrow_mut();
+            config.data = data;
+            config.clone()
+        };
+        ctx.to_preview.send(&common::LspToPreviewMessage::SetConfiguration { config });
+    }
+
     if !ctx.open_urls.borrow().contains(&url) {
         if typ == lsp_types::FileChangeType::DELETED {
             delete_document(ctx, url).await?;
@@ -867,6 +893,34 @@ pub async fn trigger_file_watcher(
     Ok(())
 }
 
+/// Reads the JSON file with the data to show in the preview. Errors are reported to the user,
+/// and the preview is shown without data.
+async fn load_preview_data(ctx: &Context) -> common::Result<Option<serde_json::Value>> {
+    let Some(path) = ctx.preview_data_path.borrow().clone() else {
+        return Ok(None);
+    };
+    let Some(open_import_fallback) = ctx.document_cache.borrow().open_import_fallback() else {
+        return Ok(None);
+    };
+    let result = match open_import_fallback(path.to_string_lossy().into()).await {
+        Some(Ok((_, contents))) => serde_json::from_str(&contents).map_err(|e| e.to_string()),
+        Some(Err(e)) => Err(e.to_string()),
+        None => Err("the file could not be read".into()),
+    };
+    match result {
+        Ok(data) => Ok(Some(data)),
+        Err(e) => {
+            ctx.server_notifier.send_notification::<lsp_types::notification::ShowMessage>(
+                lsp_types::ShowMessageParams {
+                    typ: lsp_types::MessageType::WARNING,
+                    message: format!("Cannot load the preview data from {}: {e}", path.display()),
+                },
+            )?;
+            Ok(None)
+        }
+    }
+}
+
 /// return the token, and the offset within the file
 fn token_descr(
     document_cache: &mut common::DocumentCache,
@@ -1386,11 +1440,12 @@ pub async fn load_configuration(ctx: &Context) -> common::Result<()> {
         )?
         .await?;
 
-    let (hide_ui, include_paths, library_paths, style) = {
+    let (hide_ui, include_paths, library_paths, style, data_path) = {
         let mut hide_ui = None;
         let mut include_paths = None;
         let mut library_paths = None;
         let mut style = None;
+        let mut data_path = None;
 
         for v in r {
             if let Some(o) = v.as_object() {
@@ -1419,11 +1474,29 @@ pub async fn load_configuration(ctx: &Context) -> common::Result<()> {
                     }
                 }
                 hide_ui = o.get("preview").and_then(|v| v.as_object()?.get("hide_ui")?.as_bool());
+                data_path = o
+                    .get("preview")
+                    .and_then(|v| v.as_object()?.get("data")?.as_str())
+                    .filter(|s| !s.is_empty())
+                    .map(PathBuf::from);
             }
         }
-        (hide_ui, include_paths, library_paths, style)
+        (hide_ui, include_paths, library_paths, style, data_path)
     };
 
+    // Relative paths are resolved against the workspace root
+    let workspace_root = ctx
+        .init_param
+        .workspace_folders
+        .as_ref()
+        .and_then(|folders| folders.first())
+        .and_then(|folder| common::uri_to_file(&folder.uri));
+    *ctx.preview_data_path.borrow_mut() = data_path.map(|path| match &workspace_root {
+        Some(root) if path.is_relative() => root.join(path),
+        _ => path,
+    });
+    let data = load_preview_data(ctx).await?;
+
     let document_cache = &mut ctx.document_cache.borrow_mut();
     let cc = document_cache.reconfigure(style, include_paths, library_paths).await?;
 
@@ -1432,7 +1505,52 @@ pub async fn load_configuration(ctx: &Context) -> common::Result<()> {
         style: cc.style.clone().unwrap_or_default(),
         include_paths: cc.include_paths.clone(),
         library_paths: cc.library_paths.clone(),
+        data,
+    };
+    reload_all_documents(ctx, document_cache, config).await;
+    Ok(())
+}
+
+/// Loads the slint.toml that applies to the first workspace folder, or to the current directory.
+pub fn load_project_configuration(
+    init_param: &InitializeParams,
+) -> Option<i_slint_compiler::project_config::ProjectConfiguration> {
+    let dir = init_param
+        .workspace_folders
+        .as_ref()
+        .and_then(|folders| folders.first())
+        .and_then(|folder| common::uri_to_file(&folder.uri))
+        .or_else(|| std::env::current_dir().ok())?;
+    i_slint_compiler::project_config::ProjectConfiguration::find_and_load(&dir).unwrap_or_else(
+        |err| {
+            eprintln!("Error loading the project configuration: {err}");
+            None
+        },
+    )
+}
+
+/// Reloads the slint.toml of the workspace after it was changed or deleted.
+async fn reload_project_configuration(ctx: &Context) {
+    let project_config = load_project_configuration(&ctx.init_param);
+    let document_cache = &mut ctx.document_cache.borrow_mut();
+    let cc = document_cache.set_project_configuration(project_config).await;
+
+    let config = {
+        let mut config = ctx.preview_config.borrow_mut();
+        config.style = cc.style.clone().unwrap_or_default();
+        config.include_paths = cc.include_paths.clone();
+        config.library_paths = cc.library_paths.clone();
+        config.clone()
     };
+    reload_all_documents(ctx, document_cache, config).await;
+}
+
+/// Reloads the documents and the preview after the compiler configuration changed.
+async fn reload_all_documents(
+    ctx: &Context,
+    document_cache: &mut common::DocumentCache,
+    config: common::PreviewConfig,
+) {
     *ctx.preview_config.borrow_mut() = config.clone();
     let mut diag = BuildDiagnostics::default();
     let all_urls = document_cache.all_urls().collect::<Vec<_>>();
@@ -1448,8 +1566,6 @@ pub async fn load_configuration(ctx: &Context) -> common::Result<()> {
         &all_urls.iter().filter_map(common::uri_to_file).collect(),
         diag,
     );
-
-    Ok(())
 }
 
 #[cfg(test)]
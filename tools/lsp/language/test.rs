@@ -169,6 +169,7 @@ fn accurate_diagnostics_in_dependencies() {
         #[cfg(any(feature = "preview-external", feature = "preview-engine"))]
         to_show: Default::default(),
         open_urls: RefCell::new(HashSet::from_iter([foo_url.clone(), bar_url.clone()])),
+        preview_data_path: Default::default(),
         to_preview: Rc::new(common::DummyLspToPreview::default()),
     }));
 
@@ -223,6 +224,7 @@ fn accurate_diagnostics_in_dependencies_with_parse_errors() {
         #[cfg(any(feature = "preview-external", feature = "preview-engine"))]
         to_show: Default::default(),
         open_urls: Default::default(),
+        preview_data_path: Default::default(),
         to_preview: Rc::new(common::DummyLspToPreview::default()),
     });
 
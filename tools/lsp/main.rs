@@ -327,14 +327,16 @@ fn main_loop(connection: Connection, init_param: InitializeParams, cli_args: Cli
     };
 
     let to_preview_clone = to_preview.clone();
+    // The command line arguments have precedence over the slint.toml of the workspace
     let compiler_config = CompilerConfiguration {
-        style: Some(if cli_args.style.is_empty() { "native".into() } else { cli_args.style }),
+        style: (!cli_args.style.is_empty()).then_some(cli_args.style),
         include_paths: cli_args.include_paths,
         library_paths: cli_args
             .library_paths
             .iter()
             .filter_map(|entry| entry.split('=').collect_tuple().map(|(k, v)| (k.into(), v.into())))
             .collect(),
+        project_config: language::load_project_configuration(&init_param),
         open_import_fallback: Some(Rc::new(move |path| {
             let to_preview = to_preview_clone.clone();
             // let server_notifier = server_notifier_.clone();
@@ -364,6 +366,7 @@ fn main_loop(connection: Connection, init_param: InitializeParams, cli_args: Cli
         #[cfg(any(feature = "preview-external", feature = "preview-engine"))]
         to_show: Default::default(),
         open_urls: Default::default(),
+        preview_data_path: Default::default(),
         to_preview,
     });
 
@@ -199,6 +199,25 @@ fn set_current_live_data(mut result: preview_data::PreviewDataMap) {
     })
 }
 
+/// Sets the properties from the JSON file that is configured in `slint.preview.data`.
+fn apply_preview_data_file() {
+    let Some(instance) = component_instance() else {
+        return;
+    };
+    let Some(data) = PREVIEW_STATE.with_borrow(|preview_state| preview_state.config.data.clone())
+    else {
+        return;
+    };
+
+    if let Err(e) = instance.set_json_data(&data) {
+        PREVIEW_STATE.with_borrow(|preview_state| {
+            if let Some(ui) = &preview_state.ui {
+                ui::log_messages::append_log_message(ui, ui::LogMessageLevel::Warning, None, &e);
+            }
+        });
+    }
+}
+
 fn apply_live_preview_data() {
     let Some(instance) = component_instance() else {
         return;
@@ -1085,6 +1104,7 @@ fn finish_parsing(preview_url: &Url, previewed_component: Option<String>, succes
             usize::MAX
         };
 
+        apply_preview_data_file();
         apply_live_preview_data();
 
         PREVIEW_STATE.with_borrow_mut(|preview_state| {
@@ -1129,16 +1149,35 @@ fn finish_parsing(preview_url: &Url, previewed_component: Option<String>, succes
 }
 
 fn config_changed(config: PreviewConfig) {
-    let Some((current, config)) = PREVIEW_STATE.with_borrow_mut(move |preview_state| {
+    let Some((current, config, data_only)) = PREVIEW_STATE.with_borrow_mut(move |preview_state| {
         (preview_state.config != config).then(|| {
+            let data_only =
+                PreviewConfig { data: config.data.clone(), ..preview_state.config.clone() }
+                    == config;
             preview_state.config = config.clone();
 
-            (preview_state.current_component(), preview_state.config.clone())
+            (preview_state.current_component(), preview_state.config.clone(), data_only)
         })
     }) else {
         return;
     };
 
+    if data_only {
+        // No need to reload, the data can be set on the current instance
+        apply_preview_data_file();
+        PREVIEW_STATE.with_borrow(|preview_state| {
+            if let (Some(ui), Some(instance)) =
+                (&preview_state.ui, preview_state.component_instance())
+            {
+                let preview_data =
+                    preview_data::query_preview_data_properties_and_callbacks(&instance);
+                let previewed_component = current.and_then(|c| c.component);
+                ui::ui_set_preview_data(ui, preview_data, previewed_component);
+            }
+        });
+        return;
+    }
+
     if let Some(hide_ui) = config.hide_ui {
         set_show_preview_ui(!hide_ui);
     }
@@ -500,6 +500,42 @@ fn handle_builtin_function(
                 Value::Void
             }
         }
+        BuiltinFunction::ColorHslaStruct => {
+            if arguments.len() != 1 {
+                return Value::Void;
+            }
+            if let Value::Brush(brush) = eval_expression(&arguments[0], local_context, None) {
+                let color = brush.color().to_hsl();
+                let values = IntoIterator::into_iter([
+                    ("hue".to_string(), Value::Number(color.hue.into())),
+                    ("saturation".to_string(), Value::Number(color.saturation.into())),
+                    ("lightness".to_string(), Value::Number(color.lightness.into())),
+                    ("alpha".to_string(), Value::Number(color.alpha.into())),
+                ])
+                .collect();
+                Value::Struct(values)
+            } else {
+                Value::Void
+            }
+        }
+        BuiltinFunction::ColorOklchStruct => {
+            if arguments.len() != 1 {
+                return Value::Void;
+            }
+            if let Value::Brush(brush) = eval_expression(&arguments[0], local_context, None) {
+                let color = brush.color().to_oklch();
+                let values = IntoIterator::into_iter([
+                    ("lightness".to_string(), Value::Number(color.lightness.into())),
+                    ("chroma".to_string(), Value::Number(color.chroma.into())),
+                    ("hue".to_string(), Value::Number(color.hue.into())),
+                    ("alpha".to_string(), Value::Number(color.alpha.into())),
+                ])
+                .collect();
+                Value::Struct(values)
+            } else {
+                Value::Void
+            }
+        }
         BuiltinFunction::ColorBrighter => {
             if arguments.len() != 2 {
                 return Value::Void;
@@ -562,6 +598,23 @@ fn handle_builtin_function(
 
             color_a.mix(&color_b, factor as _).into()
         }
+        BuiltinFunction::ColorMixIn => {
+            if arguments.len() != 4 {
+                return Value::Void;
+            }
+            let (Value::Brush(color_a), Value::Brush(color_b), Value::Number(factor)) = (
+                eval_expression(&arguments[0], local_context, None),
+                eval_expression(&arguments[1], local_context, None),
+                eval_expression(&arguments[2], local_context, None),
+            ) else {
+                return Value::Void;
+            };
+            let Ok(color_space) = eval_expression(&arguments[3], local_context, None).try_into()
+            else {
+                return Value::Void;
+            };
+            color_a.color().mix_in(&color_b.color(), factor as _, color_space).into()
+        }
         BuiltinFunction::ColorWithAlpha => {
             if arguments.len() != 2 {
                 return Value::Void;
@@ -628,6 +681,30 @@ fn handle_builtin_function(
             let a = (1. * a).clamp(0., 1.);
             Value::Brush(slint::Brush::SolidColor(slint::Color::from_hsva(h, s, v, a)))
         }
+        BuiltinFunction::Hsl => {
+            let h: f32 =
+                eval_expression(&arguments[0], local_context, None).try_into().unwrap_or_default();
+            let s: f32 =
+                eval_expression(&arguments[1], local_context, None).try_into().unwrap_or_default();
+            let l: f32 =
+                eval_expression(&arguments[2], local_context, None).try_into().unwrap_or_default();
+            let a: f32 =
+                eval_expression(&arguments[3], local_context, None).try_into().unwrap_or_default();
+            let (s, l, a) = (s.clamp(0., 1.), l.clamp(0., 1.), a.clamp(0., 1.));
+            Value::Brush(slint::Brush::SolidColor(slint::Color::from_hsl(h, s, l, a)))
+        }
+        BuiltinFunction::Oklch => {
+            let l: f32 =
+                eval_expression(&arguments[0], local_context, None).try_into().unwrap_or_default();
+            let c: f32 =
+                eval_expression(&arguments[1], local_context, None).try_into().unwrap_or_default();
+            let h: f32 =
+                eval_expression(&arguments[2], local_context, None).try_into().unwrap_or_default();
+            let a: f32 =
+                eval_expression(&arguments[3], local_context, None).try_into().unwrap_or_default();
+            let (l, c, a) = (l.clamp(0., 1.), c.max(0.), a.clamp(0., 1.));
+            Value::Brush(slint::Brush::SolidColor(slint::Color::from_oklch(l, c, h, a)))
+        }
         BuiltinFunction::ColorScheme => {
             local_context.window_adapter.as_ref().map_or(Value::Void, |win| {
                 win.internal(i_slint_core::InternalToken)
@@ -690,6 +767,74 @@ fn handle_builtin_function(
                     .unwrap_or_default(),
             ))
         }
+        BuiltinFunction::FuzzyFilter => {
+            let Value::Model(model) = eval_expression(&arguments[0], local_context, None) else {
+                return Value::Void;
+            };
+            let pattern: slint::SharedString =
+                eval_expression(&arguments[1], local_context, None).try_into().unwrap_or_default();
+            let texts = model.iter().map(|v| slint::SharedString::try_from(v).unwrap_or_default());
+            Value::Model(slint::ModelRc::new(slint::VecModel::from(
+                i_slint_core::fuzzy_match::fuzzy_filter(texts, &pattern)
+                    .into_iter()
+                    .map(|x| Value::Number(x as f64))
+                    .collect::<Vec<_>>(),
+            )))
+        }
+        BuiltinFunction::TableCellsToTsv => {
+            let Value::Model(rows) = eval_expression(&arguments[0], local_context, None) else {
+                return Value::Void;
+            };
+            let range = arguments[1..]
+                .iter()
+                .map(|arg| {
+                    i32::try_from(eval_expression(arg, local_context, None)).unwrap_or_default()
+                })
+                .collect::<Vec<_>>();
+            let [start_row, start_column, end_row, end_column] = range[..] else {
+                return Value::Void;
+            };
+            if start_row.max(end_row) < 0 || start_column.max(end_column) < 0 {
+                return Value::String(Default::default());
+            }
+            let columns = start_column.min(end_column).max(0)..=start_column.max(end_column);
+            Value::String(i_slint_core::tsv::cells_to_tsv(
+                (start_row.min(end_row).max(0)..=start_row.max(end_row)).map(|row| {
+                    let row = rows.row_data(row as usize);
+                    columns
+                        .clone()
+                        .map(|column| match &row {
+                            Some(Value::Model(row)) => match row.row_data(column as usize) {
+                                Some(Value::Struct(cell)) => cell
+                                    .get_field("text")
+                                    .and_then(|text| {
+                                        slint::SharedString::try_from(text.clone()).ok()
+                                    })
+                                    .unwrap_or_default(),
+                                _ => Default::default(),
+                            },
+                            _ => Default::default(),
+                        })
+                        .collect::<Vec<_>>()
+                }),
+            ))
+        }
+        BuiltinFunction::ColorToHex => {
+            let Value::Brush(brush) = eval_expression(&arguments[0], local_context, None) else {
+                return Value::Void;
+            };
+            Value::String(brush.color().to_hex_string())
+        }
+        BuiltinFunction::ParseColorHex => {
+            let s: slint::SharedString =
+                eval_expression(&arguments[0], local_context, None).try_into().unwrap_or_default();
+            let Value::Brush(fallback) = eval_expression(&arguments[1], local_context, None) else {
+                return Value::Void;
+            };
+            Value::Brush(slint::Brush::SolidColor(
+                slint::Color::from_hex_str(s.as_str()).unwrap_or(fallback.color()),
+            ))
+        }
         BuiltinFunction::Translate => {
             let original: slint::SharedString =
                 eval_expression(&arguments[0], local_context, None).try_into().unwrap_or_default();
@@ -722,6 +867,27 @@ fn from_index(&self, index: usize) -> Option<slint::SharedString> {
             Value::Bool(i_slint_core::date_time::use_24_hour_format())
         }
         BuiltinFunction::DetectOperatingSystem => i_slint_core::detect_operating_system().into(),
+        BuiltinFunction::SystemTimeOfDay => {
+            Value::Number(i_slint_core::system::system_time_of_day() as f64)
+        }
+        BuiltinFunction::SystemUnixTime => {
+            Value::Number(i_slint_core::system::system_unix_time() as f64)
+        }
+        BuiltinFunction::SystemUtcOffset => {
+            Value::Number(i_slint_core::system::system_utc_offset() as f64)
+        }
+        BuiltinFunction::SystemTimeZone => Value::String(i_slint_core::system::system_time_zone()),
+        BuiltinFunction::SystemTickInterval => {
+            Value::Number(i_slint_core::system::system_tick_interval() as f64)
+        }
+        BuiltinFunction::SystemLocale => Value::String(i_slint_core::system::system_locale()),
+        BuiltinFunction::SystemNetworkOnline => {
+            Value::Bool(i_slint_core::system::system_network_online())
+        }
+        BuiltinFunction::SystemOnBattery => Value::Bool(i_slint_core::system::system_on_battery()),
+        BuiltinFunction::SystemBatteryLevel => {
+            Value::Number(i_slint_core::system::system_battery_level() as f64)
+        }
         _ => Value::Void,
     }
 }
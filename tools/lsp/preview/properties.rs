@@ -541,6 +541,7 @@ pub(super) fn get_properties(
                     "max-height" => p.priority = 450,
                     "horizontal-stretch" => p.priority = 500,
                     "vertical-stretch" => p.priority = 550,
+                    "aspect-ratio" => p.priority = 600,
                     _ => { /* do nothing */ }
                 }
                 p
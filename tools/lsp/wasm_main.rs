@@ -224,6 +224,7 @@ pub fn create(
             server_notifier,
             to_show: Default::default(),
             open_urls: Default::default(),
+            preview_data_path: Default::default(),
             to_preview,
         }),
         reentry_guard,
@@ -6,12 +6,9 @@
 use clap::Parser;
 use i_slint_compiler::ComponentSelection;
 use itertools::Itertools;
-use slint_interpreter::{
-    json::JsonExt, ComponentDefinition, ComponentHandle, ComponentInstance, Value,
-};
-use std::collections::HashMap;
+use slint_interpreter::{json::JsonExt, ComponentHandle, ComponentInstance, Value};
 use std::io::{BufReader, BufWriter};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::{Arc, Mutex};
 
@@ -73,7 +70,8 @@ struct Cli {
     #[arg(long, action)]
     auto_reload: bool,
 
-    /// Load properties from a json file ('-' for stdin)
+    /// Load properties, and properties of exported globals, from a json file ('-' for stdin).
+    /// The file is watched, and the properties are loaded again when it changes.
     #[arg(long, value_name = "json file", action)]
     load_data: Option<std::path::PathBuf>,
 
@@ -123,7 +121,8 @@ fn main() -> Result<()> {
         )?;
     };
 
-    let fswatcher = if args.auto_reload { Some(start_fswatch_thread(args.clone())?) } else { None };
+    let watch = args.auto_reload || watched_data_path(&args).is_some();
+    let fswatcher = if watch { Some(start_fswatch_thread(args.clone())?) } else { None };
     let compiler = init_compiler(&args, fswatcher);
     let r = spin_on::spin_on(compiler.build_from_path(&args.path));
     r.print_diagnostics();
@@ -145,12 +144,12 @@ fn main() -> Result<()> {
     let component = c.create()?;
     init_dialog(&component);
 
-    if let Some(data_path) = args.load_data {
-        load_data(&c, &component, &data_path)?;
+    if let Some(data_path) = &args.load_data {
+        load_data(&component, data_path)?;
     }
     install_callbacks(&component, &args.on);
 
-    if args.auto_reload {
+    if watch {
         CURRENT_INSTANCE.with(|current| current.replace(Some(component.clone_strong())));
     }
 
@@ -198,14 +197,16 @@ fn init_compiler(
         compiler.set_style(style.clone());
     }
     if let Some(watcher) = fswatcher {
-        watch_with_retry(&args.path, &watcher);
-        if let Some(data_path) = &args.load_data {
-            watch_with_retry(data_path, &watcher);
+        if let Some(data_path) = watched_data_path(args) {
+            watch_with_retry(&data_path, &watcher);
+        }
+        if args.auto_reload {
+            watch_with_retry(&args.path, &watcher);
+            compiler.set_file_loader(move |path| {
+                watch_with_retry(path, &watcher);
+                Box::pin(async { None })
+            })
         }
-        compiler.set_file_loader(move |path| {
-            watch_with_retry(path, &watcher);
-            Box::pin(async { None })
-        })
     }
 
     compiler.compiler_configuration(i_slint_core::InternalToken).components_to_generate =
@@ -269,27 +270,46 @@ fn init_dialog(instance: &ComponentInstance) {
     }
 }
 
+/// The absolute path of the data file to watch, unless the data is read from stdin.
+/// The events of the watcher report the path as it was passed to it.
+fn watched_data_path(args: &Cli) -> Option<PathBuf> {
+    let data_path = args.load_data.as_ref().filter(|path| path.as_path() != Path::new("-"))?;
+    Some(std::path::absolute(data_path).unwrap_or_else(|_| data_path.clone()))
+}
+
 static PENDING_EVENTS: AtomicU32 = AtomicU32::new(0);
 
 fn start_fswatch_thread(args: Cli) -> Result<Arc<Mutex<notify::RecommendedWatcher>>> {
     let (tx, rx) = std::sync::mpsc::channel();
     let w = Arc::new(Mutex::new(notify::recommended_watcher(tx)?));
     let w2 = w.clone();
+    let data_path = watched_data_path(&args);
     std::thread::spawn(move || {
         while let Ok(event) = rx.recv() {
             use notify::EventKind::*;
-            if let Ok(event) = event {
-                if (matches!(event.kind, Modify(_) | Remove(_) | Create(_)))
-                    && PENDING_EVENTS.load(Ordering::SeqCst) == 0
-                {
-                    PENDING_EVENTS.fetch_add(1, Ordering::SeqCst);
-                    let args = args.clone();
-                    let w2 = w2.clone();
-                    i_slint_core::api::invoke_from_event_loop(move || {
-                        slint_interpreter::spawn_local(reload(args, w2)).unwrap();
-                    })
-                    .unwrap();
-                }
+            let Ok(event) = event else { continue };
+            if !matches!(event.kind, Modify(_) | Remove(_) | Create(_)) {
+                continue;
+            }
+            // Only load the data again if nothing else changed, to keep the state of the instance
+            if let Some(data_path) =
+                data_path.clone().filter(|data_path| event.paths.iter().all(|p| p == data_path))
+            {
+                let w2 = w2.clone();
+                i_slint_core::api::invoke_from_event_loop(move || {
+                    // Editors may replace the file when saving it, which removes the watch
+                    watch_with_retry(&data_path, &w2);
+                    reload_data(&data_path);
+                })
+                .unwrap();
+            } else if args.auto_reload && PENDING_EVENTS.load(Ordering::SeqCst) == 0 {
+                PENDING_EVENTS.fetch_add(1, Ordering::SeqCst);
+                let args = args.clone();
+                let w2 = w2.clone();
+                i_slint_core::api::invoke_from_event_loop(move || {
+                    slint_interpreter::spawn_local(reload(args, w2)).unwrap();
+                })
+                .unwrap();
             }
         }
     });
@@ -315,7 +335,7 @@ async fn reload(args: Cli, fswatcher: Arc<Mutex<notify::RecommendedWatcher>>) {
                 current.replace(handle);
             }
             if let Some(data_path) = args.load_data {
-                let _ = load_data(&c, current.as_ref().unwrap(), &data_path);
+                let _ = load_data(current.as_ref().unwrap(), &data_path);
             }
             eprintln!("Successful reload of {}", args.path.display());
         });
@@ -329,32 +349,26 @@ async fn reload(args: Cli, fswatcher: Arc<Mutex<notify::RecommendedWatcher>>) {
     PENDING_EVENTS.fetch_sub(1, Ordering::SeqCst);
 }
 
-fn load_data(
-    c: &ComponentDefinition,
-    instance: &ComponentInstance,
-    data_path: &std::path::Path,
-) -> Result<()> {
-    let json: serde_json::Value = if data_path == std::path::Path::new("-") {
+/// Loads the data file again into the current instance, after it changed.
+fn reload_data(data_path: &Path) {
+    CURRENT_INSTANCE.with(|current| {
+        let Some(instance) = current.borrow().as_ref().map(|i| i.clone_strong()) else { return };
+        match load_data(&instance, data_path) {
+            Ok(()) => eprintln!("Successful reload of {}", data_path.display()),
+            Err(e) => eprintln!("Error: cannot load data file {}: {e:?}", data_path.display()),
+        }
+    });
+}
+
+fn load_data(instance: &ComponentInstance, data_path: &Path) -> Result<()> {
+    let json: serde_json::Value = if data_path == Path::new("-") {
         serde_json::from_reader(std::io::stdin())?
     } else {
         serde_json::from_reader(BufReader::new(std::fs::File::open(data_path)?))?
     };
 
-    let types = c.properties_and_callbacks().collect::<HashMap<_, _>>();
-    let obj = json.as_object().ok_or("The data is not a JSON object")?;
-    for (name, v) in obj {
-        match types.get(name.as_str()) {
-            Some((t, _)) => match slint_interpreter::Value::from_json(t, v) {
-                Ok(v) => match instance.set_property(name, v) {
-                    Ok(()) => (),
-                    Err(e) => {
-                        eprintln!("Warning: cannot set property '{name}' from data file: {e}")
-                    }
-                },
-                Err(e) => eprintln!("Warning: cannot set property '{name}' from data file: {e}"),
-            },
-            None => eprintln!("Warning: ignoring unknown property: {name}"),
-        }
+    if let Err(e) = instance.set_json_data(&json) {
+        eprintln!("Warning: {e} from data file");
     }
     Ok(())
 }